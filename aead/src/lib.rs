@@ -36,6 +36,14 @@ use derivative::Derivative;
 use displaydoc::Display;
 use serde::{Deserialize, Deserializer, Serialize};
 
+/// Length of the zero padding prepended to the messages encrypted with
+/// [`EncKey::encrypt_key_committing`], for a 128-bit security level.
+const KEY_COMMITMENT_PADDING_LEN: usize = 32;
+/// Length of the content keys of [`encrypt_multi`].
+const CONTENT_KEY_LEN: usize = 32;
+/// Length of a wrapped content key: the content key and a 16-byte tag.
+const WRAPPED_KEY_LEN: usize = CONTENT_KEY_LEN + 16;
+
 #[derive(Clone, Eq, Derivative, Serialize, Deserialize)]
 #[derivative(PartialEq, Hash)]
 /// Public/encryption key for AEAD
@@ -112,6 +120,27 @@ impl EncKey {
             ephemeral_pk: EncKey(*ephemeral_keypair.public()),
         })
     }
+
+    /// Encrypt a message as [`Self::encrypt`], in a key-committing way: the
+    /// ciphertext only decrypts validly under a single key, with
+    /// [`KeyPair::decrypt_key_committing`].
+    ///
+    /// XChaCha20-Poly1305 alone is not key-committing, as a ciphertext can be
+    /// crafted to decrypt under two keys. This follows the padding fix of
+    /// [Albertini et al.][padding]: the message is prefixed with 32 zero
+    /// bytes, which are checked at decryption, so that a second key would have
+    /// to yield the same 256 bits of keystream.
+    ///
+    /// [padding]: https://www.usenix.org/conference/usenixsecurity22/presentation/albertini
+    pub fn encrypt_key_committing(
+        &self,
+        rng: impl RngCore + CryptoRng,
+        message: &[u8],
+        aad: &[u8],
+    ) -> Result<Ciphertext, AEADError> {
+        let padded_message = [&[0u8; KEY_COMMITMENT_PADDING_LEN][..], message].concat();
+        self.encrypt(rng, &padded_message, aad)
+    }
 }
 
 /// Private/decryption key for AEAD
@@ -135,11 +164,10 @@ impl Default for DecKey {
         Self(crypto_kx::SecretKey::from([0; crypto_kx::SecretKey::BYTES]))
     }
 }
+// never print the secret key
 impl fmt::Debug for DecKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("aead::DecKey")
-            .field(&self.0.to_bytes())
-            .finish()
+        f.debug_tuple("aead::DecKey").finish_non_exhaustive()
     }
 }
 
@@ -197,6 +225,120 @@ impl KeyPair {
             .map_err(|_| AEADError)?;
         Ok(plaintext)
     }
+
+    /// Decrypt a ciphertext from [`EncKey::encrypt_key_committing`], failing
+    /// unless its padding is intact.
+    pub fn decrypt_key_committing(
+        &self,
+        ciphertext: &Ciphertext,
+        aad: &[u8],
+    ) -> Result<Vec<u8>, AEADError> {
+        strip_key_commitment_padding(self.decrypt(ciphertext, aad)?)
+    }
+
+    /// Decrypt a ciphertext from [`encrypt_multi`] addressed to this key pair,
+    /// with the associated data used during encryption.
+    pub fn decrypt_multi(
+        &self,
+        ciphertext: &MultiRecipientCiphertext,
+        aad: &[u8],
+    ) -> Result<Vec<u8>, AEADError> {
+        let shared_secret = crypto_kx::Keypair::from(self.dec_key.0.clone())
+            .session_keys_from(&ciphertext.ephemeral_pk.0)
+            .rx;
+        let key_cipher = XChaCha20Poly1305::new(shared_secret.as_ref().into());
+        // the wrapped keys don't tell their recipients apart, so each one is
+        // tried in turn
+        let content_key = ciphertext
+            .wrapped_keys
+            .iter()
+            .find_map(|wrapped_key| {
+                key_cipher
+                    .decrypt(&ciphertext.nonce, wrapped_key.as_slice())
+                    .ok()
+            })
+            .ok_or(AEADError)?;
+        let cipher = XChaCha20Poly1305::new(content_key.as_slice().into());
+        let plaintext = cipher
+            .decrypt(
+                &ciphertext.nonce,
+                Payload {
+                    msg: &ciphertext.ct,
+                    aad,
+                },
+            )
+            .map_err(|_| AEADError)?;
+        strip_key_commitment_padding(plaintext)
+    }
+}
+
+// Check that a decrypted key-committing plaintext starts with the zero padding,
+// and remove it.
+fn strip_key_commitment_padding(mut plaintext: Vec<u8>) -> Result<Vec<u8>, AEADError> {
+    if plaintext.len() < KEY_COMMITMENT_PADDING_LEN
+        || plaintext[..KEY_COMMITMENT_PADDING_LEN]
+            .iter()
+            .any(|byte| *byte != 0)
+    {
+        return Err(AEADError);
+    }
+    plaintext.drain(..KEY_COMMITMENT_PADDING_LEN);
+    Ok(plaintext)
+}
+
+/// Encrypt a message once to several recipients: the message is encrypted
+/// under a random content key, which is then wrapped for each recipient with
+/// a key shared with a single ephemeral key pair. Each recipient thus adds 48
+/// bytes to the ciphertext, rather than a full ciphertext.
+///
+/// The message is encrypted in a key-committing way, see
+/// [`EncKey::encrypt_key_committing`], so that a sender wrapping different
+/// content keys for different recipients can't make them decrypt different
+/// messages. The ciphertext does not reveal the recipients, apart from their
+/// number.
+pub fn encrypt_multi(
+    mut rng: impl RngCore + CryptoRng,
+    recipients: &[EncKey],
+    message: &[u8],
+    aad: &[u8],
+) -> Result<MultiRecipientCiphertext, AEADError> {
+    if recipients.is_empty() {
+        return Err(AEADError);
+    }
+    let content_key = XChaCha20Poly1305::generate_key(&mut rng);
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut rng);
+    let padded_message = [&[0u8; KEY_COMMITMENT_PADDING_LEN][..], message].concat();
+    let ct = XChaCha20Poly1305::new(&content_key)
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: &padded_message,
+                aad,
+            },
+        )
+        .map_err(|_| AEADError)?;
+
+    // the keys shared with the recipients are distinct, so the nonce can be
+    // reused to wrap the content key
+    let ephemeral_keypair = crypto_kx::Keypair::generate(&mut rng);
+    let wrapped_keys = recipients
+        .iter()
+        .map(|recipient| {
+            let shared_secret = ephemeral_keypair.session_keys_to(&recipient.0).tx;
+            XChaCha20Poly1305::new(shared_secret.as_ref().into())
+                .encrypt(&nonce, content_key.as_slice())
+                .map_err(|_| AEADError)?
+                .try_into()
+                .map_err(|_| AEADError)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(MultiRecipientCiphertext {
+        nonce: Nonce(nonce),
+        ephemeral_pk: EncKey(*ephemeral_keypair.public()),
+        wrapped_keys,
+        ct,
+    })
 }
 // newtype for `chacha20poly1305::XNonce` for easier serde support for
 // `Ciphertext`.
@@ -279,6 +421,66 @@ pub struct Ciphertext {
     ephemeral_pk: EncKey,
 }
 
+/// The ciphertext produced by [`encrypt_multi`], shared by all recipients
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    CanonicalSerialize,
+    CanonicalDeserialize,
+)]
+pub struct MultiRecipientCiphertext {
+    nonce: Nonce,
+    ephemeral_pk: EncKey,
+    #[serde(with = "wrapped_keys_serde")]
+    wrapped_keys: Vec<[u8; WRAPPED_KEY_LEN]>,
+    ct: Vec<u8>,
+}
+
+// serde only supports arrays of up to 32 elements, so the wrapped keys are
+// (de)serialized as byte vectors.
+mod wrapped_keys_serde {
+    use super::*;
+
+    pub(super) fn serialize<S>(
+        wrapped_keys: &[[u8; WRAPPED_KEY_LEN]],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(wrapped_keys.iter().map(|wrapped_key| &wrapped_key[..]))
+    }
+
+    pub(super) fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> Result<Vec<[u8; WRAPPED_KEY_LEN]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<Vec<u8>>::deserialize(deserializer)?
+            .into_iter()
+            .map(|wrapped_key| {
+                wrapped_key
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| serde::de::Error::invalid_length(wrapped_key.len(), &"48 bytes"))
+            })
+            .collect()
+    }
+}
+
+impl MultiRecipientCiphertext {
+    /// The number of recipients of the ciphertext.
+    pub fn num_recipients(&self) -> usize {
+        self.wrapped_keys.len()
+    }
+}
+
 // TODO: (alex) Temporarily add CanonicalSerde back to these structs due to the
 // limitations of `tagged` proc macro and requests from downstream usage.
 // Tracking issue: <https://github.com/EspressoSystems/jellyfish/issues/288>
@@ -386,7 +588,7 @@ mod canonical_serde {
 #[cfg(test)]
 mod test {
     use super::*;
-    use ark_std::rand::SeedableRng;
+    use ark_std::{rand::SeedableRng, vec};
     use rand_chacha::ChaCha20Rng;
 
     #[test]
@@ -420,6 +622,92 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_key_committing_encryption() -> Result<(), AEADError> {
+        let mut rng = jf_utils::test_rng();
+        let keypair1 = KeyPair::generate(&mut rng);
+        let keypair2 = KeyPair::generate(&mut rng);
+        let msg = b"The quick brown fox jumps over the lazy dog".to_vec();
+        let aad = b"my associated data".to_vec();
+
+        let ct = keypair1
+            .enc_key
+            .encrypt_key_committing(&mut rng, &msg, &aad)?;
+        assert_eq!(keypair1.decrypt_key_committing(&ct, &aad)?, msg);
+        assert!(keypair2.decrypt_key_committing(&ct, &aad).is_err());
+        assert!(keypair1
+            .decrypt_key_committing(&ct, b"wrong associated data")
+            .is_err());
+        let ct = keypair1
+            .enc_key
+            .encrypt_key_committing(&mut rng, &[], &aad)?;
+        assert_eq!(
+            keypair1.decrypt_key_committing(&ct, &aad)?,
+            Vec::<u8>::new()
+        );
+
+        // the padding is part of the plaintext
+        let plaintext = keypair1.decrypt(&ct, &aad)?;
+        assert_eq!(plaintext, vec![0u8; KEY_COMMITMENT_PADDING_LEN]);
+        // messages without the padding are rejected
+        let ct = keypair1.enc_key.encrypt(&mut rng, &msg, &aad)?;
+        assert!(keypair1.decrypt_key_committing(&ct, &aad).is_err());
+        let ct =
+            keypair1
+                .enc_key
+                .encrypt(&mut rng, &[0u8; KEY_COMMITMENT_PADDING_LEN - 1], &aad)?;
+        assert!(keypair1.decrypt_key_committing(&ct, &aad).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_recipient_encryption() -> Result<(), AEADError> {
+        let mut rng = jf_utils::test_rng();
+        let keypairs: Vec<_> = (0..5).map(|_| KeyPair::generate(&mut rng)).collect();
+        let recipients: Vec<_> = keypairs[..4].iter().map(KeyPair::enc_key).collect();
+        let msg = b"The quick brown fox jumps over the lazy dog".to_vec();
+        let aad = b"my associated data".to_vec();
+
+        let ct = encrypt_multi(&mut rng, &recipients, &msg, &aad)?;
+        assert_eq!(ct.num_recipients(), 4);
+        for keypair in &keypairs[..4] {
+            assert_eq!(keypair.decrypt_multi(&ct, &aad)?, msg);
+            assert!(keypair
+                .decrypt_multi(&ct, b"wrong associated data")
+                .is_err());
+        }
+        assert!(keypairs[4].decrypt_multi(&ct, &aad).is_err());
+        assert!(encrypt_multi(&mut rng, &[], &msg, &aad).is_err());
+
+        // a single wrapped key per recipient on top of the message
+        let single_ct = encrypt_multi(&mut rng, &recipients[..1], &msg, &aad)?;
+        assert_eq!(
+            ct.serialized_size(Compress::Yes) - single_ct.serialized_size(Compress::Yes),
+            3 * WRAPPED_KEY_LEN
+        );
+
+        // serde
+        let bytes = bincode::serialize(&ct).unwrap();
+        let deserialized: MultiRecipientCiphertext = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(deserialized, ct);
+        let mut bytes = Vec::new();
+        ct.serialize_compressed(&mut bytes).unwrap();
+        let deserialized = MultiRecipientCiphertext::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(keypairs[2].decrypt_multi(&deserialized, &aad)?, msg);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_debug_hides_dec_key() {
+        let mut rng = jf_utils::test_rng();
+        let keypair = KeyPair::generate(&mut rng);
+        let secret = format!("{:?}", keypair.dec_key.0.to_bytes());
+        assert!(!format!("{:?}", keypair).contains(&secret));
+        assert_eq!(format!("{:?}", keypair.dec_key), "aead::DecKey(..)");
+    }
+
     #[test]
     fn test_serde() {
         let mut rng = jf_utils::test_rng();
@@ -0,0 +1,243 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Exponent ElGamal encryption of scalars, with the keys of this crate.
+//!
+//! A scalar `m` is encrypted to the public key `PK` as `(r * G, m * G + r *
+//! PK)` for a random `r`. Ciphertexts are additively homomorphic, see
+//! [`ExpCiphertext::add`], and can be re-randomized by anyone, see
+//! [`EncKey::rerandomize`], e.g. for encrypted tallying and mixnets.
+//! Decryption only recovers `m * G`, so [`KeyPair::decrypt_exponent`] solves
+//! the discrete logarithm of small plaintexts with baby-step giant-step.
+
+use crate::{EncKey, KeyPair, ParameterError};
+use ark_ec::{
+    twisted_edwards::{Projective, TECurveConfig as Config},
+    CurveGroup, Group,
+};
+use ark_ff::UniformRand;
+use ark_serialize::*;
+use ark_std::{
+    collections::BTreeMap,
+    format,
+    rand::{CryptoRng, RngCore},
+    vec::Vec,
+    Zero,
+};
+
+/// Exponent ElGamal ciphertext
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config"),
+    Eq(bound = "P: Config"),
+    Hash(bound = "P: Config")
+)]
+pub struct ExpCiphertext<P>
+where
+    P: Config,
+{
+    ephemeral: Projective<P>,
+    masked: Projective<P>,
+}
+
+impl<P> ExpCiphertext<P>
+where
+    P: Config,
+{
+    /// Homomorphic addition: a ciphertext of the sum of the plaintexts of
+    /// `self` and `other`, encrypted to the same key.
+    pub fn add(&self, other: &Self) -> Self {
+        Self {
+            ephemeral: self.ephemeral + other.ephemeral,
+            masked: self.masked + other.masked,
+        }
+    }
+}
+
+impl<P> EncKey<P>
+where
+    P: Config,
+{
+    /// Exponent ElGamal encryption of `msg` with pre-sampled randomness `r`
+    pub fn deterministic_encrypt_exponent(
+        &self,
+        r: P::ScalarField,
+        msg: P::ScalarField,
+    ) -> ExpCiphertext<P> {
+        ExpCiphertext {
+            ephemeral: Projective::<P>::generator() * r,
+            masked: Projective::<P>::generator() * msg + self.key * r,
+        }
+    }
+
+    /// Exponent ElGamal encryption of `msg`
+    pub fn encrypt_exponent<R: CryptoRng + RngCore>(
+        &self,
+        prng: &mut R,
+        msg: P::ScalarField,
+    ) -> ExpCiphertext<P> {
+        self.deterministic_encrypt_exponent(P::ScalarField::rand(prng), msg)
+    }
+
+    /// Re-randomize a ciphertext encrypted to this key with the randomness
+    /// `r`, i.e. add an encryption of 0 to it. The result is unlinkable to
+    /// `ctext` as long as `r` is uniformly random, and decrypts to the same
+    /// plaintext.
+    pub fn rerandomize(&self, ctext: &ExpCiphertext<P>, r: P::ScalarField) -> ExpCiphertext<P> {
+        ctext.add(&self.deterministic_encrypt_exponent(r, P::ScalarField::from(0u64)))
+    }
+}
+
+impl<P> KeyPair<P>
+where
+    P: Config,
+{
+    /// Decrypt a ciphertext whose plaintext is known to be in `[0, max)`, with
+    /// baby-step giant-step in `O(sqrt(max))` time and memory. Return an error
+    /// if the plaintext is out of range.
+    pub fn decrypt_exponent(
+        &self,
+        ctext: &ExpCiphertext<P>,
+        max: u64,
+    ) -> Result<u64, ParameterError> {
+        let target = ctext.masked - ctext.ephemeral * self.dec.key;
+        let step = ceil_sqrt(max).max(1);
+
+        // baby steps: j * G for j in [0, step)
+        let generator = Projective::<P>::generator();
+        let mut baby_steps = BTreeMap::new();
+        let mut point = Projective::<P>::zero();
+        for j in 0..step {
+            baby_steps.entry(point_key(&point)).or_insert(j);
+            point += generator;
+        }
+
+        // giant steps: target - i * step * G for i in [0, step)
+        let giant_step = generator * P::ScalarField::from(step);
+        let mut point = target;
+        for i in 0..step {
+            if let Some(j) = baby_steps.get(&point_key(&point)) {
+                let msg = i * step + j;
+                if msg < max {
+                    return Ok(msg);
+                }
+                break;
+            }
+            point -= giant_step;
+        }
+        Err(ParameterError(format!("plaintext not in [0, {max})")))
+    }
+}
+
+// The smallest `r` such that `r * r >= n`, computed with Newton's method.
+fn ceil_sqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    // start from a power of two no smaller than the square root
+    let mut root = 1u64 << ((65 - n.leading_zeros()) / 2);
+    loop {
+        let next = (root + n / root) / 2;
+        if next >= root {
+            break;
+        }
+        root = next;
+    }
+    // `root` is now the floor of the square root
+    if root * root < n {
+        root + 1
+    } else {
+        root
+    }
+}
+
+// The compressed encoding of a point, as a key of the baby-step table.
+fn point_key<P: Config>(point: &Projective<P>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    // serializing into a vector can't fail
+    point
+        .into_affine()
+        .serialize_compressed(&mut bytes)
+        .unwrap();
+    bytes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_ed_on_bls12_381::EdwardsConfig as ParamEd381;
+    use ark_ed_on_bn254::EdwardsConfig as ParamEd254;
+    use ark_std::rand::rngs::StdRng;
+
+    fn test_exponent_elgamal_helper<P: Config>() {
+        let mut rng = jf_utils::test_rng();
+        let key_pair = KeyPair::<P>::generate(&mut rng);
+        let enc_key = key_pair.enc_key();
+        let encrypt =
+            |msg: u64, rng: &mut StdRng| enc_key.encrypt_exponent(rng, P::ScalarField::from(msg));
+
+        for (msg, max) in [(0, 1), (5, 6), (99, 100), (1234, 10_000), (70_000, 1 << 20)] {
+            let ctext = encrypt(msg, &mut rng);
+            assert_eq!(key_pair.decrypt_exponent(&ctext, max).unwrap(), msg);
+            assert!(key_pair.decrypt_exponent(&ctext, msg).is_err());
+        }
+        assert!(key_pair.decrypt_exponent(&encrypt(0, &mut rng), 0).is_err());
+
+        // encrypted tally
+        let votes = [1, 0, 1, 1, 0, 1, 1];
+        let tally = votes
+            .iter()
+            .map(|vote| encrypt(*vote, &mut rng))
+            .reduce(|sum, ctext| sum.add(&ctext))
+            .unwrap();
+        assert_eq!(key_pair.decrypt_exponent(&tally, 8).unwrap(), 5);
+
+        // re-randomization
+        let ctext = encrypt(42, &mut rng);
+        let rerandomized = enc_key.rerandomize(&ctext, P::ScalarField::rand(&mut rng));
+        assert_ne!(rerandomized, ctext);
+        assert_eq!(key_pair.decrypt_exponent(&rerandomized, 100).unwrap(), 42);
+        assert_eq!(
+            enc_key.rerandomize(&ctext, P::ScalarField::from(0u64)),
+            ctext
+        );
+
+        // another key decrypts to something else
+        let other_key_pair = KeyPair::<P>::generate(&mut rng);
+        assert!(other_key_pair.decrypt_exponent(&ctext, 100).is_err());
+
+        let mut bytes = Vec::new();
+        ctext.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            ExpCiphertext::<P>::deserialize_compressed(&bytes[..]).unwrap(),
+            ctext
+        );
+    }
+
+    #[test]
+    fn test_ceil_sqrt() {
+        for n in 0..10_000u64 {
+            let root = ceil_sqrt(n);
+            assert!(root * root >= n);
+            assert!(root == 0 || (root - 1) * (root - 1) < n);
+        }
+        assert_eq!(ceil_sqrt(u64::MAX), 1 << 32);
+        assert_eq!(
+            ceil_sqrt((u32::MAX as u64) * (u32::MAX as u64)),
+            u32::MAX as u64
+        );
+        assert_eq!(ceil_sqrt(1 << 62), 1 << 31);
+        assert_eq!(ceil_sqrt((1 << 62) + 1), (1 << 31) + 1);
+    }
+
+    #[test]
+    fn test_exponent_elgamal() {
+        test_exponent_elgamal_helper::<ParamEd254>();
+        test_exponent_elgamal_helper::<ParamEd381>();
+    }
+}
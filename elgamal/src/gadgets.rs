@@ -6,12 +6,12 @@
 
 //! Circuit implementation of the ElGamal scheme.
 
-use crate::{Ciphertext, EncKey};
+use crate::{Ciphertext, Direction, EncKey, KeyPair};
 use ark_ec::{
     twisted_edwards::{Affine, TECurveConfig},
     AffineRepr, CurveGroup,
 };
-use ark_ff::PrimeField;
+use ark_ff::{BigInteger, PrimeField};
 use ark_std::{vec, vec::Vec};
 use jf_relation::{
     gadgets::ecc::{PointVariable, TEPoint},
@@ -26,6 +26,10 @@ use jf_rescue::{
 #[derive(Debug)]
 pub struct EncKeyVars(pub PointVariable);
 
+/// Variable holding a decryption key.
+#[derive(Debug)]
+pub struct DecKeyVars(pub Variable);
+
 /// Variables holding a ciphertext.
 #[derive(Debug)]
 pub struct ElGamalHybridCtxtVars {
@@ -39,17 +43,29 @@ trait ElGamalEncryptionHelperGadget<F>
 where
     F: PrimeField,
 {
+    /// Derive the symmetric key from the point shared by the ephemeral key and
+    /// the encryption key.
+    /// * `shared_pk_var` - variables corresponding to the shared point
+    /// * `returns` - the variables corresponding to the symmetric key
+    fn derive_symmetric_key(
+        &mut self,
+        shared_pk_var: &PointVariable,
+    ) -> Result<RescueStateVar, CircuitError>;
+
     /// Rescue counter mode encryption with no padding
     /// The key should be a fresh one in each call, and the nonce is initialized
     /// to zero.
     /// * `key_var` - variables corresponding to the symmetric key
     /// * `data_vars` - the variables for the data to be encrypted. The format
     ///   of this input is a list of rescue states.
+    /// * `direction` - whether the stream is added (encryption) or subtracted
+    ///   (decryption)
     /// * `returns` - the variables that map to the ciphertext contents
     fn apply_counter_mode_stream_no_padding(
         &mut self,
         key_var: &RescueStateVar,
         data_vars: &[RescueStateVar],
+        direction: Direction,
     ) -> Result<Vec<RescueStateVar>, CircuitError>;
 
     /// Rescue counter mode encryption with padding
@@ -59,12 +75,15 @@ where
     /// * `key_var` - variables corresponding to the symmetric key
     /// * `data_vars` - the variables for the data to be encrypted. The format
     ///   of this input is a list of variable of arbitrary length
+    /// * `direction` - whether the stream is added (encryption) or subtracted
+    ///   (decryption)
     /// * `returns` - the variables that map to the ciphertext contents. The
     ///   output size is the same as the length of data_vars
     fn apply_counter_mode_stream(
         &mut self,
         key_var: &RescueStateVar,
         data_vars: &[Variable],
+        direction: Direction,
     ) -> Result<Vec<Variable>, CircuitError>;
 }
 
@@ -72,10 +91,25 @@ impl<F> ElGamalEncryptionHelperGadget<F> for PlonkCircuit<F>
 where
     F: RescueParameter,
 {
+    fn derive_symmetric_key(
+        &mut self,
+        shared_pk_var: &PointVariable,
+    ) -> Result<RescueStateVar, CircuitError> {
+        let zero_var = self.zero();
+        let key_perm_input_var = RescueStateVar::from([
+            shared_pk_var.get_x(),
+            shared_pk_var.get_y(),
+            zero_var,
+            zero_var,
+        ]);
+        self.rescue_permutation(key_perm_input_var)
+    }
+
     fn apply_counter_mode_stream_no_padding(
         &mut self,
         key_var: &RescueStateVar,
         data_vars: &[RescueStateVar],
+        direction: Direction,
     ) -> Result<Vec<RescueStateVar>, CircuitError> {
         let zero_var = self.zero();
 
@@ -108,7 +142,10 @@ where
                     .iter_mut()
                     .zip(stream_chunk_vars.array().iter())
                 {
-                    *output_chunk_var = self.add(*output_chunk_var, *stream_chunk_var)?;
+                    *output_chunk_var = match direction {
+                        Direction::Encrypt => self.add(*output_chunk_var, *stream_chunk_var)?,
+                        Direction::Decrypt => self.sub(*output_chunk_var, *stream_chunk_var)?,
+                    };
                 }
                 Ok(())
             })?;
@@ -120,6 +157,7 @@ where
         &mut self,
         key_var: &RescueStateVar,
         data_vars: &[Variable],
+        direction: Direction,
     ) -> Result<Vec<Variable>, CircuitError> {
         let zero_var = self.zero();
 
@@ -139,8 +177,11 @@ where
             let state = RescueStateVar::from([block[0], block[1], block[2], block[3]]);
             data_vars_states.push(state);
         }
-        let encrypted_output_var_states =
-            self.apply_counter_mode_stream_no_padding(key_var, data_vars_states.as_slice())?;
+        let encrypted_output_var_states = self.apply_counter_mode_stream_no_padding(
+            key_var,
+            data_vars_states.as_slice(),
+            direction,
+        )?;
 
         // Rebuild the output getting rid of the extra variables
         let mut output_vars: Vec<Variable> = vec![];
@@ -203,16 +244,10 @@ where
         r: Variable,
     ) -> Result<ElGamalHybridCtxtVars, CircuitError> {
         let shared_pk_var = self.variable_base_scalar_mul::<P>(r, &pk_var.0)?;
-        let zero_var = self.zero();
-        let key_perm_input_var = RescueStateVar::from([
-            shared_pk_var.get_x(),
-            shared_pk_var.get_y(),
-            zero_var,
-            zero_var,
-        ]);
-        let symm_key_vars = self.rescue_permutation(key_perm_input_var)?;
+        let symm_key_vars = self.derive_symmetric_key(&shared_pk_var)?;
 
-        let symm_ctxts = self.apply_counter_mode_stream(&symm_key_vars, data_vars)?;
+        let symm_ctxts =
+            self.apply_counter_mode_stream(&symm_key_vars, data_vars, Direction::Encrypt)?;
         let base = Affine::<P>::generator();
         let ephemeral = self.fixed_base_scalar_mul(r, &base)?;
         Ok(ElGamalHybridCtxtVars {
@@ -245,6 +280,93 @@ where
     }
 }
 
+/// Circuit implementation of the decryption of the ElGamal scheme.
+pub trait ElGamalDecryptionGadget<F, P>
+where
+    F: PrimeField,
+    P: TECurveConfig<BaseField = F>,
+{
+    /// Compute the gadget that check a correct Elgamal decryption, without
+    /// revealing the decryption key.
+    /// * `pk_vars` - variables corresponding to the encryption public key
+    /// * `sk_var` - variable corresponding to the decryption key of `pk_vars`
+    /// * `ctxts_vars` - variables corresponding to the ciphertext
+    /// * `returns` - variables corresponding to the plaintext
+    fn elgamal_decrypt(
+        &mut self,
+        pk_vars: &EncKeyVars,
+        sk_var: &DecKeyVars,
+        ctxts_vars: &ElGamalHybridCtxtVars,
+    ) -> Result<Vec<Variable>, CircuitError>;
+
+    /// Compute the gadget that check that a ciphertext is a re-randomization
+    /// of another one, i.e. encrypts the same plaintext under the same key
+    /// with fresh randomness. The symmetric key of the hybrid encryption being
+    /// derived from the shared point, a ciphertext cannot be re-randomized
+    /// homomorphically: it is decrypted and encrypted again.
+    /// * `pk_vars` - variables corresponding to the encryption public key
+    /// * `sk_var` - variable corresponding to the decryption key of `pk_vars`
+    /// * `ctxts_vars` - variables corresponding to the ciphertext
+    /// * `r` - variable corresponding to the new encryption randomness
+    /// * `returns` - variables corresponding to the new ciphertext
+    fn elgamal_rerandomize(
+        &mut self,
+        pk_vars: &EncKeyVars,
+        sk_var: &DecKeyVars,
+        ctxts_vars: &ElGamalHybridCtxtVars,
+        r: Variable,
+    ) -> Result<ElGamalHybridCtxtVars, CircuitError>;
+
+    /// Helper function to create a decryption key variable
+    /// * `keypair` - key pair holding the decryption key
+    /// * `returns` - struct containing the variable corresponding to the key
+    fn create_dec_key_variable(&mut self, keypair: &KeyPair<P>)
+        -> Result<DecKeyVars, CircuitError>;
+}
+
+impl<F, P> ElGamalDecryptionGadget<F, P> for PlonkCircuit<F>
+where
+    F: RescueParameter,
+    P: TECurveConfig<BaseField = F>,
+{
+    fn elgamal_decrypt(
+        &mut self,
+        pk_vars: &EncKeyVars,
+        sk_var: &DecKeyVars,
+        ctxts_vars: &ElGamalHybridCtxtVars,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        // the decryption key matches the encryption key
+        let base = Affine::<P>::generator();
+        let pk_var = self.fixed_base_scalar_mul(sk_var.0, &base)?;
+        self.enforce_point_equal(&pk_var, &pk_vars.0)?;
+
+        let shared_pk_var = self.variable_base_scalar_mul::<P>(sk_var.0, &ctxts_vars.ephemeral)?;
+        let symm_key_vars = self.derive_symmetric_key(&shared_pk_var)?;
+        self.apply_counter_mode_stream(&symm_key_vars, &ctxts_vars.symm_ctxts, Direction::Decrypt)
+    }
+
+    fn elgamal_rerandomize(
+        &mut self,
+        pk_vars: &EncKeyVars,
+        sk_var: &DecKeyVars,
+        ctxts_vars: &ElGamalHybridCtxtVars,
+        r: Variable,
+    ) -> Result<ElGamalHybridCtxtVars, CircuitError> {
+        let data_vars =
+            ElGamalDecryptionGadget::<F, P>::elgamal_decrypt(self, pk_vars, sk_var, ctxts_vars)?;
+        ElGamalEncryptionGadget::<F, P>::elgamal_encrypt(self, pk_vars, &data_vars, r)
+    }
+
+    fn create_dec_key_variable(
+        &mut self,
+        keypair: &KeyPair<P>,
+    ) -> Result<DecKeyVars, CircuitError> {
+        // the scalar field is smaller than the base field
+        let sk = F::from_le_bytes_mod_order(&keypair.dec_key_ref().key.into_bigint().to_bytes_le());
+        Ok(DecKeyVars(self.create_variable(sk)?))
+    }
+}
+
 #[inline]
 fn compute_len_to_next_multiple(len: usize, multiple: usize) -> usize {
     if len % multiple == 0 {
@@ -258,7 +380,10 @@ fn compute_len_to_next_multiple(len: usize, multiple: usize) -> usize {
 mod tests {
     use crate::{
         apply_counter_mode_stream,
-        gadgets::{ElGamalEncryptionGadget, ElGamalEncryptionHelperGadget},
+        gadgets::{
+            ElGamalDecryptionGadget, ElGamalEncryptionGadget, ElGamalEncryptionHelperGadget,
+        },
+        Ciphertext,
         Direction::Encrypt,
         KeyPair,
     };
@@ -309,7 +434,7 @@ mod tests {
         }
 
         let ctxts_vars = circuit
-            .apply_counter_mode_stream_no_padding(&key_var, data_vars.as_slice())
+            .apply_counter_mode_stream_no_padding(&key_var, data_vars.as_slice(), Encrypt)
             .unwrap();
 
         let encrypted_data = apply_counter_mode_stream::<F>(&key, &data, &F::zero(), Encrypt);
@@ -415,6 +540,93 @@ mod tests {
         assert!(circuit.check_circuit_satisfiability(&[]).is_err());
     }
 
+    #[test]
+    fn test_elgamal_decrypt_circuit() {
+        test_elgamal_decrypt_circuit_helper::<FqEd254, ParamEd254>();
+        test_elgamal_decrypt_circuit_helper::<FqEd377, ParamEd377>();
+        test_elgamal_decrypt_circuit_helper::<FqEd381, ParamEd381>();
+        test_elgamal_decrypt_circuit_helper::<FqEd381b, ParamEd381b>();
+    }
+    fn test_elgamal_decrypt_circuit_helper<F, P>()
+    where
+        F: RescueParameter,
+        P: TECurveConfig<BaseField = F>,
+    {
+        let mut prng = jf_utils::test_rng();
+        let keypair = KeyPair::<P>::generate(&mut prng);
+        let other_keypair = KeyPair::<P>::generate(&mut prng);
+        let data: Vec<F> = (0..2 * STATE_SIZE + 1).map(|i| F::from(i as u32)).collect();
+        let ctxts = keypair.enc_key_ref().encrypt(&mut prng, &data);
+
+        // Decrypt
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let pk_var = circuit
+            .create_enc_key_variable(keypair.enc_key_ref())
+            .unwrap();
+        let sk_var = circuit.create_dec_key_variable(&keypair).unwrap();
+        let ctxts_vars = circuit.create_ciphertext_variable(&ctxts).unwrap();
+        let data_vars = ElGamalDecryptionGadget::<_, P>::elgamal_decrypt(
+            &mut circuit,
+            &pk_var,
+            &sk_var,
+            &ctxts_vars,
+        )
+        .unwrap();
+        assert_eq!(data_vars.len(), data.len());
+        for (val, &var) in data.iter().zip(data_vars.iter()) {
+            assert_eq!(*val, circuit.witness(var).unwrap());
+        }
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        // Alter plaintext
+        *circuit.witness_mut(data_vars[0]) = F::from(1_u32);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // Decryption key not matching the encryption key
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let pk_var = circuit
+            .create_enc_key_variable(keypair.enc_key_ref())
+            .unwrap();
+        let sk_var = circuit.create_dec_key_variable(&other_keypair).unwrap();
+        let ctxts_vars = circuit.create_ciphertext_variable(&ctxts).unwrap();
+        ElGamalDecryptionGadget::<_, P>::elgamal_decrypt(
+            &mut circuit,
+            &pk_var,
+            &sk_var,
+            &ctxts_vars,
+        )
+        .unwrap();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // Re-randomize
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let pk_var = circuit
+            .create_enc_key_variable(keypair.enc_key_ref())
+            .unwrap();
+        let sk_var = circuit.create_dec_key_variable(&keypair).unwrap();
+        let ctxts_vars = circuit.create_ciphertext_variable(&ctxts).unwrap();
+        let r = P::ScalarField::rand(&mut prng);
+        let r_var = circuit.create_variable(fr_to_fq::<F, P>(&r)).unwrap();
+        let new_ctxts_vars = ElGamalDecryptionGadget::<_, P>::elgamal_rerandomize(
+            &mut circuit,
+            &pk_var,
+            &sk_var,
+            &ctxts_vars,
+            r_var,
+        )
+        .unwrap();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        let new_ephemeral = circuit.point_witness(&new_ctxts_vars.ephemeral).unwrap();
+        let mut new_scalars = vec![new_ephemeral.get_x(), new_ephemeral.get_y()];
+        for &var in new_ctxts_vars.symm_ctxts.iter() {
+            new_scalars.push(circuit.witness(var).unwrap());
+        }
+        let new_ctxts = Ciphertext::<P>::from_scalars(&new_scalars).unwrap();
+        assert_eq!(new_ctxts, keypair.enc_key().deterministic_encrypt(r, &data));
+        assert_ne!(new_ctxts, ctxts);
+        assert_eq!(keypair.decrypt(&new_ctxts), data);
+    }
+
     #[test]
 
     fn test_create_ciphertext_variable() {
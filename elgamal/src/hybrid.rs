@@ -0,0 +1,351 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! ECIES-style hybrid encryption of byte strings, combining a key
+//! encapsulation mechanism (KEM) with a data encapsulation mechanism (DEM).
+//!
+//! The KEM is a Diffie-Hellman exchange on the twisted Edwards curve of the
+//! ElGamal keys of this crate: the sender samples an ephemeral key pair
+//! `(r, R = r * G)` and computes the shared point `S = r * PK`, which the
+//! recipient recomputes as `S = sk * R`. A [`Kdf`] derives a single-use DEM
+//! key from the coordinates of `R` and `S`, under which a [`Dem`] encrypts the
+//! message along with associated data. [`HybridCipher`] is parameterized by
+//! the curve, the KDF and the DEM:
+//!
+//! * KDFs: [`HkdfSha256`], and [`RescueKdf`] built on
+//!   [`jf_rescue::kdf::RescueKDF`].
+//! * DEMs: [`XChaCha20Poly1305Dem`], and [`RescueDem`] built on
+//!   [`jf_rescue::aead::RescueAEAD`].
+//!
+//! Unlike the Rescue stream cipher of [`EncKey::encrypt`], every DEM
+//! authenticates the ciphertext and the associated data.
+
+use crate::{EncKey, KeyPair};
+use ark_ec::{
+    twisted_edwards::{Affine, TECurveConfig as Config},
+    CurveGroup,
+};
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::*;
+use ark_std::{
+    marker::PhantomData,
+    rand::{CryptoRng, RngCore},
+    string::{String, ToString},
+    vec::Vec,
+};
+use chacha20poly1305::{
+    aead::{Aead, Payload},
+    KeyInit, XChaCha20Poly1305, XNonce,
+};
+use displaydoc::Display;
+use hkdf::Hkdf;
+use jf_rescue::{aead::RescueAEAD, kdf::RescueKDF, RescueParameter};
+use jf_utils::{bytes_from_field_elements, bytes_to_field_elements};
+use sha2::Sha256;
+
+/// Byte length of the DEM keys.
+pub const DEM_KEY_LEN: usize = 32;
+
+/// Info string of [`HkdfSha256`].
+const HKDF_INFO: &[u8] = b"JF_HYBRID_HKDF_SHA256";
+/// Label of [`RescueKdf`].
+const RESCUE_KDF_LABEL: &[u8] = b"JF_HYBRID_RESCUE_KDF";
+
+/// Hybrid encryption error: {0}
+#[derive(Display, Debug)]
+pub struct HybridCipherError(String);
+
+impl ark_std::error::Error for HybridCipherError {}
+
+/// Key derivation from the output of the KEM to the key of the DEM.
+pub trait Kdf<F: PrimeField> {
+    /// Derive the DEM key from the KEM context: the coordinates of the
+    /// ephemeral public key, then of the shared point.
+    fn derive_key(kem_context: &[F; 4]) -> [u8; DEM_KEY_LEN];
+}
+
+/// Authenticated encryption under single-use keys.
+pub trait Dem {
+    /// Encrypt `plaintext` with associated data `aad` under `key`.
+    fn encrypt(
+        key: &[u8; DEM_KEY_LEN],
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, HybridCipherError>;
+
+    /// Decrypt `ciphertext` with associated data `aad` under `key`. Return an
+    /// error if either was tampered with.
+    fn decrypt(
+        key: &[u8; DEM_KEY_LEN],
+        ciphertext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, HybridCipherError>;
+}
+
+/// HKDF with SHA-256, over the compressed serialization of the KEM context.
+#[derive(Debug, Clone, Copy)]
+pub struct HkdfSha256;
+
+impl<F: PrimeField> Kdf<F> for HkdfSha256 {
+    fn derive_key(kem_context: &[F; 4]) -> [u8; DEM_KEY_LEN] {
+        let mut ikm = Vec::new();
+        for elem in kem_context {
+            // serializing into a vector can't fail
+            elem.serialize_compressed(&mut ikm).unwrap();
+        }
+        let mut key = [0u8; DEM_KEY_LEN];
+        // a single block of output is always valid
+        Hkdf::<Sha256>::new(None, &ikm)
+            .expand(HKDF_INFO, &mut key)
+            .unwrap();
+        key
+    }
+}
+
+/// The Rescue KDF, deriving the key from the `x`-coordinate of the shared
+/// point, labelled with the rest of the KEM context. The key is the
+/// little-endian encoding of the derived field element, so the base field of
+/// the curve should be of at least 256 bits.
+#[derive(Debug, Clone, Copy)]
+pub struct RescueKdf;
+
+impl<F: RescueParameter> Kdf<F> for RescueKdf {
+    fn derive_key(kem_context: &[F; 4]) -> [u8; DEM_KEY_LEN] {
+        let label = [
+            F::from_le_bytes_mod_order(RESCUE_KDF_LABEL),
+            kem_context[0],
+            kem_context[1],
+            kem_context[3],
+        ];
+        let bytes = RescueKDF::derive(&kem_context[2], &label)
+            .into_bigint()
+            .to_bytes_le();
+        let mut key = [0u8; DEM_KEY_LEN];
+        let len = bytes.len().min(DEM_KEY_LEN);
+        key[..len].copy_from_slice(&bytes[..len]);
+        key
+    }
+}
+
+/// XChaCha20-Poly1305, with a fixed nonce as each key is used once.
+#[derive(Debug, Clone, Copy)]
+pub struct XChaCha20Poly1305Dem;
+
+impl Dem for XChaCha20Poly1305Dem {
+    fn encrypt(
+        key: &[u8; DEM_KEY_LEN],
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, HybridCipherError> {
+        XChaCha20Poly1305::new(key.as_slice().into())
+            .encrypt(
+                &XNonce::default(),
+                Payload {
+                    msg: plaintext,
+                    aad,
+                },
+            )
+            .map_err(|_| HybridCipherError("XChaCha20-Poly1305 encryption failed".to_string()))
+    }
+
+    fn decrypt(
+        key: &[u8; DEM_KEY_LEN],
+        ciphertext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, HybridCipherError> {
+        XChaCha20Poly1305::new(key.as_slice().into())
+            .decrypt(
+                &XNonce::default(),
+                Payload {
+                    msg: ciphertext,
+                    aad,
+                },
+            )
+            .map_err(|_| HybridCipherError("XChaCha20-Poly1305 decryption failed".to_string()))
+    }
+}
+
+/// The Rescue AEAD over the field `F`, with a zero nonce as each key is used
+/// once. The plaintext and associated data are encoded into field elements
+/// with [`bytes_to_field_elements`], and the ciphertext is the concatenation of
+/// the compressed encrypted elements and tag.
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""), Clone(bound = ""), Copy(bound = ""))]
+pub struct RescueDem<F>(PhantomData<F>);
+
+impl<F: RescueParameter> Dem for RescueDem<F> {
+    fn encrypt(
+        key: &[u8; DEM_KEY_LEN],
+        plaintext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, HybridCipherError> {
+        let (ciphertext, tag) = RescueAEAD::encrypt(
+            &F::from_le_bytes_mod_order(key),
+            &F::zero(),
+            &bytes_to_field_elements::<_, F>(aad),
+            &bytes_to_field_elements::<_, F>(plaintext),
+        );
+        let mut bytes = Vec::new();
+        for elem in ciphertext.iter().chain([&tag]) {
+            elem.serialize_compressed(&mut bytes)
+                .map_err(|e| HybridCipherError(e.to_string()))?;
+        }
+        Ok(bytes)
+    }
+
+    fn decrypt(
+        key: &[u8; DEM_KEY_LEN],
+        ciphertext: &[u8],
+        aad: &[u8],
+    ) -> Result<Vec<u8>, HybridCipherError> {
+        let elem_len = F::zero().compressed_size();
+        if ciphertext.is_empty() || ciphertext.len() % elem_len != 0 {
+            return Err(HybridCipherError(
+                "Rescue ciphertext of invalid length".to_string(),
+            ));
+        }
+        let mut elems = ciphertext
+            .chunks(elem_len)
+            .map(F::deserialize_compressed)
+            .collect::<Result<Vec<F>, _>>()
+            .map_err(|e| HybridCipherError(e.to_string()))?;
+        // the length was checked to be positive
+        let tag = elems.pop().unwrap();
+        let plaintext = RescueAEAD::decrypt(
+            &F::from_le_bytes_mod_order(key),
+            &F::zero(),
+            &bytes_to_field_elements::<_, F>(aad),
+            &elems,
+            &tag,
+        )
+        .map_err(|e| HybridCipherError(e.to_string()))?;
+        Ok(bytes_from_field_elements(plaintext))
+    }
+}
+
+/// Ciphertext of [`HybridCipher`]: the ephemeral public key and the DEM
+/// ciphertext.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config"),
+    Eq(bound = "P: Config"),
+    Hash(bound = "P: Config")
+)]
+pub struct HybridCiphertext<P>
+where
+    P: Config,
+{
+    ephemeral: EncKey<P>,
+    data: Vec<u8>,
+}
+
+/// Hybrid encryption with a Diffie-Hellman KEM on the curve `P`, the KDF `K`
+/// and the DEM `D`.
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""), Clone(bound = ""), Copy(bound = ""))]
+pub struct HybridCipher<P, K, D>(PhantomData<(P, K, D)>);
+
+impl<F, P, K, D> HybridCipher<P, K, D>
+where
+    F: PrimeField,
+    P: Config<BaseField = F>,
+    K: Kdf<F>,
+    D: Dem,
+{
+    /// Encrypt `message` to `enc_key`, authenticating `aad` along with it.
+    pub fn encrypt<R: CryptoRng + RngCore>(
+        rng: &mut R,
+        enc_key: &EncKey<P>,
+        message: &[u8],
+        aad: &[u8],
+    ) -> Result<HybridCiphertext<P>, HybridCipherError> {
+        let ephemeral = KeyPair::<P>::generate(rng);
+        let shared_point = (enc_key.key * ephemeral.dec_key_ref().key).into_affine();
+        let key = Self::dem_key(ephemeral.enc_key_ref(), &shared_point);
+        Ok(HybridCiphertext {
+            ephemeral: ephemeral.enc_key(),
+            data: D::encrypt(&key, message, aad)?,
+        })
+    }
+
+    /// Decrypt `ciphertext` with `key_pair`, given the associated data used
+    /// during encryption.
+    pub fn decrypt(
+        key_pair: &KeyPair<P>,
+        ciphertext: &HybridCiphertext<P>,
+        aad: &[u8],
+    ) -> Result<Vec<u8>, HybridCipherError> {
+        let shared_point = (ciphertext.ephemeral.key * key_pair.dec_key_ref().key).into_affine();
+        let key = Self::dem_key(&ciphertext.ephemeral, &shared_point);
+        D::decrypt(&key, &ciphertext.data, aad)
+    }
+
+    fn dem_key(ephemeral: &EncKey<P>, shared_point: &Affine<P>) -> [u8; DEM_KEY_LEN] {
+        let ephemeral = ephemeral.key.into_affine();
+        K::derive_key(&[ephemeral.x, ephemeral.y, shared_point.x, shared_point.y])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_ed_on_bls12_381::{EdwardsConfig as ParamEd381, Fq as FqEd381};
+    use ark_ed_on_bn254::{EdwardsConfig as ParamEd254, Fq as FqEd254};
+    use ark_std::vec;
+
+    fn test_hybrid_helper<P, K, D>()
+    where
+        P: Config,
+        P::BaseField: PrimeField,
+        K: Kdf<P::BaseField>,
+        D: Dem,
+    {
+        let mut rng = jf_utils::test_rng();
+        let key_pair = KeyPair::<P>::generate(&mut rng);
+        let other_key_pair = KeyPair::<P>::generate(&mut rng);
+        let aad = b"block 1024";
+
+        for message in [vec![], b"hello".to_vec(), vec![7u8; 200]] {
+            let ciphertext =
+                HybridCipher::<P, K, D>::encrypt(&mut rng, key_pair.enc_key_ref(), &message, aad)
+                    .unwrap();
+            assert_eq!(
+                HybridCipher::<P, K, D>::decrypt(&key_pair, &ciphertext, aad).unwrap(),
+                message
+            );
+            assert!(HybridCipher::<P, K, D>::decrypt(&other_key_pair, &ciphertext, aad).is_err());
+            assert!(
+                HybridCipher::<P, K, D>::decrypt(&key_pair, &ciphertext, b"block 1025").is_err()
+            );
+
+            let mut tampered = ciphertext.clone();
+            tampered.data[0] ^= 1;
+            assert!(HybridCipher::<P, K, D>::decrypt(&key_pair, &tampered, aad).is_err());
+            let mut tampered = ciphertext.clone();
+            tampered.data.pop();
+            assert!(HybridCipher::<P, K, D>::decrypt(&key_pair, &tampered, aad).is_err());
+
+            let mut bytes = Vec::new();
+            ciphertext.serialize_compressed(&mut bytes).unwrap();
+            let deserialized = HybridCiphertext::<P>::deserialize_compressed(&bytes[..]).unwrap();
+            assert_eq!(deserialized, ciphertext);
+            assert_eq!(
+                HybridCipher::<P, K, D>::decrypt(&key_pair, &deserialized, aad).unwrap(),
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn test_hybrid_cipher() {
+        test_hybrid_helper::<ParamEd254, HkdfSha256, XChaCha20Poly1305Dem>();
+        test_hybrid_helper::<ParamEd254, RescueKdf, RescueDem<FqEd254>>();
+        test_hybrid_helper::<ParamEd381, HkdfSha256, RescueDem<FqEd381>>();
+        test_hybrid_helper::<ParamEd381, RescueKdf, XChaCha20Poly1305Dem>();
+    }
+}
@@ -20,8 +20,10 @@ extern crate derivative;
 #[doc(hidden)]
 extern crate alloc;
 
+pub mod exponent;
 #[cfg(feature = "gadgets")]
 pub mod gadgets;
+pub mod hybrid;
 
 use ark_ec::{
     twisted_edwards::{Affine, Projective, TECurveConfig as Config},
@@ -40,7 +42,7 @@ use displaydoc::Display;
 use jf_rescue::{Permutation, RescueParameter, RescueVector, PRP, STATE_SIZE};
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Parameter error: {0}
 #[derive(Display, Debug)]
@@ -100,6 +102,7 @@ pub(crate) struct DecKey<P>
 where
     P: Config,
 {
+    #[derivative(Debug = "ignore")]
     key: P::ScalarField,
 }
 
@@ -109,6 +112,8 @@ impl<P: Config> Drop for DecKey<P> {
     }
 }
 
+impl<P: Config> ZeroizeOnDrop for DecKey<P> {}
+
 // =====================================================
 // key pair
 // =====================================================
@@ -407,7 +412,8 @@ mod test {
     };
     use ark_ed_on_bn254::{EdwardsConfig as ParamEd254, Fq as FqEd254, Fr as FrEd254};
     use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-    use ark_std::{vec, vec::Vec};
+    use ark_std::{format, vec, vec::Vec};
+    use zeroize::ZeroizeOnDrop;
 
     macro_rules! test_enc_and_dec {
         ($param: tt, $base_field:tt, $scalar_field: tt) => {
@@ -447,6 +453,17 @@ mod test {
         test_enc_and_dec!(ParamEd381b, FqEd381b, FrEd381b);
     }
 
+    #[test]
+    fn test_dec_key_hardening() {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<DecKey<ParamEd254>>();
+
+        let mut rng = jf_utils::test_rng();
+        let keypair = KeyPair::<ParamEd254>::generate(&mut rng);
+        let secret = format!("{:?}", keypair.dec.key);
+        assert!(!format!("{:?}", keypair).contains(&secret));
+    }
+
     macro_rules! test_serdes {
         ($param: tt, $base_field:tt, $scalar_field: tt) => {
             let mut rng = jf_utils::test_rng();
@@ -0,0 +1,543 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Merkle membership gadget generic over the arity of the tree and its
+//! digest algorithm.
+//!
+//! Node values and elements are represented by lists of variables, e.g. a
+//! single field element for Rescue trees, or 32 byte variables for SHA3
+//! trees. At each level of the path, the branch of the node is a one-hot
+//! vector of `ARITY` boolean variables, which places the node among its
+//! siblings and binds the path to the index of the element.
+
+use super::{MembershipProof, RescueDigestGadget};
+use crate::{
+    internal::MerkleNode,
+    prelude::{RescueHash, Sha3Digest, Sha3Node},
+    Element, Index, MerkleTreeScheme, NodeValue, ToTraversalPath,
+};
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+use ark_std::{format, string::ToString, vec, vec::Vec};
+use jf_relation::{BoolVar, Circuit, CircuitError, PlonkCircuit, Variable};
+use jf_rescue::RescueParameter;
+
+/// Circuit counterpart of a [`DigestAlgorithm`](crate::DigestAlgorithm), for
+/// Merkle trees of any arity.
+pub trait DigestGadget<F, E, I, T>
+where
+    F: PrimeField,
+    E: Element,
+    I: Index,
+    T: NodeValue,
+{
+    /// Allocate variables for a node value.
+    fn create_node_variable(
+        circuit: &mut PlonkCircuit<F>,
+        node: &T,
+    ) -> Result<Vec<Variable>, CircuitError>;
+
+    /// Allocate variables for an element.
+    fn create_elem_variable(
+        circuit: &mut PlonkCircuit<F>,
+        elem: &E,
+    ) -> Result<Vec<Variable>, CircuitError>;
+
+    /// Digest a list of node values.
+    fn digest(
+        circuit: &mut PlonkCircuit<F>,
+        nodes: &[Vec<Variable>],
+    ) -> Result<Vec<Variable>, CircuitError>;
+
+    /// Digest an element at the position `pos`.
+    fn digest_leaf(
+        circuit: &mut PlonkCircuit<F>,
+        pos: Variable,
+        elem: &[Variable],
+    ) -> Result<Vec<Variable>, CircuitError>;
+}
+
+impl<F, I> DigestGadget<F, F, I, F> for RescueHash<F>
+where
+    F: RescueParameter,
+    I: Index,
+{
+    fn create_node_variable(
+        circuit: &mut PlonkCircuit<F>,
+        node: &F,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        Ok(vec![circuit.create_variable(*node)?])
+    }
+
+    fn create_elem_variable(
+        circuit: &mut PlonkCircuit<F>,
+        elem: &F,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        Ok(vec![circuit.create_variable(*elem)?])
+    }
+
+    fn digest(
+        circuit: &mut PlonkCircuit<F>,
+        nodes: &[Vec<Variable>],
+    ) -> Result<Vec<Variable>, CircuitError> {
+        let data: Vec<_> = nodes.concat();
+        Ok(vec![
+            <RescueDigestGadget as super::DigestAlgorithmGadget<F>>::digest(circuit, &data)?,
+        ])
+    }
+
+    fn digest_leaf(
+        circuit: &mut PlonkCircuit<F>,
+        pos: Variable,
+        elem: &[Variable],
+    ) -> Result<Vec<Variable>, CircuitError> {
+        Ok(vec![
+            <RescueDigestGadget as super::DigestAlgorithmGadget<F>>::digest_leaf(
+                circuit, pos, elem[0],
+            )?,
+        ])
+    }
+}
+
+/// The digests of SHA3 trees need circuits supporting lookup.
+impl<F, E, I> DigestGadget<F, E, I, Sha3Node> for Sha3Digest
+where
+    F: PrimeField,
+    E: Element + CanonicalSerialize,
+    I: Index,
+{
+    fn create_node_variable(
+        circuit: &mut PlonkCircuit<F>,
+        node: &Sha3Node,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        node.0
+            .iter()
+            .map(|&byte| circuit.create_variable(F::from(byte)))
+            .collect()
+    }
+
+    fn create_elem_variable(
+        circuit: &mut PlonkCircuit<F>,
+        elem: &E,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        let mut bytes = Vec::new();
+        elem.serialize_compressed(&mut bytes)
+            .map_err(|e| CircuitError::ParameterError(e.to_string()))?;
+        bytes
+            .iter()
+            .map(|&byte| circuit.create_variable(F::from(byte)))
+            .collect()
+    }
+
+    fn digest(
+        circuit: &mut PlonkCircuit<F>,
+        nodes: &[Vec<Variable>],
+    ) -> Result<Vec<Variable>, CircuitError> {
+        circuit.sha3_256(&nodes.concat())
+    }
+
+    fn digest_leaf(
+        circuit: &mut PlonkCircuit<F>,
+        _pos: Variable,
+        elem: &[Variable],
+    ) -> Result<Vec<Variable>, CircuitError> {
+        circuit.sha3_256(elem)
+    }
+}
+
+/// Circuit variable for a node in the Merkle path of an `ARITY`-ary tree.
+#[derive(Debug, Clone)]
+struct MerkleNodeVar {
+    /// The `ARITY - 1` siblings of the node, in order.
+    siblings: Vec<Vec<Variable>>,
+    /// The one-hot encoding of the branch of the node.
+    branch: Vec<BoolVar>,
+}
+
+/// Circuit variable for a Merkle proof of a tree of any arity.
+/// Contains:
+/// * a list of node variables in the path,
+/// * variables corresponding to the element.
+#[derive(Debug, Clone)]
+pub struct MerkleMembershipProofVar {
+    node_vars: Vec<MerkleNodeVar>,
+    elem_var: Vec<Variable>,
+}
+
+/// Gadget for Merkle trees of any arity `ARITY` with a digest algorithm `H`.
+///
+/// # Examples
+///
+/// ```
+/// use ark_bls12_377::Fq;
+/// use jf_merkle_tree::gadgets::GenericMerkleTreeGadget;
+/// use jf_relation::{Circuit, PlonkCircuit};
+/// use jf_merkle_tree::{prelude::{RescueHash, RescueMerkleTree}, AppendableMerkleTreeScheme, MerkleTreeScheme, MerkleCommitment};
+///
+/// let mut circuit = PlonkCircuit::<Fq>::new_turbo_plonk();
+/// let elements = vec![Fq::from(1_u64), Fq::from(2_u64), Fq::from(100_u64)];
+/// let mt = RescueMerkleTree::<Fq>::from_elems(Some(1), elements).unwrap();
+/// let (_, proof) = mt.lookup(2).expect_ok().unwrap();
+///
+/// let elem_idx = circuit.create_variable(2_u64.into()).unwrap();
+/// let proof_var = GenericMerkleTreeGadget::<RescueMerkleTree<Fq>, RescueHash<Fq>, 3>::create_membership_proof_variable(
+///     &mut circuit,
+///     &proof,
+/// )
+/// .unwrap();
+/// let root_var = GenericMerkleTreeGadget::<RescueMerkleTree<Fq>, RescueHash<Fq>, 3>::create_root_variable(
+///     &mut circuit,
+///     &mt.commitment().digest(),
+/// )
+/// .unwrap();
+/// GenericMerkleTreeGadget::<RescueMerkleTree<Fq>, RescueHash<Fq>, 3>::enforce_membership_proof(
+///     &mut circuit,
+///     elem_idx,
+///     &proof_var,
+///     &root_var,
+/// )
+/// .unwrap();
+/// assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+/// ```
+pub trait GenericMerkleTreeGadget<M, H, const ARITY: usize>
+where
+    M: MerkleTreeScheme,
+{
+    /// Allocate a variable for the membership proof.
+    fn create_membership_proof_variable(
+        &mut self,
+        membership_proof: &M::MembershipProof,
+    ) -> Result<MerkleMembershipProofVar, CircuitError>;
+
+    /// Allocate variables for the merkle root.
+    fn create_root_variable(&mut self, root: &M::NodeValue) -> Result<Vec<Variable>, CircuitError>;
+
+    /// Return a `BoolVar` indicating whether `proof_var` proves the
+    /// membership of its element at the index `elem_idx_var` against the root
+    /// `root_var`.
+    fn is_member(
+        &mut self,
+        elem_idx_var: Variable,
+        proof_var: &MerkleMembershipProofVar,
+        root_var: &[Variable],
+    ) -> Result<BoolVar, CircuitError>;
+
+    /// Enforce correct `proof_var` for the `elem_idx_var` against
+    /// `expected_root_var`.
+    fn enforce_membership_proof(
+        &mut self,
+        elem_idx_var: Variable,
+        proof_var: &MerkleMembershipProofVar,
+        expected_root_var: &[Variable],
+    ) -> Result<(), CircuitError>;
+}
+
+impl<F, M, H, const ARITY: usize> GenericMerkleTreeGadget<M, H, ARITY> for PlonkCircuit<F>
+where
+    F: PrimeField,
+    M: MerkleTreeScheme,
+    M::MembershipProof: MembershipProof<M::Element, M::Index, M::NodeValue>,
+    M::Index: ToTraversalPath<ARITY>,
+    H: DigestGadget<F, M::Element, M::Index, M::NodeValue>,
+{
+    fn create_membership_proof_variable(
+        &mut self,
+        merkle_proof: &M::MembershipProof,
+    ) -> Result<MerkleMembershipProofVar, CircuitError> {
+        if ARITY != M::ARITY || ARITY < 2 {
+            return Err(CircuitError::ParameterError(format!(
+                "the gadget of arity {} doesn't match the tree of arity {}",
+                ARITY,
+                M::ARITY
+            )));
+        }
+        let path = merkle_proof
+            .index()
+            .to_traversal_path(merkle_proof.tree_height() - 1);
+        let elem = merkle_proof.elem().ok_or_else(|| {
+            CircuitError::InternalError("The proof doesn't contain a leaf element".to_string())
+        })?;
+        let elem_var = H::create_elem_variable(self, elem)?;
+
+        let node_vars = path
+            .iter()
+            .zip(merkle_proof.merkle_path().iter().skip(1))
+            .map(|(&branch, node)| {
+                let children = match node {
+                    MerkleNode::Branch { children, .. } if children.len() == ARITY => children,
+                    _ => {
+                        return Err(CircuitError::ParameterError(
+                            "Incompatible proof for this merkle tree".to_string(),
+                        ))
+                    },
+                };
+                let siblings = children
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| i != branch)
+                    .map(|(_, child)| H::create_node_variable(self, &child.value()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let branch = (0..ARITY)
+                    .map(|i| self.create_boolean_variable(i == branch))
+                    .collect::<Result<Vec<_>, _>>()?;
+                // exactly one branch is taken
+                let branch_vars: Vec<_> = branch.iter().map(|&b| b.into()).collect();
+                let num_branches = self.sum(&branch_vars)?;
+                self.enforce_constant(num_branches, F::one())?;
+                Ok(MerkleNodeVar { siblings, branch })
+            })
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+
+        Ok(MerkleMembershipProofVar {
+            node_vars,
+            elem_var,
+        })
+    }
+
+    fn create_root_variable(&mut self, root: &M::NodeValue) -> Result<Vec<Variable>, CircuitError> {
+        H::create_node_variable(self, root)
+    }
+
+    fn is_member(
+        &mut self,
+        elem_idx_var: Variable,
+        proof_var: &MerkleMembershipProofVar,
+        root_var: &[Variable],
+    ) -> Result<BoolVar, CircuitError> {
+        // the index of the element is `sum_l branch_l * ARITY^l`
+        let mut terms = vec![];
+        let mut weight = F::one();
+        for node in proof_var.node_vars.iter() {
+            for (i, &bit) in node.branch.iter().enumerate().skip(1) {
+                terms.push((weight * F::from(i as u64), bit.into()));
+            }
+            weight *= F::from(ARITY as u64);
+        }
+        let idx_var = weighted_sum(self, &terms)?;
+        let idx_matches = self.is_equal(idx_var, elem_idx_var)?;
+
+        let mut cur_label = H::digest_leaf(self, elem_idx_var, &proof_var.elem_var)?;
+        for node in proof_var.node_vars.iter() {
+            let children = order_children(self, &cur_label, node)?;
+            cur_label = H::digest(self, &children)?;
+        }
+        if cur_label.len() != root_var.len() {
+            return Err(CircuitError::ParameterError(
+                "the root and the digest have different lengths".to_string(),
+            ));
+        }
+        let mut checks = vec![idx_matches];
+        for (&computed, &expected) in cur_label.iter().zip(root_var.iter()) {
+            checks.push(self.is_equal(computed, expected)?);
+        }
+        self.logic_and_all(&checks)
+    }
+
+    fn enforce_membership_proof(
+        &mut self,
+        elem_idx_var: Variable,
+        proof_var: &MerkleMembershipProofVar,
+        expected_root_var: &[Variable],
+    ) -> Result<(), CircuitError> {
+        let bool_val = GenericMerkleTreeGadget::<M, H, ARITY>::is_member(
+            self,
+            elem_idx_var,
+            proof_var,
+            expected_root_var,
+        )?;
+        self.enforce_true(bool_val.into())
+    }
+}
+
+/// Place `node` among the siblings of `node_var` according to its branch:
+/// child `i` is the sibling `i` before the branch, `node` at the branch and
+/// the sibling `i - 1` after it.
+fn order_children<F: PrimeField>(
+    circuit: &mut PlonkCircuit<F>,
+    node: &[Variable],
+    node_var: &MerkleNodeVar,
+) -> Result<Vec<Vec<Variable>>, CircuitError> {
+    let arity = node_var.branch.len();
+    let mut children = Vec::with_capacity(arity);
+    // whether the branch is after child `i`, which is boolean since the
+    // branch is one-hot
+    let mut after = node_var.branch[1..]
+        .iter()
+        .map(|&b| b.into())
+        .collect::<Vec<Variable>>();
+    for i in 0..arity {
+        let is_branch = node_var.branch[i];
+        let child = if i == arity - 1 {
+            select_vector(circuit, is_branch, &node_var.siblings[i - 1], node)?
+        } else {
+            let is_after = BoolVar(circuit.sum(&after)?);
+            after.remove(0);
+            let before_or_at = if i == 0 {
+                node.to_vec()
+            } else {
+                select_vector(circuit, is_branch, &node_var.siblings[i - 1], node)?
+            };
+            select_vector(circuit, is_after, &before_or_at, &node_var.siblings[i])?
+        };
+        children.push(child);
+    }
+    Ok(children)
+}
+
+/// Select the vector `x_1` if `b` is true and `x_0` otherwise.
+fn select_vector<F: PrimeField>(
+    circuit: &mut PlonkCircuit<F>,
+    b: BoolVar,
+    x_0: &[Variable],
+    x_1: &[Variable],
+) -> Result<Vec<Variable>, CircuitError> {
+    Ok(circuit.mux(&[b], &[x_0.to_vec(), x_1.to_vec()])?)
+}
+
+/// Return `sum_i c_i * x_i` for the terms `(c_i, x_i)`.
+fn weighted_sum<F: PrimeField>(
+    circuit: &mut PlonkCircuit<F>,
+    terms: &[(F, Variable)],
+) -> Result<Variable, CircuitError> {
+    let zero = circuit.zero();
+    let mut acc = zero;
+    for chunk in terms.chunks(3) {
+        let mut wires = [acc, zero, zero, zero];
+        let mut coeffs = [F::one(), F::zero(), F::zero(), F::zero()];
+        for (j, &(c, x)) in chunk.iter().enumerate() {
+            wires[j + 1] = x;
+            coeffs[j + 1] = c;
+        }
+        acc = circuit.lc(&wires, &coeffs)?;
+    }
+    Ok(acc)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        prelude::{MerkleTree, RescueMerkleTree, SHA3MerkleTree},
+        AppendableMerkleTreeScheme, MerkleCommitment,
+    };
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+
+    type Rescue6AryMerkleTree<F> = MerkleTree<F, RescueHash<F>, u64, 6, F>;
+
+    #[test]
+    fn test_generic_mt_gadget() {
+        test_generic_mt_gadget_helper::<FqEd254>();
+        test_generic_mt_gadget_helper::<FqEd377>();
+        test_generic_mt_gadget_helper::<FqEd381>();
+        test_generic_mt_gadget_helper::<Fq377>();
+    }
+
+    fn check_membership<M, H, F, const ARITY: usize>(
+        mut circuit: PlonkCircuit<F>,
+        mt: &M,
+        uid: u64,
+        claimed_uid: u64,
+    ) -> bool
+    where
+        F: PrimeField,
+        M: MerkleTreeScheme<Index = u64>,
+        PlonkCircuit<F>: GenericMerkleTreeGadget<M, H, ARITY>,
+    {
+        let (_, proof) = mt.lookup(uid).expect_ok().unwrap();
+        let elem_idx_var = circuit.create_variable(claimed_uid.into()).unwrap();
+        let proof_var = GenericMerkleTreeGadget::<M, H, ARITY>::create_membership_proof_variable(
+            &mut circuit,
+            &proof,
+        )
+        .unwrap();
+        let root_var = GenericMerkleTreeGadget::<M, H, ARITY>::create_root_variable(
+            &mut circuit,
+            &mt.commitment().digest(),
+        )
+        .unwrap();
+        GenericMerkleTreeGadget::<M, H, ARITY>::enforce_membership_proof(
+            &mut circuit,
+            elem_idx_var,
+            &proof_var,
+            &root_var,
+        )
+        .unwrap();
+        if circuit.check_circuit_satisfiability(&[]).is_err() {
+            return false;
+        }
+        // a wrong root
+        *circuit.witness_mut(root_var[0]) += F::one();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        true
+    }
+
+    fn test_generic_mt_gadget_helper<F: RescueParameter>() {
+        let elements: Vec<_> = (1u64..=20).map(F::from).collect();
+        let mt = RescueMerkleTree::<F>::from_elems(Some(3), &elements).unwrap();
+        for uid in [0, 7, 19] {
+            assert!(check_membership::<_, RescueHash<F>, F, 3>(
+                PlonkCircuit::new_turbo_plonk(),
+                &mt,
+                uid,
+                uid
+            ));
+        }
+        // the path doesn't lead to the claimed index
+        assert!(!check_membership::<_, RescueHash<F>, F, 3>(
+            PlonkCircuit::new_turbo_plonk(),
+            &mt,
+            7,
+            8
+        ));
+
+        let mt = Rescue6AryMerkleTree::<F>::from_elems(Some(2), &elements).unwrap();
+        for uid in [0, 5, 6, 19] {
+            assert!(check_membership::<_, RescueHash<F>, F, 6>(
+                PlonkCircuit::new_turbo_plonk(),
+                &mt,
+                uid,
+                uid
+            ));
+        }
+        assert!(!check_membership::<_, RescueHash<F>, F, 6>(
+            PlonkCircuit::new_turbo_plonk(),
+            &mt,
+            19,
+            18
+        ));
+    }
+
+    #[test]
+    fn test_sha3_mt_gadget() {
+        let elements: Vec<_> = (1u64..=5).map(FqEd254::from).collect();
+        let mt = SHA3MerkleTree::<FqEd254>::from_elems(Some(2), &elements).unwrap();
+        for uid in [0, 4] {
+            assert!(check_membership::<_, Sha3Digest, FqEd254, 3>(
+                PlonkCircuit::new_ultra_plonk(8),
+                &mt,
+                uid,
+                uid
+            ));
+        }
+        assert!(!check_membership::<_, Sha3Digest, FqEd254, 3>(
+            PlonkCircuit::new_ultra_plonk(8),
+            &mt,
+            4,
+            1
+        ));
+        // the arity of the gadget must match the tree
+        let mut circuit = PlonkCircuit::<FqEd254>::new_ultra_plonk(8);
+        let (_, proof) = mt.lookup(0).expect_ok().unwrap();
+        assert!(GenericMerkleTreeGadget::<SHA3MerkleTree<FqEd254>, Sha3Digest, 2>::create_membership_proof_variable(
+            &mut circuit,
+            &proof,
+        )
+        .is_err());
+    }
+}
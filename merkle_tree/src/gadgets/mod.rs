@@ -10,8 +10,10 @@
 use ark_ff::PrimeField;
 use jf_relation::{BoolVar, Circuit, CircuitError, PlonkCircuit, Variable};
 
+mod generic;
 mod universal_merkle_tree;
 use ark_std::{string::ToString, vec::Vec};
+pub use generic::{DigestGadget, GenericMerkleTreeGadget, MerkleMembershipProofVar};
 
 use crate::{
     internal::{MerkleNode, MerklePath, MerkleProof},
@@ -251,6 +253,15 @@ pub struct Merkle3AryMembershipProofVar {
     node_vars: Vec<Merkle3AryNodeVar>,
     elem_var: Variable,
 }
+
+impl Merkle3AryMembershipProofVar {
+    /// The variable of the element, so that circuits can constrain the value
+    /// whose membership is proven.
+    pub fn elem_var(&self) -> Variable {
+        self.elem_var
+    }
+}
+
 /// Circuit counterpart to DigestAlgorithm
 pub trait DigestAlgorithmGadget<F>
 where
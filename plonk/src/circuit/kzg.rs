@@ -0,0 +1,288 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Circuits for verifying univariate KZG openings with a deferred pairing.
+//!
+//! A proof `π` that the polynomial committed in `C` evaluates to `y` at `z`
+//! is valid iff `e(π, [β]2) = e(C - y * [1]1 + z * π, [1]2)`. The circuit
+//! computes the two G1 points of this pairing check, and leaves the pairing
+//! to the verifier of the circuit: the points are exposed as public inputs
+//! with [`set_pairing_points_public`] and checked natively with
+//! [`decide_pairing_points`].
+
+use crate::{
+    errors::{PlonkError, SnarkError::ParameterError},
+    proof_system::{batch_arg::BatchArgument, structs::OpenKey},
+};
+use ark_ec::{
+    pairing::Pairing,
+    short_weierstrass::{Affine, SWCurveConfig as SWParam},
+    twisted_edwards::{Affine as TEAffine, TECurveConfig as TEParam},
+};
+use ark_ff::PrimeField;
+use ark_std::format;
+use jf_pcs::prelude::{Commitment, UnivariateKzgProof};
+use jf_relation::{
+    gadgets::ecc::{MultiScalarMultiplicationCircuit, PointVariable, SWToTEConParam, TEPoint},
+    Circuit, CircuitError, PlonkCircuit, Variable,
+};
+use jf_utils::field_switching;
+
+/// The number of public inputs set by [`set_pairing_points_public`].
+pub const PAIRING_POINTS_PUB_INPUT_LEN: usize = 4;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// Represent variables of a univariate KZG opening, i.e. a proof that the
+/// polynomial committed in `comm` evaluates to `value` at `point`.
+pub struct KzgOpeningVar {
+    /// The variable for the polynomial commitment.
+    pub comm: PointVariable,
+    /// The variable for the evaluation point, an element of the scalar field
+    /// of the curve.
+    pub point: Variable,
+    /// The variable for the evaluation, an element of the scalar field of the
+    /// curve.
+    pub value: Variable,
+    /// The variable for the opening proof.
+    pub proof: PointVariable,
+}
+
+impl KzgOpeningVar {
+    /// Create variables for a univariate KZG opening.
+    pub fn new<E, F, P>(
+        circuit: &mut PlonkCircuit<F>,
+        comm: &Commitment<E>,
+        point: &E::ScalarField,
+        value: &E::ScalarField,
+        proof: &UnivariateKzgProof<E>,
+    ) -> Result<Self, CircuitError>
+    where
+        E: Pairing<BaseField = F, G1Affine = Affine<P>>,
+        F: PrimeField + SWToTEConParam,
+        P: SWParam<BaseField = F>,
+    {
+        Ok(Self {
+            comm: circuit.create_point_variable(TEPoint::from(comm.0))?,
+            point: circuit.create_variable(field_switching(point))?,
+            value: circuit.create_variable(field_switching(value))?,
+            proof: circuit.create_point_variable(TEPoint::from(proof.proof))?,
+        })
+    }
+
+    /// Circuit for partially verifying the opening without performing the
+    /// pairing. Return the variables for the two group elements `inner1` and
+    /// `inner2` such that the opening is valid iff
+    /// `e(inner1, [beta]2) = e(inner2, [1]2)`.
+    /// As in [`VerifyingKeyVar::partial_verify_circuit`], both elements are
+    /// blinded by `blinding_factor`.
+    ///
+    /// [`VerifyingKeyVar::partial_verify_circuit`]: super::plonk_verifier::VerifyingKeyVar::partial_verify_circuit
+    pub fn partial_verify_circuit<F, P>(
+        &self,
+        circuit: &mut PlonkCircuit<F>,
+        beta_g: &TEPoint<F>,
+        generator_g: &TEPoint<F>,
+        blinding_factor: Variable,
+    ) -> Result<(PointVariable, PointVariable), CircuitError>
+    where
+        F: PrimeField,
+        P: TEParam<BaseField = F>,
+    {
+        // inner1
+        //  = [open_proof]
+        //  + blinding_factor * [1]1
+        let generator_g_var = circuit.create_constant_point_variable(*generator_g)?;
+        let blinding = MultiScalarMultiplicationCircuit::<_, P>::msm(
+            circuit,
+            &[generator_g_var],
+            &[blinding_factor],
+        )?;
+        let inner1 = circuit.ecc_add::<P>(&self.proof, &blinding)?;
+
+        // inner2
+        //  = eval_point * [open_proof]
+        //  + [comm]
+        //  - eval * [1]1
+        //  + blinding_factor * [beta]1
+        let generator_g_inv_var = circuit.create_constant_point_variable(generator_g.inverse())?;
+        let beta_g_var = circuit.create_constant_point_variable(*beta_g)?;
+        let bases = [self.proof, generator_g_inv_var, beta_g_var];
+        let scalars = [self.point, self.value, blinding_factor];
+        let inner2 = MultiScalarMultiplicationCircuit::<_, P>::msm(circuit, &bases, &scalars)?;
+        let inner2 = circuit.ecc_add::<P>(&inner2, &self.comm)?;
+
+        Ok((inner1, inner2))
+    }
+}
+
+/// Set the coordinates of the group elements `inner1` and `inner2` of a
+/// deferred pairing check as the next [`PAIRING_POINTS_PUB_INPUT_LEN`]
+/// public inputs of the circuit, in the order expected by
+/// [`decide_pairing_points`].
+pub fn set_pairing_points_public<F: PrimeField>(
+    circuit: &mut PlonkCircuit<F>,
+    inner1: &PointVariable,
+    inner2: &PointVariable,
+) -> Result<(), CircuitError> {
+    for var in [
+        inner1.get_x(),
+        inner1.get_y(),
+        inner2.get_x(),
+        inner2.get_y(),
+    ] {
+        circuit.set_variable_public(var)?;
+    }
+    Ok(())
+}
+
+/// Perform the deferred pairing check on the group elements whose
+/// coordinates are the public inputs `pub_input`, as set by
+/// [`set_pairing_points_public`].
+/// Return error if `pub_input` is not of length
+/// [`PAIRING_POINTS_PUB_INPUT_LEN`].
+pub fn decide_pairing_points<E, F, P>(
+    open_key: &OpenKey<E>,
+    pub_input: &[F],
+) -> Result<bool, PlonkError>
+where
+    E: Pairing<BaseField = F, G1Affine = Affine<P>>,
+    F: PrimeField + SWToTEConParam,
+    P: SWParam<BaseField = F> + TEParam,
+{
+    if pub_input.len() != PAIRING_POINTS_PUB_INPUT_LEN {
+        return Err(ParameterError(format!(
+            "the number of public inputs {} is different from {}.",
+            pub_input.len(),
+            PAIRING_POINTS_PUB_INPUT_LEN
+        ))
+        .into());
+    }
+    let mut points = [Affine::<P>::identity(); 2];
+    for (point, coords) in points.iter_mut().zip(pub_input.chunks_exact(2)) {
+        let te_point = TEAffine::<P>::new_unchecked(coords[0], coords[1]);
+        if !te_point.is_on_curve() || !te_point.is_in_correct_subgroup_assuming_on_curve() {
+            return Ok(false);
+        }
+        *point = TEPoint::from(te_point).into();
+    }
+    let [inner1, inner2] = points;
+    BatchArgument::decide(open_key, inner1.into(), inner2.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::proof_system::{PlonkKzgSnark, UniversalSNARK};
+    use ark_bls12_377::{g1::Config as Param377, Bls12_377};
+    use ark_poly::{univariate::DensePolynomial, DenseUVPolynomial};
+    use ark_std::{vec, vec::Vec, UniformRand};
+    use jf_pcs::{prelude::UnivariateKzgPCS, PolynomialCommitmentScheme};
+    use jf_relation::gadgets::test_utils::test_variable_independence_for_circuit;
+    use jf_rescue::RescueParameter;
+    use jf_utils::test_rng;
+
+    #[test]
+    fn test_kzg_partial_verification_circuit() -> Result<(), PlonkError> {
+        test_kzg_partial_verification_circuit_helper::<Bls12_377, _, Param377>()
+    }
+
+    fn test_kzg_partial_verification_circuit_helper<E, F, P>() -> Result<(), PlonkError>
+    where
+        E: Pairing<BaseField = F, G1Affine = Affine<P>>,
+        F: RescueParameter + SWToTEConParam,
+        P: SWParam<BaseField = F> + TEParam,
+    {
+        let rng = &mut test_rng();
+        let degree = 16;
+        let srs = PlonkKzgSnark::<E>::universal_setup_for_testing(degree, rng)?;
+        let (ck, vk) = UnivariateKzgPCS::<E>::trim(&srs, degree, None)?;
+        let beta_g: TEPoint<F> = ck.powers_of_g[1].into();
+        let generator_g: TEPoint<F> = vk.g.into();
+
+        let mut circuits = vec![];
+        for _ in 0..2 {
+            let poly = DensePolynomial::<E::ScalarField>::rand(degree, rng);
+            let comm = UnivariateKzgPCS::<E>::commit(&ck, &poly)?;
+            let point = E::ScalarField::rand(rng);
+            let (proof, value) = UnivariateKzgPCS::<E>::open(&ck, &poly, &point)?;
+            let blinding_factor = E::ScalarField::rand(rng);
+
+            for use_lookup in [false, true] {
+                let mut circuit = if use_lookup {
+                    PlonkCircuit::<F>::new_ultra_plonk(16)
+                } else {
+                    PlonkCircuit::<F>::new_turbo_plonk()
+                };
+                let opening_var = KzgOpeningVar::new(&mut circuit, &comm, &point, &value, &proof)?;
+                let blinding_factor_var =
+                    circuit.create_variable(field_switching(&blinding_factor))?;
+                let (inner1, inner2) = opening_var.partial_verify_circuit::<F, P>(
+                    &mut circuit,
+                    &beta_g,
+                    &generator_g,
+                    blinding_factor_var,
+                )?;
+                set_pairing_points_public(&mut circuit, &inner1, &inner2)?;
+
+                let pub_input: Vec<F> = [inner1, inner2]
+                    .iter()
+                    .flat_map(|p| {
+                        let p = circuit.point_witness(p).unwrap();
+                        [p.get_x(), p.get_y()]
+                    })
+                    .collect();
+                assert_eq!(pub_input.len(), PAIRING_POINTS_PUB_INPUT_LEN);
+                assert!(circuit.check_circuit_satisfiability(&pub_input).is_ok());
+                assert!(decide_pairing_points::<E, F, P>(&vk, &pub_input)?);
+
+                // bad path: wrong public inputs
+                let mut wrong_pub_input = pub_input.clone();
+                wrong_pub_input[0] = F::from(1u8);
+                assert!(circuit
+                    .check_circuit_satisfiability(&wrong_pub_input)
+                    .is_err());
+                assert!(!decide_pairing_points::<E, F, P>(&vk, &wrong_pub_input)?);
+                assert!(decide_pairing_points::<E, F, P>(&vk, &pub_input[1..]).is_err());
+
+                // bad path: wrong witness
+                *circuit.witness_mut(opening_var.point) += F::from(1u8);
+                assert!(circuit.check_circuit_satisfiability(&pub_input).is_err());
+                *circuit.witness_mut(opening_var.point) -= F::from(1u8);
+
+                // bad path: a wrong opening is accumulated into points that do
+                // not pass the pairing check
+                let wrong_value = value + E::ScalarField::from(1u8);
+                let mut wrong_circuit = PlonkCircuit::<F>::new_turbo_plonk();
+                let opening_var =
+                    KzgOpeningVar::new(&mut wrong_circuit, &comm, &point, &wrong_value, &proof)?;
+                let blinding_factor_var =
+                    wrong_circuit.create_variable(field_switching(&blinding_factor))?;
+                let (inner1, inner2) = opening_var.partial_verify_circuit::<F, P>(
+                    &mut wrong_circuit,
+                    &beta_g,
+                    &generator_g,
+                    blinding_factor_var,
+                )?;
+                let wrong_pub_input: Vec<F> = [inner1, inner2]
+                    .iter()
+                    .flat_map(|p| {
+                        let p = wrong_circuit.point_witness(p).unwrap();
+                        [p.get_x(), p.get_y()]
+                    })
+                    .collect();
+                assert!(!decide_pairing_points::<E, F, P>(&vk, &wrong_pub_input)?);
+
+                if use_lookup {
+                    circuit.finalize_for_arithmetization()?;
+                    circuits.push(circuit);
+                }
+            }
+        }
+        test_variable_independence_for_circuit(circuits[0].clone(), circuits[1].clone())?;
+
+        Ok(())
+    }
+}
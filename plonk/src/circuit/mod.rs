@@ -5,5 +5,6 @@
 // along with the Jellyfish library. If not, see <https://mit-license.org/>.
 
 /// Circuits implementation
+pub mod kzg;
 pub mod plonk_verifier;
 pub mod transcript;
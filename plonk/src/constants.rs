@@ -12,6 +12,22 @@
 /// initialization
 pub(crate) const EXTRA_TRANSCRIPT_MSG_LABEL: &[u8] = b"extra info";
 
+/// Domain separator for deriving the prover's blinding randomness from a
+/// caller-supplied seed.
+pub(crate) const SEEDED_PROVER_RNG_LABEL: &[u8] = b"jf-plonk seeded prover rng";
+
+/// The tag of the base64 encoding of a
+/// [`NonZkProof`](crate::proof_system::structs::NonZkProof), which tells it
+/// apart from a regular proof.
+pub const NON_ZK_PROOF_TAG: &str = "NONZKPROOF";
+
+/// The prefix of the canonical serialization of a
+/// [`NonZkProof`](crate::proof_system::structs::NonZkProof). A
+/// [`Proof`](crate::proof_system::structs::Proof) starts with the number of
+/// its wire commitments, so reading a non-zk proof as a regular one expects
+/// `u64::MAX` commitments and fails.
+pub const NON_ZK_PROOF_MARKER: u64 = u64::MAX;
+
 /// Compute the ratio between the quotient polynomial domain size and
 /// the vanishing polynomial domain size
 #[inline]
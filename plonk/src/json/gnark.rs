@@ -0,0 +1,321 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Verifying keys and proofs in the JSON encoding of gnark's BN254 Plonk
+//! backend.
+//!
+//! Field elements are decimal strings, G1 points are `{"X": x, "Y": y}` and
+//! G2 points are `{"X": {"A0": x0, "A1": x1}, "Y": {"A0": y0, "A1": y1}}`, with
+//! all-zero coordinates for the point at infinity.
+
+use super::{
+    check_proof_lengths, check_vk_lengths, coords, field_from_dec, field_to_dec, g1_from_coords,
+    g2_from_coords,
+};
+use crate::{
+    errors::{PlonkError, SnarkError::ParameterError},
+    proof_system::structs::{OpenKey, Proof, ProofEvaluations, VerifyingKey},
+};
+use ark_bn254::{Bn254, Fq2, Fr, G1Affine, G2Affine};
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+use ark_std::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use jf_pcs::prelude::Commitment;
+use serde::{Deserialize, Serialize};
+
+/// A G1 point in gnark's encoding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GnarkG1 {
+    /// x coordinate
+    pub x: String,
+    /// y coordinate
+    pub y: String,
+}
+
+/// An element of the quadratic extension `Fq2 = Fq[u] / (u^2 + 1)`, in
+/// gnark's encoding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GnarkE2 {
+    /// The constant coefficient.
+    #[serde(rename = "A0")]
+    pub a0: String,
+    /// The coefficient of `u`.
+    #[serde(rename = "A1")]
+    pub a1: String,
+}
+
+/// A G2 point in gnark's encoding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GnarkG2 {
+    /// x coordinate
+    pub x: GnarkE2,
+    /// y coordinate
+    pub y: GnarkE2,
+}
+
+/// The KZG verifying key, as in gnark's `kzg.VerifyingKey`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GnarkKzgVerifyingKey {
+    /// The G1 generator of the SRS.
+    pub g1: GnarkG1,
+    /// The G2 generator of the SRS, and its multiple by the secret.
+    pub g2: [GnarkG2; 2],
+}
+
+/// A TurboPlonk verifying key in the layout of gnark's `plonk.VerifyingKey`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GnarkVerifyingKey {
+    /// The size of the evaluation domain.
+    pub size: u64,
+    /// The inverse of `size` in the scalar field.
+    pub size_inv: String,
+    /// The generator of the evaluation domain.
+    pub generator: String,
+    /// The number of public inputs.
+    pub nb_public_variables: u64,
+    /// The KZG verifying key.
+    pub kzg: GnarkKzgVerifyingKey,
+    /// The constants separating the wire subsets.
+    pub coset_shifts: Vec<String>,
+    /// The permutation polynomial commitments.
+    pub s: Vec<GnarkG1>,
+    /// The selector polynomial commitments.
+    pub q: Vec<GnarkG1>,
+}
+
+/// An opening proof of several polynomials at the same point.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GnarkBatchOpeningProof {
+    /// The (aggregated) opening proof.
+    pub h: GnarkG1,
+    /// The claimed evaluations.
+    pub claimed_values: Vec<String>,
+}
+
+/// An opening proof of a single polynomial.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GnarkOpeningProof {
+    /// The opening proof.
+    pub h: GnarkG1,
+    /// The claimed evaluation.
+    pub claimed_value: String,
+}
+
+/// A TurboPlonk proof in the layout of gnark's `plonk.Proof`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GnarkProof {
+    /// The wire polynomial commitments.
+    #[serde(rename = "LRO")]
+    pub lro: Vec<GnarkG1>,
+    /// The permutation product polynomial commitment.
+    #[serde(rename = "Z")]
+    pub z: GnarkG1,
+    /// The split quotient polynomial commitments.
+    #[serde(rename = "H")]
+    pub h: Vec<GnarkG1>,
+    /// The opening proof at the challenge point `zeta`, together with the
+    /// evaluations of the wire polynomials and of all but the last
+    /// permutation polynomials.
+    #[serde(rename = "BatchedProof")]
+    pub batched_proof: GnarkBatchOpeningProof,
+    /// The opening proof of the permutation product polynomial at `zeta *
+    /// generator`.
+    #[serde(rename = "ZShiftedOpening")]
+    pub z_shifted_opening: GnarkOpeningProof,
+}
+
+impl TryFrom<&VerifyingKey<Bn254>> for GnarkVerifyingKey {
+    type Error = PlonkError;
+
+    fn try_from(vk: &VerifyingKey<Bn254>) -> Result<Self, Self::Error> {
+        if vk.plookup_vk.is_some() || vk.is_merged {
+            return Err(ParameterError(
+                "only non-merged TurboPlonk verifying keys are supported".to_string(),
+            )
+            .into());
+        }
+        let domain = Radix2EvaluationDomain::<Fr>::new(vk.domain_size)
+            .ok_or(PlonkError::DomainCreationError)?;
+        Ok(Self {
+            size: domain.size,
+            size_inv: field_to_dec(&domain.size_inv),
+            generator: field_to_dec(&domain.group_gen),
+            nb_public_variables: vk.num_inputs as u64,
+            kzg: GnarkKzgVerifyingKey {
+                g1: g1_to_json(&vk.open_key.g),
+                g2: [g2_to_json(&vk.open_key.h), g2_to_json(&vk.open_key.beta_h)],
+            },
+            coset_shifts: vk.k.iter().map(field_to_dec).collect(),
+            s: vk.sigma_comms.iter().map(|c| g1_to_json(&c.0)).collect(),
+            q: vk.selector_comms.iter().map(|c| g1_to_json(&c.0)).collect(),
+        })
+    }
+}
+
+impl TryFrom<GnarkVerifyingKey> for VerifyingKey<Bn254> {
+    type Error = PlonkError;
+
+    fn try_from(vk: GnarkVerifyingKey) -> Result<Self, Self::Error> {
+        check_vk_lengths(vk.coset_shifts.len(), vk.q.len(), vk.s.len())?;
+        let domain_size = usize::try_from(vk.size).map_err(|_| PlonkError::DomainCreationError)?;
+        let domain = Radix2EvaluationDomain::<Fr>::new(domain_size)
+            .filter(|domain| domain.size == vk.size)
+            .ok_or(PlonkError::DomainCreationError)?;
+        if field_from_dec::<Fr>(&vk.generator)? != domain.group_gen
+            || field_from_dec::<Fr>(&vk.size_inv)? != domain.size_inv
+        {
+            return Err(ParameterError(format!(
+                "inconsistent generator or inverse of the size for the domain of size {}",
+                vk.size
+            ))
+            .into());
+        }
+        let [h, beta_h] = &vk.kzg.g2;
+        Ok(Self {
+            domain_size,
+            num_inputs: usize::try_from(vk.nb_public_variables)
+                .map_err(|_| ParameterError("too many public inputs".to_string()))?,
+            sigma_comms: comms_from_json(&vk.s)?,
+            selector_comms: comms_from_json(&vk.q)?,
+            k: vk
+                .coset_shifts
+                .iter()
+                .map(|k| field_from_dec(k))
+                .collect::<Result<_, _>>()?,
+            open_key: OpenKey {
+                g: g1_from_json(&vk.kzg.g1)?,
+                h: g2_from_json(h)?,
+                beta_h: g2_from_json(beta_h)?,
+                powers_of_h: Vec::new(),
+                powers_of_g: Vec::new(),
+            },
+            is_merged: false,
+            plookup_vk: None,
+        })
+    }
+}
+
+impl TryFrom<&Proof<Bn254>> for GnarkProof {
+    type Error = PlonkError;
+
+    fn try_from(proof: &Proof<Bn254>) -> Result<Self, Self::Error> {
+        if proof.plookup_proof.is_some() {
+            return Err(ParameterError("only TurboPlonk proofs are supported".to_string()).into());
+        }
+        let evals = &proof.poly_evals;
+        Ok(Self {
+            lro: proof
+                .wires_poly_comms
+                .iter()
+                .map(|c| g1_to_json(&c.0))
+                .collect(),
+            z: g1_to_json(&proof.prod_perm_poly_comm.0),
+            h: proof
+                .split_quot_poly_comms
+                .iter()
+                .map(|c| g1_to_json(&c.0))
+                .collect(),
+            batched_proof: GnarkBatchOpeningProof {
+                h: g1_to_json(&proof.opening_proof.0),
+                claimed_values: evals
+                    .wires_evals
+                    .iter()
+                    .chain(evals.wire_sigma_evals.iter())
+                    .map(field_to_dec)
+                    .collect(),
+            },
+            z_shifted_opening: GnarkOpeningProof {
+                h: g1_to_json(&proof.shifted_opening_proof.0),
+                claimed_value: field_to_dec(&evals.perm_next_eval),
+            },
+        })
+    }
+}
+
+impl TryFrom<GnarkProof> for Proof<Bn254> {
+    type Error = PlonkError;
+
+    fn try_from(proof: GnarkProof) -> Result<Self, Self::Error> {
+        // the claimed values are the wire evaluations, then the permutation
+        // evaluations, with one evaluation per wire but the last one
+        let num_wires = proof.lro.len();
+        let claimed_values = &proof.batched_proof.claimed_values;
+        check_proof_lengths(
+            num_wires,
+            proof.h.len(),
+            claimed_values.len().min(num_wires),
+            claimed_values.len().saturating_sub(num_wires),
+        )?;
+        let claimed_values = claimed_values
+            .iter()
+            .map(|e| field_from_dec(e))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            wires_poly_comms: comms_from_json(&proof.lro)?,
+            prod_perm_poly_comm: Commitment(g1_from_json(&proof.z)?),
+            split_quot_poly_comms: comms_from_json(&proof.h)?,
+            opening_proof: Commitment(g1_from_json(&proof.batched_proof.h)?),
+            shifted_opening_proof: Commitment(g1_from_json(&proof.z_shifted_opening.h)?),
+            poly_evals: ProofEvaluations {
+                wires_evals: claimed_values[..num_wires].to_vec(),
+                wire_sigma_evals: claimed_values[num_wires..].to_vec(),
+                perm_next_eval: field_from_dec(&proof.z_shifted_opening.claimed_value)?,
+            },
+            plookup_proof: None,
+        })
+    }
+}
+
+fn g1_to_json(point: &G1Affine) -> GnarkG1 {
+    let (x, y) = coords(point);
+    GnarkG1 {
+        x: field_to_dec(&x),
+        y: field_to_dec(&y),
+    }
+}
+
+fn g1_from_json(point: &GnarkG1) -> Result<G1Affine, PlonkError> {
+    g1_from_coords(field_from_dec(&point.x)?, field_from_dec(&point.y)?)
+}
+
+fn e2_to_json(f: &Fq2) -> GnarkE2 {
+    GnarkE2 {
+        a0: field_to_dec(&f.c0),
+        a1: field_to_dec(&f.c1),
+    }
+}
+
+fn e2_from_json(f: &GnarkE2) -> Result<Fq2, PlonkError> {
+    Ok(Fq2::new(field_from_dec(&f.a0)?, field_from_dec(&f.a1)?))
+}
+
+fn g2_to_json(point: &G2Affine) -> GnarkG2 {
+    let (x, y) = coords(point);
+    GnarkG2 {
+        x: e2_to_json(&x),
+        y: e2_to_json(&y),
+    }
+}
+
+fn g2_from_json(point: &GnarkG2) -> Result<G2Affine, PlonkError> {
+    g2_from_coords(e2_from_json(&point.x)?, e2_from_json(&point.y)?)
+}
+
+fn comms_from_json(points: &[GnarkG1]) -> Result<Vec<Commitment<Bn254>>, PlonkError> {
+    points
+        .iter()
+        .map(|p| Ok(Commitment(g1_from_json(p)?)))
+        .collect()
+}
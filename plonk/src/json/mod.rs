@@ -0,0 +1,294 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! JSON encodings of BN254 TurboPlonk verifying keys and proofs, laid out
+//! like the keys and proofs of [snarkjs](https://github.com/iden3/snarkjs)
+//! and [gnark](https://github.com/Consensys/gnark).
+//!
+//! Field elements and group elements are encoded as in these ecosystems, so
+//! that tooling which only parses them, e.g. calldata generators, can handle
+//! the keys and proofs. This is an encoding rather than compatibility:
+//! TurboPlonk in Jellyfish uses 5 wires and 13 selectors, instead of the 3
+//! wires of vanilla Plonk, so the sets of commitments and evaluations are
+//! represented as arrays, and neither snarkjs nor gnark can verify these
+//! proofs. UltraPlonk keys and proofs are not supported.
+//!
+//! Only available with the `json` feature.
+
+use crate::errors::{PlonkError, SnarkError::ParameterError};
+use ark_bn254::{Fq, Fq2, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::{
+    format,
+    string::{String, ToString},
+};
+use jf_relation::constants::{GATE_WIDTH, N_TURBO_PLONK_SELECTORS};
+use num_bigint::BigUint;
+
+pub mod gnark;
+pub mod snarkjs;
+
+/// The number of wire types of TurboPlonk, which is also the number of
+/// permutation polynomials, split quotient polynomials and coset
+/// representatives.
+const NUM_WIRE_TYPES: usize = GATE_WIDTH + 1;
+
+/// Check the number of coset representatives, selector and permutation
+/// polynomial commitments of an imported TurboPlonk verifying key.
+pub(crate) fn check_vk_lengths(
+    num_k: usize,
+    num_selector_comms: usize,
+    num_sigma_comms: usize,
+) -> Result<(), PlonkError> {
+    check_length("coset representatives", num_k, NUM_WIRE_TYPES)?;
+    check_length(
+        "selector commitments",
+        num_selector_comms,
+        N_TURBO_PLONK_SELECTORS,
+    )?;
+    check_length("permutation commitments", num_sigma_comms, NUM_WIRE_TYPES)
+}
+
+/// Check the number of wire and split quotient polynomial commitments, and of
+/// wire and permutation polynomial evaluations of an imported TurboPlonk
+/// proof.
+pub(crate) fn check_proof_lengths(
+    num_wires_comms: usize,
+    num_split_quot_comms: usize,
+    num_wires_evals: usize,
+    num_sigma_evals: usize,
+) -> Result<(), PlonkError> {
+    check_length("wire commitments", num_wires_comms, NUM_WIRE_TYPES)?;
+    check_length(
+        "split quotient commitments",
+        num_split_quot_comms,
+        NUM_WIRE_TYPES,
+    )?;
+    check_length("wire evaluations", num_wires_evals, NUM_WIRE_TYPES)?;
+    // the last permutation polynomial is not evaluated
+    check_length(
+        "permutation evaluations",
+        num_sigma_evals,
+        NUM_WIRE_TYPES - 1,
+    )
+}
+
+fn check_length(what: &str, len: usize, expected: usize) -> Result<(), PlonkError> {
+    if len != expected {
+        return Err(ParameterError(format!("expected {expected} {what}, got {len}")).into());
+    }
+    Ok(())
+}
+
+/// Encode a field element as a decimal string.
+pub(crate) fn field_to_dec<F: PrimeField>(f: &F) -> String {
+    BigUint::from_bytes_le(&f.into_bigint().to_bytes_le()).to_string()
+}
+
+/// Decode a field element from a decimal string, rejecting non-canonical
+/// encodings.
+pub(crate) fn field_from_dec<F: PrimeField>(s: &str) -> Result<F, PlonkError> {
+    let invalid = || PlonkError::from(ParameterError(format!("invalid field element: {s}")));
+    let n = BigUint::parse_bytes(s.as_bytes(), 10).ok_or_else(invalid)?;
+    let bigint = F::BigInt::try_from(n).map_err(|_| invalid())?;
+    F::from_bigint(bigint).ok_or_else(invalid)
+}
+
+/// Build a G1 point from its affine coordinates, where `(0, 0)` stands for
+/// the point at infinity.
+pub(crate) fn g1_from_coords(x: Fq, y: Fq) -> Result<G1Affine, PlonkError> {
+    if x == Fq::from(0u8) && y == Fq::from(0u8) {
+        return Ok(G1Affine::zero());
+    }
+    let point = G1Affine::new_unchecked(x, y);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(ParameterError("invalid G1 point".to_string()).into());
+    }
+    Ok(point)
+}
+
+/// Build a G2 point from its affine coordinates, where `(0, 0)` stands for
+/// the point at infinity.
+pub(crate) fn g2_from_coords(x: Fq2, y: Fq2) -> Result<G2Affine, PlonkError> {
+    if x == Fq2::from(0u8) && y == Fq2::from(0u8) {
+        return Ok(G2Affine::zero());
+    }
+    let point = G2Affine::new_unchecked(x, y);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(ParameterError("invalid G2 point".to_string()).into());
+    }
+    Ok(point)
+}
+
+/// The affine coordinates of a point, `(0, 0)` for the point at infinity.
+pub(crate) fn coords<P: AffineRepr>(point: &P) -> (P::BaseField, P::BaseField)
+where
+    P::BaseField: Default,
+{
+    point.xy().map_or_else(
+        || (P::BaseField::default(), P::BaseField::default()),
+        |(x, y)| (*x, *y),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{gnark::*, snarkjs::*, *};
+    use crate::{
+        proof_system::{
+            snark::test::gen_circuit_for_test,
+            structs::{Proof, VerifyingKey},
+            PlonkKzgSnark, UniversalSNARK,
+        },
+        transcript::SolidityTranscript,
+        PlonkType,
+    };
+    use ark_bn254::{Bn254, Fr};
+    use jf_relation::Circuit;
+    use jf_utils::test_rng;
+
+    #[test]
+    fn test_field_encoding() -> Result<(), PlonkError> {
+        let minus_one =
+            "21888242871839275222246405745257275088548364400416034343698204186575808495616";
+        assert_eq!(field_to_dec(&-Fr::from(1u8)), minus_one);
+        assert_eq!(field_from_dec::<Fr>(minus_one)?, -Fr::from(1u8));
+        assert_eq!(field_from_dec::<Fr>("42")?, Fr::from(42u8));
+        // the modulus and anything that is not a decimal number are rejected
+        assert!(field_from_dec::<Fr>(
+            "21888242871839275222246405745257275088548364400416034343698204186575808495617"
+        )
+        .is_err());
+        assert!(field_from_dec::<Fr>("0x2a").is_err());
+        assert!(field_from_dec::<Fr>("").is_err());
+        assert!(g1_from_coords(Fq::from(1u8), Fq::from(1u8)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_interop() -> Result<(), PlonkError> {
+        let rng = &mut test_rng();
+        let circuit = gen_circuit_for_test(10, 3, PlonkType::TurboPlonk)?;
+        let srs = PlonkKzgSnark::<Bn254>::universal_setup_for_testing(80, rng)?;
+        let (pk, vk) = PlonkKzgSnark::<Bn254>::preprocess(&srs, &circuit)?;
+        let proof =
+            PlonkKzgSnark::<Bn254>::prove::<_, _, SolidityTranscript>(rng, &circuit, &pk, None)?;
+        let public_input = circuit.public_input()?;
+
+        // snarkjs
+        let json = serde_json::to_string(&SnarkjsVerifyingKey::try_from(&vk)?).unwrap();
+        assert!(json.contains("\"curve\":\"bn128\""));
+        let snarkjs_vk: SnarkjsVerifyingKey = serde_json::from_str(&json).unwrap();
+        let imported_vk = VerifyingKey::try_from(snarkjs_vk)?;
+        let json = serde_json::to_string(&SnarkjsProof::try_from(&proof)?).unwrap();
+        let imported_proof = Proof::try_from(serde_json::from_str::<SnarkjsProof>(&json).unwrap())?;
+        assert_eq!(imported_proof, proof);
+        PlonkKzgSnark::<Bn254>::verify::<SolidityTranscript>(
+            &imported_vk,
+            &public_input,
+            &imported_proof,
+            None,
+        )?;
+
+        // gnark
+        let json = serde_json::to_string(&GnarkVerifyingKey::try_from(&vk)?).unwrap();
+        let imported_vk =
+            VerifyingKey::try_from(serde_json::from_str::<GnarkVerifyingKey>(&json).unwrap())?;
+        let json = serde_json::to_string(&GnarkProof::try_from(&proof)?).unwrap();
+        assert!(json.contains("\"ZShiftedOpening\""));
+        let imported_proof = Proof::try_from(serde_json::from_str::<GnarkProof>(&json).unwrap())?;
+        assert_eq!(imported_proof, proof);
+        PlonkKzgSnark::<Bn254>::verify::<SolidityTranscript>(
+            &imported_vk,
+            &public_input,
+            &imported_proof,
+            None,
+        )?;
+
+        // a tampered key is rejected
+        let mut snarkjs_vk = SnarkjsVerifyingKey::try_from(&vk)?;
+        snarkjs_vk.s[0][1] = field_to_dec(&Fq::from(7u8));
+        assert!(VerifyingKey::try_from(snarkjs_vk).is_err());
+
+        // keys and proofs with missing or extra entries are rejected
+        let snarkjs_vk = SnarkjsVerifyingKey::try_from(&vk)?;
+        for tamper in [
+            |vk: &mut SnarkjsVerifyingKey| {
+                vk.k.pop();
+            },
+            |vk: &mut SnarkjsVerifyingKey| {
+                vk.q.pop();
+            },
+            |vk: &mut SnarkjsVerifyingKey| vk.s.push(vk.s[0].clone()),
+        ] {
+            let mut bad_vk = snarkjs_vk.clone();
+            tamper(&mut bad_vk);
+            assert!(VerifyingKey::try_from(bad_vk).is_err());
+        }
+        let snarkjs_proof = SnarkjsProof::try_from(&proof)?;
+        for tamper in [
+            |proof: &mut SnarkjsProof| {
+                proof.w.pop();
+            },
+            |proof: &mut SnarkjsProof| {
+                proof.t.pop();
+            },
+            |proof: &mut SnarkjsProof| {
+                proof.eval_w.pop();
+            },
+            |proof: &mut SnarkjsProof| proof.eval_s.push(proof.eval_s[0].clone()),
+        ] {
+            let mut bad_proof = snarkjs_proof.clone();
+            tamper(&mut bad_proof);
+            assert!(Proof::try_from(bad_proof).is_err());
+        }
+        let gnark_vk = GnarkVerifyingKey::try_from(&vk)?;
+        for tamper in [
+            |vk: &mut GnarkVerifyingKey| {
+                vk.coset_shifts.pop();
+            },
+            |vk: &mut GnarkVerifyingKey| {
+                vk.q.pop();
+            },
+            |vk: &mut GnarkVerifyingKey| {
+                vk.s.pop();
+            },
+        ] {
+            let mut bad_vk = gnark_vk.clone();
+            tamper(&mut bad_vk);
+            assert!(VerifyingKey::try_from(bad_vk).is_err());
+        }
+        let gnark_proof = GnarkProof::try_from(&proof)?;
+        for tamper in [
+            |proof: &mut GnarkProof| {
+                proof.h.pop();
+            },
+            |proof: &mut GnarkProof| {
+                proof.batched_proof.claimed_values.pop();
+            },
+            |proof: &mut GnarkProof| {
+                // one wire and its evaluation less
+                proof.lro.pop();
+                proof.batched_proof.claimed_values.remove(0);
+                proof.batched_proof.claimed_values.pop();
+            },
+        ] {
+            let mut bad_proof = gnark_proof.clone();
+            tamper(&mut bad_proof);
+            assert!(Proof::try_from(bad_proof).is_err());
+        }
+
+        // UltraPlonk is not supported
+        let circuit = gen_circuit_for_test(10, 3, PlonkType::UltraPlonk)?;
+        let (pk, vk) = PlonkKzgSnark::<Bn254>::preprocess(&srs, &circuit)?;
+        let proof =
+            PlonkKzgSnark::<Bn254>::prove::<_, _, SolidityTranscript>(rng, &circuit, &pk, None)?;
+        assert!(SnarkjsVerifyingKey::try_from(&vk).is_err());
+        assert!(GnarkProof::try_from(&proof).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,317 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Verifying keys and proofs in the JSON format of snarkjs.
+//!
+//! Field elements are decimal strings, G1 points are encoded in projective
+//! coordinates as `[x, y, "1"]` and G2 points as `[[x0, x1], [y0, y1], ["1",
+//! "0"]]`, with `["0", "1", "0"]` (resp. `[["0", "0"], ["1", "0"], ["0",
+//! "0"]]`) for the point at infinity.
+
+use super::{
+    check_proof_lengths, check_vk_lengths, coords, field_from_dec, field_to_dec, g1_from_coords,
+    g2_from_coords,
+};
+use crate::{
+    errors::{PlonkError, SnarkError::ParameterError},
+    proof_system::structs::{OpenKey, Proof, ProofEvaluations, VerifyingKey},
+};
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+use ark_std::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use jf_pcs::prelude::Commitment;
+use serde::{Deserialize, Serialize};
+
+/// The value of the `protocol` field, which tells Jellyfish keys and proofs
+/// apart from snarkjs' vanilla Plonk ones.
+pub const SNARKJS_PROTOCOL: &str = "jf_turbo_plonk";
+/// The value of the `curve` field, snarkjs' name for BN254.
+pub const SNARKJS_CURVE: &str = "bn128";
+
+/// A G1 point in snarkjs' encoding.
+pub type SnarkjsG1 = [String; 3];
+/// A G2 point in snarkjs' encoding.
+pub type SnarkjsG2 = [[String; 2]; 3];
+
+/// A TurboPlonk verifying key in the layout of a snarkjs
+/// `verification_key.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnarkjsVerifyingKey {
+    /// Always [`SNARKJS_PROTOCOL`].
+    pub protocol: String,
+    /// Always [`SNARKJS_CURVE`].
+    pub curve: String,
+    /// The number of public inputs.
+    #[serde(rename = "nPublic")]
+    pub n_public: usize,
+    /// The log2 of the domain size.
+    pub power: u32,
+    /// The constants separating the wire subsets.
+    pub k: Vec<String>,
+    /// The selector polynomial commitments.
+    #[serde(rename = "Q")]
+    pub q: Vec<SnarkjsG1>,
+    /// The permutation polynomial commitments.
+    #[serde(rename = "S")]
+    pub s: Vec<SnarkjsG1>,
+    /// The G1 generator of the SRS.
+    #[serde(rename = "G_1")]
+    pub g_1: SnarkjsG1,
+    /// The G2 generator of the SRS.
+    #[serde(rename = "G_2")]
+    pub g_2: SnarkjsG2,
+    /// The G2 generator of the SRS times the secret `x`.
+    #[serde(rename = "X_2")]
+    pub x_2: SnarkjsG2,
+    /// The generator of the evaluation domain.
+    pub w: String,
+}
+
+/// A TurboPlonk proof in the layout of a snarkjs `proof.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnarkjsProof {
+    /// Always [`SNARKJS_PROTOCOL`].
+    pub protocol: String,
+    /// Always [`SNARKJS_CURVE`].
+    pub curve: String,
+    /// The wire polynomial commitments.
+    #[serde(rename = "W")]
+    pub w: Vec<SnarkjsG1>,
+    /// The permutation product polynomial commitment.
+    #[serde(rename = "Z")]
+    pub z: SnarkjsG1,
+    /// The split quotient polynomial commitments.
+    #[serde(rename = "T")]
+    pub t: Vec<SnarkjsG1>,
+    /// The opening proof at the challenge point `xi`.
+    #[serde(rename = "Wxi")]
+    pub wxi: SnarkjsG1,
+    /// The opening proof at `xi * w`.
+    #[serde(rename = "Wxiw")]
+    pub wxiw: SnarkjsG1,
+    /// The wire polynomial evaluations at `xi`.
+    pub eval_w: Vec<String>,
+    /// The permutation polynomial evaluations at `xi`, except the last one.
+    pub eval_s: Vec<String>,
+    /// The permutation product polynomial evaluation at `xi * w`.
+    pub eval_zw: String,
+}
+
+impl TryFrom<&VerifyingKey<Bn254>> for SnarkjsVerifyingKey {
+    type Error = PlonkError;
+
+    fn try_from(vk: &VerifyingKey<Bn254>) -> Result<Self, Self::Error> {
+        if vk.plookup_vk.is_some() || vk.is_merged {
+            return Err(ParameterError(
+                "only non-merged TurboPlonk verifying keys are supported".to_string(),
+            )
+            .into());
+        }
+        let domain = Radix2EvaluationDomain::<Fr>::new(vk.domain_size)
+            .ok_or(PlonkError::DomainCreationError)?;
+        Ok(Self {
+            protocol: SNARKJS_PROTOCOL.to_string(),
+            curve: SNARKJS_CURVE.to_string(),
+            n_public: vk.num_inputs,
+            power: domain.log_size_of_group,
+            k: vk.k.iter().map(field_to_dec).collect(),
+            q: vk.selector_comms.iter().map(|c| g1_to_json(&c.0)).collect(),
+            s: vk.sigma_comms.iter().map(|c| g1_to_json(&c.0)).collect(),
+            g_1: g1_to_json(&vk.open_key.g),
+            g_2: g2_to_json(&vk.open_key.h),
+            x_2: g2_to_json(&vk.open_key.beta_h),
+            w: field_to_dec(&domain.group_gen),
+        })
+    }
+}
+
+impl TryFrom<SnarkjsVerifyingKey> for VerifyingKey<Bn254> {
+    type Error = PlonkError;
+
+    fn try_from(vk: SnarkjsVerifyingKey) -> Result<Self, Self::Error> {
+        check_header(&vk.protocol, &vk.curve)?;
+        check_vk_lengths(vk.k.len(), vk.q.len(), vk.s.len())?;
+        let domain_size = 1usize
+            .checked_shl(vk.power)
+            .ok_or(PlonkError::DomainCreationError)?;
+        let domain = Radix2EvaluationDomain::<Fr>::new(domain_size)
+            .ok_or(PlonkError::DomainCreationError)?;
+        if field_from_dec::<Fr>(&vk.w)? != domain.group_gen {
+            return Err(ParameterError(format!(
+                "w is not the generator of the domain of size 2^{}",
+                vk.power
+            ))
+            .into());
+        }
+        Ok(Self {
+            domain_size,
+            num_inputs: vk.n_public,
+            sigma_comms: comms_from_json(&vk.s)?,
+            selector_comms: comms_from_json(&vk.q)?,
+            k: vk
+                .k
+                .iter()
+                .map(|k| field_from_dec(k))
+                .collect::<Result<_, _>>()?,
+            open_key: OpenKey {
+                g: g1_from_json(&vk.g_1)?,
+                h: g2_from_json(&vk.g_2)?,
+                beta_h: g2_from_json(&vk.x_2)?,
+                powers_of_h: Vec::new(),
+                powers_of_g: Vec::new(),
+            },
+            is_merged: false,
+            plookup_vk: None,
+        })
+    }
+}
+
+impl TryFrom<&Proof<Bn254>> for SnarkjsProof {
+    type Error = PlonkError;
+
+    fn try_from(proof: &Proof<Bn254>) -> Result<Self, Self::Error> {
+        if proof.plookup_proof.is_some() {
+            return Err(ParameterError("only TurboPlonk proofs are supported".to_string()).into());
+        }
+        Ok(Self {
+            protocol: SNARKJS_PROTOCOL.to_string(),
+            curve: SNARKJS_CURVE.to_string(),
+            w: proof
+                .wires_poly_comms
+                .iter()
+                .map(|c| g1_to_json(&c.0))
+                .collect(),
+            z: g1_to_json(&proof.prod_perm_poly_comm.0),
+            t: proof
+                .split_quot_poly_comms
+                .iter()
+                .map(|c| g1_to_json(&c.0))
+                .collect(),
+            wxi: g1_to_json(&proof.opening_proof.0),
+            wxiw: g1_to_json(&proof.shifted_opening_proof.0),
+            eval_w: proof
+                .poly_evals
+                .wires_evals
+                .iter()
+                .map(field_to_dec)
+                .collect(),
+            eval_s: proof
+                .poly_evals
+                .wire_sigma_evals
+                .iter()
+                .map(field_to_dec)
+                .collect(),
+            eval_zw: field_to_dec(&proof.poly_evals.perm_next_eval),
+        })
+    }
+}
+
+impl TryFrom<SnarkjsProof> for Proof<Bn254> {
+    type Error = PlonkError;
+
+    fn try_from(proof: SnarkjsProof) -> Result<Self, Self::Error> {
+        check_header(&proof.protocol, &proof.curve)?;
+        check_proof_lengths(
+            proof.w.len(),
+            proof.t.len(),
+            proof.eval_w.len(),
+            proof.eval_s.len(),
+        )?;
+        Ok(Self {
+            wires_poly_comms: comms_from_json(&proof.w)?,
+            prod_perm_poly_comm: Commitment(g1_from_json(&proof.z)?),
+            split_quot_poly_comms: comms_from_json(&proof.t)?,
+            opening_proof: Commitment(g1_from_json(&proof.wxi)?),
+            shifted_opening_proof: Commitment(g1_from_json(&proof.wxiw)?),
+            poly_evals: ProofEvaluations {
+                wires_evals: proof
+                    .eval_w
+                    .iter()
+                    .map(|e| field_from_dec(e))
+                    .collect::<Result<_, _>>()?,
+                wire_sigma_evals: proof
+                    .eval_s
+                    .iter()
+                    .map(|e| field_from_dec(e))
+                    .collect::<Result<_, _>>()?,
+                perm_next_eval: field_from_dec(&proof.eval_zw)?,
+            },
+            plookup_proof: None,
+        })
+    }
+}
+
+fn check_header(protocol: &str, curve: &str) -> Result<(), PlonkError> {
+    if protocol != SNARKJS_PROTOCOL || curve != SNARKJS_CURVE {
+        return Err(ParameterError(format!(
+            "unsupported protocol {protocol} over curve {curve}"
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+fn g1_to_json(point: &G1Affine) -> SnarkjsG1 {
+    if point.is_zero() {
+        return ["0".to_string(), "1".to_string(), "0".to_string()];
+    }
+    let (x, y) = coords(point);
+    [field_to_dec(&x), field_to_dec(&y), "1".to_string()]
+}
+
+fn g1_from_json(point: &SnarkjsG1) -> Result<G1Affine, PlonkError> {
+    match point[2].as_str() {
+        "0" => Ok(G1Affine::zero()),
+        "1" => g1_from_coords(field_from_dec(&point[0])?, field_from_dec(&point[1])?),
+        _ => Err(ParameterError("G1 point is not in affine form".to_string()).into()),
+    }
+}
+
+fn g2_to_json(point: &G2Affine) -> SnarkjsG2 {
+    let fq2_to_json = |f: &Fq2| [field_to_dec(&f.c0), field_to_dec(&f.c1)];
+    if point.is_zero() {
+        return [
+            fq2_to_json(&Fq2::from(0u8)),
+            fq2_to_json(&Fq2::from(1u8)),
+            fq2_to_json(&Fq2::from(0u8)),
+        ];
+    }
+    let (x, y) = coords(point);
+    [
+        fq2_to_json(&x),
+        fq2_to_json(&y),
+        fq2_to_json(&Fq2::from(1u8)),
+    ]
+}
+
+fn g2_from_json(point: &SnarkjsG2) -> Result<G2Affine, PlonkError> {
+    let fq2_from_json = |f: &[String; 2]| -> Result<Fq2, PlonkError> {
+        Ok(Fq2::new(
+            field_from_dec::<Fq>(&f[0])?,
+            field_from_dec::<Fq>(&f[1])?,
+        ))
+    };
+    let z = fq2_from_json(&point[2])?;
+    if z == Fq2::from(0u8) {
+        Ok(G2Affine::zero())
+    } else if z == Fq2::from(1u8) {
+        g2_from_coords(fq2_from_json(&point[0])?, fq2_from_json(&point[1])?)
+    } else {
+        Err(ParameterError("G2 point is not in affine form".to_string()).into())
+    }
+}
+
+fn comms_from_json(points: &[SnarkjsG1]) -> Result<Vec<Commitment<Bn254>>, PlonkError> {
+    points
+        .iter()
+        .map(|p| Ok(Commitment(g1_from_json(p)?)))
+        .collect()
+}
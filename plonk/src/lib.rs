@@ -18,6 +18,8 @@ extern crate derivative;
 pub mod circuit;
 pub mod constants;
 pub mod errors;
+#[cfg(feature = "json")]
+pub mod json;
 pub mod proof_system;
 pub mod transcript;
 
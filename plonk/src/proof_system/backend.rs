@@ -0,0 +1,150 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Compute backends for the heavy operations of the Plonk prover.
+//!
+//! The prover delegates all of its multi-scalar multiplications (polynomial
+//! commitments) and the (i)FFTs over the quotient domain to a
+//! [`ProverBackend`], so that hardware accelerators can be plugged in without
+//! forking the prover. [`ArkworksBackend`] is the default CPU implementation.
+//!
+//! The interpolations of the witness-dependent polynomials over the
+//! evaluation domain, i.e. of the wire, public input, permutation product and
+//! Plookup polynomials, are not delegated: they are computed by the circuit
+//! through [`Arithmetization`](jf_relation::Arithmetization), on CPU.
+
+use super::structs::CommitKey;
+use crate::errors::PlonkError;
+use ark_ec::pairing::Pairing;
+use ark_poly::{
+    univariate::DensePolynomial, DenseUVPolynomial, EvaluationDomain, GeneralEvaluationDomain,
+};
+use ark_std::{slice, string::ToString, vec::Vec};
+use jf_pcs::{
+    prelude::{Commitment, UnivariateKzgPCS},
+    PolynomialCommitmentScheme,
+};
+use jf_utils::par_utils::parallelizable_slice_iter;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The MSM operations of the Plonk prover, and its FFT operations over the
+/// quotient domain. See the [module documentation](self) for the
+/// interpolations that are not delegated.
+///
+/// A backend is shared by the worker threads of the prover and thus has to
+/// be `Sync`.
+pub trait ProverBackend<E: Pairing>: Sync {
+    /// Commit to each of `polys` with the (non-hiding) KZG commit key `ck`,
+    /// i.e. compute one MSM per polynomial.
+    fn batch_commit(
+        &self,
+        ck: &CommitKey<E>,
+        polys: &[DensePolynomial<E::ScalarField>],
+    ) -> Result<Vec<Commitment<E>>, PlonkError>;
+
+    /// Commit to a single polynomial `poly` with the commit key `ck`.
+    fn commit(
+        &self,
+        ck: &CommitKey<E>,
+        poly: &DensePolynomial<E::ScalarField>,
+    ) -> Result<Commitment<E>, PlonkError> {
+        self.batch_commit(ck, slice::from_ref(poly))?
+            .pop()
+            .ok_or_else(|| {
+                PlonkError::InvalidParameters("backend returned no commitment".to_string())
+            })
+    }
+
+    /// Evaluate each of `polys` over `domain`, which can be a coset of a
+    /// multiplicative subgroup.
+    fn fft(
+        &self,
+        domain: &GeneralEvaluationDomain<E::ScalarField>,
+        polys: &[DensePolynomial<E::ScalarField>],
+    ) -> Result<Vec<Vec<E::ScalarField>>, PlonkError>;
+
+    /// Interpolate the polynomial whose evaluations over `domain` are `evals`.
+    fn ifft(
+        &self,
+        domain: &GeneralEvaluationDomain<E::ScalarField>,
+        evals: &[E::ScalarField],
+    ) -> Result<DensePolynomial<E::ScalarField>, PlonkError>;
+}
+
+/// The default backend, running everything on CPU with arkworks.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArkworksBackend;
+
+impl<E: Pairing> ProverBackend<E> for ArkworksBackend {
+    fn batch_commit(
+        &self,
+        ck: &CommitKey<E>,
+        polys: &[DensePolynomial<E::ScalarField>],
+    ) -> Result<Vec<Commitment<E>>, PlonkError> {
+        Ok(UnivariateKzgPCS::batch_commit(ck, polys)?)
+    }
+
+    fn commit(
+        &self,
+        ck: &CommitKey<E>,
+        poly: &DensePolynomial<E::ScalarField>,
+    ) -> Result<Commitment<E>, PlonkError> {
+        Ok(UnivariateKzgPCS::commit(ck, poly)?)
+    }
+
+    fn fft(
+        &self,
+        domain: &GeneralEvaluationDomain<E::ScalarField>,
+        polys: &[DensePolynomial<E::ScalarField>],
+    ) -> Result<Vec<Vec<E::ScalarField>>, PlonkError> {
+        Ok(parallelizable_slice_iter(polys)
+            .map(|poly| domain.fft(poly.coeffs()))
+            .collect())
+    }
+
+    fn ifft(
+        &self,
+        domain: &GeneralEvaluationDomain<E::ScalarField>,
+        evals: &[E::ScalarField],
+    ) -> Result<DensePolynomial<E::ScalarField>, PlonkError> {
+        Ok(DensePolynomial::from_coefficients_vec(domain.ifft(evals)))
+    }
+}
+
+/// A backend computing commitments with GPU-accelerated MSMs from ICICLE,
+/// and FFTs on CPU.
+#[cfg(feature = "icicle")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IcicleBackend;
+
+#[cfg(feature = "icicle")]
+impl ProverBackend<ark_bn254::Bn254> for IcicleBackend {
+    fn batch_commit(
+        &self,
+        ck: &CommitKey<ark_bn254::Bn254>,
+        polys: &[DensePolynomial<ark_bn254::Fr>],
+    ) -> Result<Vec<Commitment<ark_bn254::Bn254>>, PlonkError> {
+        use jf_pcs::icicle_deps::GPUCommittable;
+        Ok(<UnivariateKzgPCS<ark_bn254::Bn254> as GPUCommittable<_>>::gpu_batch_commit(ck, polys)?)
+    }
+
+    fn fft(
+        &self,
+        domain: &GeneralEvaluationDomain<ark_bn254::Fr>,
+        polys: &[DensePolynomial<ark_bn254::Fr>],
+    ) -> Result<Vec<Vec<ark_bn254::Fr>>, PlonkError> {
+        <ArkworksBackend as ProverBackend<ark_bn254::Bn254>>::fft(&ArkworksBackend, domain, polys)
+    }
+
+    fn ifft(
+        &self,
+        domain: &GeneralEvaluationDomain<ark_bn254::Fr>,
+        evals: &[ark_bn254::Fr],
+    ) -> Result<DensePolynomial<ark_bn254::Fr>, PlonkError> {
+        <ArkworksBackend as ProverBackend<ark_bn254::Bn254>>::ifft(&ArkworksBackend, domain, evals)
+    }
+}
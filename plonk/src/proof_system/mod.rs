@@ -13,8 +13,12 @@ use ark_std::{
     vec::Vec,
 };
 use jf_relation::Arithmetization;
+pub mod backend;
 pub mod batch_arg;
+pub mod observer;
 pub(crate) mod prover;
+pub(crate) mod quot_domain;
+pub mod registry;
 pub(crate) mod snark;
 pub mod structs;
 pub(crate) mod verifier;
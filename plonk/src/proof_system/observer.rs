@@ -0,0 +1,95 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Instrumentation hooks for the Plonk prover.
+//!
+//! A [`ProverObserver`] is notified at the start and end of every prover
+//! round, and for every multi-scalar multiplication (polynomial commitment)
+//! and (i)FFT the prover performs, so that callers can export per-phase
+//! metrics without patching the prover itself.
+
+use core::time::Duration;
+
+/// The rounds of the Plonk prover, in the order they are executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProverRound {
+    /// Round 1: wire polynomials and public input polynomial.
+    WirePolys,
+    /// Round 1.5 (Plookup only): sorted lookup vector polynomials.
+    PlookupSortedPolys,
+    /// Round 2: permutation grand product polynomial.
+    PermutationProduct,
+    /// Round 2.5 (Plookup only): Plookup grand product polynomial.
+    PlookupProduct,
+    /// Round 3: split quotient polynomials.
+    QuotientPolys,
+    /// Round 4 (and 4.5 for Plookup): polynomial evaluations and
+    /// linearization polynomial.
+    Evaluations,
+    /// Round 5: batched opening proofs.
+    OpeningProofs,
+}
+
+/// Callbacks invoked by the prover while computing a proof.
+///
+/// All methods have empty default implementations, so an implementation only
+/// needs to override the events it cares about. Rounds that do not apply to
+/// the circuits being proven (e.g. the Plookup rounds for TurboPlonk circuits)
+/// are not reported.
+pub trait ProverObserver {
+    /// Called right before `round` starts.
+    fn on_round_start(&mut self, _round: ProverRound) {}
+
+    /// Called right after `round` ends. `elapsed` is the wall-clock duration
+    /// of the round, which is only measured when the `std` feature is
+    /// enabled and is `None` otherwise.
+    fn on_round_end(&mut self, _round: ProverRound, _elapsed: Option<Duration>) {}
+
+    /// Called for every multi-scalar multiplication of `size` bases performed
+    /// during `round`.
+    fn on_msm(&mut self, _round: ProverRound, _size: usize) {}
+
+    /// Called for every FFT or inverse FFT over a domain of `size` elements
+    /// performed during `round`. This includes the interpolations of the
+    /// witness-dependent polynomials, which the circuit performs itself
+    /// rather than the [`ProverBackend`](super::backend::ProverBackend).
+    fn on_fft(&mut self, _round: ProverRound, _size: usize) {}
+}
+
+/// An observer that ignores all events.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopObserver;
+
+impl ProverObserver for NoopObserver {}
+
+/// Tracks the duration of a single prover round and reports its start and end
+/// to an observer.
+pub(crate) struct RoundTimer {
+    round: ProverRound,
+    #[cfg(feature = "std")]
+    start: std::time::Instant,
+}
+
+impl RoundTimer {
+    /// Notify `observer` that `round` starts and start the timer.
+    pub(crate) fn start<O: ProverObserver + ?Sized>(observer: &mut O, round: ProverRound) -> Self {
+        observer.on_round_start(round);
+        Self {
+            round,
+            #[cfg(feature = "std")]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    /// Stop the timer and notify `observer` that the round ends.
+    pub(crate) fn end<O: ProverObserver + ?Sized>(self, observer: &mut O) {
+        #[cfg(feature = "std")]
+        let elapsed = Some(self.start.elapsed());
+        #[cfg(not(feature = "std"))]
+        let elapsed = None;
+        observer.on_round_end(self.round, elapsed);
+    }
+}
@@ -6,9 +6,14 @@
 
 use core::ops::Neg;
 
-use super::structs::{
-    eval_merged_lookup_witness, eval_merged_table, Challenges, Oracles, PlookupEvaluations,
-    PlookupOracles, ProofEvaluations, ProvingKey,
+use super::{
+    backend::ProverBackend,
+    observer::{ProverObserver, ProverRound},
+    quot_domain::QuotientDomain,
+    structs::{
+        eval_merged_lookup_witness, eval_merged_table, Challenges, Oracles, PlookupEvaluations,
+        PlookupOracles, ProofEvaluations, ProvingKey,
+    },
 };
 use crate::{
     constants::domain_size_ratio,
@@ -16,10 +21,10 @@ use crate::{
     proof_system::structs::CommitKey,
 };
 use ark_ec::pairing::Pairing;
-use ark_ff::{FftField, Field, One, UniformRand, Zero};
+use ark_ff::{Field, One, UniformRand, Zero};
 use ark_poly::{
-    univariate::DensePolynomial, DenseUVPolynomial, EvaluationDomain, GeneralEvaluationDomain,
-    Polynomial, Radix2EvaluationDomain,
+    univariate::DensePolynomial, DenseUVPolynomial, EvaluationDomain, Polynomial,
+    Radix2EvaluationDomain,
 };
 use ark_std::{
     rand::{CryptoRng, RngCore},
@@ -27,10 +32,7 @@ use ark_std::{
     vec,
     vec::Vec,
 };
-use jf_pcs::{
-    prelude::{Commitment, UnivariateKzgPCS},
-    PolynomialCommitmentScheme,
-};
+use jf_pcs::prelude::Commitment;
 use jf_relation::{constants::GATE_WIDTH, Arithmetization};
 use jf_utils::par_utils::parallelizable_slice_iter;
 #[cfg(feature = "parallel")]
@@ -42,48 +44,84 @@ type CommitmentsAndPolys<E> = (
 );
 
 /// A Plonk IOP prover.
-pub(crate) struct Prover<E: Pairing> {
+pub(crate) struct Prover<'b, E: Pairing, B: ProverBackend<E> + ?Sized> {
     domain: Radix2EvaluationDomain<E::ScalarField>,
-    quot_domain: GeneralEvaluationDomain<E::ScalarField>,
+    quot_domain: QuotientDomain<E>,
+    backend: &'b B,
+    /// whether the witness polynomials are blinded
+    zk: bool,
 }
 
-impl<E: Pairing> Prover<E> {
+impl<'b, E: Pairing, B: ProverBackend<E> + ?Sized> Prover<'b, E, B> {
     /// Construct a Plonk prover that uses a domain with size `domain_size` and
     /// quotient polynomial domain with a size that is larger than the degree of
     /// the quotient polynomial.
     /// * `num_wire_types` - number of wire types in the corresponding
     ///   constraint system.
-    pub(crate) fn new(domain_size: usize, num_wire_types: usize) -> Result<Self, PlonkError> {
+    /// * `zk` - whether to blind the witness polynomials. Without blinding the
+    ///   proofs are NOT zero-knowledge, and the prover never uses its RNG.
+    /// * `backend` - the backend computing the MSMs and FFTs of the prover.
+    pub(crate) fn new(
+        domain_size: usize,
+        num_wire_types: usize,
+        zk: bool,
+        backend: &'b B,
+    ) -> Result<Self, PlonkError> {
         let domain = Radix2EvaluationDomain::<E::ScalarField>::new(domain_size)
             .ok_or(PlonkError::DomainCreationError)?;
-        let quot_domain = GeneralEvaluationDomain::<E::ScalarField>::new(
-            domain_size * domain_size_ratio(domain_size, num_wire_types),
-        )
-        .ok_or(PlonkError::DomainCreationError)?;
+        let quot_domain =
+            QuotientDomain::new(domain_size, domain_size_ratio(domain_size, num_wire_types))?;
         Ok(Self {
             domain,
             quot_domain,
+            backend,
+            zk,
         })
     }
 
+    /// Construct a Plonk prover as [`Self::new()`], but whose quotient
+    /// polynomial domain is always made of cosets of the evaluation domain.
+    #[cfg(test)]
+    pub(crate) fn new_with_multi_coset_quot_domain(
+        domain_size: usize,
+        num_wire_types: usize,
+        backend: &'b B,
+    ) -> Result<Self, PlonkError> {
+        let mut prover = Self::new(domain_size, num_wire_types, true, backend)?;
+        prover.quot_domain = QuotientDomain::new_multi_coset(
+            domain_size,
+            domain_size_ratio(domain_size, num_wire_types),
+        )?;
+        Ok(prover)
+    }
+
     /// Round 1:
     /// 1. Compute and commit wire witness polynomials.
     /// 2. Compute public input polynomial.
     /// Return the wire witness polynomials and their commitments,
     /// also return the public input polynomial.
-    pub(crate) fn run_1st_round<C: Arithmetization<E::ScalarField>, R: CryptoRng + RngCore>(
+    pub(crate) fn run_1st_round<
+        C: Arithmetization<E::ScalarField>,
+        R: CryptoRng + RngCore,
+        O: ProverObserver + ?Sized,
+    >(
         &self,
         prng: &mut R,
         ck: &CommitKey<E>,
         cs: &C,
+        observer: &mut O,
     ) -> Result<(CommitmentsAndPolys<E>, DensePolynomial<E::ScalarField>), PlonkError> {
+        let round = ProverRound::WirePolys;
         let wire_polys: Vec<DensePolynomial<E::ScalarField>> = cs
             .compute_wire_polynomials()?
             .into_iter()
             .map(|poly| self.mask_polynomial(prng, poly, 1))
             .collect();
-        let wires_poly_comms = UnivariateKzgPCS::batch_commit(ck, &wire_polys)?;
+        self.observe_iffts(observer, round, wire_polys.len());
+        Self::observe_msms(observer, round, &wire_polys);
+        let wires_poly_comms = self.backend.batch_commit(ck, &wire_polys)?;
         let pub_input_poly = cs.compute_pub_input_polynomial()?;
+        self.observe_iffts(observer, round, 1);
         Ok(((wires_poly_comms, wire_polys), pub_input_poly))
     }
 
@@ -96,12 +134,14 @@ impl<E: Pairing> Prover<E> {
     pub(crate) fn run_plookup_1st_round<
         C: Arithmetization<E::ScalarField>,
         R: CryptoRng + RngCore,
+        O: ProverObserver + ?Sized,
     >(
         &self,
         prng: &mut R,
         ck: &CommitKey<E>,
         cs: &C,
         tau: E::ScalarField,
+        observer: &mut O,
     ) -> Result<
         (
             CommitmentsAndPolys<E>,
@@ -116,34 +156,47 @@ impl<E: Pairing> Prover<E> {
         let h_1_poly = self.mask_polynomial(prng, h_1_poly, 2);
         let h_2_poly = self.mask_polynomial(prng, h_2_poly, 2);
         let h_polys = vec![h_1_poly, h_2_poly];
-        let h_poly_comms = UnivariateKzgPCS::batch_commit(ck, &h_polys)?;
+        let round = ProverRound::PlookupSortedPolys;
+        self.observe_iffts(observer, round, h_polys.len());
+        Self::observe_msms(observer, round, &h_polys);
+        let h_poly_comms = self.backend.batch_commit(ck, &h_polys)?;
         Ok(((h_poly_comms, h_polys), sorted_vec, merged_lookup_table))
     }
 
     /// Round 2: Compute and commit the permutation grand product polynomial.
     /// Return the grand product polynomial and its commitment.
-    pub(crate) fn run_2nd_round<C: Arithmetization<E::ScalarField>, R: CryptoRng + RngCore>(
+    pub(crate) fn run_2nd_round<
+        C: Arithmetization<E::ScalarField>,
+        R: CryptoRng + RngCore,
+        O: ProverObserver + ?Sized,
+    >(
         &self,
         prng: &mut R,
         ck: &CommitKey<E>,
         cs: &C,
         challenges: &Challenges<E::ScalarField>,
+        observer: &mut O,
     ) -> Result<(Commitment<E>, DensePolynomial<E::ScalarField>), PlonkError> {
         let prod_perm_poly = self.mask_polynomial(
             prng,
             cs.compute_prod_permutation_polynomial(&challenges.beta, &challenges.gamma)?,
             2,
         );
-        let prod_perm_comm = UnivariateKzgPCS::commit(ck, &prod_perm_poly)?;
+        let round = ProverRound::PermutationProduct;
+        self.observe_iffts(observer, round, 1);
+        Self::observe_msms(observer, round, ark_std::slice::from_ref(&prod_perm_poly));
+        let prod_perm_comm = self.backend.commit(ck, &prod_perm_poly)?;
         Ok((prod_perm_comm, prod_perm_poly))
     }
 
     /// Round 2.5 (Plookup): Compute and commit the Plookup grand product
     /// polynomial. Return the grand product polynomial and its commitment.
     /// `cs` is guaranteed to support lookup
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn run_plookup_2nd_round<
         C: Arithmetization<E::ScalarField>,
         R: CryptoRng + RngCore,
+        O: ProverObserver + ?Sized,
     >(
         &self,
         prng: &mut R,
@@ -152,6 +205,7 @@ impl<E: Pairing> Prover<E> {
         challenges: &Challenges<E::ScalarField>,
         merged_lookup_table: Option<&Vec<E::ScalarField>>,
         sorted_vec: Option<&Vec<E::ScalarField>>,
+        observer: &mut O,
     ) -> Result<(Commitment<E>, DensePolynomial<E::ScalarField>), PlonkError> {
         if sorted_vec.is_none() {
             return Err(
@@ -170,14 +224,18 @@ impl<E: Pairing> Prover<E> {
             )?,
             2,
         );
-        let prod_lookup_comm = UnivariateKzgPCS::commit(ck, &prod_lookup_poly)?;
+        let round = ProverRound::PlookupProduct;
+        self.observe_iffts(observer, round, 1);
+        Self::observe_msms(observer, round, ark_std::slice::from_ref(&prod_lookup_poly));
+        let prod_lookup_comm = self.backend.commit(ck, &prod_lookup_poly)?;
         Ok((prod_lookup_comm, prod_lookup_poly))
     }
 
     /// Round 3: Return the split quotient polynomials and their commitments.
     /// Note that the first `num_wire_types`-1 split quotient polynomials
     /// have degree `domain_size`+1.
-    pub(crate) fn run_3rd_round<R: CryptoRng + RngCore>(
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn run_3rd_round<R: CryptoRng + RngCore, O: ProverObserver + ?Sized>(
         &self,
         prng: &mut R,
         ck: &CommitKey<E>,
@@ -185,11 +243,18 @@ impl<E: Pairing> Prover<E> {
         challenges: &Challenges<E::ScalarField>,
         online_oracles: &[Oracles<E::ScalarField>],
         num_wire_types: usize,
+        observer: &mut O,
     ) -> Result<CommitmentsAndPolys<E>, PlonkError> {
-        let quot_poly =
-            self.compute_quotient_polynomial(challenges, pks, online_oracles, num_wire_types)?;
+        let quot_poly = self.compute_quotient_polynomial(
+            challenges,
+            pks,
+            online_oracles,
+            num_wire_types,
+            observer,
+        )?;
         let split_quot_polys = self.split_quotient_polynomial(prng, &quot_poly, num_wire_types)?;
-        let split_quot_poly_comms = UnivariateKzgPCS::batch_commit(ck, &split_quot_polys)?;
+        Self::observe_msms(observer, ProverRound::QuotientPolys, &split_quot_polys);
+        let split_quot_poly_comms = self.backend.batch_commit(ck, &split_quot_polys)?;
 
         Ok((split_quot_poly_comms, split_quot_polys))
     }
@@ -351,7 +416,8 @@ impl<E: Pairing> Prover<E> {
 
     /// Compute (aggregated) polynomial opening proofs at point `zeta` and
     /// `zeta * domain_generator`. TODO: Parallelize the computation.
-    pub(crate) fn compute_opening_proofs(
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn compute_opening_proofs<O: ProverObserver + ?Sized>(
         &self,
         ck: &CommitKey<E>,
         pks: &[&ProvingKey<E>],
@@ -359,6 +425,7 @@ impl<E: Pairing> Prover<E> {
         v: &E::ScalarField,
         online_oracles: &[Oracles<E::ScalarField>],
         lin_poly: &DensePolynomial<E::ScalarField>,
+        observer: &mut O,
     ) -> Result<(Commitment<E>, Commitment<E>), PlonkError> {
         if pks.is_empty() || pks.len() != online_oracles.len() {
             return Err(ParameterError(
@@ -386,7 +453,7 @@ impl<E: Pairing> Prover<E> {
         }
 
         let opening_proof =
-            Self::compute_batched_witness_polynomial_commitment(ck, &polys_ref, v, zeta)?;
+            self.compute_batched_witness_polynomial_commitment(ck, &polys_ref, v, zeta, observer)?;
 
         // List the polynomials to be opened at point `zeta * w`.
         let mut polys_ref = vec![];
@@ -400,11 +467,12 @@ impl<E: Pairing> Prover<E> {
             }
         }
 
-        let shifted_opening_proof = Self::compute_batched_witness_polynomial_commitment(
+        let shifted_opening_proof = self.compute_batched_witness_polynomial_commitment(
             ck,
             &polys_ref,
             v,
             &(self.domain.group_gen * zeta),
+            observer,
         )?;
 
         Ok((opening_proof, shifted_opening_proof))
@@ -412,7 +480,7 @@ impl<E: Pairing> Prover<E> {
 }
 
 /// Private helper methods
-impl<E: Pairing> Prover<E> {
+impl<'b, E: Pairing, B: ProverBackend<E> + ?Sized> Prover<'b, E, B> {
     /// Return the list of plookup polynomials to be opened at point `zeta`
     /// The order should be consistent with the verifier side.
     #[inline]
@@ -450,14 +518,44 @@ impl<E: Pairing> Prover<E> {
         ])
     }
 
+    /// Report an (inverse) FFT over the evaluation domain to `observer` for
+    /// each of the `num` polynomials computed from their evaluations.
+    #[inline]
+    fn observe_iffts<O: ProverObserver + ?Sized>(
+        &self,
+        observer: &mut O,
+        round: ProverRound,
+        num: usize,
+    ) {
+        for _ in 0..num {
+            observer.on_fft(round, self.domain.size());
+        }
+    }
+
+    /// Report to `observer` the MSM computing the commitment of each of
+    /// `polys`.
+    #[inline]
+    fn observe_msms<O: ProverObserver + ?Sized>(
+        observer: &mut O,
+        round: ProverRound,
+        polys: &[DensePolynomial<E::ScalarField>],
+    ) {
+        for poly in polys {
+            observer.on_msm(round, poly.coeffs.len());
+        }
+    }
+
     /// Mask the polynomial so that it remains hidden after revealing
-    /// `hiding_bound` evaluations.
+    /// `hiding_bound` evaluations. No-op if the prover is not zero-knowledge.
     fn mask_polynomial<R: CryptoRng + RngCore>(
         &self,
         prng: &mut R,
         poly: DensePolynomial<E::ScalarField>,
         hiding_bound: usize,
     ) -> DensePolynomial<E::ScalarField> {
+        if !self.zk {
+            return poly;
+        }
         let mask_poly =
             DensePolynomial::rand(hiding_bound, prng).mul_by_vanishing_poly(self.domain);
         mask_poly + poly
@@ -465,11 +563,13 @@ impl<E: Pairing> Prover<E> {
 
     /// Return a batched opening proof given a list of polynomials `polys_ref`,
     /// evaluation point `eval_point`, and randomized combiner `r`.
-    fn compute_batched_witness_polynomial_commitment(
+    fn compute_batched_witness_polynomial_commitment<O: ProverObserver + ?Sized>(
+        &self,
         ck: &CommitKey<E>,
         polys_ref: &[&DensePolynomial<E::ScalarField>],
         r: &E::ScalarField,
         eval_point: &E::ScalarField,
+        observer: &mut O,
     ) -> Result<Commitment<E>, PlonkError> {
         // Compute the aggregated polynomial
         let (batch_poly, _) = polys_ref.iter().fold(
@@ -482,16 +582,22 @@ impl<E: Pairing> Prover<E> {
             DensePolynomial::from_coefficients_vec(vec![-*eval_point, E::ScalarField::one()]);
         let witness_poly = &batch_poly / &divisor;
 
-        UnivariateKzgPCS::commit(ck, &witness_poly).map_err(PlonkError::PCSError)
+        Self::observe_msms(
+            observer,
+            ProverRound::OpeningProofs,
+            ark_std::slice::from_ref(&witness_poly),
+        );
+        self.backend.commit(ck, &witness_poly)
     }
 
     /// Compute the quotient polynomial via (i)FFTs.
-    fn compute_quotient_polynomial(
+    pub(crate) fn compute_quotient_polynomial<O: ProverObserver + ?Sized>(
         &self,
         challenges: &Challenges<E::ScalarField>,
         pks: &[&ProvingKey<E>],
         online_oracles: &[Oracles<E::ScalarField>],
         num_wire_types: usize,
+        observer: &mut O,
     ) -> Result<DensePolynomial<E::ScalarField>, PlonkError> {
         if pks.is_empty() || pks.len() != online_oracles.len() {
             return Err(ParameterError(
@@ -500,51 +606,27 @@ impl<E: Pairing> Prover<E> {
             .into());
         }
 
-        let n = self.domain.size();
         let m = self.quot_domain.size();
-        let domain_size_ratio = m / n;
-        // Compute 1/Z_H(w^i).
-        let z_h_inv: Vec<E::ScalarField> = (0..domain_size_ratio)
-            .map(|i| {
-                ((E::ScalarField::GENERATOR * self.quot_domain.element(i)).pow([n as u64])
-                    - E::ScalarField::one())
-                .inverse()
-                .unwrap()
-            })
-            .collect();
 
         // Compute coset evaluations of the quotient polynomial.
         let mut quot_poly_coset_evals_sum = vec![E::ScalarField::zero(); m];
         let mut alpha_base = E::ScalarField::one();
         let alpha_3 = challenges.alpha.square() * challenges.alpha;
         let alpha_7 = alpha_3.square() * challenges.alpha;
-        // TODO: figure out if the unwrap is safe/map error?
-        let coset = self
-            .quot_domain
-            .get_coset(E::ScalarField::GENERATOR)
-            .unwrap();
         // enumerate proving instances
         for (oracles, pk) in online_oracles.iter().zip(pks.iter()) {
             // lookup_flag = 1 if support Plookup argument.
             let lookup_flag = pk.plookup_pk.is_some();
 
             // Compute coset evaluations.
-            let selectors_coset_fft: Vec<Vec<E::ScalarField>> =
-                parallelizable_slice_iter(&pk.selectors)
-                    .map(|poly| coset.fft(poly.coeffs()))
-                    .collect();
-            let sigmas_coset_fft: Vec<Vec<E::ScalarField>> = parallelizable_slice_iter(&pk.sigmas)
-                .map(|poly| coset.fft(poly.coeffs()))
-                .collect();
-            let wire_polys_coset_fft: Vec<Vec<E::ScalarField>> =
-                parallelizable_slice_iter(&oracles.wire_polys)
-                    .map(|poly| coset.fft(poly.coeffs()))
-                    .collect();
+            let selectors_coset_fft = self.quot_domain.fft(self.backend, &pk.selectors)?;
+            let sigmas_coset_fft = self.quot_domain.fft(self.backend, &pk.sigmas)?;
+            let wire_polys_coset_fft = self.quot_domain.fft(self.backend, &oracles.wire_polys)?;
 
             // TODO: (binyi) we can also compute below in parallel with
             // `wire_polys_coset_fft`.
-            let prod_perm_poly_coset_fft = coset.fft(oracles.prod_perm_poly.coeffs());
-            let pub_input_poly_coset_fft = coset.fft(oracles.pub_inp_poly.coeffs());
+            let prod_perm_poly_coset_fft = self.coset_fft(&oracles.prod_perm_poly)?;
+            let pub_input_poly_coset_fft = self.coset_fft(&oracles.pub_inp_poly)?;
 
             // Compute coset evaluations of Plookup online oracles.
             let (
@@ -556,19 +638,18 @@ impl<E: Pairing> Prover<E> {
                 prod_lookup_poly_coset_fft,
             ) = if lookup_flag {
                 let table_dom_sep_coset_fft =
-                    coset.fft(pk.plookup_pk.as_ref().unwrap().table_dom_sep_poly.coeffs());
+                    self.coset_fft(&pk.plookup_pk.as_ref().unwrap().table_dom_sep_poly)?;
                 let q_dom_sep_coset_fft =
-                    coset.fft(pk.plookup_pk.as_ref().unwrap().q_dom_sep_poly.coeffs());
+                    self.coset_fft(&pk.plookup_pk.as_ref().unwrap().q_dom_sep_poly)?;
                 let range_table_coset_fft =
-                    coset.fft(pk.plookup_pk.as_ref().unwrap().range_table_poly.coeffs()); // safe unwrap
+                    self.coset_fft(&pk.plookup_pk.as_ref().unwrap().range_table_poly)?; // safe unwrap
                 let key_table_coset_fft =
-                    coset.fft(pk.plookup_pk.as_ref().unwrap().key_table_poly.coeffs()); // safe unwrap
-                let h_coset_ffts: Vec<Vec<E::ScalarField>> =
-                    parallelizable_slice_iter(&oracles.plookup_oracles.h_polys)
-                        .map(|poly| coset.fft(poly.coeffs()))
-                        .collect();
+                    self.coset_fft(&pk.plookup_pk.as_ref().unwrap().key_table_poly)?; // safe unwrap
+                let h_coset_ffts = self
+                    .quot_domain
+                    .fft(self.backend, &oracles.plookup_oracles.h_polys)?;
                 let prod_lookup_poly_coset_fft =
-                    coset.fft(oracles.plookup_oracles.prod_lookup_poly.coeffs());
+                    self.coset_fft(&oracles.plookup_oracles.prod_lookup_poly)?;
                 (
                     Some(table_dom_sep_coset_fft),
                     Some(q_dom_sep_coset_fft),
@@ -580,6 +661,17 @@ impl<E: Pairing> Prover<E> {
             } else {
                 (None, None, None, None, None, None)
             };
+            // Count the coset FFTs above: selectors, sigmas, wires, prod_perm and
+            // pub_input, plus the 4 lookup table polynomials, prod_lookup and the
+            // h polynomials if support Plookup.
+            let num_coset_ffts = selectors_coset_fft.len()
+                + sigmas_coset_fft.len()
+                + wire_polys_coset_fft.len()
+                + 2
+                + h_coset_ffts.as_ref().map_or(0, |h| h.len() + 5);
+            for _ in 0..num_coset_ffts * self.quot_domain.num_ffts() {
+                observer.on_fft(ProverRound::QuotientPolys, self.quot_domain.fft_size());
+            }
 
             // Compute coset evaluations of the quotient polynomial.
             let quot_poly_coset_evals: Vec<E::ScalarField> =
@@ -589,7 +681,7 @@ impl<E: Pairing> Prover<E> {
                             .map(|j| wire_polys_coset_fft[j][i])
                             .collect();
                         let w_next: Vec<E::ScalarField> = (0..num_wire_types)
-                            .map(|j| wire_polys_coset_fft[j][(i + domain_size_ratio) % m])
+                            .map(|j| wire_polys_coset_fft[j][self.quot_domain.next(i)])
                             .collect();
 
                         let t_circ = Self::compute_quotient_circuit_contribution(
@@ -601,11 +693,11 @@ impl<E: Pairing> Prover<E> {
                         let (t_perm_1, t_perm_2) =
                             Self::compute_quotient_copy_constraint_contribution(
                                 i,
-                                self.quot_domain.element(i) * E::ScalarField::GENERATOR,
+                                self.quot_domain.element(i),
                                 pk,
                                 &w,
                                 &prod_perm_poly_coset_fft[i],
-                                &prod_perm_poly_coset_fft[(i + domain_size_ratio) % m],
+                                &prod_perm_poly_coset_fft[self.quot_domain.next(i)],
                                 challenges,
                                 &sigmas_coset_fft,
                             );
@@ -617,7 +709,7 @@ impl<E: Pairing> Prover<E> {
                             let (t_lookup_1, t_lookup_2) = self
                                 .compute_quotient_plookup_contribution(
                                     i,
-                                    self.quot_domain.element(i) * E::ScalarField::GENERATOR,
+                                    self.quot_domain.element(i),
                                     pk,
                                     &w,
                                     &w_next,
@@ -635,7 +727,7 @@ impl<E: Pairing> Prover<E> {
                             t1 += t_lookup_1;
                             t2 += t_lookup_2;
                         }
-                        t1 * z_h_inv[i % domain_size_ratio] + t2
+                        t1 * self.quot_domain.vanishing_poly_inv(i) + t2
                     })
                     .collect();
 
@@ -653,9 +745,25 @@ impl<E: Pairing> Prover<E> {
             }
         }
         // Compute the coefficient form of the quotient polynomial
-        Ok(DensePolynomial::from_coefficients_vec(
-            coset.ifft(&quot_poly_coset_evals_sum),
-        ))
+        for _ in 0..self.quot_domain.num_ffts() {
+            observer.on_fft(ProverRound::QuotientPolys, self.quot_domain.fft_size());
+        }
+        self.quot_domain
+            .ifft(self.backend, &quot_poly_coset_evals_sum)
+    }
+
+    /// Evaluate a single polynomial over `coset` with the backend.
+    #[inline]
+    fn coset_fft(
+        &self,
+        poly: &DensePolynomial<E::ScalarField>,
+    ) -> Result<Vec<E::ScalarField>, PlonkError> {
+        self.quot_domain
+            .fft(self.backend, ark_std::slice::from_ref(poly))?
+            .pop()
+            .ok_or_else(|| {
+                PlonkError::InvalidParameters("backend returned no evaluations".to_string())
+            })
     }
 
     // Compute the i-th coset evaluation of the circuit part of the quotient
@@ -781,8 +889,7 @@ impl<E: Pairing> Prover<E> {
         assert_eq!(h_coset_ffts.len(), 2);
 
         let n = pk.domain_size();
-        let m = self.quot_domain.size();
-        let domain_size_ratio = m / n;
+        let i_next = self.quot_domain.next(i);
         let n_field = E::ScalarField::from(n as u64);
         let lagrange_n_coeff =
             self.domain.group_gen_inv / (n_field * (eval_point - self.domain.group_gen_inv));
@@ -792,19 +899,19 @@ impl<E: Pairing> Prover<E> {
 
         // extract polynomial evaluations
         let h_1_x = h_coset_ffts[0][i];
-        let h_1_xw = h_coset_ffts[0][(i + domain_size_ratio) % m];
+        let h_1_xw = h_coset_ffts[0][i_next];
         let h_2_x = h_coset_ffts[1][i];
-        let h_2_xw = h_coset_ffts[1][(i + domain_size_ratio) % m];
+        let h_2_xw = h_coset_ffts[1][i_next];
         let p_x = prod_lookup_coset_fft[i];
-        let p_xw = prod_lookup_coset_fft[(i + domain_size_ratio) % m];
+        let p_xw = prod_lookup_coset_fft[i_next];
         let range_table_x = range_table_coset_fft[i];
         let key_table_x = key_table_coset_fft[i];
         let table_dom_sep_x = table_dom_sep_coset_fft[i];
         let q_dom_sep_x = q_dom_sep_coset_fft[i];
 
-        let range_table_xw = range_table_coset_fft[(i + domain_size_ratio) % m];
-        let key_table_xw = key_table_coset_fft[(i + domain_size_ratio) % m];
-        let table_dom_sep_xw = table_dom_sep_coset_fft[(i + domain_size_ratio) % m];
+        let range_table_xw = range_table_coset_fft[i_next];
+        let key_table_xw = key_table_coset_fft[i_next];
+        let table_dom_sep_xw = table_dom_sep_coset_fft[i_next];
         let merged_table_x = eval_merged_table::<E>(
             challenges.tau,
             range_table_x,
@@ -818,7 +925,7 @@ impl<E: Pairing> Prover<E> {
             challenges.tau,
             range_table_xw,
             key_table_xw,
-            q_lookup_coset_fft[(i + domain_size_ratio) % m],
+            q_lookup_coset_fft[i_next],
             w_next[3],
             w_next[4],
             table_dom_sep_xw,
@@ -895,7 +1002,11 @@ impl<E: Pairing> Prover<E> {
         num_wire_types: usize,
     ) -> Result<Vec<DensePolynomial<E::ScalarField>>, PlonkError> {
         let expected_degree = quotient_polynomial_degree(self.domain.size(), num_wire_types);
-        if quot_poly.degree() != expected_degree {
+        // without blinding the witness polynomials have lower degrees, and so does the
+        // quotient polynomial
+        if quot_poly.degree() != expected_degree
+            && (self.zk || quot_poly.degree() > expected_degree)
+        {
             return Err(WrongQuotientPolyDegree(quot_poly.degree(), expected_degree).into());
         }
         let n = self.domain.size();
@@ -904,18 +1015,23 @@ impl<E: Pairing> Prover<E> {
         let mut split_quot_polys: Vec<DensePolynomial<E::ScalarField>> =
             parallelizable_slice_iter(&(0..num_wire_types).collect::<Vec<_>>())
                 .map(|&i| {
+                    let len = quot_poly.coeffs.len();
                     let end = if i < num_wire_types - 1 {
-                        (i + 1) * (n + 2)
+                        ((i + 1) * (n + 2)).min(len)
                     } else {
-                        quot_poly.degree() + 1
+                        len
                     };
                     // Degree-(n+1) polynomial has n + 2 coefficients.
                     DensePolynomial::<E::ScalarField>::from_coefficients_slice(
-                        &quot_poly.coeffs[i * (n + 2)..end],
+                        &quot_poly.coeffs[(i * (n + 2)).min(end)..end],
                     )
                 })
                 .collect();
 
+        if !self.zk {
+            return Ok(split_quot_polys);
+        }
+
         // mask splitting polynomials t_i(X), for i in {0..num_wire_types}.
         // t_i(X) = t'_i(X) - b_last_i + b_now_i * X^(n+2)
         // with t_lowest_i(X) = t_lowest_i(X) - 0 + b_now_i * X^(n+2)
@@ -1112,6 +1228,7 @@ fn quotient_polynomial_degree(domain_size: usize, num_wire_types: usize) -> usiz
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::proof_system::backend::ArkworksBackend;
     use ark_bls12_377::Bls12_377;
     use ark_bls12_381::Bls12_381;
     use ark_bn254::Bn254;
@@ -1127,7 +1244,7 @@ mod test {
     }
 
     fn test_split_quotient_polynomial_wrong_degree_helper<E: Pairing>() -> Result<(), PlonkError> {
-        let prover = Prover::<E>::new(4, GATE_WIDTH + 1)?;
+        let prover = Prover::<E, _>::new(4, GATE_WIDTH + 1, true, &ArkworksBackend)?;
         let rng = &mut test_rng();
         let bad_quot_poly = DensePolynomial::<E::ScalarField>::rand(25, rng);
         assert!(prover
@@ -0,0 +1,373 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! The evaluation domain of the quotient polynomial.
+//!
+//! The prover computes the quotient polynomial `t(X)` from its evaluations
+//! over a set of `m = k * n` points disjoint from the evaluation domain `H`
+//! of size `n`. By default this set is a coset `g * K` of a multiplicative
+//! subgroup `K` of size `m` (which is radix-2, or mixed-radix if the field
+//! supports it). When the field does not have such a subgroup, i.e. when
+//! `m` exceeds its 2-adicity limit, we fall back to `k` cosets
+//! `c_0 * H, ..., c_{k-1} * H` of `H` itself, so that any circuit whose
+//! domain `H` exists can be proven.
+//!
+//! Over the multi-coset domain, a polynomial `p(X)` is evaluated over
+//! `c_j * H` by reducing it modulo `X^n - c_j^n` and running an FFT of size
+//! `n`. Conversely, the inverse FFT over `c_j * H` gives `t(X) mod (X^n -
+//! c_j^n)`, and the chunks `t_0, ..., t_{k-1}` of `t(X) = sum_i X^{n*i} *
+//! t_i(X)` are recovered by inverting the Vandermonde matrix `(c_j^{n*i})`.
+
+use super::backend::ProverBackend;
+use crate::errors::PlonkError;
+use ark_ec::pairing::Pairing;
+use ark_ff::{FftField, Field, One, Zero};
+use ark_poly::{
+    univariate::DensePolynomial, DenseUVPolynomial, EvaluationDomain, GeneralEvaluationDomain,
+};
+use ark_std::{vec, vec::Vec};
+
+/// The set of points over which the quotient polynomial is evaluated.
+///
+/// The `i`-th evaluation point is [`Self::element(i)`](Self::element), and
+/// [`Self::next(i)`](Self::next) is the index of the point `w * element(i)`
+/// where `w` is the generator of `H`.
+pub(crate) struct QuotientDomain<E: Pairing> {
+    /// size of the evaluation domain `H`
+    n: usize,
+    layout: Layout<E::ScalarField>,
+    /// `1 / Z_H(x)` for the evaluation points `x`, which only takes
+    /// `z_h_inv.len()` distinct values
+    z_h_inv: Vec<E::ScalarField>,
+}
+
+enum Layout<F: FftField> {
+    /// A coset of a subgroup of size `m`, whose `i`-th element is
+    /// `g * v^i` for a generator `v` of the subgroup.
+    Subgroup(GeneralEvaluationDomain<F>),
+    /// `k` cosets of `H`, the `(j * n + l)`-th point is `c_j * w^l`.
+    MultiCoset {
+        cosets: Vec<GeneralEvaluationDomain<F>>,
+        /// The inverse of the Vandermonde matrix `V[j][i] = c_j^{n*i}`.
+        vandermonde_inv: Vec<Vec<F>>,
+    },
+}
+
+impl<E: Pairing> QuotientDomain<E> {
+    /// Build a quotient domain of size at least `n * num_cosets`, where `n` is
+    /// the size of the evaluation domain `H`. It's a coset of a subgroup if
+    /// the field supports it, and `num_cosets` cosets of `H` otherwise.
+    pub(crate) fn new(n: usize, num_cosets: usize) -> Result<Self, PlonkError> {
+        match GeneralEvaluationDomain::<E::ScalarField>::new(n * num_cosets) {
+            Some(subgroup) => {
+                let coset = subgroup
+                    .get_coset(E::ScalarField::GENERATOR)
+                    .ok_or(PlonkError::DomainCreationError)?;
+                let ratio = coset.size() / n;
+                // Z_H(g * v^i) only depends on i mod ratio
+                let z_h_inv = (0..ratio)
+                    .map(|i| coset.element(i).pow([n as u64]) - E::ScalarField::one())
+                    .collect::<Vec<_>>();
+                Ok(Self {
+                    n,
+                    layout: Layout::Subgroup(coset),
+                    z_h_inv: Self::batch_inverse(z_h_inv)?,
+                })
+            },
+            None => Self::new_multi_coset(n, num_cosets),
+        }
+    }
+
+    /// Build a quotient domain made of `num_cosets` cosets of the evaluation
+    /// domain `H` of size `n`.
+    pub(crate) fn new_multi_coset(n: usize, num_cosets: usize) -> Result<Self, PlonkError> {
+        let domain = GeneralEvaluationDomain::<E::ScalarField>::new(n)
+            .filter(|domain| domain.size() == n)
+            .ok_or(PlonkError::DomainCreationError)?;
+        // Use the offsets c_j = g^{j+1} for the multiplicative generator g, so that
+        // the values c_j^n = g^{n(j+1)} are distinct and different from 1 (as
+        // n * num_cosets < p - 1), i.e. the cosets are disjoint from each other and
+        // from H.
+        let mut cosets = Vec::with_capacity(num_cosets);
+        let mut offset = E::ScalarField::one();
+        for _ in 0..num_cosets {
+            offset *= E::ScalarField::GENERATOR;
+            cosets.push(
+                domain
+                    .get_coset(offset)
+                    .ok_or(PlonkError::DomainCreationError)?,
+            );
+        }
+        let offsets_pow_n: Vec<E::ScalarField> = cosets
+            .iter()
+            .map(|coset| coset.coset_offset_pow_size())
+            .collect();
+        let vandermonde = offsets_pow_n
+            .iter()
+            .map(|a| {
+                let mut row = Vec::with_capacity(num_cosets);
+                let mut a_pow = E::ScalarField::one();
+                for _ in 0..num_cosets {
+                    row.push(a_pow);
+                    a_pow *= a;
+                }
+                row
+            })
+            .collect();
+        let z_h_inv = offsets_pow_n
+            .iter()
+            .map(|a| *a - E::ScalarField::one())
+            .collect();
+        Ok(Self {
+            n,
+            layout: Layout::MultiCoset {
+                cosets,
+                vandermonde_inv: invert_matrix(vandermonde)?,
+            },
+            z_h_inv: Self::batch_inverse(z_h_inv)?,
+        })
+    }
+
+    /// The number of evaluation points.
+    pub(crate) fn size(&self) -> usize {
+        match &self.layout {
+            Layout::Subgroup(coset) => coset.size(),
+            Layout::MultiCoset { cosets, .. } => cosets.len() * self.n,
+        }
+    }
+
+    /// The `i`-th evaluation point.
+    pub(crate) fn element(&self, i: usize) -> E::ScalarField {
+        match &self.layout {
+            Layout::Subgroup(coset) => coset.element(i),
+            Layout::MultiCoset { cosets, .. } => cosets[i / self.n].element(i % self.n),
+        }
+    }
+
+    /// The index of the evaluation point `w * element(i)`.
+    #[inline]
+    pub(crate) fn next(&self, i: usize) -> usize {
+        match &self.layout {
+            Layout::Subgroup(coset) => (i + coset.size() / self.n) % coset.size(),
+            Layout::MultiCoset { .. } => i - i % self.n + (i + 1) % self.n,
+        }
+    }
+
+    /// The inverse of the vanishing polynomial of `H` at `element(i)`.
+    #[inline]
+    pub(crate) fn vanishing_poly_inv(&self, i: usize) -> E::ScalarField {
+        match &self.layout {
+            Layout::Subgroup(_) => self.z_h_inv[i % self.z_h_inv.len()],
+            Layout::MultiCoset { .. } => self.z_h_inv[i / self.n],
+        }
+    }
+
+    /// The number of FFTs, each of size [`Self::fft_size()`], needed to
+    /// evaluate a polynomial over the domain.
+    pub(crate) fn num_ffts(&self) -> usize {
+        match &self.layout {
+            Layout::Subgroup(_) => 1,
+            Layout::MultiCoset { cosets, .. } => cosets.len(),
+        }
+    }
+
+    /// The size of each FFT.
+    pub(crate) fn fft_size(&self) -> usize {
+        match &self.layout {
+            Layout::Subgroup(coset) => coset.size(),
+            Layout::MultiCoset { .. } => self.n,
+        }
+    }
+
+    /// Evaluate each of `polys` over the domain.
+    pub(crate) fn fft<B: ProverBackend<E> + ?Sized>(
+        &self,
+        backend: &B,
+        polys: &[DensePolynomial<E::ScalarField>],
+    ) -> Result<Vec<Vec<E::ScalarField>>, PlonkError> {
+        match &self.layout {
+            Layout::Subgroup(coset) => backend.fft(coset, polys),
+            Layout::MultiCoset { cosets, .. } => {
+                let mut evals = vec![Vec::with_capacity(self.size()); polys.len()];
+                for coset in cosets.iter() {
+                    let reduced: Vec<_> = polys
+                        .iter()
+                        .map(|poly| reduce(poly, self.n, coset.coset_offset_pow_size()))
+                        .collect();
+                    for (evals, coset_evals) in evals.iter_mut().zip(backend.fft(coset, &reduced)?)
+                    {
+                        evals.extend(coset_evals);
+                    }
+                }
+                Ok(evals)
+            },
+        }
+    }
+
+    /// Interpolate the polynomial of degree less than [`Self::size()`] whose
+    /// evaluations over the domain are `evals`.
+    pub(crate) fn ifft<B: ProverBackend<E> + ?Sized>(
+        &self,
+        backend: &B,
+        evals: &[E::ScalarField],
+    ) -> Result<DensePolynomial<E::ScalarField>, PlonkError> {
+        match &self.layout {
+            Layout::Subgroup(coset) => backend.ifft(coset, evals),
+            Layout::MultiCoset {
+                cosets,
+                vandermonde_inv,
+            } => {
+                if evals.len() != self.size() {
+                    return Err(PlonkError::InvalidParameters(ark_std::format!(
+                        "expected {} evaluations, got {}",
+                        self.size(),
+                        evals.len()
+                    )));
+                }
+                // remainders[j] = t(X) mod (X^n - c_j^n)
+                let remainders = cosets
+                    .iter()
+                    .zip(evals.chunks(self.n))
+                    .map(|(coset, coset_evals)| backend.ifft(coset, coset_evals))
+                    .collect::<Result<Vec<_>, _>>()?;
+                // t_i = sum_j V^{-1}[i][j] * remainders[j]
+                let mut coeffs = vec![E::ScalarField::zero(); self.size()];
+                for (chunk, row) in coeffs.chunks_mut(self.n).zip(vandermonde_inv.iter()) {
+                    for (remainder, v) in remainders.iter().zip(row.iter()) {
+                        for (c, r) in chunk.iter_mut().zip(remainder.coeffs()) {
+                            *c += *v * r;
+                        }
+                    }
+                }
+                Ok(DensePolynomial::from_coefficients_vec(coeffs))
+            },
+        }
+    }
+
+    fn batch_inverse(mut values: Vec<E::ScalarField>) -> Result<Vec<E::ScalarField>, PlonkError> {
+        if values.iter().any(|v| v.is_zero()) {
+            return Err(PlonkError::DomainCreationError);
+        }
+        ark_ff::batch_inversion(&mut values);
+        Ok(values)
+    }
+}
+
+/// Reduce `poly` modulo `X^n - a`.
+fn reduce<F: Field>(poly: &DensePolynomial<F>, n: usize, a: F) -> DensePolynomial<F> {
+    if poly.coeffs.len() <= n {
+        return poly.clone();
+    }
+    let mut coeffs = vec![F::zero(); n];
+    let mut a_pow = F::one();
+    for chunk in poly.coeffs.chunks(n) {
+        for (c, p) in coeffs.iter_mut().zip(chunk) {
+            *c += a_pow * p;
+        }
+        a_pow *= a;
+    }
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+/// Invert a square matrix with Gauss-Jordan elimination.
+fn invert_matrix<F: Field>(mut matrix: Vec<Vec<F>>) -> Result<Vec<Vec<F>>, PlonkError> {
+    let size = matrix.len();
+    let mut inverse: Vec<Vec<F>> = (0..size)
+        .map(|i| {
+            let mut row = vec![F::zero(); size];
+            row[i] = F::one();
+            row
+        })
+        .collect();
+    for col in 0..size {
+        let pivot = (col..size)
+            .find(|&row| !matrix[row][col].is_zero())
+            .ok_or(PlonkError::DomainCreationError)?;
+        matrix.swap(col, pivot);
+        inverse.swap(col, pivot);
+        let pivot_inv = matrix[col][col]
+            .inverse()
+            .ok_or(PlonkError::DomainCreationError)?;
+        for k in 0..size {
+            matrix[col][k] *= pivot_inv;
+            inverse[col][k] *= pivot_inv;
+        }
+        for row in 0..size {
+            if row != col && !matrix[row][col].is_zero() {
+                let factor = matrix[row][col];
+                for k in 0..size {
+                    let (m, inv) = (matrix[col][k], inverse[col][k]);
+                    matrix[row][k] -= factor * m;
+                    inverse[row][k] -= factor * inv;
+                }
+            }
+        }
+    }
+    Ok(inverse)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::proof_system::backend::ArkworksBackend;
+    use ark_bls12_377::Bls12_377;
+    use ark_bn254::Bn254;
+    use ark_poly::Polynomial;
+
+    #[test]
+    fn test_multi_coset_domain() -> Result<(), PlonkError> {
+        test_multi_coset_domain_helper::<Bn254>()?;
+        test_multi_coset_domain_helper::<Bls12_377>()
+    }
+
+    fn test_multi_coset_domain_helper<E: Pairing>() -> Result<(), PlonkError> {
+        let rng = &mut jf_utils::test_rng();
+        let n = 16;
+        let w = GeneralEvaluationDomain::<E::ScalarField>::new(n)
+            .unwrap()
+            .group_gen();
+        for num_cosets in [1, 3, 6] {
+            let domain = QuotientDomain::<E>::new_multi_coset(n, num_cosets)?;
+            let m = domain.size();
+            assert_eq!(m, n * num_cosets);
+            assert_eq!(domain.num_ffts() * domain.fft_size(), m);
+
+            // points are distinct, not in H, and `next` multiplies by w
+            let points: Vec<_> = (0..m).map(|i| domain.element(i)).collect();
+            for i in 0..m {
+                assert!(!points[..i].contains(&points[i]));
+                let z_h = points[i].pow([n as u64]) - E::ScalarField::one();
+                assert_eq!(domain.vanishing_poly_inv(i) * z_h, E::ScalarField::one());
+                assert_eq!(points[domain.next(i)], points[i] * w);
+            }
+
+            // FFT of polynomials longer than n, and interpolation back
+            let polys: Vec<_> = [1, n, m - 1, m]
+                .iter()
+                .map(|&degree| DensePolynomial::<E::ScalarField>::rand(degree - 1, rng))
+                .collect();
+            let evals = domain.fft(&ArkworksBackend, &polys)?;
+            for (poly, evals) in polys.iter().zip(evals.iter()) {
+                let expected: Vec<_> = points.iter().map(|x| poly.evaluate(x)).collect();
+                assert_eq!(evals, &expected);
+                assert_eq!(&domain.ifft(&ArkworksBackend, evals)?, poly);
+            }
+        }
+
+        // the default domain uses a subgroup when available
+        let domain = QuotientDomain::<E>::new(n, 6)?;
+        assert_eq!(domain.num_ffts(), 1);
+        assert_eq!(domain.size(), 128);
+        let poly = DensePolynomial::<E::ScalarField>::rand(100, rng);
+        let evals = domain.fft(&ArkworksBackend, ark_std::slice::from_ref(&poly))?;
+        for (i, eval) in evals[0].iter().enumerate() {
+            assert_eq!(*eval, poly.evaluate(&domain.element(i)));
+            assert_eq!(domain.element(domain.next(i)), domain.element(i) * w);
+        }
+        assert_eq!(domain.ifft(&ArkworksBackend, &evals[0])?, poly);
+        Ok(())
+    }
+}
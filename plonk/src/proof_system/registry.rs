@@ -0,0 +1,185 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! A registry of verifying keys indexed by their hash.
+
+use super::{
+    structs::{Proof, VerifyingKey, VerifyingKeyHash},
+    PlonkKzgSnark, UniversalSNARK,
+};
+use crate::{
+    errors::{PlonkError, SnarkError::ParameterError},
+    transcript::PlonkTranscript,
+};
+use ark_ec::{
+    pairing::Pairing,
+    short_weierstrass::{Affine, SWCurveConfig},
+};
+use ark_std::{format, vec::Vec};
+use hashbrown::HashMap;
+use jf_relation::gadgets::ecc::SWToTEConParam;
+use jf_rescue::RescueParameter;
+
+/// A set of verifying keys indexed by their [`VerifyingKey::hash()`], so
+/// that systems verifying proofs for many circuits can look up the key of a
+/// proof from a short, collision-resistant identifier instead of trusting
+/// the prover to provide the key.
+#[derive(Debug, Clone, Derivative)]
+#[derivative(Default(bound = ""))]
+pub struct VkRegistry<E: Pairing> {
+    keys: HashMap<VerifyingKeyHash, VerifyingKey<E>>,
+}
+
+impl<E: Pairing> VkRegistry<E> {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `vk` to the registry and return its hash. Registering the same key
+    /// twice is a no-op.
+    pub fn register(&mut self, vk: VerifyingKey<E>) -> Result<VerifyingKeyHash, PlonkError> {
+        let hash = vk.hash()?;
+        self.keys.entry(hash).or_insert(vk);
+        Ok(hash)
+    }
+
+    /// The key with hash `hash`, if registered.
+    pub fn get(&self, hash: &VerifyingKeyHash) -> Option<&VerifyingKey<E>> {
+        self.keys.get(hash)
+    }
+
+    /// Whether a key with hash `hash` is registered.
+    pub fn contains(&self, hash: &VerifyingKeyHash) -> bool {
+        self.keys.contains_key(hash)
+    }
+
+    /// Remove and return the key with hash `hash`, if registered.
+    pub fn remove(&mut self, hash: &VerifyingKeyHash) -> Option<VerifyingKey<E>> {
+        self.keys.remove(hash)
+    }
+
+    /// The hashes of all registered keys, in arbitrary order.
+    pub fn hashes(&self) -> impl Iterator<Item = &VerifyingKeyHash> {
+        self.keys.keys()
+    }
+
+    /// The number of registered keys.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Whether the registry is empty.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl<E, F, P> VkRegistry<E>
+where
+    E: Pairing<BaseField = F, G1Affine = Affine<P>>,
+    F: RescueParameter + SWToTEConParam,
+    P: SWCurveConfig<BaseField = F>,
+{
+    /// Verify `proof` against the registered key with hash `vk_hash`. Return
+    /// an error if no such key is registered.
+    pub fn verify<T>(
+        &self,
+        vk_hash: &VerifyingKeyHash,
+        public_input: &[E::ScalarField],
+        proof: &Proof<E>,
+        extra_transcript_init_msg: Option<Vec<u8>>,
+    ) -> Result<(), PlonkError>
+    where
+        T: PlonkTranscript<F>,
+    {
+        let vk = self
+            .get(vk_hash)
+            .ok_or_else(|| ParameterError(format!("unknown verifying key {vk_hash}")))?;
+        PlonkKzgSnark::<E>::verify::<T>(vk, public_input, proof, extra_transcript_init_msg)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        proof_system::snark::test::gen_circuit_for_test,
+        transcript::{RescueTranscript, StandardTranscript},
+        PlonkType,
+    };
+    use ark_bls12_377::{Bls12_377, Fq as Fq377};
+    use ark_bn254::{Bn254, Fq as Fq254};
+    use ark_std::string::ToString;
+    use jf_relation::Circuit;
+    use jf_utils::test_rng;
+
+    #[test]
+    fn test_vk_registry() -> Result<(), PlonkError> {
+        test_vk_registry_helper::<Bn254, Fq254, _, StandardTranscript>()?;
+        test_vk_registry_helper::<Bls12_377, Fq377, _, RescueTranscript<_>>()
+    }
+
+    fn test_vk_registry_helper<E, F, P, T>() -> Result<(), PlonkError>
+    where
+        E: Pairing<BaseField = F, G1Affine = Affine<P>>,
+        F: RescueParameter + SWToTEConParam,
+        P: SWCurveConfig<BaseField = F>,
+        T: PlonkTranscript<F>,
+    {
+        let rng = &mut test_rng();
+        let srs = PlonkKzgSnark::<E>::universal_setup_for_testing(80, rng)?;
+        let turbo_circuit = gen_circuit_for_test(3, 4, PlonkType::TurboPlonk)?;
+        let ultra_circuit = gen_circuit_for_test(3, 4, PlonkType::UltraPlonk)?;
+        let (turbo_pk, turbo_vk) = PlonkKzgSnark::<E>::preprocess(&srs, &turbo_circuit)?;
+        let (ultra_pk, ultra_vk) = PlonkKzgSnark::<E>::preprocess(&srs, &ultra_circuit)?;
+
+        // hashes are deterministic, independent of the SRS trimming, and tell
+        // keys apart
+        assert_eq!(turbo_vk.hash()?, turbo_vk.clone().hash()?);
+        assert_eq!(turbo_vk.hash_rescue()?, turbo_vk.clone().hash_rescue()?);
+        let mut trimmed_vk = turbo_vk.clone();
+        trimmed_vk.open_key.powers_of_g.clear();
+        trimmed_vk.open_key.powers_of_h.clear();
+        assert_eq!(trimmed_vk.hash()?, turbo_vk.hash()?);
+        assert_ne!(turbo_vk.hash()?, ultra_vk.hash()?);
+        assert_ne!(turbo_vk.hash_rescue()?, ultra_vk.hash_rescue()?);
+        let mut other_vk = turbo_vk.clone();
+        other_vk.num_inputs += 1;
+        assert_ne!(other_vk.hash()?, turbo_vk.hash()?);
+        assert_ne!(other_vk.hash_rescue()?, turbo_vk.hash_rescue()?);
+
+        let mut registry = VkRegistry::<E>::new();
+        assert!(registry.is_empty());
+        let turbo_hash = registry.register(turbo_vk.clone())?;
+        assert_eq!(registry.register(turbo_vk.clone())?, turbo_hash);
+        let ultra_hash = registry.register(ultra_vk.clone())?;
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.get(&turbo_hash), Some(&turbo_vk));
+        assert!(registry.contains(&ultra_hash));
+        assert_eq!(turbo_hash.to_string().len(), 64);
+
+        // dispatch verification by key hash
+        let turbo_proof =
+            PlonkKzgSnark::<E>::prove::<_, _, T>(rng, &turbo_circuit, &turbo_pk, None)?;
+        let ultra_proof =
+            PlonkKzgSnark::<E>::prove::<_, _, T>(rng, &ultra_circuit, &ultra_pk, None)?;
+        let turbo_input = turbo_circuit.public_input()?;
+        let ultra_input = ultra_circuit.public_input()?;
+        registry.verify::<T>(&turbo_hash, &turbo_input, &turbo_proof, None)?;
+        registry.verify::<T>(&ultra_hash, &ultra_input, &ultra_proof, None)?;
+        assert!(registry
+            .verify::<T>(&turbo_hash, &ultra_input, &ultra_proof, None)
+            .is_err());
+
+        assert_eq!(registry.remove(&ultra_hash), Some(ultra_vk));
+        assert!(registry
+            .verify::<T>(&ultra_hash, &ultra_input, &ultra_proof, None)
+            .is_err());
+        assert_eq!(registry.hashes().collect::<Vec<_>>(), [&turbo_hash]);
+        Ok(())
+    }
+}
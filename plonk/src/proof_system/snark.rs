@@ -6,16 +6,18 @@
 
 //! Instantiations of Plonk-based proof systems
 use super::{
+    backend::{ArkworksBackend, ProverBackend},
+    observer::{NoopObserver, ProverObserver, ProverRound, RoundTimer},
     prover::Prover,
     structs::{
-        BatchProof, Challenges, Oracles, PlookupProof, PlookupProvingKey, PlookupVerifyingKey,
-        Proof, ProvingKey, VerifyingKey,
+        BatchProof, Challenges, NonZkProof, Oracles, PlookupProof, PlookupProvingKey,
+        PlookupVerifyingKey, Proof, ProvingKey, VerifyingKey,
     },
     verifier::Verifier,
     UniversalSNARK,
 };
 use crate::{
-    constants::EXTRA_TRANSCRIPT_MSG_LABEL,
+    constants::{EXTRA_TRANSCRIPT_MSG_LABEL, SEEDED_PROVER_RNG_LABEL},
     errors::{PlonkError, SnarkError::ParameterError},
     proof_system::structs::UniversalSrs,
     transcript::*,
@@ -25,6 +27,7 @@ use ark_ec::{
     short_weierstrass::{Affine, SWCurveConfig},
 };
 use ark_ff::{Field, One};
+use ark_serialize::CanonicalSerialize;
 use ark_std::{
     format,
     marker::PhantomData,
@@ -39,8 +42,10 @@ use jf_relation::{
 };
 use jf_rescue::RescueParameter;
 use jf_utils::par_utils::parallelizable_slice_iter;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
+use sha3::{Digest, Keccak256};
 
 /// A Plonk instantiated with KZG PCS
 pub struct PlonkKzgSnark<E: Pairing>(PhantomData<E>);
@@ -68,11 +73,301 @@ where
         R: CryptoRng + RngCore,
         T: PlonkTranscript<F>,
     {
-        let (batch_proof, ..) =
-            Self::batch_prove_internal::<_, _, T>(prng, circuits, prove_keys, None)?;
+        let (batch_proof, ..) = Self::batch_prove_internal::<_, _, T, _, _>(
+            prng,
+            circuits,
+            prove_keys,
+            None,
+            true,
+            &ArkworksBackend,
+            &mut NoopObserver,
+        )?;
         Ok(batch_proof)
     }
 
+    /// Compute a Plonk proof, reporting the progress of the prover to
+    /// `observer`. See [`ProverObserver`] for the reported events.
+    ///
+    /// `circuit` and `prove_key` has to be consistent (with the same evaluation
+    /// domain etc.), otherwise return error.
+    pub fn prove_with_observer<C, R, T, O>(
+        prng: &mut R,
+        circuit: &C,
+        prove_key: &ProvingKey<E>,
+        extra_transcript_init_msg: Option<Vec<u8>>,
+        observer: &mut O,
+    ) -> Result<Proof<E>, PlonkError>
+    where
+        C: Arithmetization<E::ScalarField>,
+        R: CryptoRng + RngCore,
+        T: PlonkTranscript<F>,
+        O: ProverObserver + ?Sized,
+    {
+        Self::prove_internal::<_, _, T, _, _>(
+            prng,
+            circuit,
+            prove_key,
+            extra_transcript_init_msg,
+            true,
+            &ArkworksBackend,
+            observer,
+        )
+    }
+
+    /// Compute a Plonk proof, delegating the MSMs of the prover and its FFTs
+    /// over the quotient domain to `backend`. See [`ProverBackend`].
+    ///
+    /// `circuit` and `prove_key` has to be consistent (with the same evaluation
+    /// domain etc.), otherwise return error.
+    pub fn prove_with_backend<C, R, T, B>(
+        prng: &mut R,
+        circuit: &C,
+        prove_key: &ProvingKey<E>,
+        extra_transcript_init_msg: Option<Vec<u8>>,
+        backend: &B,
+    ) -> Result<Proof<E>, PlonkError>
+    where
+        C: Arithmetization<E::ScalarField>,
+        R: CryptoRng + RngCore,
+        T: PlonkTranscript<F>,
+        B: ProverBackend<E> + ?Sized,
+    {
+        Self::prove_internal::<_, _, T, _, _>(
+            prng,
+            circuit,
+            prove_key,
+            extra_transcript_init_msg,
+            true,
+            backend,
+            &mut NoopObserver,
+        )
+    }
+
+    /// Compute a single Plonk proof with the given backend and observer,
+    /// blinding the witness polynomials iff `zk`.
+    fn prove_internal<C, R, T, B, O>(
+        prng: &mut R,
+        circuit: &C,
+        prove_key: &ProvingKey<E>,
+        extra_transcript_init_msg: Option<Vec<u8>>,
+        zk: bool,
+        backend: &B,
+        observer: &mut O,
+    ) -> Result<Proof<E>, PlonkError>
+    where
+        C: Arithmetization<E::ScalarField>,
+        R: CryptoRng + RngCore,
+        T: PlonkTranscript<F>,
+        B: ProverBackend<E> + ?Sized,
+        O: ProverObserver + ?Sized,
+    {
+        let (batch_proof, ..) = Self::batch_prove_internal::<_, _, T, _, _>(
+            prng,
+            &[circuit],
+            &[prove_key],
+            extra_transcript_init_msg,
+            zk,
+            backend,
+            observer,
+        )?;
+        Ok(Proof {
+            wires_poly_comms: batch_proof.wires_poly_comms_vec[0].clone(),
+            prod_perm_poly_comm: batch_proof.prod_perm_poly_comms_vec[0],
+            split_quot_poly_comms: batch_proof.split_quot_poly_comms,
+            opening_proof: batch_proof.opening_proof,
+            shifted_opening_proof: batch_proof.shifted_opening_proof,
+            poly_evals: batch_proof.poly_evals_vec[0].clone(),
+            plookup_proof: batch_proof.plookup_proofs_vec[0].clone(),
+        })
+    }
+
+    /// Compute a Plonk proof whose blinding randomness is entirely derived
+    /// from `seed` instead of an external RNG.
+    ///
+    /// The seed is used as the key of a PRF (keyed Keccak256) evaluated on the
+    /// verifying key, the full witness assignment and
+    /// `extra_transcript_init_msg`; the PRF output seeds the ChaCha20 stream
+    /// that replaces the prover's RNG. Proving the same statement and witness
+    /// with the same seed therefore always yields the same proof, which makes
+    /// proofs reproducible for debugging and lets prover clusters be audited
+    /// for determinism.
+    ///
+    /// Zero-knowledge is retained as long as `seed` is secret and uniformly
+    /// random; since the witness is bound into the derivation, reusing a seed
+    /// across different witnesses does not reuse blinding factors.
+    pub fn prove_with_seed<C, T>(
+        seed: &[u8; 32],
+        circuit: &C,
+        prove_key: &ProvingKey<E>,
+        extra_transcript_init_msg: Option<Vec<u8>>,
+    ) -> Result<Proof<E>, PlonkError>
+    where
+        C: Arithmetization<E::ScalarField>,
+        T: PlonkTranscript<F>,
+    {
+        let mut prng = Self::seeded_prover_rng(
+            seed,
+            circuit,
+            &prove_key.vk,
+            extra_transcript_init_msg.as_deref(),
+        )?;
+        <Self as UniversalSNARK<E>>::prove::<_, _, T>(
+            &mut prng,
+            circuit,
+            prove_key,
+            extra_transcript_init_msg,
+        )
+    }
+
+    /// Compute an independent Plonk proof for each of `instances`, e.g. for
+    /// many circuits whose proving keys are preprocessed from the same SRS,
+    /// scheduling the provers across the rayon thread pool.
+    ///
+    /// At most `max_concurrency` proofs are computed at the same time, which
+    /// caps the memory held by the provers. Each proof draws its blinding
+    /// randomness from its own ChaCha20 stream seeded from `prng`, so that
+    /// the output does not depend on the scheduling. The proofs are returned
+    /// in the order of `instances`, and `extra_transcript_init_msgs[i]` is
+    /// used for the `i`-th proof.
+    pub fn prove_batch<C, R, T>(
+        prng: &mut R,
+        instances: &[(&C, &ProvingKey<E>)],
+        extra_transcript_init_msgs: &[Option<Vec<u8>>],
+        max_concurrency: usize,
+    ) -> Result<Vec<Proof<E>>, PlonkError>
+    where
+        C: Arithmetization<E::ScalarField> + Sync,
+        R: CryptoRng + RngCore,
+        T: PlonkTranscript<F>,
+    {
+        if extra_transcript_init_msgs.len() != instances.len() {
+            return Err(ParameterError(format!(
+                "instances.len: {}, extra_transcript_msg.len: {}",
+                instances.len(),
+                extra_transcript_init_msgs.len()
+            ))
+            .into());
+        }
+        if max_concurrency == 0 {
+            return Err(ParameterError("max_concurrency cannot be zero".to_string()).into());
+        }
+
+        let jobs: Vec<_> = instances
+            .iter()
+            .zip(extra_transcript_init_msgs.iter())
+            .map(|(&(circuit, prove_key), extra_msg)| {
+                let mut seed = [0u8; 32];
+                prng.fill_bytes(&mut seed);
+                (seed, circuit, prove_key, extra_msg)
+            })
+            .collect();
+        let mut proofs = Vec::with_capacity(jobs.len());
+        for chunk in jobs.chunks(max_concurrency) {
+            let chunk_proofs = parallelizable_slice_iter(chunk)
+                .map(|(seed, circuit, prove_key, extra_msg)| {
+                    <Self as UniversalSNARK<E>>::prove::<_, _, T>(
+                        &mut ChaCha20Rng::from_seed(*seed),
+                        *circuit,
+                        prove_key,
+                        (*extra_msg).clone(),
+                    )
+                })
+                .collect::<Result<Vec<_>, PlonkError>>()?;
+            proofs.extend(chunk_proofs);
+        }
+        Ok(proofs)
+    }
+
+    /// Preprocess `circuit` against the universal `srs` and prove it in one
+    /// call, returning the proof together with the verifying key to check it
+    /// with.
+    ///
+    /// The SRS is trimmed to the degree the circuit needs. Return an error if
+    /// the circuit is not finalized, if the SRS is too small for it, or if
+    /// the witness does not satisfy it. Callers proving the same circuit
+    /// several times should rather [`UniversalSNARK::preprocess()`] it once.
+    pub fn prove_with_srs<C, R, T>(
+        srs: &UniversalSrs<E>,
+        circuit: &C,
+        prng: &mut R,
+    ) -> Result<(Proof<E>, VerifyingKey<E>), PlonkError>
+    where
+        C: Arithmetization<E::ScalarField>,
+        R: CryptoRng + RngCore,
+        T: PlonkTranscript<F>,
+    {
+        let srs_size = circuit.srs_size().map_err(|e| {
+            ParameterError(format!(
+                "cannot compute the SRS size of the circuit, is it finalized? {e}"
+            ))
+        })?;
+        if srs.max_degree() < srs_size {
+            return Err(ParameterError(format!(
+                "the circuit needs an SRS of degree at least {srs_size}, got {}",
+                srs.max_degree()
+            ))
+            .into());
+        }
+        let public_input = circuit.public_input()?;
+        circuit
+            .check_circuit_satisfiability(&public_input)
+            .map_err(|e| {
+                ParameterError(format!("the witness does not satisfy the circuit: {e}"))
+            })?;
+
+        let (pk, vk) = <Self as UniversalSNARK<E>>::preprocess(srs, circuit)?;
+        let proof = <Self as UniversalSNARK<E>>::prove::<_, _, T>(prng, circuit, &pk, None)?;
+        Ok((proof, vk))
+    }
+
+    /// Compute a Plonk proof WITHOUT blinding the witness polynomials, e.g. for
+    /// benchmarking or debugging a circuit.
+    ///
+    /// The resulting proof is NOT zero-knowledge, which is why it is returned
+    /// as a [`NonZkProof`] rather than a [`Proof`]. It is deterministic, and
+    /// slightly cheaper to compute than a regular proof since the prover
+    /// skips sampling the blinding factors.
+    pub fn prove_non_zk<C, T>(
+        circuit: &C,
+        prove_key: &ProvingKey<E>,
+        extra_transcript_init_msg: Option<Vec<u8>>,
+    ) -> Result<NonZkProof<E>, PlonkError>
+    where
+        C: Arithmetization<E::ScalarField>,
+        T: PlonkTranscript<F>,
+    {
+        // the prover does not use any randomness without blinding
+        let mut prng = ChaCha20Rng::from_seed([0u8; 32]);
+        Self::prove_internal::<_, _, T, _, _>(
+            &mut prng,
+            circuit,
+            prove_key,
+            extra_transcript_init_msg,
+            false,
+            &ArkworksBackend,
+            &mut NoopObserver,
+        )
+        .map(NonZkProof)
+    }
+
+    /// Verify a proof computed by [`Self::prove_non_zk()`].
+    pub fn verify_non_zk<T>(
+        verify_key: &VerifyingKey<E>,
+        public_input: &[E::ScalarField],
+        proof: &NonZkProof<E>,
+        extra_transcript_init_msg: Option<Vec<u8>>,
+    ) -> Result<(), PlonkError>
+    where
+        T: PlonkTranscript<F>,
+    {
+        <Self as UniversalSNARK<E>>::verify::<T>(
+            verify_key,
+            public_input,
+            &proof.0,
+            extra_transcript_init_msg,
+        )
+    }
+
     /// Verify a single aggregated Plonk proof.
     pub fn verify_batch_proof<T>(
         verify_keys: &[&VerifyingKey<E>],
@@ -151,6 +446,43 @@ where
         Ok(())
     }
 
+    /// Derive the prover RNG used by [`Self::prove_with_seed`]:
+    /// `ChaCha20Rng(Keccak256(label || seed || vk || witness || extra_msg))`,
+    /// where the verifying key, the witness and the extra message are each
+    /// prefixed with their length as a little-endian `u64`, and the extra
+    /// message with a byte telling whether it is present.
+    fn seeded_prover_rng<C>(
+        seed: &[u8; 32],
+        circuit: &C,
+        vk: &VerifyingKey<E>,
+        extra_transcript_init_msg: Option<&[u8]>,
+    ) -> Result<ChaCha20Rng, PlonkError>
+    where
+        C: Arithmetization<E::ScalarField>,
+    {
+        let mut hasher = Keccak256::new();
+        hasher.update(SEEDED_PROVER_RNG_LABEL);
+        hasher.update(seed);
+
+        hasher.update((vk.compressed_size() as u64).to_le_bytes());
+        vk.serialize_compressed(HashWriter(&mut hasher))?;
+        hasher.update((circuit.num_vars() as u64).to_le_bytes());
+        for var in 0..circuit.num_vars() {
+            circuit
+                .witness(var)?
+                .serialize_compressed(HashWriter(&mut hasher))?;
+        }
+        match extra_transcript_init_msg {
+            Some(msg) => {
+                hasher.update([1u8]);
+                hasher.update((msg.len() as u64).to_le_bytes());
+                hasher.update(msg);
+            },
+            None => hasher.update([0u8]),
+        }
+        Ok(ChaCha20Rng::from_seed(hasher.finalize().into()))
+    }
+
     /// An internal private API for ease of testing
     ///
     /// Batchly compute a Plonk proof for multiple instances. Return the batch
@@ -158,13 +490,17 @@ where
     /// challenges. Refer to Sec 8.4 of https://eprint.iacr.org/2019/953.pdf
     ///
     /// `circuit` and `prove_key` has to be consistent (with the same evaluation
-    /// domain etc.), otherwise return error.
+    /// domain etc.), otherwise return error. If `zk` is false, the witness
+    /// polynomials are not blinded and the proof is not zero-knowledge.
     #[allow(clippy::type_complexity)]
-    fn batch_prove_internal<C, R, T>(
+    fn batch_prove_internal<C, R, T, B, O>(
         prng: &mut R,
         circuits: &[&C],
         prove_keys: &[&ProvingKey<E>],
         extra_transcript_init_msg: Option<Vec<u8>>,
+        zk: bool,
+        backend: &B,
+        observer: &mut O,
     ) -> Result<
         (
             BatchProof<E>,
@@ -177,6 +513,8 @@ where
         C: Arithmetization<E::ScalarField>,
         R: CryptoRng + RngCore,
         T: PlonkTranscript<F>,
+        B: ProverBackend<E> + ?Sized,
+        O: ProverObserver + ?Sized,
     {
         if circuits.is_empty() {
             return Err(ParameterError("zero number of circuits/proving keys".to_string()).into());
@@ -238,23 +576,29 @@ where
         // Initialize verifier challenges and online polynomial oracles.
         let mut challenges = Challenges::default();
         let mut online_oracles = vec![Oracles::default(); circuits.len()];
-        let prover = Prover::new(n, num_wire_types)?;
+        let prover = Prover::new(n, num_wire_types, zk, backend)?;
+
+        let support_lookup = circuits.iter().any(|circuit| circuit.support_lookup());
 
         // Round 1
+        let timer = RoundTimer::start(observer, ProverRound::WirePolys);
         let mut wires_poly_comms_vec = vec![];
         for i in 0..circuits.len() {
             let ((wires_poly_comms, wire_polys), pi_poly) =
-                prover.run_1st_round(prng, &prove_keys[i].commit_key, circuits[i])?;
+                prover.run_1st_round(prng, &prove_keys[i].commit_key, circuits[i], observer)?;
             online_oracles[i].wire_polys = wire_polys;
             online_oracles[i].pub_inp_poly = pi_poly;
             transcript.append_commitments(b"witness_poly_comms", &wires_poly_comms)?;
             wires_poly_comms_vec.push(wires_poly_comms);
         }
+        timer.end(observer);
 
         // Round 1.5
         // Plookup: compute and interpolate the sorted concatenation of the (merged)
         // lookup table and the (merged) witness values
         challenges.tau = transcript.get_and_append_challenge::<E>(b"tau")?;
+        let timer =
+            support_lookup.then(|| RoundTimer::start(observer, ProverRound::PlookupSortedPolys));
         let mut h_poly_comms_vec = vec![];
         let mut sorted_vec_list = vec![];
         let mut merged_table_list = vec![];
@@ -266,6 +610,7 @@ where
                         &prove_keys[i].commit_key,
                         circuits[i],
                         challenges.tau,
+                        observer,
                     )?;
                 online_oracles[i].plookup_oracles.h_polys = h_polys;
                 transcript.append_commitments(b"h_poly_comms", &h_poly_comms)?;
@@ -277,21 +622,33 @@ where
             sorted_vec_list.push(sorted_vec);
             merged_table_list.push(merged_table);
         }
+        if let Some(timer) = timer {
+            timer.end(observer);
+        }
 
         // Round 2
         challenges.beta = transcript.get_and_append_challenge::<E>(b"beta")?;
         challenges.gamma = transcript.get_and_append_challenge::<E>(b"gamma")?;
+        let timer = RoundTimer::start(observer, ProverRound::PermutationProduct);
         let mut prod_perm_poly_comms_vec = vec![];
         for i in 0..circuits.len() {
-            let (prod_perm_poly_comm, prod_perm_poly) =
-                prover.run_2nd_round(prng, &prove_keys[i].commit_key, circuits[i], &challenges)?;
+            let (prod_perm_poly_comm, prod_perm_poly) = prover.run_2nd_round(
+                prng,
+                &prove_keys[i].commit_key,
+                circuits[i],
+                &challenges,
+                observer,
+            )?;
             online_oracles[i].prod_perm_poly = prod_perm_poly;
             transcript.append_commitment(b"perm_poly_comms", &prod_perm_poly_comm)?;
             prod_perm_poly_comms_vec.push(prod_perm_poly_comm);
         }
+        timer.end(observer);
 
         // Round 2.5
         // Plookup: compute Plookup product accumulation polynomial
+        let timer =
+            support_lookup.then(|| RoundTimer::start(observer, ProverRound::PlookupProduct));
         let mut prod_lookup_poly_comms_vec = vec![];
         for i in 0..circuits.len() {
             let prod_lookup_poly_comm = if circuits[i].support_lookup() {
@@ -302,6 +659,7 @@ where
                     &challenges,
                     merged_table_list[i].as_ref(),
                     sorted_vec_list[i].as_ref(),
+                    observer,
                 )?;
                 online_oracles[i].plookup_oracles.prod_lookup_poly = prod_lookup_poly;
                 transcript.append_commitment(b"plookup_poly_comms", &prod_lookup_poly_comm)?;
@@ -311,9 +669,13 @@ where
             };
             prod_lookup_poly_comms_vec.push(prod_lookup_poly_comm);
         }
+        if let Some(timer) = timer {
+            timer.end(observer);
+        }
 
         // Round 3
         challenges.alpha = transcript.get_and_append_challenge::<E>(b"alpha")?;
+        let timer = RoundTimer::start(observer, ProverRound::QuotientPolys);
         let (split_quot_poly_comms, split_quot_polys) = prover.run_3rd_round(
             prng,
             &prove_keys[0].commit_key,
@@ -321,11 +683,14 @@ where
             &challenges,
             &online_oracles,
             num_wire_types,
+            observer,
         )?;
         transcript.append_commitments(b"quot_poly_comms", &split_quot_poly_comms)?;
+        timer.end(observer);
 
         // Round 4
         challenges.zeta = transcript.get_and_append_challenge::<E>(b"zeta")?;
+        let timer = RoundTimer::start(observer, ProverRound::Evaluations);
         let mut poly_evals_vec = vec![];
         for i in 0..circuits.len() {
             let poly_evals = prover.compute_evaluations(
@@ -356,7 +721,7 @@ where
             plookup_evals_vec.push(plookup_evals);
         }
 
-        let mut lin_poly = Prover::<E>::compute_quotient_component_for_lin_poly(
+        let mut lin_poly = Prover::<E, B>::compute_quotient_component_for_lin_poly(
             n,
             challenges.zeta,
             &split_quot_polys,
@@ -382,9 +747,11 @@ where
                 alpha_base *= alpha_3;
             }
         }
+        timer.end(observer);
 
         // Round 5
         challenges.v = transcript.get_and_append_challenge::<E>(b"v")?;
+        let timer = RoundTimer::start(observer, ProverRound::OpeningProofs);
         let (opening_proof, shifted_opening_proof) = prover.compute_opening_proofs(
             &prove_keys[0].commit_key,
             prove_keys,
@@ -392,7 +759,9 @@ where
             &challenges.v,
             &online_oracles,
             &lin_poly,
+            observer,
         )?;
+        timer.end(observer);
 
         // Plookup: build Plookup argument
         let mut plookup_proofs_vec = vec![];
@@ -425,6 +794,20 @@ where
     }
 }
 
+/// Streams serialized data into a hasher, without buffering it.
+struct HashWriter<'a>(&'a mut Keccak256);
+
+impl ark_std::io::Write for HashWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> ark_std::io::Result<usize> {
+        self.0.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> ark_std::io::Result<()> {
+        Ok(())
+    }
+}
+
 impl<E, F, P> UniversalSNARK<E> for PlonkKzgSnark<E>
 where
     E: Pairing<BaseField = F, G1Affine = Affine<P>>,
@@ -593,21 +976,15 @@ where
         R: CryptoRng + RngCore,
         T: PlonkTranscript<F>,
     {
-        let (batch_proof, ..) = Self::batch_prove_internal::<_, _, T>(
+        Self::prove_internal::<_, _, T, _, _>(
             rng,
-            &[circuit],
-            &[prove_key],
+            circuit,
+            prove_key,
             extra_transcript_init_msg,
-        )?;
-        Ok(Proof {
-            wires_poly_comms: batch_proof.wires_poly_comms_vec[0].clone(),
-            prod_perm_poly_comm: batch_proof.prod_perm_poly_comms_vec[0],
-            split_quot_poly_comms: batch_proof.split_quot_poly_comms,
-            opening_proof: batch_proof.opening_proof,
-            shifted_opening_proof: batch_proof.shifted_opening_proof,
-            poly_evals: batch_proof.poly_evals_vec[0].clone(),
-            plookup_proof: batch_proof.plookup_proofs_vec[0].clone(),
-        })
+            true,
+            &ArkworksBackend,
+            &mut NoopObserver,
+        )
     }
 
     fn verify<T>(
@@ -633,9 +1010,12 @@ pub mod test {
     use crate::{
         errors::PlonkError,
         proof_system::{
+            backend::{ArkworksBackend, ProverBackend},
+            observer::{NoopObserver, ProverObserver, ProverRound},
+            prover::Prover,
             structs::{
-                eval_merged_lookup_witness, eval_merged_table, Challenges, Oracles, Proof,
-                ProvingKey, UniversalSrs, VerifyingKey,
+                eval_merged_lookup_witness, eval_merged_table, Challenges, CommitKey, NonZkProof,
+                Oracles, Proof, ProvingKey, UniversalSrs, VerifyingKey,
             },
             PlonkKzgSnark, UniversalSNARK,
         },
@@ -655,8 +1035,8 @@ pub mod test {
     };
     use ark_ff::{One, PrimeField, Zero};
     use ark_poly::{
-        univariate::DensePolynomial, DenseUVPolynomial, EvaluationDomain, Polynomial,
-        Radix2EvaluationDomain,
+        univariate::DensePolynomial, DenseUVPolynomial, EvaluationDomain, GeneralEvaluationDomain,
+        Polynomial, Radix2EvaluationDomain,
     };
     use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
     use ark_std::{
@@ -666,7 +1046,10 @@ pub mod test {
         vec,
         vec::Vec,
     };
-    use core::ops::{Mul, Neg};
+    use core::{
+        ops::{Mul, Neg},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
     use jf_pcs::{
         prelude::{Commitment, UnivariateKzgPCS},
         PolynomialCommitmentScheme,
@@ -677,6 +1060,7 @@ pub mod test {
     };
     use jf_rescue::RescueParameter;
     use jf_utils::test_rng;
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 
     // Different `m`s lead to different circuits.
     // Different `a0`s lead to different witness values.
@@ -1228,12 +1612,47 @@ pub mod test {
         let (pk, _) = PlonkKzgSnark::<E>::preprocess(&srs, &circuit)?;
 
         // 4. Proving
-        let (_, oracles, challenges) =
-            PlonkKzgSnark::<E>::batch_prove_internal::<_, _, T>(rng, &[&circuit], &[&pk], None)?;
+        let (_, oracles, challenges) = PlonkKzgSnark::<E>::batch_prove_internal::<_, _, T, _, _>(
+            rng,
+            &[&circuit],
+            &[&pk],
+            None,
+            true,
+            &ArkworksBackend,
+            &mut NoopObserver,
+        )?;
 
         // 5. Check that the targeted polynomials evaluate to zero on the vanishing set.
         check_plonk_prover_polynomials(plonk_type, &oracles[0], &pk, &challenges)?;
 
+        // 6. Check that the quotient polynomial is the same when evaluated over cosets
+        // of the evaluation domain instead of a coset of a larger subgroup.
+        let num_wire_types = circuit.num_wire_types();
+        let quot_poly =
+            Prover::<E, _>::new(pk.domain_size(), num_wire_types, true, &ArkworksBackend)?
+                .compute_quotient_polynomial(
+                    &challenges,
+                    &[&pk],
+                    &oracles,
+                    num_wire_types,
+                    &mut NoopObserver,
+                )?;
+        let multi_coset_prover = Prover::<E, _>::new_with_multi_coset_quot_domain(
+            pk.domain_size(),
+            num_wire_types,
+            &ArkworksBackend,
+        )?;
+        assert_eq!(
+            multi_coset_prover.compute_quotient_polynomial(
+                &challenges,
+                &[&pk],
+                &oracles,
+                num_wire_types,
+                &mut NoopObserver,
+            )?,
+            quot_poly
+        );
+
         Ok(())
     }
 
@@ -1561,6 +1980,566 @@ pub mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_prove_with_seed() -> Result<(), PlonkError> {
+        test_prove_with_seed_helper::<Bn254, Fq254, _, StandardTranscript>(PlonkType::TurboPlonk)?;
+        test_prove_with_seed_helper::<Bn254, Fq254, _, StandardTranscript>(PlonkType::UltraPlonk)?;
+        test_prove_with_seed_helper::<Bls12_377, Fq377, _, RescueTranscript<_>>(
+            PlonkType::TurboPlonk,
+        )?;
+        test_prove_with_seed_helper::<Bls12_381, Fq381, _, SolidityTranscript>(
+            PlonkType::TurboPlonk,
+        )
+    }
+
+    fn test_prove_with_seed_helper<E, F, P, T>(plonk_type: PlonkType) -> Result<(), PlonkError>
+    where
+        E: Pairing<BaseField = F, G1Affine = Affine<P>>,
+        F: RescueParameter + SWToTEConParam,
+        P: SWCurveConfig<BaseField = F>,
+        T: PlonkTranscript<F>,
+    {
+        let rng = &mut test_rng();
+        let srs = PlonkKzgSnark::<E>::universal_setup_for_testing(80, rng)?;
+        let circuit = gen_circuit_for_test(3, 4, plonk_type)?;
+        let other_circuit = gen_circuit_for_test(3, 3, plonk_type)?;
+        let (pk, vk) = PlonkKzgSnark::<E>::preprocess(&srs, &circuit)?;
+        let extra_msg = Some(b"extra message".to_vec());
+
+        let seed = [7u8; 32];
+        let proof =
+            PlonkKzgSnark::<E>::prove_with_seed::<_, T>(&seed, &circuit, &pk, extra_msg.clone())?;
+        let public_input = circuit.public_input()?;
+        PlonkKzgSnark::<E>::verify::<T>(&vk, &public_input, &proof, extra_msg.clone())?;
+
+        // same seed, same statement and witness -> same proof
+        assert_eq!(
+            proof,
+            PlonkKzgSnark::<E>::prove_with_seed::<_, T>(&seed, &circuit, &pk, extra_msg.clone())?
+        );
+        // a different seed, witness or extra message changes the blinding
+        assert_ne!(
+            proof,
+            PlonkKzgSnark::<E>::prove_with_seed::<_, T>(
+                &[8u8; 32],
+                &circuit,
+                &pk,
+                extra_msg.clone()
+            )?
+        );
+        let other_proof = PlonkKzgSnark::<E>::prove_with_seed::<_, T>(
+            &seed,
+            &other_circuit,
+            &pk,
+            extra_msg.clone(),
+        )?;
+        assert_ne!(proof.wires_poly_comms, other_proof.wires_poly_comms);
+        PlonkKzgSnark::<E>::verify::<T>(
+            &vk,
+            &other_circuit.public_input()?,
+            &other_proof,
+            extra_msg,
+        )?;
+        assert_ne!(
+            proof,
+            PlonkKzgSnark::<E>::prove_with_seed::<_, T>(&seed, &circuit, &pk, None)?
+        );
+        // an absent extra message is not confused with an empty one
+        let mut rng = PlonkKzgSnark::<E>::seeded_prover_rng(&seed, &circuit, &pk.vk, None)?;
+        let mut other_rng =
+            PlonkKzgSnark::<E>::seeded_prover_rng(&seed, &circuit, &pk.vk, Some(&[]))?;
+        assert_ne!(rng.next_u64(), other_rng.next_u64());
+
+        Ok(())
+    }
+
+    #[derive(Default)]
+    struct RecordingObserver {
+        started: Vec<ProverRound>,
+        ended: Vec<ProverRound>,
+        msms: Vec<(ProverRound, usize)>,
+        ffts: Vec<(ProverRound, usize)>,
+    }
+
+    impl ProverObserver for RecordingObserver {
+        fn on_round_start(&mut self, round: ProverRound) {
+            assert_eq!(self.started.len(), self.ended.len());
+            self.started.push(round);
+        }
+
+        fn on_round_end(&mut self, round: ProverRound, _elapsed: Option<core::time::Duration>) {
+            assert_eq!(self.started.last(), Some(&round));
+            self.ended.push(round);
+        }
+
+        fn on_msm(&mut self, round: ProverRound, size: usize) {
+            assert_eq!(self.started.last(), Some(&round));
+            self.msms.push((round, size));
+        }
+
+        fn on_fft(&mut self, round: ProverRound, size: usize) {
+            assert_eq!(self.started.last(), Some(&round));
+            self.ffts.push((round, size));
+        }
+    }
+
+    #[test]
+    fn test_prove_with_observer() -> Result<(), PlonkError> {
+        test_prove_with_observer_helper::<Bn254, Fq254, _, StandardTranscript>(
+            PlonkType::TurboPlonk,
+        )?;
+        test_prove_with_observer_helper::<Bn254, Fq254, _, StandardTranscript>(
+            PlonkType::UltraPlonk,
+        )?;
+        test_prove_with_observer_helper::<Bls12_381, Fq381, _, SolidityTranscript>(
+            PlonkType::TurboPlonk,
+        )
+    }
+
+    fn test_prove_with_observer_helper<E, F, P, T>(plonk_type: PlonkType) -> Result<(), PlonkError>
+    where
+        E: Pairing<BaseField = F, G1Affine = Affine<P>>,
+        F: RescueParameter + SWToTEConParam,
+        P: SWCurveConfig<BaseField = F>,
+        T: PlonkTranscript<F>,
+    {
+        let rng = &mut test_rng();
+        let srs = PlonkKzgSnark::<E>::universal_setup_for_testing(80, rng)?;
+        let circuit = gen_circuit_for_test(3, 4, plonk_type)?;
+        let (pk, vk) = PlonkKzgSnark::<E>::preprocess(&srs, &circuit)?;
+        let n = circuit.eval_domain_size()?;
+        let num_wire_types = circuit.num_wire_types();
+
+        let mut observer = RecordingObserver::default();
+        let proof = PlonkKzgSnark::<E>::prove_with_observer::<_, _, T, _>(
+            rng,
+            &circuit,
+            &pk,
+            None,
+            &mut observer,
+        )?;
+        PlonkKzgSnark::<E>::verify::<T>(&vk, &circuit.public_input()?, &proof, None)?;
+
+        let expected_rounds = if plonk_type == PlonkType::UltraPlonk {
+            vec![
+                ProverRound::WirePolys,
+                ProverRound::PlookupSortedPolys,
+                ProverRound::PermutationProduct,
+                ProverRound::PlookupProduct,
+                ProverRound::QuotientPolys,
+                ProverRound::Evaluations,
+                ProverRound::OpeningProofs,
+            ]
+        } else {
+            vec![
+                ProverRound::WirePolys,
+                ProverRound::PermutationProduct,
+                ProverRound::QuotientPolys,
+                ProverRound::Evaluations,
+                ProverRound::OpeningProofs,
+            ]
+        };
+        assert_eq!(observer.started, expected_rounds);
+        assert_eq!(observer.ended, expected_rounds);
+
+        // one MSM per commitment: wire polys (masked with degree 1), the
+        // permutation product (masked with degree 2), split quotient polys and
+        // the two opening proofs
+        let msms_in = |round| {
+            observer
+                .msms
+                .iter()
+                .filter(|(r, _)| *r == round)
+                .map(|(_, size)| *size)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(msms_in(ProverRound::WirePolys), vec![n + 2; num_wire_types]);
+        assert_eq!(msms_in(ProverRound::PermutationProduct), vec![n + 3]);
+        assert_eq!(
+            msms_in(ProverRound::QuotientPolys).len(),
+            proof.split_quot_poly_comms.len()
+        );
+        assert_eq!(msms_in(ProverRound::OpeningProofs).len(), 2);
+        assert!(msms_in(ProverRound::Evaluations).is_empty());
+
+        // wire polys and the public input poly are interpolated over the domain
+        let quot_domain_size = observer
+            .ffts
+            .iter()
+            .find(|(r, _)| *r == ProverRound::QuotientPolys)
+            .unwrap()
+            .1;
+        assert!(quot_domain_size > n);
+        for (round, size) in observer.ffts.iter() {
+            match round {
+                ProverRound::QuotientPolys => assert_eq!(*size, quot_domain_size),
+                _ => assert_eq!(*size, n),
+            }
+        }
+        assert_eq!(
+            observer
+                .ffts
+                .iter()
+                .filter(|(r, _)| *r == ProverRound::WirePolys)
+                .count(),
+            num_wire_types + 1
+        );
+
+        // observed proving does not change the proof
+        let mut observer = NoopObserver;
+        let proof = PlonkKzgSnark::<E>::prove_with_observer::<_, _, T, _>(
+            rng,
+            &circuit,
+            &pk,
+            None,
+            &mut observer,
+        )?;
+        PlonkKzgSnark::<E>::verify::<T>(&vk, &circuit.public_input()?, &proof, None)
+    }
+
+    /// A backend delegating to [`ArkworksBackend`] while counting the
+    /// operations it computes.
+    #[derive(Default)]
+    struct CountingBackend {
+        num_msms: AtomicUsize,
+        num_ffts: AtomicUsize,
+    }
+
+    impl<E: Pairing> ProverBackend<E> for CountingBackend {
+        fn batch_commit(
+            &self,
+            ck: &CommitKey<E>,
+            polys: &[DensePolynomial<E::ScalarField>],
+        ) -> Result<Vec<Commitment<E>>, PlonkError> {
+            self.num_msms.fetch_add(polys.len(), Ordering::Relaxed);
+            ProverBackend::<E>::batch_commit(&ArkworksBackend, ck, polys)
+        }
+
+        fn fft(
+            &self,
+            domain: &GeneralEvaluationDomain<E::ScalarField>,
+            polys: &[DensePolynomial<E::ScalarField>],
+        ) -> Result<Vec<Vec<E::ScalarField>>, PlonkError> {
+            self.num_ffts.fetch_add(polys.len(), Ordering::Relaxed);
+            ProverBackend::<E>::fft(&ArkworksBackend, domain, polys)
+        }
+
+        fn ifft(
+            &self,
+            domain: &GeneralEvaluationDomain<E::ScalarField>,
+            evals: &[E::ScalarField],
+        ) -> Result<DensePolynomial<E::ScalarField>, PlonkError> {
+            self.num_ffts.fetch_add(1, Ordering::Relaxed);
+            ProverBackend::<E>::ifft(&ArkworksBackend, domain, evals)
+        }
+    }
+
+    #[test]
+    fn test_prove_with_backend() -> Result<(), PlonkError> {
+        test_prove_with_backend_helper::<Bn254, Fq254, _, StandardTranscript>(
+            PlonkType::TurboPlonk,
+        )?;
+        test_prove_with_backend_helper::<Bn254, Fq254, _, StandardTranscript>(
+            PlonkType::UltraPlonk,
+        )?;
+        test_prove_with_backend_helper::<Bls12_377, Fq377, _, RescueTranscript<_>>(
+            PlonkType::TurboPlonk,
+        )
+    }
+
+    fn test_prove_with_backend_helper<E, F, P, T>(plonk_type: PlonkType) -> Result<(), PlonkError>
+    where
+        E: Pairing<BaseField = F, G1Affine = Affine<P>>,
+        F: RescueParameter + SWToTEConParam,
+        P: SWCurveConfig<BaseField = F>,
+        T: PlonkTranscript<F>,
+    {
+        let rng = &mut test_rng();
+        let srs = PlonkKzgSnark::<E>::universal_setup_for_testing(80, rng)?;
+        let circuit = gen_circuit_for_test(3, 4, plonk_type)?;
+        let (pk, vk) = PlonkKzgSnark::<E>::preprocess(&srs, &circuit)?;
+        let public_input = circuit.public_input()?;
+
+        // the same randomness with a different backend results in the same proof
+        let seed = [3u8; 32];
+        let backend = CountingBackend::default();
+        let proof = PlonkKzgSnark::<E>::prove_with_backend::<_, _, T, _>(
+            &mut ChaCha20Rng::from_seed(seed),
+            &circuit,
+            &pk,
+            None,
+            &backend,
+        )?;
+        PlonkKzgSnark::<E>::verify::<T>(&vk, &public_input, &proof, None)?;
+        assert_eq!(
+            proof,
+            PlonkKzgSnark::<E>::prove::<_, _, T>(
+                &mut ChaCha20Rng::from_seed(seed),
+                &circuit,
+                &pk,
+                None
+            )?
+        );
+
+        // every commitment and quotient (i)FFT goes through the backend
+        let mut observer = RecordingObserver::default();
+        PlonkKzgSnark::<E>::prove_with_observer::<_, _, T, _>(
+            &mut ChaCha20Rng::from_seed(seed),
+            &circuit,
+            &pk,
+            None,
+            &mut observer,
+        )?;
+        assert_eq!(backend.num_msms.into_inner(), observer.msms.len());
+        assert_eq!(
+            backend.num_ffts.into_inner(),
+            observer
+                .ffts
+                .iter()
+                .filter(|(round, _)| *round == ProverRound::QuotientPolys)
+                .count()
+        );
+
+        // backends can be used as trait objects
+        let backend: &dyn ProverBackend<E> = &ArkworksBackend;
+        let proof = PlonkKzgSnark::<E>::prove_with_backend::<_, _, T, _>(
+            rng, &circuit, &pk, None, backend,
+        )?;
+        PlonkKzgSnark::<E>::verify::<T>(&vk, &public_input, &proof, None)
+    }
+
+    #[test]
+    fn test_prove_batch() -> Result<(), PlonkError> {
+        test_prove_batch_helper::<Bn254, Fq254, _, StandardTranscript>(PlonkType::TurboPlonk)?;
+        test_prove_batch_helper::<Bn254, Fq254, _, StandardTranscript>(PlonkType::UltraPlonk)?;
+        test_prove_batch_helper::<Bls12_377, Fq377, _, RescueTranscript<_>>(PlonkType::TurboPlonk)
+    }
+
+    fn test_prove_batch_helper<E, F, P, T>(plonk_type: PlonkType) -> Result<(), PlonkError>
+    where
+        E: Pairing<BaseField = F, G1Affine = Affine<P>>,
+        F: RescueParameter + SWToTEConParam,
+        P: SWCurveConfig<BaseField = F>,
+        T: PlonkTranscript<F>,
+    {
+        let rng = &mut test_rng();
+        let srs = PlonkKzgSnark::<E>::universal_setup_for_testing(80, rng)?;
+        let circuits = (0..5)
+            .map(|i| gen_circuit_for_test(1 + i % 3, i, plonk_type))
+            .collect::<Result<Vec<_>, _>>()?;
+        let keys = circuits
+            .iter()
+            .map(|circuit| PlonkKzgSnark::<E>::preprocess(&srs, circuit))
+            .collect::<Result<Vec<_>, _>>()?;
+        let instances: Vec<_> = circuits.iter().zip(keys.iter().map(|(pk, _)| pk)).collect();
+        let extra_msgs: Vec<_> = (0..5u8).map(|i| Some(vec![i])).collect();
+
+        let seed = [7u8; 32];
+        let proofs = PlonkKzgSnark::<E>::prove_batch::<_, _, T>(
+            &mut ChaCha20Rng::from_seed(seed),
+            &instances,
+            &extra_msgs,
+            2,
+        )?;
+        assert_eq!(proofs.len(), circuits.len());
+        for (((circuit, (_, vk)), proof), extra_msg) in circuits
+            .iter()
+            .zip(keys.iter())
+            .zip(proofs.iter())
+            .zip(extra_msgs.iter())
+        {
+            PlonkKzgSnark::<E>::verify::<T>(
+                vk,
+                &circuit.public_input()?,
+                proof,
+                extra_msg.clone(),
+            )?;
+        }
+
+        // the proofs don't depend on the concurrency
+        for max_concurrency in [1, 5, 10] {
+            assert_eq!(
+                PlonkKzgSnark::<E>::prove_batch::<_, _, T>(
+                    &mut ChaCha20Rng::from_seed(seed),
+                    &instances,
+                    &extra_msgs,
+                    max_concurrency,
+                )?,
+                proofs
+            );
+        }
+
+        // bad parameters
+        assert!(
+            PlonkKzgSnark::<E>::prove_batch::<_, _, T>(rng, &instances, &extra_msgs, 0).is_err()
+        );
+        assert!(
+            PlonkKzgSnark::<E>::prove_batch::<_, _, T>(rng, &instances, &extra_msgs[1..], 2)
+                .is_err()
+        );
+        assert!(
+            PlonkKzgSnark::<E>::prove_batch::<PlonkCircuit<E::ScalarField>, _, T>(
+                rng,
+                &[],
+                &[],
+                2
+            )?
+            .is_empty()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_prove_non_zk() -> Result<(), PlonkError> {
+        test_prove_non_zk_helper::<Bn254, Fq254, _, StandardTranscript>(PlonkType::TurboPlonk)?;
+        test_prove_non_zk_helper::<Bn254, Fq254, _, StandardTranscript>(PlonkType::UltraPlonk)?;
+        test_prove_non_zk_helper::<Bls12_377, Fq377, _, RescueTranscript<_>>(PlonkType::TurboPlonk)
+    }
+
+    fn test_prove_non_zk_helper<E, F, P, T>(plonk_type: PlonkType) -> Result<(), PlonkError>
+    where
+        E: Pairing<BaseField = F, G1Affine = Affine<P>>,
+        F: RescueParameter + SWToTEConParam,
+        P: SWCurveConfig<BaseField = F>,
+        T: PlonkTranscript<F>,
+    {
+        let rng = &mut test_rng();
+        let srs = PlonkKzgSnark::<E>::universal_setup_for_testing(80, rng)?;
+        let circuit = gen_circuit_for_test(3, 4, plonk_type)?;
+        let (pk, vk) = PlonkKzgSnark::<E>::preprocess(&srs, &circuit)?;
+        let public_input = circuit.public_input()?;
+
+        let proof = PlonkKzgSnark::<E>::prove_non_zk::<_, T>(&circuit, &pk, Some(vec![1]))?;
+        PlonkKzgSnark::<E>::verify_non_zk::<T>(&vk, &public_input, &proof, Some(vec![1]))?;
+        assert!(PlonkKzgSnark::<E>::verify_non_zk::<T>(&vk, &public_input, &proof, None).is_err());
+
+        // the proof is deterministic, and differs from a blinded one
+        assert_eq!(
+            PlonkKzgSnark::<E>::prove_non_zk::<_, T>(&circuit, &pk, Some(vec![1]))?,
+            proof
+        );
+        let zk_proof = PlonkKzgSnark::<E>::prove::<_, _, T>(rng, &circuit, &pk, Some(vec![1]))?;
+        assert_ne!(zk_proof, proof.clone().into_proof_insecure());
+
+        // the serialization tag tells it apart from a regular proof
+        assert!(proof.to_string().starts_with("NONZKPROOF~"));
+
+        // so does the canonical serialization
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes)?;
+        assert_eq!(NonZkProof::<E>::deserialize_compressed(&bytes[..])?, proof);
+        assert!(Proof::<E>::deserialize_compressed(&bytes[..]).is_err());
+        let mut zk_bytes = Vec::new();
+        zk_proof.serialize_compressed(&mut zk_bytes)?;
+        assert!(NonZkProof::<E>::deserialize_compressed(&zk_bytes[..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_prove_with_srs() -> Result<(), PlonkError> {
+        test_prove_with_srs_helper::<Bn254, Fq254, _, StandardTranscript>(PlonkType::TurboPlonk)?;
+        test_prove_with_srs_helper::<Bn254, Fq254, _, StandardTranscript>(PlonkType::UltraPlonk)?;
+        test_prove_with_srs_helper::<Bls12_377, Fq377, _, RescueTranscript<_>>(
+            PlonkType::TurboPlonk,
+        )
+    }
+
+    fn test_prove_with_srs_helper<E, F, P, T>(plonk_type: PlonkType) -> Result<(), PlonkError>
+    where
+        E: Pairing<BaseField = F, G1Affine = Affine<P>>,
+        F: RescueParameter + SWToTEConParam,
+        P: SWCurveConfig<BaseField = F>,
+        T: PlonkTranscript<F>,
+    {
+        let rng = &mut test_rng();
+        let srs = PlonkKzgSnark::<E>::universal_setup_for_testing(80, rng)?;
+        let mut circuit = gen_circuit_for_test(3, 4, plonk_type)?;
+        let (proof, vk) = PlonkKzgSnark::<E>::prove_with_srs::<_, _, T>(&srs, &circuit, rng)?;
+        let (_, expected_vk) = PlonkKzgSnark::<E>::preprocess(&srs, &circuit)?;
+        assert_eq!(vk, expected_vk);
+        PlonkKzgSnark::<E>::verify::<T>(&vk, &circuit.public_input()?, &proof, None)?;
+
+        // the SRS is too small
+        let small_srs =
+            PlonkKzgSnark::<E>::universal_setup_for_testing(circuit.srs_size()? - 1, rng)?;
+        assert!(PlonkKzgSnark::<E>::prove_with_srs::<_, _, T>(&small_srs, &circuit, rng).is_err());
+
+        // the circuit is not finalized
+        let unfinalized_circuit = PlonkCircuit::<E::ScalarField>::new_turbo_plonk();
+        assert!(
+            PlonkKzgSnark::<E>::prove_with_srs::<_, _, T>(&srs, &unfinalized_circuit, rng).is_err()
+        );
+
+        // the witness is wrong
+        *circuit.witness_mut(2) += E::ScalarField::one();
+        assert!(PlonkKzgSnark::<E>::prove_with_srs::<_, _, T>(&srs, &circuit, rng).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_dynamic_table_lookup() -> Result<(), PlonkError> {
+        test_dynamic_table_lookup_helper::<Bn254, Fq254, _, StandardTranscript>()?;
+        test_dynamic_table_lookup_helper::<Bls12_377, Fq377, _, RescueTranscript<_>>()
+    }
+
+    fn test_dynamic_table_lookup_helper<E, F, P, T>() -> Result<(), PlonkError>
+    where
+        E: Pairing<BaseField = F, G1Affine = Affine<P>>,
+        F: RescueParameter + SWToTEConParam,
+        P: SWCurveConfig<BaseField = F>,
+        T: PlonkTranscript<F>,
+    {
+        // a circuit reading `reads` from a memory holding `memory`
+        let gen_circuit = |memory: &[(u64, u64)], reads: &[usize]| {
+            let mut circuit = PlonkCircuit::<E::ScalarField>::new_ultra_plonk(4);
+            let table_vars = memory
+                .iter()
+                .map(|&(addr, val)| {
+                    Ok((
+                        circuit.create_variable(E::ScalarField::from(addr))?,
+                        circuit.create_variable(E::ScalarField::from(val))?,
+                    ))
+                })
+                .collect::<Result<Vec<_>, PlonkError>>()?;
+            let lookup_vars = reads
+                .iter()
+                .map(|&i| {
+                    Ok((
+                        circuit.create_public_variable(E::ScalarField::from(memory[i].0))?,
+                        circuit.create_variable(E::ScalarField::from(memory[i].1))?,
+                    ))
+                })
+                .collect::<Result<Vec<_>, PlonkError>>()?;
+            circuit.create_dynamic_table_and_lookup_variables(&lookup_vars, &table_vars)?;
+            circuit.finalize_for_arithmetization()?;
+            Ok::<_, PlonkError>(circuit)
+        };
+
+        let rng = &mut test_rng();
+        let srs = PlonkKzgSnark::<E>::universal_setup_for_testing(80, rng)?;
+        let circuit_1 = gen_circuit(&[(7, 1), (3, 2), (12, 3), (0, 4)], &[2, 0, 2])?;
+        let circuit_2 = gen_circuit(&[(5, 9), (1, 8), (8, 7), (2, 6)], &[3, 3, 1])?;
+        let (pk, vk) = PlonkKzgSnark::<E>::preprocess(&srs, &circuit_1)?;
+        // the keys don't depend on the table contents
+        assert_eq!(PlonkKzgSnark::<E>::preprocess(&srs, &circuit_2)?.1, vk);
+        for circuit in [&circuit_1, &circuit_2] {
+            let proof = PlonkKzgSnark::<E>::prove::<_, _, T>(rng, circuit, &pk, None)?;
+            PlonkKzgSnark::<E>::verify::<T>(&vk, &circuit.public_input()?, &proof, None)?;
+        }
+
+        // reading an address that is not in the memory fails
+        let mut bad_circuit = PlonkCircuit::<E::ScalarField>::new_ultra_plonk(4);
+        let addr = bad_circuit.create_variable(E::ScalarField::from(3u8))?;
+        let val = bad_circuit.create_variable(E::ScalarField::from(2u8))?;
+        let bad_addr = bad_circuit.create_variable(E::ScalarField::from(4u8))?;
+        bad_circuit
+            .create_dynamic_table_and_lookup_variables(&[(bad_addr, val)], &[(addr, val)])?;
+        bad_circuit.finalize_for_arithmetization()?;
+        let (pk, _) = PlonkKzgSnark::<E>::preprocess(&srs, &bad_circuit)?;
+        assert!(PlonkKzgSnark::<E>::prove::<_, _, T>(rng, &bad_circuit, &pk, None).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_key_aggregation_and_batch_prove() -> Result<(), PlonkError> {
         // merlin transcripts
@@ -7,6 +7,7 @@
 //! Data structures used in Plonk proof systems
 use crate::{
     circuit::plonk_verifier::{BatchProofVar, ProofEvaluationsVar},
+    constants::{NON_ZK_PROOF_MARKER, NON_ZK_PROOF_TAG},
     errors::{
         PlonkError,
         SnarkError::{self, ParameterError, SnarkLookupUnsupported},
@@ -35,8 +36,9 @@ use jf_relation::{
     },
     PlonkCircuit,
 };
-use jf_rescue::RescueParameter;
-use jf_utils::{field_switching, fq_to_fr, fr_to_fq};
+use jf_rescue::{crhf::RescueCRHF, RescueParameter};
+use jf_utils::{bytes_to_field_elements, field_switching, fq_to_fr, fr_to_fq};
+use sha3::{Digest, Keccak256};
 use tagged_base64::tagged;
 
 /// Universal StructuredReferenceString
@@ -74,6 +76,63 @@ pub struct Proof<E: Pairing> {
     pub plookup_proof: Option<PlookupProof<E>>,
 }
 
+/// A Plonk proof computed without blinding the witness polynomials, see
+/// [`PlonkKzgSnark::prove_non_zk()`].
+///
+/// **This proof is NOT zero-knowledge**: it leaks information about the
+/// witness and must only be used for benchmarking and debugging. It is a
+/// distinct type from [`Proof`], with a distinct serialization tag, and its
+/// canonical serialization starts with [`NON_ZK_PROOF_MARKER`], so that it
+/// cannot be mistaken for a production proof.
+///
+/// [`PlonkKzgSnark::prove_non_zk()`]: crate::proof_system::PlonkKzgSnark::prove_non_zk
+#[tagged(NON_ZK_PROOF_TAG)]
+#[derive(Debug, Clone, Eq, Derivative)]
+#[derivative(PartialEq, Hash(bound = "E:Pairing"))]
+pub struct NonZkProof<E: Pairing>(pub(crate) Proof<E>);
+
+impl<E: Pairing> CanonicalSerialize for NonZkProof<E> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        NON_ZK_PROOF_MARKER.serialize_with_mode(&mut writer, compress)?;
+        self.0.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        NON_ZK_PROOF_MARKER.serialized_size(compress) + self.0.serialized_size(compress)
+    }
+}
+
+impl<E: Pairing> Valid for NonZkProof<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.0.check()
+    }
+}
+
+impl<E: Pairing> CanonicalDeserialize for NonZkProof<E> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        if u64::deserialize_with_mode(&mut reader, compress, validate)? != NON_ZK_PROOF_MARKER {
+            return Err(SerializationError::InvalidData);
+        }
+        Proof::deserialize_with_mode(reader, compress, validate).map(Self)
+    }
+}
+
+impl<E: Pairing> NonZkProof<E> {
+    /// Get the underlying proof. Only use it where leaking the witness is
+    /// acceptable.
+    pub fn into_proof_insecure(self) -> Proof<E> {
+        self.0
+    }
+}
+
 impl<E, P> TryFrom<Vec<E::BaseField>> for Proof<E>
 where
     E: Pairing<G1Affine = Affine<P>>,
@@ -709,6 +768,21 @@ where
     }
 }
 
+impl<E, F> VerifyingKey<E>
+where
+    E: Pairing<BaseField = F>,
+    F: RescueParameter,
+{
+    /// Hash the canonical encoding of the key (see [`Self::hash()`]), packed
+    /// into base field elements, with the Rescue sponge. This variant is
+    /// cheaper to recompute inside a circuit over the base field, e.g. when
+    /// recursively verifying proofs.
+    pub fn hash_rescue(&self) -> Result<F, PlonkError> {
+        let elems: Vec<F> = bytes_to_field_elements(self.canonical_bytes()?);
+        Ok(RescueCRHF::<F>::sponge_with_bit_padding(&elems, 1)[0])
+    }
+}
+
 impl<E, F, P> VerifyingKey<E>
 where
     E: Pairing<BaseField = F, G1Affine = Affine<P>>,
@@ -752,7 +826,54 @@ pub struct PlookupVerifyingKey<E: Pairing> {
     pub(crate) q_dom_sep_comm: Commitment<E>,
 }
 
+/// The Keccak256 hash of a verifying key, see [`VerifyingKey::hash()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct VerifyingKeyHash(pub [u8; 32]);
+
+impl ark_std::fmt::Display for VerifyingKeyHash {
+    fn fmt(&self, f: &mut ark_std::fmt::Formatter<'_>) -> ark_std::fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl AsRef<[u8]> for VerifyingKeyHash {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 impl<E: Pairing> VerifyingKey<E> {
+    /// The canonical encoding of the key that is hashed by [`Self::hash()`]
+    /// and [`Self::hash_rescue()`]: the compressed serialization of every
+    /// field used by the verifier, i.e. all fields except the powers of the
+    /// SRS in the opening key, which depend on how the SRS was trimmed.
+    fn canonical_bytes(&self) -> Result<Vec<u8>, PlonkError> {
+        let mut bytes = Vec::new();
+        (self.domain_size as u64).serialize_compressed(&mut bytes)?;
+        (self.num_inputs as u64).serialize_compressed(&mut bytes)?;
+        self.sigma_comms.serialize_compressed(&mut bytes)?;
+        self.selector_comms.serialize_compressed(&mut bytes)?;
+        self.k.serialize_compressed(&mut bytes)?;
+        self.open_key.g.serialize_compressed(&mut bytes)?;
+        self.open_key.h.serialize_compressed(&mut bytes)?;
+        self.open_key.beta_h.serialize_compressed(&mut bytes)?;
+        self.is_merged.serialize_compressed(&mut bytes)?;
+        self.plookup_vk.serialize_compressed(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Hash the canonical encoding of the key with Keccak256.
+    ///
+    /// The hash does not depend on how the SRS was trimmed, and identifies
+    /// the circuit the key was preprocessed for, e.g. in a [`VkRegistry`].
+    ///
+    /// [`VkRegistry`]: crate::proof_system::registry::VkRegistry
+    pub fn hash(&self) -> Result<VerifyingKeyHash, PlonkError> {
+        Ok(VerifyingKeyHash(
+            Keccak256::digest(self.canonical_bytes()?).into(),
+        ))
+    }
+
     /// Create a dummy TurboPlonk verification key for a circuit with
     /// `num_inputs` public inputs and domain size `domain_size`.
     pub fn dummy(num_inputs: usize, domain_size: usize) -> Self {
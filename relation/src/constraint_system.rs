@@ -16,12 +16,27 @@ use ark_poly::{
     domain::Radix2EvaluationDomain, univariate::DensePolynomial, DenseUVPolynomial,
     EvaluationDomain,
 };
-use ark_std::{boxed::Box, cmp::max, format, string::ToString, vec, vec::Vec};
+use ark_std::{
+    boxed::Box,
+    cmp::max,
+    fmt, format,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
 use hashbrown::{HashMap, HashSet};
 use jf_utils::par_utils::parallelizable_slice_iter;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+mod description;
+mod public_inputs;
+mod scope;
+pub use description::CIRCUIT_FORMAT_VERSION;
+pub use public_inputs::{PublicInputLayout, PublicInputs};
+pub use scope::ScopeReport;
+
 /// An index to a gate in circuit.
 pub type GateId = usize;
 /// An index to the type of gate wires.
@@ -394,6 +409,109 @@ where
     /// For each inserted table, the 1st value is the start id of the table,
     /// the 2nd values is the length of the table.
     table_gate_ids: Vec<(GateId, usize)>,
+
+    /// The label of each gate, if any, for debugging purposes.
+    gate_labels: Vec<Option<Arc<str>>>,
+    /// The label of each range check, if any, for debugging purposes.
+    range_labels: Vec<Option<Arc<str>>>,
+    /// The label of the gates being created.
+    current_label: Option<Arc<str>>,
+    /// The labeled scopes, with the number of gates and variables created in
+    /// each of them.
+    scopes: scope::ScopeTree,
+}
+
+/// The kind of constraint violated by an [`UnsatisfiedGate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintKind {
+    /// An algebraic gate equation.
+    Gate,
+    /// An UltraPlonk range check.
+    Range,
+    /// An UltraPlonk lookup.
+    Lookup,
+}
+
+/// The first constraint of a circuit violated by its witness, see
+/// [`PlonkCircuit::find_unsatisfied_gate()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsatisfiedGate<F: Field> {
+    /// The kind of the violated constraint.
+    pub kind: ConstraintKind,
+    /// The index of the gate, or of the range check for
+    /// [`ConstraintKind::Range`].
+    pub gate_id: GateId,
+    /// The name of the gate.
+    pub gate_name: &'static str,
+    /// The label that was set when the gate was created, see
+    /// [`PlonkCircuit::set_label()`].
+    pub label: Option<String>,
+    /// The selector values of the gate, by name.
+    pub selectors: Vec<(&'static str, F)>,
+    /// The values of the wires of the gate.
+    pub wire_values: Vec<F>,
+    /// The public input of the gate, zero unless it is an IO gate.
+    pub public_input: F,
+}
+
+impl<F: Field> fmt::Display for UnsatisfiedGate<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} constraint #{} ({})",
+            self.kind, self.gate_id, self.gate_name
+        )?;
+        if let Some(label) = &self.label {
+            write!(f, " created in \"{label}\"")?;
+        }
+        write!(f, " failed with wire values [")?;
+        for (i, val) in self.wire_values.iter().enumerate() {
+            write!(f, "{}{val}", if i == 0 { "" } else { ", " })?;
+        }
+        write!(f, "], public input {}, selectors [", self.public_input)?;
+        let mut first = true;
+        for (name, val) in self.selectors.iter().filter(|(_, val)| !val.is_zero()) {
+            write!(f, "{}{name} = {val}", if first { "" } else { ", " })?;
+            first = false;
+        }
+        write!(f, "]")
+    }
+}
+
+/// The savings of [`PlonkCircuit::optimize()`], and the renumbering of the
+/// variables of the circuit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OptimizationReport {
+    /// The number of removed gates.
+    pub num_gates_removed: usize,
+    /// The number of removed variables.
+    pub num_vars_removed: usize,
+    // the variable replacing each variable of the circuit before the
+    // optimization, if any
+    new_vars: Vec<Option<Variable>>,
+}
+
+impl OptimizationReport {
+    /// Return the variable replacing `var`, a variable created before the
+    /// optimization, or `None` if it was removed. The variables enforced to a
+    /// same constant are replaced by a single one.
+    pub fn new_variable(&self, var: Variable) -> Option<Variable> {
+        self.new_vars.get(var).copied().flatten()
+    }
+
+    /// Return the variables replacing `vars`, see [`Self::new_variable()`].
+    /// Return error if any of them was removed.
+    pub fn new_variables(&self, vars: &[Variable]) -> Result<Vec<Variable>, CircuitError> {
+        vars.iter()
+            .map(|&var| {
+                self.new_variable(var).ok_or_else(|| {
+                    CircuitError::ParameterError(format!(
+                        "variable {var} was removed by the optimization"
+                    ))
+                })
+            })
+            .collect()
+    }
 }
 
 impl<F: FftField> Default for PlonkCircuit<F> {
@@ -428,6 +546,10 @@ impl<F: FftField> PlonkCircuit<F> {
             plonk_params,
             num_table_elems: 0,
             table_gate_ids: vec![],
+            gate_labels: vec![],
+            range_labels: vec![],
+            current_label: None,
+            scopes: scope::ScopeTree::default(),
         };
         // Constrain variables `0`/`1` to have value 0/1.
         circuit.enforce_constant(0, zero).unwrap(); // safe unwrap
@@ -466,9 +588,93 @@ impl<F: FftField> PlonkCircuit<F> {
         }
 
         self.gates.push(gate);
+        self.gate_labels.push(self.current_label.clone());
         Ok(())
     }
 
+    /// Label the gates created from now on with `label`, so that
+    /// [`Self::find_unsatisfied_gate()`] can tell which part of the circuit a
+    /// violated gate comes from.
+    pub fn set_label(&mut self, label: &str) {
+        self.current_label = Some(Arc::from(label));
+    }
+
+    /// Stop labeling the gates created from now on.
+    pub fn clear_label(&mut self) {
+        self.current_label = None;
+    }
+
+    /// Return the first constraint of the circuit that is not satisfied by
+    /// its witness and the public input `pub_input`, with the values needed
+    /// to debug it, or `None` if the circuit is satisfied.
+    ///
+    /// Gate equations are checked first, by increasing gate index, then the
+    /// UltraPlonk range checks and lookups. Return an error if `pub_input`
+    /// has the wrong length.
+    pub fn find_unsatisfied_gate(
+        &self,
+        pub_input: &[F],
+    ) -> Result<Option<UnsatisfiedGate<F>>, CircuitError> {
+        if pub_input.len() != self.num_inputs() {
+            return Err(PubInputLenMismatch(
+                pub_input.len(),
+                self.pub_input_gate_ids.len(),
+            ));
+        }
+        let mut gate_pub_input = vec![F::zero(); self.num_gates()];
+        for (&gate_id, &pi) in self.pub_input_gate_ids.iter().zip(pub_input) {
+            gate_pub_input[gate_id] = pi;
+        }
+        for (gate_id, pi) in gate_pub_input.iter().enumerate() {
+            match self.check_gate(gate_id, pi) {
+                Err(GateCheckFailure(..)) => {
+                    return Ok(Some(self.unsatisfied_gate(
+                        ConstraintKind::Gate,
+                        gate_id,
+                        *pi,
+                    )))
+                },
+                res => res?,
+            }
+        }
+        if self.plonk_params.plonk_type == PlonkType::UltraPlonk {
+            for idx in 0..self.wire_variables[RANGE_WIRE_ID].len() {
+                match self.check_range_gate(idx) {
+                    Err(GateCheckFailure(..)) => {
+                        return Ok(Some(UnsatisfiedGate {
+                            kind: ConstraintKind::Range,
+                            gate_id: idx,
+                            gate_name: "Range Check",
+                            label: self
+                                .range_labels
+                                .get(idx)
+                                .cloned()
+                                .flatten()
+                                .map(|l| l.to_string()),
+                            selectors: vec![],
+                            wire_values: vec![
+                                self.witness[self.wire_variables[RANGE_WIRE_ID][idx]],
+                            ],
+                            public_input: F::zero(),
+                        }))
+                    },
+                    res => res?,
+                }
+            }
+            match self.check_lookup_gates() {
+                Err(GateCheckFailure(gate_id, _)) => {
+                    return Ok(Some(self.unsatisfied_gate(
+                        ConstraintKind::Lookup,
+                        gate_id,
+                        F::zero(),
+                    )))
+                },
+                res => res?,
+            }
+        }
+        Ok(None)
+    }
+
     /// Add a range_check gate that checks whether a variable is in the range
     /// [0, range_size). Return an error if the circuit does not support
     /// lookup.
@@ -477,6 +683,7 @@ impl<F: FftField> PlonkCircuit<F> {
         self.check_finalize_flag(false)?;
         self.check_var_bound(var)?;
         self.wire_variables[RANGE_WIRE_ID].push(var);
+        self.range_labels.push(self.current_label.clone());
         Ok(())
     }
 
@@ -512,6 +719,16 @@ impl<F: FftField> PlonkCircuit<F> {
         &mut self.witness[idx]
     }
 
+    /// Get the values of all the variables.
+    pub(crate) fn witness_values(&self) -> &[F] {
+        &self.witness
+    }
+
+    /// Get the mutable reference of the values of all the variables.
+    pub(crate) fn witness_values_mut(&mut self) -> &mut [F] {
+        &mut self.witness
+    }
+
     /// Get the mutable reference of the inserted table ids.
     pub(crate) fn table_gate_ids_mut(&mut self) -> &mut Vec<(GateId, usize)> {
         &mut self.table_gate_ids
@@ -608,43 +825,7 @@ impl<F: FftField> Circuit<F> for PlonkCircuit<F> {
                 self.check_range_gate(idx)?
             }
             // key-value map lookup gates
-            let mut key_val_table = HashSet::new();
-            key_val_table.insert((F::zero(), F::zero(), F::zero(), F::zero()));
-            let q_lookup_vec = self.q_lookup();
-            let q_dom_sep_vec = self.q_dom_sep();
-            let table_key_vec = self.table_key_vec();
-            let table_dom_sep_vec = self.table_dom_sep_vec();
-            // insert table elements
-            for (gate_id, ((&q_lookup, &table_dom_sep), &table_key)) in q_lookup_vec
-                .iter()
-                .zip(table_dom_sep_vec.iter())
-                .zip(table_key_vec.iter())
-                .enumerate()
-            {
-                if q_lookup != F::zero() {
-                    let val0 = self.witness(self.wire_variable(TABLE_VAL_1_WIRE_ID, gate_id))?;
-                    let val1 = self.witness(self.wire_variable(TABLE_VAL_2_WIRE_ID, gate_id))?;
-                    key_val_table.insert((table_dom_sep, table_key, val0, val1));
-                }
-            }
-            // check lookups
-            for (gate_id, (&q_lookup, &q_dom_sep)) in
-                q_lookup_vec.iter().zip(q_dom_sep_vec.iter()).enumerate()
-            {
-                if q_lookup != F::zero() {
-                    let key = self.witness(self.wire_variable(LOOKUP_KEY_WIRE_ID, gate_id))?;
-                    let val0 = self.witness(self.wire_variable(LOOKUP_VAL_1_WIRE_ID, gate_id))?;
-                    let val1 = self.witness(self.wire_variable(LOOKUP_VAL_2_WIRE_ID, gate_id))?;
-                    if !key_val_table.contains(&(q_dom_sep, key, val0, val1)) {
-                        return Err(GateCheckFailure(
-                            gate_id,
-                            format!(
-                                "Lookup gate failed: ({q_dom_sep}, {key}, {val0}, {val1}) not in the table",
-                            ),
-                        ));
-                    }
-                }
-            }
+            self.check_lookup_gates()?;
         }
         Ok(())
     }
@@ -818,6 +999,89 @@ impl<F: FftField> PlonkCircuit<F> {
         Ok(())
     }
 
+    /// Check that all the UltraPlonk lookups are in the lookup tables.
+    fn check_lookup_gates(&self) -> Result<(), CircuitError> {
+        let mut key_val_table = HashSet::new();
+        key_val_table.insert((F::zero(), F::zero(), F::zero(), F::zero()));
+        let q_lookup_vec = self.q_lookup();
+        let q_dom_sep_vec = self.q_dom_sep();
+        let table_key_vec = self.table_key_vec();
+        let table_dom_sep_vec = self.table_dom_sep_vec();
+        // insert table elements
+        for (gate_id, ((&q_lookup, &table_dom_sep), &table_key)) in q_lookup_vec
+            .iter()
+            .zip(table_dom_sep_vec.iter())
+            .zip(table_key_vec.iter())
+            .enumerate()
+        {
+            if q_lookup != F::zero() {
+                let val0 = self.witness(self.wire_variable(TABLE_VAL_1_WIRE_ID, gate_id))?;
+                let val1 = self.witness(self.wire_variable(TABLE_VAL_2_WIRE_ID, gate_id))?;
+                key_val_table.insert((table_dom_sep, table_key, val0, val1));
+            }
+        }
+        // check lookups
+        for (gate_id, (&q_lookup, &q_dom_sep)) in
+            q_lookup_vec.iter().zip(q_dom_sep_vec.iter()).enumerate()
+        {
+            if q_lookup != F::zero() {
+                let key = self.witness(self.wire_variable(LOOKUP_KEY_WIRE_ID, gate_id))?;
+                let val0 = self.witness(self.wire_variable(LOOKUP_VAL_1_WIRE_ID, gate_id))?;
+                let val1 = self.witness(self.wire_variable(LOOKUP_VAL_2_WIRE_ID, gate_id))?;
+                if !key_val_table.contains(&(q_dom_sep, key, val0, val1)) {
+                    return Err(GateCheckFailure(
+                        gate_id,
+                        format!(
+                            "Lookup gate failed: ({q_dom_sep}, {key}, {val0}, {val1}) not in the table",
+                        ),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Collect the values of the `gate_id`-th gate that violates a constraint
+    /// of kind `kind`.
+    fn unsatisfied_gate(
+        &self,
+        kind: ConstraintKind,
+        gate_id: GateId,
+        public_input: F,
+    ) -> UnsatisfiedGate<F> {
+        let gate = &self.gates[gate_id];
+        let (q_lc, q_mul, q_hash) = (gate.q_lc(), gate.q_mul(), gate.q_hash());
+        UnsatisfiedGate {
+            kind,
+            gate_id,
+            gate_name: gate.name(),
+            label: self.gate_labels[gate_id].as_ref().map(|l| l.to_string()),
+            selectors: vec![
+                ("q_lc0", q_lc[0]),
+                ("q_lc1", q_lc[1]),
+                ("q_lc2", q_lc[2]),
+                ("q_lc3", q_lc[3]),
+                ("q_mul0", q_mul[0]),
+                ("q_mul1", q_mul[1]),
+                ("q_hash0", q_hash[0]),
+                ("q_hash1", q_hash[1]),
+                ("q_hash2", q_hash[2]),
+                ("q_hash3", q_hash[3]),
+                ("q_o", gate.q_o()),
+                ("q_c", gate.q_c()),
+                ("q_ecc", gate.q_ecc()),
+                ("q_lookup", gate.q_lookup()),
+                ("q_dom_sep", gate.q_dom_sep()),
+                ("table_key", gate.table_key()),
+                ("table_dom_sep", gate.table_dom_sep()),
+            ],
+            wire_values: (0..GATE_WIDTH + 1)
+                .map(|i| self.witness[self.wire_variables[i][gate_id]])
+                .collect(),
+            public_input,
+        }
+    }
+
     fn is_finalized(&self) -> bool {
         self.eval_domain.size() != 1
     }
@@ -834,6 +1098,7 @@ impl<F: FftField> PlonkCircuit<F> {
             if *io_gate_id > gate_id {
                 // Swap gate types
                 self.gates.swap(gate_id, *io_gate_id);
+                self.gate_labels.swap(gate_id, *io_gate_id);
                 // Swap wire variables
                 for i in 0..GATE_WIDTH + 1 {
                     self.wire_variables[i].swap(gate_id, *io_gate_id);
@@ -853,6 +1118,7 @@ impl<F: FftField> PlonkCircuit<F> {
                     if gate_id < cur_gate_id {
                         // Swap gate types
                         self.gates.swap(gate_id, cur_gate_id);
+                        self.gate_labels.swap(gate_id, cur_gate_id);
                         // Swap wire variables
                         for j in 0..GATE_WIDTH + 1 {
                             self.wire_variables[j].swap(gate_id, cur_gate_id);
@@ -877,6 +1143,7 @@ impl<F: FftField> PlonkCircuit<F> {
         for _ in self.num_gates()..n {
             self.gates.push(Box::new(PaddingGate));
         }
+        self.gate_labels.resize(n, None);
         for wire_id in 0..self.num_wire_types() {
             self.wire_variables[wire_id].resize(n, self.zero());
         }
@@ -925,8 +1192,9 @@ impl<F: FftField> PlonkCircuit<F> {
                 GateCheckFailure(
                     gate_id,
                     format!(
-                        "gate: {:?}, wire values: {:?}, pub_input: {}, expected_gate_output: {}, gate_output: {}",
+                        "gate: {:?}{}, wire values: {:?}, pub_input: {}, expected_gate_output: {}, gate_output: {}",
                         self.gates[gate_id],
+                        self.gate_labels[gate_id].as_ref().map_or_else(String::new, |l| format!(" (label: {l})")),
                         w_vals,
                         pub_input,
                         expected_gate_output,
@@ -1161,6 +1429,275 @@ impl<F: PrimeField> PlonkCircuit<F> {
     }
 }
 
+/// Methods for optimizing the circuit.
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Optimize the circuit before finalizing it, and return the number of
+    /// gates and variables saved:
+    /// 1. the variables enforced to a same constant are merged, and the
+    ///    constant wires of the arithmetic gates are folded into their
+    ///    selectors, so that gates over constants only are removed;
+    /// 2. duplicate arithmetic gates, i.e. with the same selectors and wires,
+    ///    are removed;
+    /// 3. the variables that are not referenced by any gate any more are
+    ///    removed, together with the gates only enforcing them to a constant.
+    ///
+    /// IO and lookup gates are left untouched, and a satisfied circuit remains
+    /// satisfied with the same public input. The scopes and labels of the
+    /// gates are kept up to date, but the variables are renumbered: the
+    /// variables created before the optimization must be translated with
+    /// [`OptimizationReport::new_variable()`], and the memories holding
+    /// variables updated with
+    /// [`Ram::renumber_variables()`](crate::gadgets::ultraplonk::ram::Ram::renumber_variables)
+    /// and
+    /// [`Rom::renumber_variables()`](crate::gadgets::ultraplonk::rom::Rom::renumber_variables).
+    /// The variables not referenced by any gate yet are removed, hence the
+    /// circuit should be optimized once complete.
+    /// Return error if the circuit is already finalized.
+    pub fn optimize(&mut self) -> Result<OptimizationReport, CircuitError> {
+        self.check_finalize_flag(false)?;
+        // a pass may turn gates into constant gates, hence repeat until nothing
+        // is removed
+        let mut report = OptimizationReport {
+            new_vars: (0..self.num_vars).map(Some).collect(),
+            ..Default::default()
+        };
+        loop {
+            let pass_report = self.optimize_once();
+            for new_var in report.new_vars.iter_mut() {
+                *new_var = new_var.and_then(|var| pass_report.new_vars[var]);
+            }
+            if pass_report.num_gates_removed == 0 && pass_report.num_vars_removed == 0 {
+                return Ok(report);
+            }
+            report.num_gates_removed += pass_report.num_gates_removed;
+            report.num_vars_removed += pass_report.num_vars_removed;
+        }
+    }
+
+    // A single pass of `Self::optimize()`.
+    fn optimize_once(&mut self) -> OptimizationReport {
+        let num_gates = self.num_gates();
+        let num_vars = self.num_vars;
+        // whether the gates are arithmetic gates, i.e. neither IO nor lookup
+        // gates
+        let mut is_arith = vec![true; num_gates];
+        for &gate_id in self.pub_input_gate_ids.iter() {
+            is_arith[gate_id] = false;
+        }
+        for &(start, len) in self.table_gate_ids.iter() {
+            is_arith[start..start + len].fill(false);
+        }
+
+        // The first gate enforcing a variable to a constant defines it, and
+        // the variables of a same constant are replaced by the first one.
+        let mut constants: HashMap<Variable, F> = HashMap::new();
+        let mut canonical_vars: HashMap<F, Variable> = HashMap::new();
+        let mut substitutes: HashMap<Variable, Variable> = HashMap::new();
+        let mut constant_gate_ids = HashSet::new();
+        for gate_id in (0..num_gates).filter(|&gate_id| is_arith[gate_id]) {
+            let constant = match self.enforced_constant(gate_id) {
+                Some(constant) => constant,
+                None => continue,
+            };
+            let var = self.wire_variables[GATE_WIDTH][gate_id];
+            if constants.contains_key(&var) {
+                continue;
+            }
+            constants.insert(var, constant);
+            match canonical_vars.get(&constant) {
+                Some(&canonical_var) => {
+                    substitutes.insert(var, canonical_var);
+                },
+                None => {
+                    canonical_vars.insert(constant, var);
+                    constant_gate_ids.insert(gate_id);
+                },
+            }
+        }
+        for wire_vars in self.wire_variables.iter_mut() {
+            for var in wire_vars.iter_mut() {
+                if let Some(&canonical_var) = substitutes.get(var) {
+                    *var = canonical_var;
+                }
+            }
+        }
+
+        // Fold the constant wires, and remove the gates that are trivially
+        // satisfied and the duplicate gates.
+        let mut keep = vec![true; num_gates];
+        let mut gates_seen = HashSet::new();
+        for gate_id in (0..num_gates).filter(|&gate_id| is_arith[gate_id]) {
+            if constant_gate_ids.contains(&gate_id) {
+                for wire_vars in self.wire_variables.iter_mut().take(GATE_WIDTH) {
+                    wire_vars[gate_id] = 0;
+                }
+            } else if self.gates[gate_id].q_ecc().is_zero() {
+                keep[gate_id] = self.fold_constants(gate_id, &constants);
+            }
+            if keep[gate_id] {
+                let gate = &self.gates[gate_id];
+                let mut selectors = gate.q_lc().to_vec();
+                selectors.extend_from_slice(&gate.q_mul());
+                selectors.extend_from_slice(&gate.q_hash());
+                selectors.extend_from_slice(&[gate.q_o(), gate.q_c(), gate.q_ecc()]);
+                let wire_vars: Vec<Variable> = (0..GATE_WIDTH + 1)
+                    .map(|i| self.wire_variables[i][gate_id])
+                    .collect();
+                keep[gate_id] = gates_seen.insert((selectors, wire_vars));
+            }
+        }
+
+        // Remove the gates defining constants that are not used any more.
+        let mut num_refs = vec![0usize; num_vars];
+        for (i, wire_vars) in self.wire_variables.iter().enumerate() {
+            for (gate_id, &var) in wire_vars.iter().enumerate() {
+                if i == RANGE_WIRE_ID || keep[gate_id] {
+                    num_refs[var] += 1;
+                }
+            }
+        }
+        for &gate_id in constant_gate_ids.iter() {
+            let var = self.wire_variables[GATE_WIDTH][gate_id];
+            if var > 1 && keep[gate_id] && num_refs[var] == 1 {
+                keep[gate_id] = false;
+                num_refs[var] = 0;
+            }
+        }
+
+        // Compact the gates, `new_gate_ids[i]` being the number of gates kept
+        // before the `i`-th one.
+        let mut new_gate_ids = Vec::with_capacity(num_gates + 1);
+        let mut num_kept = 0;
+        for &kept in keep.iter() {
+            new_gate_ids.push(num_kept);
+            num_kept += kept as usize;
+        }
+        new_gate_ids.push(num_kept);
+        let mut keep_iter = keep.iter();
+        self.gates.retain(|_| *keep_iter.next().unwrap()); // safe unwrap
+        let mut keep_iter = keep.iter();
+        self.gate_labels.retain(|_| *keep_iter.next().unwrap()); // safe unwrap
+        for wire_vars in self.wire_variables.iter_mut().take(GATE_WIDTH + 1) {
+            let mut keep_iter = keep.iter();
+            wire_vars.retain(|_| *keep_iter.next().unwrap()); // safe unwrap
+        }
+        for gate_id in self.pub_input_gate_ids.iter_mut() {
+            *gate_id = new_gate_ids[*gate_id];
+        }
+        for (start, _) in self.table_gate_ids.iter_mut() {
+            *start = new_gate_ids[*start];
+        }
+
+        // Compact the variables, keeping the constant variables `0` and `1`,
+        // `new_vars[i]` being the number of variables kept before the `i`-th
+        // one.
+        num_refs[0] += 1;
+        num_refs[1] += 1;
+        let mut new_vars = Vec::with_capacity(num_vars + 1);
+        let mut witness = Vec::with_capacity(num_vars);
+        for (var, &n) in num_refs.iter().enumerate() {
+            new_vars.push(witness.len());
+            if n > 0 {
+                witness.push(self.witness[var]);
+            }
+        }
+        new_vars.push(witness.len());
+        for wire_vars in self.wire_variables.iter_mut() {
+            for var in wire_vars.iter_mut() {
+                *var = new_vars[*var];
+            }
+        }
+        self.num_vars = witness.len();
+        self.witness = witness;
+        self.scopes.renumber(&new_gate_ids, &new_vars);
+
+        OptimizationReport {
+            num_gates_removed: num_gates - self.num_gates(),
+            num_vars_removed: num_vars - self.num_vars,
+            new_vars: (0..num_vars)
+                .map(|var| {
+                    let var = substitutes.get(&var).copied().unwrap_or(var);
+                    (num_refs[var] > 0).then(|| new_vars[var])
+                })
+                .collect(),
+        }
+    }
+
+    // Return the constant `c` if the `gate_id`-th gate only enforces its output
+    // wire to be `c`.
+    fn enforced_constant(&self, gate_id: GateId) -> Option<F> {
+        let gate = &self.gates[gate_id];
+        let only_output = gate.q_lc().iter().all(|q| q.is_zero())
+            && gate.q_mul().iter().all(|q| q.is_zero())
+            && gate.q_hash().iter().all(|q| q.is_zero())
+            && gate.q_ecc().is_zero();
+        match only_output {
+            true => gate.q_o().inverse().map(|q_o_inv| gate.q_c() * q_o_inv),
+            false => None,
+        }
+    }
+
+    // Fold the wires of the `gate_id`-th gate holding the `constants` into its
+    // selectors, and set the wires not used by the gate equation to `0`.
+    // Return false if the gate becomes trivially satisfied.
+    fn fold_constants(&mut self, gate_id: GateId, constants: &HashMap<Variable, F>) -> bool {
+        let gate = &self.gates[gate_id];
+        let (mut q_lc, mut q_mul, mut q_hash) = (gate.q_lc(), gate.q_mul(), gate.q_hash());
+        let (mut q_o, mut q_c) = (gate.q_o(), gate.q_c());
+        let wire_consts: Vec<Option<F>> = (0..GATE_WIDTH + 1)
+            .map(|i| constants.get(&self.wire_variables[i][gate_id]).copied())
+            .collect();
+        let mut folded = false;
+        for (i, q) in q_mul.iter_mut().enumerate() {
+            let (a, b) = (2 * i, 2 * i + 1);
+            match (wire_consts[a], wire_consts[b]) {
+                (Some(val_a), Some(val_b)) => q_c += *q * val_a * val_b,
+                (Some(val_a), None) => q_lc[b] += *q * val_a,
+                (None, Some(val_b)) => q_lc[a] += *q * val_b,
+                (None, None) => continue,
+            }
+            folded |= !q.is_zero();
+            *q = F::zero();
+        }
+        for i in 0..GATE_WIDTH {
+            if let Some(val) = wire_consts[i] {
+                folded |= !q_lc[i].is_zero() || !q_hash[i].is_zero();
+                q_c += q_lc[i] * val + q_hash[i] * val.pow([5]);
+                q_lc[i] = F::zero();
+                q_hash[i] = F::zero();
+            }
+        }
+        if let Some(val) = wire_consts[GATE_WIDTH] {
+            folded |= !q_o.is_zero();
+            q_c -= q_o * val;
+            q_o = F::zero();
+        }
+
+        for i in 0..GATE_WIDTH {
+            if q_lc[i].is_zero() && q_hash[i].is_zero() && q_mul[i / 2].is_zero() {
+                self.wire_variables[i][gate_id] = 0;
+            }
+        }
+        if q_o.is_zero() {
+            self.wire_variables[GATE_WIDTH][gate_id] = 0;
+        }
+        if folded {
+            self.gates[gate_id] = Box::new(FoldedGate {
+                q_lc,
+                q_mul,
+                q_hash,
+                q_o,
+                q_c,
+            });
+        }
+        !(q_c.is_zero()
+            && q_o.is_zero()
+            && q_lc.iter().all(|q| q.is_zero())
+            && q_mul.iter().all(|q| q.is_zero())
+            && q_hash.iter().all(|q| q.is_zero()))
+    }
+}
+
 /// Methods for finalizing and merging the circuits.
 impl<F: PrimeField> PlonkCircuit<F> {
     /// Finalize the setup of the circuit before arithmetization.
@@ -1205,6 +1742,7 @@ impl<F: PrimeField> PlonkCircuit<F> {
         for _ in 0..n {
             self.gates.push(Box::new(PaddingGate));
         }
+        self.gate_labels.resize(2 * n, None);
         for wire_id in 0..self.num_wire_types() {
             self.wire_variables[wire_id].resize(2 * n, self.zero());
         }
@@ -1220,6 +1758,7 @@ impl<F: PrimeField> PlonkCircuit<F> {
         } else {
             // reverse the gate indices.
             self.gates.reverse();
+            self.gate_labels.reverse();
             for wire_id in 0..self.num_wire_types() {
                 self.wire_variables[wire_id].reverse();
             }
@@ -1316,6 +1855,12 @@ impl<F: PrimeField> PlonkCircuit<F> {
             }
         }
 
+        let gate_labels = self.gate_labels[..n]
+            .iter()
+            .chain(other.gate_labels[n..].iter())
+            .cloned()
+            .collect();
+
         // merge wire_permutation
         let mut wire_permutation = vec![(0usize, 0usize); self.num_wire_types * 2 * n];
         for i in 0..self.num_wire_types {
@@ -1338,6 +1883,10 @@ impl<F: PrimeField> PlonkCircuit<F> {
             plonk_params: self.plonk_params,
             num_table_elems: 0,
             table_gate_ids: vec![],
+            gate_labels,
+            range_labels: vec![],
+            current_label: None,
+            scopes: scope::ScopeTree::default(),
         })
     }
 }
@@ -1689,7 +2238,7 @@ impl<F: PrimeField> PlonkCircuit<F> {
 
 #[cfg(test)]
 pub(crate) mod test {
-    use super::{Arithmetization, Circuit, PlonkCircuit};
+    use super::{Arithmetization, Circuit, ConstraintKind, PlonkCircuit};
     use crate::{constants::compute_coset_representatives, CircuitError};
     use ark_bls12_377::Fq as Fq377;
     use ark_ed_on_bls12_377::Fq as FqEd377;
@@ -1697,7 +2246,7 @@ pub(crate) mod test {
     use ark_ed_on_bn254::Fq as FqEd254;
     use ark_ff::PrimeField;
     use ark_poly::{domain::Radix2EvaluationDomain, univariate::DensePolynomial, EvaluationDomain};
-    use ark_std::{vec, vec::Vec};
+    use ark_std::{string::ToString, vec, vec::Vec};
     use jf_utils::test_rng;
 
     #[test]
@@ -1749,6 +2298,85 @@ pub(crate) mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_find_unsatisfied_gate() -> Result<(), CircuitError> {
+        test_find_unsatisfied_gate_helper::<FqEd254>()?;
+        test_find_unsatisfied_gate_helper::<FqEd377>()?;
+        test_find_unsatisfied_gate_helper::<FqEd381>()?;
+        test_find_unsatisfied_gate_helper::<Fq377>()
+    }
+
+    fn test_find_unsatisfied_gate_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        let a = circuit.create_variable(F::from(3u32))?;
+        let b = circuit.create_variable(F::from(1u32))?;
+        circuit.set_label("adder");
+        let c = circuit.add(a, b)?;
+        circuit.clear_label();
+        let d = circuit.mul(c, c)?;
+        let e = circuit.create_public_variable(F::from(16u32))?;
+        circuit.enforce_equal(d, e)?;
+
+        assert_eq!(circuit.find_unsatisfied_gate(&[F::from(16u32)])?, None);
+        assert!(circuit.find_unsatisfied_gate(&[]).is_err());
+        // the IO gate is violated by a wrong public input
+        let violation = circuit.find_unsatisfied_gate(&[F::from(0u32)])?.unwrap();
+        assert_eq!(violation.kind, ConstraintKind::Gate);
+        assert_eq!(violation.gate_id, 4);
+        assert_eq!(violation.label, None);
+        assert_eq!(violation.public_input, F::from(0u32));
+
+        // the first violated gate is reported, with its label
+        *circuit.witness_mut(c) = F::from(5u32);
+        let violation = circuit.find_unsatisfied_gate(&[F::from(16u32)])?.unwrap();
+        assert_eq!(violation.gate_id, 2);
+        assert_eq!(violation.label.as_deref(), Some("adder"));
+        assert_eq!(
+            violation.wire_values,
+            vec![
+                F::from(3u32),
+                F::from(1u32),
+                F::from(0u32),
+                F::from(0u32),
+                F::from(5u32)
+            ]
+        );
+        assert!(violation.selectors.contains(&("q_lc0", F::from(1u32))));
+        assert!(violation.to_string().contains("adder"));
+        let err = circuit
+            .check_circuit_satisfiability(&[F::from(16u32)])
+            .unwrap_err();
+        assert!(err.to_string().contains("adder"));
+
+        // labels follow the gates when they are rearranged
+        circuit.finalize_for_arithmetization()?;
+        let violation = circuit.find_unsatisfied_gate(&[F::from(16u32)])?.unwrap();
+        assert_eq!(violation.label.as_deref(), Some("adder"));
+        assert_eq!(violation.wire_values[4], F::from(5u32));
+
+        // range checks and lookups
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(4);
+        circuit.set_label("range");
+        let a = circuit.create_variable(F::from(20u32))?;
+        circuit.add_range_check_variable(a)?;
+        circuit.set_label("lookup");
+        let b = circuit.create_variable(F::from(7u32))?;
+        let c = circuit.create_variable(F::from(8u32))?;
+        circuit.create_table_and_lookup_variables(&[(circuit.zero(), c, b)], &[(b, c)])?;
+        let violation = circuit.find_unsatisfied_gate(&[])?.unwrap();
+        assert_eq!(violation.kind, ConstraintKind::Range);
+        assert_eq!(violation.label.as_deref(), Some("range"));
+        assert_eq!(violation.wire_values, vec![F::from(20u32)]);
+        *circuit.witness_mut(a) = F::from(15u32);
+        let violation = circuit.find_unsatisfied_gate(&[])?.unwrap();
+        assert_eq!(violation.kind, ConstraintKind::Lookup);
+        assert_eq!(violation.label.as_deref(), Some("lookup"));
+        *circuit.witness_mut(c) = F::from(7u32);
+        assert_eq!(circuit.find_unsatisfied_gate(&[])?, None);
+
+        Ok(())
+    }
+
     #[test]
     fn test_add() -> Result<(), CircuitError> {
         test_add_helper::<FqEd254>()?;
@@ -2125,6 +2753,93 @@ pub(crate) mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_optimize() -> Result<(), CircuitError> {
+        test_optimize_helper::<FqEd254>()?;
+        test_optimize_helper::<FqEd377>()?;
+        test_optimize_helper::<FqEd381>()?;
+        test_optimize_helper::<Fq377>()
+    }
+
+    fn test_optimize_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        let a = circuit.create_variable(F::from(5u32))?;
+        let p = circuit.create_public_variable(F::from(2u32))?;
+        let c1 = circuit.create_constant_variable(F::from(3u32))?;
+        let c2 = circuit.create_constant_variable(F::from(3u32))?;
+        circuit.create_constant_variable(F::zero())?;
+        circuit.create_variable(F::from(7u32))?;
+        let x = circuit.add(a, c1)?;
+        let y = circuit.add(a, c2)?;
+        circuit.enforce_equal(x, y)?;
+        circuit.enforce_equal(x, y)?;
+        circuit.add(c1, c2)?;
+        circuit.add(p, c1)?;
+        circuit.mul(x, y)?;
+        assert_eq!(circuit.num_gates(), 13);
+        assert_eq!(circuit.num_vars(), 13);
+        let num_vars = circuit.num_vars();
+
+        // The duplicate constant gates, the duplicate equality gate, and the
+        // constant gates of `c1` and `c1 + c2` after folding are removed, as
+        // well as their variables, `c2`, the duplicate zero and the unused
+        // variable.
+        let report = circuit.optimize()?;
+        assert_eq!(report.num_gates_removed, 5);
+        assert_eq!(report.num_vars_removed, 5);
+        assert_eq!(circuit.num_gates(), 8);
+        assert_eq!(circuit.num_vars(), 8);
+        // the variables are renumbered
+        let new_vars = report.new_variables(&[a, p, x, y])?;
+        for (new_var, val) in new_vars.into_iter().zip([5u32, 2, 8, 8]) {
+            assert_eq!(circuit.witness(new_var)?, F::from(val));
+        }
+        // `c2` is merged into `c1`
+        assert_eq!(report.new_variable(c2), report.new_variable(c1));
+        assert!(report.new_variables(&[a, num_vars]).is_err());
+        assert_eq!(circuit.num_inputs(), 1);
+        assert!(circuit
+            .check_circuit_satisfiability(&[F::from(2u32)])
+            .is_ok());
+        assert!(circuit
+            .check_circuit_satisfiability(&[F::from(3u32)])
+            .is_err());
+        // nothing left to optimize
+        let report = circuit.optimize()?;
+        assert_eq!((report.num_gates_removed, report.num_vars_removed), (0, 0));
+        assert_eq!(report.new_variable(3), Some(3));
+        circuit.finalize_for_arithmetization()?;
+        assert!(circuit.optimize().is_err());
+        test_arithmetization_for_circuit(circuit, vec![F::from(2u32)])?;
+
+        // conflicting constants remain unsatisfiable
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        let a = circuit.create_variable(F::from(5u32))?;
+        circuit.enforce_constant(a, F::from(5u32))?;
+        circuit.enforce_constant(a, F::from(4u32))?;
+        let b = circuit.create_constant_variable(F::from(4u32))?;
+        circuit.add(a, b)?;
+        circuit.optimize()?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // lookup and range gates are kept
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(4);
+        let a = circuit.create_variable(F::from(9u32))?;
+        circuit.add_range_check_variable(a)?;
+        let c1 = circuit.create_constant_variable(F::from(9u32))?;
+        let c2 = circuit.create_constant_variable(F::from(9u32))?;
+        let c3 = circuit.create_constant_variable(F::from(9u32))?;
+        circuit.create_table_and_lookup_variables(&[(circuit.zero(), a, c1)], &[(c2, c3)])?;
+        circuit.enforce_equal(a, c1)?;
+        let report = circuit.optimize()?;
+        assert_eq!(report.num_gates_removed, 2);
+        assert_eq!(report.num_vars_removed, 2);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        circuit.finalize_for_arithmetization()?;
+        test_arithmetization_for_lookup_circuit(&circuit)?;
+        test_arithmetization_for_circuit(circuit, vec![])
+    }
+
     // Test flags
     //
 
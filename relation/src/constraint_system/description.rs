@@ -0,0 +1,393 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Serialization of the description of a circuit, without its witness.
+
+use super::{GateId, PlonkCircuit, PlonkParams, PlonkType, Variable, WireId};
+use crate::{
+    constants::{GATE_WIDTH, N_MUL_SELECTORS},
+    gates::{Gate, IoGate, PaddingGate},
+    CircuitError::{self, ParameterError},
+};
+use ark_ff::{FftField, Field};
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Read, Write};
+use ark_std::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+
+/// The magic bytes starting a serialized circuit description.
+const CIRCUIT_MAGIC: [u8; 4] = *b"JFPC";
+
+/// The version of the serialization format of circuit descriptions.
+pub const CIRCUIT_FORMAT_VERSION: u8 = 1;
+
+// Gate kinds, IO and padding gates are told apart from the others as the
+// constraint system handles them specifically.
+const GENERIC_GATE: u8 = 0;
+const IO_GATE: u8 = 1;
+const PADDING_GATE: u8 = 2;
+
+/// A gate loaded from a circuit description, defined by its selectors.
+#[derive(Debug, Clone)]
+struct LoadedGate<F: Field> {
+    q_lc: [F; GATE_WIDTH],
+    q_mul: [F; N_MUL_SELECTORS],
+    q_hash: [F; GATE_WIDTH],
+    q_o: F,
+    q_c: F,
+    q_ecc: F,
+    q_lookup: F,
+    q_dom_sep: F,
+    table_key: F,
+    table_dom_sep: F,
+}
+
+impl<F: Field> Gate<F> for LoadedGate<F> {
+    fn name(&self) -> &'static str {
+        "Loaded Gate"
+    }
+    fn q_lc(&self) -> [F; GATE_WIDTH] {
+        self.q_lc
+    }
+    fn q_hash(&self) -> [F; GATE_WIDTH] {
+        self.q_hash
+    }
+    fn q_mul(&self) -> [F; N_MUL_SELECTORS] {
+        self.q_mul
+    }
+    fn q_ecc(&self) -> F {
+        self.q_ecc
+    }
+    fn q_c(&self) -> F {
+        self.q_c
+    }
+    fn q_o(&self) -> F {
+        self.q_o
+    }
+    fn q_lookup(&self) -> F {
+        self.q_lookup
+    }
+    fn q_dom_sep(&self) -> F {
+        self.q_dom_sep
+    }
+    fn table_key(&self) -> F {
+        self.table_key
+    }
+    fn table_dom_sep(&self) -> F {
+        self.table_dom_sep
+    }
+}
+
+/// The serialized fields of a [`PlonkCircuit`].
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+struct CircuitDescription<F: Field> {
+    is_ultra_plonk: bool,
+    range_bit_len: Option<usize>,
+    num_vars: usize,
+    eval_domain_size: usize,
+    // the kind of each gate, with its selectors for generic gates
+    gates: Vec<(u8, Vec<F>)>,
+    wire_variables: Vec<Vec<Variable>>,
+    pub_input_gate_ids: Vec<GateId>,
+    wire_permutation: Vec<(WireId, GateId)>,
+    extended_id_permutation: Vec<F>,
+    num_table_elems: usize,
+    table_gate_ids: Vec<(GateId, usize)>,
+    gate_labels: Vec<Option<String>>,
+    range_labels: Vec<Option<String>>,
+}
+
+impl<F: FftField> PlonkCircuit<F> {
+    /// Serialize the description of the circuit: its gates, wiring, public
+    /// inputs and lookup tables, but not its witness. The circuit can be
+    /// finalized or not.
+    ///
+    /// The format is versioned, see [`CIRCUIT_FORMAT_VERSION`], so that a
+    /// circuit compiled once can be loaded with [`Self::load()`] without
+    /// running the gadget code again.
+    pub fn save<W: Write>(&self, mut writer: W) -> Result<(), CircuitError> {
+        let gates = self
+            .gates
+            .iter()
+            .map(|gate| {
+                if gate.as_any().is::<IoGate>() {
+                    (IO_GATE, vec![])
+                } else if gate.as_any().is::<PaddingGate>() {
+                    (PADDING_GATE, vec![])
+                } else {
+                    let selectors = gate
+                        .q_lc()
+                        .into_iter()
+                        .chain(gate.q_mul())
+                        .chain(gate.q_hash())
+                        .chain([
+                            gate.q_o(),
+                            gate.q_c(),
+                            gate.q_ecc(),
+                            gate.q_lookup(),
+                            gate.q_dom_sep(),
+                            gate.table_key(),
+                            gate.table_dom_sep(),
+                        ])
+                        .collect();
+                    (GENERIC_GATE, selectors)
+                }
+            })
+            .collect();
+        let labels = |labels: &[Option<Arc<str>>]| {
+            labels
+                .iter()
+                .map(|label| label.as_ref().map(|l| l.to_string()))
+                .collect()
+        };
+        let description = CircuitDescription {
+            is_ultra_plonk: self.plonk_params.plonk_type == PlonkType::UltraPlonk,
+            range_bit_len: self.plonk_params.range_bit_len,
+            num_vars: self.num_vars,
+            eval_domain_size: self.eval_domain.size(),
+            gates,
+            wire_variables: self.wire_variables.to_vec(),
+            pub_input_gate_ids: self.pub_input_gate_ids.clone(),
+            wire_permutation: self.wire_permutation.clone(),
+            extended_id_permutation: self.extended_id_permutation.clone(),
+            num_table_elems: self.num_table_elems,
+            table_gate_ids: self.table_gate_ids.clone(),
+            gate_labels: labels(&self.gate_labels),
+            range_labels: labels(&self.range_labels),
+        };
+        writer
+            .write_all(&CIRCUIT_MAGIC)
+            .and_then(|_| writer.write_all(&[CIRCUIT_FORMAT_VERSION]))
+            .map_err(|e| ParameterError(format!("cannot write the circuit: {e}")))?;
+        description
+            .serialize_compressed(writer)
+            .map_err(|e| ParameterError(format!("cannot write the circuit: {e}")))
+    }
+
+    /// Load a circuit saved with [`Self::save()`].
+    ///
+    /// The witness of the loaded circuit is zero, except for the constant
+    /// variables `0` and `1`: assign it with
+    /// [`Self::load_witness_assignment()`] before proving.
+    pub fn load<R: Read>(mut reader: R) -> Result<Self, CircuitError> {
+        let invalid = |msg: &str| ParameterError(format!("invalid circuit description: {msg}"));
+        let mut header = [0u8; 5];
+        reader
+            .read_exact(&mut header)
+            .map_err(|e| invalid(&e.to_string()))?;
+        if header[..4] != CIRCUIT_MAGIC {
+            return Err(invalid("wrong magic bytes"));
+        }
+        if header[4] != CIRCUIT_FORMAT_VERSION {
+            return Err(invalid(&format!("unsupported version {}", header[4])));
+        }
+        let description = CircuitDescription::<F>::deserialize_compressed(reader)
+            .map_err(|e| invalid(&e.to_string()))?;
+
+        let plonk_type = if description.is_ultra_plonk {
+            PlonkType::UltraPlonk
+        } else {
+            PlonkType::TurboPlonk
+        };
+        let plonk_params = PlonkParams::init(plonk_type, description.range_bit_len)?;
+        let eval_domain = Radix2EvaluationDomain::new(description.eval_domain_size)
+            .filter(|domain| domain.size() == description.eval_domain_size)
+            .ok_or_else(|| invalid("wrong domain size"))?;
+        let num_gates = description.gates.len();
+        let gates = description
+            .gates
+            .into_iter()
+            .map(|(kind, selectors)| -> Result<Box<dyn Gate<F>>, CircuitError> {
+                match (kind, selectors.as_slice()) {
+                    (IO_GATE, []) => Ok(Box::new(IoGate)),
+                    (PADDING_GATE, []) => Ok(Box::new(PaddingGate)),
+                    (GENERIC_GATE, &[q_lc0, q_lc1, q_lc2, q_lc3, q_mul0, q_mul1, q_hash0, q_hash1, q_hash2, q_hash3, q_o, q_c, q_ecc, q_lookup, q_dom_sep, table_key, table_dom_sep]) => {
+                        Ok(Box::new(LoadedGate {
+                            q_lc: [q_lc0, q_lc1, q_lc2, q_lc3],
+                            q_mul: [q_mul0, q_mul1],
+                            q_hash: [q_hash0, q_hash1, q_hash2, q_hash3],
+                            q_o,
+                            q_c,
+                            q_ecc,
+                            q_lookup,
+                            q_dom_sep,
+                            table_key,
+                            table_dom_sep,
+                        }))
+                    },
+                    _ => Err(invalid("malformed gate")),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let wire_variables: [Vec<Variable>; GATE_WIDTH + 2] = description
+            .wire_variables
+            .try_into()
+            .map_err(|_| invalid("wrong number of wire types"))?;
+
+        // check that all the indices are in range
+        let num_wire_types = GATE_WIDTH
+            + 1
+            + match plonk_type {
+                PlonkType::TurboPlonk => 0,
+                PlonkType::UltraPlonk => 1,
+            };
+        let is_finalized = description.eval_domain_size != 1;
+        if description.num_vars < 2
+            || description.gate_labels.len() != num_gates
+            || wire_variables
+                .iter()
+                .flatten()
+                .any(|&var| var >= description.num_vars)
+            || wire_variables[..GATE_WIDTH + 1]
+                .iter()
+                .any(|vars| vars.len() != num_gates)
+            || description
+                .pub_input_gate_ids
+                .iter()
+                .any(|&gate_id| gate_id >= num_gates)
+            || description
+                .table_gate_ids
+                .iter()
+                .any(|&(gate_id, size)| gate_id + size > num_gates)
+            || (is_finalized
+                && (num_gates != eval_domain.size()
+                    || description.wire_permutation.len() != num_wire_types * num_gates
+                    || description
+                        .wire_permutation
+                        .iter()
+                        .any(|&(wire_id, gate_id)| {
+                            wire_id >= num_wire_types || gate_id >= num_gates
+                        })
+                    || description.extended_id_permutation.len() != num_wire_types * num_gates))
+        {
+            return Err(invalid("index out of range"));
+        }
+
+        let to_labels = |labels: Vec<Option<String>>| {
+            labels
+                .into_iter()
+                .map(|label| label.map(Arc::from))
+                .collect()
+        };
+        let mut witness = vec![F::zero(); description.num_vars];
+        witness[1] = F::one();
+        Ok(Self {
+            num_vars: description.num_vars,
+            gates,
+            wire_variables,
+            pub_input_gate_ids: description.pub_input_gate_ids,
+            witness,
+            wire_permutation: description.wire_permutation,
+            extended_id_permutation: description.extended_id_permutation,
+            num_wire_types,
+            eval_domain,
+            plonk_params,
+            num_table_elems: description.num_table_elems,
+            table_gate_ids: description.table_gate_ids,
+            gate_labels: to_labels(description.gate_labels),
+            range_labels: to_labels(description.range_labels),
+            current_label: None,
+            scopes: Default::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Arithmetization, Circuit, CircuitError, PlonkCircuit};
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_ff::PrimeField;
+    use ark_std::vec::Vec;
+
+    #[test]
+    fn test_circuit_description() -> Result<(), CircuitError> {
+        test_circuit_description_helper::<FqEd254>()?;
+        test_circuit_description_helper::<FqEd377>()?;
+        test_circuit_description_helper::<FqEd381>()?;
+        test_circuit_description_helper::<Fq377>()
+    }
+
+    fn gen_circuit<F: PrimeField>(x: u32, finalize: bool) -> Result<PlonkCircuit<F>, CircuitError> {
+        let mut circuit = PlonkCircuit::new_ultra_plonk(4);
+        let x_var = circuit.create_variable(F::from(x))?;
+        let y_var = circuit.create_public_variable(F::from(x * x + 1))?;
+        circuit.set_label("square");
+        let x_square = circuit.mul(x_var, x_var)?;
+        circuit.add_constant_gate(x_square, F::one(), y_var)?;
+        circuit.clear_label();
+        circuit.add_range_check_variable(x_var)?;
+        let table_val = circuit.create_variable(F::from(x + 3))?;
+        let lookup_val = circuit.create_variable(F::from(x + 3))?;
+        circuit.create_table_and_lookup_variables(
+            &[(circuit.zero(), lookup_val, circuit.zero())],
+            &[(table_val, circuit.zero())],
+        )?;
+        if finalize {
+            circuit.finalize_for_arithmetization()?;
+        }
+        Ok(circuit)
+    }
+
+    fn test_circuit_description_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        for finalize in [false, true] {
+            let circuit = gen_circuit::<F>(5, finalize)?;
+            let mut bytes = Vec::new();
+            circuit.save(&mut bytes)?;
+            let mut loaded = PlonkCircuit::<F>::load(&bytes[..])?;
+            assert_eq!(loaded.num_gates(), circuit.num_gates());
+            assert_eq!(loaded.num_vars(), circuit.num_vars());
+            assert_eq!(loaded.num_inputs(), circuit.num_inputs());
+
+            // the loaded circuit has no witness until one is assigned
+            assert!(loaded
+                .check_circuit_satisfiability(&[F::from(26u32)])
+                .is_err());
+            loaded.load_witness_assignment(&circuit.witness_assignment())?;
+            loaded.check_circuit_satisfiability(&[F::from(26u32)])?;
+            if !finalize {
+                loaded.finalize_for_arithmetization()?;
+                continue;
+            }
+            assert_eq!(loaded.eval_domain_size()?, circuit.eval_domain_size()?);
+            assert_eq!(
+                loaded.compute_selector_polynomials()?,
+                circuit.compute_selector_polynomials()?
+            );
+            assert_eq!(
+                loaded.compute_extended_permutation_polynomials()?,
+                circuit.compute_extended_permutation_polynomials()?
+            );
+            assert_eq!(
+                loaded.compute_key_table_polynomial()?,
+                circuit.compute_key_table_polynomial()?
+            );
+
+            // labels are kept
+            *loaded.witness_mut(2) = F::from(6u32);
+            let violation = loaded.find_unsatisfied_gate(&[F::from(26u32)])?.unwrap();
+            assert_eq!(violation.label.as_deref(), Some("square"));
+
+            // malformed descriptions are rejected
+            assert!(PlonkCircuit::<F>::load(&bytes[..bytes.len() - 1]).is_err());
+            let mut bad_bytes = bytes.clone();
+            bad_bytes[4] += 1;
+            assert!(PlonkCircuit::<F>::load(&bad_bytes[..]).is_err());
+            bad_bytes = bytes.clone();
+            bad_bytes[0] = 0;
+            assert!(PlonkCircuit::<F>::load(&bad_bytes[..]).is_err());
+        }
+        Ok(())
+    }
+}
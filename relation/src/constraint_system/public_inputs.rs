@@ -0,0 +1,363 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Named public inputs of a circuit.
+//!
+//! The public inputs of a circuit are ordered as their variables are set
+//! public, possibly from within gadgets, and a verifier must provide them in
+//! the same order. [`PublicInputs`] registers the public inputs under names
+//! as they are created, recording their positions in the circuit, and
+//! [`PublicInputLayout`] maps the names back to the positions to read or
+//! assemble the public input vector.
+
+use super::PlonkCircuit;
+use crate::{
+    Circuit,
+    CircuitError::{self, ParameterError},
+    Variable,
+};
+use ark_ff::{FftField, Field};
+use ark_std::{
+    format,
+    ops::Range,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// A named public input, as consecutive elements of the public input vector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PublicInputEntry {
+    name: String,
+    offset: usize,
+    len: usize,
+}
+
+/// A builder registering the public inputs of a circuit under names, see
+/// [`Self::finish()`] for the resulting layout.
+#[derive(Debug, Clone, Default)]
+pub struct PublicInputs {
+    // the entries in the order of their positions
+    entries: Vec<PublicInputEntry>,
+}
+
+impl PublicInputs {
+    /// Create a builder without public input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a public variable for `val` to `circuit`, named `name`.
+    /// Return error if the name is already registered, or the circuit is
+    /// finalized or is not the one of the previous registrations.
+    pub fn create_public_variable<F: FftField>(
+        &mut self,
+        circuit: &mut PlonkCircuit<F>,
+        name: &str,
+        val: F,
+    ) -> Result<Variable, CircuitError> {
+        Ok(self.create_public_variables(circuit, name, &[val])?[0])
+    }
+
+    /// Add public variables for the values of `vals` to `circuit`, named
+    /// `name` as a whole.
+    /// Return error if the name is already registered, or the circuit is
+    /// finalized or is not the one of the previous registrations.
+    pub fn create_public_variables<F: FftField>(
+        &mut self,
+        circuit: &mut PlonkCircuit<F>,
+        name: &str,
+        vals: &[F],
+    ) -> Result<Vec<Variable>, CircuitError> {
+        self.check_registration(circuit, name)?;
+        let vars = vals
+            .iter()
+            .map(|&val| circuit.create_variable(val))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        self.set_variables_public(circuit, name, &vars)?;
+        Ok(vars)
+    }
+
+    /// Set the variables of `vars` public in `circuit`, named `name` as a
+    /// whole.
+    /// Return error if the name is already registered, the variables are
+    /// invalid, or the circuit is finalized or is not the one of the previous
+    /// registrations.
+    pub fn set_variables_public<F: FftField>(
+        &mut self,
+        circuit: &mut PlonkCircuit<F>,
+        name: &str,
+        vars: &[Variable],
+    ) -> Result<(), CircuitError> {
+        self.check_registration(circuit, name)?;
+        circuit.check_vars_bound(vars)?;
+        let offset = circuit.num_inputs();
+        for &var in vars.iter() {
+            circuit.set_variable_public(var)?;
+        }
+        self.entries.push(PublicInputEntry {
+            name: name.to_string(),
+            offset,
+            len: vars.len(),
+        });
+        Ok(())
+    }
+
+    /// Return the layout of the public inputs of `circuit`.
+    /// Return error if some public inputs of the circuit are not named, e.g.
+    /// set public by a gadget, or the circuit is not the one of the
+    /// registrations.
+    pub fn finish<F: FftField>(
+        self,
+        circuit: &PlonkCircuit<F>,
+    ) -> Result<PublicInputLayout, CircuitError> {
+        let mut next = 0;
+        for entry in self.entries.iter() {
+            if entry.offset != next {
+                return Err(ParameterError(format!(
+                    "the public inputs at positions {}..{} are not named",
+                    next, entry.offset
+                )));
+            }
+            next += entry.len;
+        }
+        if next != circuit.num_inputs() {
+            return Err(ParameterError(format!(
+                "the circuit has {} public inputs, {} of them being named",
+                circuit.num_inputs(),
+                next
+            )));
+        }
+        Ok(PublicInputLayout {
+            entries: self.entries,
+            num_inputs: next,
+        })
+    }
+
+    fn check_registration<F: FftField>(
+        &self,
+        circuit: &PlonkCircuit<F>,
+        name: &str,
+    ) -> Result<(), CircuitError> {
+        if self.entries.iter().any(|entry| entry.name == name) {
+            return Err(ParameterError(format!(
+                "the public input {name} is already registered"
+            )));
+        }
+        // the circuit never loses public inputs
+        if let Some(last) = self.entries.last() {
+            if circuit.num_inputs() < last.offset + last.len {
+                return Err(ParameterError(
+                    "the public inputs are registered on another circuit".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The positions of the named public inputs of a circuit in its public input
+/// vector, as returned by [`PublicInputs::finish()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicInputLayout {
+    entries: Vec<PublicInputEntry>,
+    num_inputs: usize,
+}
+
+impl PublicInputLayout {
+    /// The number of elements of the public input vector.
+    pub fn num_inputs(&self) -> usize {
+        self.num_inputs
+    }
+
+    /// The names of the public inputs, in the order of their positions.
+    pub fn names(&self) -> Vec<&str> {
+        self.entries
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect()
+    }
+
+    /// The positions of the public input named `name` in the public input
+    /// vector, if any.
+    pub fn range(&self, name: &str) -> Option<Range<usize>> {
+        self.entry(name)
+            .map(|entry| entry.offset..entry.offset + entry.len)
+    }
+
+    /// Return the values of the public input named `name` in the public
+    /// input vector `pub_input`, e.g. the output of
+    /// [`Circuit::public_input()`].
+    /// Return error if there is no such name or the vector has not the
+    /// length of the layout.
+    pub fn get<'a, F>(&self, name: &str, pub_input: &'a [F]) -> Result<&'a [F], CircuitError> {
+        self.check_num_inputs(pub_input.len())?;
+        let range = self
+            .range(name)
+            .ok_or_else(|| ParameterError(format!("no public input is named {name}")))?;
+        Ok(&pub_input[range])
+    }
+
+    /// Return the public input vector with the values of `values` given by
+    /// name, in any order.
+    /// Return error if a name is unknown or given twice, a public input is
+    /// missing or the number of values of a public input is wrong.
+    pub fn assemble<F: Field>(&self, values: &[(&str, &[F])]) -> Result<Vec<F>, CircuitError> {
+        let mut pub_input = vec![F::zero(); self.num_inputs];
+        let mut assigned = vec![false; self.entries.len()];
+        for &(name, vals) in values.iter() {
+            let index = self
+                .entries
+                .iter()
+                .position(|entry| entry.name == name)
+                .ok_or_else(|| ParameterError(format!("no public input is named {name}")))?;
+            let entry = &self.entries[index];
+            if assigned[index] {
+                return Err(ParameterError(format!(
+                    "the public input {name} is given twice"
+                )));
+            }
+            if vals.len() != entry.len {
+                return Err(ParameterError(format!(
+                    "the public input {} has {} values instead of {}",
+                    name,
+                    vals.len(),
+                    entry.len
+                )));
+            }
+            pub_input[entry.offset..entry.offset + entry.len].copy_from_slice(vals);
+            assigned[index] = true;
+        }
+        if let Some(index) = assigned.iter().position(|&assigned| !assigned) {
+            return Err(ParameterError(format!(
+                "the public input {} is missing",
+                self.entries[index].name
+            )));
+        }
+        Ok(pub_input)
+    }
+
+    fn entry(&self, name: &str) -> Option<&PublicInputEntry> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    fn check_num_inputs(&self, len: usize) -> Result<(), CircuitError> {
+        if len != self.num_inputs {
+            return Err(CircuitError::PubInputLenMismatch(len, self.num_inputs));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_ff::PrimeField;
+
+    #[test]
+    fn test_public_inputs() -> Result<(), CircuitError> {
+        test_public_inputs_helper::<FqEd254>()?;
+        test_public_inputs_helper::<FqEd377>()?;
+        test_public_inputs_helper::<FqEd381>()?;
+        test_public_inputs_helper::<Fq377>()
+    }
+
+    fn test_public_inputs_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        let mut inputs = PublicInputs::new();
+        let root = inputs.create_public_variable(&mut circuit, "root", F::from(7u8))?;
+        let leaves = inputs.create_public_variables(
+            &mut circuit,
+            "leaves",
+            &[F::from(1u8), F::from(2u8), F::from(3u8)],
+        )?;
+        let sum = circuit.sum(&leaves)?;
+        let total = circuit.add(sum, root)?;
+        inputs.set_variables_public(&mut circuit, "total", &[total])?;
+        let empty = inputs.create_public_variables(&mut circuit, "empty", &[])?;
+        assert!(empty.is_empty());
+        assert!(inputs
+            .create_public_variable(&mut circuit, "root", F::one())
+            .is_err());
+        let invalid_var = circuit.num_vars();
+        assert!(inputs
+            .set_variables_public(&mut circuit, "other", &[invalid_var])
+            .is_err());
+
+        let layout = inputs.clone().finish(&circuit)?;
+        assert_eq!(layout.num_inputs(), 5);
+        assert_eq!(layout.names(), ["root", "leaves", "total", "empty"]);
+        assert_eq!(layout.range("leaves"), Some(1..4));
+        assert_eq!(layout.range("other"), None);
+        let pub_input = circuit.public_input()?;
+        assert_eq!(layout.get("total", &pub_input)?, &[F::from(13u8)]);
+        assert!(layout.get("other", &pub_input).is_err());
+        assert!(layout.get("total", &pub_input[1..]).is_err());
+
+        // the public input vector is assembled by name, in any order
+        let assembled = layout.assemble(&[
+            ("total", &[F::from(13u8)]),
+            ("empty", &[]),
+            ("leaves", &pub_input[1..4]),
+            ("root", &[F::from(7u8)]),
+        ])?;
+        assert_eq!(assembled, pub_input);
+        assert!(circuit.check_circuit_satisfiability(&assembled).is_ok());
+        // unknown, duplicated or missing names, and wrong lengths
+        assert!(layout
+            .assemble(&[
+                ("total", &[F::from(13u8)]),
+                ("empty", &[]),
+                ("leaves", &pub_input[1..4]),
+                ("root", &[F::from(7u8)]),
+                ("other", &[]),
+            ])
+            .is_err());
+        assert!(layout
+            .assemble(&[
+                ("total", &[F::from(13u8)]),
+                ("empty", &[]),
+                ("leaves", &pub_input[1..4]),
+                ("root", &[F::from(7u8)]),
+                ("root", &[F::from(7u8)]),
+            ])
+            .is_err());
+        assert!(layout
+            .assemble(&[
+                ("total", &[F::from(13u8)]),
+                ("empty", &[]),
+                ("leaves", &pub_input[1..4]),
+            ])
+            .is_err());
+        assert!(layout
+            .assemble(&[
+                ("total", &[F::from(13u8)]),
+                ("empty", &[]),
+                ("leaves", &pub_input[1..3]),
+                ("root", &[F::from(7u8)]),
+            ])
+            .is_err());
+
+        // a public input that is not named
+        let mut unnamed_circuit = circuit.clone();
+        unnamed_circuit.create_public_variable(F::one())?;
+        assert!(inputs.clone().finish(&unnamed_circuit).is_err());
+        let mut inputs_with_gap = inputs.clone();
+        inputs_with_gap.create_public_variable(&mut unnamed_circuit, "last", F::one())?;
+        assert!(inputs_with_gap.finish(&unnamed_circuit).is_err());
+        // another circuit
+        let mut other_circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        assert!(inputs
+            .create_public_variable(&mut other_circuit, "last", F::one())
+            .is_err());
+        assert!(inputs.finish(&other_circuit).is_err());
+        Ok(())
+    }
+}
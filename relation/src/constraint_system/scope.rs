@@ -0,0 +1,338 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Labeled scopes of a circuit, and the number of gates and variables created
+//! in each of them.
+
+use super::PlonkCircuit;
+use crate::{
+    Circuit,
+    CircuitError::{self, ParameterError},
+};
+use ark_ff::FftField;
+use ark_std::{
+    fmt,
+    ops::Range,
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+
+/// The label of the root scope, i.e. of the whole circuit.
+const ROOT_LABEL: &str = "circuit";
+
+/// The number of gates and variables created in a scope of a circuit and in
+/// its nested scopes, see [`PlonkCircuit::scope_report()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeReport {
+    /// The label of the scope.
+    pub label: String,
+    /// The number of gates created in the scope.
+    pub num_gates: usize,
+    /// The number of variables created in the scope.
+    pub num_vars: usize,
+    /// The nested scopes, in the order they were first entered.
+    pub children: Vec<ScopeReport>,
+}
+
+impl ScopeReport {
+    /// Return the report of the nested scope at `path`, a list of labels from
+    /// this scope, if any.
+    pub fn get(&self, path: &[&str]) -> Option<&ScopeReport> {
+        match path.split_first() {
+            Some((label, rest)) => self
+                .children
+                .iter()
+                .find(|child| child.label == *label)?
+                .get(rest),
+            None => Some(self),
+        }
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        writeln!(
+            f,
+            "{:indent$}{}: {} gates, {} variables",
+            "",
+            self.label,
+            self.num_gates,
+            self.num_vars,
+            indent = 2 * depth
+        )?;
+        for child in self.children.iter() {
+            child.fmt_indented(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ScopeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+/// The tree of the scopes of a circuit.
+#[derive(Debug, Clone)]
+pub(super) struct ScopeTree {
+    // the scopes, the root scope being the first one
+    nodes: Vec<ScopeNode>,
+    // the scopes being entered, from the outermost one
+    open_scopes: Vec<OpenScope>,
+}
+
+#[derive(Debug, Clone)]
+struct ScopeNode {
+    label: Arc<str>,
+    children: Vec<usize>,
+    // the ranges of the gates and variables created in the scope, each time it
+    // is exited
+    gate_ranges: Vec<Range<usize>>,
+    var_ranges: Vec<Range<usize>>,
+}
+
+impl ScopeNode {
+    fn new(label: &str) -> Self {
+        Self {
+            label: Arc::from(label),
+            children: vec![],
+            gate_ranges: vec![],
+            var_ranges: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct OpenScope {
+    node: usize,
+    // the number of gates and variables of the circuit when entering the scope
+    num_gates: usize,
+    num_vars: usize,
+    // the label of the gates before entering the scope
+    prev_label: Option<Arc<str>>,
+}
+
+impl Default for ScopeTree {
+    fn default() -> Self {
+        Self {
+            nodes: vec![ScopeNode::new(ROOT_LABEL)],
+            open_scopes: vec![],
+        }
+    }
+}
+
+impl ScopeTree {
+    // Renumber the gates and variables of the scopes once some of them are
+    // removed, `new_gate_ids[i]` (resp. `new_vars[i]`) being the number of gates
+    // (resp. variables) kept before the `i`-th one, up to the former number of
+    // gates (resp. variables) included.
+    pub(super) fn renumber(&mut self, new_gate_ids: &[usize], new_vars: &[usize]) {
+        for node in self.nodes.iter_mut() {
+            for range in node.gate_ranges.iter_mut() {
+                *range = new_gate_ids[range.start]..new_gate_ids[range.end];
+            }
+            for range in node.var_ranges.iter_mut() {
+                *range = new_vars[range.start]..new_vars[range.end];
+            }
+        }
+        for scope in self.open_scopes.iter_mut() {
+            scope.num_gates = new_gate_ids[scope.num_gates];
+            scope.num_vars = new_vars[scope.num_vars];
+        }
+    }
+}
+
+impl<F: FftField> PlonkCircuit<F> {
+    /// Enter a nested scope labeled `label`, so that the gates and variables
+    /// created until the matching [`Self::pop_scope()`] are accounted to it in
+    /// [`Self::scope_report()`]. Entering a scope with the same label as a
+    /// previous scope of the same parent accumulates into it, e.g. when a
+    /// gadget is called several times.
+    ///
+    /// The gates created in the scope are labeled with the path of the scope,
+    /// e.g. `"verifier/sha256"`, for [`Self::find_unsatisfied_gate()`].
+    pub fn push_scope(&mut self, label: &str) {
+        let parent = self.current_scope();
+        let node = match self.scopes.nodes[parent]
+            .children
+            .iter()
+            .find(|&&child| &*self.scopes.nodes[child].label == label)
+        {
+            Some(&child) => child,
+            None => {
+                self.scopes.nodes.push(ScopeNode::new(label));
+                let child = self.scopes.nodes.len() - 1;
+                self.scopes.nodes[parent].children.push(child);
+                child
+            },
+        };
+        let path = match self.scopes.open_scopes.is_empty() {
+            true => label.to_string(),
+            false => {
+                let mut path: Vec<&str> = self
+                    .scopes
+                    .open_scopes
+                    .iter()
+                    .map(|scope| &*self.scopes.nodes[scope.node].label)
+                    .collect();
+                path.push(label);
+                path.join("/")
+            },
+        };
+        let prev_label = self.current_label.replace(Arc::from(path));
+        self.scopes.open_scopes.push(OpenScope {
+            node,
+            num_gates: self.num_gates(),
+            num_vars: self.num_vars(),
+            prev_label,
+        });
+    }
+
+    /// Exit the scope entered by the latest [`Self::push_scope()`], and
+    /// restore the label of the gates created from now on.
+    /// Return error if there is no scope to exit.
+    pub fn pop_scope(&mut self) -> Result<(), CircuitError> {
+        let scope = self
+            .scopes
+            .open_scopes
+            .pop()
+            .ok_or_else(|| ParameterError("there is no scope to exit".to_string()))?;
+        let (num_gates, num_vars) = (self.num_gates(), self.num_vars());
+        let node = &mut self.scopes.nodes[scope.node];
+        node.gate_ranges.push(scope.num_gates..num_gates);
+        node.var_ranges.push(scope.num_vars..num_vars);
+        self.current_label = scope.prev_label;
+        Ok(())
+    }
+
+    /// Return the number of gates and variables of the circuit broken down by
+    /// the tree of its scopes. The scopes that are not exited yet account for
+    /// the gates and variables created so far.
+    ///
+    /// The numbers reflect [`Self::optimize()`], but not the padding of the
+    /// circuit when finalizing it.
+    pub fn scope_report(&self) -> ScopeReport {
+        let mut report = self.scope_node_report(0);
+        report.num_gates = self.num_gates();
+        report.num_vars = self.num_vars();
+        report
+    }
+
+    // The index of the innermost scope being entered.
+    fn current_scope(&self) -> usize {
+        self.scopes.open_scopes.last().map_or(0, |scope| scope.node)
+    }
+
+    fn scope_node_report(&self, node: usize) -> ScopeReport {
+        let scope = &self.scopes.nodes[node];
+        let mut num_gates: usize = scope.gate_ranges.iter().map(ExactSizeIterator::len).sum();
+        let mut num_vars: usize = scope.var_ranges.iter().map(ExactSizeIterator::len).sum();
+        if let Some(open_scope) = self
+            .scopes
+            .open_scopes
+            .iter()
+            .find(|open_scope| open_scope.node == node)
+        {
+            num_gates += self.num_gates().saturating_sub(open_scope.num_gates);
+            num_vars += self.num_vars().saturating_sub(open_scope.num_vars);
+        }
+        ScopeReport {
+            label: scope.label.to_string(),
+            num_gates,
+            num_vars,
+            children: scope
+                .children
+                .iter()
+                .map(|&child| self.scope_node_report(child))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_ff::PrimeField;
+
+    #[test]
+    fn test_scope_report() -> Result<(), CircuitError> {
+        test_scope_report_helper::<FqEd254>()?;
+        test_scope_report_helper::<FqEd377>()?;
+        test_scope_report_helper::<FqEd381>()?;
+        test_scope_report_helper::<Fq377>()
+    }
+
+    fn test_scope_report_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        let a = circuit.create_variable(F::from(3u32))?;
+        circuit.push_scope("outer");
+        let b = circuit.create_variable(F::from(4u32))?;
+        for _ in 0..2 {
+            circuit.push_scope("adder");
+            circuit.add(a, b)?;
+            circuit.pop_scope()?;
+        }
+        circuit.push_scope("multiplier");
+        let c = circuit.mul(a, b)?;
+        circuit.pop_scope()?;
+        circuit.pop_scope()?;
+        assert!(circuit.pop_scope().is_err());
+        circuit.push_scope("open");
+        circuit.set_label("label");
+        circuit.enforce_equal(c, c)?;
+
+        let report = circuit.scope_report();
+        assert_eq!(report.label, ROOT_LABEL);
+        assert_eq!(report.num_gates, circuit.num_gates());
+        assert_eq!(report.num_vars, circuit.num_vars());
+        assert_eq!(report.children.len(), 2);
+        let outer = report.get(&["outer"]).unwrap();
+        assert_eq!((outer.num_gates, outer.num_vars), (3, 4));
+        let adder = report.get(&["outer", "adder"]).unwrap();
+        assert_eq!((adder.num_gates, adder.num_vars), (2, 2));
+        let multiplier = outer.get(&["multiplier"]).unwrap();
+        assert_eq!((multiplier.num_gates, multiplier.num_vars), (1, 1));
+        let open = report.get(&["open"]).unwrap();
+        assert_eq!((open.num_gates, open.num_vars), (1, 0));
+        assert!(report.get(&["adder"]).is_none());
+        assert!(report
+            .to_string()
+            .contains("    adder: 2 gates, 2 variables"));
+
+        // the gates are labeled with the path of their scope, and the labels
+        // are restored when exiting the scopes
+        *circuit.witness_mut(c) = F::zero();
+        let unsatisfied = circuit.find_unsatisfied_gate(&[])?.unwrap();
+        assert_eq!(unsatisfied.label.as_deref(), Some("outer/multiplier"));
+        circuit.pop_scope()?;
+        assert_eq!(circuit.current_label.as_deref(), None);
+
+        // the gates and variables removed by an optimization are not accounted
+        // for
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        let a = circuit.create_variable(F::from(3u32))?;
+        circuit.push_scope("constants");
+        circuit.create_constant_variable(F::from(5u32))?;
+        circuit.create_constant_variable(F::from(5u32))?;
+        circuit.pop_scope()?;
+        circuit.push_scope("adder");
+        circuit.add(a, a)?;
+        circuit.optimize()?;
+        circuit.add(a, a)?;
+        circuit.pop_scope()?;
+        let report = circuit.scope_report();
+        let constants = report.get(&["constants"]).unwrap();
+        assert_eq!((constants.num_gates, constants.num_vars), (0, 0));
+        let adder = report.get(&["adder"]).unwrap();
+        assert_eq!((adder.num_gates, adder.num_vars), (2, 2));
+        Ok(())
+    }
+}
@@ -0,0 +1,534 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Import of circom circuits, from the `.r1cs` file of their constraints and
+//! the `.wtns` file of a witness computed by their witness generator.
+//!
+//! Each R1CS constraint `<A, w> * <B, w> = <C, w>` becomes one linear
+//! combination gate per 3 terms of each of `A`, `B` and `C`, and a
+//! multiplication gate, or a single linear constraint if `A` or `B` is
+//! constant.
+
+use crate::{Circuit, CircuitError, PlonkCircuit, Variable};
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::{format, io::Read, string::ToString, vec, vec::Vec};
+
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+const WTNS_MAGIC: &[u8; 4] = b"wtns";
+const R1CS_HEADER_SECTION: u32 = 1;
+const R1CS_CONSTRAINTS_SECTION: u32 = 2;
+const WTNS_HEADER_SECTION: u32 = 1;
+const WTNS_DATA_SECTION: u32 = 2;
+
+/// A linear combination `sum_i q_i * w_i` of the wires, as pairs
+/// `(i, q_i)`. Wire 0 is the constant 1.
+pub type LinearCombination<F> = Vec<(u32, F)>;
+
+/// An R1CS constraint `<a, w> * <b, w> = <c, w>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct R1csConstraint<F: PrimeField> {
+    /// The left factor.
+    pub a: LinearCombination<F>,
+    /// The right factor.
+    pub b: LinearCombination<F>,
+    /// The product.
+    pub c: LinearCombination<F>,
+}
+
+/// The content of a circom `.r1cs` file.
+///
+/// The wires are, in order: the constant 1, the public outputs, the public
+/// inputs, the private inputs and the intermediate signals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct R1csFile<F: PrimeField> {
+    /// The number of wires.
+    pub num_wires: u32,
+    /// The number of public outputs.
+    pub num_pub_out: u32,
+    /// The number of public inputs.
+    pub num_pub_in: u32,
+    /// The number of private inputs.
+    pub num_prv_in: u32,
+    /// The constraints.
+    pub constraints: Vec<R1csConstraint<F>>,
+}
+
+impl<F: PrimeField> R1csFile<F> {
+    /// Parse a `.r1cs` file. Return an error if it is malformed or defined
+    /// over another field than `F`.
+    pub fn read<R: Read>(mut reader: R) -> Result<Self, CircuitError> {
+        let sections = read_sections(&mut reader, R1CS_MAGIC)?;
+        let mut header = Cursor::new(find_section(&sections, R1CS_HEADER_SECTION)?);
+        let n8 = read_prime::<F>(&mut header)?;
+        let num_wires = header.read_u32()?;
+        let num_pub_out = header.read_u32()?;
+        let num_pub_in = header.read_u32()?;
+        let num_prv_in = header.read_u32()?;
+        let _num_labels = header.read_u64()?;
+        let num_constraints = header.read_u32()?;
+
+        let mut content = Cursor::new(find_section(&sections, R1CS_CONSTRAINTS_SECTION)?);
+        let read_lc = |content: &mut Cursor| -> Result<LinearCombination<F>, CircuitError> {
+            let num_terms = content.read_u32()?;
+            (0..num_terms)
+                .map(|_| {
+                    let wire = content.read_u32()?;
+                    if wire >= num_wires {
+                        return Err(CircuitError::VarIndexOutOfBound(
+                            wire as usize,
+                            num_wires as usize,
+                        ));
+                    }
+                    Ok((wire, content.read_field(n8)?))
+                })
+                .collect()
+        };
+        let constraints = (0..num_constraints)
+            .map(|_| {
+                Ok(R1csConstraint {
+                    a: read_lc(&mut content)?,
+                    b: read_lc(&mut content)?,
+                    c: read_lc(&mut content)?,
+                })
+            })
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        let num_io_wires = num_pub_out
+            .checked_add(num_pub_in)
+            .and_then(|n| n.checked_add(num_prv_in))
+            .and_then(|n| n.checked_add(1));
+        if !matches!(num_io_wires, Some(n) if n <= num_wires) {
+            return Err(malformed("inconsistent number of wires"));
+        }
+        Ok(Self {
+            num_wires,
+            num_pub_out,
+            num_pub_in,
+            num_prv_in,
+            constraints,
+        })
+    }
+
+    /// Add the constraints to `circuit`, with the value of each wire in
+    /// `witness`, and return the variable of each wire.
+    ///
+    /// The public outputs, then the public inputs, become the public inputs
+    /// of `circuit`, as in the public signals of circom proofs. Return an
+    /// error if the witness does not have one value per wire, or the
+    /// constraints refer to wires beyond `num_wires`.
+    pub fn synthesize(
+        &self,
+        circuit: &mut PlonkCircuit<F>,
+        witness: &[F],
+    ) -> Result<Vec<Variable>, CircuitError> {
+        if self.num_wires == 0 {
+            return Err(CircuitError::ParameterError(
+                "the R1CS has no constant wire".to_string(),
+            ));
+        }
+        if witness.len() != self.num_wires as usize || witness[0] != F::one() {
+            return Err(CircuitError::ParameterError(format!(
+                "the witness should assign the constant 1 and {} wires",
+                self.num_wires - 1
+            )));
+        }
+        for constraint in self.constraints.iter() {
+            for &(wire, _) in constraint
+                .a
+                .iter()
+                .chain(constraint.b.iter())
+                .chain(constraint.c.iter())
+            {
+                if wire >= self.num_wires {
+                    return Err(CircuitError::VarIndexOutOfBound(
+                        wire as usize,
+                        self.num_wires as usize,
+                    ));
+                }
+            }
+        }
+        let num_public = self.num_pub_out as usize + self.num_pub_in as usize;
+        let mut vars = vec![circuit.one()];
+        for (i, &value) in witness.iter().enumerate().skip(1) {
+            vars.push(if i <= num_public {
+                circuit.create_public_variable(value)?
+            } else {
+                circuit.create_variable(value)?
+            });
+        }
+
+        for constraint in self.constraints.iter() {
+            let (a_terms, a_c) = split_constant(&constraint.a, &vars);
+            let (b_terms, b_c) = split_constant(&constraint.b, &vars);
+            let (c_terms, c_c) = split_constant(&constraint.c, &vars);
+            if a_terms.is_empty() || b_terms.is_empty() {
+                // linear constraint `a_c * B - C = 0` or `b_c * A - C = 0`
+                let (k, terms, q) = if a_terms.is_empty() {
+                    (a_c, b_terms, b_c)
+                } else {
+                    (b_c, a_terms, a_c)
+                };
+                let mut lin_terms: Vec<_> = terms.iter().map(|&(q, v)| (k * q, v)).collect();
+                lin_terms.extend(c_terms.iter().map(|&(q, v)| (-q, v)));
+                let sum = circuit.lin_comb_with_constant(&lin_terms, k * q - c_c)?;
+                circuit.enforce_constant(sum, F::zero())?;
+            } else {
+                let a = circuit.lin_comb_with_constant(&a_terms, a_c)?;
+                let b = circuit.lin_comb_with_constant(&b_terms, b_c)?;
+                let c = circuit.lin_comb_with_constant(&c_terms, c_c)?;
+                circuit.mul_gate(a, b, c)?;
+            }
+        }
+        Ok(vars)
+    }
+}
+
+/// Parse a `.wtns` file into the value of each wire. Return an error if it is
+/// malformed or defined over another field than `F`.
+pub fn read_witness<F: PrimeField, R: Read>(mut reader: R) -> Result<Vec<F>, CircuitError> {
+    let sections = read_sections(&mut reader, WTNS_MAGIC)?;
+    let mut header = Cursor::new(find_section(&sections, WTNS_HEADER_SECTION)?);
+    let n8 = read_prime::<F>(&mut header)?;
+    let num_values = header.read_u32()?;
+    let mut data = Cursor::new(find_section(&sections, WTNS_DATA_SECTION)?);
+    (0..num_values).map(|_| data.read_field(n8)).collect()
+}
+
+fn malformed(msg: &str) -> CircuitError {
+    CircuitError::ParameterError(format!("malformed circom file: {msg}"))
+}
+
+// Split the constant term, on wire 0, from the other terms of `lc`.
+fn split_constant<F: PrimeField>(
+    lc: &LinearCombination<F>,
+    vars: &[Variable],
+) -> (Vec<(F, Variable)>, F) {
+    let mut constant = F::zero();
+    let mut terms = vec![];
+    for &(wire, q) in lc.iter() {
+        if wire == 0 {
+            constant += q;
+        } else {
+            terms.push((q, vars[wire as usize]));
+        }
+    }
+    (terms, constant)
+}
+
+// Read the sections, as pairs `(type, content)`, of a circom binary file.
+fn read_sections<R: Read>(
+    reader: &mut R,
+    magic: &[u8; 4],
+) -> Result<Vec<(u32, Vec<u8>)>, CircuitError> {
+    let mut buf = [0u8; 4];
+    read_bytes(reader, &mut buf)?;
+    if &buf != magic {
+        return Err(malformed("wrong magic number"));
+    }
+    let _version = read_u32(reader)?;
+    let num_sections = read_u32(reader)?;
+    (0..num_sections)
+        .map(|_| {
+            let section_type = read_u32(reader)?;
+            let mut size = [0u8; 8];
+            read_bytes(reader, &mut size)?;
+            let content = read_content(reader, u64::from_le_bytes(size))?;
+            Ok((section_type, content))
+        })
+        .collect()
+}
+
+// Read `size` bytes, growing the buffer as they arrive rather than allocating
+// it upfront, so that the size read from a malformed file can't exhaust the
+// memory.
+fn read_content<R: Read>(reader: &mut R, size: u64) -> Result<Vec<u8>, CircuitError> {
+    let mut content = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let mut remaining = size;
+    while remaining > 0 {
+        let len = remaining.min(chunk.len() as u64) as usize;
+        read_bytes(reader, &mut chunk[..len])?;
+        content.extend_from_slice(&chunk[..len]);
+        remaining -= len as u64;
+    }
+    Ok(content)
+}
+
+fn find_section(sections: &[(u32, Vec<u8>)], section_type: u32) -> Result<&[u8], CircuitError> {
+    sections
+        .iter()
+        .find(|(t, _)| *t == section_type)
+        .map(|(_, content)| content.as_slice())
+        .ok_or_else(|| malformed(&format!("missing section {section_type}")))
+}
+
+// Read the field size and the prime of a header, and check that the prime is
+// the modulus of `F`.
+fn read_prime<F: PrimeField>(header: &mut Cursor) -> Result<usize, CircuitError> {
+    let modulus = F::MODULUS.to_bytes_le();
+    let n8 = header.read_u32()? as usize;
+    if n8 != modulus.len() {
+        return Err(malformed(&format!(
+            "field size of {n8} bytes, expecting {}",
+            modulus.len()
+        )));
+    }
+    let prime = header.take(n8)?;
+    if prime != modulus.as_slice() {
+        return Err(CircuitError::ParameterError(
+            "the circom file is defined over another field".to_string(),
+        ));
+    }
+    Ok(n8)
+}
+
+fn read_bytes<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<(), CircuitError> {
+    reader
+        .read_exact(buf)
+        .map_err(|e| malformed(&format!("{e:?}")))
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> Result<u32, CircuitError> {
+    let mut buf = [0u8; 4];
+    read_bytes(reader, &mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+// A cursor over the content of a section.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CircuitError> {
+        if self.bytes.len() < len {
+            return Err(malformed("truncated section"));
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, CircuitError> {
+        let mut buf = [0u8; 4];
+        buf.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn read_u64(&mut self) -> Result<u64, CircuitError> {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(self.take(8)?);
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    // Read a field element, rejecting non-canonical encodings.
+    fn read_field<F: PrimeField>(&mut self, n8: usize) -> Result<F, CircuitError> {
+        let bytes = self.take(n8)?;
+        let elem = F::from_le_bytes_mod_order(bytes);
+        if elem.into_bigint().to_bytes_le() != bytes {
+            return Err(malformed("non-canonical field element"));
+        }
+        Ok(elem)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+
+    #[test]
+    fn test_circom_import() -> Result<(), CircuitError> {
+        test_circom_import_helper::<FqEd254>()?;
+        test_circom_import_helper::<FqEd377>()?;
+        test_circom_import_helper::<FqEd381>()?;
+        test_circom_import_helper::<Fq377>()
+    }
+
+    fn write_section(out: &mut Vec<u8>, section_type: u32, content: &[u8]) {
+        out.extend_from_slice(&section_type.to_le_bytes());
+        out.extend_from_slice(&(content.len() as u64).to_le_bytes());
+        out.extend_from_slice(content);
+    }
+
+    fn write_prime<F: PrimeField>(out: &mut Vec<u8>) {
+        write_prime_bytes(out, &F::MODULUS.to_bytes_le());
+    }
+
+    fn write_prime_bytes(out: &mut Vec<u8>, prime: &[u8]) {
+        out.extend_from_slice(&(prime.len() as u32).to_le_bytes());
+        out.extend_from_slice(prime);
+    }
+
+    fn write_field<F: PrimeField>(out: &mut Vec<u8>, x: F) {
+        out.extend_from_slice(&x.into_bigint().to_bytes_le());
+    }
+
+    fn write_r1cs<F: PrimeField>(r1cs: &R1csFile<F>) -> Vec<u8> {
+        let mut header = vec![];
+        write_prime::<F>(&mut header);
+        for n in [
+            r1cs.num_wires,
+            r1cs.num_pub_out,
+            r1cs.num_pub_in,
+            r1cs.num_prv_in,
+        ] {
+            header.extend_from_slice(&n.to_le_bytes());
+        }
+        header.extend_from_slice(&(r1cs.num_wires as u64).to_le_bytes());
+        header.extend_from_slice(&(r1cs.constraints.len() as u32).to_le_bytes());
+        let mut content = vec![];
+        for constraint in r1cs.constraints.iter() {
+            for lc in [&constraint.a, &constraint.b, &constraint.c] {
+                content.extend_from_slice(&(lc.len() as u32).to_le_bytes());
+                for &(wire, q) in lc.iter() {
+                    content.extend_from_slice(&wire.to_le_bytes());
+                    write_field(&mut content, q);
+                }
+            }
+        }
+        let mut out = b"r1cs".to_vec();
+        out.extend_from_slice(&1u32.to_le_bytes());
+        out.extend_from_slice(&2u32.to_le_bytes());
+        // sections may come in any order
+        write_section(&mut out, R1CS_CONSTRAINTS_SECTION, &content);
+        write_section(&mut out, R1CS_HEADER_SECTION, &header);
+        out
+    }
+
+    fn write_wtns<F: PrimeField>(witness: &[F]) -> Vec<u8> {
+        write_wtns_with_prime(&F::MODULUS.to_bytes_le(), witness)
+    }
+
+    fn write_wtns_with_prime<F: PrimeField>(prime: &[u8], witness: &[F]) -> Vec<u8> {
+        let mut header = vec![];
+        write_prime_bytes(&mut header, prime);
+        header.extend_from_slice(&(witness.len() as u32).to_le_bytes());
+        let mut data = vec![];
+        for &x in witness.iter() {
+            write_field(&mut data, x);
+        }
+        let mut out = b"wtns".to_vec();
+        out.extend_from_slice(&2u32.to_le_bytes());
+        out.extend_from_slice(&2u32.to_le_bytes());
+        write_section(&mut out, WTNS_HEADER_SECTION, &header);
+        write_section(&mut out, WTNS_DATA_SECTION, &data);
+        out
+    }
+
+    // signal output out; signal input x; signal input y; signal t;
+    // t <== (x + 2) * (y - 3);
+    // out <== 5 * t + x;
+    // x * (x - 1) === 0;
+    fn gen_r1cs<F: PrimeField>() -> R1csFile<F> {
+        let (out, x, y, t) = (1, 2, 3, 4);
+        R1csFile {
+            num_wires: 5,
+            num_pub_out: 1,
+            num_pub_in: 0,
+            num_prv_in: 2,
+            constraints: vec![
+                R1csConstraint {
+                    a: vec![(x, F::one()), (0, F::from(2u8))],
+                    b: vec![(y, F::one()), (0, -F::from(3u8))],
+                    c: vec![(t, F::one())],
+                },
+                R1csConstraint {
+                    a: vec![(0, F::one())],
+                    b: vec![(t, F::from(5u8)), (x, F::one())],
+                    c: vec![(out, F::one())],
+                },
+                R1csConstraint {
+                    a: vec![(x, F::one())],
+                    b: vec![(x, F::one()), (0, -F::one())],
+                    c: vec![],
+                },
+            ],
+        }
+    }
+
+    fn test_circom_import_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let r1cs = gen_r1cs::<F>();
+        let r1cs_bytes = write_r1cs(&r1cs);
+        assert_eq!(R1csFile::<F>::read(&r1cs_bytes[..])?, r1cs);
+        let (x, y) = (F::one(), F::from(10u8));
+        let t = (x + F::from(2u8)) * (y - F::from(3u8));
+        let out = F::from(5u8) * t + x;
+        let witness = read_witness::<F, _>(&write_wtns(&[F::one(), out, x, y, t])[..])?;
+        assert_eq!(witness, vec![F::one(), out, x, y, t]);
+
+        for mut circuit in [
+            PlonkCircuit::<F>::new_turbo_plonk(),
+            PlonkCircuit::<F>::new_ultra_plonk(8),
+        ] {
+            let vars = r1cs.synthesize(&mut circuit, &witness)?;
+            assert_eq!(vars.len(), 5);
+            assert_eq!(circuit.public_input()?, vec![out]);
+            circuit.check_circuit_satisfiability(&[out])?;
+            circuit.finalize_for_arithmetization()?;
+        }
+
+        // a wrong witness
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        r1cs.synthesize(&mut circuit, &[F::one(), out, F::from(2u8), y, t])?;
+        assert!(circuit.check_circuit_satisfiability(&[out]).is_err());
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        assert!(r1cs.synthesize(&mut circuit, &witness[..4]).is_err());
+        // inconsistent R1CS, as its fields are public
+        let mut bad_r1cs = r1cs.clone();
+        bad_r1cs.num_wires = 0;
+        assert!(bad_r1cs.synthesize(&mut circuit, &[]).is_err());
+        let mut bad_r1cs = r1cs.clone();
+        bad_r1cs.constraints[1].b[0].0 = 5;
+        assert!(bad_r1cs.synthesize(&mut circuit, &witness).is_err());
+
+        // a non-canonical witness value, `t + p`
+        let modulus = F::MODULUS.to_bytes_le();
+        let mut bad_bytes = write_wtns(&witness);
+        let n8 = modulus.len();
+        let start = bad_bytes.len() - n8;
+        let mut t_plus_p = F::MODULUS;
+        assert!(!t_plus_p.add_with_carry(&t.into_bigint()));
+        bad_bytes[start..].copy_from_slice(&t_plus_p.to_bytes_le());
+        assert!(read_witness::<F, _>(&bad_bytes[..]).is_err());
+        bad_bytes[start..].copy_from_slice(&modulus);
+        assert!(read_witness::<F, _>(&bad_bytes[..]).is_err());
+
+        // malformed files
+        assert!(R1csFile::<F>::read(&r1cs_bytes[..r1cs_bytes.len() - 1]).is_err());
+        let mut bad_bytes = r1cs_bytes.clone();
+        bad_bytes[0] = b'x';
+        assert!(R1csFile::<F>::read(&bad_bytes[..]).is_err());
+        let mut bad_r1cs = r1cs.clone();
+        bad_r1cs.constraints[0].c[0].0 = 5;
+        assert!(R1csFile::<F>::read(&write_r1cs(&bad_r1cs)[..]).is_err());
+
+        // malformed headers: a huge section size, a number of wires that
+        // overflows, and a field size or prime of the wrong length
+        let mut bad_bytes = r1cs_bytes.clone();
+        bad_bytes[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(R1csFile::<F>::read(&bad_bytes[..]).is_err());
+        let mut bad_r1cs = r1cs;
+        bad_r1cs.num_pub_out = u32::MAX;
+        bad_r1cs.num_pub_in = 1;
+        assert!(R1csFile::<F>::read(&write_r1cs(&bad_r1cs)[..]).is_err());
+        assert!(read_witness::<F, _>(&write_wtns_with_prime::<F>(&[], &[])[..]).is_err());
+        assert!(read_witness::<F, _>(
+            &write_wtns_with_prime(&modulus[..modulus.len() - 1], &witness)[..]
+        )
+        .is_err());
+        let mut long_modulus = modulus;
+        long_modulus.push(0);
+        assert!(read_witness::<F, _>(&write_wtns_with_prime(&long_modulus, &witness)[..]).is_err());
+        Ok(())
+    }
+}
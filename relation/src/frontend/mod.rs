@@ -0,0 +1,43 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Front-ends building [`PlonkCircuit`](crate::PlonkCircuit)s from circuits
+//! compiled by other toolchains.
+
+use crate::{Circuit, CircuitError, PlonkCircuit, Variable};
+use ark_ff::PrimeField;
+use ark_std::vec::Vec;
+
+pub mod circom;
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Return a variable constrained to `q_c + sum_i q_i * v_i` for `terms`
+    /// `(q_i, v_i)`, with one linear combination gate per 3 terms or so.
+    pub(crate) fn lin_comb_with_constant(
+        &mut self,
+        terms: &[(F, Variable)],
+        q_c: F,
+    ) -> Result<Variable, CircuitError> {
+        let mut terms = terms.to_vec();
+        if q_c != F::zero() {
+            terms.push((q_c, self.one()));
+        }
+        // reduce the terms 4 by 4, the result of a chunk being a new term
+        while terms.len() > 4 {
+            let chunk: Vec<_> = terms.drain(..4).collect();
+            let var = self.lc(
+                &[chunk[0].1, chunk[1].1, chunk[2].1, chunk[3].1],
+                &[chunk[0].0, chunk[1].0, chunk[2].0, chunk[3].0],
+            )?;
+            terms.push((F::one(), var));
+        }
+        terms.resize(4, (F::zero(), self.zero()));
+        self.lc(
+            &[terms[0].1, terms[1].1, terms[2].1, terms[3].1],
+            &[terms[0].0, terms[1].0, terms[2].0, terms[3].0],
+        )
+    }
+}
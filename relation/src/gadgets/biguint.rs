@@ -0,0 +1,547 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Arithmetic of big unsigned integers modulo a modulus given as a variable,
+//! e.g. an RSA public key. Integers are split into limbs of
+//! [`BIGUINT_LIMB_BITS`] bits, range checked with lookups on circuits
+//! supporting them. A modular multiplication `a * b = q * n + r` is checked
+//! limb by limb with the products computed schoolbook, propagating signed
+//! carries.
+
+use super::biguint_to_limbs;
+use crate::{Circuit, CircuitError, PlonkCircuit, Variable};
+use ark_ff::PrimeField;
+use ark_std::{format, string::ToString, vec, vec::Vec};
+use num_bigint::BigUint;
+
+/// The number of bits of the limbs of a [`BigUintVar`].
+pub const BIGUINT_LIMB_BITS: usize = 64;
+
+/// A variable for a big unsigned integer, as little-endian limbs of
+/// [`BIGUINT_LIMB_BITS`] bits.
+#[derive(Debug, Clone)]
+pub struct BigUintVar(pub(crate) Vec<Variable>);
+
+impl BigUintVar {
+    /// Return the limb variables of the integer.
+    pub fn native_vars(&self) -> Vec<Variable> {
+        self.0.clone()
+    }
+}
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Return the witness of a big integer variable.
+    pub fn biguint_witness(&self, a: &BigUintVar) -> Result<BigUint, CircuitError> {
+        a.0.iter()
+            .rev()
+            .try_fold(BigUint::from(0u32), |acc, &limb| {
+                Ok((acc << BIGUINT_LIMB_BITS) + <F as Into<BigUint>>::into(self.witness(limb)?))
+            })
+    }
+
+    /// Add a variable for the integer `val` with `num_limbs` limbs, each
+    /// constrained to [`BIGUINT_LIMB_BITS`] bits.
+    /// Return error if `val` does not fit in `num_limbs` limbs.
+    pub fn create_biguint_variable(
+        &mut self,
+        val: &BigUint,
+        num_limbs: usize,
+    ) -> Result<BigUintVar, CircuitError> {
+        let limbs = self.biguint_limbs(val, num_limbs)?;
+        let limbs = limbs
+            .into_iter()
+            .map(|limb| self.create_variable(limb))
+            .collect::<Result<Vec<_>, _>>()?;
+        for &limb in limbs.iter() {
+            self.enforce_in_range(limb, BIGUINT_LIMB_BITS)?;
+        }
+        Ok(BigUintVar(limbs))
+    }
+
+    /// Add a constant variable for the integer `val` with `num_limbs` limbs.
+    /// Return error if `val` does not fit in `num_limbs` limbs.
+    pub fn create_constant_biguint_variable(
+        &mut self,
+        val: &BigUint,
+        num_limbs: usize,
+    ) -> Result<BigUintVar, CircuitError> {
+        let limbs = self.biguint_limbs(val, num_limbs)?;
+        Ok(BigUintVar(
+            limbs
+                .into_iter()
+                .map(|limb| self.create_constant_variable(limb))
+                .collect::<Result<Vec<_>, _>>()?,
+        ))
+    }
+
+    /// Add a public variable for the integer `val` with `num_limbs` limbs.
+    /// Return error if `val` does not fit in `num_limbs` limbs.
+    pub fn create_public_biguint_variable(
+        &mut self,
+        val: &BigUint,
+        num_limbs: usize,
+    ) -> Result<BigUintVar, CircuitError> {
+        let limbs = self.biguint_limbs(val, num_limbs)?;
+        Ok(BigUintVar(
+            limbs
+                .into_iter()
+                .map(|limb| self.create_public_variable(limb))
+                .collect::<Result<Vec<_>, _>>()?,
+        ))
+    }
+
+    /// Constrain two big integer variables with the same number of limbs to
+    /// be equal.
+    /// Return error if the numbers of limbs differ or the variables are
+    /// invalid.
+    pub fn enforce_biguint_equal(
+        &mut self,
+        a: &BigUintVar,
+        b: &BigUintVar,
+    ) -> Result<(), CircuitError> {
+        self.check_biguint_num_limbs(a, b)?;
+        for (&a, &b) in a.0.iter().zip(b.0.iter()) {
+            self.enforce_equal(a, b)?;
+        }
+        Ok(())
+    }
+
+    /// Constrain `a < b` for variables with the same number of limbs, by
+    /// checking that `b - 1 - a` computed limb by limb does not underflow.
+    /// Return error if the numbers of limbs differ or the variables are
+    /// invalid.
+    pub fn enforce_biguint_lt(
+        &mut self,
+        a: &BigUintVar,
+        b: &BigUintVar,
+    ) -> Result<(), CircuitError> {
+        self.check_biguint_num_limbs(a, b)?;
+        self.check_vars_bound(&a.0)?;
+        self.check_vars_bound(&b.0)?;
+        let b_pow = F::from(2u32).pow([BIGUINT_LIMB_BITS as u64]);
+        let mut borrow_in = self.zero();
+        for (i, (&a_limb, &b_limb)) in a.0.iter().zip(b.0.iter()).enumerate() {
+            let one_in = if i == 0 { F::one() } else { F::zero() };
+            // the most significant limb cannot borrow
+            let borrow_out = if i == a.0.len() - 1 {
+                self.zero()
+            } else {
+                let val_a: BigUint =
+                    (self.witness(a_limb)? + self.witness(borrow_in)? + one_in).into();
+                let val_b: BigUint = self.witness(b_limb)?.into();
+                self.create_boolean_variable(val_b < val_a)?.into()
+            };
+            // diff = b - a - borrow_in - [i = 0] + borrow_out * 2^B
+            let diff = self.lin_comb_with_constant(
+                &[
+                    (F::one(), b_limb),
+                    (-F::one(), a_limb),
+                    (-F::one(), borrow_in),
+                    (b_pow, borrow_out),
+                ],
+                -one_in,
+            )?;
+            self.enforce_in_range(diff, BIGUINT_LIMB_BITS)?;
+            borrow_in = borrow_out;
+        }
+        Ok(())
+    }
+
+    /// Return `a * b mod n` for variables with the same number of limbs,
+    /// constrained to be smaller than `n`. The inputs `a` and `b` should be
+    /// smaller than `n`, e.g. outputs of this gadget, so that the quotient
+    /// fits in as many limbs. The circuit is unsatisfiable if `n` is zero.
+    /// Return error if the numbers of limbs differ, the native field is too
+    /// small for the limbs, the quotient does not fit or the variables are
+    /// invalid.
+    pub fn biguint_mul_mod(
+        &mut self,
+        a: &BigUintVar,
+        b: &BigUintVar,
+        n: &BigUintVar,
+    ) -> Result<BigUintVar, CircuitError> {
+        self.check_biguint_num_limbs(a, b)?;
+        self.check_biguint_num_limbs(a, n)?;
+        self.check_vars_bound(&a.0)?;
+        self.check_vars_bound(&b.0)?;
+        self.check_vars_bound(&n.0)?;
+        let num_limbs = n.0.len();
+        // a column sum is at most `num_limbs * 2^(2 * B)` in absolute value,
+        // and so is a carry times 2^B
+        let num_col_bits =
+            2 * BIGUINT_LIMB_BITS + (usize::BITS - num_limbs.leading_zeros()) as usize;
+        if num_col_bits + 2 >= F::MODULUS_BIT_SIZE as usize {
+            return Err(CircuitError::ParameterError(format!(
+                "the native field of {} bits is too small for products of {} limbs",
+                F::MODULUS_BIT_SIZE,
+                num_limbs
+            )));
+        }
+
+        let a_val = self.biguint_witness(a)?;
+        let b_val = self.biguint_witness(b)?;
+        let n_val = self.biguint_witness(n)?;
+        let (q_val, r_val) = if n_val == BigUint::from(0u32) {
+            (BigUint::from(0u32), BigUint::from(0u32))
+        } else {
+            let product = a_val * b_val;
+            (&product / &n_val, product % &n_val)
+        };
+        let q = self.create_biguint_variable(&q_val, num_limbs)?;
+        let r = self.create_biguint_variable(&r_val, num_limbs)?;
+        self.enforce_biguint_lt(&r, n)?;
+
+        // column k: sum_{i + j = k} (a_i * b_j - q_i * n_j) - r_k + c_{k-1} = 2^B * c_k
+        let b_pow = F::from(2u32).pow([BIGUINT_LIMB_BITS as u64]);
+        let num_carry_bits = num_col_bits - BIGUINT_LIMB_BITS + 1;
+        let carry_offset = F::from(2u32).pow([num_carry_bits as u64 - 1]);
+        let mut carry_in = self.zero();
+        for k in 0..2 * num_limbs - 1 {
+            let mut terms = vec![];
+            for i in k.saturating_sub(num_limbs - 1)..=k.min(num_limbs - 1) {
+                let j = k - i;
+                let term =
+                    self.mul_add(&[a.0[i], b.0[j], q.0[i], n.0[j]], &[F::one(), -F::one()])?;
+                terms.push((F::one(), term));
+            }
+            if k < num_limbs {
+                terms.push((-F::one(), r.0[k]));
+            }
+            terms.push((F::one(), carry_in));
+            let col = self.lin_comb_with_constant(&terms, F::zero())?;
+            if k == 2 * num_limbs - 2 {
+                self.enforce_constant(col, F::zero())?;
+            } else {
+                // the column is a multiple of 2^B, of absolute value smaller than
+                // `carry_offset * 2^B`
+                let carry_out = self.witness(col)? / b_pow;
+                let carry_out = self.create_variable(carry_out)?;
+                self.lc_gate(
+                    &[col, carry_out, self.zero(), self.zero(), self.zero()],
+                    &[F::one(), -b_pow, F::zero(), F::zero()],
+                )?;
+                let shifted = self.add_constant(carry_out, &carry_offset)?;
+                self.enforce_in_range(shifted, num_carry_bits)?;
+                carry_in = carry_out;
+            }
+        }
+        Ok(r)
+    }
+
+    /// Return `a + b mod n` for variables with the same number of limbs,
+    /// constrained to be smaller than `n`. The inputs `a` and `b` should be
+    /// smaller than `n`, so that the sum exceeds `n` at most once. The circuit
+    /// is unsatisfiable if `n` is zero.
+    /// Return error if the numbers of limbs differ or the variables are
+    /// invalid.
+    pub fn biguint_add_mod(
+        &mut self,
+        a: &BigUintVar,
+        b: &BigUintVar,
+        n: &BigUintVar,
+    ) -> Result<BigUintVar, CircuitError> {
+        self.check_biguint_num_limbs(a, b)?;
+        self.check_biguint_num_limbs(a, n)?;
+        self.check_vars_bound(&a.0)?;
+        self.check_vars_bound(&b.0)?;
+        self.check_vars_bound(&n.0)?;
+        let num_limbs = n.0.len();
+
+        let sum = self.biguint_witness(a)? + self.biguint_witness(b)?;
+        let n_val = self.biguint_witness(n)?;
+        let (q_val, r_val) = if n_val == BigUint::from(0u32) {
+            (false, BigUint::from(0u32))
+        } else if sum >= n_val {
+            (true, sum - &n_val)
+        } else {
+            (false, sum)
+        };
+        let q = self.create_boolean_variable(q_val)?;
+        let r = self.create_biguint_variable(&r_val, num_limbs)?;
+        self.enforce_biguint_lt(&r, n)?;
+
+        // limb k: a_k + b_k - q * n_k - r_k + c_{k-1} = 2^B * c_k, where the
+        // carries are in {-1, 0, 1, 2}
+        let b_pow = F::from(2u32).pow([BIGUINT_LIMB_BITS as u64]);
+        let mut carry_in = self.zero();
+        for k in 0..num_limbs {
+            let term = self.gen_quad_poly(
+                &[a.0[k], b.0[k], q.into(), n.0[k]],
+                &[F::one(), F::one(), F::zero(), F::zero()],
+                &[F::zero(), -F::one()],
+                F::zero(),
+            )?;
+            let col = self.lin_comb_with_constant(
+                &[(F::one(), term), (-F::one(), r.0[k]), (F::one(), carry_in)],
+                F::zero(),
+            )?;
+            if k == num_limbs - 1 {
+                self.enforce_constant(col, F::zero())?;
+            } else {
+                let carry_out = self.witness(col)? / b_pow;
+                let carry_out = self.create_variable(carry_out)?;
+                self.lc_gate(
+                    &[col, carry_out, self.zero(), self.zero(), self.zero()],
+                    &[F::one(), -b_pow, F::zero(), F::zero()],
+                )?;
+                let shifted = self.add_constant(carry_out, &F::one())?;
+                self.enforce_in_range(shifted, 2)?;
+                carry_in = carry_out;
+            }
+        }
+        Ok(r)
+    }
+
+    /// Return a big integer variable with `num_limbs` limbs equal to `var`,
+    /// constrained to `bit_len` bits. The limbs beyond `bit_len` bits are
+    /// zero.
+    /// Return error if `bit_len` is not smaller than the bit size of the
+    /// native field or does not fit in `num_limbs` limbs, the value does not
+    /// fit in `bit_len` bits or the variable is invalid.
+    pub fn biguint_from_variable(
+        &mut self,
+        var: Variable,
+        bit_len: usize,
+        num_limbs: usize,
+    ) -> Result<BigUintVar, CircuitError> {
+        self.check_var_bound(var)?;
+        if bit_len == 0
+            || bit_len >= F::MODULUS_BIT_SIZE as usize
+            || bit_len > num_limbs * BIGUINT_LIMB_BITS
+        {
+            return Err(CircuitError::ParameterError(format!(
+                "cannot split an integer of {bit_len} bits into {num_limbs} limbs"
+            )));
+        }
+        let val: BigUint = self.witness(var)?.into();
+        if val.bits() as usize > bit_len {
+            return Err(CircuitError::ParameterError(format!(
+                "the integer of {} bits does not fit in {} bits",
+                val.bits(),
+                bit_len
+            )));
+        }
+        let limbs = self.biguint_limbs(&val, num_limbs)?;
+        let mut limb_vars = Vec::with_capacity(num_limbs);
+        for (i, limb) in limbs.into_iter().enumerate() {
+            let limb_bits = bit_len
+                .saturating_sub(i * BIGUINT_LIMB_BITS)
+                .min(BIGUINT_LIMB_BITS);
+            if limb_bits == 0 {
+                limb_vars.push(self.zero());
+            } else {
+                let limb_var = self.create_variable(limb)?;
+                self.enforce_in_range(limb_var, limb_bits)?;
+                limb_vars.push(limb_var);
+            }
+        }
+        // no wraparound since the integer is smaller than the native modulus
+        let sum = self.biguint_to_variable(&BigUintVar(limb_vars.clone()))?;
+        self.enforce_equal(sum, var)?;
+        Ok(BigUintVar(limb_vars))
+    }
+
+    /// Return a variable equal to the big integer `a` modulo the native
+    /// modulus.
+    /// Return error if the variable is invalid.
+    pub fn biguint_to_variable(&mut self, a: &BigUintVar) -> Result<Variable, CircuitError> {
+        self.check_vars_bound(&a.0)?;
+        let b_pow = F::from(2u32).pow([BIGUINT_LIMB_BITS as u64]);
+        let mut coeff = F::one();
+        let mut terms = Vec::with_capacity(a.0.len());
+        for &limb in a.0.iter() {
+            terms.push((coeff, limb));
+            coeff *= b_pow;
+        }
+        self.lin_comb_with_constant(&terms, F::zero())
+    }
+
+    /// Return `a^exp mod n` for a constant exponent `exp`, by square and
+    /// multiply. See [`Self::biguint_mul_mod()`] for the conditions on the
+    /// inputs.
+    /// Return error if `exp` is zero or the input variables are invalid.
+    pub fn biguint_pow_mod(
+        &mut self,
+        a: &BigUintVar,
+        exp: &BigUint,
+        n: &BigUintVar,
+    ) -> Result<BigUintVar, CircuitError> {
+        if *exp == BigUint::from(0u32) {
+            return Err(CircuitError::ParameterError(
+                "the exponent is zero".to_string(),
+            ));
+        }
+        let mut result = a.clone();
+        for i in (0..exp.bits() - 1).rev() {
+            result = self.biguint_mul_mod(&result, &result, n)?;
+            if exp.bit(i) {
+                result = self.biguint_mul_mod(&result, a, n)?;
+            }
+        }
+        Ok(result)
+    }
+
+    fn biguint_limbs(&self, val: &BigUint, num_limbs: usize) -> Result<Vec<F>, CircuitError> {
+        if val.bits() as usize > num_limbs * BIGUINT_LIMB_BITS {
+            return Err(CircuitError::ParameterError(format!(
+                "the integer of {} bits does not fit in {} limbs",
+                val.bits(),
+                num_limbs
+            )));
+        }
+        Ok(biguint_to_limbs(val, BIGUINT_LIMB_BITS, num_limbs))
+    }
+
+    fn check_biguint_num_limbs(&self, a: &BigUintVar, b: &BigUintVar) -> Result<(), CircuitError> {
+        if a.0.is_empty() || a.0.len() != b.0.len() {
+            return Err(CircuitError::ParameterError(format!(
+                "big integers of {} and {} limbs",
+                a.0.len(),
+                b.0.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_biguint_mul_mod() -> Result<(), CircuitError> {
+        test_biguint_mul_mod_helper::<FqEd254>()?;
+        test_biguint_mul_mod_helper::<FqEd377>()?;
+        test_biguint_mul_mod_helper::<FqEd381>()?;
+        test_biguint_mul_mod_helper::<Fq377>()
+    }
+
+    fn test_biguint_mul_mod_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let rng = &mut jf_utils::test_rng();
+        let num_limbs = 4;
+        let rand_biguint = |rng: &mut _, num_bits: u64| {
+            let limbs: Vec<u64> = (0..num_limbs).map(|_| u64::rand(rng)).collect();
+            let val = limbs
+                .iter()
+                .fold(BigUint::from(0u32), |acc, &limb| (acc << 64) + limb);
+            val >> (64 * num_limbs as u64 - num_bits)
+        };
+        for mut circuit in [
+            PlonkCircuit::<F>::new_turbo_plonk(),
+            PlonkCircuit::<F>::new_ultra_plonk(16),
+        ] {
+            let n_val = rand_biguint(rng, 256) | BigUint::from(1u32) << 255;
+            let a_val = rand_biguint(rng, 256) % &n_val;
+            let b_val = rand_biguint(rng, 256) % &n_val;
+            let exp = BigUint::from(65537u32);
+            let n = circuit.create_biguint_variable(&n_val, num_limbs)?;
+            let a = circuit.create_biguint_variable(&a_val, num_limbs)?;
+            let b = circuit.create_biguint_variable(&b_val, num_limbs)?;
+            let product = circuit.biguint_mul_mod(&a, &b, &n)?;
+            assert_eq!(circuit.biguint_witness(&product)?, &a_val * &b_val % &n_val);
+            let power = circuit.biguint_pow_mod(&a, &exp, &n)?;
+            assert_eq!(circuit.biguint_witness(&power)?, a_val.modpow(&exp, &n_val));
+            let expected =
+                circuit.create_constant_biguint_variable(&a_val.modpow(&exp, &n_val), num_limbs)?;
+            circuit.enforce_biguint_equal(&power, &expected)?;
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+            // a wrong limb of the product
+            *circuit.witness_mut(product.0[1]) += F::one();
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+            *circuit.witness_mut(product.0[1]) -= F::one();
+
+            // `a` not smaller than `n`
+            let mut circuit_clone = circuit.clone();
+            let a = circuit_clone.create_biguint_variable(&n_val, num_limbs)?;
+            circuit_clone.enforce_biguint_lt(&a, &n)?;
+            assert!(circuit_clone.check_circuit_satisfiability(&[]).is_err());
+
+            // mismatched numbers of limbs, and too large integers
+            let c = circuit.create_biguint_variable(&a_val, num_limbs + 1)?;
+            assert!(circuit.biguint_mul_mod(&a, &c, &n).is_err());
+            assert!(circuit
+                .create_biguint_variable(&(BigUint::from(1u32) << 256), num_limbs)
+                .is_err());
+            assert!(circuit
+                .biguint_pow_mod(&a, &BigUint::from(0u32), &n)
+                .is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_biguint_add_mod() -> Result<(), CircuitError> {
+        test_biguint_add_mod_helper::<FqEd254>()?;
+        test_biguint_add_mod_helper::<FqEd377>()?;
+        test_biguint_add_mod_helper::<FqEd381>()?;
+        test_biguint_add_mod_helper::<Fq377>()
+    }
+
+    fn test_biguint_add_mod_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let rng = &mut jf_utils::test_rng();
+        let num_limbs = 4;
+        for mut circuit in [
+            PlonkCircuit::<F>::new_turbo_plonk(),
+            PlonkCircuit::<F>::new_ultra_plonk(16),
+        ] {
+            // a modulus of 250 bits, and inputs fitting in a field element
+            let n_val: BigUint = F::rand(rng).into();
+            let n_val = (n_val % (BigUint::from(1u32) << 249)) | BigUint::from(1u32) << 249;
+            let n = circuit.create_constant_biguint_variable(&n_val, num_limbs)?;
+            for (a_val, b_val) in [
+                (F::rand(rng).into(), F::rand(rng).into()),
+                (&n_val - 1u32, &n_val - 1u32),
+                (BigUint::from(0u32), BigUint::from(5u32)),
+            ] {
+                let a_val: BigUint = a_val % &n_val;
+                let b_val: BigUint = b_val % &n_val;
+                let a_var = circuit.create_variable(F::from(a_val.clone()))?;
+                let a = circuit.biguint_from_variable(a_var, 250, num_limbs)?;
+                let b = circuit.create_biguint_variable(&b_val, num_limbs)?;
+                assert_eq!(circuit.biguint_witness(&a)?, a_val);
+                let sum = circuit.biguint_add_mod(&a, &b, &n)?;
+                assert_eq!(circuit.biguint_witness(&sum)?, (&a_val + &b_val) % &n_val);
+                let sum_var = circuit.biguint_to_variable(&sum)?;
+                assert_eq!(
+                    circuit.witness(sum_var)?,
+                    F::from((&a_val + &b_val) % &n_val)
+                );
+            }
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+            // a wrong limb of the sum
+            let a = circuit.create_biguint_variable(&(&n_val - 1u32), num_limbs)?;
+            let sum = circuit.biguint_add_mod(&a, &a, &n)?;
+            *circuit.witness_mut(sum.0[0]) += F::one();
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+            *circuit.witness_mut(sum.0[0]) -= F::one();
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+            // a variable larger than its bit length
+            let var = circuit.create_variable(F::from(8u8))?;
+            let a = circuit.biguint_from_variable(var, 4, 1)?;
+            *circuit.witness_mut(var) = F::from(16u8);
+            *circuit.witness_mut(a.0[0]) = F::from(16u8);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+            // error paths
+            assert!(circuit.biguint_from_variable(var, 3, 1).is_err());
+            assert!(circuit.biguint_from_variable(var, 65, 1).is_err());
+            assert!(circuit
+                .biguint_from_variable(var, F::MODULUS_BIT_SIZE as usize, num_limbs)
+                .is_err());
+            let c = circuit.create_biguint_variable(&n_val, num_limbs + 1)?;
+            assert!(circuit.biguint_add_mod(&a, &c, &n).is_err());
+        }
+        Ok(())
+    }
+}
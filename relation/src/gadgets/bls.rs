@@ -0,0 +1,557 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! BLS signature verification over BLS12-381 with signatures in G1, e.g. the
+//! `BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_` ciphersuite, with the base
+//! field emulated, e.g. over the BN254 scalar field for light clients
+//! verifying consensus signatures.
+//!
+//! The messages are hashed to G1 in the circuit as the
+//! `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite of RFC 9380: `expand_message_xmd`
+//! with SHA-256, the simplified SWU map to a curve isogenous to G1 followed
+//! by the 11-isogeny, and cofactor clearing by `h_eff`. The pairings are then
+//! deferred to a single check `e(σ, -g2) * Π e(H(m_i), pk_i) = 1`, sharing
+//! the squarings of the Miller loop and the final exponentiation between all
+//! the messages of an aggregate signature.
+
+use crate::{
+    gadgets::{
+        ecc::emulated::EmulatedSWPointVariable, pairing::EmulatedBls12G2PointVariable,
+        EmulatedVariable, EmulationConfig,
+    },
+    BoolVar, Circuit, CircuitError, PlonkCircuit, Variable,
+};
+use ark_bls12_381::{g1::Config as G1Config, Config, Fq, G1Affine, G2Affine};
+use ark_ec::{
+    hashing::curve_maps::{swu::SWUConfig, wb::WBConfig},
+    short_weierstrass::SWCurveConfig,
+    AffineRepr,
+};
+use ark_ff::{BigInteger, BitIteratorBE, Field, MontFp, PrimeField};
+use ark_std::{format, marker::PhantomData, string::ToString, vec, vec::Vec};
+use num_bigint::BigUint;
+
+/// `|x|` for the parameter `x = -0xd201000000010000` of BLS12-381.
+const X_ABS: u64 = 0xd201000000010000;
+
+/// The effective cofactor `h_eff = 1 - x` of G1, see RFC 9380, section 8.8.1.
+const H_EFF: u64 = 0xd201000000010001;
+
+/// The cube root of unity `β` such that `(x, y) -> (β * x, y)` is the
+/// multiplication by `-x^2` on G1.
+const BETA: Fq = MontFp!("793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620350");
+
+/// Length in bytes of the output of `expand_message_xmd`: two field elements
+/// of `ceil((381 + 128) / 8) = 64` bytes.
+const UNIFORM_BYTES_LEN: usize = 128;
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Add a variable for a BLS signature over BLS12-381, constraining it to
+    /// be a point of G1 other than the point at infinity.
+    pub fn create_bls12_381_signature_variable(
+        &mut self,
+        sig: G1Affine,
+    ) -> Result<EmulatedSWPointVariable<Fq>, CircuitError>
+    where
+        Fq: EmulationConfig<F>,
+    {
+        let sig = self.create_emulated_sw_point_variable(sig.into())?;
+        self.enforce_bls12_381_g1_membership(&sig)?;
+        Ok(sig)
+    }
+
+    /// Constrain `sig` to be a valid BLS signature of the message `msg`,
+    /// given as byte variables, under the public key `pk`, with the domain
+    /// separation tag `dst` of the ciphersuite: `e(sig, g2) = e(H(msg), pk)`
+    /// where `H` is [`Self::bls12_381_hash_to_g1()`].
+    ///
+    /// The signature should be created with
+    /// [`Self::create_bls12_381_signature_variable()`]. The public key is
+    /// assumed to be in G2, e.g. constant or committed to outside of the
+    /// circuit, otherwise the circuit may be unsatisfiable.
+    /// Return error if the input variables are invalid or `dst` is empty or
+    /// longer than 255 bytes.
+    pub fn enforce_bls12_381_signature(
+        &mut self,
+        pk: &EmulatedBls12G2PointVariable<Fq>,
+        msg: &[Variable],
+        sig: &EmulatedSWPointVariable<Fq>,
+        dst: &[u8],
+    ) -> Result<(), CircuitError>
+    where
+        Fq: EmulationConfig<F>,
+    {
+        self.enforce_bls12_381_aggregate_signature(&[pk.clone()], &[msg.to_vec()], sig, dst)
+    }
+
+    /// Constrain `sig` to be a valid aggregate signature of the messages
+    /// `msgs` under the public keys `pks`, in the same order: `e(sig, g2) =
+    /// Π e(H(msgs[i]), pks[i])`, checked as a single product of pairings. See
+    /// [`Self::enforce_bls12_381_signature()`] for the conditions on the
+    /// inputs.
+    ///
+    /// As for the aggregate verification of the [BLS draft][agg], the
+    /// messages should be distinct unless the public keys come with proofs of
+    /// possession, which is left to the caller.
+    /// Return error if `pks` and `msgs` are empty or of different lengths, if
+    /// the input variables are invalid or `dst` is empty or longer than 255
+    /// bytes.
+    ///
+    /// [agg]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-05#section-3.1.1
+    pub fn enforce_bls12_381_aggregate_signature(
+        &mut self,
+        pks: &[EmulatedBls12G2PointVariable<Fq>],
+        msgs: &[Vec<Variable>],
+        sig: &EmulatedSWPointVariable<Fq>,
+        dst: &[u8],
+    ) -> Result<(), CircuitError>
+    where
+        Fq: EmulationConfig<F>,
+    {
+        if pks.is_empty() {
+            return Err(CircuitError::ParameterError(
+                "no public keys to verify the signature against".to_string(),
+            ));
+        }
+        if pks.len() != msgs.len() {
+            return Err(CircuitError::ParameterError(format!(
+                "{} public keys for {} messages",
+                pks.len(),
+                msgs.len()
+            )));
+        }
+        let neg_g2 = self
+            .create_constant_emulated_bls12_g2_point_variable::<Config>(-G2Affine::generator())?;
+        let mut pairs = vec![(sig.clone(), neg_g2)];
+        for (pk, msg) in pks.iter().zip(msgs.iter()) {
+            let msg_point = self.bls12_381_hash_to_g1(msg, dst)?;
+            pairs.push((msg_point, pk.clone()));
+        }
+        self.enforce_emulated_bls12_pairing_check::<Config>(&pairs)
+    }
+
+    /// Return the hash of the message `msg`, given as byte variables, to G1
+    /// of BLS12-381 with the domain separation tag `dst`, as the
+    /// `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite of RFC 9380. The circuit is
+    /// unsatisfiable in the exceptional cases of the map to the curve, which
+    /// occur with negligible probability.
+    /// Return error if the input variables are invalid or `dst` is empty or
+    /// longer than 255 bytes.
+    pub fn bls12_381_hash_to_g1(
+        &mut self,
+        msg: &[Variable],
+        dst: &[u8],
+    ) -> Result<EmulatedSWPointVariable<Fq>, CircuitError>
+    where
+        Fq: EmulationConfig<F>,
+    {
+        let bits = self.expand_message_xmd_sha256(msg, dst, UNIFORM_BYTES_LEN)?;
+        // each half is a big-endian integer of 512 bits, reduced modulo p as
+        // `hi * 2^256 + lo`
+        let mut points = vec![];
+        for chunk in bits.chunks(bits.len() / 2) {
+            let (hi, lo) = chunk.split_at(chunk.len() / 2);
+            let hi = self.emulated_var_from_bits_be::<Fq>(hi)?;
+            let lo = self.emulated_var_from_bits_be::<Fq>(lo)?;
+            let shift = Fq::from(BigUint::from(1u32) << (chunk.len() / 2));
+            let u = self.emulated_mul_constant(&hi, shift)?;
+            let u = self.emulated_add(&u, &lo)?;
+            points.push(self.emulated_wb_map_to_curve::<G1Config>(&u)?);
+        }
+        let point = self.emulated_sw_ecc_add(&points[0], &points[1], G1Config::COEFF_A)?;
+        self.emulated_sw_mul_by_u64(&point, H_EFF, G1Config::COEFF_A)
+    }
+
+    /// Constrain `p` to be a point of G1 other than the point at infinity,
+    /// with the endomorphism test of <https://eprint.iacr.org/2021/1130>,
+    /// section 6: `(β * x, y) = -x^2 * (x, y)`.
+    fn enforce_bls12_381_g1_membership(
+        &mut self,
+        p: &EmulatedSWPointVariable<Fq>,
+    ) -> Result<(), CircuitError>
+    where
+        Fq: EmulationConfig<F>,
+    {
+        self.enforce_emulated_sw_point_on_curve::<G1Config>(p)?;
+        let x_p = self.emulated_sw_mul_by_u64(p, X_ABS, G1Config::COEFF_A)?;
+        let x_sq_p = self.emulated_sw_mul_by_u64(&x_p, X_ABS, G1Config::COEFF_A)?;
+        self.enforce_false(x_sq_p.2.into())?;
+        let beta_x = self.emulated_mul_constant(&p.0, BETA)?;
+        self.enforce_emulated_var_equal(&x_sq_p.0, &beta_x)?;
+        let neg_y = self.emulated_mul_constant(&p.1, -Fq::ONE)?;
+        self.enforce_emulated_var_equal(&x_sq_p.1, &neg_y)
+    }
+
+    /// Return the big-endian bits of the `len` bytes output by
+    /// `expand_message_xmd` of RFC 9380, section 5.3.1, with SHA-256.
+    fn expand_message_xmd_sha256(
+        &mut self,
+        msg: &[Variable],
+        dst: &[u8],
+        len: usize,
+    ) -> Result<Vec<BoolVar>, CircuitError> {
+        if dst.is_empty() || dst.len() > 255 {
+            return Err(CircuitError::ParameterError(format!(
+                "domain separation tag of {} bytes, expecting 1 to 255",
+                dst.len()
+            )));
+        }
+        let dst_prime = dst
+            .iter()
+            .copied()
+            .chain([dst.len() as u8])
+            .map(|byte| self.create_constant_variable(F::from(byte)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // b_0 = H(Z_pad || msg || I2OSP(len, 2) || I2OSP(0, 1) || DST_prime)
+        let mut input = vec![self.zero(); 64];
+        input.extend_from_slice(msg);
+        for byte in [(len >> 8) as u8, len as u8, 0] {
+            input.push(self.create_constant_variable(F::from(byte))?);
+        }
+        input.extend_from_slice(&dst_prime);
+        let b_0 = self.sha256_bits(&input)?;
+
+        // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime),
+        // b_i = H((b_0 XOR b_{i-1}) || I2OSP(i, 1) || DST_prime)
+        let mut uniform_bits = vec![];
+        let mut b_i: Vec<BoolVar> = vec![];
+        for i in 1..=(len + 31) / 32 {
+            let chained = if i == 1 {
+                b_0.clone()
+            } else {
+                b_0.iter()
+                    .zip(b_i.iter())
+                    .map(|(&a, &b)| self.bit_xor(a, b))
+                    .collect::<Result<Vec<_>, _>>()?
+            };
+            let mut input = chained
+                .chunks(8)
+                .map(|byte| {
+                    let terms: Vec<_> = byte
+                        .iter()
+                        .enumerate()
+                        .map(|(j, bit)| (F::from(1u32 << (7 - j)), bit.0))
+                        .collect();
+                    self.lin_comb_with_constant(&terms, F::zero())
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            input.push(self.create_constant_variable(F::from(i as u8))?);
+            input.extend_from_slice(&dst_prime);
+            b_i = self.sha256_bits(&input)?;
+            uniform_bits.extend_from_slice(&b_i);
+        }
+        uniform_bits.truncate(len * 8);
+        Ok(uniform_bits)
+    }
+
+    /// Return `a XOR b` for boolean variables `a` and `b`.
+    fn bit_xor(&mut self, a: BoolVar, b: BoolVar) -> Result<BoolVar, CircuitError> {
+        // a + b - 2 * a * b
+        let c = self.gen_quad_poly(
+            &[a.into(), b.into(), self.zero(), self.zero()],
+            &[F::one(), F::one(), F::zero(), F::zero()],
+            &[-F::from(2u8), F::zero()],
+            F::zero(),
+        )?;
+        Ok(BoolVar(c))
+    }
+
+    /// Return the emulated variable of the integer with big-endian bits
+    /// `bits`, which are fewer than `E::MODULUS_BIT_SIZE` so that it is
+    /// reduced.
+    fn emulated_var_from_bits_be<E: EmulationConfig<F>>(
+        &mut self,
+        bits: &[BoolVar],
+    ) -> Result<EmulatedVariable<E>, CircuitError> {
+        let bits_le: Vec<_> = bits.iter().rev().copied().collect();
+        let mut limbs = bits_le
+            .chunks(E::B)
+            .map(|chunk| {
+                let terms: Vec<_> = chunk
+                    .iter()
+                    .enumerate()
+                    .map(|(i, bit)| (F::from(2u8).pow([i as u64]), bit.0))
+                    .collect();
+                self.lin_comb_with_constant(&terms, F::zero())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        limbs.resize(E::NUM_LIMBS, self.zero());
+        Ok(EmulatedVariable(limbs, PhantomData))
+    }
+
+    /// Return the image of `u` by the simplified SWU map to the curve
+    /// isogenous to `P`, followed by the isogeny to `P`, as in RFC 9380,
+    /// section 6.6.3. The exceptional cases, i.e. a zero denominator in the
+    /// map or the isogeny, make the circuit unsatisfiable.
+    fn emulated_wb_map_to_curve<P>(
+        &mut self,
+        u: &EmulatedVariable<P::BaseField>,
+    ) -> Result<EmulatedSWPointVariable<P::BaseField>, CircuitError>
+    where
+        P: WBConfig,
+        P::BaseField: EmulationConfig<F>,
+    {
+        let a = <P::IsogenousCurve as SWCurveConfig>::COEFF_A;
+        let b = <P::IsogenousCurve as SWCurveConfig>::COEFF_B;
+        let z = <P::IsogenousCurve as SWUConfig>::ZETA;
+        // sgn0(u), the parity of the reduced `u`
+        self.enforce_emulated_var_reduced(u)?;
+        let u_sign = self.unpack(u.0[0], P::BaseField::B)?[0];
+
+        // x1 = -B / A * (1 + 1 / (Z^2 * u^4 + Z * u^2)), x2 = Z * u^2 * x1
+        let u_sq = self.emulated_mul(u, u)?;
+        let z_u_sq = self.emulated_mul_constant(&u_sq, z)?;
+        let ta = self.emulated_mul(&z_u_sq, &z_u_sq)?;
+        let ta = self.emulated_add(&ta, &z_u_sq)?;
+        let ta_inv = self.emulated_inverse(&ta)?;
+        let x1 = self.emulated_add_constant(&ta_inv, P::BaseField::ONE)?;
+        let x1 = self.emulated_mul_constant(&x1, -b / a)?;
+        let x2 = self.emulated_mul(&z_u_sq, &x1)?;
+        let mut gxs = vec![];
+        for x in [&x1, &x2] {
+            // x^3 + A * x + B
+            let x_sq = self.emulated_mul(x, x)?;
+            let x_cube = self.emulated_mul(&x_sq, x)?;
+            let a_x = self.emulated_mul_constant(x, a)?;
+            let gx = self.emulated_add(&x_cube, &a_x)?;
+            gxs.push(self.emulated_add_constant(&gx, b)?);
+        }
+
+        // As `Z` is not a square, `g(x2) = Z^3 * u^6 * g(x1)` is a square if
+        // and only if `g(x1)` is not, up to the negligible case `g(x1) = 0`:
+        // a square root of the selected `g(x)` proves the selection.
+        let gx1_is_square = self.emulated_witness(&gxs[0])?.sqrt().is_some();
+        let gx1_is_square = self.create_boolean_variable(gx1_is_square)?;
+        let x = self.conditional_select_emulated(gx1_is_square, &x2, &x1)?;
+        let gx = self.conditional_select_emulated(gx1_is_square, &gxs[1], &gxs[0])?;
+        // y = ±sqrt(g(x)) with sgn0(y) = sgn0(u)
+        let u_odd = self.witness(u_sign.into())? == F::one();
+        let y = self.emulated_witness(&gx)?.sqrt().unwrap_or_default();
+        let y = if y.into_bigint().is_odd() == u_odd {
+            y
+        } else {
+            -y
+        };
+        let y = self.create_emulated_variable(y)?;
+        self.enforce_emulated_var_reduced(&y)?;
+        self.emulated_mul_gate(&y, &y, &gx)?;
+        let y_sign = self.unpack(y.0[0], P::BaseField::B)?[0];
+        self.enforce_equal(y_sign.into(), u_sign.into())?;
+
+        // (x, y) -> (x_num(x) / x_den(x), y * y_num(x) / y_den(x))
+        let map = P::ISOGENY_MAP;
+        let degree = [
+            map.x_map_numerator.len(),
+            map.x_map_denominator.len(),
+            map.y_map_numerator.len(),
+            map.y_map_denominator.len(),
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or_default();
+        // x, x^2, ..., x^(degree - 1)
+        let mut powers = vec![x.clone()];
+        for _ in 2..degree {
+            let power = self.emulated_mul(&powers[powers.len() - 1], &x)?;
+            powers.push(power);
+        }
+        let x_num = self.emulated_poly_eval(&powers, map.x_map_numerator)?;
+        let x_den = self.emulated_poly_eval(&powers, map.x_map_denominator)?;
+        let y_num = self.emulated_poly_eval(&powers, map.y_map_numerator)?;
+        let y_den = self.emulated_poly_eval(&powers, map.y_map_denominator)?;
+        let x = self.emulated_div(&x_num, &x_den)?;
+        let y = self.emulated_mul(&y, &y_num)?;
+        let y = self.emulated_div(&y, &y_den)?;
+        Ok(EmulatedSWPointVariable(x, y, BoolVar(self.zero())))
+    }
+
+    /// Evaluate the polynomial with coefficients `coeffs`, in ascending
+    /// degree, at the point with powers `powers`, starting from the first.
+    fn emulated_poly_eval<E: EmulationConfig<F>>(
+        &mut self,
+        powers: &[EmulatedVariable<E>],
+        coeffs: &[E],
+    ) -> Result<EmulatedVariable<E>, CircuitError> {
+        let mut acc =
+            self.create_constant_emulated_variable(coeffs.first().copied().unwrap_or_default())?;
+        for (power, &coeff) in powers.iter().zip(coeffs.iter().skip(1)) {
+            if !coeff.is_zero() {
+                let term = self.emulated_mul_constant(power, coeff)?;
+                acc = self.emulated_add(&acc, &term)?;
+            }
+        }
+        Ok(acc)
+    }
+
+    /// Return `scalar * p` on the curve with coefficient `a`, by
+    /// double-and-add. `scalar` should be non-zero.
+    fn emulated_sw_mul_by_u64<E: EmulationConfig<F>>(
+        &mut self,
+        p: &EmulatedSWPointVariable<E>,
+        scalar: u64,
+        a: E,
+    ) -> Result<EmulatedSWPointVariable<E>, CircuitError> {
+        let mut acc = p.clone();
+        for bit in BitIteratorBE::without_leading_zeros([scalar]).skip(1) {
+            acc = self.emulated_sw_ecc_add(&acc, &acc, a)?;
+            if bit {
+                acc = self.emulated_sw_ecc_add(&acc, p, a)?;
+            }
+        }
+        Ok(acc)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gadgets::ecc::emulated::SWPoint;
+    use ark_bls12_381::{Fr, G2Projective};
+    use ark_bn254::Fr as Fr254;
+    use ark_ec::{
+        hashing::{curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve},
+        short_weierstrass::Projective,
+        CurveGroup, Group,
+    };
+    use ark_ff::field_hashers::DefaultFieldHasher;
+    use ark_std::UniformRand;
+    use sha2::Sha256;
+
+    const DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+
+    fn hash_to_g1(msg: &[u8]) -> G1Affine {
+        MapToCurveBasedHasher::<
+            Projective<G1Config>,
+            DefaultFieldHasher<Sha256, 128>,
+            WBMap<G1Config>,
+        >::new(DST)
+        .unwrap()
+        .hash(msg)
+        .unwrap()
+    }
+
+    #[test]
+    fn test_bls12_381_hash_to_g1() -> Result<(), CircuitError> {
+        let msgs: [&[u8]; 3] = [b"", b"abc", &[0x5a; 100]];
+        for msg in msgs {
+            let mut circuit = PlonkCircuit::<Fr254>::new_ultra_plonk(16);
+            let msg_vars = circuit.create_byte_variables(msg)?;
+            let point = circuit.bls12_381_hash_to_g1(&msg_vars, DST)?;
+            assert_eq!(
+                circuit.emulated_sw_point_witness(&point)?,
+                SWPoint::from(hash_to_g1(msg))
+            );
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+            // invalid domain separation tags
+            assert!(circuit.bls12_381_hash_to_g1(&msg_vars, b"").is_err());
+            assert!(circuit.bls12_381_hash_to_g1(&msg_vars, &[0; 256]).is_err());
+
+            // wrong output
+            let witness = circuit.witness(point.1 .0[0])?;
+            *circuit.witness_mut(point.1 .0[0]) = witness + Fr254::from(1u8);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bls12_381_g1_membership() -> Result<(), CircuitError> {
+        let rng = &mut jf_utils::test_rng();
+        // the endomorphism is the multiplication by -x^2
+        let p = Projective::<G1Config>::rand(rng).into_affine();
+        let x_sq = Fr::from(X_ABS) * Fr::from(X_ABS);
+        assert_eq!(
+            G1Affine::new_unchecked(BETA * p.x, p.y),
+            (-(p * x_sq)).into_affine()
+        );
+
+        let mut circuit = PlonkCircuit::<Fr254>::new_ultra_plonk(16);
+        circuit.create_bls12_381_signature_variable(p)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        // a point on the curve outside of G1
+        let mut x = Fq::rand(rng);
+        let q = loop {
+            if let Some(q) = G1Affine::get_point_from_x_unchecked(x, false) {
+                break q;
+            }
+            x += Fq::ONE;
+        };
+        assert!(!q.is_in_correct_subgroup_assuming_on_curve());
+        let mut circuit = PlonkCircuit::<Fr254>::new_ultra_plonk(16);
+        circuit.create_bls12_381_signature_variable(q)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        Ok(())
+    }
+
+    fn build_signature_circuit(
+        pks: &[G2Affine],
+        msgs: &[&[u8]],
+        sig: G1Affine,
+    ) -> Result<PlonkCircuit<Fr254>, CircuitError> {
+        let mut circuit = PlonkCircuit::<Fr254>::new_ultra_plonk(16);
+        let pks = pks
+            .iter()
+            .map(|&pk| circuit.create_constant_emulated_bls12_g2_point_variable::<Config>(pk))
+            .collect::<Result<Vec<_>, _>>()?;
+        let msgs = msgs
+            .iter()
+            .map(|msg| circuit.create_byte_variables(msg))
+            .collect::<Result<Vec<_>, _>>()?;
+        let sig = circuit.create_bls12_381_signature_variable(sig)?;
+        if pks.len() == 1 {
+            circuit.enforce_bls12_381_signature(&pks[0], &msgs[0], &sig, DST)?;
+        } else {
+            circuit.enforce_bls12_381_aggregate_signature(&pks, &msgs, &sig, DST)?;
+        }
+        Ok(circuit)
+    }
+
+    // A full pairing costs millions of constraints, run with `--release`.
+    #[test]
+    #[ignore]
+    fn test_bls12_381_signature() -> Result<(), CircuitError> {
+        let rng = &mut jf_utils::test_rng();
+        let msgs: [&[u8]; 2] = [b"first block header", b"second block header"];
+        let sks = [Fr::rand(rng), Fr::rand(rng)];
+        let pks = sks.map(|sk| (G2Projective::generator() * sk).into_affine());
+        let sigs = [
+            (hash_to_g1(msgs[0]) * sks[0]).into_affine(),
+            (hash_to_g1(msgs[1]) * sks[1]).into_affine(),
+        ];
+
+        let circuit = build_signature_circuit(&pks[..1], &msgs[..1], sigs[0])?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        // wrong public key, message and signature
+        let circuit = build_signature_circuit(&pks[1..], &msgs[..1], sigs[0])?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        let circuit = build_signature_circuit(&pks[..1], &msgs[1..], sigs[0])?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        let circuit = build_signature_circuit(&pks[..1], &msgs[..1], sigs[1])?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // aggregate signature
+        let agg_sig = (sigs[0] + sigs[1]).into_affine();
+        let circuit = build_signature_circuit(&pks, &msgs, agg_sig)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        let circuit = build_signature_circuit(&pks, &msgs, sigs[0])?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // mismatched inputs
+        let mut circuit = PlonkCircuit::<Fr254>::new_ultra_plonk(16);
+        let sig = circuit.create_bls12_381_signature_variable(agg_sig)?;
+        assert!(circuit
+            .enforce_bls12_381_aggregate_signature(&[], &[], &sig, DST)
+            .is_err());
+        let pk = circuit.create_constant_emulated_bls12_g2_point_variable::<Config>(pks[0])?;
+        assert!(circuit
+            .enforce_bls12_381_aggregate_signature(&[pk], &[], &sig, DST)
+            .is_err());
+        Ok(())
+    }
+}
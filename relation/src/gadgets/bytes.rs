@@ -0,0 +1,450 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Gadgets on strings given as byte variables, e.g. the JSON payloads or the
+//! headers of signed emails: packing into field elements, UTF-8 validation,
+//! equality and substrings.
+//!
+//! Each byte is a variable constrained to 8 bits. Bytes are packed in
+//! little-endian order, as many as fit in a field element without overflow.
+
+use crate::{BoolVar, Circuit, CircuitError, PlonkCircuit, Variable};
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::{format, vec::Vec};
+
+// The states of the automaton validating UTF-8: the number of continuation
+// bytes still expected, and whether the next one has a narrower range to
+// rule out overlong encodings, surrogates and code points beyond U+10FFFF.
+const UTF8_ACCEPT: u8 = 0;
+const UTF8_CONT_1: u8 = 1;
+const UTF8_CONT_2: u8 = 2;
+const UTF8_CONT_3: u8 = 3;
+const UTF8_AFTER_E0: u8 = 4;
+const UTF8_AFTER_ED: u8 = 5;
+const UTF8_AFTER_F0: u8 = 6;
+const UTF8_AFTER_F4: u8 = 7;
+const UTF8_REJECT: u8 = 8;
+// the number of states with transitions, i.e. all but the rejecting one
+const UTF8_NUM_STATES: u8 = 8;
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Add variables for the bytes `bytes`, each constrained to 8 bits.
+    pub fn create_byte_variables(&mut self, bytes: &[u8]) -> Result<Vec<Variable>, CircuitError> {
+        bytes
+            .iter()
+            .map(|&byte| {
+                let var = self.create_variable(F::from(byte))?;
+                self.enforce_in_range(var, 8)?;
+                Ok(var)
+            })
+            .collect()
+    }
+
+    /// Return variables for the bytes `bytes` packed into field elements, in
+    /// little-endian order, with [`bytes_per_field_element()`] bytes per
+    /// element except maybe the last one. Each byte is constrained to 8 bits,
+    /// so that the packing is injective.
+    /// Return error if the input variables are invalid.
+    pub fn pack_bytes(&mut self, bytes: &[Variable]) -> Result<Vec<Variable>, CircuitError> {
+        self.check_vars_bound(bytes)?;
+        for &byte in bytes.iter() {
+            self.enforce_in_range(byte, 8)?;
+        }
+        bytes
+            .chunks(bytes_per_field_element::<F>())
+            .map(|chunk| {
+                let terms: Vec<_> = chunk
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &byte)| (F::from(2u8).pow([8 * i as u64]), byte))
+                    .collect();
+                self.lin_comb_with_constant(&terms, F::zero())
+            })
+            .collect()
+    }
+
+    /// Return the `num_bytes` little-endian bytes of `elem`, each constrained
+    /// to 8 bits, i.e. the inverse of [`Self::pack_bytes()`].
+    /// Return error if `num_bytes` exceeds [`bytes_per_field_element()`], the
+    /// value of `elem` does not fit in `num_bytes` bytes or the variable is
+    /// invalid.
+    pub fn unpack_bytes(
+        &mut self,
+        elem: Variable,
+        num_bytes: usize,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        self.check_var_bound(elem)?;
+        if num_bytes > bytes_per_field_element::<F>() {
+            return Err(CircuitError::ParameterError(format!(
+                "a field element packs at most {} bytes, not {}",
+                bytes_per_field_element::<F>(),
+                num_bytes
+            )));
+        }
+        let val = self.witness(elem)?.into_bigint().to_bytes_le();
+        if val[num_bytes..].iter().any(|&byte| byte != 0) {
+            return Err(CircuitError::ParameterError(format!(
+                "the value {} does not fit in {} bytes",
+                self.witness(elem)?,
+                num_bytes
+            )));
+        }
+        let bytes = self.create_byte_variables(&val[..num_bytes])?;
+        let terms: Vec<_> = bytes
+            .iter()
+            .enumerate()
+            .map(|(i, &byte)| (F::from(2u8).pow([8 * i as u64]), byte))
+            .collect();
+        let packed = self.lin_comb_with_constant(&terms, F::zero())?;
+        self.enforce_equal(packed, elem)?;
+        Ok(bytes)
+    }
+
+    /// Constrain the bytes `bytes` to be a valid UTF-8 encoding, and each
+    /// byte to 8 bits. The bytes go through an automaton whose transitions
+    /// are looked up in a table of 2048 rows created by each call.
+    /// Return error if the circuit does not support lookup or the variables
+    /// are invalid.
+    pub fn enforce_utf8(&mut self, bytes: &[Variable]) -> Result<(), CircuitError> {
+        self.check_vars_bound(bytes)?;
+        if !self.support_lookup() {
+            return Err(CircuitError::LookupUnsupported);
+        }
+        if bytes.is_empty() {
+            return Ok(());
+        }
+        let zero = self.zero();
+        let states = (0..=UTF8_REJECT)
+            .map(|state| self.create_constant_variable(F::from(state)))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        // the row of the transition from `state` on `byte` is 256 * state + byte
+        let table: Vec<_> = (0..UTF8_NUM_STATES)
+            .flat_map(|state| (0..=255u8).map(move |byte| (state, byte)))
+            .map(|(state, byte)| (states[utf8_transition(state, byte) as usize], zero))
+            .collect();
+
+        let mut state = UTF8_ACCEPT;
+        let mut state_var = states[UTF8_ACCEPT as usize];
+        let mut lookups = Vec::with_capacity(bytes.len());
+        for &byte in bytes.iter() {
+            self.enforce_in_range(byte, 8)?;
+            let key = self.lin_comb_with_constant(
+                &[(F::from(256u32), state_var), (F::one(), byte)],
+                F::zero(),
+            )?;
+            // an invalid encoding leads to an unsatisfied circuit
+            let byte_val = self.witness(byte)?.into_bigint().as_ref()[0] as u8;
+            state = match state {
+                UTF8_REJECT => UTF8_REJECT,
+                _ => utf8_transition(state, byte_val),
+            };
+            state_var = self.create_variable(F::from(state))?;
+            lookups.push((key, state_var, zero));
+        }
+        self.enforce_constant(state_var, F::from(UTF8_ACCEPT))?;
+        self.create_table_and_lookup_variables(&lookups, &table)
+    }
+
+    /// Constrain the strings `a` and `b` of the same length to be equal.
+    /// Return error if the lengths differ or the variables are invalid.
+    pub fn enforce_bytes_equal(
+        &mut self,
+        a: &[Variable],
+        b: &[Variable],
+    ) -> Result<(), CircuitError> {
+        check_same_length(a, b)?;
+        for (&a, &b) in a.iter().zip(b.iter()) {
+            self.enforce_equal(a, b)?;
+        }
+        Ok(())
+    }
+
+    /// Return a boolean variable for whether the strings `a` and `b` of the
+    /// same length are equal.
+    /// Return error if the lengths differ or the variables are invalid.
+    pub fn is_bytes_equal(
+        &mut self,
+        a: &[Variable],
+        b: &[Variable],
+    ) -> Result<BoolVar, CircuitError> {
+        check_same_length(a, b)?;
+        if a.is_empty() {
+            return Ok(self.true_var());
+        }
+        let eqs = a
+            .iter()
+            .zip(b.iter())
+            .map(|(&a, &b)| self.is_equal(a, b))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        self.logic_and_all(&eqs)
+    }
+
+    /// Constrain the string `needle` to be the substring of `haystack`
+    /// starting at position `offset`, which is constrained to be at most
+    /// `haystack.len() - needle.len()`.
+    /// With lookup, the bytes of `needle` are looked up in a table of
+    /// `haystack`; otherwise the substring is selected among all the
+    /// positions, which takes gates quadratic in the lengths.
+    /// Return error if `needle` is longer than `haystack` or the variables
+    /// are invalid.
+    pub fn enforce_substring(
+        &mut self,
+        haystack: &[Variable],
+        needle: &[Variable],
+        offset: Variable,
+    ) -> Result<(), CircuitError> {
+        self.check_vars_bound(haystack)?;
+        self.check_vars_bound(needle)?;
+        self.check_var_bound(offset)?;
+        if needle.len() > haystack.len() {
+            return Err(CircuitError::ParameterError(format!(
+                "a substring of {} bytes does not fit in {} bytes",
+                needle.len(),
+                haystack.len()
+            )));
+        }
+        if needle.is_empty() {
+            return Ok(());
+        }
+        match self.support_lookup() {
+            true => self.substring_with_lookup(haystack, needle, offset),
+            false => self.substring_with_selection(haystack, needle, offset),
+        }
+    }
+
+    // Look up each `(offset + j, needle[j])` in the table of the positions and
+    // the bytes of `haystack`. The first and the last lookups bound `offset`.
+    fn substring_with_lookup(
+        &mut self,
+        haystack: &[Variable],
+        needle: &[Variable],
+        offset: Variable,
+    ) -> Result<(), CircuitError> {
+        let zero = self.zero();
+        let table: Vec<_> = haystack.iter().map(|&byte| (byte, zero)).collect();
+        let lookups = needle
+            .iter()
+            .enumerate()
+            .map(|(j, &byte)| {
+                let pos = match j {
+                    0 => offset,
+                    _ => self.add_constant(offset, &F::from(j as u64))?,
+                };
+                Ok((pos, byte, zero))
+            })
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        self.create_table_and_lookup_variables(&lookups, &table)
+    }
+
+    // Select the substring at `offset` with the indicators of the positions
+    // `i == offset`, exactly one of which is set.
+    fn substring_with_selection(
+        &mut self,
+        haystack: &[Variable],
+        needle: &[Variable],
+        offset: Variable,
+    ) -> Result<(), CircuitError> {
+        let num_positions = haystack.len() - needle.len() + 1;
+        let selectors = (0..num_positions)
+            .map(|i| {
+                let diff = self.add_constant(offset, &-F::from(i as u64))?;
+                Ok(self.is_zero(diff)?.into())
+            })
+            .collect::<Result<Vec<Variable>, CircuitError>>()?;
+        let num_selected = self.sum(&selectors)?;
+        self.enforce_constant(num_selected, F::one())?;
+
+        for (j, &byte) in needle.iter().enumerate() {
+            // sum_i selectors[i] * haystack[i + j], two products per gate
+            let products = selectors
+                .chunks(2)
+                .zip(haystack[j..].chunks(2))
+                .map(|(sel, hay)| match sel.len() {
+                    2 => self.mul_add(&[sel[0], hay[0], sel[1], hay[1]], &[F::one(), F::one()]),
+                    _ => self.mul(sel[0], hay[0]),
+                })
+                .collect::<Result<Vec<_>, CircuitError>>()?;
+            let selected = self.sum(&products)?;
+            self.enforce_equal(selected, byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// The number of bytes packed in a field element by
+/// [`PlonkCircuit::pack_bytes()`], i.e. the largest number of bytes whose
+/// values are all smaller than the modulus.
+pub fn bytes_per_field_element<F: PrimeField>() -> usize {
+    (F::MODULUS_BIT_SIZE as usize - 1) / 8
+}
+
+fn check_same_length(a: &[Variable], b: &[Variable]) -> Result<(), CircuitError> {
+    if a.len() != b.len() {
+        return Err(CircuitError::ParameterError(format!(
+            "strings of different lengths {} and {}",
+            a.len(),
+            b.len()
+        )));
+    }
+    Ok(())
+}
+
+// The transition of the UTF-8 automaton from the non-rejecting state `state`
+// on `byte`, following the well-formed byte sequences of the Unicode standard.
+fn utf8_transition(state: u8, byte: u8) -> u8 {
+    match (state, byte) {
+        (UTF8_ACCEPT, 0x00..=0x7f) => UTF8_ACCEPT,
+        (UTF8_ACCEPT, 0xc2..=0xdf) => UTF8_CONT_1,
+        (UTF8_ACCEPT, 0xe0) => UTF8_AFTER_E0,
+        (UTF8_ACCEPT, 0xe1..=0xec | 0xee..=0xef) => UTF8_CONT_2,
+        (UTF8_ACCEPT, 0xed) => UTF8_AFTER_ED,
+        (UTF8_ACCEPT, 0xf0) => UTF8_AFTER_F0,
+        (UTF8_ACCEPT, 0xf1..=0xf3) => UTF8_CONT_3,
+        (UTF8_ACCEPT, 0xf4) => UTF8_AFTER_F4,
+        (UTF8_CONT_1, 0x80..=0xbf) => UTF8_ACCEPT,
+        (UTF8_CONT_2, 0x80..=0xbf) => UTF8_CONT_1,
+        (UTF8_CONT_3, 0x80..=0xbf) => UTF8_CONT_2,
+        (UTF8_AFTER_E0, 0xa0..=0xbf) => UTF8_CONT_1,
+        (UTF8_AFTER_ED, 0x80..=0x9f) => UTF8_CONT_1,
+        (UTF8_AFTER_F0, 0x90..=0xbf) => UTF8_CONT_2,
+        (UTF8_AFTER_F4, 0x80..=0x8f) => UTF8_CONT_2,
+        _ => UTF8_REJECT,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+
+    #[test]
+    fn test_utf8_transition() {
+        for bytes in [
+            "".as_bytes(),
+            "hello".as_bytes(),
+            "h\u{e9}llo w\u{f6}rld \u{20ac}\u{1d11e}\u{10ffff}\u{d7ff}\u{e000}".as_bytes(),
+            b"\xc0\x80",
+            b"\xe2\x82",
+            b"\xed\xa0\x80",
+            b"\xe0\x9f\xbf",
+            b"\xf4\x90\x80\x80",
+            b"\xf5\x80\x80\x80",
+            b"\x80",
+        ] {
+            let state = bytes.iter().fold(UTF8_ACCEPT, |state, &byte| match state {
+                UTF8_REJECT => UTF8_REJECT,
+                _ => utf8_transition(state, byte),
+            });
+            assert_eq!(state == UTF8_ACCEPT, ark_std::str::from_utf8(bytes).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_bytes() -> Result<(), CircuitError> {
+        test_bytes_helper::<FqEd254>()?;
+        test_bytes_helper::<FqEd377>()?;
+        test_bytes_helper::<FqEd381>()?;
+        test_bytes_helper::<Fq377>()
+    }
+
+    fn test_bytes_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        // packing
+        let bytes: Vec<u8> = (0..70u8).map(|i| i.wrapping_mul(97)).collect();
+        let per_elem = bytes_per_field_element::<F>();
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        let byte_vars = bytes
+            .iter()
+            .map(|&byte| circuit.create_variable(F::from(byte)))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        let packed = circuit.pack_bytes(&byte_vars)?;
+        assert_eq!(packed.len(), (bytes.len() - 1) / per_elem + 1);
+        for (&elem, chunk) in packed.iter().zip(bytes.chunks(per_elem)) {
+            assert_eq!(circuit.witness(elem)?, F::from_le_bytes_mod_order(chunk));
+            let unpacked = circuit.unpack_bytes(elem, chunk.len())?;
+            for (&var, &byte) in unpacked.iter().zip(chunk.iter()) {
+                assert_eq!(circuit.witness(var)?, F::from(byte));
+            }
+        }
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        *circuit.witness_mut(byte_vars[0]) += F::from(256u32);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        assert!(circuit.unpack_bytes(packed[0], per_elem + 1).is_err());
+        assert!(circuit.unpack_bytes(packed[0], 1).is_err());
+        assert!(circuit.pack_bytes(&[circuit.num_vars()]).is_err());
+
+        // UTF-8
+        let valid = "h\u{e9}llo \u{20ac}\u{1d11e}".as_bytes();
+        let invalid: [&[u8]; 4] = [
+            b"\xc0\x80",
+            b"ab\xe2\x82",
+            b"\xed\xa0\x80",
+            b"\xf4\x90\x80\x80",
+        ];
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+        let vars = circuit.create_byte_variables(valid)?;
+        circuit.enforce_utf8(&vars)?;
+        circuit.enforce_utf8(&[])?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        for bytes in invalid {
+            let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+            let vars = circuit.create_byte_variables(bytes)?;
+            circuit.enforce_utf8(&vars)?;
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        }
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        let vars = circuit.create_byte_variables(valid)?;
+        assert!(circuit.enforce_utf8(&vars).is_err());
+
+        // equality and substrings
+        let haystack = b"{\"sub\":\"alice\",\"iss\":\"bob\"}";
+        for mut circuit in [
+            PlonkCircuit::<F>::new_turbo_plonk(),
+            PlonkCircuit::<F>::new_ultra_plonk(8),
+        ] {
+            let haystack = circuit.create_byte_variables(haystack)?;
+            let needle = circuit.create_byte_variables(b"\"alice\"")?;
+            let other = circuit.create_byte_variables(b"\"alicf\"")?;
+            let eq = circuit.is_bytes_equal(&needle, &haystack[7..14])?;
+            let neq = circuit.is_bytes_equal(&needle, &other)?;
+            assert_eq!(circuit.witness(eq.into())?, F::one());
+            assert_eq!(circuit.witness(neq.into())?, F::zero());
+            circuit.enforce_bytes_equal(&needle, &haystack[7..14])?;
+            assert!(circuit.is_bytes_equal(&needle, &haystack).is_err());
+            assert!(circuit.enforce_bytes_equal(&needle, &other[1..]).is_err());
+
+            let offset = circuit.create_variable(F::from(7u8))?;
+            circuit.enforce_substring(&haystack, &needle, offset)?;
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+            *circuit.witness_mut(offset) = F::from(8u8);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+            // the substring does not fit at the offset
+            *circuit.witness_mut(offset) = F::from(haystack.len() as u64 - 6);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+            *circuit.witness_mut(offset) = F::from(7u8);
+            *circuit.witness_mut(needle[3]) = F::zero();
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+            assert!(circuit
+                .enforce_substring(&needle, &haystack, offset)
+                .is_err());
+            assert!(circuit
+                .enforce_substring(&haystack, &needle, circuit.num_vars())
+                .is_err());
+        }
+
+        // a substring at the very end
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        let haystack = circuit.create_byte_variables(b"abcabd")?;
+        let needle = circuit.create_byte_variables(b"abd")?;
+        let offset = circuit.create_variable(F::from(3u8))?;
+        circuit.enforce_substring(&haystack, &needle, offset)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        Ok(())
+    }
+}
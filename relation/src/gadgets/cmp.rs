@@ -5,9 +5,14 @@
 // along with the Jellyfish library. If not, see <https://mit-license.org/>.
 
 //! Comparison gadgets for circuit
+//!
+//! Field elements are compared as their canonical integer representatives in
+//! `[0, p)`, so the gadgets apply to arbitrary field elements rather than only
+//! to values bounded beforehand.
 
 use crate::{BoolVar, Circuit, CircuitError, PlonkCircuit, Variable};
 use ark_ff::{BigInteger, PrimeField};
+use ark_std::vec::Vec;
 
 impl<F: PrimeField> PlonkCircuit<F> {
     /// Constrain that `a` < `b`.
@@ -220,13 +225,41 @@ impl<F: PrimeField> PlonkCircuit<F> {
 
     /// Helper function to check whether `a` is greater than a given
     /// constant. Let N = F::MODULUS_BIT_SIZE, it assumes that the
-    /// constant < 2^N. And it uses at most N AND/OR gates.
+    /// constant < 2^N. And it uses at most 2N AND/OR gates.
     fn is_gt_constant_internal(
         &mut self,
         a: Variable,
         constant: &F,
     ) -> Result<BoolVar, CircuitError> {
+        let a_bits_le = self.unpack_canonical(a)?;
+        self.bits_gt_constant(&a_bits_le, constant)
+    }
+
+    /// Return the `F::MODULUS_BIT_SIZE`-long binary representation of `a`,
+    /// constrained to be the canonical one, i.e. of an integer smaller than
+    /// the modulus rather than of `a` plus the modulus.
+    pub(crate) fn unpack_canonical(&mut self, a: Variable) -> Result<Vec<BoolVar>, CircuitError> {
         let a_bits_le = self.unpack(a, F::MODULUS_BIT_SIZE as usize)?;
+        self.enforce_canonical_bits(&a_bits_le)?;
+        Ok(a_bits_le)
+    }
+
+    /// Constrain the little-endian bits `bits_le` to represent an integer
+    /// smaller than the modulus.
+    fn enforce_canonical_bits(&mut self, bits_le: &[BoolVar]) -> Result<(), CircuitError> {
+        let modulus_minus_one = F::zero() - F::one();
+        let non_canonical = self.bits_gt_constant(bits_le, &modulus_minus_one)?;
+        self.enforce_false(non_canonical.into())
+    }
+
+    /// Return a `BoolVar` indicating whether the integer of little-endian bits
+    /// `bits_le` is greater than a given constant. It uses at most
+    /// `bits_le.len()` AND/OR gates.
+    fn bits_gt_constant(
+        &mut self,
+        bits_le: &[BoolVar],
+        constant: &F,
+    ) -> Result<BoolVar, CircuitError> {
         let const_bits_le = constant.into_bigint().to_bits_le();
 
         // Iterating from LSB to MSB. Skip the front consecutive 1's.
@@ -234,8 +267,8 @@ impl<F: PrimeField> PlonkCircuit<F> {
         let mut zipped = const_bits_le
             .into_iter()
             .chain(ark_std::iter::repeat(false))
-            .take(a_bits_le.len())
-            .zip(a_bits_le.iter())
+            .take(bits_le.len())
+            .zip(bits_le.iter())
             .skip_while(|(b, _)| *b);
         if let Some((_, &var)) = zipped.next() {
             zipped.try_fold(var, |current, (b, a)| -> Result<BoolVar, CircuitError> {
@@ -259,8 +292,8 @@ mod test {
     use ark_ed_on_bls12_377::Fq as FqEd377;
     use ark_ed_on_bls12_381::Fq as FqEd381;
     use ark_ed_on_bn254::Fq as FqEd254;
-    use ark_ff::PrimeField;
-    use ark_std::cmp::Ordering;
+    use ark_ff::{BigInteger, PrimeField};
+    use ark_std::{cmp::Ordering, vec::Vec};
     use itertools::multizip;
 
     #[test]
@@ -419,4 +452,58 @@ mod test {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_cmp_full_width() -> Result<(), CircuitError> {
+        test_cmp_full_width_helper::<FqEd254>()?;
+        test_cmp_full_width_helper::<FqEd377>()?;
+        test_cmp_full_width_helper::<FqEd381>()?;
+        test_cmp_full_width_helper::<Fq377>()
+    }
+
+    fn test_cmp_full_width_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let half = F::from(F::MODULUS_MINUS_ONE_DIV_TWO);
+        let values = [
+            F::zero(),
+            F::one(),
+            half,
+            half + F::one(),
+            -F::from(2u8),
+            -F::one(),
+        ];
+        for a in values {
+            for b in values {
+                let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+                let a_var = circuit.create_variable(a)?;
+                let b_var = circuit.create_variable(b)?;
+                let lt = circuit.is_lt(a_var, b_var)?;
+                let leq = circuit.is_leq(a_var, b_var)?;
+                assert_eq!(circuit.witness(lt.into())?, F::from(a < b));
+                assert_eq!(circuit.witness(leq.into())?, F::from(a <= b));
+                assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+                circuit.enforce_lt(a_var, b_var)?;
+                assert_eq!(circuit.check_circuit_satisfiability(&[]).is_ok(), a < b);
+            }
+        }
+
+        // the bits of `p + 1` also add up to one, but aren't canonical
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        for (val, expected) in [(-F::one(), true), (F::zero(), false)] {
+            let mut bits = val.into_bigint().to_bits_le();
+            bits.truncate(F::MODULUS_BIT_SIZE as usize);
+            if !expected {
+                let mut modulus_plus_one = F::MODULUS;
+                modulus_plus_one.add_with_carry(&F::BigInt::from(1u8));
+                bits = modulus_plus_one.to_bits_le();
+                bits.truncate(F::MODULUS_BIT_SIZE as usize);
+            }
+            let bits = bits
+                .into_iter()
+                .map(|b| circuit.create_boolean_variable(b))
+                .collect::<Result<Vec<_>, _>>()?;
+            circuit.enforce_canonical_bits(&bits)?;
+            assert_eq!(circuit.check_circuit_satisfiability(&[]).is_ok(), expected);
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,558 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Gadgets whose constraints are only enforced under a boolean condition,
+//! see [`PlonkCircuit::with_condition()`].
+//!
+//! A constraint `P(x) = 0` is made conditional on `b` by enforcing
+//! `b * P(x) = 0` instead. The constraints of a gadget that computes an
+//! output are satisfiable for any input, so only the enforcing methods of
+//! [`ConditionalCircuit`] need to be conditional; a gadget whose constraints
+//! may be unsatisfiable, e.g. [`PlonkCircuit::enforce_in_range()`], is
+//! replaced by its conditional counterpart, usually the enforcement of the
+//! boolean output of the corresponding `is_*` gadget.
+
+use crate::{
+    gadgets::{ecc::PointVariable, ultraplonk::set::CommittedSet},
+    BoolVar, Circuit, CircuitError, PlonkCircuit, Variable,
+};
+use ark_ff::PrimeField;
+use ark_std::ops::Deref;
+
+/// A view of a circuit whose enforcing methods only constrain the variables
+/// when a boolean condition holds, created by
+/// [`PlonkCircuit::with_condition()`].
+///
+/// The view only dereferences to the underlying circuit immutably, e.g. to
+/// read witnesses. Its mutable API is explicit: the gadgets computing an
+/// output, whose constraints are satisfiable on any input, and conditional
+/// versions of the enforcing gadgets. Other gadgets, e.g. signature
+/// verifications, can't be called on the view, as their constraints would be
+/// enforced regardless of the condition; enforce the boolean output of their
+/// `is_*` counterpart instead, if any.
+#[derive(Debug)]
+pub struct ConditionalCircuit<'a, F: PrimeField> {
+    circuit: &'a mut PlonkCircuit<F>,
+    condition: BoolVar,
+}
+
+impl<'a, F: PrimeField> Deref for ConditionalCircuit<'a, F> {
+    type Target = PlonkCircuit<F>;
+
+    fn deref(&self) -> &Self::Target {
+        self.circuit
+    }
+}
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Build the gadget `f` on a view of the circuit whose enforcing methods
+    /// only constrain the variables when `condition` is true, e.g. to write
+    /// "if enabled then enforce X". Return the output of `f`.
+    ///
+    /// The variables computed in `f` are computed regardless of the
+    /// condition, see [`ConditionalCircuit::select()`] to fall back on a
+    /// default value when the condition is false.
+    /// Return error if `condition` is invalid, or if `f` does.
+    pub fn with_condition<T>(
+        &mut self,
+        condition: BoolVar,
+        f: impl FnOnce(&mut ConditionalCircuit<'_, F>) -> Result<T, CircuitError>,
+    ) -> Result<T, CircuitError> {
+        self.check_var_bound(condition.into())?;
+        f(&mut ConditionalCircuit {
+            circuit: self,
+            condition,
+        })
+    }
+}
+
+impl<'a, F: PrimeField> ConditionalCircuit<'a, F> {
+    /// The condition of the enforcing methods.
+    pub fn condition(&self) -> BoolVar {
+        self.condition
+    }
+
+    /// Build the gadget `f` under the conjunction of the condition of this
+    /// view and of `condition`, see [`PlonkCircuit::with_condition()`].
+    pub fn with_condition<T>(
+        &mut self,
+        condition: BoolVar,
+        f: impl FnOnce(&mut ConditionalCircuit<'_, F>) -> Result<T, CircuitError>,
+    ) -> Result<T, CircuitError> {
+        let condition = self.circuit.logic_and(self.condition, condition)?;
+        self.circuit.with_condition(condition, f)
+    }
+
+    /// Obtain a variable that equals `x` if the condition is true, or
+    /// `default` otherwise.
+    /// Return error if variables are invalid.
+    pub fn select(&mut self, default: Variable, x: Variable) -> Result<Variable, CircuitError> {
+        self.circuit.conditional_select(self.condition, default, x)
+    }
+
+    /// Create a variable, see [`Circuit::create_variable()`].
+    pub fn create_variable(&mut self, val: F) -> Result<Variable, CircuitError> {
+        self.circuit.create_variable(val)
+    }
+
+    /// Create a constant variable, see
+    /// [`Circuit::create_constant_variable()`].
+    pub fn create_constant_variable(&mut self, val: F) -> Result<Variable, CircuitError> {
+        self.circuit.create_constant_variable(val)
+    }
+
+    /// Create a boolean variable, see
+    /// [`Circuit::create_boolean_variable()`].
+    pub fn create_boolean_variable(&mut self, val: bool) -> Result<BoolVar, CircuitError> {
+        self.circuit.create_boolean_variable(val)
+    }
+
+    /// Obtain `a + b`, see [`Circuit::add()`].
+    pub fn add(&mut self, a: Variable, b: Variable) -> Result<Variable, CircuitError> {
+        self.circuit.add(a, b)
+    }
+
+    /// Obtain `a - b`, see [`Circuit::sub()`].
+    pub fn sub(&mut self, a: Variable, b: Variable) -> Result<Variable, CircuitError> {
+        self.circuit.sub(a, b)
+    }
+
+    /// Obtain `a * b`, see [`Circuit::mul()`].
+    pub fn mul(&mut self, a: Variable, b: Variable) -> Result<Variable, CircuitError> {
+        self.circuit.mul(a, b)
+    }
+
+    /// Obtain `a + elem`, see [`PlonkCircuit::add_constant()`].
+    pub fn add_constant(&mut self, a: Variable, elem: &F) -> Result<Variable, CircuitError> {
+        self.circuit.add_constant(a, elem)
+    }
+
+    /// Obtain `a * elem`, see [`PlonkCircuit::mul_constant()`].
+    pub fn mul_constant(&mut self, a: Variable, elem: &F) -> Result<Variable, CircuitError> {
+        self.circuit.mul_constant(a, elem)
+    }
+
+    /// Obtain the sum of `elems`, see [`PlonkCircuit::sum()`].
+    pub fn sum(&mut self, elems: &[Variable]) -> Result<Variable, CircuitError> {
+        self.circuit.sum(elems)
+    }
+
+    /// Obtain `a AND b`, see [`PlonkCircuit::logic_and()`].
+    pub fn logic_and(&mut self, a: BoolVar, b: BoolVar) -> Result<BoolVar, CircuitError> {
+        self.circuit.logic_and(a, b)
+    }
+
+    /// Obtain `a OR b`, see [`PlonkCircuit::logic_or()`].
+    pub fn logic_or(&mut self, a: BoolVar, b: BoolVar) -> Result<BoolVar, CircuitError> {
+        self.circuit.logic_or(a, b)
+    }
+
+    /// Obtain `NOT a`, see [`PlonkCircuit::logic_neg()`].
+    pub fn logic_neg(&mut self, a: BoolVar) -> Result<BoolVar, CircuitError> {
+        self.circuit.logic_neg(a)
+    }
+
+    /// Obtain whether `a` == `b`, see [`PlonkCircuit::is_equal()`].
+    pub fn is_equal(&mut self, a: Variable, b: Variable) -> Result<BoolVar, CircuitError> {
+        self.circuit.is_equal(a, b)
+    }
+
+    /// Obtain whether `a` == 0, see [`PlonkCircuit::is_zero()`].
+    pub fn is_zero(&mut self, a: Variable) -> Result<BoolVar, CircuitError> {
+        self.circuit.is_zero(a)
+    }
+
+    /// Obtain whether `a` is in the [0, 2^`bit_len`) range, see
+    /// [`PlonkCircuit::is_in_range()`].
+    pub fn is_in_range(&mut self, a: Variable, bit_len: usize) -> Result<BoolVar, CircuitError> {
+        self.circuit.is_in_range(a, bit_len)
+    }
+
+    /// Obtain whether `a` < `b`, see [`PlonkCircuit::is_lt()`].
+    pub fn is_lt(&mut self, a: Variable, b: Variable) -> Result<BoolVar, CircuitError> {
+        self.circuit.is_lt(a, b)
+    }
+
+    /// Obtain whether `a` <= `b`, see [`PlonkCircuit::is_leq()`].
+    pub fn is_leq(&mut self, a: Variable, b: Variable) -> Result<BoolVar, CircuitError> {
+        self.circuit.is_leq(a, b)
+    }
+
+    /// Obtain whether `a` > `b`, see [`PlonkCircuit::is_gt()`].
+    pub fn is_gt(&mut self, a: Variable, b: Variable) -> Result<BoolVar, CircuitError> {
+        self.circuit.is_gt(a, b)
+    }
+
+    /// Obtain whether `a` >= `b`, see [`PlonkCircuit::is_geq()`].
+    pub fn is_geq(&mut self, a: Variable, b: Variable) -> Result<BoolVar, CircuitError> {
+        self.circuit.is_geq(a, b)
+    }
+
+    /// Obtain whether `a` < `val`, see [`PlonkCircuit::is_lt_constant()`].
+    pub fn is_lt_constant(&mut self, a: Variable, val: F) -> Result<BoolVar, CircuitError> {
+        self.circuit.is_lt_constant(a, val)
+    }
+
+    /// Obtain whether `a` <= `val`, see [`PlonkCircuit::is_leq_constant()`].
+    pub fn is_leq_constant(&mut self, a: Variable, val: F) -> Result<BoolVar, CircuitError> {
+        self.circuit.is_leq_constant(a, val)
+    }
+
+    /// Obtain whether `a` > `val`, see [`PlonkCircuit::is_gt_constant()`].
+    pub fn is_gt_constant(&mut self, a: Variable, val: F) -> Result<BoolVar, CircuitError> {
+        self.circuit.is_gt_constant(a, val)
+    }
+
+    /// Obtain whether `a` >= `val`, see [`PlonkCircuit::is_geq_constant()`].
+    pub fn is_geq_constant(&mut self, a: Variable, val: F) -> Result<BoolVar, CircuitError> {
+        self.circuit.is_geq_constant(a, val)
+    }
+
+    /// Obtain whether two points are equal, see
+    /// [`PlonkCircuit::is_point_equal()`].
+    pub fn is_point_equal(
+        &mut self,
+        point0: &PointVariable,
+        point1: &PointVariable,
+    ) -> Result<BoolVar, CircuitError> {
+        self.circuit.is_point_equal(point0, point1)
+    }
+
+    /// Obtain a variable that equals `x_0` if `b` is false, or `x_1`
+    /// otherwise, see [`PlonkCircuit::conditional_select()`].
+    pub fn conditional_select(
+        &mut self,
+        b: BoolVar,
+        x_0: Variable,
+        x_1: Variable,
+    ) -> Result<Variable, CircuitError> {
+        self.circuit.conditional_select(b, x_0, x_1)
+    }
+
+    /// Constrain that `a` == `b` if the condition is true.
+    /// Return error if variables are invalid.
+    pub fn enforce_equal(&mut self, a: Variable, b: Variable) -> Result<(), CircuitError> {
+        // cond * a - cond * b = 0
+        let cond = self.condition.into();
+        let zero = self.circuit.zero();
+        self.circuit.quad_poly_gate(
+            &[cond, a, cond, b, zero],
+            &[F::zero(); 4],
+            &[F::one(), -F::one()],
+            F::zero(),
+            F::zero(),
+        )
+    }
+
+    /// Constrain that `var` == `constant` if the condition is true.
+    /// Return error if the variable is invalid.
+    pub fn enforce_constant(&mut self, var: Variable, constant: F) -> Result<(), CircuitError> {
+        // cond * var - constant * cond = 0
+        let cond = self.condition.into();
+        let zero = self.circuit.zero();
+        self.circuit.quad_poly_gate(
+            &[cond, var, zero, zero, zero],
+            &[-constant, F::zero(), F::zero(), F::zero()],
+            &[F::one(), F::zero()],
+            F::zero(),
+            F::zero(),
+        )
+    }
+
+    /// Constrain that `a` is boolean if the condition is true.
+    /// Return error if the variable is invalid.
+    pub fn enforce_bool(&mut self, a: Variable) -> Result<(), CircuitError> {
+        let a_square = self.circuit.mul(a, a)?;
+        self.enforce_equal(a_square, a)
+    }
+
+    /// Assuming values represented by `a` is boolean.
+    /// Constrain `a` is true if the condition is true.
+    pub fn enforce_true(&mut self, a: Variable) -> Result<(), CircuitError> {
+        self.enforce_constant(a, F::one())
+    }
+
+    /// Assuming values represented by `a` is boolean.
+    /// Constrain `a` is false if the condition is true.
+    pub fn enforce_false(&mut self, a: Variable) -> Result<(), CircuitError> {
+        self.enforce_constant(a, F::zero())
+    }
+
+    /// Constrain a variable to be non-zero if the condition is true.
+    /// Return error if the variable is invalid.
+    pub fn non_zero_gate(&mut self, var: Variable) -> Result<(), CircuitError> {
+        let inverse = self.circuit.witness(var)?.inverse().unwrap_or_else(F::zero);
+        let inv_var = self.circuit.create_variable(inverse)?;
+        let product = self.circuit.mul(var, inv_var)?;
+        self.enforce_true(product)
+    }
+
+    /// Constrain a variable to be within the [0, 2^`bit_len`) range if the
+    /// condition is true.
+    /// Return error if the variable is invalid.
+    pub fn enforce_in_range(&mut self, a: Variable, bit_len: usize) -> Result<(), CircuitError> {
+        let in_range = self.circuit.is_in_range(a, bit_len)?;
+        self.enforce_true(in_range.into())
+    }
+
+    /// Constrain that `a` < `b` if the condition is true.
+    /// Return error if variables are invalid.
+    pub fn enforce_lt(&mut self, a: Variable, b: Variable) -> Result<(), CircuitError> {
+        let lt = self.circuit.is_lt(a, b)?;
+        self.enforce_true(lt.into())
+    }
+
+    /// Constrain that `a` <= `b` if the condition is true.
+    /// Return error if variables are invalid.
+    pub fn enforce_leq(&mut self, a: Variable, b: Variable) -> Result<(), CircuitError> {
+        let gt = self.circuit.is_lt(b, a)?;
+        self.enforce_false(gt.into())
+    }
+
+    /// Constrain that `a` > `b` if the condition is true.
+    /// Return error if variables are invalid.
+    pub fn enforce_gt(&mut self, a: Variable, b: Variable) -> Result<(), CircuitError> {
+        self.enforce_lt(b, a)
+    }
+
+    /// Constrain that `a` >= `b` if the condition is true.
+    /// Return error if variables are invalid.
+    pub fn enforce_geq(&mut self, a: Variable, b: Variable) -> Result<(), CircuitError> {
+        self.enforce_leq(b, a)
+    }
+
+    /// Constrain that `a` < `val` if the condition is true.
+    /// Return error if the variable is invalid.
+    pub fn enforce_lt_constant(&mut self, a: Variable, val: F) -> Result<(), CircuitError> {
+        let lt = self.circuit.is_lt_constant(a, val)?;
+        self.enforce_true(lt.into())
+    }
+
+    /// Constrain that `a` <= `val` if the condition is true.
+    /// Return error if the variable is invalid.
+    pub fn enforce_leq_constant(&mut self, a: Variable, val: F) -> Result<(), CircuitError> {
+        let leq = self.circuit.is_leq_constant(a, val)?;
+        self.enforce_true(leq.into())
+    }
+
+    /// Constrain that `a` > `val` if the condition is true.
+    /// Return error if the variable is invalid.
+    pub fn enforce_gt_constant(&mut self, a: Variable, val: F) -> Result<(), CircuitError> {
+        let gt = self.circuit.is_gt_constant(a, val)?;
+        self.enforce_true(gt.into())
+    }
+
+    /// Constrain that `a` >= `val` if the condition is true.
+    /// Return error if the variable is invalid.
+    pub fn enforce_geq_constant(&mut self, a: Variable, val: F) -> Result<(), CircuitError> {
+        let geq = self.circuit.is_geq_constant(a, val)?;
+        self.enforce_true(geq.into())
+    }
+
+    /// Constrain that `a` != `b` if the condition is true.
+    /// Return error if variables are invalid.
+    pub fn enforce_neq(&mut self, a: Variable, b: Variable) -> Result<(), CircuitError> {
+        let delta = self.circuit.sub(a, b)?;
+        self.non_zero_gate(delta)
+    }
+
+    /// Constrain that two points are equal if the condition is true.
+    /// Return error if variables are invalid.
+    pub fn enforce_point_equal(
+        &mut self,
+        point0: &PointVariable,
+        point1: &PointVariable,
+    ) -> Result<(), CircuitError> {
+        let equal = self.circuit.is_point_equal(point0, point1)?;
+        self.enforce_true(equal.into())
+    }
+
+    /// Constrain `var` to be in the set `set` if the condition is true: an
+    /// element of the set is looked up instead of `var` otherwise.
+    /// Return error if the circuit does not support lookup, the set is empty
+    /// or the variable is invalid.
+    pub fn enforce_in_set(
+        &mut self,
+        var: Variable,
+        set: &CommittedSet<F>,
+    ) -> Result<(), CircuitError> {
+        let var = match set.any_member() {
+            Some(member) => {
+                let member = self.circuit.create_constant_variable(member)?;
+                self.select(member, var)?
+            },
+            None => var,
+        };
+        self.circuit.enforce_in_set(var, set)
+    }
+
+    /// Constrain `var` not to be in the set `set` if the condition is true: a
+    /// non-member is looked up instead of `var` otherwise.
+    /// Return error if the circuit does not support lookup or the variable is
+    /// invalid.
+    pub fn enforce_not_in_set(
+        &mut self,
+        var: Variable,
+        set: &CommittedSet<F>,
+    ) -> Result<(), CircuitError> {
+        let var = match set.any_non_member() {
+            Some(non_member) => {
+                let non_member = self.circuit.create_constant_variable(non_member)?;
+                self.select(non_member, var)?
+            },
+            None => var,
+        };
+        self.circuit.enforce_not_in_set(var, set)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+
+    type ConditionalCheck<F> =
+        fn(&mut ConditionalCircuit<'_, F>, Variable) -> Result<(), CircuitError>;
+
+    #[test]
+    fn test_with_condition() -> Result<(), CircuitError> {
+        test_with_condition_helper::<FqEd254>()?;
+        test_with_condition_helper::<FqEd377>()?;
+        test_with_condition_helper::<FqEd381>()?;
+        test_with_condition_helper::<Fq377>()
+    }
+
+    fn test_with_condition_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        // the constraints hold for (a, b) = (3, 4) but not for (0, 0), and
+        // the nested one never holds
+        for (a_val, b_val) in [(3u32, 4u32), (0, 0)] {
+            for (enabled_val, c_val) in [(false, false), (false, true), (true, false), (true, true)]
+            {
+                let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+                let enabled = circuit.create_boolean_variable(enabled_val)?;
+                let a = circuit.create_variable(F::from(a_val))?;
+                let b = circuit.create_variable(F::from(b_val))?;
+                let c = circuit.create_boolean_variable(c_val)?;
+                let seven = circuit.create_constant_variable(F::from(7u32))?;
+                let sum = circuit.with_condition(enabled, |sub| {
+                    assert_eq!(sub.condition().0, enabled.0);
+                    let sum = sub.add(a, b)?;
+                    sub.enforce_equal(sum, seven)?;
+                    sub.enforce_constant(a, F::from(3u32))?;
+                    let one = sub.one();
+                    let is_one = sub.is_equal(b, one)?;
+                    sub.enforce_false(is_one.into())?;
+                    sub.non_zero_gate(b)?;
+                    sub.enforce_in_range(b, 3)?;
+                    sub.enforce_lt(a, b)?;
+                    sub.enforce_leq(b, sum)?;
+                    let a_minus_two = sub.add_constant(a, &-F::from(2u32))?;
+                    sub.enforce_bool(a_minus_two)?;
+                    sub.enforce_true(a_minus_two)?;
+                    sub.with_condition(c, |sub| sub.enforce_constant(b, F::from(5u32)))?;
+                    let zero = sub.zero();
+                    sub.select(zero, sum)
+                })?;
+                let expected_sum = match enabled_val {
+                    true => F::from(a_val + b_val),
+                    false => F::zero(),
+                };
+                assert_eq!(circuit.witness(sum)?, expected_sum);
+                let satisfied = !enabled_val || (a_val == 3 && !c_val);
+                assert_eq!(circuit.check_circuit_satisfiability(&[]).is_ok(), satisfied);
+            }
+        }
+
+        // each conditional constraint alone is violated when enabled
+        let checks: [ConditionalCheck<F>; 16] = [
+            |sub, x| {
+                let one = sub.one();
+                sub.enforce_equal(x, one)
+            },
+            |sub, x| sub.enforce_constant(x, F::one()),
+            |sub, x| {
+                let two = sub.add(x, x)?;
+                let two = sub.add_constant(two, &F::from(2u32))?;
+                sub.enforce_bool(two)
+            },
+            |sub, x| sub.enforce_true(x),
+            |sub, x| {
+                let not_x = sub.add_constant(x, &F::one())?;
+                sub.enforce_false(not_x)
+            },
+            |sub, x| sub.non_zero_gate(x),
+            |sub, x| {
+                let big = sub.add_constant(x, &F::from(8u32))?;
+                sub.enforce_in_range(big, 3)
+            },
+            |sub, x| sub.enforce_lt(x, x),
+            |sub, x| {
+                let one = sub.one();
+                sub.enforce_leq(one, x)
+            },
+            |sub, x| sub.enforce_gt(x, x),
+            |sub, x| {
+                let one = sub.one();
+                sub.enforce_geq(x, one)
+            },
+            |sub, x| sub.enforce_lt_constant(x, F::zero()),
+            |sub, _| {
+                let one = sub.one();
+                sub.enforce_leq_constant(one, F::zero())
+            },
+            |sub, x| sub.enforce_gt_constant(x, F::zero()),
+            |sub, x| sub.enforce_geq_constant(x, F::one()),
+            |sub, x| sub.enforce_neq(x, x),
+        ];
+        for check in checks {
+            for enabled_val in [false, true] {
+                let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+                let enabled = circuit.create_boolean_variable(enabled_val)?;
+                let x = circuit.zero();
+                circuit.with_condition(enabled, |sub| check(sub, x))?;
+                assert_eq!(
+                    circuit.check_circuit_satisfiability(&[]).is_err(),
+                    enabled_val
+                );
+            }
+        }
+
+        // set membership, with lookups
+        let set = CommittedSet::new(&[F::from(1u32), F::from(2u32)]);
+        for (val, enabled_val, in_set) in [
+            (1u32, true, true),
+            (5, true, false),
+            (1, false, true),
+            (5, false, false),
+        ] {
+            for member in [true, false] {
+                let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+                let enabled = circuit.create_boolean_variable(enabled_val)?;
+                let x = circuit.create_variable(F::from(val))?;
+                circuit.with_condition(enabled, |sub| match member {
+                    true => sub.enforce_in_set(x, &set),
+                    false => sub.enforce_not_in_set(x, &set),
+                })?;
+                let satisfied = !enabled_val || in_set == member;
+                assert_eq!(circuit.check_circuit_satisfiability(&[]).is_ok(), satisfied);
+            }
+        }
+
+        // error paths
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        let bad_condition = BoolVar::new_unchecked(circuit.num_vars());
+        assert!(circuit.with_condition(bad_condition, |_| Ok(())).is_err());
+        let enabled = circuit.create_boolean_variable(true)?;
+        let a = circuit.num_vars();
+        assert!(circuit
+            .with_condition(enabled, |sub| sub.enforce_equal(a, a))
+            .is_err());
+        Ok(())
+    }
+}
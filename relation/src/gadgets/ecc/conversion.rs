@@ -8,6 +8,8 @@
 //! - short Weierstrass form
 //! - twisted Edwards form
 //!
+//! in both directions.
+//!
 //! Note that the APIs below create no circuits.
 //! An entity should either know both the SW and TE form of a
 //! point; or know none of the two. There is no need to generate
@@ -57,6 +59,47 @@ where
     }
 }
 
+impl<F, P> From<TEPoint<F>> for SWAffine<P>
+where
+    F: PrimeField + SWToTEConParam,
+    P: SWParam<BaseField = F>,
+{
+    fn from(p: TEPoint<F>) -> Self {
+        // this function is only correct for BLS12-377
+        // (other curves does not impl an SW form)
+
+        // if p is an infinity point
+        // return infinity point
+        if p.0.is_zero() && p.1.is_one() {
+            return Self::identity();
+        }
+
+        // safe unwrap
+        let s = F::from(F::S);
+        let neg_alpha = F::from(F::NEG_ALPHA);
+        let beta = F::from(F::BETA);
+
+        // we first transform the TE point (ex, ey) to Montgomery point (mx, my)
+        // where
+        // mx = (1 + ey) / (1 - ey)
+        // my = beta * mx / ex, which is 0 for the point (0, -1)
+        let montgomery_x = (F::one() + p.1) / (F::one() - p.1);
+        let montgomery_y = if p.0.is_zero() {
+            F::zero()
+        } else {
+            beta * montgomery_x / p.0
+        };
+        // then we transform the Montgomery point (mx, my) to Weierstrass point
+        // (px, py) where
+        // px = mx / s + alpha
+        // py = my / s
+        let weierstrass_x = montgomery_x / s - neg_alpha;
+        let weierstrass_y = montgomery_y / s;
+
+        Self::new_unchecked(weierstrass_x, weierstrass_y)
+    }
+}
+
 /// This trait holds constants that are used for curve conversion from
 /// short Weierstrass form to twisted Edwards form.
 pub trait SWToTEConParam: PrimeField {
@@ -177,4 +220,20 @@ mod test {
         let p: TEPoint<Fq377> = g1.into();
         assert!(is_on_bls12_377_ed_curve(&p));
     }
+
+    #[test]
+    fn test_te_to_sw_conversion() {
+        let mut rng = test_rng();
+
+        for g1 in [
+            G1Affine::generator(),
+            G1Affine::zero(),
+            G1Projective::rand(&mut rng).into_affine(),
+        ] {
+            let p: TEPoint<Fq377> = g1.into();
+            let q: G1Affine = p.into();
+            assert!(q.is_on_curve());
+            assert_eq!(q, g1);
+        }
+    }
 }
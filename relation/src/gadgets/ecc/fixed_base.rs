@@ -0,0 +1,305 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Fixed-base scalar multiplications with precomputed tables of multiples of
+//! the base, for bases used by many multiplications of a circuit, e.g. the
+//! generator of the curve or the bases of a commitment scheme.
+//!
+//! The scalar is decomposed into `ceil(n / w)` windows of `w` bits, `n` being
+//! the bit length of the scalar field, and the multiple of the base by the
+//! digit of each window is read from a [`Rom`] of the `2^w` multiples for
+//! this window. The tables cost about `3 * 2^w * ceil(n / w)` gates, and each
+//! multiplication about `3 * ceil(n / w)` gates for the lookups and the point
+//! additions, a lookup being free as long as it shares its gate with an
+//! entry of the table. For a base used by dozens of multiplications, this is
+//! several times cheaper than [`PlonkCircuit::fixed_base_scalar_mul()`] and
+//! [`PlonkCircuit::variable_base_scalar_mul()`].
+//!
+//! [`Rom`]: crate::gadgets::ultraplonk::rom::Rom
+
+use super::{PointVariable, TEPoint};
+use crate::{gadgets::ultraplonk::rom::Rom, Circuit, CircuitError, PlonkCircuit, Variable};
+use ark_ec::{
+    twisted_edwards::{Affine, Projective, TECurveConfig as Config},
+    AffineRepr, CurveConfig, CurveGroup,
+};
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::{format, marker::PhantomData, string::ToString, vec::Vec, Zero};
+
+/// The maximal number of bits of a window of a [`FixedBaseTable`].
+pub const MAX_FIXED_BASE_WINDOW_BITS: usize = 16;
+
+/// The tables of the multiples of a fixed base, windowed by digits of
+/// `window_bits` bits, shared by all the multiplications by the base of a
+/// circuit, see [`PlonkCircuit::fixed_base_scalar_mul_with_table()`].
+#[derive(Debug, Clone)]
+pub struct FixedBaseTable<F: PrimeField, P: Config<BaseField = F>> {
+    window_bits: usize,
+    // the memory of the window `i` holds the points `d * 2^(i * window_bits) *
+    // base` for all the digits `d` of the window
+    windows: Vec<Rom<F>>,
+    _phantom: PhantomData<P>,
+}
+
+impl<F: PrimeField, P: Config<BaseField = F>> FixedBaseTable<F, P> {
+    /// Precompute the tables of the multiples of `base` for windows of
+    /// `window_bits` bits.
+    /// Return error if `window_bits` is zero or larger than
+    /// [`MAX_FIXED_BASE_WINDOW_BITS`], or if the scalar field of the curve is
+    /// not smaller than its base field.
+    pub fn new(base: &Affine<P>, window_bits: usize) -> Result<Self, CircuitError> {
+        if window_bits == 0 || window_bits > MAX_FIXED_BASE_WINDOW_BITS {
+            return Err(CircuitError::ParameterError(format!(
+                "window of {} bits, should be between 1 and {}",
+                window_bits, MAX_FIXED_BASE_WINDOW_BITS
+            )));
+        }
+        // the recomposition of the digits must not wrap around the modulus
+        let num_bits = <P as CurveConfig>::ScalarField::MODULUS_BIT_SIZE as usize;
+        if num_bits >= F::MODULUS_BIT_SIZE as usize {
+            return Err(CircuitError::ParameterError(
+                "the scalar field is not smaller than the base field".to_string(),
+            ));
+        }
+
+        let mut window_base = base.into_group();
+        let windows = (0..num_bits)
+            .step_by(window_bits)
+            .map(|start| {
+                // the last window may be shorter
+                let len = window_bits.min(num_bits - start);
+                let mut multiples = Vec::with_capacity(1 << len);
+                let mut multiple = Projective::<P>::zero();
+                for _ in 0..1 << len {
+                    multiples.push(multiple);
+                    multiple += window_base;
+                }
+                window_base = multiple;
+                let entries: Vec<_> = Projective::normalize_batch(&multiples)
+                    .into_iter()
+                    .map(|point| {
+                        let point = TEPoint::from(point);
+                        (point.get_x(), point.get_y())
+                    })
+                    .collect();
+                Rom::new_with_pairs(&entries)
+            })
+            .collect();
+        Ok(Self {
+            window_bits,
+            windows,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Precompute the tables of the multiples of the generator of the curve
+    /// for windows of `window_bits` bits, see [`Self::new()`].
+    pub fn generator(window_bits: usize) -> Result<Self, CircuitError> {
+        Self::new(&Affine::<P>::generator(), window_bits)
+    }
+
+    /// The number of bits of a window.
+    pub fn window_bits(&self) -> usize {
+        self.window_bits
+    }
+
+    /// The number of windows of a scalar.
+    pub fn num_windows(&self) -> usize {
+        self.windows.len()
+    }
+}
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Obtain the fixed-based scalar multiplication result of `scalar` * the
+    /// base of `table`, with a lookup of a multiple of the base for each
+    /// window of the scalar.
+    /// The lookups are only constrained by
+    /// [`Self::enforce_fixed_base_table_reads()`].
+    /// Return error if the circuit does not support lookup, the variable is
+    /// invalid, or the scalar doesn't fit the bit length of the scalar field.
+    pub fn fixed_base_scalar_mul_with_table<P: Config<BaseField = F>>(
+        &mut self,
+        scalar: Variable,
+        table: &mut FixedBaseTable<F, P>,
+    ) -> Result<PointVariable, CircuitError> {
+        self.check_var_bound(scalar)?;
+        if !self.support_lookup() {
+            return Err(CircuitError::LookupUnsupported);
+        }
+        let num_bits = <P as CurveConfig>::ScalarField::MODULUS_BIT_SIZE as usize;
+        let scalar_bits_le = self.witness(scalar)?.into_bigint().to_bits_le();
+        if scalar_bits_le[num_bits..].iter().any(|&bit| bit) {
+            return Err(CircuitError::ParameterError(format!(
+                "the scalar doesn't fit in {} bits",
+                num_bits
+            )));
+        }
+
+        let digits = scalar_bits_le[..num_bits]
+            .chunks(table.window_bits)
+            .map(|bits_le| {
+                let digit = bits_le
+                    .iter()
+                    .rev()
+                    .fold(0u64, |digit, &bit| (digit << 1) | bit as u64);
+                self.create_variable(F::from(digit))
+            })
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        // the lookups bound each digit by the size of the table of its window
+        let mut accum: Option<PointVariable> = None;
+        for (&digit, window) in digits.iter().zip(table.windows.iter_mut()) {
+            let (x, y) = self.rom_read_pair(window, digit)?;
+            let multiple = PointVariable(x, y);
+            accum = Some(match accum {
+                Some(accum) => self.ecc_add::<P>(&accum, &multiple)?,
+                None => multiple,
+            });
+        }
+        self.decomposition_gate(digits, scalar, F::from(1u64 << table.window_bits))?;
+        accum.ok_or_else(|| CircuitError::InternalError("no window".to_string()))
+    }
+
+    /// Add the tables of `table`, and constrain all the multiples of its base
+    /// read by [`Self::fixed_base_scalar_mul_with_table()`].
+    /// Return error if the circuit does not support lookup.
+    pub fn enforce_fixed_base_table_reads<P: Config<BaseField = F>>(
+        &mut self,
+        table: FixedBaseTable<F, P>,
+    ) -> Result<(), CircuitError> {
+        for window in table.windows {
+            self.enforce_rom_reads(window)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gadgets::test_utils::test_variable_independence_for_circuit;
+    use ark_bls12_377::{g1::Config as Param761, Fq as Fq377};
+    use ark_ed_on_bls12_377::{EdwardsConfig as Param377, Fq as FqEd377};
+    use ark_ed_on_bls12_381::{EdwardsConfig as Param381, Fq as FqEd381};
+    use ark_ed_on_bls12_381_bandersnatch::{EdwardsConfig as Param381b, Fq as FqEd381b};
+    use ark_ed_on_bn254::{EdwardsConfig as Param254, Fq as FqEd354};
+    use ark_std::{One, UniformRand};
+    use jf_utils::{fr_to_fq, test_rng};
+
+    #[test]
+    fn test_fixed_base_scalar_mul_with_table() -> Result<(), CircuitError> {
+        test_fixed_base_scalar_mul_with_table_helper::<FqEd354, Param254>()?;
+        test_fixed_base_scalar_mul_with_table_helper::<FqEd377, Param377>()?;
+        test_fixed_base_scalar_mul_with_table_helper::<FqEd381, Param381>()?;
+        test_fixed_base_scalar_mul_with_table_helper::<FqEd381b, Param381b>()?;
+        test_fixed_base_scalar_mul_with_table_helper::<Fq377, Param761>()
+    }
+
+    fn test_fixed_base_scalar_mul_with_table_helper<F, P>() -> Result<(), CircuitError>
+    where
+        F: PrimeField,
+        P: Config<BaseField = F>,
+    {
+        let mut rng = test_rng();
+        for window_bits in [1, 4, 5] {
+            let base = Affine::<P>::rand(&mut rng);
+            let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+            let mut table = FixedBaseTable::new(&base, window_bits)?;
+            let mut scalars = Vec::new();
+            let mut results = Vec::new();
+            for _ in 0..4 {
+                let s = <P as CurveConfig>::ScalarField::rand(&mut rng);
+                let scalar = circuit.create_variable(fr_to_fq::<F, P>(&s))?;
+                let result = circuit.fixed_base_scalar_mul_with_table(scalar, &mut table)?;
+                assert_eq!(TEPoint::from(base * s), circuit.point_witness(&result)?);
+                scalars.push(scalar);
+                results.push(result);
+            }
+            // the multiplication by zero and by the largest scalar
+            for s in [
+                <P as CurveConfig>::ScalarField::zero(),
+                -<P as CurveConfig>::ScalarField::one(),
+            ] {
+                let scalar = circuit.create_variable(fr_to_fq::<F, P>(&s))?;
+                let result = circuit.fixed_base_scalar_mul_with_table(scalar, &mut table)?;
+                assert_eq!(TEPoint::from(base * s), circuit.point_witness(&result)?);
+            }
+            circuit.enforce_fixed_base_table_reads(table)?;
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+            // wrong scalar
+            *circuit.witness_mut(scalars[0]) += F::one();
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+            *circuit.witness_mut(scalars[0]) -= F::one();
+            // wrong result
+            *circuit.witness_mut(results[1].get_x()) = F::rand(&mut rng);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        }
+
+        // several times cheaper than the other scalar multiplications when the
+        // tables are shared by many multiplications
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+        let mut table = FixedBaseTable::<F, P>::generator(4)?;
+        for _ in 0..64 {
+            let scalar = circuit.create_variable(F::from(u64::rand(&mut rng)))?;
+            circuit.fixed_base_scalar_mul_with_table(scalar, &mut table)?;
+        }
+        circuit.enforce_fixed_base_table_reads(table)?;
+        let num_gates = circuit.num_gates();
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+        for _ in 0..64 {
+            let scalar = circuit.create_variable(F::from(u64::rand(&mut rng)))?;
+            circuit.fixed_base_scalar_mul(scalar, &Affine::<P>::generator())?;
+        }
+        assert!(2 * num_gates < circuit.num_gates());
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+        let generator = circuit.create_constant_point_variable(Affine::<P>::generator().into())?;
+        for _ in 0..64 {
+            let scalar = circuit.create_variable(F::from(u64::rand(&mut rng)))?;
+            circuit.variable_base_scalar_mul::<P>(scalar, &generator)?;
+        }
+        assert!(2 * num_gates < circuit.num_gates());
+
+        // the gates don't depend on the scalars
+        let circuit_1 = build_fixed_base_scalar_mul_with_table_circuit::<F, P>(F::from(87u32))?;
+        let circuit_2 = build_fixed_base_scalar_mul_with_table_circuit::<F, P>(F::from(2u32))?;
+        test_variable_independence_for_circuit(circuit_1, circuit_2)?;
+
+        // error paths
+        assert!(FixedBaseTable::<F, P>::generator(0).is_err());
+        assert!(FixedBaseTable::<F, P>::generator(MAX_FIXED_BASE_WINDOW_BITS + 1).is_err());
+        let mut table = FixedBaseTable::<F, P>::generator(4)?;
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+        assert!(circuit
+            .fixed_base_scalar_mul_with_table(circuit.num_vars(), &mut table)
+            .is_err());
+        let too_large = circuit.create_variable(-F::one())?;
+        assert!(circuit
+            .fixed_base_scalar_mul_with_table(too_large, &mut table)
+            .is_err());
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        assert!(circuit
+            .fixed_base_scalar_mul_with_table(circuit.zero(), &mut table)
+            .is_err());
+        assert!(circuit.enforce_fixed_base_table_reads(table).is_err());
+        Ok(())
+    }
+
+    fn build_fixed_base_scalar_mul_with_table_circuit<F, P>(
+        scalar: F,
+    ) -> Result<PlonkCircuit<F>, CircuitError>
+    where
+        F: PrimeField,
+        P: Config<BaseField = F>,
+    {
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+        let mut table = FixedBaseTable::<F, P>::generator(4)?;
+        let scalar_var = circuit.create_variable(scalar)?;
+        circuit.fixed_base_scalar_mul_with_table(scalar_var, &mut table)?;
+        circuit.enforce_fixed_base_table_reads(table)?;
+        circuit.finalize_for_arithmetization()?;
+        Ok(circuit)
+    }
+}
@@ -13,6 +13,7 @@ use ark_ec::{
     CurveGroup,
 };
 use ark_ff::{PrimeField, Zero};
+use ark_std::{format, string::ToString, vec::Vec};
 use jf_utils::field_switching;
 use num_bigint::{BigInt, BigUint};
 
@@ -85,45 +86,86 @@ where
 {
     /// Perform GLV multiplication in circuit (which costs a few less
     /// constraints).
+    /// Return error if the curve is not Bandersnatch, the only supported curve
+    /// with an efficient endomorphism.
     pub fn glv_mul<P: TECurveConfig<BaseField = F>>(
         &mut self,
         scalar: Variable,
         base: &PointVariable,
     ) -> Result<PointVariable, CircuitError> {
-        self.check_var_bound(scalar)?;
-        self.check_point_var_bound(base)?;
-
-        let (s1_var, s2_var, s2_sign_var) =
-            scalar_decomposition_gate::<P::BaseField, P::ScalarField>(self, &scalar)?;
+        self.glv_msm::<P>(&[*base], &[scalar])
+    }
 
-        let endo_base_var = endomorphism_circuit::<_, P>(self, base)?;
-        multi_scalar_mul_circuit::<_, P>(self, base, s1_var, &endo_base_var, s2_var, s2_sign_var)
+    /// Compute the multi-scalar multiplication of `bases` by `scalars` with
+    /// GLV, i.e. each scalar is decomposed into two scalars of 128 bits, for
+    /// the base and its image by the endomorphism, which halves the number of
+    /// doublings. The doublings of a multi-scalar multiplication are shared
+    /// anyway, so that GLV only takes fewer gates for a single base.
+    /// Return error if the curve is not Bandersnatch, the only supported curve
+    /// with an efficient endomorphism, or the number of bases does not match
+    /// the number of scalars.
+    pub fn glv_msm<P: TECurveConfig<BaseField = F>>(
+        &mut self,
+        bases: &[PointVariable],
+        scalars: &[Variable],
+    ) -> Result<PointVariable, CircuitError> {
+        if !is_bandersnatch::<F, P>() {
+            return Err(CircuitError::ParameterError(
+                "GLV is only supported for Bandersnatch".to_string(),
+            ));
+        }
+        if bases.len() != scalars.len() {
+            return Err(CircuitError::ParameterError(format!(
+                "bases length ({}) does not match scalar length ({})",
+                bases.len(),
+                scalars.len()
+            )));
+        }
+        let mut glv_bases = Vec::with_capacity(2 * bases.len());
+        let mut glv_scalars = Vec::with_capacity(2 * scalars.len());
+        for (base, &scalar) in bases.iter().zip(scalars.iter()) {
+            self.check_var_bound(scalar)?;
+            self.check_point_var_bound(base)?;
+
+            let (s1_var, s2_var, s2_sign_var) =
+                scalar_decomposition_gate::<P::BaseField, P::ScalarField>(self, &scalar)?;
+
+            let endo_base = endomorphism_circuit::<_, P>(self, base)?;
+            let endo_base_neg = self.inverse_point(&endo_base)?;
+            let endo_base =
+                self.binary_point_vars_select(s2_sign_var, &endo_base, &endo_base_neg)?;
+
+            glv_bases.extend_from_slice(&[*base, endo_base]);
+            glv_scalars.extend_from_slice(&[s1_var, s2_var]);
+        }
+        // without lookup, the bits of the scalars constrain them to 128 bits
+        if self.support_lookup() {
+            for &scalar in glv_scalars.iter() {
+                self.enforce_in_range(scalar, 128)?;
+            }
+        }
+        MultiScalarMultiplicationCircuit::<F, P>::msm_with_var_scalar_length(
+            self,
+            &glv_bases,
+            &glv_scalars,
+            128,
+        )
     }
 }
 
-/// The circuit for 2 base scalar multiplication with scalar bit length 128.
-fn multi_scalar_mul_circuit<F, P>(
-    circuit: &mut PlonkCircuit<F>,
-    base: &PointVariable,
-    scalar_1: Variable,
-    endo_base: &PointVariable,
-    scalar_2: Variable,
-    scalar_2_sign_var: BoolVar,
-) -> Result<PointVariable, CircuitError>
+/// Whether the curve is Bandersnatch, whose endomorphism and scalar
+/// decomposition are implemented in this module.
+pub(crate) fn is_bandersnatch<F, P>() -> bool
 where
     F: PrimeField,
     P: TECurveConfig<BaseField = F>,
 {
-    let endo_base_neg = circuit.inverse_point(endo_base)?;
-    let endo_base =
-        circuit.binary_point_vars_select(scalar_2_sign_var, endo_base, &endo_base_neg)?;
-
-    MultiScalarMultiplicationCircuit::<F, P>::msm_with_var_scalar_length(
-        circuit,
-        &[*base, endo_base],
-        &[scalar_1, scalar_2],
-        128,
-    )
+    let scalar_modulus: BigUint = P::ScalarField::MODULUS.into();
+    let base_modulus: BigUint = F::MODULUS.into();
+    let bls12_381_fr_modulus: BigUint = ark_bls12_381::Fr::MODULUS.into();
+    base_modulus == bls12_381_fr_modulus
+        && scalar_modulus == BigUint::from_bytes_le(&R1) + (BigUint::from_bytes_le(&R2) << 128)
+        && P::COEFF_A == -F::from(5u8)
 }
 
 /// Mapping a point G to phi(G):= lambda G where phi is the endomorphism
@@ -472,8 +514,7 @@ where
     // ============================================
     //  (a) k1 < 2^128
     //  (b) k2 < 2^128
-    circuit.enforce_in_range(k1_var, 128)?;
-    circuit.enforce_in_range(k2_var, 128)?;
+    //  are left to the caller, whose scalar multiplication may constrain them
 
     //  (c) tmp1 = 0        <- implied by tmp = 2^128 * tmp2
     //  (d) tmp2 < 2^128
@@ -653,6 +694,77 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_glv_msm() -> Result<(), CircuitError> {
+        let mut rng = test_rng();
+        for dim in [1, 4] {
+            let bases: Vec<EdwardsAffine> =
+                (0..dim).map(|_| EdwardsAffine::rand(&mut rng)).collect();
+            let scalars: Vec<Fr> = (0..dim).map(|_| Fr::rand(&mut rng)).collect();
+            let expected: EdwardsAffine = bases
+                .iter()
+                .zip(scalars.iter())
+                .map(|(&base, &scalar)| base * scalar)
+                .sum::<Projective<EdwardsConfig>>()
+                .into();
+
+            for ultra in [false, true] {
+                let mut gate_counts = Vec::new();
+                for glv in [true, false] {
+                    let mut circuit: PlonkCircuit<Fq> = match ultra {
+                        true => PlonkCircuit::new_ultra_plonk(16),
+                        false => PlonkCircuit::new_turbo_plonk(),
+                    };
+                    let base_vars = bases
+                        .iter()
+                        .map(|&base| circuit.create_point_variable(TEPoint::from(base)))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let scalar_vars = scalars
+                        .iter()
+                        .map(|scalar| {
+                            circuit.create_variable(fr_to_fq::<Fq, EdwardsConfig>(scalar))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let res = match glv {
+                        true => circuit.glv_msm::<EdwardsConfig>(&base_vars, &scalar_vars)?,
+                        false => MultiScalarMultiplicationCircuit::<Fq, EdwardsConfig>::msm_with_var_scalar_length(
+                            &mut circuit,
+                            &base_vars,
+                            &scalar_vars,
+                            Fr::MODULUS_BIT_SIZE as usize,
+                        )?,
+                    };
+                    assert_eq!(circuit.point_witness(&res)?, TEPoint::from(expected));
+                    assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+                    gate_counts.push(circuit.num_gates());
+
+                    if glv {
+                        *circuit.witness_mut(scalar_vars[0]) += Fq::one();
+                        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+                    }
+                }
+                // GLV only pays off for a single base
+                if dim == 1 {
+                    assert!(gate_counts[0] < gate_counts[1]);
+                }
+            }
+        }
+
+        // other curves are not supported
+        let mut circuit: PlonkCircuit<ark_ed_on_bls12_381::Fq> = PlonkCircuit::new_ultra_plonk(16);
+        let base = circuit.create_point_variable(TEPoint::from(
+            ark_ed_on_bls12_381::EdwardsAffine::rand(&mut rng),
+        ))?;
+        assert!(!is_bandersnatch::<
+            ark_ed_on_bls12_381::Fq,
+            ark_ed_on_bls12_381::EdwardsConfig,
+        >());
+        assert!(circuit
+            .glv_mul::<ark_ed_on_bls12_381::EdwardsConfig>(circuit.one(), &base)
+            .is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_endomorphism() {
         let base_point = EdwardsAffine::new_unchecked(
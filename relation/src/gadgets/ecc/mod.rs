@@ -19,9 +19,11 @@ use core::marker::PhantomData;
 
 mod conversion;
 pub mod emulated;
+mod fixed_base;
 mod glv;
 mod msm;
 pub use conversion::*;
+pub use fixed_base::*;
 pub use msm::*;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -475,13 +477,8 @@ impl<F: PrimeField> PlonkCircuit<F> {
         self.check_var_bound(scalar)?;
         self.check_point_var_bound(base)?;
 
-        if self.support_lookup()
-            && P::ScalarField::MODULUS_BIT_SIZE == 253
-            && P::BaseField::MODULUS_BIT_SIZE == 255
-        {
+        if glv::is_bandersnatch::<F, P>() {
             // bandersnatch glv multiplication
-            // FIXME: we do not have an easier flag to tell if a parameter
-            // is bandersnatch or not, yet.
             self.glv_mul::<P>(scalar, base)
         } else {
             // non-bandersantch multiplication
@@ -521,6 +518,69 @@ impl<F: PrimeField> PlonkCircuit<F> {
         self.check_var_bound(point_var.1)?;
         Ok(())
     }
+
+    // The coordinates of P3 - P2 - P1 + P0 where P0 = (0, 1) is the neutral
+    // point, for `Self::quaternary_point_vars_select()` with P3 = P1 + P2.
+    fn quaternary_point_vars_diff(
+        &mut self,
+        point1: &PointVariable,
+        point2: &PointVariable,
+        point3: &PointVariable,
+    ) -> Result<PointVariable, CircuitError> {
+        let (one, neg_one) = (F::one(), -F::one());
+        Ok(PointVariable(
+            self.lin_comb_with_constant(
+                &[(one, point3.0), (neg_one, point2.0), (neg_one, point1.0)],
+                F::zero(),
+            )?,
+            self.lin_comb_with_constant(
+                &[(one, point3.1), (neg_one, point2.1), (neg_one, point1.1)],
+                one,
+            )?,
+        ))
+    }
+
+    // Select the point variable P_{b0 + 2 * b1} where P0 = (0, 1) is the
+    // neutral point and P3 = P1 + P2, given the coordinates `diff` of
+    // P3 - P2 - P1 + P0. Each coordinate is
+    //   c0 + b0 * (c1 - c0) + b1 * (c2 - c0 + b0 * diff)
+    // which takes 2 gates.
+    fn quaternary_point_vars_select(
+        &mut self,
+        b0: BoolVar,
+        b1: BoolVar,
+        point1: &PointVariable,
+        point2: &PointVariable,
+        diff: &PointVariable,
+    ) -> Result<PointVariable, CircuitError> {
+        let zero = self.zero();
+        let (one, neg_one) = (F::one(), -F::one());
+        let partial_x = self.gen_quad_poly(
+            &[b0.into(), diff.0, point2.0, zero],
+            &[F::zero(), F::zero(), one, F::zero()],
+            &[one, F::zero()],
+            F::zero(),
+        )?;
+        let x = self.gen_quad_poly(
+            &[b0.into(), point1.0, b1.into(), partial_x],
+            &[F::zero(); 4],
+            &[one, one],
+            F::zero(),
+        )?;
+        let partial_y = self.gen_quad_poly(
+            &[b0.into(), diff.1, point2.1, zero],
+            &[F::zero(), F::zero(), one, F::zero()],
+            &[one, F::zero()],
+            neg_one,
+        )?;
+        let y = self.gen_quad_poly(
+            &[b0.into(), point1.1, b1.into(), partial_y],
+            &[neg_one, F::zero(), F::zero(), F::zero()],
+            &[one, one],
+            one,
+        )?;
+        Ok(PointVariable(x, y))
+    }
 }
 
 // Given a base point [G] and a scalar s of length 2*n, denote as s[G] the
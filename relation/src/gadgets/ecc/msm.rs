@@ -6,7 +6,7 @@
 
 //! This module implements multi-scalar-multiplication circuits.
 
-use super::{PointVariable, TEPoint};
+use super::{glv::is_bandersnatch, PointVariable, TEPoint};
 use crate::{Circuit, CircuitError, PlonkCircuit, Variable};
 use ark_ec::{
     twisted_edwards::{Projective, TECurveConfig as Config},
@@ -24,7 +24,10 @@ where
 {
     /// Compute the multi-scalar-multiplications.
     /// Use pippenger when the circuit supports lookup;
-    /// Use naive method otherwise.
+    /// Use Straus' method otherwise, i.e. a double-and-add shared by all the
+    /// bases.
+    /// For a single Bandersnatch base, use GLV decomposition, which takes
+    /// fewer gates.
     /// Return error if the number bases does not match the number of scalars.
     fn msm(
         &mut self,
@@ -52,6 +55,9 @@ where
         bases: &[PointVariable],
         scalars: &[Variable],
     ) -> Result<PointVariable, CircuitError> {
+        if is_bandersnatch::<F, P>() && bases.len() == 1 {
+            return self.glv_msm::<P>(bases, scalars);
+        }
         let scalar_bit_length = <P as CurveConfig>::ScalarField::MODULUS_BIT_SIZE as usize;
         MultiScalarMultiplicationCircuit::<F, P>::msm_with_var_scalar_length(
             self,
@@ -78,51 +84,52 @@ where
         if self.support_lookup() {
             msm_pippenger::<F, P>(self, bases, scalars, scalar_bit_length)
         } else {
-            msm_naive::<F, P>(self, bases, scalars, scalar_bit_length)
+            msm_interleaved::<F, P>(self, bases, scalars, scalar_bit_length)
         }
     }
 }
 
-// A naive way to implement msm by computing them individually.
-// Used for double checking the correctness; also as a fall-back solution
-// to Pippenger.
+// Straus' method: the bases share a single double-and-add over windows of 2
+// bits, where each base adds one of [0 * base, 1 * base, 2 * base, 3 * base]
+// selected by the bits of its window. Used when the circuit does not support
+// lookup.
 //
 // Some typical result on BW6-761 curve is shown below (i.e. the circuit
 // simulates BLS12-377 curve operations). More results are available in the test
 // function.
 //
 // number of basis: 1
-// #variables: 1867
-// #constraints: 1865
+// #variables: 1614
+// #constraints: 1612
 //
 // number of basis: 2
-// #variables: 3734
-// #constraints: 3730
+// #variables: 2722
+// #constraints: 2718
 //
 // number of basis: 4
-// #variables: 7468
-// #constraints: 7460
+// #variables: 4938
+// #constraints: 4930
 //
 // number of basis: 8
-// #variables: 14936
-// #constraints: 14920
+// #variables: 9370
+// #constraints: 9354
 //
 // number of basis: 16
-// #variables: 29872
-// #constraints: 29840
+// #variables: 18234
+// #constraints: 18202
 //
 // number of basis: 32
-// #variables: 59744
-// #constraints: 59680
+// #variables: 35962
+// #constraints: 35898
 //
 // number of basis: 64
-// #variables: 119488
-// #constraints: 119360
+// #variables: 71418
+// #constraints: 71290
 //
 // number of basis: 128
-// #variables: 238976
-// #constraints: 238720
-fn msm_naive<F, P>(
+// #variables: 142330
+// #constraints: 142074
+fn msm_interleaved<F, P>(
     circuit: &mut PlonkCircuit<F>,
     bases: &[PointVariable],
     scalars: &[Variable],
@@ -137,16 +144,35 @@ where
         circuit.check_point_var_bound(base)?;
     }
 
-    let scalar_0_bits_le = circuit.unpack(scalars[0], scalar_bit_length)?;
-    let mut res = circuit.variable_base_binary_scalar_mul::<P>(&scalar_0_bits_le, &bases[0])?;
-
-    for (base, scalar) in bases.iter().zip(scalars.iter()).skip(1) {
-        let scalar_bits_le = circuit.unpack(*scalar, scalar_bit_length)?;
-        let tmp = circuit.variable_base_binary_scalar_mul::<P>(&scalar_bits_le, base)?;
-        res = circuit.ecc_add::<P>(&res, &tmp)?;
+    let num_windows = (scalar_bit_length + 1) / 2;
+    let mut tables = Vec::with_capacity(bases.len());
+    for (base, &scalar) in bases.iter().zip(scalars.iter()) {
+        let mut scalar_bits_le = circuit.unpack(scalar, scalar_bit_length)?;
+        scalar_bits_le.resize(2 * num_windows, circuit.false_var());
+        let double = circuit.ecc_add::<P>(base, base)?;
+        let triple = circuit.ecc_add::<P>(&double, base)?;
+        let diff = circuit.quaternary_point_vars_diff(base, &double, &triple)?;
+        tables.push((scalar_bits_le, *base, double, diff));
     }
 
-    Ok(res)
+    let mut accum = circuit.neutral_point_variable();
+    for i in (0..num_windows).rev() {
+        if i + 1 < num_windows {
+            accum = circuit.ecc_add::<P>(&accum, &accum)?;
+            accum = circuit.ecc_add::<P>(&accum, &accum)?;
+        }
+        for (scalar_bits_le, base, double, diff) in tables.iter() {
+            let selected = circuit.quaternary_point_vars_select(
+                scalar_bits_le[2 * i],
+                scalar_bits_le[2 * i + 1],
+                base,
+                double,
+                diff,
+            )?;
+            accum = circuit.ecc_add::<P>(&accum, &selected)?;
+        }
+    }
+    Ok(accum)
 }
 
 // A variant of Pippenger MSM.
@@ -378,6 +404,7 @@ mod tests {
     use ark_ed_on_bls12_381::{EdwardsConfig as ParamEd381, Fq as FqEd381};
     use ark_ed_on_bn254::{EdwardsConfig as ParamEd254, Fq as FqEd254};
     use ark_ff::UniformRand;
+    use ark_std::rand::Rng;
     use jf_utils::fr_to_fq;
 
     const RANGE_BIT_LEN_FOR_TEST: usize = 8;
@@ -476,4 +503,74 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_msm_interleaved() -> Result<(), CircuitError> {
+        test_msm_interleaved_helper::<FqEd254, ParamEd254>()?;
+        test_msm_interleaved_helper::<Fq377, Param377>()
+    }
+
+    fn test_msm_interleaved_helper<F, P>() -> Result<(), CircuitError>
+    where
+        F: PrimeField,
+        P: Config<BaseField = F>,
+    {
+        let mut rng = jf_utils::test_rng();
+        // scalars of an odd number of bits, for a partial last window
+        let scalar_bit_length = 9;
+        for dim in [0, 1, 3] {
+            let bases: Vec<Affine<P>> = (0..dim).map(|_| Affine::<P>::rand(&mut rng)).collect();
+            let scalars: Vec<P::ScalarField> = (0..dim)
+                .map(|_| P::ScalarField::from(rng.gen_range(0..1u16 << scalar_bit_length)))
+                .collect();
+            let scalar_reprs: Vec<<P::ScalarField as PrimeField>::BigInt> =
+                scalars.iter().map(|x| x.into_bigint()).collect();
+            let res_point: TEPoint<F> = Projective::<P>::msm_bigint(&bases, &scalar_reprs).into();
+
+            let mut gate_counts = vec![];
+            for interleaved in [true, false] {
+                let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+                let bases_vars = bases
+                    .iter()
+                    .map(|&x| circuit.create_point_variable(x.into()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let scalar_vars = scalars
+                    .iter()
+                    .map(|x| circuit.create_variable(fr_to_fq::<F, P>(x)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let num_gates = circuit.num_gates();
+                let res_var = match interleaved {
+                    true => MultiScalarMultiplicationCircuit::<F, P>::msm_with_var_scalar_length(
+                        &mut circuit,
+                        &bases_vars,
+                        &scalar_vars,
+                        scalar_bit_length,
+                    )?,
+                    // repeated scalar multiplications then additions
+                    false => {
+                        let mut res = circuit.neutral_point_variable();
+                        for (base, &scalar) in bases_vars.iter().zip(scalar_vars.iter()) {
+                            let bits = circuit.unpack(scalar, scalar_bit_length)?;
+                            let tmp = circuit.variable_base_binary_scalar_mul::<P>(&bits, base)?;
+                            res = circuit.ecc_add::<P>(&res, &tmp)?;
+                        }
+                        res
+                    },
+                };
+                assert_eq!(circuit.point_witness(&res_var)?, res_point);
+                assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+                gate_counts.push(circuit.num_gates() - num_gates);
+
+                // a scalar out of range should fail
+                if interleaved && dim > 0 {
+                    *circuit.witness_mut(scalar_vars[0]) += F::from(1u16 << scalar_bit_length);
+                    assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+                }
+            }
+            if dim > 0 {
+                assert!(gate_counts[0] < gate_counts[1]);
+            }
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,507 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! ECDSA signature verification over short Weierstrass curves whose base and
+//! scalar fields are both emulated, e.g. secp256k1 or P-256 over the BN254
+//! scalar field.
+
+pub mod p256;
+pub mod secp256k1;
+
+use crate::{
+    gadgets::{
+        biguint_to_limbs,
+        ecc::emulated::{EmulatedSWPointVariable, SWPoint},
+        EmulatedVariable, EmulationConfig,
+    },
+    BoolVar, Circuit, CircuitError, PlonkCircuit, Variable,
+};
+use ark_ec::{
+    short_weierstrass::{Affine, Projective, SWCurveConfig},
+    CurveGroup,
+};
+use ark_ff::PrimeField;
+use ark_std::{format, string::ToString, vec, vec::Vec};
+use num_bigint::BigUint;
+
+/// Number of bits of the scalars processed at once in the scalar
+/// multiplications.
+pub(super) const WINDOW_SIZE: usize = 4;
+
+/// The variable of an ECDSA signature `(r, s)`, with emulated scalars.
+#[derive(Debug, Clone)]
+pub struct EcdsaSignatureVar<S: PrimeField> {
+    /// The x-coordinate of the nonce point, reduced into the scalar field.
+    pub r: EmulatedVariable<S>,
+    /// The proof of knowledge of the signing key.
+    pub s: EmulatedVariable<S>,
+}
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Add an ECDSA signature variable, constraining its scalars `r` and `s`
+    /// to be reduced.
+    pub fn create_ecdsa_signature_variable<S: EmulationConfig<F>>(
+        &mut self,
+        r: S,
+        s: S,
+    ) -> Result<EcdsaSignatureVar<S>, CircuitError> {
+        let r = self.create_emulated_variable(r)?;
+        let s = self.create_emulated_variable(s)?;
+        self.enforce_emulated_var_reduced(&r)?;
+        self.enforce_emulated_var_reduced(&s)?;
+        Ok(EcdsaSignatureVar { r, s })
+    }
+
+    /// Constrain `sig` to be a valid ECDSA signature of the message hash
+    /// `msg_hash`, already reduced into the scalar field, under the public
+    /// key `pk` on the curve `P`: with `u1 = msg_hash / s` and `u2 = r / s`,
+    /// the x-coordinate of `u1 * G + u2 * pk` is `r` modulo the group order.
+    ///
+    /// `pk` is constrained to be a point of `P` other than the point at
+    /// infinity. The scalars of `sig` should be reduced, as done by
+    /// [`Self::create_ecdsa_signature_variable()`].
+    /// Return error if the fields of `P` are emulated with different limbs, or
+    /// if the base field modulus is not smaller than twice the group order,
+    /// as the x-coordinate of `R` is then not reduced with a single
+    /// subtraction.
+    pub fn enforce_ecdsa_signature<P>(
+        &mut self,
+        pk: &EmulatedSWPointVariable<P::BaseField>,
+        msg_hash: &EmulatedVariable<P::ScalarField>,
+        sig: &EcdsaSignatureVar<P::ScalarField>,
+    ) -> Result<(), CircuitError>
+    where
+        P: SWCurveConfig,
+        P::BaseField: EmulationConfig<F>,
+        P::ScalarField: EmulationConfig<F>,
+    {
+        if P::BaseField::B != P::ScalarField::B
+            || P::BaseField::NUM_LIMBS != P::ScalarField::NUM_LIMBS
+        {
+            return Err(CircuitError::ParameterError(format!(
+                "the base and scalar fields are emulated with {} limbs of {} bits and {} limbs \
+                 of {} bits",
+                P::BaseField::NUM_LIMBS,
+                P::BaseField::B,
+                P::ScalarField::NUM_LIMBS,
+                P::ScalarField::B
+            )));
+        }
+        let base_modulus: BigUint = P::BaseField::MODULUS.into();
+        let scalar_modulus: BigUint = P::ScalarField::MODULUS.into();
+        if base_modulus >= scalar_modulus << 1 {
+            return Err(CircuitError::ParameterError(
+                "the base field modulus is not smaller than twice the group order".to_string(),
+            ));
+        }
+        self.enforce_emulated_sw_point_on_curve::<P>(pk)?;
+        let r_is_zero = self.is_emulated_var_zero(&sig.r)?;
+        self.enforce_false(r_is_zero.into())?;
+        let s_is_zero = self.is_emulated_var_zero(&sig.s)?;
+        self.enforce_false(s_is_zero.into())?;
+
+        // u1 = msg_hash / s, u2 = r / s
+        let s_inv = self.emulated_inverse(&sig.s)?;
+        let u1 = self.emulated_mul(msg_hash, &s_inv)?;
+        let u2 = self.emulated_mul(&sig.r, &s_inv)?;
+
+        // R = u1 * G + u2 * pk
+        let point = self.emulated_sw_double_scalar_mul::<P>(&u1, &u2, pk)?;
+        self.enforce_false(point.2.into())?;
+
+        // R.x mod n = r, where R.x < q < 2n
+        self.enforce_emulated_var_reduced(&point.0)?;
+        self.enforce_limbs_congruent(&point.0 .0, &sig.r)
+    }
+
+    /// Constrain `sig` to be a valid ECDSA signature of the message `msg`,
+    /// given as byte variables, hashed with SHA-256 in the circuit, under the
+    /// public key `pk` on the curve `P`. As in ECDSA, the digest is truncated
+    /// to the bit length of the group order.
+    ///
+    /// For a WebAuthn (passkey) assertion, `msg` is the authenticator data
+    /// followed by the SHA-256 digest of the client data JSON, which can be
+    /// computed in the circuit with [`Self::sha256()`].
+    /// Return error if the input variables are invalid.
+    pub fn enforce_ecdsa_sha256_signature<P>(
+        &mut self,
+        pk: &EmulatedSWPointVariable<P::BaseField>,
+        msg: &[Variable],
+        sig: &EcdsaSignatureVar<P::ScalarField>,
+    ) -> Result<(), CircuitError>
+    where
+        P: SWCurveConfig,
+        P::BaseField: EmulationConfig<F>,
+        P::ScalarField: EmulationConfig<F>,
+    {
+        let msg_hash = self.ecdsa_sha256_msg_hash::<P::ScalarField>(msg)?;
+        self.enforce_ecdsa_signature::<P>(pk, &msg_hash, sig)
+    }
+
+    /// Return the SHA-256 digest of `msg`, truncated to its leftmost
+    /// `S::MODULUS_BIT_SIZE` bits and reduced into `S`.
+    /// Return error if the truncated digest may not be smaller than
+    /// `2 * S::MODULUS`.
+    fn ecdsa_sha256_msg_hash<S: EmulationConfig<F>>(
+        &mut self,
+        msg: &[Variable],
+    ) -> Result<EmulatedVariable<S>, CircuitError> {
+        let bits = self.sha256_bits(msg)?;
+        let num_bits = ark_std::cmp::min(S::MODULUS_BIT_SIZE as usize, bits.len());
+        let modulus: BigUint = S::MODULUS.into();
+        if BigUint::from(1u32) << num_bits > modulus << 1 {
+            return Err(CircuitError::ParameterError(format!(
+                "a digest of {num_bits} bits may not be smaller than twice the modulus"
+            )));
+        }
+        let bits_le: Vec<_> = bits[..num_bits].iter().rev().copied().collect();
+        let mut limbs = bits_le
+            .chunks(S::B)
+            .map(|chunk| {
+                let terms: Vec<_> = chunk
+                    .iter()
+                    .enumerate()
+                    .map(|(i, bit)| (F::from(2u32).pow([i as u64]), bit.0))
+                    .collect();
+                self.lin_comb_with_constant(&terms, F::zero())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        limbs.resize(S::NUM_LIMBS, self.zero());
+
+        // the truncated digest is smaller than 2^num_bits <= 2 * S::MODULUS
+        let msg_hash = S::from(self.limbs_witness::<S>(&limbs)?);
+        let msg_hash = self.create_emulated_variable(msg_hash)?;
+        self.enforce_limbs_congruent(&limbs, &msg_hash)?;
+        Ok(msg_hash)
+    }
+
+    /// Constrain the integer with little-endian limbs `limbs` of `S::B` bits,
+    /// smaller than `2 * S::MODULUS`, to be `a + wraps * S::MODULUS` for a
+    /// boolean `wraps`, hence congruent to `a` modulo `S::MODULUS`.
+    fn enforce_limbs_congruent<S: EmulationConfig<F>>(
+        &mut self,
+        limbs: &[Variable],
+        a: &EmulatedVariable<S>,
+    ) -> Result<(), CircuitError> {
+        let modulus: BigUint = S::MODULUS.into();
+        let wraps = self.create_boolean_variable(self.limbs_witness::<S>(limbs)? >= modulus)?;
+        let modulus_limbs = biguint_to_limbs::<F>(&modulus, S::B, S::NUM_LIMBS);
+        let b_pow = F::from(2u32).pow([S::B as u64]);
+        let mut carry_in = self.zero();
+        for (i, (&a, &limb)) in a.0.iter().zip(limbs.iter()).enumerate() {
+            let carry_out = if i == S::NUM_LIMBS - 1 {
+                self.zero()
+            } else {
+                let sum = self.witness(a)?
+                    + self.witness(wraps.into())? * modulus_limbs[i]
+                    + self.witness(carry_in)?;
+                let carry_out = <F as Into<BigUint>>::into(sum) >> S::B;
+                self.create_boolean_variable(carry_out == BigUint::from(1u32))?
+                    .into()
+            };
+            self.lc_gate(
+                &[a, wraps.into(), carry_in, carry_out, limb],
+                &[F::one(), modulus_limbs[i], F::one(), -b_pow],
+            )?;
+            carry_in = carry_out;
+        }
+        Ok(())
+    }
+
+    /// The integer with little-endian limbs `limbs` of `S::B` bits.
+    fn limbs_witness<S: EmulationConfig<F>>(
+        &self,
+        limbs: &[Variable],
+    ) -> Result<BigUint, CircuitError> {
+        limbs
+            .iter()
+            .rev()
+            .try_fold(BigUint::from(0u32), |acc, &limb| {
+                Ok((acc << S::B) + <F as Into<BigUint>>::into(self.witness(limb)?))
+            })
+    }
+
+    /// Constrain `p` to be a point of the curve `P` other than the point at
+    /// infinity.
+    pub(super) fn enforce_emulated_sw_point_on_curve<P>(
+        &mut self,
+        p: &EmulatedSWPointVariable<P::BaseField>,
+    ) -> Result<(), CircuitError>
+    where
+        P: SWCurveConfig,
+        P::BaseField: EmulationConfig<F>,
+    {
+        self.enforce_false(p.2.into())?;
+        // y^2 = x^3 + a * x + b
+        let x_sq = self.emulated_mul(&p.0, &p.0)?;
+        let x_cube = self.emulated_mul(&x_sq, &p.0)?;
+        let a_x = self.emulated_mul_constant(&p.0, P::COEFF_A)?;
+        let rhs = self.emulated_add(&x_cube, &a_x)?;
+        let rhs = self.emulated_add_constant(&rhs, P::COEFF_B)?;
+        let lhs = self.emulated_mul(&p.1, &p.1)?;
+        self.enforce_emulated_var_equal(&lhs, &rhs)
+    }
+
+    /// Return the point `u1 * G + u2 * p` on the curve `P` with generator `G`,
+    /// computed with Shamir's trick and a fixed window of [`WINDOW_SIZE`]
+    /// bits.
+    fn emulated_sw_double_scalar_mul<P>(
+        &mut self,
+        u1: &EmulatedVariable<P::ScalarField>,
+        u2: &EmulatedVariable<P::ScalarField>,
+        p: &EmulatedSWPointVariable<P::BaseField>,
+    ) -> Result<EmulatedSWPointVariable<P::BaseField>, CircuitError>
+    where
+        P: SWCurveConfig,
+        P::BaseField: EmulationConfig<F>,
+        P::ScalarField: EmulationConfig<F>,
+    {
+        let u1_bits = self.emulated_var_to_windows(u1)?;
+        let u2_bits = self.emulated_var_to_windows(u2)?;
+
+        // tables of [0, 1, ..., 2^WINDOW_SIZE - 1] times G and p
+        let mut g_table = vec![];
+        let mut multiple = Projective::<P>::default();
+        for _ in 0..1 << WINDOW_SIZE {
+            let point: Affine<P> = multiple.into_affine();
+            g_table.push(self.create_constant_emulated_sw_point_variable(point.into())?);
+            multiple += P::GENERATOR;
+        }
+        let mut p_table =
+            vec![self.create_constant_emulated_sw_point_variable(SWPoint::default())?];
+        for i in 1..1 << WINDOW_SIZE {
+            let point = self.emulated_sw_ecc_add(&p_table[i - 1], p, P::COEFF_A)?;
+            p_table.push(point);
+        }
+
+        let mut acc: Option<EmulatedSWPointVariable<P::BaseField>> = None;
+        for (u1_window, u2_window) in u1_bits.iter().zip(u2_bits.iter()).rev() {
+            let mut point = match acc {
+                Some(mut point) => {
+                    for _ in 0..WINDOW_SIZE {
+                        point = self.emulated_sw_ecc_add(&point, &point, P::COEFF_A)?;
+                    }
+                    let g_multiple = self.emulated_sw_point_table_select(u1_window, &g_table)?;
+                    self.emulated_sw_ecc_add(&point, &g_multiple, P::COEFF_A)?
+                },
+                None => self.emulated_sw_point_table_select(u1_window, &g_table)?,
+            };
+            let p_multiple = self.emulated_sw_point_table_select(u2_window, &p_table)?;
+            point = self.emulated_sw_ecc_add(&point, &p_multiple, P::COEFF_A)?;
+            acc = Some(point);
+        }
+        acc.ok_or_else(|| CircuitError::InternalError("empty scalar".to_string()))
+    }
+
+    /// Decompose an emulated scalar into little-endian windows of
+    /// [`WINDOW_SIZE`] little-endian bits, constraining it to
+    /// `S::MODULUS_BIT_SIZE` bits. The scalar may not be reduced, which does
+    /// not change its multiples.
+    pub(super) fn emulated_var_to_windows<S: EmulationConfig<F>>(
+        &mut self,
+        a: &EmulatedVariable<S>,
+    ) -> Result<Vec<Vec<BoolVar>>, CircuitError> {
+        let mut bits = vec![];
+        let mut num_bits = S::MODULUS_BIT_SIZE as usize;
+        for &limb in a.0.iter() {
+            let bit_len = ark_std::cmp::min(S::B, num_bits);
+            if bit_len == 0 {
+                self.enforce_constant(limb, F::zero())?;
+            } else {
+                bits.extend(self.unpack(limb, bit_len)?);
+            }
+            num_bits -= bit_len;
+        }
+        let zero = BoolVar(self.zero());
+        Ok(bits
+            .chunks(WINDOW_SIZE)
+            .map(|window| {
+                let mut window = window.to_vec();
+                window.resize(WINDOW_SIZE, zero);
+                window
+            })
+            .collect())
+    }
+
+    /// Return `table[i]` for the index `i` of little-endian bits `bits`.
+    fn emulated_sw_point_table_select<E: EmulationConfig<F>>(
+        &mut self,
+        bits: &[BoolVar],
+        table: &[EmulatedSWPointVariable<E>],
+    ) -> Result<EmulatedSWPointVariable<E>, CircuitError> {
+        let mut table = table.to_vec();
+        for &bit in bits.iter() {
+            table = table
+                .chunks(2)
+                .map(|pair| self.binary_emulated_sw_point_vars_select(bit, &pair[0], &pair[1]))
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+        Ok(table.remove(0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fr as Fr254;
+    use ark_ec::{AffineRepr, CurveConfig};
+    use ark_ff::{Field, One};
+    use ark_std::UniformRand;
+    use sha2::{Digest, Sha256};
+
+    type ScalarField<P> = <P as CurveConfig>::ScalarField;
+
+    #[test]
+    fn test_ecdsa() -> Result<(), CircuitError> {
+        test_ecdsa_helper::<Fr254, secp256k1::Config>()?;
+        test_ecdsa_helper::<Fr254, p256::Config>()
+    }
+
+    // A curve over the BN254 base field with the much smaller Ed25519 group
+    // order, only used to check that the field sizes are rejected.
+    #[derive(Copy, Clone, Default, PartialEq, Eq)]
+    struct WideBaseFieldConfig;
+
+    impl CurveConfig for WideBaseFieldConfig {
+        type BaseField = ark_bn254::Fq;
+        type ScalarField = crate::gadgets::eddsa::ed25519::Fr;
+
+        const COFACTOR: &'static [u64] = &[0x1];
+        const COFACTOR_INV: Self::ScalarField = <Self::ScalarField as Field>::ONE;
+    }
+
+    impl SWCurveConfig for WideBaseFieldConfig {
+        const COEFF_A: ark_bn254::Fq = <ark_bn254::Fq as Field>::ZERO;
+        const COEFF_B: ark_bn254::Fq = ark_ff::MontFp!("3");
+        const GENERATOR: Affine<Self> =
+            Affine::new_unchecked(ark_ff::MontFp!("1"), ark_ff::MontFp!("2"));
+    }
+
+    #[test]
+    fn test_ecdsa_wide_base_field() -> Result<(), CircuitError> {
+        type ScalarField = <WideBaseFieldConfig as CurveConfig>::ScalarField;
+        let mut circuit = PlonkCircuit::<Fr254>::new_ultra_plonk(16);
+        let pk =
+            circuit.create_emulated_sw_point_variable(WideBaseFieldConfig::GENERATOR.into())?;
+        let z = circuit.create_emulated_variable(ScalarField::one())?;
+        let sig =
+            circuit.create_ecdsa_signature_variable(ScalarField::one(), ScalarField::one())?;
+        assert!(matches!(
+            circuit.enforce_ecdsa_signature::<WideBaseFieldConfig>(&pk, &z, &sig),
+            Err(CircuitError::ParameterError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ecdsa_sha256() -> Result<(), CircuitError> {
+        test_ecdsa_sha256_helper::<Fr254, p256::Config>()
+    }
+
+    // Sign the message hash `z` with `sk`.
+    fn sign<P>(sk: ScalarField<P>, z: ScalarField<P>) -> (ScalarField<P>, ScalarField<P>)
+    where
+        P: SWCurveConfig,
+        P::BaseField: PrimeField,
+    {
+        let rng = &mut jf_utils::test_rng();
+        let k = ScalarField::<P>::rand(rng);
+        let nonce_x: BigUint = (P::GENERATOR * k).into_affine().x.into();
+        let r = ScalarField::<P>::from(nonce_x);
+        let s = k.inverse().unwrap() * (z + r * sk);
+        (r, s)
+    }
+
+    fn gen_circuit<F, P>(
+        pk: Affine<P>,
+        z: ScalarField<P>,
+        sig: (ScalarField<P>, ScalarField<P>),
+    ) -> Result<PlonkCircuit<F>, CircuitError>
+    where
+        F: PrimeField,
+        P: SWCurveConfig,
+        P::BaseField: EmulationConfig<F>,
+        P::ScalarField: EmulationConfig<F>,
+    {
+        let mut circuit = PlonkCircuit::<F>::new_ultra_plonk(16);
+        let pk = circuit.create_emulated_sw_point_variable(pk.into())?;
+        let z = circuit.create_public_emulated_variable(z)?;
+        let sig = circuit.create_ecdsa_signature_variable(sig.0, sig.1)?;
+        circuit.enforce_ecdsa_signature::<P>(&pk, &z, &sig)?;
+        Ok(circuit)
+    }
+
+    fn test_ecdsa_helper<F, P>() -> Result<(), CircuitError>
+    where
+        F: PrimeField,
+        P: SWCurveConfig,
+        P::BaseField: EmulationConfig<F>,
+        P::ScalarField: EmulationConfig<F>,
+    {
+        let rng = &mut jf_utils::test_rng();
+        assert!(P::GENERATOR.is_on_curve());
+        assert!(P::GENERATOR
+            .mul_bigint(ScalarField::<P>::MODULUS)
+            .into_affine()
+            .is_zero());
+
+        let sk = ScalarField::<P>::rand(rng);
+        let pk = (P::GENERATOR * sk).into_affine();
+        let z = ScalarField::<P>::rand(rng);
+        let sig = sign::<P>(sk, z);
+        let public_input = crate::gadgets::from_emulated_field::<_, F>(z);
+
+        let circuit = gen_circuit::<F, P>(pk, z, sig)?;
+        circuit.check_circuit_satisfiability(&public_input)?;
+
+        // wrong message hash, public key and signature
+        let wrong_z = z + ScalarField::<P>::one();
+        let circuit = gen_circuit::<F, P>(pk, wrong_z, sig)?;
+        assert!(circuit
+            .check_circuit_satisfiability(&crate::gadgets::from_emulated_field::<_, F>(wrong_z))
+            .is_err());
+        let wrong_pk = (pk + P::GENERATOR).into_affine();
+        let circuit = gen_circuit::<F, P>(wrong_pk, z, sig)?;
+        assert!(circuit.check_circuit_satisfiability(&public_input).is_err());
+        let circuit = gen_circuit::<F, P>(pk, z, (sig.0, sig.1 + ScalarField::<P>::one()))?;
+        assert!(circuit.check_circuit_satisfiability(&public_input).is_err());
+        Ok(())
+    }
+
+    fn test_ecdsa_sha256_helper<F, P>() -> Result<(), CircuitError>
+    where
+        F: PrimeField,
+        P: SWCurveConfig,
+        P::BaseField: EmulationConfig<F>,
+        P::ScalarField: EmulationConfig<F>,
+    {
+        let rng = &mut jf_utils::test_rng();
+        let sk = ScalarField::<P>::rand(rng);
+        let pk = (P::GENERATOR * sk).into_affine();
+        // authenticator data followed by the digest of the client data JSON
+        let mut msg = b"authenticator data".to_vec();
+        msg.extend(Sha256::digest(
+            br#"{"type":"webauthn.get","challenge":"..."}"#,
+        ));
+        let z = ScalarField::<P>::from(BigUint::from_bytes_be(&Sha256::digest(&msg)));
+        let sig = sign::<P>(sk, z);
+
+        let mut circuit = PlonkCircuit::<F>::new_ultra_plonk(16);
+        let pk_var = circuit.create_emulated_sw_point_variable(pk.into())?;
+        let msg_vars = msg
+            .iter()
+            .map(|&byte| circuit.create_variable(F::from(byte)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let sig_var = circuit.create_ecdsa_signature_variable(sig.0, sig.1)?;
+        circuit.enforce_ecdsa_sha256_signature::<P>(&pk_var, &msg_vars, &sig_var)?;
+        circuit.check_circuit_satisfiability(&[])?;
+
+        // a message with a different byte
+        *circuit.witness_mut(msg_vars[0]) += F::one();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! The P-256 (secp256r1) curve `y^2 = x^3 - 3 * x + b` of WebAuthn passkeys.
+
+// the `MontConfig` derive macro implements traits inside a function
+#![allow(non_local_definitions)]
+
+use crate::gadgets::EmulationConfig;
+use ark_ec::{models::CurveConfig, short_weierstrass::SWCurveConfig};
+use ark_ff::{
+    fields::{Fp256, MontBackend, MontConfig},
+    Field, MontFp,
+};
+
+/// The parameters of [`Fq`].
+#[derive(MontConfig)]
+#[modulus = "115792089210356248762697446949407573530086143415290314195533631308867097853951"]
+#[generator = "6"]
+pub struct FqConfig;
+/// The base field of P-256.
+pub type Fq = Fp256<MontBackend<FqConfig, 4>>;
+
+/// The parameters of [`Fr`].
+#[derive(MontConfig)]
+#[modulus = "115792089210356248762697446949407573529996955224135760342422259061068512044369"]
+#[generator = "7"]
+pub struct FrConfig;
+/// The scalar field of P-256.
+pub type Fr = Fp256<MontBackend<FrConfig, 4>>;
+
+/// The parameters of P-256.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Config;
+
+/// A P-256 point in affine coordinates.
+pub type Affine = ark_ec::short_weierstrass::Affine<Config>;
+
+impl CurveConfig for Config {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[0x1];
+    const COFACTOR_INV: Fr = Fr::ONE;
+}
+
+impl SWCurveConfig for Config {
+    const COEFF_A: Fq = MontFp!("-3");
+    const COEFF_B: Fq =
+        MontFp!("41058363725152142129326129780047268409114441015993725554835256314039467401291");
+    const GENERATOR: Affine = Affine::new_unchecked(
+        MontFp!("48439561293906451759052585252797914202762949526041747995844080717082404635286"),
+        MontFp!("36134250956749795798585127919587881956611106672985015071877198253568414405109"),
+    );
+}
+
+impl EmulationConfig<ark_bn254::Fr> for Fq {
+    const T: usize = 300;
+    const B: usize = 100;
+    const NUM_LIMBS: usize = 3;
+}
+
+impl EmulationConfig<ark_bn254::Fr> for Fr {
+    const T: usize = 300;
+    const B: usize = 100;
+    const NUM_LIMBS: usize = 3;
+}
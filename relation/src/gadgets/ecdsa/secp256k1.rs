@@ -0,0 +1,74 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! The secp256k1 curve `y^2 = x^3 + 7` of Bitcoin and Ethereum accounts.
+
+// the `MontConfig` derive macro implements traits inside a function
+#![allow(non_local_definitions)]
+
+use crate::gadgets::EmulationConfig;
+use ark_ec::{models::CurveConfig, short_weierstrass::SWCurveConfig};
+use ark_ff::{
+    fields::{Fp256, MontBackend, MontConfig},
+    Field, MontFp, Zero,
+};
+
+/// The parameters of [`Fq`].
+#[derive(MontConfig)]
+#[modulus = "115792089237316195423570985008687907853269984665640564039457584007908834671663"]
+#[generator = "3"]
+pub struct FqConfig;
+/// The base field of secp256k1.
+pub type Fq = Fp256<MontBackend<FqConfig, 4>>;
+
+/// The parameters of [`Fr`].
+#[derive(MontConfig)]
+#[modulus = "115792089237316195423570985008687907852837564279074904382605163141518161494337"]
+#[generator = "7"]
+pub struct FrConfig;
+/// The scalar field of secp256k1.
+pub type Fr = Fp256<MontBackend<FrConfig, 4>>;
+
+/// The parameters of secp256k1.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Config;
+
+/// A secp256k1 point in affine coordinates.
+pub type Affine = ark_ec::short_weierstrass::Affine<Config>;
+
+impl CurveConfig for Config {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[0x1];
+    const COFACTOR_INV: Fr = Fr::ONE;
+}
+
+impl SWCurveConfig for Config {
+    const COEFF_A: Fq = Fq::ZERO;
+    const COEFF_B: Fq = MontFp!("7");
+    const GENERATOR: Affine = Affine::new_unchecked(
+        MontFp!("55066263022277343669578718895168534326250603453777594175500187360389116729240"),
+        MontFp!("32670510020758816978083085130507043184471273380659243275938904335757337482424"),
+    );
+
+    #[inline(always)]
+    fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+impl EmulationConfig<ark_bn254::Fr> for Fq {
+    const T: usize = 300;
+    const B: usize = 100;
+    const NUM_LIMBS: usize = 3;
+}
+
+impl EmulationConfig<ark_bn254::Fr> for Fr {
+    const T: usize = 300;
+    const B: usize = 100;
+    const NUM_LIMBS: usize = 3;
+}
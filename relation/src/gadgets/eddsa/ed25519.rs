@@ -0,0 +1,92 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! The edwards25519 curve `-x^2 + y^2 = 1 + d * x^2 * y^2` of Ed25519
+//! signatures, birationally equivalent to Curve25519.
+
+// the `MontConfig` derive macro implements traits inside a function, and
+// checks the `asm` feature of ark-ff for moduli with a spare bit
+#![allow(non_local_definitions, unexpected_cfgs)]
+
+use crate::gadgets::EmulationConfig;
+use ark_ec::{
+    models::CurveConfig,
+    twisted_edwards::{MontCurveConfig, TECurveConfig},
+};
+use ark_ff::{
+    fields::{Fp256, MontBackend, MontConfig},
+    MontFp,
+};
+
+/// The parameters of [`Fq`].
+#[derive(MontConfig)]
+#[modulus = "57896044618658097711785492504343953926634992332820282019728792003956564819949"]
+#[generator = "2"]
+pub struct FqConfig;
+/// The base field of edwards25519, of modulus `2^255 - 19`.
+pub type Fq = Fp256<MontBackend<FqConfig, 4>>;
+
+/// The parameters of [`Fr`].
+#[derive(MontConfig)]
+#[modulus = "7237005577332262213973186563042994240857116359379907606001950938285454250989"]
+#[generator = "2"]
+pub struct FrConfig;
+/// The scalar field of edwards25519, of the prime order `L` of its base point.
+pub type Fr = Fp256<MontBackend<FrConfig, 4>>;
+
+/// The parameters of edwards25519.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Config;
+
+/// An edwards25519 point in affine coordinates.
+pub type Affine = ark_ec::twisted_edwards::Affine<Config>;
+
+impl CurveConfig for Config {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[0x8];
+    const COFACTOR_INV: Fr =
+        MontFp!("2713877091499598330239944961141122840321418634767465352250731601857045344121");
+}
+
+impl TECurveConfig for Config {
+    const COEFF_A: Fq = MontFp!("-1");
+    // -121665 / 121666
+    const COEFF_D: Fq =
+        MontFp!("37095705934669439343138083508754565189542113879843219016388785533085940283555");
+    const GENERATOR: Affine = Affine::new_unchecked(
+        MontFp!("15112221349535400772501151409588531511454012693041857206046113283949847762202"),
+        MontFp!("46316835694926478169428394003475163141307993866256225615783033603165251855960"),
+    );
+
+    type MontCurveConfig = Config;
+
+    fn mul_by_a(elem: Fq) -> Fq {
+        -elem
+    }
+}
+
+impl MontCurveConfig for Config {
+    const COEFF_A: Fq = MontFp!("486662");
+    // -486664, so that the map to edwards25519 has no scaling
+    const COEFF_B: Fq =
+        MontFp!("57896044618658097711785492504343953926634992332820282019728792003956564333285");
+
+    type TECurveConfig = Config;
+}
+
+impl EmulationConfig<ark_bn254::Fr> for Fq {
+    const T: usize = 300;
+    const B: usize = 100;
+    const NUM_LIMBS: usize = 3;
+}
+
+impl EmulationConfig<ark_bn254::Fr> for Fr {
+    const T: usize = 300;
+    const B: usize = 100;
+    const NUM_LIMBS: usize = 3;
+}
@@ -0,0 +1,335 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Ed25519 signature verification as in RFC 8032, with the base and scalar
+//! fields of edwards25519 emulated, e.g. over the BN254 scalar field. The
+//! challenge is hashed with SHA-512 in the circuit.
+
+pub mod ed25519;
+
+use super::ecdsa::WINDOW_SIZE;
+use crate::{
+    gadgets::{
+        ecc::{emulated::EmulatedTEPointVariable, TEPoint},
+        EmulatedVariable, EmulationConfig,
+    },
+    BoolVar, Circuit, CircuitError, PlonkCircuit, Variable,
+};
+use ark_ec::{
+    twisted_edwards::{Projective, TECurveConfig},
+    CurveGroup,
+};
+use ark_ff::{Field, PrimeField};
+use ark_std::{marker::PhantomData, string::ToString, vec, vec::Vec};
+use ed25519::{Fq, Fr};
+
+/// The variable of an Ed25519 signature `(R, S)`.
+#[derive(Debug, Clone)]
+pub struct Ed25519SignatureVar {
+    /// The nonce point.
+    pub r: EmulatedTEPointVariable<Fq>,
+    /// The proof of knowledge of the signing key.
+    pub s: EmulatedVariable<Fr>,
+}
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Add an Ed25519 signature variable, constraining `r` to be on the curve
+    /// and `s` to be reduced, as required by RFC 8032.
+    pub fn create_ed25519_signature_variable(
+        &mut self,
+        r: ed25519::Affine,
+        s: Fr,
+    ) -> Result<Ed25519SignatureVar, CircuitError>
+    where
+        Fq: EmulationConfig<F>,
+        Fr: EmulationConfig<F>,
+    {
+        let r = self.create_emulated_te_point_variable(TEPoint::from(r))?;
+        self.enforce_ed25519_point_on_curve(&r)?;
+        let s = self.create_emulated_variable(s)?;
+        self.enforce_emulated_var_reduced(&s)?;
+        Ok(Ed25519SignatureVar { r, s })
+    }
+
+    /// Constrain `sig` to be a valid Ed25519 signature of the message `msg`,
+    /// given as byte variables, under the public key `pk`: with `k` the
+    /// SHA-512 digest of the encodings of `R` and `pk` followed by `msg`,
+    /// read as a little-endian integer, `S * B = R + k * pk` for the base
+    /// point `B`. This is the cofactorless check of most implementations.
+    ///
+    /// `pk` is constrained to be on the curve. The signature should be
+    /// created with [`Self::create_ed25519_signature_variable()`].
+    /// Return error if the input variables are invalid.
+    pub fn enforce_ed25519_signature(
+        &mut self,
+        pk: &EmulatedTEPointVariable<Fq>,
+        msg: &[Variable],
+        sig: &Ed25519SignatureVar,
+    ) -> Result<(), CircuitError>
+    where
+        Fq: EmulationConfig<F>,
+        Fr: EmulationConfig<F>,
+    {
+        self.check_vars_bound(msg)?;
+        self.enforce_ed25519_point_on_curve(pk)?;
+
+        // k = SHA-512(R || pk || msg) mod L
+        let mut bits = self.ed25519_point_encoding(&sig.r)?;
+        bits.extend(self.ed25519_point_encoding(pk)?);
+        for &byte in msg.iter() {
+            bits.extend(self.unpack(byte, 8)?.into_iter().rev());
+        }
+        let digest = self.sha512_bits(bits)?;
+        let digest_le: Vec<_> = digest
+            .chunks(8)
+            .flat_map(|byte| byte.iter().rev().copied())
+            .collect();
+        let k = self.ed25519_bits_to_scalar(&digest_le)?;
+
+        // S * B - k * pk = R
+        let neg_x = self.emulated_mul_constant(&pk.0, -Fq::ONE)?;
+        let neg_pk = EmulatedTEPointVariable(neg_x, pk.1.clone());
+        let point = self.emulated_te_double_scalar_mul::<ed25519::Config>(&sig.s, &k, &neg_pk)?;
+        self.enforce_emulated_te_point_equal(&point, &sig.r)
+    }
+
+    /// Constrain `p` to be on edwards25519.
+    fn enforce_ed25519_point_on_curve(
+        &mut self,
+        p: &EmulatedTEPointVariable<Fq>,
+    ) -> Result<(), CircuitError>
+    where
+        Fq: EmulationConfig<F>,
+    {
+        // y^2 - x^2 = 1 + d * x^2 * y^2
+        let x_sq = self.emulated_mul(&p.0, &p.0)?;
+        let y_sq = self.emulated_mul(&p.1, &p.1)?;
+        let lhs = self.emulated_sub(&y_sq, &x_sq)?;
+        let rhs = self.emulated_mul(&x_sq, &y_sq)?;
+        let rhs = self.emulated_mul_constant(&rhs, ed25519::Config::COEFF_D)?;
+        let rhs = self.emulated_add_constant(&rhs, Fq::ONE)?;
+        self.enforce_emulated_var_equal(&lhs, &rhs)
+    }
+
+    /// Return the bits of the 32-byte encoding of `p`, the little-endian
+    /// `y`-coordinate with the parity of the `x`-coordinate as its most
+    /// significant bit, with the bits of each byte big-endian as in SHA-512.
+    /// The coordinates are constrained to be reduced.
+    fn ed25519_point_encoding(
+        &mut self,
+        p: &EmulatedTEPointVariable<Fq>,
+    ) -> Result<Vec<BoolVar>, CircuitError>
+    where
+        Fq: EmulationConfig<F>,
+    {
+        self.enforce_emulated_var_reduced(&p.0)?;
+        self.enforce_emulated_var_reduced(&p.1)?;
+        let x_parity = self.unpack(p.0 .0[0], Fq::B)?[0];
+        // 255 bits of `y` padded to 256
+        let mut bits = self.emulated_var_to_windows(&p.1)?.concat();
+        bits[255] = x_parity;
+        Ok(bits
+            .chunks(8)
+            .flat_map(|byte| byte.iter().rev().copied())
+            .collect())
+    }
+
+    /// Return the integer with little-endian bits `bits` reduced into the
+    /// scalar field, summing its chunks of `Fr::B` bits times powers of two.
+    fn ed25519_bits_to_scalar(
+        &mut self,
+        bits: &[BoolVar],
+    ) -> Result<EmulatedVariable<Fr>, CircuitError>
+    where
+        Fr: EmulationConfig<F>,
+    {
+        let mut acc: Option<EmulatedVariable<Fr>> = None;
+        for (i, chunk) in bits.chunks(Fr::B).enumerate() {
+            let terms: Vec<_> = chunk
+                .iter()
+                .enumerate()
+                .map(|(j, bit)| (F::from(2u8).pow([j as u64]), bit.0))
+                .collect();
+            let mut limbs = vec![self.lin_comb_with_constant(&terms, F::zero())?];
+            limbs.resize(Fr::NUM_LIMBS, self.zero());
+            let chunk = EmulatedVariable(limbs, PhantomData);
+            let pow = Fr::from(2u8).pow([(i * Fr::B) as u64]);
+            let term = self.emulated_mul_constant(&chunk, pow)?;
+            acc = Some(match acc {
+                Some(acc) => self.emulated_add(&acc, &term)?,
+                None => term,
+            });
+        }
+        acc.ok_or_else(|| CircuitError::InternalError("empty digest".to_string()))
+    }
+
+    /// Return the point `u1 * G + u2 * p` on the twisted Edwards curve `P`
+    /// with generator `G` and `a = -1`, computed with Shamir's trick and a
+    /// fixed window of [`WINDOW_SIZE`] bits.
+    fn emulated_te_double_scalar_mul<P>(
+        &mut self,
+        u1: &EmulatedVariable<P::ScalarField>,
+        u2: &EmulatedVariable<P::ScalarField>,
+        p: &EmulatedTEPointVariable<P::BaseField>,
+    ) -> Result<EmulatedTEPointVariable<P::BaseField>, CircuitError>
+    where
+        P: TECurveConfig,
+        P::BaseField: EmulationConfig<F>,
+        P::ScalarField: EmulationConfig<F>,
+    {
+        let d = P::COEFF_D;
+        let u1_bits = self.emulated_var_to_windows(u1)?;
+        let u2_bits = self.emulated_var_to_windows(u2)?;
+
+        // tables of [0, 1, ..., 2^WINDOW_SIZE - 1] times G and p
+        let mut g_table = vec![];
+        let mut multiple = Projective::<P>::default();
+        for _ in 0..1 << WINDOW_SIZE {
+            let point = TEPoint::from(multiple.into_affine());
+            g_table.push(self.create_constant_emulated_te_point_variable(point)?);
+            multiple += P::GENERATOR;
+        }
+        let mut p_table =
+            vec![self.create_constant_emulated_te_point_variable(TEPoint::default())?];
+        for i in 1..1 << WINDOW_SIZE {
+            let point = self.emulated_te_ecc_add(&p_table[i - 1], p, d)?;
+            p_table.push(point);
+        }
+
+        let mut acc: Option<EmulatedTEPointVariable<P::BaseField>> = None;
+        for (u1_window, u2_window) in u1_bits.iter().zip(u2_bits.iter()).rev() {
+            let mut point = match acc {
+                Some(mut point) => {
+                    for _ in 0..WINDOW_SIZE {
+                        point = self.emulated_te_ecc_add(&point, &point, d)?;
+                    }
+                    let g_multiple = self.emulated_te_point_table_select(u1_window, &g_table)?;
+                    self.emulated_te_ecc_add(&point, &g_multiple, d)?
+                },
+                None => self.emulated_te_point_table_select(u1_window, &g_table)?,
+            };
+            let p_multiple = self.emulated_te_point_table_select(u2_window, &p_table)?;
+            point = self.emulated_te_ecc_add(&point, &p_multiple, d)?;
+            acc = Some(point);
+        }
+        acc.ok_or_else(|| CircuitError::InternalError("empty scalar".to_string()))
+    }
+
+    /// Return `table[i]` for the index `i` of little-endian bits `bits`.
+    fn emulated_te_point_table_select<E: EmulationConfig<F>>(
+        &mut self,
+        bits: &[BoolVar],
+        table: &[EmulatedTEPointVariable<E>],
+    ) -> Result<EmulatedTEPointVariable<E>, CircuitError> {
+        let mut table = table.to_vec();
+        for &bit in bits.iter() {
+            table = table
+                .chunks(2)
+                .map(|pair| self.binary_emulated_te_point_vars_select(bit, &pair[0], &pair[1]))
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+        Ok(table.remove(0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fr as Fr254;
+    use ark_ec::AffineRepr;
+    use ark_ff::BigInteger;
+    use ark_std::UniformRand;
+    use sha2::{Digest, Sha512};
+
+    // The 32-byte encoding of `p`.
+    fn encode(p: &ed25519::Affine) -> Vec<u8> {
+        let mut bytes = p.y.into_bigint().to_bytes_le();
+        bytes[31] |= (p.x.into_bigint().is_odd() as u8) << 7;
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> ed25519::Affine {
+        let mut bytes = bytes.to_vec();
+        let x_is_odd = bytes[31] >> 7 == 1;
+        bytes[31] &= 0x7f;
+        let y = Fq::from_le_bytes_mod_order(&bytes);
+        let p = ed25519::Affine::get_point_from_y_unchecked(y, false).unwrap();
+        if p.x.into_bigint().is_odd() == x_is_odd {
+            p
+        } else {
+            -p
+        }
+    }
+
+    fn challenge(r: &ed25519::Affine, pk: &ed25519::Affine, msg: &[u8]) -> Fr {
+        let digest = Sha512::new()
+            .chain_update(encode(r))
+            .chain_update(encode(pk))
+            .chain_update(msg)
+            .finalize();
+        Fr::from_le_bytes_mod_order(&digest)
+    }
+
+    fn check_signature(
+        pk: ed25519::Affine,
+        msg: &[u8],
+        sig: (ed25519::Affine, Fr),
+    ) -> Result<PlonkCircuit<Fr254>, CircuitError> {
+        let mut circuit = PlonkCircuit::<Fr254>::new_ultra_plonk(16);
+        let pk = circuit.create_emulated_te_point_variable(pk.into())?;
+        let msg = msg
+            .iter()
+            .map(|&byte| circuit.create_variable(Fr254::from(byte)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let sig = circuit.create_ed25519_signature_variable(sig.0, sig.1)?;
+        circuit.enforce_ed25519_signature(&pk, &msg, &sig)?;
+        Ok(circuit)
+    }
+
+    #[test]
+    fn test_ed25519() -> Result<(), CircuitError> {
+        let rng = &mut jf_utils::test_rng();
+        let g = ed25519::Config::GENERATOR;
+        assert!(g.is_on_curve());
+        assert!(g.mul_bigint(Fr::MODULUS).into_affine().is_zero());
+
+        // RFC 8032, test 2
+        let pk = decode(&[
+            0x3d, 0x40, 0x17, 0xc3, 0xe8, 0x43, 0x89, 0x5a, 0x92, 0xb7, 0x0a, 0xa7, 0x4d, 0x1b,
+            0x7e, 0xbc, 0x9c, 0x98, 0x2c, 0xcf, 0x2e, 0xc4, 0x96, 0x8c, 0xc0, 0xcd, 0x55, 0xf1,
+            0x2a, 0xf4, 0x66, 0x0c,
+        ]);
+        let sig = [
+            0x92, 0xa0, 0x09, 0xa9, 0xf0, 0xd4, 0xca, 0xb8, 0x72, 0x0e, 0x82, 0x0b, 0x5f, 0x64,
+            0x25, 0x40, 0xa2, 0xb2, 0x7b, 0x54, 0x16, 0x50, 0x3f, 0x8f, 0xb3, 0x76, 0x22, 0x23,
+            0xeb, 0xdb, 0x69, 0xda, 0x08, 0x5a, 0xc1, 0xe4, 0x3e, 0x15, 0x99, 0x6e, 0x45, 0x8f,
+            0x36, 0x13, 0xd0, 0xf1, 0x1d, 0x8c, 0x38, 0x7b, 0x2e, 0xae, 0xb4, 0x30, 0x2a, 0xee,
+            0xb0, 0x0d, 0x29, 0x16, 0x12, 0xbb, 0x0c, 0x00,
+        ];
+        let sig = (decode(&sig[..32]), Fr::from_le_bytes_mod_order(&sig[32..]));
+        let circuit = check_signature(pk, &[0x72], sig)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        let circuit = check_signature(pk, &[0x73], sig)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // a random key, and a message of two SHA-512 blocks
+        let sk = Fr::rand(rng);
+        let pk = (g * sk).into_affine();
+        let msg = [0x5a; 100];
+        let nonce = Fr::rand(rng);
+        let r = (g * nonce).into_affine();
+        let s = nonce + challenge(&r, &pk, &msg) * sk;
+        let circuit = check_signature(pk, &msg, (r, s))?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        // wrong signature and wrong key
+        let circuit = check_signature(pk, &msg, (r, s + Fr::ONE))?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        let circuit = check_signature((g * (sk + Fr::ONE)).into_affine(), &msg, (r, s))?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        Ok(())
+    }
+}
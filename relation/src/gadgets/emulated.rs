@@ -35,7 +35,7 @@ pub trait SerializableEmulatedStruct<F: PrimeField> {
     fn serialize_to_native_elements(&self) -> Vec<F>;
 }
 
-fn biguint_to_limbs<F: PrimeField>(val: &BigUint, b: usize, num_limbs: usize) -> Vec<F> {
+pub(crate) fn biguint_to_limbs<F: PrimeField>(val: &BigUint, b: usize, num_limbs: usize) -> Vec<F> {
     let mut result = vec![];
     let b_pow = BigUint::one() << b;
     let mut val = val.clone();
@@ -586,6 +586,70 @@ impl<F: PrimeField> PlonkCircuit<F> {
         self.emulated_add_constant_gate(&c, b, a)?;
         Ok(c)
     }
+
+    /// Return an [`EmulatedVariable`] which equals to 1/a. The circuit is
+    /// unsatisfiable if `a` is zero, but building it still succeeds, e.g. with
+    /// dummy witnesses.
+    pub fn emulated_inverse<E: EmulationConfig<F>>(
+        &mut self,
+        a: &EmulatedVariable<E>,
+    ) -> Result<EmulatedVariable<E>, CircuitError> {
+        let inverse = self.emulated_witness(a)?.inverse().unwrap_or_default();
+        let inverse = self.create_emulated_variable(inverse)?;
+        let one = self.create_constant_emulated_variable(E::one())?;
+        self.emulated_mul_gate(a, &inverse, &one)?;
+        Ok(inverse)
+    }
+
+    /// Return an [`EmulatedVariable`] which equals to a/b. The circuit is
+    /// unsatisfiable if `b` is zero, even if `a` is zero too, but building it
+    /// still succeeds, e.g. with dummy witnesses.
+    pub fn emulated_div<E: EmulationConfig<F>>(
+        &mut self,
+        a: &EmulatedVariable<E>,
+        b: &EmulatedVariable<E>,
+    ) -> Result<EmulatedVariable<E>, CircuitError> {
+        let b_inv = self.emulated_inverse(b)?;
+        self.emulated_mul(a, &b_inv)
+    }
+
+    /// Return the [`EmulatedVariable`]s of the inverses of `vars`. The
+    /// witnesses are computed with a single field inversion, while each
+    /// inverse costs a multiplication gate in the circuit, which is cheaper
+    /// than Montgomery's trick in-circuit. The circuit is unsatisfiable if any
+    /// of `vars` is zero, but building it still succeeds.
+    pub fn emulated_batch_inverse<E: EmulationConfig<F>>(
+        &mut self,
+        vars: &[EmulatedVariable<E>],
+    ) -> Result<Vec<EmulatedVariable<E>>, CircuitError> {
+        let mut inverses = vars
+            .iter()
+            .map(|var| self.emulated_witness(var))
+            .collect::<Result<Vec<_>, _>>()?;
+        // zeros are left untouched
+        ark_ff::batch_inversion(&mut inverses);
+        let one = self.create_constant_emulated_variable(E::one())?;
+        vars.iter()
+            .zip(inverses)
+            .map(|(var, inverse)| {
+                let inverse = self.create_emulated_variable(inverse)?;
+                self.emulated_mul_gate(var, &inverse, &one)?;
+                Ok(inverse)
+            })
+            .collect()
+    }
+
+    /// Constrain an emulated variable to be non-zero modulo `E::MODULUS`, by
+    /// exhibiting its inverse.
+    /// Return error if the input variable is invalid.
+    pub fn emulated_assert_nonzero<E: EmulationConfig<F>>(
+        &mut self,
+        a: &EmulatedVariable<E>,
+    ) -> Result<(), CircuitError> {
+        self.check_vars_bound(&a.0)?;
+        self.emulated_inverse(a).map(|_| ())
+    }
+
     /// Obtain an emulated variable of the conditional selection from 2 emulated
     /// variables. `b` is a boolean variable that indicates selection of P_b
     /// from (P0, P1).
@@ -655,6 +719,45 @@ impl<F: PrimeField> PlonkCircuit<F> {
         self.logic_and_all(&c)
     }
 
+    /// Constrain an emulated variable to be reduced, i.e. smaller than
+    /// `E::MODULUS`, by checking that `E::MODULUS - 1 - a` computed limb by
+    /// limb does not underflow.
+    /// Return error if the input variable is invalid.
+    pub fn enforce_emulated_var_reduced<E: EmulationConfig<F>>(
+        &mut self,
+        a: &EmulatedVariable<E>,
+    ) -> Result<(), CircuitError> {
+        self.check_vars_bound(&a.0)?;
+        let modulus: BigUint = E::MODULUS.into();
+        let bound_limbs = biguint_to_limbs::<F>(&(modulus - 1u32), E::B, E::NUM_LIMBS);
+        let b_pow = F::from(2u32).pow([E::B as u64]);
+
+        let mut borrow_in = self.zero();
+        for (i, (&limb, &bound_limb)) in a.0.iter().zip(bound_limbs.iter()).enumerate() {
+            let val_limb: BigUint = self.witness(limb)?.into();
+            let val_borrow_in: BigUint = self.witness(borrow_in)?.into();
+            // the most significant limb cannot borrow
+            let borrow_out = if i == E::NUM_LIMBS - 1 {
+                self.zero()
+            } else {
+                self.create_boolean_variable(
+                    <F as Into<BigUint>>::into(bound_limb) < val_limb + val_borrow_in,
+                )?
+                .into()
+            };
+            // diff = bound_limb - limb - borrow_in + borrow_out * 2^B
+            let diff = self.gen_quad_poly(
+                &[limb, borrow_in, borrow_out, self.zero()],
+                &[-F::one(), -F::one(), b_pow, F::zero()],
+                &[F::zero(), F::zero()],
+                bound_limb,
+            )?;
+            self.enforce_in_range(diff, E::B)?;
+            borrow_in = borrow_out;
+        }
+        Ok(())
+    }
+
     /// Given an emulated field element `a`, return `a mod F::MODULUS` in the
     /// native field.
     fn mod_to_native_field<E: EmulationConfig<F>>(
@@ -698,6 +801,12 @@ impl EmulationConfig<ark_bn254::Fr> for ark_bls12_377::Fq {
     const NUM_LIMBS: usize = 5;
 }
 
+impl EmulationConfig<ark_bn254::Fr> for ark_bls12_381::Fq {
+    const T: usize = 520;
+    const B: usize = 104;
+    const NUM_LIMBS: usize = 5;
+}
+
 impl EmulationConfig<ark_bn254::Fr> for ark_bn254::Fq {
     const T: usize = 300;
     const B: usize = 100;
@@ -819,6 +928,54 @@ mod tests {
             .is_err());
     }
 
+    #[test]
+    fn test_emulated_div() {
+        test_emulated_div_helper::<Fq377, Fr254>();
+        test_emulated_div_helper::<Fq254, Fr254>();
+    }
+
+    fn test_emulated_div_helper<E, F>()
+    where
+        E: EmulationConfig<F>,
+        F: PrimeField,
+    {
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let x = E::from(6732u64);
+        let y = E::from(E::MODULUS.into() - 12387u64);
+        let var_x = circuit.create_emulated_variable(x).unwrap();
+        let var_y = circuit.create_emulated_variable(y).unwrap();
+        let var_z = circuit.emulated_div(&var_x, &var_y).unwrap();
+        assert_eq!(circuit.emulated_witness(&var_z).unwrap(), x / y);
+        circuit.emulated_assert_nonzero(&var_x).unwrap();
+        let inverses = circuit
+            .emulated_batch_inverse(&[var_x.clone(), var_y.clone(), var_z])
+            .unwrap();
+        for (inverse, expected) in inverses.iter().zip([x, y, x / y]) {
+            assert_eq!(
+                circuit.emulated_witness(inverse).unwrap(),
+                expected.inverse().unwrap()
+            );
+        }
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        *circuit.witness_mut(inverses[1].0[0]) += F::one();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // zero is rejected, even divided by itself
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let var_zero = circuit.create_emulated_variable(E::zero()).unwrap();
+        circuit.emulated_div(&var_zero, &var_zero).unwrap();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let var_zero = circuit.create_emulated_variable(E::zero()).unwrap();
+        circuit.emulated_assert_nonzero(&var_zero).unwrap();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let var_x = circuit.create_emulated_variable(x).unwrap();
+        let var_zero = circuit.create_emulated_variable(E::zero()).unwrap();
+        circuit.emulated_batch_inverse(&[var_x, var_zero]).unwrap();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+    }
+
     #[test]
     fn test_select() {
         test_select_helper::<Fq377, Fr254>();
@@ -11,7 +11,7 @@ use crate::{
     BoolVar, Circuit, CircuitError, PlonkCircuit, Variable,
 };
 use ark_ff::PrimeField;
-use ark_std::{boxed::Box, string::ToString};
+use ark_std::{boxed::Box, format, string::ToString, vec::Vec};
 
 impl<F: PrimeField> PlonkCircuit<F> {
     /// Constrain that `a` is true or `b` is true.
@@ -162,6 +162,48 @@ impl<F: PrimeField> PlonkCircuit<F> {
         self.insert_gate(&wire_vars, Box::new(CondSelectGate))?;
         Ok(y)
     }
+
+    /// Obtain the vector `vectors[i]` for the index `i` whose little-endian
+    /// bits are `selector_bits`, with a tree of `2^k - 1` conditional
+    /// selections of vectors for `k` selector bits.
+    /// Return error if the variables are invalid, or if `vectors` doesn't
+    /// consist of `2^k` vectors of the same length.
+    pub fn mux(
+        &mut self,
+        selector_bits: &[BoolVar],
+        vectors: &[Vec<Variable>],
+    ) -> Result<Vec<Variable>, CircuitError> {
+        if selector_bits.len() >= usize::BITS as usize
+            || vectors.len() != 1 << selector_bits.len()
+            || vectors.iter().any(|v| v.len() != vectors[0].len())
+        {
+            return Err(CircuitError::ParameterError(format!(
+                "cannot select with {} bits among {} vectors of the same length",
+                selector_bits.len(),
+                vectors.len()
+            )));
+        }
+        for &bit in selector_bits.iter() {
+            self.check_var_bound(bit.into())?;
+        }
+        for vector in vectors.iter() {
+            self.check_vars_bound(vector)?;
+        }
+        let mut vectors = vectors.to_vec();
+        for &bit in selector_bits.iter() {
+            vectors = vectors
+                .chunks(2)
+                .map(|pair| {
+                    pair[0]
+                        .iter()
+                        .zip(pair[1].iter())
+                        .map(|(&x_0, &x_1)| self.conditional_select(bit, x_0, x_1))
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .collect::<Result<Vec<_>, CircuitError>>()?;
+        }
+        Ok(vectors.remove(0))
+    }
 }
 
 #[cfg(test)]
@@ -175,6 +217,7 @@ mod test {
     use ark_ed_on_bls12_381::Fq as FqEd381;
     use ark_ed_on_bn254::Fq as FqEd254;
     use ark_ff::PrimeField;
+    use ark_std::{vec, vec::Vec};
 
     #[test]
     fn test_logic_or() -> Result<(), CircuitError> {
@@ -425,6 +468,51 @@ mod test {
         Ok(circuit)
     }
 
+    #[test]
+    fn test_mux() -> Result<(), CircuitError> {
+        test_mux_helper::<FqEd254>()?;
+        test_mux_helper::<FqEd377>()?;
+        test_mux_helper::<FqEd381>()?;
+        test_mux_helper::<Fq377>()
+    }
+
+    fn test_mux_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        let vectors = (0..8u32)
+            .map(|i| {
+                (0..3u32)
+                    .map(|j| circuit.create_variable(F::from(10 * i + j)))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut bits = vec![];
+        for i in 0..8u32 {
+            bits = (0..3)
+                .map(|j| circuit.create_boolean_variable(i >> j & 1 == 1))
+                .collect::<Result<Vec<_>, _>>()?;
+            let selected = circuit.mux(&bits, &vectors)?;
+            for (j, &var) in selected.iter().enumerate() {
+                assert_eq!(circuit.witness(var)?, F::from(10 * i + j as u32));
+            }
+        }
+        assert_eq!(circuit.mux(&[], &vectors[..1])?, vectors[0]);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        // if mess up the selector, should fail
+        *circuit.witness_mut(bits[0].into()) = F::zero();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // Error paths
+        assert!(circuit.mux(&bits, &vectors[..4]).is_err());
+        assert!(circuit.mux(&bits[..2], &vectors[..4]).is_ok());
+        let mut bad_vectors = vectors[..2].to_vec();
+        bad_vectors[1].pop();
+        assert!(circuit.mux(&bits[..1], &bad_vectors).is_err());
+        bad_vectors[1].push(circuit.num_vars());
+        assert!(circuit.mux(&bits[..1], &bad_vectors).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_non_zero_gate() -> Result<(), CircuitError> {
         test_non_zero_gate_helper::<FqEd254>()?;
@@ -6,24 +6,44 @@
 
 //! Gates and gadgets implementations
 
+pub mod bls;
 pub mod ecc;
+pub mod ecdsa;
+pub mod eddsa;
+pub mod pairing;
 pub mod ultraplonk;
 
 mod arithmetic;
+mod biguint;
+mod bytes;
 mod cmp;
+mod conditional;
 mod emulated;
 mod logic;
 mod range;
+mod rsa;
+mod sha256;
+mod sha512;
+mod sort;
+mod word;
 #[allow(unused_imports)]
 pub use arithmetic::*;
 #[allow(unused_imports)]
+pub use biguint::*;
+#[allow(unused_imports)]
+pub use bytes::*;
+#[allow(unused_imports)]
 pub use cmp::*;
 #[allow(unused_imports)]
+pub use conditional::*;
+#[allow(unused_imports)]
 pub use emulated::*;
 #[allow(unused_imports)]
 pub use logic::*;
 #[allow(unused_imports)]
 pub use range::*;
+#[allow(unused_imports)]
+pub use word::*;
 
 // Helper functions
 mod utils;
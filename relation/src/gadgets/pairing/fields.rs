@@ -0,0 +1,637 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! The tower `Fp2 = Fp[u] / (u^2 - β)`, `Fp6 = Fp2[v] / (v^3 - ξ)` and
+//! `Fp12 = Fp6[w] / (w^2 - v)` of a BLS12 curve over an emulated base field
+//! `Fp`, following the arithmetic of [`ark_ff`] so that witnesses match.
+
+use crate::{
+    gadgets::{EmulatedVariable, EmulationConfig},
+    CircuitError, PlonkCircuit,
+};
+use ark_ec::bls12::Bls12Config;
+use ark_ff::{
+    fields::{Fp12Config, Fp2Config, Fp6Config},
+    Field, Fp12, Fp2, Fp6, PrimeField,
+};
+use ark_std::vec;
+
+/// An element `c0 + c1 * u` of the quadratic extension of an emulated field.
+#[derive(Debug, Clone)]
+pub struct EmulatedFp2Variable<E: PrimeField>(pub EmulatedVariable<E>, pub EmulatedVariable<E>);
+
+/// An element `c0 + c1 * v + c2 * v^2` of the cubic extension of an
+/// [`EmulatedFp2Variable`].
+#[derive(Debug, Clone)]
+pub struct EmulatedFp6Variable<E: PrimeField>(
+    pub EmulatedFp2Variable<E>,
+    pub EmulatedFp2Variable<E>,
+    pub EmulatedFp2Variable<E>,
+);
+
+/// An element `c0 + c1 * w` of the quadratic extension of an
+/// [`EmulatedFp6Variable`].
+#[derive(Debug, Clone)]
+pub struct EmulatedFp12Variable<E: PrimeField>(
+    pub EmulatedFp6Variable<E>,
+    pub EmulatedFp6Variable<E>,
+);
+
+type Fp2Of<P> = Fp2<<P as Bls12Config>::Fp2Config>;
+type Fp6Of<P> = Fp6<<P as Bls12Config>::Fp6Config>;
+type Fp12Of<P> = Fp12<<P as Bls12Config>::Fp12Config>;
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    pub(crate) fn create_emulated_fp2_variable<P: Bls12Config>(
+        &mut self,
+        val: Fp2Of<P>,
+    ) -> Result<EmulatedFp2Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        Ok(EmulatedFp2Variable(
+            self.create_emulated_variable(val.c0)?,
+            self.create_emulated_variable(val.c1)?,
+        ))
+    }
+
+    pub(crate) fn create_constant_emulated_fp2_variable<P: Bls12Config>(
+        &mut self,
+        val: Fp2Of<P>,
+    ) -> Result<EmulatedFp2Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        Ok(EmulatedFp2Variable(
+            self.create_constant_emulated_variable(val.c0)?,
+            self.create_constant_emulated_variable(val.c1)?,
+        ))
+    }
+
+    pub(crate) fn emulated_fp2_witness<P: Bls12Config>(
+        &self,
+        a: &EmulatedFp2Variable<P::Fp>,
+    ) -> Result<Fp2Of<P>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        Ok(Fp2Of::<P>::new(
+            self.emulated_witness(&a.0)?,
+            self.emulated_witness(&a.1)?,
+        ))
+    }
+
+    pub(crate) fn enforce_emulated_fp2_equal<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp2Variable<P::Fp>,
+        b: &EmulatedFp2Variable<P::Fp>,
+    ) -> Result<(), CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        self.enforce_emulated_var_equal(&a.0, &b.0)?;
+        self.enforce_emulated_var_equal(&a.1, &b.1)
+    }
+
+    pub(crate) fn emulated_fp2_add<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp2Variable<P::Fp>,
+        b: &EmulatedFp2Variable<P::Fp>,
+    ) -> Result<EmulatedFp2Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        Ok(EmulatedFp2Variable(
+            self.emulated_add(&a.0, &b.0)?,
+            self.emulated_add(&a.1, &b.1)?,
+        ))
+    }
+
+    pub(crate) fn emulated_fp2_sub<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp2Variable<P::Fp>,
+        b: &EmulatedFp2Variable<P::Fp>,
+    ) -> Result<EmulatedFp2Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        Ok(EmulatedFp2Variable(
+            self.emulated_sub(&a.0, &b.0)?,
+            self.emulated_sub(&a.1, &b.1)?,
+        ))
+    }
+
+    pub(crate) fn emulated_fp2_neg<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp2Variable<P::Fp>,
+    ) -> Result<EmulatedFp2Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        Ok(EmulatedFp2Variable(
+            self.emulated_mul_constant(&a.0, -P::Fp::from(1u8))?,
+            self.emulated_mul_constant(&a.1, -P::Fp::from(1u8))?,
+        ))
+    }
+
+    // Multiply `a` by the element `b` of the base field.
+    pub(crate) fn emulated_fp2_mul_by_fp<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp2Variable<P::Fp>,
+        b: &EmulatedVariable<P::Fp>,
+    ) -> Result<EmulatedFp2Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        Ok(EmulatedFp2Variable(
+            self.emulated_mul(&a.0, b)?,
+            self.emulated_mul(&a.1, b)?,
+        ))
+    }
+
+    pub(crate) fn emulated_fp2_mul_constant<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp2Variable<P::Fp>,
+        b: Fp2Of<P>,
+    ) -> Result<EmulatedFp2Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        if b.c1 == P::Fp::from(0u8) {
+            return Ok(EmulatedFp2Variable(
+                self.emulated_mul_constant(&a.0, b.c0)?,
+                self.emulated_mul_constant(&a.1, b.c0)?,
+            ));
+        }
+        // (a0 + a1 * u) * (b0 + b1 * u) = a0 * b0 + β * a1 * b1 + (a0 * b1 + a1 * b0) * u
+        let nonresidue = <P::Fp2Config as Fp2Config>::NONRESIDUE;
+        let t0 = self.emulated_mul_constant(&a.0, b.c0)?;
+        let t1 = self.emulated_mul_constant(&a.1, nonresidue * b.c1)?;
+        let c0 = self.emulated_add(&t0, &t1)?;
+        let t0 = self.emulated_mul_constant(&a.0, b.c1)?;
+        let t1 = self.emulated_mul_constant(&a.1, b.c0)?;
+        let c1 = self.emulated_add(&t0, &t1)?;
+        Ok(EmulatedFp2Variable(c0, c1))
+    }
+
+    pub(crate) fn emulated_fp2_mul<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp2Variable<P::Fp>,
+        b: &EmulatedFp2Variable<P::Fp>,
+    ) -> Result<EmulatedFp2Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let nonresidue = <P::Fp2Config as Fp2Config>::NONRESIDUE;
+        let t00 = self.emulated_mul(&a.0, &b.0)?;
+        let t11 = self.emulated_mul(&a.1, &b.1)?;
+        let c0 = if nonresidue == -P::Fp::from(1u8) {
+            self.emulated_sub(&t00, &t11)?
+        } else {
+            let t11 = self.emulated_mul_constant(&t11, nonresidue)?;
+            self.emulated_add(&t00, &t11)?
+        };
+        let t01 = self.emulated_mul(&a.0, &b.1)?;
+        let t10 = self.emulated_mul(&a.1, &b.0)?;
+        let c1 = self.emulated_add(&t01, &t10)?;
+        Ok(EmulatedFp2Variable(c0, c1))
+    }
+
+    // Multiply by the non-residue `ξ` defining `Fp6`.
+    fn emulated_fp2_mul_by_nonresidue<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp2Variable<P::Fp>,
+    ) -> Result<EmulatedFp2Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        self.emulated_fp2_mul_constant::<P>(a, <P::Fp6Config as Fp6Config>::NONRESIDUE)
+    }
+
+    fn emulated_fp2_frobenius<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp2Variable<P::Fp>,
+        power: usize,
+    ) -> Result<EmulatedFp2Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let coeff = <P::Fp2Config as Fp2Config>::FROBENIUS_COEFF_FP2_C1[power % 2];
+        if coeff == P::Fp::from(1u8) {
+            return Ok(a.clone());
+        }
+        Ok(EmulatedFp2Variable(
+            a.0.clone(),
+            self.emulated_mul_constant(&a.1, coeff)?,
+        ))
+    }
+
+    pub(crate) fn create_emulated_fp12_variable<P: Bls12Config>(
+        &mut self,
+        val: Fp12Of<P>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let mut coeffs = vec![];
+        for c in [val.c0, val.c1] {
+            coeffs.push(EmulatedFp6Variable(
+                self.create_emulated_fp2_variable::<P>(c.c0)?,
+                self.create_emulated_fp2_variable::<P>(c.c1)?,
+                self.create_emulated_fp2_variable::<P>(c.c2)?,
+            ));
+        }
+        let c1 = coeffs.pop().unwrap();
+        Ok(EmulatedFp12Variable(coeffs.pop().unwrap(), c1))
+    }
+
+    pub(crate) fn create_constant_emulated_fp12_variable<P: Bls12Config>(
+        &mut self,
+        val: Fp12Of<P>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let mut coeffs = vec![];
+        for c in [val.c0, val.c1] {
+            coeffs.push(EmulatedFp6Variable(
+                self.create_constant_emulated_fp2_variable::<P>(c.c0)?,
+                self.create_constant_emulated_fp2_variable::<P>(c.c1)?,
+                self.create_constant_emulated_fp2_variable::<P>(c.c2)?,
+            ));
+        }
+        let c1 = coeffs.pop().unwrap();
+        Ok(EmulatedFp12Variable(coeffs.pop().unwrap(), c1))
+    }
+
+    /// Return the witness of an element of the degree 12 extension of the
+    /// emulated base field of a BLS12 curve.
+    pub fn emulated_fp12_witness<P: Bls12Config>(
+        &self,
+        a: &EmulatedFp12Variable<P::Fp>,
+    ) -> Result<Fp12Of<P>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let fp6_witness = |a: &EmulatedFp6Variable<P::Fp>| -> Result<Fp6Of<P>, CircuitError> {
+            Ok(Fp6Of::<P>::new(
+                self.emulated_fp2_witness::<P>(&a.0)?,
+                self.emulated_fp2_witness::<P>(&a.1)?,
+                self.emulated_fp2_witness::<P>(&a.2)?,
+            ))
+        };
+        Ok(Fp12Of::<P>::new(fp6_witness(&a.0)?, fp6_witness(&a.1)?))
+    }
+
+    pub(crate) fn enforce_emulated_fp12_equal<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp12Variable<P::Fp>,
+        b: &EmulatedFp12Variable<P::Fp>,
+    ) -> Result<(), CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        for (a, b) in [(&a.0, &b.0), (&a.1, &b.1)] {
+            self.enforce_emulated_fp2_equal::<P>(&a.0, &b.0)?;
+            self.enforce_emulated_fp2_equal::<P>(&a.1, &b.1)?;
+            self.enforce_emulated_fp2_equal::<P>(&a.2, &b.2)?;
+        }
+        Ok(())
+    }
+
+    fn emulated_fp6_add<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp6Variable<P::Fp>,
+        b: &EmulatedFp6Variable<P::Fp>,
+    ) -> Result<EmulatedFp6Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        Ok(EmulatedFp6Variable(
+            self.emulated_fp2_add::<P>(&a.0, &b.0)?,
+            self.emulated_fp2_add::<P>(&a.1, &b.1)?,
+            self.emulated_fp2_add::<P>(&a.2, &b.2)?,
+        ))
+    }
+
+    fn emulated_fp6_sub<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp6Variable<P::Fp>,
+        b: &EmulatedFp6Variable<P::Fp>,
+    ) -> Result<EmulatedFp6Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        Ok(EmulatedFp6Variable(
+            self.emulated_fp2_sub::<P>(&a.0, &b.0)?,
+            self.emulated_fp2_sub::<P>(&a.1, &b.1)?,
+            self.emulated_fp2_sub::<P>(&a.2, &b.2)?,
+        ))
+    }
+
+    // Multiply `a` by `b0 + b1 * v + b2 * v^2`, skipping the terms of the
+    // coefficients `b1` and `b2` which are `None`, i.e. zero.
+    fn emulated_fp6_mul_sparse<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp6Variable<P::Fp>,
+        b0: &EmulatedFp2Variable<P::Fp>,
+        b1: Option<&EmulatedFp2Variable<P::Fp>>,
+        b2: Option<&EmulatedFp2Variable<P::Fp>>,
+    ) -> Result<EmulatedFp6Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        // the coefficients of v^0, ..., v^4 of the product
+        let mut coeffs: [Option<EmulatedFp2Variable<P::Fp>>; 5] = Default::default();
+        for (i, a_i) in [&a.0, &a.1, &a.2].into_iter().enumerate() {
+            for (j, b_j) in [Some(b0), b1, b2].into_iter().enumerate() {
+                if let Some(b_j) = b_j {
+                    let term = self.emulated_fp2_mul::<P>(a_i, b_j)?;
+                    coeffs[i + j] = Some(match &coeffs[i + j] {
+                        Some(sum) => self.emulated_fp2_add::<P>(sum, &term)?,
+                        None => term,
+                    });
+                }
+            }
+        }
+        // v^3 = ξ, and the coefficients of v^0, v^1, v^2 are non-zero as `b0` is
+        let [c0, c1, c2, c3, c4] = coeffs;
+        let (c0, c1, c2) = (c0.unwrap(), c1.unwrap(), c2.unwrap());
+        let c0 = match c3 {
+            Some(c3) => {
+                let c3 = self.emulated_fp2_mul_by_nonresidue::<P>(&c3)?;
+                self.emulated_fp2_add::<P>(&c0, &c3)?
+            },
+            None => c0,
+        };
+        let c1 = match c4 {
+            Some(c4) => {
+                let c4 = self.emulated_fp2_mul_by_nonresidue::<P>(&c4)?;
+                self.emulated_fp2_add::<P>(&c1, &c4)?
+            },
+            None => c1,
+        };
+        Ok(EmulatedFp6Variable(c0, c1, c2))
+    }
+
+    fn emulated_fp6_mul<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp6Variable<P::Fp>,
+        b: &EmulatedFp6Variable<P::Fp>,
+    ) -> Result<EmulatedFp6Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        self.emulated_fp6_mul_sparse::<P>(a, &b.0, Some(&b.1), Some(&b.2))
+    }
+
+    // Multiply by `v`, the non-residue defining `Fp12`.
+    fn emulated_fp6_mul_by_nonresidue<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp6Variable<P::Fp>,
+    ) -> Result<EmulatedFp6Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let c0 = self.emulated_fp2_mul_by_nonresidue::<P>(&a.2)?;
+        Ok(EmulatedFp6Variable(c0, a.0.clone(), a.1.clone()))
+    }
+
+    fn emulated_fp6_frobenius<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp6Variable<P::Fp>,
+        power: usize,
+    ) -> Result<EmulatedFp6Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let c0 = self.emulated_fp2_frobenius::<P>(&a.0, power)?;
+        let c1 = self.emulated_fp2_frobenius::<P>(&a.1, power)?;
+        let c1 = self.emulated_fp2_mul_constant::<P>(
+            &c1,
+            <P::Fp6Config as Fp6Config>::FROBENIUS_COEFF_FP6_C1[power % 6],
+        )?;
+        let c2 = self.emulated_fp2_frobenius::<P>(&a.2, power)?;
+        let c2 = self.emulated_fp2_mul_constant::<P>(
+            &c2,
+            <P::Fp6Config as Fp6Config>::FROBENIUS_COEFF_FP6_C2[power % 6],
+        )?;
+        Ok(EmulatedFp6Variable(c0, c1, c2))
+    }
+
+    pub(crate) fn emulated_fp12_add<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp12Variable<P::Fp>,
+        b: &EmulatedFp12Variable<P::Fp>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        Ok(EmulatedFp12Variable(
+            self.emulated_fp6_add::<P>(&a.0, &b.0)?,
+            self.emulated_fp6_add::<P>(&a.1, &b.1)?,
+        ))
+    }
+
+    pub(crate) fn emulated_fp12_mul<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp12Variable<P::Fp>,
+        b: &EmulatedFp12Variable<P::Fp>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        // Karatsuba: c0 = a0 * b0 + v * a1 * b1,
+        // c1 = (a0 + a1) * (b0 + b1) - a0 * b0 - a1 * b1
+        let v0 = self.emulated_fp6_mul::<P>(&a.0, &b.0)?;
+        let v1 = self.emulated_fp6_mul::<P>(&a.1, &b.1)?;
+        let a_sum = self.emulated_fp6_add::<P>(&a.0, &a.1)?;
+        let b_sum = self.emulated_fp6_add::<P>(&b.0, &b.1)?;
+        let c1 = self.emulated_fp6_mul::<P>(&a_sum, &b_sum)?;
+        let c1 = self.emulated_fp6_sub::<P>(&c1, &v0)?;
+        let c1 = self.emulated_fp6_sub::<P>(&c1, &v1)?;
+        let v1 = self.emulated_fp6_mul_by_nonresidue::<P>(&v1)?;
+        let c0 = self.emulated_fp6_add::<P>(&v0, &v1)?;
+        Ok(EmulatedFp12Variable(c0, c1))
+    }
+
+    // Multiply `a` by the element `b0 + b1 * v` of `Fp6`.
+    pub(crate) fn emulated_fp12_mul_by_fp6_01<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp12Variable<P::Fp>,
+        b0: &EmulatedFp2Variable<P::Fp>,
+        b1: &EmulatedFp2Variable<P::Fp>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        Ok(EmulatedFp12Variable(
+            self.emulated_fp6_mul_sparse::<P>(&a.0, b0, Some(b1), None)?,
+            self.emulated_fp6_mul_sparse::<P>(&a.1, b0, Some(b1), None)?,
+        ))
+    }
+
+    // Multiply by `w`.
+    pub(crate) fn emulated_fp12_mul_by_w<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp12Variable<P::Fp>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let c0 = self.emulated_fp6_mul_by_nonresidue::<P>(&a.1)?;
+        Ok(EmulatedFp12Variable(c0, a.0.clone()))
+    }
+
+    // Multiply by `v = w^2`.
+    pub(crate) fn emulated_fp12_mul_by_v<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp12Variable<P::Fp>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        Ok(EmulatedFp12Variable(
+            self.emulated_fp6_mul_by_nonresidue::<P>(&a.0)?,
+            self.emulated_fp6_mul_by_nonresidue::<P>(&a.1)?,
+        ))
+    }
+
+    pub(crate) fn emulated_fp12_square<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp12Variable<P::Fp>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        // c0 = (a0 + a1) * (a0 + v * a1) - a0 * a1 - v * a0 * a1,
+        // c1 = 2 * a0 * a1
+        let ab = self.emulated_fp6_mul::<P>(&a.0, &a.1)?;
+        let a_sum = self.emulated_fp6_add::<P>(&a.0, &a.1)?;
+        let v_a1 = self.emulated_fp6_mul_by_nonresidue::<P>(&a.1)?;
+        let t = self.emulated_fp6_add::<P>(&a.0, &v_a1)?;
+        let c0 = self.emulated_fp6_mul::<P>(&a_sum, &t)?;
+        let c0 = self.emulated_fp6_sub::<P>(&c0, &ab)?;
+        let v_ab = self.emulated_fp6_mul_by_nonresidue::<P>(&ab)?;
+        let c0 = self.emulated_fp6_sub::<P>(&c0, &v_ab)?;
+        let c1 = self.emulated_fp6_add::<P>(&ab, &ab)?;
+        Ok(EmulatedFp12Variable(c0, c1))
+    }
+
+    /// Square an element of the cyclotomic subgroup, following Granger and
+    /// Scott, "Faster Squaring in the Cyclotomic Subgroup of Sixth Degree
+    /// Extensions". The result is incorrect for other elements.
+    pub(crate) fn emulated_fp12_cyclotomic_square<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp12Variable<P::Fp>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let (r0, r4, r3) = (&a.0 .0, &a.0 .1, &a.0 .2);
+        let (r2, r1, r5) = (&a.1 .0, &a.1 .1, &a.1 .2);
+
+        // t0 + t1 * y = (x0 + x1 * y)^2 in Fp4 = Fp2[y] / (y^2 - ξ)
+        let mut fp4_square = |x0: &EmulatedFp2Variable<P::Fp>,
+                              x1: &EmulatedFp2Variable<P::Fp>|
+         -> Result<_, CircuitError> {
+            let tmp = self.emulated_fp2_mul::<P>(x0, x1)?;
+            let sum = self.emulated_fp2_add::<P>(x0, x1)?;
+            let nr_x1 = self.emulated_fp2_mul_by_nonresidue::<P>(x1)?;
+            let t = self.emulated_fp2_add::<P>(&nr_x1, x0)?;
+            let t0 = self.emulated_fp2_mul::<P>(&sum, &t)?;
+            let t0 = self.emulated_fp2_sub::<P>(&t0, &tmp)?;
+            let nr_tmp = self.emulated_fp2_mul_by_nonresidue::<P>(&tmp)?;
+            let t0 = self.emulated_fp2_sub::<P>(&t0, &nr_tmp)?;
+            let t1 = self.emulated_fp2_add::<P>(&tmp, &tmp)?;
+            Ok((t0, t1))
+        };
+        let (t0, t1) = fp4_square(r0, r1)?;
+        let (t2, t3) = fp4_square(r2, r3)?;
+        let (t4, t5) = fp4_square(r4, r5)?;
+
+        let nr_t5 = self.emulated_fp2_mul_by_nonresidue::<P>(&t5)?;
+
+        // 3 * t - 2 * z, or 3 * t + 2 * z
+        let mut combine = |t: &EmulatedFp2Variable<P::Fp>,
+                           z: &EmulatedFp2Variable<P::Fp>,
+                           sub: bool|
+         -> Result<_, CircuitError> {
+            let d = if sub {
+                self.emulated_fp2_sub::<P>(t, z)?
+            } else {
+                self.emulated_fp2_add::<P>(t, z)?
+            };
+            let d = self.emulated_fp2_add::<P>(&d, &d)?;
+            self.emulated_fp2_add::<P>(&d, t)
+        };
+        let z0 = combine(&t0, r0, true)?;
+        let z1 = combine(&t1, r1, false)?;
+        let z2 = combine(&nr_t5, r2, false)?;
+        let z3 = combine(&t4, r3, true)?;
+        let z4 = combine(&t2, r4, true)?;
+        let z5 = combine(&t3, r5, false)?;
+        Ok(EmulatedFp12Variable(
+            EmulatedFp6Variable(z0, z4, z3),
+            EmulatedFp6Variable(z2, z1, z5),
+        ))
+    }
+
+    /// Return `a^(p^6)`, which is the inverse of `a` in the cyclotomic
+    /// subgroup.
+    pub(crate) fn emulated_fp12_conjugate<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp12Variable<P::Fp>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let c1 = EmulatedFp6Variable(
+            self.emulated_fp2_neg::<P>(&a.1 .0)?,
+            self.emulated_fp2_neg::<P>(&a.1 .1)?,
+            self.emulated_fp2_neg::<P>(&a.1 .2)?,
+        );
+        Ok(EmulatedFp12Variable(a.0.clone(), c1))
+    }
+
+    pub(crate) fn emulated_fp12_frobenius<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp12Variable<P::Fp>,
+        power: usize,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let c0 = self.emulated_fp6_frobenius::<P>(&a.0, power)?;
+        let c1 = self.emulated_fp6_frobenius::<P>(&a.1, power)?;
+        let coeff = <P::Fp12Config as Fp12Config>::FROBENIUS_COEFF_FP12_C1[power % 12];
+        let c1 = EmulatedFp6Variable(
+            self.emulated_fp2_mul_constant::<P>(&c1.0, coeff)?,
+            self.emulated_fp2_mul_constant::<P>(&c1.1, coeff)?,
+            self.emulated_fp2_mul_constant::<P>(&c1.2, coeff)?,
+        );
+        Ok(EmulatedFp12Variable(c0, c1))
+    }
+
+    /// Return the inverse of `a`. The circuit is unsatisfiable if `a` is zero.
+    pub(crate) fn emulated_fp12_inverse<P: Bls12Config>(
+        &mut self,
+        a: &EmulatedFp12Variable<P::Fp>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let inverse = self
+            .emulated_fp12_witness::<P>(a)?
+            .inverse()
+            .unwrap_or_default();
+        let inverse = self.create_emulated_fp12_variable::<P>(inverse)?;
+        let product = self.emulated_fp12_mul::<P>(a, &inverse)?;
+        let one = self.create_constant_emulated_fp12_variable::<P>(Fp12Of::<P>::from(1u8))?;
+        self.enforce_emulated_fp12_equal::<P>(&product, &one)?;
+        Ok(inverse)
+    }
+}
@@ -0,0 +1,439 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Pairing gadgets for BLS12 curves over an emulated base field, e.g. to verify
+//! BLS signatures or KZG openings over BLS12-381 in circuits over BN254.
+//!
+//! The Miller loop runs in affine coordinates with the slopes given as
+//! witnesses. Each line is divided by `y_P`, a factor cancelled by the final
+//! exponentiation, so that it has a coefficient one and multiplies cheaply.
+//! The final exponentiation follows [`ark_ec`] with cyclotomic squarings.
+
+use crate::{
+    gadgets::{ecc::emulated::EmulatedSWPointVariable, EmulatedVariable, EmulationConfig},
+    CircuitError, PlonkCircuit,
+};
+use ark_ec::{
+    bls12::{Bls12Config, G2Affine, TwistType},
+    AffineRepr,
+};
+use ark_ff::{BitIteratorBE, Field, Fp12, Fp2, One, PrimeField};
+use ark_std::{string::ToString, vec::Vec};
+
+mod fields;
+pub use fields::*;
+
+/// An affine point of the G2 group of a BLS12 curve, other than the point at
+/// infinity, over the quadratic extension of the emulated base field.
+#[derive(Debug, Clone)]
+pub struct EmulatedBls12G2PointVariable<E: PrimeField>(
+    pub EmulatedFp2Variable<E>,
+    pub EmulatedFp2Variable<E>,
+);
+
+/// A pair of a G1 point and a G2 point of a BLS12 curve, the arguments of a
+/// pairing.
+pub type EmulatedBls12PairingInput<E> =
+    (EmulatedSWPointVariable<E>, EmulatedBls12G2PointVariable<E>);
+
+/// The coefficients `(a, b)` of a line divided by `y_P`, see
+/// `emulated_bls12_line_step`.
+type Line<E> = (EmulatedFp2Variable<E>, EmulatedFp2Variable<E>);
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Create a variable for a G2 point of a BLS12 curve.
+    /// Return error if the point is the point at infinity.
+    pub fn create_emulated_bls12_g2_point_variable<P: Bls12Config>(
+        &mut self,
+        point: G2Affine<P>,
+    ) -> Result<EmulatedBls12G2PointVariable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let (x, y) = point.xy().ok_or_else(|| {
+            CircuitError::ParameterError("the G2 point is the point at infinity".to_string())
+        })?;
+        Ok(EmulatedBls12G2PointVariable(
+            self.create_emulated_fp2_variable::<P>(*x)?,
+            self.create_emulated_fp2_variable::<P>(*y)?,
+        ))
+    }
+
+    /// Create a constant variable for a G2 point of a BLS12 curve.
+    /// Return error if the point is the point at infinity.
+    pub fn create_constant_emulated_bls12_g2_point_variable<P: Bls12Config>(
+        &mut self,
+        point: G2Affine<P>,
+    ) -> Result<EmulatedBls12G2PointVariable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let (x, y) = point.xy().ok_or_else(|| {
+            CircuitError::ParameterError("the G2 point is the point at infinity".to_string())
+        })?;
+        Ok(EmulatedBls12G2PointVariable(
+            self.create_constant_emulated_fp2_variable::<P>(*x)?,
+            self.create_constant_emulated_fp2_variable::<P>(*y)?,
+        ))
+    }
+
+    /// Return the product of the pairings `e(p_i, q_i)` of the pairs `(p_i,
+    /// q_i)` of `pairs`, as the witness [`ark_ec`] computes.
+    /// The G1 points are constrained not to be the point at infinity. The
+    /// points are assumed to be in the prime order subgroups, e.g. checked
+    /// elsewhere or constant, otherwise the circuit may be unsatisfiable.
+    /// Return error if `pairs` is empty or the input variables are invalid.
+    pub fn emulated_bls12_multi_pairing<P: Bls12Config>(
+        &mut self,
+        pairs: &[EmulatedBls12PairingInput<P::Fp>],
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let f = self.emulated_bls12_miller_loop::<P>(pairs)?;
+        self.emulated_bls12_final_exponentiation::<P>(&f)
+    }
+
+    /// Constrain the product of the pairings `e(p_i, q_i)` of the pairs `(p_i,
+    /// q_i)` of `pairs` to be one, as for the check `e(σ, g2) = e(H(m), pk)` of
+    /// a BLS signature. See [`Self::emulated_bls12_multi_pairing`] for the
+    /// conditions on the points.
+    /// Return error if `pairs` is empty or the input variables are invalid.
+    pub fn enforce_emulated_bls12_pairing_check<P: Bls12Config>(
+        &mut self,
+        pairs: &[EmulatedBls12PairingInput<P::Fp>],
+    ) -> Result<(), CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let product = self.emulated_bls12_multi_pairing::<P>(pairs)?;
+        let one = self.create_constant_emulated_fp12_variable::<P>(Fp12::one())?;
+        self.enforce_emulated_fp12_equal::<P>(&product, &one)
+    }
+
+    // The Miller loop of the optimal ate pairing, sharing the squarings
+    // between the pairs.
+    fn emulated_bls12_miller_loop<P: Bls12Config>(
+        &mut self,
+        pairs: &[EmulatedBls12PairingInput<P::Fp>],
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        if pairs.is_empty() {
+            return Err(CircuitError::ParameterError(
+                "no pairs to compute the pairings of".to_string(),
+            ));
+        }
+        // (-x_P / y_P, 1 / y_P) for every pair
+        let mut scaled_points = Vec::with_capacity(pairs.len());
+        for (p, q) in pairs.iter() {
+            self.check_vars_bound(&p.0 .0)?;
+            self.check_vars_bound(&p.1 .0)?;
+            self.check_var_bound(p.2.into())?;
+            for coord in [&q.0, &q.1] {
+                self.check_vars_bound(&coord.0 .0)?;
+                self.check_vars_bound(&coord.1 .0)?;
+            }
+            self.enforce_false(p.2.into())?;
+            let y_inv = self.emulated_inverse(&p.1)?;
+            let x_scaled = self.emulated_mul(&p.0, &y_inv)?;
+            let x_scaled = self.emulated_mul_constant(&x_scaled, -P::Fp::one())?;
+            scaled_points.push((x_scaled, y_inv));
+        }
+
+        let mut f = self.create_constant_emulated_fp12_variable::<P>(Fp12::one())?;
+        let mut ts: Vec<_> = pairs.iter().map(|(_, q)| q.clone()).collect();
+        for bit in BitIteratorBE::without_leading_zeros(P::X).skip(1) {
+            f = self.emulated_fp12_square::<P>(&f)?;
+            for (t, p) in ts.iter_mut().zip(scaled_points.iter()) {
+                let (doubled, line) = self.emulated_bls12_line_step::<P>(t, None, p)?;
+                *t = doubled;
+                f = self.emulated_bls12_mul_by_line::<P>(&f, &line)?;
+            }
+            if bit {
+                for ((t, (_, q)), p) in ts.iter_mut().zip(pairs.iter()).zip(scaled_points.iter()) {
+                    let (sum, line) = self.emulated_bls12_line_step::<P>(t, Some(q), p)?;
+                    *t = sum;
+                    f = self.emulated_bls12_mul_by_line::<P>(&f, &line)?;
+                }
+            }
+        }
+        if P::X_IS_NEGATIVE {
+            f = self.emulated_fp12_conjugate::<P>(&f)?;
+        }
+        Ok(f)
+    }
+
+    // Return `t + q`, or `2 * t` if `q` is `None`, and the coefficients `(a,
+    // b)` of the line through them evaluated at `p` and divided by `y_P`,
+    // where `p = (-x_P / y_P, 1 / y_P)`. The line is `a + b * v + v * w` for
+    // an M-type twist, `1 + b * w + a * v * w` for a D-type twist.
+    #[allow(clippy::type_complexity)]
+    fn emulated_bls12_line_step<P: Bls12Config>(
+        &mut self,
+        t: &EmulatedBls12G2PointVariable<P::Fp>,
+        q: Option<&EmulatedBls12G2PointVariable<P::Fp>>,
+        p: &(EmulatedVariable<P::Fp>, EmulatedVariable<P::Fp>),
+    ) -> Result<(EmulatedBls12G2PointVariable<P::Fp>, Line<P::Fp>), CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let x_t = self.emulated_fp2_witness::<P>(&t.0)?;
+        let y_t = self.emulated_fp2_witness::<P>(&t.1)?;
+        // the slope, constrained by `slope * den = num`
+        let (num, den) = match q {
+            None => {
+                let x_sq = self.emulated_fp2_mul::<P>(&t.0, &t.0)?;
+                let num = self.emulated_fp2_mul_constant::<P>(&x_sq, Fp2::from(3u8))?;
+                let den = self.emulated_fp2_add::<P>(&t.1, &t.1)?;
+                (num, den)
+            },
+            Some(q) => {
+                let num = self.emulated_fp2_sub::<P>(&q.1, &t.1)?;
+                let den = self.emulated_fp2_sub::<P>(&q.0, &t.0)?;
+                (num, den)
+            },
+        };
+        let slope = match q {
+            None => (x_t.square() * Fp2::from(3u8)) * y_t.double().inverse().unwrap_or_default(),
+            Some(q) => {
+                let x_q = self.emulated_fp2_witness::<P>(&q.0)?;
+                let y_q = self.emulated_fp2_witness::<P>(&q.1)?;
+                (y_q - y_t) * (x_q - x_t).inverse().unwrap_or_default()
+            },
+        };
+        let slope = self.create_emulated_fp2_variable::<P>(slope)?;
+        let product = self.emulated_fp2_mul::<P>(&slope, &den)?;
+        self.enforce_emulated_fp2_equal::<P>(&product, &num)?;
+
+        // x = slope^2 - x_t - x_q, y = slope * (x_t - x) - y_t
+        let slope_sq = self.emulated_fp2_mul::<P>(&slope, &slope)?;
+        let x = self.emulated_fp2_sub::<P>(&slope_sq, &t.0)?;
+        let x = self.emulated_fp2_sub::<P>(&x, q.map_or(&t.0, |q| &q.0))?;
+        let diff = self.emulated_fp2_sub::<P>(&t.0, &x)?;
+        let y = self.emulated_fp2_mul::<P>(&slope, &diff)?;
+        let y = self.emulated_fp2_sub::<P>(&y, &t.1)?;
+
+        // a = (slope * x_t - y_t) / y_P, b = -slope * x_P / y_P
+        let a = self.emulated_fp2_mul::<P>(&slope, &t.0)?;
+        let a = self.emulated_fp2_sub::<P>(&a, &t.1)?;
+        let a = self.emulated_fp2_mul_by_fp::<P>(&a, &p.1)?;
+        let b = self.emulated_fp2_mul_by_fp::<P>(&slope, &p.0)?;
+        Ok((EmulatedBls12G2PointVariable(x, y), (a, b)))
+    }
+
+    fn emulated_bls12_mul_by_line<P: Bls12Config>(
+        &mut self,
+        f: &EmulatedFp12Variable<P::Fp>,
+        line: &Line<P::Fp>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let (a, b) = line;
+        match P::TWIST_TYPE {
+            // f * (a + b * v) + f * v * w
+            TwistType::M => {
+                let t = self.emulated_fp12_mul_by_fp6_01::<P>(f, a, b)?;
+                let f_w = self.emulated_fp12_mul_by_w::<P>(f)?;
+                let f_vw = self.emulated_fp12_mul_by_v::<P>(&f_w)?;
+                self.emulated_fp12_add::<P>(&t, &f_vw)
+            },
+            // f + f * (b + a * v) * w
+            TwistType::D => {
+                let t = self.emulated_fp12_mul_by_fp6_01::<P>(f, b, a)?;
+                let t_w = self.emulated_fp12_mul_by_w::<P>(&t)?;
+                self.emulated_fp12_add::<P>(f, &t_w)
+            },
+        }
+    }
+
+    // Raise the Miller loop output to the power `(p^12 - 1) / r`, or rather
+    // the multiple of it used by [`ark_ec`].
+    fn emulated_bls12_final_exponentiation<P: Bls12Config>(
+        &mut self,
+        f: &EmulatedFp12Variable<P::Fp>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        // easy part: r = f^((p^6 - 1) * (p^2 + 1))
+        let f1 = self.emulated_fp12_conjugate::<P>(f)?;
+        let f2 = self.emulated_fp12_inverse::<P>(f)?;
+        let f2 = self.emulated_fp12_mul::<P>(&f1, &f2)?;
+        let r = self.emulated_fp12_frobenius::<P>(&f2, 2)?;
+        let mut r = self.emulated_fp12_mul::<P>(&r, &f2)?;
+
+        // hard part
+        let mut y0 = self.emulated_fp12_cyclotomic_square::<P>(&r)?;
+        let y1 = self.emulated_bls12_exp_by_x::<P>(&r)?;
+        let y2 = self.emulated_fp12_conjugate::<P>(&r)?;
+        let y1 = self.emulated_fp12_mul::<P>(&y1, &y2)?;
+        let y2 = self.emulated_bls12_exp_by_x::<P>(&y1)?;
+        let y1 = self.emulated_fp12_conjugate::<P>(&y1)?;
+        let y1 = self.emulated_fp12_mul::<P>(&y1, &y2)?;
+        let y2 = self.emulated_bls12_exp_by_x::<P>(&y1)?;
+        let y1 = self.emulated_fp12_frobenius::<P>(&y1, 1)?;
+        let y1 = self.emulated_fp12_mul::<P>(&y1, &y2)?;
+        r = self.emulated_fp12_mul::<P>(&r, &y0)?;
+        y0 = self.emulated_bls12_exp_by_x::<P>(&y1)?;
+        let y2 = self.emulated_bls12_exp_by_x::<P>(&y0)?;
+        let y0 = self.emulated_fp12_frobenius::<P>(&y1, 2)?;
+        let y1 = self.emulated_fp12_conjugate::<P>(&y1)?;
+        let y1 = self.emulated_fp12_mul::<P>(&y1, &y2)?;
+        let y1 = self.emulated_fp12_mul::<P>(&y1, &y0)?;
+        self.emulated_fp12_mul::<P>(&r, &y1)
+    }
+
+    // Raise an element of the cyclotomic subgroup to the power `x`.
+    fn emulated_bls12_exp_by_x<P: Bls12Config>(
+        &mut self,
+        f: &EmulatedFp12Variable<P::Fp>,
+    ) -> Result<EmulatedFp12Variable<P::Fp>, CircuitError>
+    where
+        P::Fp: EmulationConfig<F>,
+    {
+        let mut result = f.clone();
+        for bit in BitIteratorBE::without_leading_zeros(P::X).skip(1) {
+            result = self.emulated_fp12_cyclotomic_square::<P>(&result)?;
+            if bit {
+                result = self.emulated_fp12_mul::<P>(&result, f)?;
+            }
+        }
+        if P::X_IS_NEGATIVE {
+            result = self.emulated_fp12_conjugate::<P>(&result)?;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{gadgets::EmulationConfig, Circuit};
+    use ark_bn254::Fr as Fr254;
+    use ark_ec::{bls12::Bls12, pairing::Pairing, CurveGroup};
+    use ark_ff::{Field, Fp12, UniformRand};
+
+    #[test]
+    fn test_emulated_fp12_arithmetic() -> Result<(), CircuitError> {
+        test_emulated_fp12_arithmetic_helper::<ark_bls12_381::Config>()?;
+        test_emulated_fp12_arithmetic_helper::<ark_bls12_377::Config>()
+    }
+
+    fn test_emulated_fp12_arithmetic_helper<P: Bls12Config>() -> Result<(), CircuitError>
+    where
+        P::Fp: EmulationConfig<Fr254>,
+    {
+        let mut rng = jf_utils::test_rng();
+        let mut circuit = PlonkCircuit::<Fr254>::new_ultra_plonk(20);
+        let a = Fp12::<P::Fp12Config>::rand(&mut rng);
+        let b = Fp12::<P::Fp12Config>::rand(&mut rng);
+        let c0 = Fp2::<P::Fp2Config>::rand(&mut rng);
+        let c1 = Fp2::<P::Fp2Config>::rand(&mut rng);
+        let a_var = circuit.create_emulated_fp12_variable::<P>(a)?;
+        let b_var = circuit.create_emulated_fp12_variable::<P>(b)?;
+        let c0_var = circuit.create_emulated_fp2_variable::<P>(c0)?;
+        let c1_var = circuit.create_emulated_fp2_variable::<P>(c1)?;
+
+        let product = circuit.emulated_fp12_mul::<P>(&a_var, &b_var)?;
+        assert_eq!(circuit.emulated_fp12_witness::<P>(&product)?, a * b);
+        let square = circuit.emulated_fp12_square::<P>(&a_var)?;
+        assert_eq!(circuit.emulated_fp12_witness::<P>(&square)?, a.square());
+        let inverse = circuit.emulated_fp12_inverse::<P>(&a_var)?;
+        assert_eq!(
+            circuit.emulated_fp12_witness::<P>(&inverse)?,
+            a.inverse().unwrap()
+        );
+        let conjugate = circuit.emulated_fp12_conjugate::<P>(&a_var)?;
+        let mut expected = a;
+        expected.conjugate_in_place();
+        assert_eq!(circuit.emulated_fp12_witness::<P>(&conjugate)?, expected);
+        for power in 1..4 {
+            let frobenius = circuit.emulated_fp12_frobenius::<P>(&a_var, power)?;
+            assert_eq!(
+                circuit.emulated_fp12_witness::<P>(&frobenius)?,
+                a.frobenius_map(power)
+            );
+        }
+        let sparse = circuit.emulated_fp12_mul_by_fp6_01::<P>(&a_var, &c0_var, &c1_var)?;
+        let mut expected = a;
+        expected.c0.mul_by_01(&c0, &c1);
+        expected.c1.mul_by_01(&c0, &c1);
+        assert_eq!(circuit.emulated_fp12_witness::<P>(&sparse)?, expected);
+
+        // an element of the cyclotomic subgroup
+        let mut cyclotomic = a;
+        cyclotomic.conjugate_in_place();
+        cyclotomic *= a.inverse().unwrap();
+        cyclotomic = cyclotomic.frobenius_map(2) * cyclotomic;
+        let cyclotomic_var = circuit.create_emulated_fp12_variable::<P>(cyclotomic)?;
+        let square = circuit.emulated_fp12_cyclotomic_square::<P>(&cyclotomic_var)?;
+        assert_eq!(
+            circuit.emulated_fp12_witness::<P>(&square)?,
+            cyclotomic.square()
+        );
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        // wrong inverse
+        let witness = circuit.witness(inverse.0 .0 .0 .0[0])?;
+        *circuit.witness_mut(inverse.0 .0 .0 .0[0]) = witness + Fr254::from(1u8);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        Ok(())
+    }
+
+    // A full pairing costs millions of constraints, run with `--release`.
+    #[test]
+    #[ignore]
+    fn test_emulated_bls12_pairing() -> Result<(), CircuitError> {
+        test_emulated_bls12_pairing_helper::<ark_bls12_381::Config>()?;
+        test_emulated_bls12_pairing_helper::<ark_bls12_377::Config>()
+    }
+
+    fn test_emulated_bls12_pairing_helper<P: Bls12Config>() -> Result<(), CircuitError>
+    where
+        P::Fp: EmulationConfig<Fr254>,
+    {
+        let mut rng = jf_utils::test_rng();
+        let g1 = <Bls12<P> as Pairing>::G1::rand(&mut rng).into_affine();
+        let g2 = <Bls12<P> as Pairing>::G2::rand(&mut rng).into_affine();
+        let a = <Bls12<P> as Pairing>::ScalarField::rand(&mut rng);
+        let a_g1 = (g1 * a).into_affine();
+        let a_g2 = (g2 * a).into_affine();
+
+        let mut circuit = PlonkCircuit::<Fr254>::new_ultra_plonk(20);
+        let g1_var = circuit.create_emulated_sw_point_variable(g1.into())?;
+        let g2_var = circuit.create_emulated_bls12_g2_point_variable::<P>(g2)?;
+        let pairing = circuit.emulated_bls12_multi_pairing::<P>(&[(g1_var, g2_var)])?;
+        assert_eq!(
+            circuit.emulated_fp12_witness::<P>(&pairing)?,
+            Bls12::<P>::pairing(g1, g2).0
+        );
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        // empty input
+        assert!(circuit.emulated_bls12_multi_pairing::<P>(&[]).is_err());
+        drop(circuit);
+
+        // e(a * g1, g2) * e(-g1, a * g2) = 1
+        let mut circuit = PlonkCircuit::<Fr254>::new_ultra_plonk(20);
+        let a_g1_var = circuit.create_emulated_sw_point_variable(a_g1.into())?;
+        let neg_g1_var = circuit.create_emulated_sw_point_variable((-g1).into())?;
+        let g2_var = circuit.create_constant_emulated_bls12_g2_point_variable::<P>(g2)?;
+        let a_g2_var = circuit.create_emulated_bls12_g2_point_variable::<P>(a_g2)?;
+        circuit.enforce_emulated_bls12_pairing_check::<P>(&[
+            (a_g1_var.clone(), g2_var),
+            (neg_g1_var, a_g2_var),
+        ])?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        // wrong G1 point
+        let witness = circuit.witness(a_g1_var.0 .0[0])?;
+        *circuit.witness_mut(a_g1_var.0 .0[0]) = witness + Fr254::from(1u8);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! RSA signature verification with the PKCS#1 v1.5 encoding of SHA-256
+//! digests (RFC 8017), e.g. for the `rsa-sha256` signatures of DKIM.
+
+use super::{BigUintVar, BIGUINT_LIMB_BITS};
+use crate::{CircuitError, PlonkCircuit, Variable};
+use ark_ff::PrimeField;
+use ark_std::{format, vec, vec::Vec};
+use num_bigint::BigUint;
+
+/// The DER encoding of the `DigestInfo` of a SHA-256 digest, without the
+/// digest.
+const SHA256_DIGEST_INFO: [u8; 19] = [
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05,
+    0x00, 0x04, 0x20,
+];
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Constrain `sig` to be a valid RSA PKCS#1 v1.5 signature of the SHA-256
+    /// digest `digest`, given as 32 byte variables, under the public key
+    /// `(modulus, exp)`: `sig^exp mod modulus` is the encoding
+    /// `00 01 ff .. ff 00 || DigestInfo || digest`.
+    ///
+    /// The modulus should have exactly `64 * num_limbs` bits, e.g. 32 limbs for
+    /// RSA-2048. `sig` is constrained to be smaller than the modulus and the
+    /// digest bytes to 8 bits.
+    /// Return error if the modulus is too small for the encoding, `exp` is
+    /// zero or the input variables are invalid.
+    pub fn enforce_rsa_signature(
+        &mut self,
+        modulus: &BigUintVar,
+        exp: &BigUint,
+        digest: &[Variable],
+        sig: &BigUintVar,
+    ) -> Result<(), CircuitError> {
+        let num_bytes = modulus.0.len() * BIGUINT_LIMB_BITS / 8;
+        if digest.len() != 32 || num_bytes < SHA256_DIGEST_INFO.len() + 32 + 11 {
+            return Err(CircuitError::ParameterError(format!(
+                "a digest of {} bytes cannot be encoded for a modulus of {} bytes",
+                digest.len(),
+                num_bytes
+            )));
+        }
+        self.check_vars_bound(digest)?;
+        for &byte in digest.iter() {
+            self.enforce_in_range(byte, 8)?;
+        }
+        self.enforce_biguint_lt(sig, modulus)?;
+        let encoded = self.biguint_pow_mod(sig, exp, modulus)?;
+
+        // 00 01 ff .. ff 00 || DigestInfo, followed by 32 zero bytes
+        let mut prefix = vec![0x00, 0x01];
+        prefix.resize(num_bytes - SHA256_DIGEST_INFO.len() - 33, 0xff);
+        prefix.push(0x00);
+        prefix.extend_from_slice(&SHA256_DIGEST_INFO);
+        prefix.resize(num_bytes, 0x00);
+        let prefix = self
+            .create_constant_biguint_variable(&BigUint::from_bytes_be(&prefix), modulus.0.len())?;
+
+        // the digest fills the 256 / BIGUINT_LIMB_BITS least significant limbs
+        let mut expected = vec![];
+        for limb_bytes in digest.rchunks(BIGUINT_LIMB_BITS / 8) {
+            let terms: Vec<_> = limb_bytes
+                .iter()
+                .rev()
+                .enumerate()
+                .map(|(i, &byte)| (F::from(2u8).pow([8 * i as u64]), byte))
+                .collect();
+            expected.push(self.lin_comb_with_constant(&terms, F::zero())?);
+        }
+        expected.extend_from_slice(&prefix.0[expected.len()..]);
+        self.enforce_biguint_equal(&encoded, &BigUintVar(expected))
+    }
+
+    /// Constrain `sig` to be a valid RSA PKCS#1 v1.5 signature of the message
+    /// `msg`, given as byte variables, hashed with SHA-256 in the circuit.
+    /// See [`Self::enforce_rsa_signature()`] for the conditions on the inputs.
+    /// Return error if the input variables are invalid.
+    pub fn enforce_rsa_sha256_signature(
+        &mut self,
+        modulus: &BigUintVar,
+        exp: &BigUint,
+        msg: &[Variable],
+        sig: &BigUintVar,
+    ) -> Result<(), CircuitError> {
+        let digest = self.sha256(msg)?;
+        self.enforce_rsa_signature(modulus, exp, &digest, sig)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Circuit;
+    use ark_bn254::Fr as Fr254;
+
+    // an RSA-2048 key, and the signature of "jellyfish" with
+    // `openssl dgst -sha256 -sign`
+    const MODULUS: &str = concat!(
+        "a92cc31411f6279b5694134b1fbab48dd531502cae27a8e0c28b615968bab4aa",
+        "feb6f4c5f8cb270061a3a3db8838ed3aa449b49183b665717bc9dbe010ec9495",
+        "3c52d6664327b9b74c8c4aa508840bcf2c2250d632891967068721fce59086ad",
+        "ece10595e324963db8613995b24723777aa58210896a8f22106ccb3354a9dfde",
+        "d3d80b67a9d8616edf5ef7a85c1ef79ad82ed4d315278ac499b9478c6289d059",
+        "6dfb8ffa28e4e7b5a55cd888c0228201b6e57d30c0c40503b451510bfe7d73dd",
+        "4f33256b0a97eabf90d788bec36e707524e474e87ebe7b9b5adb96734be47501",
+        "c374a23f8bd5416cb23cb4173128de3c628babc0d06c60aa28c6c63b975d4acf",
+    );
+    const SIG: &str = concat!(
+        "2217e61725c33820500a0064cbb8de8db0d0dac3456cfb35adebcbd53bff5839",
+        "32d500369c6e3041ff6f1b48f57a509b1cb61d186344a33293476982ed249c72",
+        "91c5aab35a26e877770916d1a93c78b9e50ef96826204857bf5067cb55dbe63c",
+        "61943cd0537621150bee9bfa415d944f37b2af755d92fa7abb346c9e88cd73a3",
+        "1f13663aaf99007d396e451e1302a12b9a2ea7872163e241197a0e826a9704c3",
+        "998c68b72fa268c906046db951a442b50d69712d2c930f5c211eacc12bb1cb38",
+        "67a6faa5fdcd9be2886fd2321a15cfd0f17bca523d89d3c0af83a0ee438d5f34",
+        "16f2a9dde03fbc93cbce9d9f7bb46c9068cc76115961947d8c2155d571518a48",
+    );
+
+    fn check_signature(msg: &[u8], sig: &BigUint) -> Result<PlonkCircuit<Fr254>, CircuitError> {
+        let mut circuit = PlonkCircuit::<Fr254>::new_ultra_plonk(16);
+        let modulus = BigUint::parse_bytes(MODULUS.as_bytes(), 16).unwrap();
+        let modulus = circuit.create_public_biguint_variable(&modulus, 32)?;
+        let msg = msg
+            .iter()
+            .map(|&byte| circuit.create_variable(Fr254::from(byte)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let sig = circuit.create_biguint_variable(sig, 32)?;
+        circuit.enforce_rsa_sha256_signature(&modulus, &BigUint::from(65537u32), &msg, &sig)?;
+        Ok(circuit)
+    }
+
+    #[test]
+    fn test_rsa() -> Result<(), CircuitError> {
+        let sig = BigUint::parse_bytes(SIG.as_bytes(), 16).unwrap();
+        let circuit = check_signature(b"jellyfish", &sig)?;
+        let modulus = BigUint::parse_bytes(MODULUS.as_bytes(), 16).unwrap();
+        let public_input: Vec<_> = modulus
+            .to_u64_digits()
+            .into_iter()
+            .map(Fr254::from)
+            .collect();
+        assert!(circuit.check_circuit_satisfiability(&public_input).is_ok());
+
+        // wrong message and wrong signature
+        let circuit = check_signature(b"jellyfisH", &sig)?;
+        assert!(circuit.check_circuit_satisfiability(&public_input).is_err());
+        let circuit = check_signature(b"jellyfish", &(sig + 1u32))?;
+        assert!(circuit.check_circuit_satisfiability(&public_input).is_err());
+
+        // a too small modulus
+        let mut circuit = PlonkCircuit::<Fr254>::new_ultra_plonk(16);
+        let modulus = circuit.create_biguint_variable(&BigUint::from(5u32), 4)?;
+        let sig = circuit.create_biguint_variable(&BigUint::from(2u32), 4)?;
+        let digest = vec![circuit.zero(); 32];
+        assert!(circuit
+            .enforce_rsa_signature(&modulus, &BigUint::from(3u32), &digest, &sig)
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,491 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! SHA-256 hash gadget over messages of a length fixed at circuit building
+//! time. 32-bit words are represented by their bits, so that rotations and
+//! shifts are free and boolean functions cost one or two gates per bit;
+//! modular additions pack the bits of their operands and unpack the sum.
+//!
+//! On circuits supporting lookup, the three-way XORs of the σ and Σ functions
+//! are looked up two output bits at a time in a table of 64 rows, created
+//! once per gadget call.
+//!
+//! The word operations are generic over the word length and shared with the
+//! SHA-512 gadget.
+
+use crate::{BoolVar, Circuit, CircuitError, PlonkCircuit, Variable};
+use ark_ff::PrimeField;
+use ark_std::{format, vec, vec::Vec};
+
+/// A word of 32 or 64 bits, as its big-endian bits.
+pub(super) type Word = Vec<BoolVar>;
+
+/// The lookups `(key, a0 ^ b0 ^ c0, a1 ^ b1 ^ c1)` of a gadget call, with
+/// `key = a0 + 2 * b0 + 4 * c0 + 8 * a1 + 16 * b1 + 32 * c1`, or `None` if the
+/// circuit does not support lookup.
+pub(super) type Sha2Lookups = Option<Vec<(Variable, Variable, Variable)>>;
+
+/// The rotation and shift amounts of the σ and Σ functions of SHA-2: σ0 and
+/// σ1 rotate by two amounts and shift by the third, Σ0 and Σ1 rotate by all
+/// three.
+pub(super) struct Sha2Rotations {
+    pub(super) sigma0: [usize; 3],
+    pub(super) sigma1: [usize; 3],
+    pub(super) big_sigma0: [usize; 3],
+    pub(super) big_sigma1: [usize; 3],
+}
+
+const SHA256_ROTATIONS: Sha2Rotations = Sha2Rotations {
+    sigma0: [7, 18, 3],
+    sigma1: [17, 19, 10],
+    big_sigma0: [2, 13, 22],
+    big_sigma1: [6, 11, 25],
+};
+
+const H0: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const K: [u64; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Return the 32 bytes of the SHA-256 digest of the message `msg`, given
+    /// as byte variables. Each byte of `msg` is constrained to 8 bits.
+    /// Return error if the input variables are invalid.
+    pub fn sha256(&mut self, msg: &[Variable]) -> Result<Vec<Variable>, CircuitError> {
+        let bits = self.sha256_bits(msg)?;
+        bits.chunks(8)
+            .map(|byte| {
+                let terms: Vec<_> = byte
+                    .iter()
+                    .enumerate()
+                    .map(|(i, bit)| (F::from(1u32 << (7 - i)), bit.0))
+                    .collect();
+                self.lin_comb_with_constant(&terms, F::zero())
+            })
+            .collect()
+    }
+
+    /// Return the 256 big-endian bits of the SHA-256 digest of the message
+    /// `msg`, given as byte variables.
+    pub(crate) fn sha256_bits(&mut self, msg: &[Variable]) -> Result<Vec<BoolVar>, CircuitError> {
+        self.check_vars_bound(msg)?;
+        let zero = BoolVar(self.zero());
+        let one = BoolVar(self.one());
+
+        // message bits, then 1, zeros and the 64-bit length
+        let mut bits = vec![];
+        for &byte in msg.iter() {
+            bits.extend(self.unpack(byte, 8)?.into_iter().rev());
+        }
+        let bit_len = (msg.len() as u64) * 8;
+        bits.push(one);
+        while bits.len() % 512 != 448 {
+            bits.push(zero);
+        }
+        bits.extend(
+            (0..64)
+                .rev()
+                .map(|i| if bit_len >> i & 1 == 1 { one } else { zero }),
+        );
+
+        let mut lookups = self.sha2_lookups();
+        let mut state: Vec<Word> = H0
+            .iter()
+            .map(|&h| self.sha2_constant_word(u64::from(h), 32))
+            .collect();
+        for block in bits.chunks(512) {
+            state = self.sha256_compress(&mut lookups, &state, block)?;
+        }
+        self.sha2_add_table(lookups)?;
+        Ok(state.concat())
+    }
+
+    /// Return the 8 words of the SHA-256 compression function applied to the
+    /// chaining value `state`, given as 8 word variables, and the message
+    /// block `block`, given as 16 word variables. Words are big-endian as in
+    /// the SHA-256 specification, and each input word is constrained to 32
+    /// bits. Iterating this gadget from the initial hash value over padded
+    /// blocks yields the digest, e.g. for midstate-based statements.
+    /// Return error if the input variables are invalid.
+    pub fn sha256_compression(
+        &mut self,
+        state: &[Variable],
+        block: &[Variable],
+    ) -> Result<Vec<Variable>, CircuitError> {
+        if state.len() != 8 || block.len() != 16 {
+            return Err(CircuitError::ParameterError(format!(
+                "SHA-256 compression takes 8 state words and 16 block words, got {} and {}",
+                state.len(),
+                block.len()
+            )));
+        }
+        self.check_vars_bound(state)?;
+        self.check_vars_bound(block)?;
+        let unpack_word = |circuit: &mut Self, w: Variable| -> Result<Word, CircuitError> {
+            Ok(circuit.unpack(w, 32)?.into_iter().rev().collect())
+        };
+        let state_words = state
+            .iter()
+            .map(|&w| unpack_word(self, w))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut block_bits = vec![];
+        for &w in block.iter() {
+            block_bits.extend(unpack_word(self, w)?);
+        }
+
+        let mut lookups = self.sha2_lookups();
+        let out = self.sha256_compress(&mut lookups, &state_words, &block_bits)?;
+        self.sha2_add_table(lookups)?;
+        out.iter()
+            .map(|word| {
+                let terms: Vec<_> = word
+                    .iter()
+                    .enumerate()
+                    .map(|(i, bit)| (F::from(1u64 << (31 - i)), bit.0))
+                    .collect();
+                self.lin_comb_with_constant(&terms, F::zero())
+            })
+            .collect()
+    }
+
+    pub(super) fn sha2_lookups(&self) -> Sha2Lookups {
+        self.support_lookup().then(Vec::new)
+    }
+
+    // Add the XOR table of the gadget call with its lookups.
+    pub(super) fn sha2_add_table(&mut self, lookups: Sha2Lookups) -> Result<(), CircuitError> {
+        if let Some(lookups) = lookups {
+            let bit = |circuit: &Self, i: u32| {
+                if i.count_ones() % 2 == 1 {
+                    circuit.one()
+                } else {
+                    circuit.zero()
+                }
+            };
+            let table: Vec<_> = (0..64u32)
+                .map(|i| (bit(self, i & 7), bit(self, i >> 3)))
+                .collect();
+            self.create_table_and_lookup_variables(&lookups, &table)?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn sha2_constant_word(&self, w: u64, num_bits: usize) -> Word {
+        (0..num_bits)
+            .rev()
+            .map(|i| {
+                BoolVar(if w >> i & 1 == 1 {
+                    self.one()
+                } else {
+                    self.zero()
+                })
+            })
+            .collect()
+    }
+
+    // Apply the compression function to the state and a block of 16 words.
+    fn sha256_compress(
+        &mut self,
+        lookups: &mut Sha2Lookups,
+        state: &[Word],
+        block: &[BoolVar],
+    ) -> Result<Vec<Word>, CircuitError> {
+        self.sha2_compress(lookups, &SHA256_ROTATIONS, &K, state, block)
+    }
+
+    // Apply the compression function of SHA-256 or SHA-512, with round
+    // constants `k`, to the state and a block of 16 words.
+    pub(super) fn sha2_compress(
+        &mut self,
+        lookups: &mut Sha2Lookups,
+        rotations: &Sha2Rotations,
+        k: &[u64],
+        state: &[Word],
+        block: &[BoolVar],
+    ) -> Result<Vec<Word>, CircuitError> {
+        let n = state[0].len();
+        let [r0, r1, r2] = rotations.sigma0;
+        let [r3, r4, r5] = rotations.sigma1;
+        let mut w: Vec<Word> = block.chunks(n).map(|word| word.to_vec()).collect();
+        for i in 16..k.len() {
+            // sigma0 = rotr(w, r0) ^ rotr(w, r1) ^ shr(w, r2)
+            let s0 = self.sha2_xor3(
+                lookups,
+                &rotr(&w[i - 15], r0),
+                &rotr(&w[i - 15], r1),
+                &self.sha2_shr(&w[i - 15], r2),
+            )?;
+            // sigma1 = rotr(w, r3) ^ rotr(w, r4) ^ shr(w, r5)
+            let s1 = self.sha2_xor3(
+                lookups,
+                &rotr(&w[i - 2], r3),
+                &rotr(&w[i - 2], r4),
+                &self.sha2_shr(&w[i - 2], r5),
+            )?;
+            let next = self.sha2_add(&[&s1, &w[i - 7], &s0, &w[i - 16]], 0)?;
+            w.push(next);
+        }
+
+        let [r0, r1, r2] = rotations.big_sigma0;
+        let [r3, r4, r5] = rotations.big_sigma1;
+        let mut v = state.to_vec();
+        for (k, w) in k.iter().zip(w.iter()) {
+            let (a, b, c, e, f, g) = (&v[0], &v[1], &v[2], &v[4], &v[5], &v[6]);
+            // Sigma1 = rotr(e, r3) ^ rotr(e, r4) ^ rotr(e, r5)
+            let s1 = self.sha2_xor3(lookups, &rotr(e, r3), &rotr(e, r4), &rotr(e, r5))?;
+            // ch = (e & f) ^ (!e & g) = e * (f - g) + g
+            let ch = e
+                .iter()
+                .zip(f.iter().zip(g.iter()))
+                .map(|(&e, (&f, &g))| {
+                    self.gen_quad_poly(
+                        &[e.0, f.0, e.0, g.0],
+                        &[F::zero(), F::zero(), F::zero(), F::one()],
+                        &[F::one(), -F::one()],
+                        F::zero(),
+                    )
+                    .map(BoolVar)
+                })
+                .collect::<Result<Word, _>>()?;
+            // Sigma0 = rotr(a, r0) ^ rotr(a, r1) ^ rotr(a, r2)
+            let s0 = self.sha2_xor3(lookups, &rotr(a, r0), &rotr(a, r1), &rotr(a, r2))?;
+            // maj = (a & b) ^ (a & c) ^ (b & c) = a * b + c * (a ^ b)
+            let maj = a
+                .iter()
+                .zip(b.iter().zip(c.iter()))
+                .map(|(&a, (&b, &c))| {
+                    let a_xor_b = self.sha2_xor(a, b)?;
+                    self.mul_add(&[a.0, b.0, c.0, a_xor_b.0], &[F::one(), F::one()])
+                        .map(BoolVar)
+                })
+                .collect::<Result<Word, _>>()?;
+
+            // temp1 = h + Sigma1 + ch + k + w, temp2 = Sigma0 + maj
+            let new_e = self.sha2_add(&[&v[3], &v[7], &s1, &ch, w], *k)?;
+            let new_a = self.sha2_add(&[&v[7], &s1, &ch, w, &s0, &maj], *k)?;
+            v.pop();
+            v.insert(0, new_a);
+            v[4] = new_e;
+        }
+        state
+            .iter()
+            .zip(v.iter())
+            .map(|(h, v)| self.sha2_add(&[h, v], 0))
+            .collect()
+    }
+
+    // Return the sum of `words` and `constant` modulo 2^n, for words of n
+    // bits.
+    pub(super) fn sha2_add(
+        &mut self,
+        words: &[&Word],
+        constant: u64,
+    ) -> Result<Word, CircuitError> {
+        let n = words[0].len();
+        let terms: Vec<_> = words
+            .iter()
+            .flat_map(|word| {
+                word.iter()
+                    .enumerate()
+                    .map(|(i, bit)| (F::from(2u8).pow([(n - 1 - i) as u64]), bit.0))
+            })
+            .collect();
+        let sum = self.lin_comb_with_constant(&terms, F::from(constant))?;
+        // the sum is smaller than `(words.len() + 1) * 2^n`
+        let num_carry_bits = (usize::BITS - words.len().leading_zeros()) as usize;
+        let sum_bits = self.unpack(sum, n + num_carry_bits)?;
+        Ok(sum_bits[..n].iter().rev().copied().collect())
+    }
+
+    pub(super) fn sha2_xor(&mut self, a: BoolVar, b: BoolVar) -> Result<BoolVar, CircuitError> {
+        if b.0 == self.zero() {
+            return Ok(a);
+        }
+        // a ^ b = a + b - 2ab
+        self.gen_quad_poly(
+            &[a.0, b.0, self.zero(), self.zero()],
+            &[F::one(), F::one(), F::zero(), F::zero()],
+            &[-F::from(2u8), F::zero()],
+            F::zero(),
+        )
+        .map(BoolVar)
+    }
+
+    pub(super) fn sha2_xor3(
+        &mut self,
+        lookups: &mut Sha2Lookups,
+        a: &Word,
+        b: &Word,
+        c: &Word,
+    ) -> Result<Word, CircuitError> {
+        if let Some(lookups) = lookups {
+            let zero = self.zero();
+            let mut out = vec![];
+            for i in (0..a.len()).step_by(2) {
+                let t = self.lc(
+                    &[a[i].0, b[i].0, c[i].0, a[i + 1].0],
+                    &[F::one(), F::from(2u8), F::from(4u8), F::from(8u8)],
+                )?;
+                let key = self.lc(
+                    &[t, b[i + 1].0, c[i + 1].0, zero],
+                    &[F::one(), F::from(16u8), F::from(32u8), F::zero()],
+                )?;
+                let bits = [i, i + 1]
+                    .into_iter()
+                    .map(|j| {
+                        let val =
+                            self.witness(a[j].0)? + self.witness(b[j].0)? + self.witness(c[j].0)?;
+                        // the parity of a sum of three bits
+                        let val = val == F::one() || val == F::from(3u8);
+                        // the table values are bits
+                        self.create_boolean_variable_unchecked(F::from(val))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                lookups.push((key, bits[0].0, bits[1].0));
+                out.extend(bits);
+            }
+            return Ok(out);
+        }
+        a.iter()
+            .zip(b.iter().zip(c.iter()))
+            .map(|(&a, (&b, &c))| {
+                let t = self.sha2_xor(a, b)?;
+                self.sha2_xor(t, c)
+            })
+            .collect()
+    }
+
+    pub(super) fn sha2_shr(&self, w: &Word, n: usize) -> Word {
+        let mut shifted = vec![BoolVar(self.zero()); n];
+        shifted.extend_from_slice(&w[..w.len() - n]);
+        shifted
+    }
+}
+
+// Rotate a word right by `n` bits.
+pub(super) fn rotr(w: &Word, n: usize) -> Word {
+    let mut rotated = w[w.len() - n..].to_vec();
+    rotated.extend_from_slice(&w[..w.len() - n]);
+    rotated
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn test_sha256() -> Result<(), CircuitError> {
+        test_sha256_helper::<FqEd254>()?;
+        test_sha256_helper::<FqEd377>()?;
+        test_sha256_helper::<FqEd381>()?;
+        test_sha256_helper::<Fq377>()
+    }
+
+    fn test_sha256_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        // empty, one block, and two blocks because of the padding
+        for msg in [&b""[..], b"abc", &[0x5a; 56]] {
+            let expected = Sha256::digest(msg);
+            let mut num_gates = vec![];
+            for mut circuit in [
+                PlonkCircuit::<F>::new_turbo_plonk(),
+                PlonkCircuit::<F>::new_ultra_plonk(8),
+            ] {
+                let msg_vars = msg
+                    .iter()
+                    .map(|&byte| circuit.create_variable(F::from(byte)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let digest = circuit.sha256(&msg_vars)?;
+                for (&byte, &expected) in digest.iter().zip(expected.iter()) {
+                    assert_eq!(circuit.witness(byte)?, F::from(expected));
+                }
+                circuit.check_circuit_satisfiability(&[])?;
+                num_gates.push(circuit.num_gates());
+
+                // a wrong digest byte
+                *circuit.witness_mut(digest[0]) += F::one();
+                assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+            }
+            // the lookups save gates
+            assert!(num_gates[1] < num_gates[0]);
+        }
+
+        // bytes are range checked
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let byte = circuit.create_variable(F::from(256u32))?;
+        assert!(circuit.sha256(&[byte]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sha256_compression() -> Result<(), CircuitError> {
+        test_sha256_compression_helper::<FqEd254>()?;
+        test_sha256_compression_helper::<FqEd377>()?;
+        test_sha256_compression_helper::<FqEd381>()?;
+        test_sha256_compression_helper::<Fq377>()
+    }
+
+    fn test_sha256_compression_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        // the two padded blocks of a 56-byte message
+        let msg = [0x5a; 56];
+        let mut padded = msg.to_vec();
+        padded.push(0x80);
+        padded.resize(120, 0);
+        padded.extend((8 * msg.len() as u64).to_be_bytes());
+        let expected = Sha256::digest(msg);
+        let expected: Vec<_> = expected
+            .chunks(4)
+            .map(|w| u32::from_be_bytes([w[0], w[1], w[2], w[3]]))
+            .collect();
+
+        for mut circuit in [
+            PlonkCircuit::<F>::new_turbo_plonk(),
+            PlonkCircuit::<F>::new_ultra_plonk(8),
+        ] {
+            let mut state = H0
+                .iter()
+                .map(|&h| circuit.create_variable(F::from(h)))
+                .collect::<Result<Vec<_>, _>>()?;
+            for block in padded.chunks(64) {
+                let block = block
+                    .chunks(4)
+                    .map(|w| {
+                        circuit
+                            .create_variable(F::from(u32::from_be_bytes([w[0], w[1], w[2], w[3]])))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                state = circuit.sha256_compression(&state, &block)?;
+            }
+            for (&word, &expected) in state.iter().zip(expected.iter()) {
+                assert_eq!(circuit.witness(word)?, F::from(expected));
+            }
+            circuit.check_circuit_satisfiability(&[])?;
+            *circuit.witness_mut(state[7]) += F::one();
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+            // words are range checked and the lengths are checked
+            let big = circuit.create_variable(F::from(1u64 << 32))?;
+            let zero = circuit.zero();
+            assert!(circuit.sha256_compression(&[big; 8], &[zero; 16]).is_err());
+            assert!(circuit.sha256_compression(&[zero; 8], &[zero; 15]).is_err());
+        }
+        Ok(())
+    }
+}
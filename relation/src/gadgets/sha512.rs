@@ -0,0 +1,219 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! SHA-512 hash gadget over messages of a length fixed at circuit building
+//! time, built from the word operations of the SHA-256 gadget on 64-bit words.
+
+use super::sha256::{Sha2Rotations, Word};
+use crate::{BoolVar, Circuit, CircuitError, PlonkCircuit, Variable};
+use ark_ff::PrimeField;
+use ark_std::{vec, vec::Vec};
+
+const SHA512_ROTATIONS: Sha2Rotations = Sha2Rotations {
+    sigma0: [1, 8, 7],
+    sigma1: [19, 61, 6],
+    big_sigma0: [28, 34, 39],
+    big_sigma1: [14, 18, 41],
+};
+
+const H0: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+const K: [u64; 80] = [
+    0x428a2f98d728ae22,
+    0x7137449123ef65cd,
+    0xb5c0fbcfec4d3b2f,
+    0xe9b5dba58189dbbc,
+    0x3956c25bf348b538,
+    0x59f111f1b605d019,
+    0x923f82a4af194f9b,
+    0xab1c5ed5da6d8118,
+    0xd807aa98a3030242,
+    0x12835b0145706fbe,
+    0x243185be4ee4b28c,
+    0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f,
+    0x80deb1fe3b1696b1,
+    0x9bdc06a725c71235,
+    0xc19bf174cf692694,
+    0xe49b69c19ef14ad2,
+    0xefbe4786384f25e3,
+    0x0fc19dc68b8cd5b5,
+    0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275,
+    0x4a7484aa6ea6e483,
+    0x5cb0a9dcbd41fbd4,
+    0x76f988da831153b5,
+    0x983e5152ee66dfab,
+    0xa831c66d2db43210,
+    0xb00327c898fb213f,
+    0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2,
+    0xd5a79147930aa725,
+    0x06ca6351e003826f,
+    0x142929670a0e6e70,
+    0x27b70a8546d22ffc,
+    0x2e1b21385c26c926,
+    0x4d2c6dfc5ac42aed,
+    0x53380d139d95b3df,
+    0x650a73548baf63de,
+    0x766a0abb3c77b2a8,
+    0x81c2c92e47edaee6,
+    0x92722c851482353b,
+    0xa2bfe8a14cf10364,
+    0xa81a664bbc423001,
+    0xc24b8b70d0f89791,
+    0xc76c51a30654be30,
+    0xd192e819d6ef5218,
+    0xd69906245565a910,
+    0xf40e35855771202a,
+    0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8,
+    0x1e376c085141ab53,
+    0x2748774cdf8eeb99,
+    0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63,
+    0x4ed8aa4ae3418acb,
+    0x5b9cca4f7763e373,
+    0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc,
+    0x78a5636f43172f60,
+    0x84c87814a1f0ab72,
+    0x8cc702081a6439ec,
+    0x90befffa23631e28,
+    0xa4506cebde82bde9,
+    0xbef9a3f7b2c67915,
+    0xc67178f2e372532b,
+    0xca273eceea26619c,
+    0xd186b8c721c0c207,
+    0xeada7dd6cde0eb1e,
+    0xf57d4f7fee6ed178,
+    0x06f067aa72176fba,
+    0x0a637dc5a2c898a6,
+    0x113f9804bef90dae,
+    0x1b710b35131c471b,
+    0x28db77f523047d84,
+    0x32caab7b40c72493,
+    0x3c9ebe0a15c9bebc,
+    0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6,
+    0x597f299cfc657e2a,
+    0x5fcb6fab3ad6faec,
+    0x6c44198c4a475817,
+];
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Return the 64 bytes of the SHA-512 digest of the message `msg`, given
+    /// as byte variables. Each byte of `msg` is constrained to 8 bits.
+    /// Return error if the input variables are invalid.
+    pub fn sha512(&mut self, msg: &[Variable]) -> Result<Vec<Variable>, CircuitError> {
+        self.check_vars_bound(msg)?;
+        let mut bits = vec![];
+        for &byte in msg.iter() {
+            bits.extend(self.unpack(byte, 8)?.into_iter().rev());
+        }
+        let bits = self.sha512_bits(bits)?;
+        bits.chunks(8)
+            .map(|byte| {
+                let terms: Vec<_> = byte
+                    .iter()
+                    .enumerate()
+                    .map(|(i, bit)| (F::from(1u32 << (7 - i)), bit.0))
+                    .collect();
+                self.lin_comb_with_constant(&terms, F::zero())
+            })
+            .collect()
+    }
+
+    /// Return the 512 big-endian bits of the SHA-512 digest of the message
+    /// with big-endian bits `bits`, a whole number of bytes.
+    pub(crate) fn sha512_bits(
+        &mut self,
+        mut bits: Vec<BoolVar>,
+    ) -> Result<Vec<BoolVar>, CircuitError> {
+        let zero = BoolVar(self.zero());
+        let one = BoolVar(self.one());
+
+        // message bits, then 1, zeros and the 128-bit length
+        let bit_len = bits.len() as u64;
+        bits.push(one);
+        while bits.len() % 1024 != 896 {
+            bits.push(zero);
+        }
+        bits.extend(vec![zero; 64]);
+        bits.extend(
+            (0..64)
+                .rev()
+                .map(|i| if bit_len >> i & 1 == 1 { one } else { zero }),
+        );
+
+        let mut lookups = self.sha2_lookups();
+        let mut state: Vec<Word> = H0.iter().map(|&h| self.sha2_constant_word(h, 64)).collect();
+        for block in bits.chunks(1024) {
+            state = self.sha2_compress(&mut lookups, &SHA512_ROTATIONS, &K, &state, block)?;
+        }
+        self.sha2_add_table(lookups)?;
+        Ok(state.concat())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use sha2::{Digest, Sha512};
+
+    #[test]
+    fn test_sha512() -> Result<(), CircuitError> {
+        test_sha512_helper::<FqEd254>()?;
+        test_sha512_helper::<FqEd377>()?;
+        test_sha512_helper::<FqEd381>()?;
+        test_sha512_helper::<Fq377>()
+    }
+
+    fn test_sha512_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        // empty, one block, and two blocks because of the padding
+        for msg in [&b""[..], b"abc", &[0x5a; 112]] {
+            let expected = Sha512::digest(msg);
+            for mut circuit in [
+                PlonkCircuit::<F>::new_turbo_plonk(),
+                PlonkCircuit::<F>::new_ultra_plonk(8),
+            ] {
+                let msg_vars = msg
+                    .iter()
+                    .map(|&byte| circuit.create_variable(F::from(byte)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let digest = circuit.sha512(&msg_vars)?;
+                assert_eq!(digest.len(), 64);
+                for (&byte, &expected) in digest.iter().zip(expected.iter()) {
+                    assert_eq!(circuit.witness(byte)?, F::from(expected));
+                }
+                circuit.check_circuit_satisfiability(&[])?;
+
+                // a wrong digest byte
+                *circuit.witness_mut(digest[63]) += F::one();
+                assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+            }
+        }
+
+        // bytes are range checked
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let byte = circuit.create_variable(F::from(256u32))?;
+        assert!(circuit.sha512(&[byte]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Sorting of bounded values, with a proof that the sorted values are a
+//! permutation of the input.
+//!
+//! TurboPlonk circuits sort with Batcher's odd-even merge sorting network,
+//! whose compare-and-swap gadgets preserve the multiset of the values.
+//! UltraPlonk circuits instead let the prover provide the sorted values with
+//! the positions they come from, and each `(position, value)` pair is looked
+//! up in a table of the input. Strictly increasing `(value, position)` pairs
+//! make the positions distinct, hence a permutation.
+
+use crate::{BoolVar, Circuit, CircuitError, PlonkCircuit, Variable};
+use ark_ff::PrimeField;
+use ark_std::{cmp::max, format, vec::Vec};
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Return variables for the values of `vars` sorted in increasing order,
+    /// which are constrained to be a permutation of `vars`. The values are
+    /// constrained to `bit_len` bits.
+    /// Return error if `bit_len` is zero or too large for the field, a value
+    /// does not fit in `bit_len` bits or the variables are invalid.
+    pub fn sort(
+        &mut self,
+        vars: &[Variable],
+        bit_len: usize,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        self.check_vars_bound(vars)?;
+        if bit_len == 0 || bit_len + 1 >= F::MODULUS_BIT_SIZE as usize {
+            return Err(CircuitError::ParameterError(format!(
+                "cannot sort values of {bit_len} bits"
+            )));
+        }
+        let bound = F::from(2u8).pow([bit_len as u64]);
+        for &var in vars.iter() {
+            if self.witness(var)? >= bound {
+                return Err(CircuitError::ParameterError(format!(
+                    "the value {} does not fit in {} bits",
+                    self.witness(var)?,
+                    bit_len
+                )));
+            }
+            self.enforce_in_range(var, bit_len)?;
+        }
+        match self.support_lookup() {
+            true => self.sort_with_lookup(vars, bit_len),
+            false => self.sort_with_network(vars, bit_len),
+        }
+    }
+
+    // Sort the `bit_len`-bit values of `vars` with an odd-even merge sorting
+    // network, whose comparators beyond the last value are skipped when the
+    // number of values is not a power of two.
+    fn sort_with_network(
+        &mut self,
+        vars: &[Variable],
+        bit_len: usize,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        let n = vars.len();
+        let mut sorted = vars.to_vec();
+        let mut p = 1;
+        while p < n {
+            let mut k = p;
+            while k >= 1 {
+                let mut j = k % p;
+                while j + k < n {
+                    for i in 0..k.min(n - j - k) {
+                        if (i + j) / (2 * p) == (i + j + k) / (2 * p) {
+                            let (lo, hi) =
+                                self.compare_and_swap(sorted[i + j], sorted[i + j + k], bit_len)?;
+                            sorted[i + j] = lo;
+                            sorted[i + j + k] = hi;
+                        }
+                    }
+                    j += 2 * k;
+                }
+                k /= 2;
+            }
+            p *= 2;
+        }
+        Ok(sorted)
+    }
+
+    // Return the minimum and the maximum of the `bit_len`-bit values `a` and
+    // `b`.
+    fn compare_and_swap(
+        &mut self,
+        a: Variable,
+        b: Variable,
+        bit_len: usize,
+    ) -> Result<(Variable, Variable), CircuitError> {
+        // a <= b iff the bit `bit_len` of b - a + 2^bit_len is set
+        let shifted_diff = self.lin_comb_with_constant(
+            &[(F::one(), b), (-F::one(), a)],
+            F::from(2u8).pow([bit_len as u64]),
+        )?;
+        let a_leq_b: BoolVar = self.unpack(shifted_diff, bit_len + 1)?[bit_len];
+        let lo = self.conditional_select(a_leq_b, b, a)?;
+        let hi = self
+            .lin_comb_with_constant(&[(F::one(), a), (F::one(), b), (-F::one(), lo)], F::zero())?;
+        Ok((lo, hi))
+    }
+
+    // Sort the `bit_len`-bit values of `vars` with the positions of the sorted
+    // values looked up in a table of the input.
+    fn sort_with_lookup(
+        &mut self,
+        vars: &[Variable],
+        bit_len: usize,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        let n = vars.len();
+        if n <= 1 {
+            return Ok(vars.to_vec());
+        }
+        let zero = self.zero();
+        let table: Vec<_> = vars.iter().map(|&var| (var, zero)).collect();
+        let mut entries = vars
+            .iter()
+            .enumerate()
+            .map(|(pos, &var)| Ok((self.witness(var)?, pos)))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        entries.sort();
+
+        let pos_bits = max(usize::BITS - (n - 1).leading_zeros(), 1) as usize;
+        let diff_bits = max(bit_len, pos_bits);
+        let mut lookups = Vec::with_capacity(n);
+        let mut sorted = Vec::with_capacity(n);
+        let mut prev: Option<(Variable, Variable)> = None;
+        for (val, pos) in entries {
+            let pos = self.create_variable(F::from(pos as u64))?;
+            let val = self.create_variable(val)?;
+            lookups.push((pos, val, zero));
+            sorted.push(val);
+            if let Some(prev) = prev {
+                self.enforce_key_pos_increasing(prev, (val, pos), diff_bits)?;
+            }
+            prev = Some((val, pos));
+        }
+        self.create_table_and_lookup_variables(&lookups, &table)?;
+        Ok(sorted)
+    }
+
+    // Constrain `next = (key, pos)` to be strictly greater than `prev` in
+    // lexicographic order: either a larger key, or the same key at a later
+    // position, where the difference minus one must fit in `diff_bits` bits.
+    // Return whether the keys are equal.
+    pub(crate) fn enforce_key_pos_increasing(
+        &mut self,
+        prev: (Variable, Variable),
+        next: (Variable, Variable),
+        diff_bits: usize,
+    ) -> Result<BoolVar, CircuitError> {
+        let ((prev_key, prev_pos), (key, pos)) = (prev, next);
+        let same_key = self.is_equal(key, prev_key)?;
+        let key_diff =
+            self.lin_comb_with_constant(&[(F::one(), key), (-F::one(), prev_key)], -F::one())?;
+        let pos_diff =
+            self.lin_comb_with_constant(&[(F::one(), pos), (-F::one(), prev_pos)], -F::one())?;
+        let diff = self.conditional_select(same_key, key_diff, pos_diff)?;
+        self.enforce_in_range(diff, diff_bits)?;
+        Ok(same_key)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::{rand::Rng, vec};
+    use jf_utils::test_rng;
+
+    #[test]
+    fn test_sort() -> Result<(), CircuitError> {
+        test_sort_helper::<FqEd254>()?;
+        test_sort_helper::<FqEd377>()?;
+        test_sort_helper::<FqEd381>()?;
+        test_sort_helper::<Fq377>()
+    }
+
+    fn test_sort_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let mut rng = test_rng();
+        for n in [0, 1, 2, 5, 8, 13] {
+            for mut circuit in [
+                PlonkCircuit::<F>::new_turbo_plonk(),
+                PlonkCircuit::<F>::new_ultra_plonk(4),
+            ] {
+                // values of 6 bits, with duplicates
+                let mut values: Vec<u64> = (0..n).map(|_| rng.gen_range(0..64)).collect();
+                if n > 2 {
+                    values[2] = values[0];
+                }
+                let vars = values
+                    .iter()
+                    .map(|&val| circuit.create_variable(F::from(val)))
+                    .collect::<Result<Vec<_>, CircuitError>>()?;
+                let sorted = circuit.sort(&vars, 6)?;
+                values.sort();
+                assert_eq!(
+                    sorted
+                        .iter()
+                        .map(|&var| circuit.witness(var))
+                        .collect::<Result<Vec<_>, CircuitError>>()?,
+                    values.iter().map(|&val| F::from(val)).collect::<Vec<_>>()
+                );
+                assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+                // sorted values that are not a permutation of the input
+                if n > 1 {
+                    *circuit.witness_mut(sorted[0]) += F::one();
+                    assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+                }
+            }
+        }
+
+        // error paths
+        let mut circuit = PlonkCircuit::<F>::new_ultra_plonk(4);
+        let vars = vec![
+            circuit.create_variable(F::from(3u8))?,
+            circuit.create_variable(F::from(64u8))?,
+        ];
+        assert!(circuit.sort(&vars, 6).is_err());
+        assert!(circuit.sort(&vars[..1], 0).is_err());
+        assert!(circuit
+            .sort(&vars[..1], F::MODULUS_BIT_SIZE as usize)
+            .is_err());
+        assert!(circuit.sort(&[circuit.num_vars()], 6).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,348 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! BLAKE2s-256 hash gadget.
+//!
+//! A 32-bit word is represented by its 8 little-endian nibbles, so that the
+//! rotations by 16, 12 and 8 bits are free. XORs and the remaining bit of the
+//! rotation by 7 are looked up nibble by nibble in small tables, created once
+//! per gadget call, and modular additions pack the nibbles of their operands
+//! and split the sum back into range-checked nibbles.
+
+use crate::{Circuit, CircuitError, PlonkCircuit, Variable};
+use ark_ff::PrimeField;
+use ark_std::{vec, vec::Vec};
+
+/// The number of bytes of a message block.
+const BLAKE2S_BLOCK_SIZE: usize = 64;
+
+/// The initialization vector, shared with SHA-256.
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// The message word permutations of the 10 rounds.
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// A 32-bit word, as its 8 little-endian nibbles.
+type Word = Vec<Variable>;
+
+/// The lookups of a gadget call, added to their tables at the end of the
+/// call.
+struct Blake2sLookups {
+    /// The constant nibbles.
+    nibbles: Vec<Variable>,
+    /// `(a + 16 * b, 0, a ^ b)` for nibbles `a` and `b`.
+    xor: Vec<(Variable, Variable, Variable)>,
+    /// `(a, (a << 1) & 0xf, a >> 3)` for a nibble `a`.
+    rotl1: Vec<(Variable, Variable, Variable)>,
+}
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Return the 32 bytes of the unkeyed BLAKE2s-256 digest of the message
+    /// `msg`, given as byte variables. Each byte of `msg` is constrained to 8
+    /// bits.
+    /// Return error if the circuit does not support lookup or the input
+    /// variables are invalid.
+    pub fn blake2s256(&mut self, msg: &[Variable]) -> Result<Vec<Variable>, CircuitError> {
+        self.check_vars_bound(msg)?;
+        if !self.support_lookup() {
+            return Err(CircuitError::LookupUnsupported);
+        }
+        let mut lookups = Blake2sLookups {
+            nibbles: (0..16u8)
+                .map(|i| self.create_constant_variable(F::from(i)))
+                .collect::<Result<Vec<_>, _>>()?,
+            xor: vec![],
+            rotl1: vec![],
+        };
+        let mut nibbles = vec![];
+        for &byte in msg.iter() {
+            nibbles.extend(self.blake2s_byte_to_nibbles(&mut lookups, byte)?);
+        }
+        // pad with zeros to a positive number of blocks
+        let zero = self.zero();
+        let num_blocks =
+            ark_std::cmp::max((msg.len() + BLAKE2S_BLOCK_SIZE - 1) / BLAKE2S_BLOCK_SIZE, 1);
+        nibbles.resize(2 * BLAKE2S_BLOCK_SIZE * num_blocks, zero);
+
+        // the parameter block of a 32-byte digest without key
+        let mut params = IV;
+        params[0] ^= 0x01010020;
+        let mut h: Vec<Word> = params
+            .iter()
+            .map(|&w| blake2s_constant_word(&lookups, w))
+            .collect();
+        for (i, block) in nibbles.chunks(2 * BLAKE2S_BLOCK_SIZE).enumerate() {
+            let is_last = i + 1 == num_blocks;
+            let counter = if is_last {
+                msg.len()
+            } else {
+                (i + 1) * BLAKE2S_BLOCK_SIZE
+            } as u64;
+            let m: Vec<Word> = block.chunks(8).map(|w| w.to_vec()).collect();
+            h = self.blake2s_compress(&mut lookups, &h, &m, counter, is_last)?;
+        }
+
+        let digest = h
+            .concat()
+            .chunks(2)
+            .map(|byte| {
+                self.lc(
+                    &[byte[0], byte[1], zero, zero],
+                    &[F::one(), F::from(16u8), F::zero(), F::zero()],
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.blake2s_add_tables(lookups)?;
+        Ok(digest)
+    }
+
+    // Add the tables of the gadget call with their lookups.
+    fn blake2s_add_tables(&mut self, lookups: Blake2sLookups) -> Result<(), CircuitError> {
+        let zero = self.zero();
+        let nibbles = &lookups.nibbles;
+        let xor_table: Vec<_> = (0..256)
+            .map(|i| (zero, nibbles[(i % 16) ^ (i / 16)]))
+            .collect();
+        self.create_table_and_lookup_variables(&lookups.xor, &xor_table)?;
+        let rotl1_table: Vec<_> = (0..16)
+            .map(|a| (nibbles[(a << 1) & 0xf], nibbles[a >> 3]))
+            .collect();
+        self.create_table_and_lookup_variables(&lookups.rotl1, &rotl1_table)
+    }
+
+    // Split a byte into its two nibbles.
+    fn blake2s_byte_to_nibbles(
+        &mut self,
+        lookups: &mut Blake2sLookups,
+        byte: Variable,
+    ) -> Result<[Variable; 2], CircuitError> {
+        let val = self.nibble_witness(byte)?;
+        let lo = self.create_variable(F::from((val & 0xf) as u64))?;
+        let hi = self.create_variable(F::from((val >> 4) as u64))?;
+        let zero = self.zero();
+        self.lc_gate(
+            &[lo, hi, zero, zero, byte],
+            &[F::one(), F::from(16u8), F::zero(), F::zero()],
+        )?;
+        // `(a, 0, a)` is in the XOR table iff `a` is a nibble
+        lookups.xor.push((lo, zero, lo));
+        lookups.xor.push((hi, zero, hi));
+        Ok([lo, hi])
+    }
+
+    // Apply the compression function to the chaining value `h` and the
+    // message words `m`, after `counter` bytes.
+    fn blake2s_compress(
+        &mut self,
+        lookups: &mut Blake2sLookups,
+        h: &[Word],
+        m: &[Word],
+        counter: u64,
+        is_last: bool,
+    ) -> Result<Vec<Word>, CircuitError> {
+        let mut tail = IV;
+        tail[4] ^= counter as u32;
+        tail[5] ^= (counter >> 32) as u32;
+        if is_last {
+            tail[6] ^= u32::MAX;
+        }
+        let mut v = h.to_vec();
+        v.extend(tail.iter().map(|&w| blake2s_constant_word(lookups, w)));
+
+        for s in SIGMA.iter() {
+            for (i, [a, b, c, d]) in [
+                [0, 4, 8, 12],
+                [1, 5, 9, 13],
+                [2, 6, 10, 14],
+                [3, 7, 11, 15],
+                [0, 5, 10, 15],
+                [1, 6, 11, 12],
+                [2, 7, 8, 13],
+                [3, 4, 9, 14],
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                let (x, y) = (&m[s[2 * i]], &m[s[2 * i + 1]]);
+                v[a] = self.blake2s_add(lookups, &[&v[a], &v[b], x])?;
+                v[d] = rotr(&self.blake2s_xor_word(lookups, &v[d], &v[a])?, 16);
+                v[c] = self.blake2s_add(lookups, &[&v[c], &v[d]])?;
+                v[b] = rotr(&self.blake2s_xor_word(lookups, &v[b], &v[c])?, 12);
+                v[a] = self.blake2s_add(lookups, &[&v[a], &v[b], y])?;
+                v[d] = rotr(&self.blake2s_xor_word(lookups, &v[d], &v[a])?, 8);
+                v[c] = self.blake2s_add(lookups, &[&v[c], &v[d]])?;
+                // rotate right by 7 bits, i.e. by 8 bits and then left by 1 bit
+                let t = rotr(&self.blake2s_xor_word(lookups, &v[b], &v[c])?, 8);
+                v[b] = self.blake2s_rotl1(lookups, &t)?;
+            }
+        }
+
+        (0..8)
+            .map(|i| {
+                let t = self.blake2s_xor_word(lookups, &h[i], &v[i])?;
+                self.blake2s_xor_word(lookups, &t, &v[i + 8])
+            })
+            .collect()
+    }
+
+    // Return the sum of `words` modulo 2^32.
+    fn blake2s_add(
+        &mut self,
+        lookups: &mut Blake2sLookups,
+        words: &[&Word],
+    ) -> Result<Word, CircuitError> {
+        let terms: Vec<_> = words
+            .iter()
+            .flat_map(|word| {
+                word.iter()
+                    .enumerate()
+                    .map(|(j, &nibble)| (F::from(1u64 << (4 * j)), nibble))
+            })
+            .collect();
+        let sum = self.lin_comb_with_constant(&terms, F::zero())?;
+        let val = self.witness(sum)?.into_bigint().as_ref()[0];
+
+        // split the sum into 8 nibbles and a carry, which is smaller than 16
+        let zero = self.zero();
+        let mut out_terms = vec![];
+        for j in 0..9 {
+            let nibble = self.create_variable(F::from((val >> (4 * j)) & 0xf))?;
+            lookups.xor.push((nibble, zero, nibble));
+            out_terms.push((F::from(1u64 << (4 * j)), nibble));
+        }
+        let packed = self.lin_comb_with_constant(&out_terms, F::zero())?;
+        self.enforce_equal(sum, packed)?;
+        Ok(out_terms[..8].iter().map(|&(_, nibble)| nibble).collect())
+    }
+
+    fn blake2s_xor_word(
+        &mut self,
+        lookups: &mut Blake2sLookups,
+        a: &[Variable],
+        b: &[Variable],
+    ) -> Result<Word, CircuitError> {
+        let zero = self.zero();
+        a.iter()
+            .zip(b.iter())
+            .map(|(&a, &b)| {
+                if b == zero {
+                    return Ok(a);
+                }
+                if a == zero {
+                    return Ok(b);
+                }
+                let key = self.lc(
+                    &[a, b, zero, zero],
+                    &[F::one(), F::from(16u8), F::zero(), F::zero()],
+                )?;
+                let out = self.nibble_witness(a)? ^ self.nibble_witness(b)?;
+                let out = self.create_variable(F::from(out as u64))?;
+                lookups.xor.push((key, zero, out));
+                Ok(out)
+            })
+            .collect()
+    }
+
+    // Rotate a word left by 1 bit.
+    fn blake2s_rotl1(
+        &mut self,
+        lookups: &mut Blake2sLookups,
+        a: &[Variable],
+    ) -> Result<Word, CircuitError> {
+        // nibble j becomes ((a_j << 1) & 0xf) | (a_{j-1} >> 3)
+        let mut parts = vec![];
+        for &nibble in a.iter() {
+            let val = self.nibble_witness(nibble)?;
+            let hi = self.create_variable(F::from(((val << 1) & 0xf) as u64))?;
+            let lo = self.create_variable(F::from((val >> 3) as u64))?;
+            lookups.rotl1.push((nibble, hi, lo));
+            parts.push((hi, lo));
+        }
+        (0..8)
+            .map(|j| self.add(parts[j].0, parts[(j + 7) % 8].1))
+            .collect()
+    }
+}
+
+// The nibbles of a constant word.
+fn blake2s_constant_word(lookups: &Blake2sLookups, w: u32) -> Word {
+    (0..8)
+        .map(|j| lookups.nibbles[((w >> (4 * j)) & 0xf) as usize])
+        .collect()
+}
+
+// Rotate a word right by a multiple `r` of 4 bits.
+fn rotr(w: &[Variable], r: usize) -> Word {
+    (0..8).map(|j| w[(j + r / 4) % 8]).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use blake2::{Blake2s256, Digest};
+
+    #[test]
+    fn test_blake2s() -> Result<(), CircuitError> {
+        test_blake2s_helper::<FqEd254>()?;
+        test_blake2s_helper::<FqEd377>()?;
+        test_blake2s_helper::<FqEd381>()?;
+        test_blake2s_helper::<Fq377>()
+    }
+
+    fn test_blake2s_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        // empty, one block, exactly one block and two blocks
+        for msg in [
+            &b""[..],
+            b"abc",
+            &[0x5a; BLAKE2S_BLOCK_SIZE],
+            &[0xa5; BLAKE2S_BLOCK_SIZE + 1],
+        ] {
+            let mut circuit = PlonkCircuit::<F>::new_ultra_plonk(8);
+            let msg_vars = msg
+                .iter()
+                .map(|&byte| circuit.create_variable(F::from(byte)))
+                .collect::<Result<Vec<_>, _>>()?;
+            let digest = circuit.blake2s256(&msg_vars)?;
+            let expected = Blake2s256::digest(msg);
+            for (&byte, &expected) in digest.iter().zip(expected.iter()) {
+                assert_eq!(circuit.witness(byte)?, F::from(expected));
+            }
+            circuit.check_circuit_satisfiability(&[])?;
+
+            // a wrong digest byte
+            *circuit.witness_mut(digest[0]) += F::one();
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        }
+
+        // bytes are range checked
+        let mut circuit = PlonkCircuit::<F>::new_ultra_plonk(8);
+        let byte = circuit.create_variable(F::from(256u32))?;
+        circuit.blake2s256(&[byte])?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // lookups are required
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        assert!(circuit.blake2s256(&[]).is_err());
+        Ok(())
+    }
+}
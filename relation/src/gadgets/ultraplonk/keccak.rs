@@ -0,0 +1,463 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Keccak-f\[1600\] permutation and Keccak-256 hash gadgets.
+//!
+//! A 64-bit lane is represented by its 16 little-endian nibbles. The XORs of
+//! the θ and ι steps, the χ step and the bit rotations of the ρ step are
+//! looked up nibble by nibble in small tables, created once per gadget call,
+//! which also constrain every nibble variable to 4 bits.
+
+use crate::{Circuit, CircuitError, PlonkCircuit, Variable};
+use ark_ff::PrimeField;
+use ark_std::{format, vec, vec::Vec};
+
+/// The number of bytes absorbed per permutation by Keccak-256.
+const KECCAK256_RATE: usize = 136;
+
+/// The number of bytes of the Keccak-f\[1600\] state.
+const KECCAK_STATE_SIZE: usize = 200;
+
+/// The rotation offset of the lane `x + 5 * y` in the ρ step.
+const ROTATION_OFFSETS: [usize; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// The round constants of the ι step.
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// A lane, as its 16 little-endian nibbles.
+type Lane = Vec<Variable>;
+
+/// The lookups of a gadget call, added to their tables at the end of the
+/// call.
+struct KeccakLookups {
+    /// The constant nibbles.
+    nibbles: Vec<Variable>,
+    /// `(a + 16 * b, 0, a ^ b)` for nibbles `a` and `b`.
+    xor: Vec<(Variable, Variable, Variable)>,
+    /// `(a + 16 * b + 256 * c, 0, a ^ (!b & c))` for nibbles `a`, `b`, `c`.
+    chi: Vec<(Variable, Variable, Variable)>,
+    /// `(a, (a << s) & 0xf, a >> (4 - s))` for a nibble `a`, for `s` in
+    /// 1..4.
+    rotl: [Vec<(Variable, Variable, Variable)>; 3],
+}
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Return the 32 bytes of the Keccak-256 digest, as used by Ethereum, of
+    /// the message `msg`, given as byte variables. Each byte of `msg` is
+    /// constrained to 8 bits.
+    /// Return error if the circuit does not support lookup or the input
+    /// variables are invalid.
+    pub fn keccak256(&mut self, msg: &[Variable]) -> Result<Vec<Variable>, CircuitError> {
+        self.keccak_sponge256(msg, 0x01)
+    }
+
+    /// Return the 32 bytes of the SHA3-256 digest (FIPS 202) of the message
+    /// `msg`, given as byte variables. Each byte of `msg` is constrained to 8
+    /// bits.
+    /// Return error if the circuit does not support lookup or the input
+    /// variables are invalid.
+    pub fn sha3_256(&mut self, msg: &[Variable]) -> Result<Vec<Variable>, CircuitError> {
+        self.keccak_sponge256(msg, 0x06)
+    }
+
+    // The sponge of Keccak-256 and SHA3-256, which differ in the first
+    // padding byte `domain`.
+    fn keccak_sponge256(
+        &mut self,
+        msg: &[Variable],
+        domain: u8,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        self.check_vars_bound(msg)?;
+        let mut lookups = self.keccak_lookups()?;
+        let mut nibbles = vec![];
+        for &byte in msg.iter() {
+            nibbles.extend(self.keccak_byte_to_nibbles(&mut lookups, byte)?);
+        }
+        // pad with the domain byte, zeros and a final 0x80
+        let zero = self.zero();
+        nibbles.extend([
+            lookups.nibbles[(domain & 0xf) as usize],
+            lookups.nibbles[(domain >> 4) as usize],
+        ]);
+        while nibbles.len() % (2 * KECCAK256_RATE) != 0 {
+            nibbles.push(zero);
+        }
+        let last = nibbles.len() - 1;
+        nibbles[last] = lookups.nibbles[8];
+
+        let mut state = vec![vec![zero; 16]; 25];
+        for block in nibbles.chunks(2 * KECCAK256_RATE) {
+            for (lane, block_lane) in state.iter_mut().zip(block.chunks(16)) {
+                *lane = self.keccak_xor_lane(&mut lookups, lane, block_lane)?;
+            }
+            state = self.keccak_permute(&mut lookups, state)?;
+        }
+        let digest = state[..4]
+            .concat()
+            .chunks(2)
+            .map(|byte| {
+                self.lc(
+                    &[byte[0], byte[1], zero, zero],
+                    &[F::one(), F::from(16u8), F::zero(), F::zero()],
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.keccak_add_tables(lookups)?;
+        Ok(digest)
+    }
+
+    /// Return the Keccak-f\[1600\] permutation of the state `state`, given as
+    /// its 200 byte variables, with the bytes of lane `x + 5 * y` at indices
+    /// `8 * (x + 5 * y)..8 * (x + 5 * y + 1)` in little-endian order. Each
+    /// byte of `state` is constrained to 8 bits.
+    /// Return error if the circuit does not support lookup or the input
+    /// variables are invalid.
+    pub fn keccak_f1600(&mut self, state: &[Variable]) -> Result<Vec<Variable>, CircuitError> {
+        if state.len() != KECCAK_STATE_SIZE {
+            return Err(CircuitError::ParameterError(format!(
+                "the Keccak state has {KECCAK_STATE_SIZE} bytes, got {}",
+                state.len()
+            )));
+        }
+        self.check_vars_bound(state)?;
+        let mut lookups = self.keccak_lookups()?;
+        let mut nibbles = vec![];
+        for &byte in state.iter() {
+            nibbles.extend(self.keccak_byte_to_nibbles(&mut lookups, byte)?);
+        }
+        let state = nibbles.chunks(16).map(|lane| lane.to_vec()).collect();
+        let state = self.keccak_permute(&mut lookups, state)?;
+        let zero = self.zero();
+        let bytes = state
+            .concat()
+            .chunks(2)
+            .map(|byte| {
+                self.lc(
+                    &[byte[0], byte[1], zero, zero],
+                    &[F::one(), F::from(16u8), F::zero(), F::zero()],
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.keccak_add_tables(lookups)?;
+        Ok(bytes)
+    }
+
+    fn keccak_lookups(&mut self) -> Result<KeccakLookups, CircuitError> {
+        if !self.support_lookup() {
+            return Err(CircuitError::LookupUnsupported);
+        }
+        Ok(KeccakLookups {
+            nibbles: (0..16u8)
+                .map(|i| self.create_constant_variable(F::from(i)))
+                .collect::<Result<Vec<_>, _>>()?,
+            xor: vec![],
+            chi: vec![],
+            rotl: [vec![], vec![], vec![]],
+        })
+    }
+
+    // Add the tables of the gadget call with their lookups.
+    fn keccak_add_tables(&mut self, lookups: KeccakLookups) -> Result<(), CircuitError> {
+        let zero = self.zero();
+        let nibbles = &lookups.nibbles;
+        let xor_table: Vec<_> = (0..256)
+            .map(|i| (zero, nibbles[(i % 16) ^ (i / 16)]))
+            .collect();
+        self.create_table_and_lookup_variables(&lookups.xor, &xor_table)?;
+        let chi_table: Vec<_> = (0..4096)
+            .map(|i| {
+                let (a, b, c) = (i % 16, (i / 16) % 16, i / 256);
+                (zero, nibbles[a ^ (!b & c & 0xf)])
+            })
+            .collect();
+        self.create_table_and_lookup_variables(&lookups.chi, &chi_table)?;
+        for (s, rotl) in (1..4).zip(lookups.rotl.iter()) {
+            let rotl_table: Vec<_> = (0..16)
+                .map(|a| (nibbles[(a << s) & 0xf], nibbles[a >> (4 - s)]))
+                .collect();
+            self.create_table_and_lookup_variables(rotl, &rotl_table)?;
+        }
+        Ok(())
+    }
+
+    // Split a byte into its two nibbles.
+    fn keccak_byte_to_nibbles(
+        &mut self,
+        lookups: &mut KeccakLookups,
+        byte: Variable,
+    ) -> Result<[Variable; 2], CircuitError> {
+        let val = self.nibble_witness(byte)?;
+        let lo = self.create_variable(F::from((val & 0xf) as u64))?;
+        let hi = self.create_variable(F::from((val >> 4) as u64))?;
+        let zero = self.zero();
+        self.lc_gate(
+            &[lo, hi, zero, zero, byte],
+            &[F::one(), F::from(16u8), F::zero(), F::zero()],
+        )?;
+        // `(a, 0, a)` is in the XOR table iff `a` is a nibble
+        lookups.xor.push((lo, zero, lo));
+        lookups.xor.push((hi, zero, hi));
+        Ok([lo, hi])
+    }
+
+    // The witness of a small variable.
+    pub(super) fn nibble_witness(&self, var: Variable) -> Result<usize, CircuitError> {
+        Ok(self.witness(var)?.into_bigint().as_ref()[0] as usize)
+    }
+
+    fn keccak_xor(
+        &mut self,
+        lookups: &mut KeccakLookups,
+        a: Variable,
+        b: Variable,
+    ) -> Result<Variable, CircuitError> {
+        let zero = self.zero();
+        if b == zero {
+            return Ok(a);
+        }
+        if a == zero {
+            return Ok(b);
+        }
+        let key = self.lc(
+            &[a, b, zero, zero],
+            &[F::one(), F::from(16u8), F::zero(), F::zero()],
+        )?;
+        let out = self.nibble_witness(a)? ^ self.nibble_witness(b)?;
+        let out = self.create_variable(F::from(out as u64))?;
+        lookups.xor.push((key, zero, out));
+        Ok(out)
+    }
+
+    fn keccak_xor_lane(
+        &mut self,
+        lookups: &mut KeccakLookups,
+        a: &[Variable],
+        b: &[Variable],
+    ) -> Result<Lane, CircuitError> {
+        a.iter()
+            .zip(b.iter())
+            .map(|(&a, &b)| self.keccak_xor(lookups, a, b))
+            .collect()
+    }
+
+    // Rotate a lane left by `r` bits.
+    fn keccak_rotl_lane(
+        &mut self,
+        lookups: &mut KeccakLookups,
+        a: &[Variable],
+        r: usize,
+    ) -> Result<Lane, CircuitError> {
+        let (q, s) = (r / 4, r % 4);
+        let rotated: Lane = if s == 0 {
+            a.to_vec()
+        } else {
+            // nibble j becomes ((a_j << s) & 0xf) | (a_{j-1} >> (4 - s))
+            let mut parts = vec![];
+            for &nibble in a.iter() {
+                let val = self.nibble_witness(nibble)?;
+                let hi = self.create_variable(F::from(((val << s) & 0xf) as u64))?;
+                let lo = self.create_variable(F::from((val >> (4 - s)) as u64))?;
+                lookups.rotl[s - 1].push((nibble, hi, lo));
+                parts.push((hi, lo));
+            }
+            (0..16)
+                .map(|j| self.add(parts[j].0, parts[(j + 15) % 16].1))
+                .collect::<Result<_, _>>()?
+        };
+        Ok((0..16).map(|j| rotated[(j + 16 - q) % 16]).collect())
+    }
+
+    // Apply the 24 rounds of Keccak-f[1600] to the lanes.
+    fn keccak_permute(
+        &mut self,
+        lookups: &mut KeccakLookups,
+        mut a: Vec<Lane>,
+    ) -> Result<Vec<Lane>, CircuitError> {
+        for rc in ROUND_CONSTANTS.iter() {
+            // θ
+            let mut c = vec![];
+            for x in 0..5 {
+                let mut lane = a[x].clone();
+                for y in 1..5 {
+                    lane = self.keccak_xor_lane(lookups, &lane, &a[x + 5 * y])?;
+                }
+                c.push(lane);
+            }
+            for x in 0..5 {
+                let rotated = self.keccak_rotl_lane(lookups, &c[(x + 1) % 5], 1)?;
+                let d = self.keccak_xor_lane(lookups, &c[(x + 4) % 5], &rotated)?;
+                for y in 0..5 {
+                    a[x + 5 * y] = self.keccak_xor_lane(lookups, &a[x + 5 * y], &d)?;
+                }
+            }
+
+            // ρ and π
+            let mut b = vec![vec![]; 25];
+            for x in 0..5 {
+                for y in 0..5 {
+                    b[y + 5 * ((2 * x + 3 * y) % 5)] =
+                        self.keccak_rotl_lane(lookups, &a[x + 5 * y], ROTATION_OFFSETS[x + 5 * y])?;
+                }
+            }
+
+            // χ
+            let zero = self.zero();
+            for x in 0..5 {
+                for y in 0..5 {
+                    let (b0, b1, b2) = (
+                        &b[x + 5 * y],
+                        &b[(x + 1) % 5 + 5 * y],
+                        &b[(x + 2) % 5 + 5 * y],
+                    );
+                    a[x + 5 * y] = (0..16)
+                        .map(|j| {
+                            let key = self.lc(
+                                &[b0[j], b1[j], b2[j], zero],
+                                &[F::one(), F::from(16u8), F::from(256u16), F::zero()],
+                            )?;
+                            let (v0, v1, v2) = (
+                                self.nibble_witness(b0[j])?,
+                                self.nibble_witness(b1[j])?,
+                                self.nibble_witness(b2[j])?,
+                            );
+                            let out =
+                                self.create_variable(F::from((v0 ^ (!v1 & v2 & 0xf)) as u64))?;
+                            lookups.chi.push((key, zero, out));
+                            Ok(out)
+                        })
+                        .collect::<Result<_, CircuitError>>()?;
+                }
+            }
+
+            // ι
+            let rc_nibbles: Vec<_> = (0..16)
+                .map(|j| {
+                    let nibble = (rc >> (4 * j)) & 0xf;
+                    if nibble == 0 {
+                        zero
+                    } else {
+                        lookups.nibbles[nibble as usize]
+                    }
+                })
+                .collect();
+            a[0] = self.keccak_xor_lane(lookups, &a[0], &rc_nibbles)?;
+        }
+        Ok(a)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use sha3::{Digest, Keccak256, Sha3_256};
+
+    #[test]
+    fn test_keccak() -> Result<(), CircuitError> {
+        test_keccak_helper::<FqEd254>()?;
+        test_keccak_helper::<FqEd377>()?;
+        test_keccak_helper::<FqEd381>()?;
+        test_keccak_helper::<Fq377>()
+    }
+
+    fn create_bytes<F: PrimeField>(
+        circuit: &mut PlonkCircuit<F>,
+        bytes: &[u8],
+    ) -> Result<Vec<Variable>, CircuitError> {
+        bytes
+            .iter()
+            .map(|&byte| circuit.create_variable(F::from(byte)))
+            .collect()
+    }
+
+    fn test_keccak_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        // the permutation of the zero state
+        let mut circuit = PlonkCircuit::<F>::new_ultra_plonk(8);
+        let state = create_bytes(&mut circuit, &[0u8; KECCAK_STATE_SIZE])?;
+        let permuted = circuit.keccak_f1600(&state)?;
+        let first_lane: Vec<_> = 0xf1258f7940e1dde7u64
+            .to_le_bytes()
+            .iter()
+            .map(|&byte| F::from(byte))
+            .collect();
+        for (&byte, expected) in permuted[..8].iter().zip(first_lane) {
+            assert_eq!(circuit.witness(byte)?, expected);
+        }
+        circuit.check_circuit_satisfiability(&[])?;
+        assert!(circuit.keccak_f1600(&state[1..]).is_err());
+
+        // one block, one block with a single padding byte, two blocks
+        for msg in [
+            &b""[..],
+            b"abc",
+            &[0x5a; KECCAK256_RATE - 1],
+            &[0xa5; KECCAK256_RATE],
+        ] {
+            let mut circuit = PlonkCircuit::<F>::new_ultra_plonk(8);
+            let msg_vars = create_bytes(&mut circuit, msg)?;
+            let digest = circuit.keccak256(&msg_vars)?;
+            let expected = Keccak256::digest(msg);
+            for (&byte, &expected) in digest.iter().zip(expected.iter()) {
+                assert_eq!(circuit.witness(byte)?, F::from(expected));
+            }
+            circuit.check_circuit_satisfiability(&[])?;
+
+            // a wrong digest byte
+            *circuit.witness_mut(digest[31]) += F::one();
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+            let mut circuit = PlonkCircuit::<F>::new_ultra_plonk(8);
+            let msg_vars = create_bytes(&mut circuit, msg)?;
+            let digest = circuit.sha3_256(&msg_vars)?;
+            let expected = Sha3_256::digest(msg);
+            for (&byte, &expected) in digest.iter().zip(expected.iter()) {
+                assert_eq!(circuit.witness(byte)?, F::from(expected));
+            }
+            circuit.check_circuit_satisfiability(&[])?;
+        }
+
+        // bytes are range checked
+        let mut circuit = PlonkCircuit::<F>::new_ultra_plonk(8);
+        let byte = circuit.create_variable(F::from(256u32))?;
+        circuit.keccak256(&[byte])?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // lookups are needed
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        assert!(circuit.keccak256(&[]).is_err());
+        Ok(())
+    }
+}
@@ -8,7 +8,7 @@
 
 use crate::{gates::LookupGate, Circuit, CircuitError, PlonkCircuit, Variable};
 use ark_ff::PrimeField;
-use ark_std::{boxed::Box, cmp::max};
+use ark_std::{boxed::Box, cmp::max, vec::Vec};
 
 impl<F: PrimeField> PlonkCircuit<F> {
     /// Create a table with keys/values
@@ -69,6 +69,52 @@ impl<F: PrimeField> PlonkCircuit<F> {
         *self.num_table_elems_mut() += n;
         Ok(())
     }
+
+    /// Create a dynamic table of key-value pairs
+    ///     [table_vars\[0\], ..., table_vars\[n - 1\]]
+    /// whose keys and values are both witness values, and enforce that each
+    /// pair in `lookup_vars` is one of the table entries. This is e.g. the
+    /// building block of read-only memory, where the table holds the
+    /// `(address, value)` pairs of the memory.
+    ///
+    /// Internally, entry `i` is stored at the fixed key `i` of a regular
+    /// table, and each lookup is matched against the position of its entry,
+    /// which the prover provides as a hint. Return the variables of these
+    /// positions, in the order of `lookup_vars`. A lookup of a pair that is
+    /// not in the table leads to an unsatisfied circuit.
+    pub fn create_dynamic_table_and_lookup_variables(
+        &mut self,
+        lookup_vars: &[(Variable, Variable)],
+        table_vars: &[(Variable, Variable)],
+    ) -> Result<Vec<Variable>, CircuitError> {
+        self.check_vars_bound(
+            &lookup_vars
+                .iter()
+                .flat_map(|&(k, v)| [k, v])
+                .collect::<Vec<_>>(),
+        )?;
+        self.check_vars_bound(
+            &table_vars
+                .iter()
+                .flat_map(|&(k, v)| [k, v])
+                .collect::<Vec<_>>(),
+        )?;
+        let table = table_vars
+            .iter()
+            .map(|&(key, val)| Ok((self.witness(key)?, self.witness(val)?)))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        let mut position_vars = Vec::with_capacity(lookup_vars.len());
+        let mut indexed_lookup_vars = Vec::with_capacity(lookup_vars.len());
+        for &(key, val) in lookup_vars.iter() {
+            let entry = (self.witness(key)?, self.witness(val)?);
+            let position = table.iter().position(|e| *e == entry).unwrap_or_default();
+            let position_var = self.create_variable(F::from(position as u64))?;
+            position_vars.push(position_var);
+            indexed_lookup_vars.push((position_var, key, val));
+        }
+        self.create_table_and_lookup_variables(&indexed_lookup_vars, table_vars)?;
+        Ok(position_vars)
+    }
 }
 
 #[cfg(test)]
@@ -169,4 +215,64 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_dynamic_lookup_table() -> Result<(), CircuitError> {
+        test_dynamic_lookup_table_helper::<FqEd254>()?;
+        test_dynamic_lookup_table_helper::<FqEd377>()?;
+        test_dynamic_lookup_table_helper::<FqEd381>()?;
+        test_dynamic_lookup_table_helper::<Fq377>()
+    }
+    fn test_dynamic_lookup_table_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(4);
+        let mut rng = test_rng();
+
+        // a table with witness keys, some of them repeated
+        let n = 10;
+        let mut table_vars = vec![];
+        for i in 0..n {
+            let key = circuit.create_variable(F::from((i % 7) as u32 * 3))?;
+            let val = circuit.create_variable(F::rand(&mut rng))?;
+            table_vars.push((key, val));
+        }
+        let lookup_vars = [9, 2, 2, 5]
+            .iter()
+            .map(|&i| {
+                let (key, val) = table_vars[i];
+                Ok((
+                    circuit.create_variable(circuit.witness(key)?)?,
+                    circuit.create_variable(circuit.witness(val)?)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        let positions =
+            circuit.create_dynamic_table_and_lookup_variables(&lookup_vars, &table_vars)?;
+        assert_eq!(circuit.witness(positions[0])?, F::from(9u32));
+        assert_eq!(circuit.witness(positions[1])?, F::from(2u32));
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        // the table itself can change with the witness
+        let new_val = F::rand(&mut rng);
+        *circuit.witness_mut(table_vars[2].1) = new_val;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        *circuit.witness_mut(lookup_vars[1].1) = new_val;
+        *circuit.witness_mut(lookup_vars[2].1) = new_val;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        // Error paths
+        // a pair outside the table, even if its key and value are in it
+        let bad_lookup = (lookup_vars[0].0, lookup_vars[3].1);
+        circuit.create_dynamic_table_and_lookup_variables(&[bad_lookup], &table_vars)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // out-of-bound variables
+        let bad_vars = vec![(circuit.num_vars(), circuit.zero())];
+        assert!(circuit
+            .create_dynamic_table_and_lookup_variables(&bad_vars, &table_vars)
+            .is_err());
+        assert!(circuit
+            .create_dynamic_table_and_lookup_variables(&lookup_vars, &bad_vars)
+            .is_err());
+        Ok(())
+    }
 }
@@ -6,7 +6,12 @@
 
 //! Implements ultra-plonk related circuits.
 
+mod blake2s;
+mod keccak;
 mod lookup_table;
 pub mod mod_arith;
 mod non_native_gates;
+pub mod ram;
 mod range;
+pub mod rom;
+pub mod set;
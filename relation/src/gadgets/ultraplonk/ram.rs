@@ -0,0 +1,269 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Read/write memory with offline memory checking.
+//!
+//! Each access to a [`Ram`] is logged as an `(address, value)` pair at the key
+//! of its timestamp, i.e. its position in the log, of a lookup table. When
+//! enforcing the consistency of the memory, the prover provides the log sorted
+//! by address then timestamp, and each sorted access is looked up in the table
+//! together with its timestamp. Strictly increasing `(address, timestamp)`
+//! pairs make the sorted accesses a permutation of the log, along which every
+//! read returns the value of the latest write to its address, or zero.
+
+use crate::{Circuit, CircuitError, OptimizationReport, PlonkCircuit, Variable};
+use ark_ff::PrimeField;
+use ark_std::{cmp::max, collections::BTreeMap, format, vec::Vec};
+
+/// A read/write memory of a circuit, whose cells initially hold zero.
+#[derive(Debug, Clone)]
+pub struct Ram<F: PrimeField> {
+    addr_bits: usize,
+    // the `(address, value, is_write)` accesses, in order
+    log: Vec<(Variable, Variable, bool)>,
+    // the current content of the memory
+    cells: BTreeMap<F, F>,
+}
+
+impl<F: PrimeField> Ram<F> {
+    /// Create an empty memory whose addresses have at most `addr_bits` bits.
+    pub fn new(addr_bits: usize) -> Self {
+        Self {
+            addr_bits,
+            log: Vec::new(),
+            cells: BTreeMap::new(),
+        }
+    }
+
+    /// The number of accesses to the memory so far.
+    pub fn num_accesses(&self) -> usize {
+        self.log.len()
+    }
+
+    /// Translate the variables of the accesses so far after
+    /// [`PlonkCircuit::optimize()`] reported `report`.
+    /// Return error if a variable was removed, e.g. the value of a read, as
+    /// the consistency of the memory is not enforced yet.
+    pub fn renumber_variables(&mut self, report: &OptimizationReport) -> Result<(), CircuitError> {
+        for (addr, val, _) in self.log.iter_mut() {
+            let new_vars = report.new_variables(&[*addr, *val])?;
+            *addr = new_vars[0];
+            *val = new_vars[1];
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Return a variable for the value at address `addr` of the memory `ram`.
+    /// `addr` is constrained to the address bit length of the memory.
+    /// The value is only constrained by [`Self::enforce_ram_consistency()`].
+    /// Return error if the circuit does not support lookup, the address is
+    /// too large or the variable is invalid.
+    pub fn ram_read(&mut self, ram: &mut Ram<F>, addr: Variable) -> Result<Variable, CircuitError> {
+        let addr_val = self.ram_address(ram, addr)?;
+        let val = ram.cells.get(&addr_val).copied().unwrap_or_default();
+        let val = self.create_variable(val)?;
+        ram.log.push((addr, val, false));
+        Ok(val)
+    }
+
+    /// Write `val` at address `addr` of the memory `ram`.
+    /// `addr` is constrained to the address bit length of the memory.
+    /// Return error if the circuit does not support lookup, the address is
+    /// too large or the variables are invalid.
+    pub fn ram_write(
+        &mut self,
+        ram: &mut Ram<F>,
+        addr: Variable,
+        val: Variable,
+    ) -> Result<(), CircuitError> {
+        self.check_var_bound(val)?;
+        let addr_val = self.ram_address(ram, addr)?;
+        ram.cells.insert(addr_val, self.witness(val)?);
+        ram.log.push((addr, val, true));
+        Ok(())
+    }
+
+    /// Constrain every read of the memory `ram` to return the value of the
+    /// latest write to its address, or zero if there is none.
+    /// Return error if the circuit does not support lookup.
+    pub fn enforce_ram_consistency(&mut self, ram: Ram<F>) -> Result<(), CircuitError> {
+        if !self.support_lookup() {
+            return Err(CircuitError::LookupUnsupported);
+        }
+        let n = ram.log.len();
+        if n == 0 {
+            return Ok(());
+        }
+        let zero = self.zero();
+        let table: Vec<_> = ram.log.iter().map(|&(addr, val, _)| (addr, val)).collect();
+        let is_write_table = ram
+            .log
+            .iter()
+            .map(|&(.., is_write)| Ok((self.create_constant_variable(F::from(is_write))?, zero)))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+
+        // the accesses sorted by address then timestamp
+        let mut sorted = ram
+            .log
+            .iter()
+            .enumerate()
+            .map(|(ts, &(addr, val, is_write))| Ok((self.witness(addr)?, ts, val, is_write)))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        sorted.sort_by_key(|&(addr, ts, ..)| (addr, ts));
+
+        let ts_bits = max(usize::BITS - (n - 1).leading_zeros(), 1) as usize;
+        let diff_bits = max(ram.addr_bits, ts_bits);
+        let mut lookups = Vec::with_capacity(n);
+        let mut is_write_lookups = Vec::with_capacity(n);
+        let mut prev: Option<(Variable, Variable, Variable)> = None;
+        for (addr, ts, val, is_write) in sorted {
+            let ts = self.create_variable(F::from(ts as u64))?;
+            let addr = self.create_variable(addr)?;
+            let val = self.create_variable(self.witness(val)?)?;
+            let is_write = self.create_boolean_variable_unchecked(F::from(is_write))?;
+            lookups.push((ts, addr, val));
+            is_write_lookups.push((ts, is_write.into(), zero));
+
+            // the value of the cell before the access, zero for a first access
+            let prev_val = match prev {
+                Some((prev_ts, prev_addr, prev_val)) => {
+                    // either a larger address, or the same address at a later
+                    // timestamp
+                    let same_addr = self.enforce_key_pos_increasing(
+                        (prev_addr, prev_ts),
+                        (addr, ts),
+                        diff_bits,
+                    )?;
+                    self.mul(same_addr.into(), prev_val)?
+                },
+                None => zero,
+            };
+            // a read returns the value before the access:
+            // (1 - is_write) * (val - prev_val) = 0
+            let delta = self.sub(val, prev_val)?;
+            let masked = self.mul(is_write.into(), delta)?;
+            self.enforce_equal(masked, delta)?;
+            prev = Some((ts, addr, val));
+        }
+        self.create_table_and_lookup_variables(&lookups, &table)?;
+        self.create_table_and_lookup_variables(&is_write_lookups, &is_write_table)
+    }
+
+    // Check and range-check an address of `ram`, and return its value.
+    fn ram_address(&mut self, ram: &Ram<F>, addr: Variable) -> Result<F, CircuitError> {
+        self.check_var_bound(addr)?;
+        if !self.support_lookup() {
+            return Err(CircuitError::LookupUnsupported);
+        }
+        let addr_val = self.witness(addr)?;
+        if addr_val >= F::from(2u8).pow([ram.addr_bits as u64]) {
+            return Err(CircuitError::ParameterError(format!(
+                "the address does not fit in {} bits",
+                ram.addr_bits
+            )));
+        }
+        self.enforce_in_range(addr, ram.addr_bits)?;
+        Ok(addr_val)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::{rand::Rng, vec};
+    use jf_utils::test_rng;
+
+    #[test]
+    fn test_ram() -> Result<(), CircuitError> {
+        test_ram_helper::<FqEd254>()?;
+        test_ram_helper::<FqEd377>()?;
+        test_ram_helper::<FqEd381>()?;
+        test_ram_helper::<Fq377>()
+    }
+
+    fn test_ram_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let mut rng = test_rng();
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+        let mut ram = Ram::new(10);
+        let mut expected = [F::zero(); 8];
+        let mut reads = vec![];
+        for _ in 0..50 {
+            let addr = rng.gen_range(0..8);
+            let addr_var = circuit.create_variable(F::from(addr as u64 * 100))?;
+            if rng.gen_bool(0.5) {
+                let val = F::rand(&mut rng);
+                let val_var = circuit.create_variable(val)?;
+                circuit.ram_write(&mut ram, addr_var, val_var)?;
+                expected[addr] = val;
+            } else {
+                let val_var = circuit.ram_read(&mut ram, addr_var)?;
+                assert_eq!(circuit.witness(val_var)?, expected[addr]);
+                reads.push(val_var);
+            }
+        }
+        assert_eq!(ram.num_accesses(), 50);
+        circuit.enforce_ram_consistency(ram)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        // a read of a wrong value
+        let read = reads[reads.len() / 2];
+        *circuit.witness_mut(read) += F::one();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // a read of an unwritten cell returns zero
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+        let mut ram = Ram::new(4);
+        let addr = circuit.create_variable(F::from(3u8))?;
+        let val = circuit.ram_read(&mut ram, addr)?;
+        assert_eq!(circuit.witness(val)?, F::zero());
+        let val = circuit.create_variable(F::from(7u8))?;
+        circuit.ram_write(&mut ram, addr, val)?;
+        let read = circuit.ram_read(&mut ram, addr)?;
+        circuit.enforce_ram_consistency(ram)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        *circuit.witness_mut(read) = F::zero();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // the accesses are renumbered after an optimization
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+        let mut ram = Ram::new(4);
+        circuit.create_constant_variable(F::from(5u8))?;
+        circuit.create_constant_variable(F::from(5u8))?;
+        let addr = circuit.create_variable(F::from(3u8))?;
+        let a = circuit.create_variable(F::from(2u8))?;
+        let b = circuit.create_variable(F::from(3u8))?;
+        let val = circuit.mul(a, b)?;
+        circuit.ram_write(&mut ram, addr, val)?;
+        let report = circuit.optimize()?;
+        assert_eq!(report.num_vars_removed, 2);
+        ram.renumber_variables(&report)?;
+        let addr = report.new_variable(addr).unwrap();
+        let read = circuit.ram_read(&mut ram, addr)?;
+        assert_eq!(circuit.witness(read)?, F::from(6u8));
+        circuit.enforce_ram_consistency(ram)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        // the value of a pending read is removed
+        let mut ram = Ram::new(4);
+        circuit.ram_read(&mut ram, addr)?;
+        let report = circuit.optimize()?;
+        assert!(ram.renumber_variables(&report).is_err());
+
+        // error paths
+        let mut ram = Ram::new(4);
+        let addr = circuit.create_variable(F::from(16u8))?;
+        assert!(circuit.ram_read(&mut ram, addr).is_err());
+        assert!(circuit.ram_read(&mut ram, circuit.num_vars()).is_err());
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        assert!(circuit.ram_read(&mut ram, circuit.zero()).is_err());
+        Ok(())
+    }
+}
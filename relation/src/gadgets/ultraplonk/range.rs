@@ -14,7 +14,9 @@ use ark_ff::{BigInteger, PrimeField};
 use ark_std::{string::ToString, vec::Vec};
 
 impl<F: PrimeField> PlonkCircuit<F> {
-    /// Constrain a variable to be within the [0, 2^{bit_len}) range
+    /// Constrain a variable to be within the [0, 2^{bit_len}) range, by
+    /// decomposing it into chunks of `RANGE_BIT_LEN` bits, the chunk size
+    /// chosen when creating the circuit, each looked up in the range table.
     /// Return error if one of the following holds:
     /// 1. the variable is invalid;
     /// 2. `RANGE_BIT_LEN` equals zero;
@@ -44,8 +46,16 @@ impl<F: PrimeField> PlonkCircuit<F> {
             self.add_range_check_variable(var)?;
         }
 
+        // the leftover chunk `c` is looked up both as is and shifted to the top
+        // of the table, which bounds `c` by `2^leftover` without decomposing it
+        // into bits: since `c < RANGE_SIZE`, the shifted value doesn't wrap
+        // around the field modulus
         if leftover > 0 {
-            self.range_gate_internal(reprs_le_vars[lookup_len], leftover)?;
+            let var = reprs_le_vars[lookup_len];
+            let shifted =
+                self.mul_constant(var, &F::from(2u8).pow([(range_bit_len - leftover) as u64]))?;
+            self.add_range_check_variable(var)?;
+            self.add_range_check_variable(shifted)?;
         }
 
         // add linear combination gates
@@ -171,6 +181,16 @@ mod test {
         circuit.range_gate_with_lookup(a_var, 2 * bit_len)?;
         assert!(circuit.check_circuit_satisfiability(&[]).is_err());
 
+        // Should fail when the leftover chunk is too large, but not the value
+        // of the largest chunks
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(RANGE_BIT_LEN_FOR_TEST);
+        let a_var = circuit.create_variable(F::from((1u64 << bit_len) - 1))?;
+        circuit.range_gate_with_lookup(a_var, bit_len)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        let b_var = circuit.create_variable(F::from((1u64 << (bit_len + 1)) - 1))?;
+        circuit.range_gate_with_lookup(b_var, bit_len)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
         let zero_var = circuit.zero();
         // bit_len = 0
         assert!(circuit.range_gate_with_lookup(zero_var, 0).is_err());
@@ -184,4 +204,20 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_range_gate_with_lookup_num_gates() -> Result<(), CircuitError> {
+        let mut circuit: PlonkCircuit<FqEd254> = PlonkCircuit::new_ultra_plonk(16);
+        let a = circuit.create_variable(FqEd254::from(u64::MAX))?;
+        let num_gates = circuit.num_gates();
+        circuit.range_gate_with_lookup(a, 64)?;
+        // 4 chunks combined with a single gate
+        assert_eq!(circuit.num_gates() - num_gates, 1);
+        let num_gates = circuit.num_gates();
+        circuit.range_gate_with_lookup(a, 68)?;
+        // 5 chunks combined with 2 gates, and one gate for the shifted chunk
+        assert_eq!(circuit.num_gates() - num_gates, 3);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        Ok(())
+    }
 }
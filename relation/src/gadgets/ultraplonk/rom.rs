@@ -0,0 +1,215 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Read-only memory of constants, e.g. S-boxes or tables of curve points.
+//!
+//! Entry `i` of a [`Rom`] is stored at the key `i` of a lookup table, and each
+//! read is a single lookup of its index and values. The table and the lookups
+//! are only added to the circuit by [`PlonkCircuit::enforce_rom_reads()`],
+//! so that all the reads of a memory share one table.
+
+use crate::{Circuit, CircuitError, OptimizationReport, PlonkCircuit, Variable};
+use ark_ff::PrimeField;
+use ark_std::{format, vec::Vec};
+
+/// A read-only memory of constant entries, each holding a pair of values.
+#[derive(Debug, Clone)]
+pub struct Rom<F: PrimeField> {
+    entries: Vec<(F, F)>,
+    // the `(index, value0, value1)` of each read
+    reads: Vec<(Variable, Variable, Variable)>,
+}
+
+impl<F: PrimeField> Rom<F> {
+    /// Create a memory whose entry `i` is `values[i]`.
+    pub fn new(values: &[F]) -> Self {
+        Self::new_with_pairs(
+            &values
+                .iter()
+                .map(|&val| (val, F::zero()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Create a memory whose entry `i` is the pair of values `entries[i]`.
+    pub fn new_with_pairs(entries: &[(F, F)]) -> Self {
+        Self {
+            entries: entries.to_vec(),
+            reads: Vec::new(),
+        }
+    }
+
+    /// The number of entries of the memory.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the memory has no entry.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Translate the variables of the reads so far after
+    /// [`PlonkCircuit::optimize()`] reported `report`.
+    /// Return error if a variable was removed, e.g. the values of a read, as
+    /// the reads are not enforced yet.
+    pub fn renumber_variables(&mut self, report: &OptimizationReport) -> Result<(), CircuitError> {
+        for (index, val0, val1) in self.reads.iter_mut() {
+            let new_vars = report.new_variables(&[*index, *val0, *val1])?;
+            *index = new_vars[0];
+            *val0 = new_vars[1];
+            *val1 = new_vars[2];
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Return a variable for the entry of `rom` at index `index`, which is
+    /// constrained to be smaller than the number of entries.
+    /// For memories of pairs, this is the first value of the entry.
+    /// The read is only constrained by [`Self::enforce_rom_reads()`].
+    /// Return error if the circuit does not support lookup, the index is out
+    /// of range or the variable is invalid.
+    pub fn rom_read(
+        &mut self,
+        rom: &mut Rom<F>,
+        index: Variable,
+    ) -> Result<Variable, CircuitError> {
+        Ok(self.rom_read_pair(rom, index)?.0)
+    }
+
+    /// Return variables for the pair of values of the entry of `rom` at index
+    /// `index`, which is constrained to be smaller than the number of entries.
+    /// The read is only constrained by [`Self::enforce_rom_reads()`].
+    /// Return error if the circuit does not support lookup, the index is out
+    /// of range or the variable is invalid.
+    pub fn rom_read_pair(
+        &mut self,
+        rom: &mut Rom<F>,
+        index: Variable,
+    ) -> Result<(Variable, Variable), CircuitError> {
+        self.check_var_bound(index)?;
+        if !self.support_lookup() {
+            return Err(CircuitError::LookupUnsupported);
+        }
+        let index_val = self.witness(index)?;
+        let entry = rom
+            .entries
+            .iter()
+            .enumerate()
+            .find(|&(i, _)| F::from(i as u64) == index_val)
+            .map(|(_, &entry)| entry)
+            .ok_or_else(|| {
+                CircuitError::ParameterError(format!(
+                    "the index {} is out of the {} entries",
+                    index_val,
+                    rom.entries.len()
+                ))
+            })?;
+        let val0 = self.create_variable(entry.0)?;
+        let val1 = self.create_variable(entry.1)?;
+        rom.reads.push((index, val0, val1));
+        Ok((val0, val1))
+    }
+
+    /// Add the table of the entries of `rom`, and constrain all its reads to
+    /// return the entries at their indices.
+    /// Return error if the circuit does not support lookup.
+    pub fn enforce_rom_reads(&mut self, rom: Rom<F>) -> Result<(), CircuitError> {
+        if !self.support_lookup() {
+            return Err(CircuitError::LookupUnsupported);
+        }
+        if rom.reads.is_empty() {
+            return Ok(());
+        }
+        let table = rom
+            .entries
+            .iter()
+            .map(|&(val0, val1)| {
+                Ok((
+                    self.create_constant_variable(val0)?,
+                    self.create_constant_variable(val1)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        self.create_table_and_lookup_variables(&rom.reads, &table)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::{rand::Rng, vec::Vec};
+    use jf_utils::test_rng;
+
+    #[test]
+    fn test_rom() -> Result<(), CircuitError> {
+        test_rom_helper::<FqEd254>()?;
+        test_rom_helper::<FqEd377>()?;
+        test_rom_helper::<FqEd381>()?;
+        test_rom_helper::<Fq377>()
+    }
+
+    fn test_rom_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let mut rng = test_rng();
+        let entries: Vec<_> = (0..20)
+            .map(|_| (F::rand(&mut rng), F::rand(&mut rng)))
+            .collect();
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+        let mut rom = Rom::new_with_pairs(&entries);
+        let mut reads = Vec::new();
+        for _ in 0..30 {
+            let i = rng.gen_range(0..entries.len());
+            let index = circuit.create_variable(F::from(i as u64))?;
+            let (val0, val1) = circuit.rom_read_pair(&mut rom, index)?;
+            assert_eq!(circuit.witness(val0)?, entries[i].0);
+            assert_eq!(circuit.witness(val1)?, entries[i].1);
+            reads.push((index, val0));
+        }
+        circuit.enforce_rom_reads(rom)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        // wrong values and index
+        *circuit.witness_mut(reads[0].1) += F::one();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        *circuit.witness_mut(reads[0].1) -= F::one();
+        *circuit.witness_mut(reads[1].0) += F::one();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // single values, as with an S-box
+        let sbox: Vec<_> = (0..16u64).map(|i| F::from((i * 7 + 3) % 16)).collect();
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+        let mut rom = Rom::new(&sbox);
+        assert_eq!(rom.len(), 16);
+        let index = circuit.create_variable(F::from(5u8))?;
+        let val = circuit.rom_read(&mut rom, index)?;
+        assert_eq!(circuit.witness(val)?, F::from(6u8));
+        circuit.enforce_rom_reads(rom)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        // the values of pending reads are removed by an optimization
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+        let mut rom = Rom::new(&sbox);
+        rom.renumber_variables(&circuit.optimize()?)?;
+        let index = circuit.create_variable(F::from(5u8))?;
+        circuit.rom_read(&mut rom, index)?;
+        assert!(rom.renumber_variables(&circuit.optimize()?).is_err());
+
+        // error paths
+        let mut rom = Rom::new(&sbox);
+        let index = circuit.create_variable(F::from(16u8))?;
+        assert!(circuit.rom_read(&mut rom, index).is_err());
+        assert!(circuit.rom_read(&mut rom, circuit.num_vars()).is_err());
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        assert!(circuit.rom_read(&mut rom, circuit.zero()).is_err());
+        Ok(())
+    }
+}
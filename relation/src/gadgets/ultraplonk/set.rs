@@ -0,0 +1,275 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Membership and non-membership in a set of constants, e.g. an allowlist or
+//! a denylist small enough to fit in a lookup table.
+//!
+//! A member is looked up in a table of the elements of the set. A non-member
+//! is looked up in a table of the gaps between the sorted elements, i.e. the
+//! maximal intervals of non-members, and is compared to the bounds of its
+//! gap.
+
+use crate::{Circuit, CircuitError, PlonkCircuit, Variable};
+use ark_ff::PrimeField;
+use ark_std::{string::ToString, vec::Vec};
+
+/// A set of constants fixed by a circuit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommittedSet<F: PrimeField> {
+    // the sorted elements, without duplicates
+    elems: Vec<F>,
+}
+
+impl<F: PrimeField> CommittedSet<F> {
+    /// Create the set of `elems`.
+    pub fn new(elems: &[F]) -> Self {
+        let mut elems = elems.to_vec();
+        elems.sort();
+        elems.dedup();
+        Self { elems }
+    }
+
+    /// The number of elements of the set.
+    pub fn len(&self) -> usize {
+        self.elems.len()
+    }
+
+    /// Whether the set has no element.
+    pub fn is_empty(&self) -> bool {
+        self.elems.is_empty()
+    }
+
+    /// Whether `elem` is in the set.
+    pub fn contains(&self, elem: &F) -> bool {
+        self.elems.binary_search(elem).is_ok()
+    }
+
+    // An element of the set, if any.
+    pub(crate) fn any_member(&self) -> Option<F> {
+        self.elems.first().copied()
+    }
+
+    // An element outside of the set, if any.
+    pub(crate) fn any_non_member(&self) -> Option<F> {
+        self.gaps().first().map(|&(low, _)| low)
+    }
+
+    // The maximal intervals `[low, high]` of elements outside of the set.
+    fn gaps(&self) -> Vec<(F, F)> {
+        let mut gaps = Vec::with_capacity(self.elems.len() + 1);
+        let mut low = Some(F::zero());
+        for &elem in self.elems.iter() {
+            if let Some(low) = low.filter(|&low| low < elem) {
+                gaps.push((low, elem - F::one()));
+            }
+            // no element beyond the largest one
+            low = (elem != -F::one()).then(|| elem + F::one());
+        }
+        if let Some(low) = low {
+            gaps.push((low, -F::one()));
+        }
+        gaps
+    }
+}
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Constrain `var` to be in the set `set`.
+    /// Each call adds a table of the elements of the set, see
+    /// [`Self::enforce_all_in_set()`] to check several variables at once.
+    /// Return error if the circuit does not support lookup, the set is empty
+    /// or the variable is invalid.
+    pub fn enforce_in_set(
+        &mut self,
+        var: Variable,
+        set: &CommittedSet<F>,
+    ) -> Result<(), CircuitError> {
+        self.enforce_all_in_set(&[var], set)
+    }
+
+    /// Constrain all the variables of `vars` to be in the set `set`, with a
+    /// single table of its elements.
+    /// Return error if the circuit does not support lookup, the set is empty
+    /// or the variables are invalid.
+    pub fn enforce_all_in_set(
+        &mut self,
+        vars: &[Variable],
+        set: &CommittedSet<F>,
+    ) -> Result<(), CircuitError> {
+        self.check_vars_bound(vars)?;
+        if !self.support_lookup() {
+            return Err(CircuitError::LookupUnsupported);
+        }
+        if set.is_empty() {
+            return Err(CircuitError::ParameterError(
+                "no variable is in the empty set".to_string(),
+            ));
+        }
+        if vars.is_empty() {
+            return Ok(());
+        }
+        let zero = self.zero();
+        let table = set
+            .elems
+            .iter()
+            .map(|&elem| Ok((self.create_constant_variable(elem)?, zero)))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        let lookups = vars
+            .iter()
+            .map(|&var| {
+                // a non-member leads to an unsatisfied circuit
+                let val = self.witness(var)?;
+                let index = set.elems.binary_search(&val).unwrap_or_default();
+                Ok((self.create_variable(F::from(index as u64))?, var, zero))
+            })
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        self.create_table_and_lookup_variables(&lookups, &table)
+    }
+
+    /// Constrain `var` not to be in the set `set`.
+    /// Each call adds a table of the gaps of the set, see
+    /// [`Self::enforce_none_in_set()`] to check several variables at once.
+    /// Return error if the circuit does not support lookup or the variable is
+    /// invalid.
+    pub fn enforce_not_in_set(
+        &mut self,
+        var: Variable,
+        set: &CommittedSet<F>,
+    ) -> Result<(), CircuitError> {
+        self.enforce_none_in_set(&[var], set)
+    }
+
+    /// Constrain none of the variables of `vars` to be in the set `set`, with
+    /// a single table of the gaps of the set.
+    /// Return error if the circuit does not support lookup or the variables
+    /// are invalid.
+    pub fn enforce_none_in_set(
+        &mut self,
+        vars: &[Variable],
+        set: &CommittedSet<F>,
+    ) -> Result<(), CircuitError> {
+        self.check_vars_bound(vars)?;
+        if !self.support_lookup() {
+            return Err(CircuitError::LookupUnsupported);
+        }
+        if vars.is_empty() || set.is_empty() {
+            return Ok(());
+        }
+        let gaps = set.gaps();
+        let table = gaps
+            .iter()
+            .map(|&(low, high)| {
+                Ok((
+                    self.create_constant_variable(low)?,
+                    self.create_constant_variable(high)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        let mut lookups = Vec::with_capacity(vars.len());
+        for &var in vars.iter() {
+            // a member leads to an unsatisfied circuit
+            let val = self.witness(var)?;
+            let index = gaps
+                .iter()
+                .position(|&(low, high)| low <= val && val <= high)
+                .unwrap_or_default();
+            let low = self.create_variable(gaps[index].0)?;
+            let high = self.create_variable(gaps[index].1)?;
+            self.enforce_leq(low, var)?;
+            self.enforce_leq(var, high)?;
+            lookups.push((self.create_variable(F::from(index as u64))?, low, high));
+        }
+        self.create_table_and_lookup_variables(&lookups, &table)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::vec;
+
+    #[test]
+    fn test_set() -> Result<(), CircuitError> {
+        test_set_helper::<FqEd254>()?;
+        test_set_helper::<FqEd377>()?;
+        test_set_helper::<FqEd381>()?;
+        test_set_helper::<Fq377>()
+    }
+
+    fn test_set_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let set = CommittedSet::new(&[
+            F::from(7u8),
+            F::zero(),
+            F::from(3u8),
+            F::from(4u8),
+            F::from(7u8),
+            -F::from(2u8),
+        ]);
+        assert_eq!(set.len(), 5);
+        assert!(set.contains(&F::from(4u8)) && !set.contains(&F::from(5u8)));
+        assert_eq!(
+            set.gaps(),
+            vec![
+                (F::one(), F::from(2u8)),
+                (F::from(5u8), F::from(6u8)),
+                (F::from(8u8), -F::from(3u8)),
+                (-F::one(), -F::one())
+            ]
+        );
+
+        let members = [F::zero(), F::from(4u8), F::from(7u8), -F::from(2u8)];
+        let non_members = [F::one(), F::from(6u8), F::from(100u8), -F::one()];
+        for (&member_val, &non_member_val) in members.iter().zip(non_members.iter()) {
+            let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+            let member = circuit.create_variable(member_val)?;
+            let non_member = circuit.create_variable(non_member_val)?;
+            circuit.enforce_in_set(member, &set)?;
+            circuit.enforce_not_in_set(non_member, &set)?;
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+            let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+            let member = circuit.create_variable(member_val)?;
+            circuit.enforce_not_in_set(member, &set)?;
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+            let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+            let non_member = circuit.create_variable(non_member_val)?;
+            circuit.enforce_in_set(non_member, &set)?;
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        }
+
+        // several variables sharing a table
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_ultra_plonk(8);
+        let members = members
+            .iter()
+            .map(|&val| circuit.create_variable(val))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        let non_members = non_members
+            .iter()
+            .map(|&val| circuit.create_variable(val))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        circuit.enforce_all_in_set(&members, &set)?;
+        circuit.enforce_none_in_set(&non_members, &set)?;
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        *circuit.witness_mut(members[1]) = F::from(5u8);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        *circuit.witness_mut(members[1]) = F::from(4u8);
+        *circuit.witness_mut(non_members[1]) = F::from(3u8);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // error paths
+        let empty = CommittedSet::new(&[]);
+        assert!(circuit.enforce_in_set(circuit.zero(), &empty).is_err());
+        assert!(circuit.enforce_not_in_set(circuit.zero(), &empty).is_ok());
+        assert!(circuit.enforce_in_set(circuit.num_vars(), &set).is_err());
+        let mut circuit: PlonkCircuit<F> = PlonkCircuit::new_turbo_plonk();
+        assert!(circuit.enforce_in_set(circuit.zero(), &set).is_err());
+        assert!(circuit.enforce_not_in_set(circuit.zero(), &set).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,479 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Arithmetic and bitwise gadgets on unsigned words of up to 64 bits, e.g.
+//! the 32-bit and 64-bit words of hash functions.
+//!
+//! A word is a single variable constrained to its bit length. Additions,
+//! subtractions, shifts and rotations only split or recombine words with range
+//! checks. On circuits supporting lookup, XOR and AND look up the words 2 bits
+//! at a time in a table of 16 rows created once per gadget call; otherwise
+//! the words are decomposed into bits.
+
+use crate::{BoolVar, Circuit, CircuitError, PlonkCircuit, Variable};
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::{format, vec, vec::Vec};
+
+/// A variable for an unsigned word of `N` bits, with `N` even and at most 64.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordVar<const N: usize>(pub(crate) Variable);
+
+/// A variable for a 32-bit unsigned word.
+pub type U32Var = WordVar<32>;
+
+/// A variable for a 64-bit unsigned word.
+pub type U64Var = WordVar<64>;
+
+impl<const N: usize> From<WordVar<N>> for Variable {
+    fn from(w: WordVar<N>) -> Self {
+        w.0
+    }
+}
+
+/// The bitwise operations looked up in the table of a gadget call.
+#[derive(Clone, Copy)]
+enum BitwiseOp {
+    Xor,
+    And,
+}
+
+impl<F: PrimeField> PlonkCircuit<F> {
+    /// Return the witness of a word variable.
+    pub fn word_witness<const N: usize>(&self, a: WordVar<N>) -> Result<u64, CircuitError> {
+        Ok(self.witness(a.0)?.into_bigint().as_ref()[0])
+    }
+
+    /// Add a variable for the word `val`, constrained to `N` bits.
+    /// Return error if `N` is not valid or `val` has more than `N` bits.
+    pub fn create_word_variable<const N: usize>(
+        &mut self,
+        val: u64,
+    ) -> Result<WordVar<N>, CircuitError> {
+        check_word_value::<N>(val)?;
+        let var = self.create_variable(F::from(val))?;
+        self.enforce_in_range(var, N)?;
+        Ok(WordVar(var))
+    }
+
+    /// Add a constant variable for the word `val`.
+    /// Return error if `N` is not valid or `val` has more than `N` bits.
+    pub fn create_constant_word_variable<const N: usize>(
+        &mut self,
+        val: u64,
+    ) -> Result<WordVar<N>, CircuitError> {
+        check_word_value::<N>(val)?;
+        Ok(WordVar(self.create_constant_variable(F::from(val))?))
+    }
+
+    /// Convert a field variable into a word, constraining it to `N` bits.
+    /// Return error if `N` is not valid, the variable is invalid or its value
+    /// has more than `N` bits.
+    pub fn word_from_variable<const N: usize>(
+        &mut self,
+        var: Variable,
+    ) -> Result<WordVar<N>, CircuitError> {
+        check_word_length::<N>()?;
+        self.check_var_bound(var)?;
+        if self.witness(var)?.into_bigint().num_bits() as usize > N {
+            return Err(CircuitError::ParameterError(format!(
+                "the variable does not fit in {N} bits"
+            )));
+        }
+        self.enforce_in_range(var, N)?;
+        Ok(WordVar(var))
+    }
+
+    /// Return `(a + b + carry_in) mod 2^N` and the carry out.
+    /// Return error if the input variables are invalid.
+    pub fn word_add_with_carry<const N: usize>(
+        &mut self,
+        a: WordVar<N>,
+        b: WordVar<N>,
+        carry_in: BoolVar,
+    ) -> Result<(WordVar<N>, BoolVar), CircuitError> {
+        self.check_var_bound(carry_in.into())?;
+        let sum = self.word_witness(a)? as u128
+            + self.word_witness(b)? as u128
+            + self.witness(carry_in.into())?.into_bigint().as_ref()[0] as u128;
+        let carry_out = self.create_boolean_variable(sum >> N == 1)?;
+        let out = self.create_variable(F::from((sum % (1u128 << N)) as u64))?;
+        self.enforce_in_range(out, N)?;
+        // a + b + carry_in = out + 2^N * carry_out
+        self.lc_gate(
+            &[a.0, b.0, carry_in.into(), carry_out.into(), out],
+            &[F::one(), F::one(), F::one(), -pow2::<F>(N)],
+        )?;
+        Ok((WordVar(out), carry_out))
+    }
+
+    /// Return `(a + b) mod 2^N`.
+    /// Return error if the input variables are invalid.
+    pub fn word_add<const N: usize>(
+        &mut self,
+        a: WordVar<N>,
+        b: WordVar<N>,
+    ) -> Result<WordVar<N>, CircuitError> {
+        let zero = BoolVar(self.zero());
+        Ok(self.word_add_with_carry(a, b, zero)?.0)
+    }
+
+    /// Return `(a - b - borrow_in) mod 2^N` and the borrow out.
+    /// Return error if the input variables are invalid.
+    pub fn word_sub_with_borrow<const N: usize>(
+        &mut self,
+        a: WordVar<N>,
+        b: WordVar<N>,
+        borrow_in: BoolVar,
+    ) -> Result<(WordVar<N>, BoolVar), CircuitError> {
+        self.check_var_bound(borrow_in.into())?;
+        let diff = self.word_witness(a)? as i128
+            - self.word_witness(b)? as i128
+            - self.witness(borrow_in.into())?.into_bigint().as_ref()[0] as i128;
+        let borrow_out = self.create_boolean_variable(diff < 0)?;
+        let out = self.create_variable(F::from(diff.rem_euclid(1i128 << N) as u64))?;
+        self.enforce_in_range(out, N)?;
+        // a - b - borrow_in + 2^N * borrow_out = out
+        self.lc_gate(
+            &[a.0, b.0, borrow_in.into(), borrow_out.into(), out],
+            &[F::one(), -F::one(), -F::one(), pow2::<F>(N)],
+        )?;
+        Ok((WordVar(out), borrow_out))
+    }
+
+    /// Return `(a - b) mod 2^N`.
+    /// Return error if the input variables are invalid.
+    pub fn word_sub<const N: usize>(
+        &mut self,
+        a: WordVar<N>,
+        b: WordVar<N>,
+    ) -> Result<WordVar<N>, CircuitError> {
+        let zero = BoolVar(self.zero());
+        Ok(self.word_sub_with_borrow(a, b, zero)?.0)
+    }
+
+    /// Return `a` rotated right by `n` bits.
+    /// Return error if the input variable is invalid.
+    pub fn word_rotr<const N: usize>(
+        &mut self,
+        a: WordVar<N>,
+        n: usize,
+    ) -> Result<WordVar<N>, CircuitError> {
+        let n = n % N;
+        if n == 0 {
+            self.check_var_bound(a.0)?;
+            return Ok(a);
+        }
+        let (lo, hi) = self.word_split(a, n)?;
+        // hi + 2^(N - n) * lo
+        let zero = self.zero();
+        let out = self.lc(
+            &[hi, lo, zero, zero],
+            &[F::one(), pow2::<F>(N - n), F::zero(), F::zero()],
+        )?;
+        Ok(WordVar(out))
+    }
+
+    /// Return `a` rotated left by `n` bits.
+    /// Return error if the input variable is invalid.
+    pub fn word_rotl<const N: usize>(
+        &mut self,
+        a: WordVar<N>,
+        n: usize,
+    ) -> Result<WordVar<N>, CircuitError> {
+        self.word_rotr(a, N - n % N)
+    }
+
+    /// Return `a >> n`, which is zero if `n >= N`.
+    /// Return error if the input variable is invalid.
+    pub fn word_shr<const N: usize>(
+        &mut self,
+        a: WordVar<N>,
+        n: usize,
+    ) -> Result<WordVar<N>, CircuitError> {
+        self.check_var_bound(a.0)?;
+        match n {
+            0 => Ok(a),
+            n if n >= N => Ok(WordVar(self.zero())),
+            n => Ok(WordVar(self.word_split(a, n)?.1)),
+        }
+    }
+
+    /// Return `(a << n) mod 2^N`, which is zero if `n >= N`.
+    /// Return error if the input variable is invalid.
+    pub fn word_shl<const N: usize>(
+        &mut self,
+        a: WordVar<N>,
+        n: usize,
+    ) -> Result<WordVar<N>, CircuitError> {
+        self.check_var_bound(a.0)?;
+        match n {
+            0 => Ok(a),
+            n if n >= N => Ok(WordVar(self.zero())),
+            n => {
+                let (lo, _) = self.word_split(a, N - n)?;
+                Ok(WordVar(self.mul_constant(lo, &pow2::<F>(n))?))
+            },
+        }
+    }
+
+    /// Return `!a`, the bitwise complement of `a`.
+    /// Return error if the input variable is invalid.
+    pub fn word_not<const N: usize>(&mut self, a: WordVar<N>) -> Result<WordVar<N>, CircuitError> {
+        // 2^N - 1 - a
+        let out = self.lin_comb_with_constant(&[(-F::one(), a.0)], pow2::<F>(N) - F::one())?;
+        Ok(WordVar(out))
+    }
+
+    /// Return `a ^ b`.
+    /// Return error if the input variables are invalid.
+    pub fn word_xor<const N: usize>(
+        &mut self,
+        a: WordVar<N>,
+        b: WordVar<N>,
+    ) -> Result<WordVar<N>, CircuitError> {
+        self.word_bitwise(a, b, BitwiseOp::Xor)
+    }
+
+    /// Return `a & b`.
+    /// Return error if the input variables are invalid.
+    pub fn word_and<const N: usize>(
+        &mut self,
+        a: WordVar<N>,
+        b: WordVar<N>,
+    ) -> Result<WordVar<N>, CircuitError> {
+        self.word_bitwise(a, b, BitwiseOp::And)
+    }
+
+    // Split `a` into `(lo, hi)` with `a = lo + 2^n * hi`, `lo` of `n` bits and
+    // `hi` of `N - n` bits, for `0 < n < N`.
+    fn word_split<const N: usize>(
+        &mut self,
+        a: WordVar<N>,
+        n: usize,
+    ) -> Result<(Variable, Variable), CircuitError> {
+        self.check_var_bound(a.0)?;
+        let val = self.word_witness(a)?;
+        let lo = self.create_variable(F::from(val & ((1u64 << n) - 1)))?;
+        let hi = self.create_variable(F::from(val >> n))?;
+        self.enforce_in_range(lo, n)?;
+        self.enforce_in_range(hi, N - n)?;
+        let zero = self.zero();
+        self.lc_gate(
+            &[lo, hi, zero, zero, a.0],
+            &[F::one(), pow2::<F>(n), F::zero(), F::zero()],
+        )?;
+        Ok((lo, hi))
+    }
+
+    fn word_bitwise<const N: usize>(
+        &mut self,
+        a: WordVar<N>,
+        b: WordVar<N>,
+        op: BitwiseOp,
+    ) -> Result<WordVar<N>, CircuitError> {
+        self.check_var_bound(a.0)?;
+        self.check_var_bound(b.0)?;
+        let zero = self.zero();
+        if !self.support_lookup() {
+            let a_bits = self.unpack(a.0, N)?;
+            let b_bits = self.unpack(b.0, N)?;
+            let terms = a_bits
+                .iter()
+                .zip(b_bits.iter())
+                .enumerate()
+                .map(|(i, (&a, &b))| {
+                    let bit = match op {
+                        // a + b - 2ab
+                        BitwiseOp::Xor => self.gen_quad_poly(
+                            &[a.0, b.0, zero, zero],
+                            &[F::one(), F::one(), F::zero(), F::zero()],
+                            &[-F::from(2u8), F::zero()],
+                            F::zero(),
+                        )?,
+                        BitwiseOp::And => self.mul(a.0, b.0)?,
+                    };
+                    Ok((pow2::<F>(i), bit))
+                })
+                .collect::<Result<Vec<_>, CircuitError>>()?;
+            return Ok(WordVar(self.lin_comb_with_constant(&terms, F::zero())?));
+        }
+
+        // table row `x + 4 * y` is `(x ^ y, x & y)` for 2-bit `x` and `y`, so
+        // that looking up `(x, x, 0)` constrains `x` to 2 bits
+        let crumbs: Vec<_> = (0..4u8)
+            .map(|i| self.create_constant_variable(F::from(i)))
+            .collect::<Result<_, _>>()?;
+        let mut lookups = vec![];
+        let mut to_crumbs =
+            |circuit: &mut Self, w: WordVar<N>| -> Result<Vec<Variable>, CircuitError> {
+                let val = circuit.word_witness(w)?;
+                let vars = (0..N / 2)
+                    .map(|i| circuit.create_variable(F::from(val >> (2 * i) & 3)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let terms: Vec<_> = vars
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &v)| (pow2::<F>(2 * i), v))
+                    .collect();
+                let sum = circuit.lin_comb_with_constant(&terms, F::zero())?;
+                circuit.enforce_equal(sum, w.0)?;
+                for &v in vars.iter() {
+                    lookups.push((v, v, zero));
+                }
+                Ok(vars)
+            };
+        let a_crumbs = to_crumbs(self, a)?;
+        let b_crumbs = to_crumbs(self, b)?;
+        let mut terms = vec![];
+        for (i, (&x, &y)) in a_crumbs.iter().zip(b_crumbs.iter()).enumerate() {
+            let key = self.lc(
+                &[x, y, zero, zero],
+                &[F::one(), F::from(4u8), F::zero(), F::zero()],
+            )?;
+            let (x_val, y_val) = (
+                self.word_witness(WordVar::<N>(x))?,
+                self.word_witness(WordVar::<N>(y))?,
+            );
+            let xor = self.create_variable(F::from(x_val ^ y_val))?;
+            let and = self.create_variable(F::from(x_val & y_val))?;
+            lookups.push((key, xor, and));
+            let out = match op {
+                BitwiseOp::Xor => xor,
+                BitwiseOp::And => and,
+            };
+            terms.push((pow2::<F>(2 * i), out));
+        }
+        let table: Vec<_> = (0..16)
+            .map(|i| (crumbs[(i % 4) ^ (i / 4)], crumbs[(i % 4) & (i / 4)]))
+            .collect();
+        self.create_table_and_lookup_variables(&lookups, &table)?;
+        Ok(WordVar(self.lin_comb_with_constant(&terms, F::zero())?))
+    }
+}
+
+fn pow2<F: PrimeField>(n: usize) -> F {
+    F::from(2u8).pow([n as u64])
+}
+
+fn check_word_length<const N: usize>() -> Result<(), CircuitError> {
+    if N == 0 || N > 64 || N % 2 != 0 {
+        return Err(CircuitError::ParameterError(format!(
+            "words of {N} bits are not supported"
+        )));
+    }
+    Ok(())
+}
+
+fn check_word_value<const N: usize>(val: u64) -> Result<(), CircuitError> {
+    check_word_length::<N>()?;
+    if N < 64 && val >> N != 0 {
+        return Err(CircuitError::ParameterError(format!(
+            "{val} does not fit in {N} bits"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::rand::Rng;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_word_arithmetic() -> Result<(), CircuitError> {
+        test_word_arithmetic_helper::<FqEd254>()?;
+        test_word_arithmetic_helper::<FqEd377>()?;
+        test_word_arithmetic_helper::<FqEd381>()?;
+        test_word_arithmetic_helper::<Fq377>()
+    }
+
+    fn test_word_arithmetic_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let rng = &mut jf_utils::test_rng();
+        for mut circuit in [
+            PlonkCircuit::<F>::new_turbo_plonk(),
+            PlonkCircuit::<F>::new_ultra_plonk(8),
+        ] {
+            let (x, y) = (u32::rand(rng), u32::rand(rng));
+            let a = circuit.create_word_variable::<32>(x as u64)?;
+            let b = circuit.create_word_variable::<32>(y as u64)?;
+            let n = rng.gen_range(1..32);
+            let one = circuit.true_var();
+            let (sum, carry) = circuit.word_add_with_carry(a, b, one)?;
+            let (diff, borrow) = circuit.word_sub_with_borrow(a, b, one)?;
+            let expected = [
+                (circuit.word_add(a, b)?, x.wrapping_add(y)),
+                (circuit.word_sub(a, b)?, x.wrapping_sub(y)),
+                (sum, x.wrapping_add(y).wrapping_add(1)),
+                (diff, x.wrapping_sub(y).wrapping_sub(1)),
+                (circuit.word_rotr(a, n)?, x.rotate_right(n as u32)),
+                (circuit.word_rotl(a, n)?, x.rotate_left(n as u32)),
+                (circuit.word_shr(a, n)?, x >> n),
+                (circuit.word_shl(a, n)?, x << n),
+                (circuit.word_shr(a, 32)?, 0),
+                (circuit.word_not(a)?, !x),
+                (circuit.word_xor(a, b)?, x ^ y),
+                (circuit.word_and(a, b)?, x & y),
+            ];
+            for (var, val) in expected {
+                assert_eq!(circuit.word_witness(var)?, val as u64);
+            }
+            assert_eq!(
+                circuit.witness(carry.into())?,
+                F::from((x as u64 + y as u64 + 1) >> 32)
+            );
+            assert_eq!(
+                circuit.witness(borrow.into())?,
+                F::from((x as u64) < y as u64 + 1)
+            );
+
+            // 64-bit words
+            let (x, y) = (u64::MAX - u32::rand(rng) as u64, u64::rand(rng));
+            let a = circuit.create_word_variable::<64>(x)?;
+            let b = circuit.create_word_variable::<64>(y)?;
+            let expected = [
+                (circuit.word_add(a, b)?, x.wrapping_add(y)),
+                (circuit.word_sub(b, a)?, y.wrapping_sub(x)),
+                (circuit.word_rotr(a, 61)?, x.rotate_right(61)),
+                (circuit.word_shl(a, 3)?, x << 3),
+                (circuit.word_xor(a, b)?, x ^ y),
+                (circuit.word_and(a, b)?, x & y),
+            ];
+            for (var, val) in expected {
+                assert_eq!(circuit.word_witness(var)?, val);
+            }
+            let var = circuit.create_variable(F::from(y))?;
+            let c = circuit.word_from_variable::<64>(var)?;
+            let d = circuit.word_xor(c, b)?;
+            assert_eq!(circuit.word_witness(d)?, 0);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+            // wrong outputs
+            let e = circuit.word_xor(a, b)?;
+            *circuit.witness_mut(e.0) = F::from(x & y);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+            *circuit.witness_mut(e.0) = F::from(x ^ y);
+            *circuit.witness_mut(sum.0) += F::from(1u64 << 32);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+            *circuit.witness_mut(sum.0) -= F::from(1u64 << 32);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+            // bad inputs
+            assert!(circuit.create_word_variable::<32>(1 << 32).is_err());
+            assert!(circuit.create_word_variable::<31>(1).is_err());
+            let var = circuit.create_variable(F::from(1u64 << 32))?;
+            assert!(circuit.word_from_variable::<32>(var).is_err());
+            let c = circuit.create_word_variable::<32>(1)?;
+            let bad = WordVar::<32>(circuit.num_vars());
+            assert!(circuit.word_add(c, bad).is_err());
+        }
+        Ok(())
+    }
+}
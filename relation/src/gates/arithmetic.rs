@@ -231,6 +231,40 @@ where
     }
 }
 
+/// A deg-5 polynomial gate without elliptic curve term, e.g. a gate whose
+/// constant wires are folded into its selectors
+#[derive(Clone)]
+pub struct FoldedGate<F: Field> {
+    pub(crate) q_lc: [F; GATE_WIDTH],
+    pub(crate) q_mul: [F; N_MUL_SELECTORS],
+    pub(crate) q_hash: [F; GATE_WIDTH],
+    pub(crate) q_o: F,
+    pub(crate) q_c: F,
+}
+impl<F> Gate<F> for FoldedGate<F>
+where
+    F: Field,
+{
+    fn name(&self) -> &'static str {
+        "Folded Gate"
+    }
+    fn q_lc(&self) -> [F; GATE_WIDTH] {
+        self.q_lc
+    }
+    fn q_mul(&self) -> [F; N_MUL_SELECTORS] {
+        self.q_mul
+    }
+    fn q_hash(&self) -> [F; GATE_WIDTH] {
+        self.q_hash
+    }
+    fn q_o(&self) -> F {
+        self.q_o
+    }
+    fn q_c(&self) -> F {
+        self.q_c
+    }
+}
+
 /// A linear combination gate
 #[derive(Clone)]
 pub struct LinCombGate<F: Field> {
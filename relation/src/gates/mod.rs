@@ -24,7 +24,10 @@ pub use logic::*;
 pub use lookup::*;
 
 /// Describes a gate with getter for all selectors configuration
-pub trait Gate<F: Field>: Downcast + DynClone {
+///
+/// Gates are `Send + Sync` so that circuits can be shared across the threads
+/// proving them.
+pub trait Gate<F: Field>: Downcast + DynClone + Send + Sync {
     /// Get the name of a gate.
     fn name(&self) -> &'static str;
     /// Selectors for linear combination.
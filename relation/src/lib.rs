@@ -9,11 +9,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 pub mod constants;
+pub mod frontend;
 pub mod gadgets;
 pub mod gates;
 
 pub mod constraint_system;
 pub use constraint_system::*;
+pub mod witness;
+pub use witness::*;
 
 use ark_std::string::String;
 use displaydoc::Display;
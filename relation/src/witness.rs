@@ -0,0 +1,198 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Serialization of the witness of a circuit, separately from its
+//! description.
+//!
+//! A client can build a circuit, hand its [`WitnessAssignment`] over to a
+//! prover service, which loads it into its own copy of the same circuit with
+//! [`PlonkCircuit::load_witness_assignment()`] and proves it against a
+//! proving key preprocessed once and for all.
+
+use crate::{
+    CircuitError::{self, ParameterError},
+    PlonkCircuit,
+};
+use ark_ff::{FftField, Field};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::{format, string::ToString, vec::Vec};
+
+/// The version of the serialization format of [`WitnessAssignment`],
+/// written as its first byte.
+pub const WITNESS_FORMAT_VERSION: u8 = 1;
+
+/// The values of all the variables of a circuit, including its public
+/// inputs, in the order of their creation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WitnessAssignment<F: Field> {
+    values: Vec<F>,
+}
+
+impl<F: Field> WitnessAssignment<F> {
+    /// The value of each variable.
+    pub fn values(&self) -> &[F] {
+        &self.values
+    }
+
+    /// The number of variables.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether there is no variable.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl<F: Field> CanonicalSerialize for WitnessAssignment<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        WITNESS_FORMAT_VERSION.serialize_with_mode(&mut writer, compress)?;
+        self.values.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        WITNESS_FORMAT_VERSION.serialized_size(compress) + self.values.serialized_size(compress)
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for WitnessAssignment<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let version = u8::deserialize_with_mode(&mut reader, compress, validate)?;
+        if version != WITNESS_FORMAT_VERSION {
+            return Err(SerializationError::InvalidData);
+        }
+        let values = Vec::<F>::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self { values })
+    }
+}
+
+impl<F: Field> Valid for WitnessAssignment<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.values.check()
+    }
+}
+
+impl<F: FftField> PlonkCircuit<F> {
+    /// Export the values of all the variables of the circuit.
+    pub fn witness_assignment(&self) -> WitnessAssignment<F> {
+        WitnessAssignment {
+            values: self.witness_values().to_vec(),
+        }
+    }
+
+    /// Replace the values of all the variables of the circuit with
+    /// `witness`, e.g. exported from another instance of the same circuit.
+    /// This can be done after the circuit is finalized, as the witness does
+    /// not change its arithmetization.
+    ///
+    /// Return an error if `witness` does not have one value per variable or
+    /// does not assign the constant variables `0` and `1` correctly. The
+    /// circuit is not checked to be satisfied.
+    pub fn load_witness_assignment(
+        &mut self,
+        witness: &WitnessAssignment<F>,
+    ) -> Result<(), CircuitError> {
+        let values = self.witness_values_mut();
+        if witness.len() != values.len() {
+            return Err(ParameterError(format!(
+                "the witness assigns {} variables, the circuit has {}",
+                witness.len(),
+                values.len()
+            )));
+        }
+        if witness.values[..2] != values[..2] {
+            return Err(ParameterError(
+                "the witness does not assign the constant variables".to_string(),
+            ));
+        }
+        values.copy_from_slice(&witness.values);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Arithmetization, Circuit};
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_ff::PrimeField;
+    use ark_std::vec;
+
+    #[test]
+    fn test_witness_assignment() -> Result<(), CircuitError> {
+        test_witness_assignment_helper::<FqEd254>()?;
+        test_witness_assignment_helper::<FqEd377>()?;
+        test_witness_assignment_helper::<FqEd381>()?;
+        test_witness_assignment_helper::<Fq377>()
+    }
+
+    // proves the knowledge of `a`, `b` such that `(a + b) * a = c` for a public
+    // `c`
+    fn gen_circuit<F: PrimeField>(a: u32, b: u32) -> Result<PlonkCircuit<F>, CircuitError> {
+        let mut circuit = PlonkCircuit::new_turbo_plonk();
+        let a_var = circuit.create_variable(F::from(a))?;
+        let b_var = circuit.create_variable(F::from(b))?;
+        let c_var = circuit.create_public_variable(F::from((a + b) * a))?;
+        let sum = circuit.add(a_var, b_var)?;
+        circuit.mul_gate(sum, a_var, c_var)?;
+        circuit.finalize_for_arithmetization()?;
+        Ok(circuit)
+    }
+
+    fn test_witness_assignment_helper<F: PrimeField>() -> Result<(), CircuitError> {
+        let client_circuit = gen_circuit::<F>(3, 4)?;
+        let mut bytes = Vec::new();
+        client_circuit
+            .witness_assignment()
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        assert_eq!(bytes[0], WITNESS_FORMAT_VERSION);
+
+        // the prover loads the witness into its copy of the circuit
+        let mut prover_circuit = gen_circuit::<F>(0, 0)?;
+        let witness = WitnessAssignment::<F>::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(witness, client_circuit.witness_assignment());
+        prover_circuit.load_witness_assignment(&witness)?;
+        assert_eq!(prover_circuit.public_input()?, vec![F::from(21u32)]);
+        prover_circuit.check_circuit_satisfiability(&[F::from(21u32)])?;
+        assert_eq!(
+            prover_circuit.compute_wire_polynomials()?,
+            client_circuit.compute_wire_polynomials()?
+        );
+
+        // unknown version
+        bytes[0] += 1;
+        assert!(WitnessAssignment::<F>::deserialize_compressed(&bytes[..]).is_err());
+
+        // wrong number of variables or constants
+        let mut bad_witness = witness.clone();
+        bad_witness.values.pop();
+        assert!(prover_circuit
+            .load_witness_assignment(&bad_witness)
+            .is_err());
+        let mut bad_witness = witness;
+        bad_witness.values[1] = F::zero();
+        assert!(prover_circuit
+            .load_witness_assignment(&bad_witness)
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! A rescue authenticated encryption scheme with associated data (AEAD),
+//! based on a keyed duplex sponge.
+
+use crate::{ct_eq, sponge::RescueDuplexSponge, RescueError, RescueParameter, CRHF_RATE};
+use ark_std::{marker::PhantomData, string::ToString, vec::Vec};
+
+/// Domain-separation tag of the sponge underlying [`RescueAEAD`].
+pub(crate) const AEAD_DOMAIN_SEPARATOR: &[u8] = b"JF-RESCUE-AEAD";
+
+/// Authenticated encryption of field elements.
+///
+/// A [`RescueDuplexSponge`] of rate [`CRHF_RATE`], initialized with an
+/// AEAD-specific domain-separation tag, absorbs the key and the nonce, then
+/// the associated data. Each plaintext element is then added to an element
+/// squeezed from the sponge to get the ciphertext, and absorbed in turn, so
+/// that the rate holds the ciphertext before the next permutation. The tag is
+/// finally squeezed from the sponge. Phases are closed by
+/// [`RescueDuplexSponge::separate`].
+///
+/// A nonce should never be reused under the same key.
+#[derive(Debug, Clone)]
+pub struct RescueAEAD<F: RescueParameter>(PhantomData<F>);
+
+impl<F: RescueParameter> RescueAEAD<F> {
+    /// Encrypt `plaintext` with associated data `ad` under `key` and `nonce`.
+    /// Return the ciphertext, of the same length as `plaintext`, and the tag.
+    pub fn encrypt(key: &F, nonce: &F, ad: &[F], plaintext: &[F]) -> (Vec<F>, F) {
+        let mut sponge = Self::init_sponge(key, nonce, ad);
+        let mut ciphertext = Vec::with_capacity(plaintext.len());
+        for chunk in plaintext.chunks(CRHF_RATE) {
+            let keystream = sponge.squeeze(chunk.len());
+            ciphertext.extend(chunk.iter().zip(keystream.iter()).map(|(p, k)| *p + k));
+            sponge.absorb(chunk);
+        }
+        sponge.separate(F::zero());
+        (ciphertext, sponge.squeeze(1)[0])
+    }
+
+    /// Decrypt `ciphertext` with associated data `ad` under `key` and `nonce`.
+    /// Return an error if `tag` is invalid, the comparison of tags running in
+    /// constant time.
+    pub fn decrypt(
+        key: &F,
+        nonce: &F,
+        ad: &[F],
+        ciphertext: &[F],
+        tag: &F,
+    ) -> Result<Vec<F>, RescueError> {
+        let mut sponge = Self::init_sponge(key, nonce, ad);
+        let mut plaintext = Vec::with_capacity(ciphertext.len());
+        for chunk in ciphertext.chunks(CRHF_RATE) {
+            let keystream = sponge.squeeze(chunk.len());
+            let plain_chunk: Vec<F> = chunk
+                .iter()
+                .zip(keystream.iter())
+                .map(|(c, k)| *c - k)
+                .collect();
+            sponge.absorb(&plain_chunk);
+            plaintext.extend(plain_chunk);
+        }
+        sponge.separate(F::zero());
+
+        if ct_eq(&sponge.squeeze(1)[0], tag) {
+            Ok(plaintext)
+        } else {
+            Err(RescueError::VerificationError(
+                "Rescue AEAD Error: invalid tag.".to_string(),
+            ))
+        }
+    }
+
+    fn init_sponge(key: &F, nonce: &F, ad: &[F]) -> RescueDuplexSponge<F, CRHF_RATE> {
+        let domain_tag = F::from_le_bytes_mod_order(AEAD_DOMAIN_SEPARATOR);
+        // `RescueDuplexSponge::new` only fails on a bad rate
+        let mut sponge = RescueDuplexSponge::new(domain_tag).unwrap();
+        sponge.absorb(&[*key, *nonce]);
+        sponge.separate(F::zero());
+        sponge.absorb(ad);
+        sponge.separate(F::zero());
+        sponge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RescueAEAD;
+    use crate::RescueParameter;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::{vec::Vec, UniformRand};
+
+    #[test]
+    fn test_aead() {
+        test_aead_helper::<FqEd254>();
+        test_aead_helper::<FqEd377>();
+        test_aead_helper::<FqEd381>();
+        test_aead_helper::<Fq377>();
+    }
+
+    fn test_aead_helper<F: RescueParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let key = F::rand(&mut rng);
+        let nonce = F::rand(&mut rng);
+        let ad: Vec<F> = (0..2).map(|_| F::rand(&mut rng)).collect();
+        for len in [0, 1, 3, 7] {
+            let plaintext: Vec<F> = (0..len).map(|_| F::rand(&mut rng)).collect();
+            let (ciphertext, tag) = RescueAEAD::encrypt(&key, &nonce, &ad, &plaintext);
+            assert_eq!(ciphertext.len(), len);
+            if len > 0 {
+                assert_ne!(ciphertext, plaintext);
+            }
+            assert_eq!(
+                RescueAEAD::decrypt(&key, &nonce, &ad, &ciphertext, &tag).unwrap(),
+                plaintext
+            );
+
+            // a different nonce yields a different encryption
+            let (ciphertext2, tag2) =
+                RescueAEAD::encrypt(&key, &(nonce + F::one()), &ad, &plaintext);
+            assert_ne!(tag, tag2);
+            if len > 0 {
+                assert_ne!(ciphertext, ciphertext2);
+            }
+
+            // wrong key, nonce, associated data, ciphertext or tag
+            assert!(
+                RescueAEAD::decrypt(&(key + F::one()), &nonce, &ad, &ciphertext, &tag).is_err()
+            );
+            assert!(
+                RescueAEAD::decrypt(&key, &(nonce + F::one()), &ad, &ciphertext, &tag).is_err()
+            );
+            assert!(RescueAEAD::decrypt(&key, &nonce, &ad[..1], &ciphertext, &tag).is_err());
+            assert!(
+                RescueAEAD::decrypt(&key, &nonce, &ad, &ciphertext, &(tag + F::one())).is_err()
+            );
+            let mut bad_ciphertext = ciphertext.clone();
+            bad_ciphertext.push(F::zero());
+            assert!(RescueAEAD::decrypt(&key, &nonce, &ad, &bad_ciphertext, &tag).is_err());
+            if len > 0 {
+                bad_ciphertext = ciphertext.clone();
+                bad_ciphertext[len - 1] += F::one();
+                assert!(RescueAEAD::decrypt(&key, &nonce, &ad, &bad_ciphertext, &tag).is_err());
+            }
+        }
+    }
+}
@@ -6,10 +6,14 @@
 
 //! Implements a rescue hash based commitment scheme.
 
-use crate::{crhf::FixedLengthRescueCRHF, RescueError, RescueParameter};
-use ark_std::{borrow::Borrow, marker::PhantomData, string::ToString};
+use crate::{crhf::RescueCRHF, ct_eq, prf::RescuePRF, RescueError, RescueParameter};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{borrow::Borrow, marker::PhantomData, string::ToString, vec, vec::Vec};
 use jf_commitment::CommitmentScheme;
-use jf_crhf::CRHF;
+use jf_prf::PRF;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use zeroize::Zeroize;
 
 /// Glorified bool type;
 type VerificationResult = Result<(), ()>;
@@ -29,6 +33,114 @@ pub struct FixedLengthRescueCommitment<
     const INPUT_LEN_PLUS_ONE: usize,
 >(PhantomData<F>);
 
+impl<F: RescueParameter, const INPUT_LEN: usize, const INPUT_LEN_PLUS_ONE: usize>
+    FixedLengthRescueCommitment<F, INPUT_LEN, INPUT_LEN_PLUS_ONE>
+{
+    /// Similar to [`CommitmentScheme::commit`] except the domain-separation
+    /// tag `tag` is absorbed first by the underlying hash, see
+    /// [`RescueCRHF::tagged_sponge_with_bit_padding`], so that commitments
+    /// computed for different purposes can't be confused. A zero tag yields
+    /// the untagged commitment.
+    pub fn commit_with_tag(
+        tag: &F,
+        input: &[F; INPUT_LEN],
+        r: Option<&F>,
+    ) -> Result<F, RescueError> {
+        let mut msg = [F::zero(); INPUT_LEN_PLUS_ONE];
+        msg[0] = *r.ok_or_else(|| {
+            RescueError::ParameterError("Expecting a blinding factor".to_string())
+        })?;
+        msg[1..INPUT_LEN_PLUS_ONE].copy_from_slice(&input[..(INPUT_LEN)]);
+
+        Ok(RescueCRHF::tagged_sponge_with_zero_padding(tag, &msg, 1)[0])
+    }
+
+    /// Similar to [`CommitmentScheme::verify`] for commitments computed with
+    /// [`Self::commit_with_tag`].
+    pub fn verify_with_tag(
+        tag: &F,
+        input: &[F; INPUT_LEN],
+        r: Option<&F>,
+        comm: &F,
+    ) -> Result<VerificationResult, RescueError> {
+        if ct_eq(&Self::commit_with_tag(tag, input, r)?, comm) {
+            Ok(Ok(()))
+        } else {
+            Ok(Err(()))
+        }
+    }
+}
+
+/// Opening of a [`FixedLengthRescueCommitment`]: the committed message and
+/// the blinding factor.
+#[derive(Debug, Clone, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize, Zeroize)]
+pub struct RescueCommitmentOpening<F: RescueParameter, const INPUT_LEN: usize> {
+    /// The committed message
+    pub message: [F; INPUT_LEN],
+    /// The blinding factor
+    pub blinding: F,
+}
+
+impl<F: RescueParameter, const INPUT_LEN: usize, const INPUT_LEN_PLUS_ONE: usize>
+    FixedLengthRescueCommitment<F, INPUT_LEN, INPUT_LEN_PLUS_ONE>
+{
+    /// Return the opening of the commitment to `input` with blinding factor
+    /// `blinding`, to be checked with [`Self::verify_opening`].
+    pub fn open(input: &[F; INPUT_LEN], blinding: &F) -> RescueCommitmentOpening<F, INPUT_LEN> {
+        RescueCommitmentOpening {
+            message: *input,
+            blinding: *blinding,
+        }
+    }
+
+    /// Check that `opening` opens the commitment `comm`. The comparison of
+    /// the recomputed and given commitments runs in constant time.
+    pub fn verify_opening(
+        comm: &F,
+        opening: &RescueCommitmentOpening<F, INPUT_LEN>,
+    ) -> Result<(), RescueError> {
+        let expected =
+            <Self as CommitmentScheme>::commit(opening.message, Some(&opening.blinding))?;
+        if ct_eq(&expected, comm) {
+            Ok(())
+        } else {
+            Err(RescueError::VerificationError(
+                "Rescue commitment Error: invalid opening.".to_string(),
+            ))
+        }
+    }
+
+    /// Derive the blinding factor of the `index`-th commitment of a batch from
+    /// `master_blinding`, i.e. the [`RescuePRF`] keyed by `master_blinding`
+    /// evaluated at `[index]`.
+    pub fn derive_blinding(master_blinding: &F, index: u64) -> Result<F, RescueError> {
+        Ok(RescuePRF::<F, 1, 1>::evaluate(master_blinding, [F::from(index)])?[0])
+    }
+
+    /// Commit to each of `inputs`, in parallel with the `parallel` feature,
+    /// with the blinding factor derived for its position in the batch by
+    /// [`Self::derive_blinding`]. Return the commitments along with their
+    /// openings, so that only `master_blinding` needs to be kept to recompute
+    /// them.
+    pub fn commit_batch(
+        inputs: &[[F; INPUT_LEN]],
+        master_blinding: &F,
+    ) -> Result<(Vec<F>, Vec<RescueCommitmentOpening<F, INPUT_LEN>>), RescueError> {
+        let commit_one = |(i, input): (usize, &[F; INPUT_LEN])| {
+            let blinding = Self::derive_blinding(master_blinding, i as u64)?;
+            let comm = <Self as CommitmentScheme>::commit(input, Some(&blinding))?;
+            Ok((comm, Self::open(input, &blinding)))
+        };
+        #[cfg(feature = "parallel")]
+        let results: Result<Vec<_>, RescueError> =
+            inputs.par_iter().enumerate().map(commit_one).collect();
+        #[cfg(not(feature = "parallel"))]
+        let results: Result<Vec<_>, RescueError> =
+            inputs.iter().enumerate().map(commit_one).collect();
+        Ok(results?.into_iter().unzip())
+    }
+}
+
 impl<F: RescueParameter, const INPUT_LEN: usize, const INPUT_LEN_PLUS_ONE: usize> CommitmentScheme
     for FixedLengthRescueCommitment<F, INPUT_LEN, INPUT_LEN_PLUS_ONE>
 {
@@ -41,13 +153,47 @@ impl<F: RescueParameter, const INPUT_LEN: usize, const INPUT_LEN_PLUS_ONE: usize
         input: T,
         r: Option<&Self::Randomness>,
     ) -> Result<Self::Output, Self::Error> {
-        let mut msg = [F::zero(); INPUT_LEN_PLUS_ONE];
-        msg[0] = *r.ok_or_else(|| {
+        Self::commit_with_tag(&F::zero(), input.borrow(), r)
+    }
+
+    fn verify<T: Borrow<Self::Input>>(
+        input: T,
+        r: Option<&Self::Randomness>,
+        comm: &Self::Output,
+    ) -> Result<VerificationResult, Self::Error> {
+        if ct_eq(&<Self as CommitmentScheme>::commit(input, r)?, comm) {
+            Ok(Ok(()))
+        } else {
+            Ok(Err(()))
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+/// Rescue-based Commitment instance for variable-length input
+///
+/// The blinding factor and the input are hashed with
+/// [`RescueCRHF::sponge_with_length_prefix`], i.e. after their overall length,
+/// so that inputs differing by trailing zeros, e.g. `[x]` and `[x, 0]`, have
+/// different commitments.
+pub struct VariableLengthRescueCommitment<F: RescueParameter>(PhantomData<F>);
+
+impl<F: RescueParameter> CommitmentScheme for VariableLengthRescueCommitment<F> {
+    type Input = Vec<F>;
+    type Output = F;
+    type Randomness = F;
+    type Error = RescueError;
+
+    fn commit<T: Borrow<Self::Input>>(
+        input: T,
+        r: Option<&Self::Randomness>,
+    ) -> Result<Self::Output, Self::Error> {
+        let mut msg = vec![*r.ok_or_else(|| {
             RescueError::ParameterError("Expecting a blinding factor".to_string())
-        })?;
-        msg[1..INPUT_LEN_PLUS_ONE].copy_from_slice(&input.borrow()[..(INPUT_LEN)]);
+        })?];
+        msg.extend_from_slice(input.borrow());
 
-        Ok(FixedLengthRescueCRHF::<F, INPUT_LEN_PLUS_ONE, 1>::evaluate(&msg)?[0])
+        Ok(RescueCRHF::sponge_with_length_prefix(&msg, 1)[0])
     }
 
     fn verify<T: Borrow<Self::Input>>(
@@ -55,7 +201,7 @@ impl<F: RescueParameter, const INPUT_LEN: usize, const INPUT_LEN_PLUS_ONE: usize
         r: Option<&Self::Randomness>,
         comm: &Self::Output,
     ) -> Result<VerificationResult, Self::Error> {
-        if <Self as CommitmentScheme>::commit(input, r)? == *comm {
+        if ct_eq(&<Self as CommitmentScheme>::commit(input, r)?, comm) {
             Ok(Ok(()))
         } else {
             Ok(Err(()))
@@ -66,9 +212,12 @@ impl<F: RescueParameter, const INPUT_LEN: usize, const INPUT_LEN_PLUS_ONE: usize
 #[cfg(test)]
 mod test {
     use crate::{
-        commitment::{CommitmentScheme, FixedLengthRescueCommitment},
+        commitment::{
+            CommitmentScheme, FixedLengthRescueCommitment, RescueCommitmentOpening,
+            VariableLengthRescueCommitment,
+        },
         crhf::RescueCRHF,
-        CRHF_RATE,
+        RescueParameter, CRHF_RATE,
     };
     use ark_bls12_377::Fq as Fq377;
     use ark_bn254::Fq as Fq254;
@@ -77,7 +226,8 @@ mod test {
     use ark_ed_on_bls12_381_bandersnatch::Fq as FqEd381b;
     use ark_ed_on_bn254::Fq as FqEd254;
     use ark_ff::UniformRand;
-    use ark_std::vec;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{vec, vec::Vec};
 
     macro_rules! test_commit {
         ($tr:tt) => {
@@ -134,4 +284,177 @@ mod test {
         test_commit!(Fq377);
         test_commit!(Fq254);
     }
+
+    #[test]
+    fn test_commit_with_tag() {
+        test_commit_with_tag_helper::<FqEd254>();
+        test_commit_with_tag_helper::<FqEd377>();
+        test_commit_with_tag_helper::<FqEd381>();
+        test_commit_with_tag_helper::<Fq377>();
+    }
+
+    fn test_commit_with_tag_helper<F: RescueParameter>() {
+        let mut prng = jf_utils::test_rng();
+        let input = [F::from(1u64), F::from(2u64), F::from(3u64)];
+        let blind = F::rand(&mut prng);
+        let tag = F::rand(&mut prng);
+
+        let c = FixedLengthRescueCommitment::<F, 3, 4>::commit_with_tag(&tag, &input, Some(&blind))
+            .unwrap();
+        assert!(FixedLengthRescueCommitment::<F, 3, 4>::verify_with_tag(
+            &tag,
+            &input,
+            Some(&blind),
+            &c
+        )
+        .unwrap()
+        .is_ok());
+        // test for correctness
+        let mut msg = vec![blind];
+        msg.extend_from_slice(&input);
+        assert_eq!(
+            c,
+            RescueCRHF::tagged_sponge_with_zero_padding(&tag, &msg, 1)[0]
+        );
+
+        // bad tag
+        assert!(FixedLengthRescueCommitment::<F, 3, 4>::verify_with_tag(
+            &(tag + F::one()),
+            &input,
+            Some(&blind),
+            &c
+        )
+        .unwrap()
+        .is_err());
+        // a zero tag yields the untagged commitment
+        assert_eq!(
+            FixedLengthRescueCommitment::<F, 3, 4>::commit_with_tag(
+                &F::zero(),
+                &input,
+                Some(&blind)
+            )
+            .unwrap(),
+            FixedLengthRescueCommitment::<F, 3, 4>::commit(&input, Some(&blind)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_variable_length_commit() {
+        test_variable_length_commit_helper::<FqEd254>();
+        test_variable_length_commit_helper::<FqEd377>();
+        test_variable_length_commit_helper::<FqEd381>();
+        test_variable_length_commit_helper::<Fq377>();
+    }
+
+    fn test_variable_length_commit_helper<F: RescueParameter>() {
+        let mut prng = jf_utils::test_rng();
+        let blind = F::rand(&mut prng);
+        for len in 0..5 {
+            let input: Vec<F> = (0..len).map(|_| F::rand(&mut prng)).collect();
+            let c = VariableLengthRescueCommitment::commit(&input, Some(&blind)).unwrap();
+            assert!(
+                VariableLengthRescueCommitment::verify(&input, Some(&blind), &c)
+                    .unwrap()
+                    .is_ok()
+            );
+            // test for correctness
+            let mut msg = vec![blind];
+            msg.extend_from_slice(&input);
+            assert_eq!(c, RescueCRHF::sponge_with_length_prefix(&msg, 1)[0]);
+
+            // bad blinding factor
+            assert!(
+                VariableLengthRescueCommitment::verify(&input, Some(&(blind + F::one())), &c)
+                    .unwrap()
+                    .is_err()
+            );
+            // trailing zero
+            let mut bad_input = input.clone();
+            bad_input.push(F::zero());
+            assert!(
+                VariableLengthRescueCommitment::verify(&bad_input, Some(&blind), &c)
+                    .unwrap()
+                    .is_err()
+            );
+        }
+        assert!(VariableLengthRescueCommitment::<F>::commit(&vec![], None).is_err());
+    }
+
+    #[test]
+    fn test_opening() {
+        test_opening_helper::<FqEd254>();
+        test_opening_helper::<FqEd377>();
+        test_opening_helper::<FqEd381>();
+        test_opening_helper::<Fq377>();
+    }
+
+    fn test_opening_helper<F: RescueParameter>() {
+        let mut prng = jf_utils::test_rng();
+        let input = [F::rand(&mut prng), F::rand(&mut prng), F::rand(&mut prng)];
+        let blind = F::rand(&mut prng);
+        let c = FixedLengthRescueCommitment::<F, 3, 4>::commit(&input, Some(&blind)).unwrap();
+
+        let opening = FixedLengthRescueCommitment::<F, 3, 4>::open(&input, &blind);
+        assert!(FixedLengthRescueCommitment::<F, 3, 4>::verify_opening(&c, &opening).is_ok());
+        assert!(
+            FixedLengthRescueCommitment::<F, 3, 4>::verify_opening(&(c + F::one()), &opening)
+                .is_err()
+        );
+
+        // bad message or blinding factor
+        let mut bad_opening = opening.clone();
+        bad_opening.message[1] += F::one();
+        assert!(FixedLengthRescueCommitment::<F, 3, 4>::verify_opening(&c, &bad_opening).is_err());
+        let mut bad_opening = opening.clone();
+        bad_opening.blinding += F::one();
+        assert!(FixedLengthRescueCommitment::<F, 3, 4>::verify_opening(&c, &bad_opening).is_err());
+
+        // serialization round trip
+        let mut bytes = Vec::new();
+        opening.serialize_compressed(&mut bytes).unwrap();
+        let deserialized =
+            RescueCommitmentOpening::<F, 3>::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(deserialized, opening);
+    }
+
+    #[test]
+    fn test_commit_batch() {
+        test_commit_batch_helper::<FqEd254>();
+        test_commit_batch_helper::<FqEd377>();
+        test_commit_batch_helper::<FqEd381>();
+        test_commit_batch_helper::<Fq377>();
+    }
+
+    fn test_commit_batch_helper<F: RescueParameter>() {
+        let mut prng = jf_utils::test_rng();
+        let master_blinding = F::rand(&mut prng);
+        let inputs: Vec<[F; 3]> = (0..10)
+            .map(|_| [F::rand(&mut prng), F::rand(&mut prng), F::rand(&mut prng)])
+            .collect();
+
+        let (comms, openings) =
+            FixedLengthRescueCommitment::<F, 3, 4>::commit_batch(&inputs, &master_blinding)
+                .unwrap();
+        assert_eq!(comms.len(), inputs.len());
+        assert_eq!(openings.len(), inputs.len());
+        for (i, (comm, opening)) in comms.iter().zip(openings.iter()).enumerate() {
+            let blinding =
+                FixedLengthRescueCommitment::<F, 3, 4>::derive_blinding(&master_blinding, i as u64)
+                    .unwrap();
+            assert_eq!(opening.message, inputs[i]);
+            assert_eq!(opening.blinding, blinding);
+            assert_eq!(
+                *comm,
+                FixedLengthRescueCommitment::<F, 3, 4>::commit(&inputs[i], Some(&blinding))
+                    .unwrap()
+            );
+            assert!(FixedLengthRescueCommitment::<F, 3, 4>::verify_opening(comm, opening).is_ok());
+        }
+        // blinding factors are distinct across the batch
+        assert_ne!(openings[0].blinding, openings[1].blinding);
+
+        let (comms, openings) =
+            FixedLengthRescueCommitment::<F, 3, 4>::commit_batch(&[], &master_blinding).unwrap();
+        assert!(comms.is_empty() && openings.is_empty());
+    }
 }
@@ -8,6 +8,7 @@
 
 use crate::{
     sponge::RescueSponge, Permutation, RescueError, RescueParameter, RescueVector, CRHF_RATE,
+    STATE_SIZE,
 };
 use ark_crypto_primitives::sponge::{
     CryptographicSponge, FieldBasedCryptographicSponge, SpongeExt,
@@ -52,10 +53,56 @@ impl<F: RescueParameter> RescueCRHF<F> {
             .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
     }
 
+    /// Similar to [`RescueCRHF::sponge_with_zero_padding`] except the length
+    /// of `input` is prepended to it, so that inputs of different lengths,
+    /// e.g. differing by trailing zeros, are hashed differently. This is the
+    /// hash computed by the circuits of messages of variable length.
+    pub fn sponge_with_length_prefix(input: &[F], num_outputs: usize) -> Vec<F> {
+        let mut padded = Vec::with_capacity(input.len() + CRHF_RATE);
+        padded.push(F::from(input.len() as u64));
+        padded.extend_from_slice(input);
+        pad_with_zeros(&mut padded, CRHF_RATE);
+        Self::sponge_no_padding(padded.as_slice(), num_outputs)
+            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+    }
+
     /// Sponge hashing based on rescue permutation for RATE 3 and CAPACITY 1. It
     /// allows inputs with length that is a multiple of `CRHF_RATE` and
     /// returns a vector of `num_outputs` elements.
     pub fn sponge_no_padding(input: &[F], num_output: usize) -> Result<Vec<F>, RescueError> {
+        Self::tagged_sponge_no_padding(&F::zero(), input, num_output)
+    }
+
+    /// Similar to [`RescueCRHF::sponge_with_bit_padding`] except the
+    /// domain-separation tag `tag` is absorbed first, into the capacity
+    /// element of the initial state, so that hashes computed for different
+    /// purposes can't be confused. A zero tag yields the untagged hash.
+    pub fn tagged_sponge_with_bit_padding(tag: &F, input: &[F], num_outputs: usize) -> Vec<F> {
+        let mut padded = input.to_vec();
+        padded.push(F::one());
+        pad_with_zeros(&mut padded, CRHF_RATE);
+        Self::tagged_sponge_no_padding(tag, padded.as_slice(), num_outputs)
+            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+    }
+
+    /// Similar to [`RescueCRHF::sponge_with_zero_padding`] except the
+    /// domain-separation tag `tag` is absorbed first, see
+    /// [`RescueCRHF::tagged_sponge_with_bit_padding`].
+    pub fn tagged_sponge_with_zero_padding(tag: &F, input: &[F], num_outputs: usize) -> Vec<F> {
+        let mut padded = input.to_vec();
+        pad_with_zeros(&mut padded, CRHF_RATE);
+        Self::tagged_sponge_no_padding(tag, padded.as_slice(), num_outputs)
+            .expect("Bug in JF Primitives : bad padding of input for FSKS construction")
+    }
+
+    /// Similar to [`RescueCRHF::sponge_no_padding`] except the
+    /// domain-separation tag `tag` is absorbed first, see
+    /// [`RescueCRHF::tagged_sponge_with_bit_padding`].
+    pub fn tagged_sponge_no_padding(
+        tag: &F,
+        input: &[F],
+        num_output: usize,
+    ) -> Result<Vec<F>, RescueError> {
         if input.len() % CRHF_RATE != 0 {
             return Err(RescueError::ParameterError(
                 "Rescue sponge Error : input to sponge hashing function is not multiple of RATE."
@@ -63,8 +110,10 @@ impl<F: RescueParameter> RescueCRHF<F> {
             ));
         }
         // ABSORB PHASE
+        let mut state = RescueVector::zero();
+        state.vec[STATE_SIZE - 1] = *tag;
         let mut r = Self {
-            sponge: RescueSponge::from_state(RescueVector::zero(), &Permutation::default()),
+            sponge: RescueSponge::from_state(state, &Permutation::default()),
         };
         r.sponge.absorb(&input);
 
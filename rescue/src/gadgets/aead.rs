@@ -0,0 +1,118 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Circuit implementation of the rescue AEAD encryption, mirroring
+//! [`RescueAEAD`](crate::aead::RescueAEAD).
+
+use super::sponge::RescueDuplexSpongeVar;
+use crate::{aead::AEAD_DOMAIN_SEPARATOR, RescueParameter, CRHF_RATE};
+use ark_std::vec::Vec;
+use jf_relation::{Circuit, CircuitError, PlonkCircuit, Variable};
+
+/// Circuit implementation of an AEAD encryption.
+pub trait AEADGadget {
+    /// Encrypt a plaintext with associated data
+    /// * `key` - key variable
+    /// * `nonce` - nonce variable
+    /// * `ad` - associated data variables
+    /// * `plaintext` - plaintext variables
+    /// * `returns` - variables that refer to the ciphertext and to the tag
+    fn aead_encrypt(
+        &mut self,
+        key: Variable,
+        nonce: Variable,
+        ad: &[Variable],
+        plaintext: &[Variable],
+    ) -> Result<(Vec<Variable>, Variable), CircuitError>;
+}
+
+impl<F> AEADGadget for PlonkCircuit<F>
+where
+    F: RescueParameter,
+{
+    fn aead_encrypt(
+        &mut self,
+        key: Variable,
+        nonce: Variable,
+        ad: &[Variable],
+        plaintext: &[Variable],
+    ) -> Result<(Vec<Variable>, Variable), CircuitError> {
+        let domain_tag = F::from_le_bytes_mod_order(AEAD_DOMAIN_SEPARATOR);
+        let mut sponge = RescueDuplexSpongeVar::<CRHF_RATE>::new(self, domain_tag)?;
+        sponge.absorb(self, &[key, nonce])?;
+        sponge.separate(self, F::zero())?;
+        sponge.absorb(self, ad)?;
+        sponge.separate(self, F::zero())?;
+
+        let mut ciphertext = Vec::with_capacity(plaintext.len());
+        for chunk in plaintext.chunks(CRHF_RATE) {
+            let keystream = sponge.squeeze(self, chunk.len())?;
+            for (p, k) in chunk.iter().zip(keystream.iter()) {
+                ciphertext.push(self.add(*p, *k)?);
+            }
+            sponge.absorb(self, chunk)?;
+        }
+        sponge.separate(self, F::zero())?;
+        let tag = sponge.squeeze(self, 1)?[0];
+        Ok((ciphertext, tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AEADGadget;
+    use crate::{aead::RescueAEAD, RescueParameter};
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::{vec::Vec, UniformRand};
+    use jf_relation::{Circuit, PlonkCircuit, Variable};
+
+    #[test]
+    fn test_aead_circuit() {
+        test_aead_circuit_helper::<FqEd254>();
+        test_aead_circuit_helper::<FqEd377>();
+        test_aead_circuit_helper::<FqEd381>();
+    }
+
+    fn test_aead_circuit_helper<F: RescueParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let key = F::rand(&mut rng);
+        let nonce = F::rand(&mut rng);
+        let ad: Vec<F> = (0..2).map(|_| F::rand(&mut rng)).collect();
+        for len in [0, 1, 4] {
+            let plaintext: Vec<F> = (0..len).map(|_| F::rand(&mut rng)).collect();
+            let (ciphertext, tag) = RescueAEAD::encrypt(&key, &nonce, &ad, &plaintext);
+
+            let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+            let key_var = circuit.create_variable(key).unwrap();
+            let nonce_var = circuit.create_variable(nonce).unwrap();
+            let ad_vars: Vec<Variable> = ad
+                .iter()
+                .map(|x| circuit.create_variable(*x).unwrap())
+                .collect();
+            let plaintext_vars: Vec<Variable> = plaintext
+                .iter()
+                .map(|x| circuit.create_variable(*x).unwrap())
+                .collect();
+            let (ciphertext_vars, tag_var) = circuit
+                .aead_encrypt(key_var, nonce_var, &ad_vars, &plaintext_vars)
+                .unwrap();
+
+            // Check output consistency with the native encryption
+            assert_eq!(ciphertext_vars.len(), len);
+            for (var, val) in ciphertext_vars.iter().zip(ciphertext.iter()) {
+                assert_eq!(circuit.witness(*var).unwrap(), *val);
+            }
+            assert_eq!(circuit.witness(tag_var).unwrap(), tag);
+
+            // Check constraints
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+            *circuit.witness_mut(tag_var) = F::from(1_u32);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        }
+    }
+}
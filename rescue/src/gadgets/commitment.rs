@@ -6,7 +6,7 @@
 
 //! Circuit implementation of the rescue-based commitment scheme.
 
-use super::RescueNativeGadget;
+use super::{variable_length::VariableLengthRescueGadget, RescueNativeGadget};
 use crate::{RescueParameter, CRHF_RATE};
 use ark_std::{vec, vec::Vec};
 use jf_relation::{Circuit, CircuitError, PlonkCircuit, Variable};
@@ -35,6 +35,65 @@ where
     }
 }
 
+/// Gadget for the commitment of messages of variable length, mirroring
+/// [`VariableLengthRescueCommitment`](crate::commitment::VariableLengthRescueCommitment).
+pub trait VariableLengthCommitmentGadget {
+    /// Commitment function where the length of the input is bound to the
+    /// commitment.
+    /// * `input` - input variables,
+    /// * `blinding` - blinding variable
+    /// * `returns` a variable that refers to the commitment value
+    fn commit_variable_length(
+        &mut self,
+        input: &[Variable],
+        blinding: Variable,
+    ) -> Result<Variable, CircuitError>;
+
+    /// Similar to [`Self::commit_variable_length`] except the input is made
+    /// of the first `len` variables of `input`, the length being a witness.
+    /// The circuit is unsatisfiable if `len` is larger than `input.len()`.
+    /// * `input` - input variables, of the maximal length of the input
+    /// * `len` - variable for the length of the input
+    /// * `blinding` - blinding variable
+    /// * `returns` a variable that refers to the commitment value
+    fn commit_with_length(
+        &mut self,
+        input: &[Variable],
+        len: Variable,
+        blinding: Variable,
+    ) -> Result<Variable, CircuitError>;
+}
+
+impl<F> VariableLengthCommitmentGadget for PlonkCircuit<F>
+where
+    F: RescueParameter,
+{
+    fn commit_variable_length(
+        &mut self,
+        input: &[Variable],
+        blinding: Variable,
+    ) -> Result<Variable, CircuitError> {
+        let len = self.create_constant_variable(F::from(input.len() as u64 + 1))?;
+        let mut msg = vec![len, blinding];
+        msg.extend_from_slice(input);
+        pad_with(&mut msg, CRHF_RATE, self.zero());
+        Ok(RescueNativeGadget::<F>::rescue_sponge_no_padding(self, &msg, 1)?[0])
+    }
+
+    fn commit_with_length(
+        &mut self,
+        input: &[Variable],
+        len: Variable,
+        blinding: Variable,
+    ) -> Result<Variable, CircuitError> {
+        // the blinding factor is part of the hashed message
+        let msg_len = self.add_constant(len, &F::one())?;
+        let mut msg = vec![blinding];
+        msg.extend_from_slice(input);
+        Ok(self.rescue_sponge_with_length(&msg, msg_len, 1)?[0])
+    }
+}
+
 #[inline]
 pub(crate) fn pad_with(vec: &mut Vec<Variable>, multiple: usize, var: Variable) {
     let len = vec.len();
@@ -48,8 +107,11 @@ pub(crate) fn pad_with(vec: &mut Vec<Variable>, multiple: usize, var: Variable)
 
 #[cfg(test)]
 mod tests {
-    use super::CommitmentGadget;
-    use crate::commitment::FixedLengthRescueCommitment;
+    use super::{CommitmentGadget, VariableLengthCommitmentGadget};
+    use crate::{
+        commitment::{FixedLengthRescueCommitment, VariableLengthRescueCommitment},
+        RescueParameter,
+    };
     use ark_bls12_377::Fq as Fq377;
     use ark_ed_on_bls12_377::Fq as FqEd377;
     use ark_ed_on_bls12_381::Fq as FqEd381;
@@ -109,4 +171,55 @@ mod tests {
         test_commit_circuit!(FqEd381b);
         test_commit_circuit!(Fq377);
     }
+
+    #[test]
+    fn test_variable_length_commit_circuit() {
+        test_variable_length_commit_circuit_helper::<FqEd254>();
+        test_variable_length_commit_circuit_helper::<FqEd377>();
+        test_variable_length_commit_circuit_helper::<FqEd381>();
+        test_variable_length_commit_circuit_helper::<Fq377>();
+    }
+
+    fn test_variable_length_commit_circuit_helper<F: RescueParameter>() {
+        let mut prng = jf_utils::test_rng();
+        let blinding = F::rand(&mut prng);
+        let max_len = 5;
+        let data: Vec<F> = (0..max_len).map(|_| F::rand(&mut prng)).collect();
+        for len in 0..=max_len {
+            let expected =
+                VariableLengthRescueCommitment::commit(&data[..len].to_vec(), Some(&blinding))
+                    .unwrap();
+
+            // constant length
+            let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+            let blinding_var = circuit.create_variable(blinding).unwrap();
+            let data_vars: Vec<Variable> = data[..len]
+                .iter()
+                .map(|&x| circuit.create_variable(x).unwrap())
+                .collect();
+            let commitment_var = circuit
+                .commit_variable_length(&data_vars, blinding_var)
+                .unwrap();
+            assert_eq!(expected, circuit.witness(commitment_var).unwrap());
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+            *circuit.witness_mut(commitment_var) = F::from(1_u32);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+            // witness length
+            let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+            let blinding_var = circuit.create_variable(blinding).unwrap();
+            let data_vars: Vec<Variable> = data
+                .iter()
+                .map(|&x| circuit.create_variable(x).unwrap())
+                .collect();
+            let len_var = circuit.create_variable(F::from(len as u64)).unwrap();
+            let commitment_var = circuit
+                .commit_with_length(&data_vars, len_var, blinding_var)
+                .unwrap();
+            assert_eq!(expected, circuit.witness(commitment_var).unwrap());
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+            *circuit.witness_mut(commitment_var) = F::from(1_u32);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        }
+    }
 }
@@ -0,0 +1,117 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Circuit implementation of the rescue KDF, mirroring
+//! [`RescueKDF`](crate::kdf::RescueKDF).
+
+use super::sponge::RescueDuplexSpongeVar;
+use crate::{kdf::KDF_DOMAIN_SEPARATOR, RescueParameter, CRHF_RATE};
+use jf_relation::{CircuitError, PlonkCircuit, Variable};
+
+/// Circuit implementation of a KDF.
+pub trait KDFGadget {
+    /// Derive the child key of a parent key for a label
+    /// * `parent` - parent key variable
+    /// * `label` - label variables
+    /// * `returns` - variable that refers to the child key
+    fn derive_key(
+        &mut self,
+        parent: Variable,
+        label: &[Variable],
+    ) -> Result<Variable, CircuitError>;
+
+    /// Derive the key at a path below a master key, each index of the path
+    /// being the label of a child of the previous key
+    /// * `master` - master key variable
+    /// * `path` - index variables
+    /// * `returns` - variable that refers to the derived key
+    fn derive_key_path(
+        &mut self,
+        master: Variable,
+        path: &[Variable],
+    ) -> Result<Variable, CircuitError>;
+}
+
+impl<F> KDFGadget for PlonkCircuit<F>
+where
+    F: RescueParameter,
+{
+    fn derive_key(
+        &mut self,
+        parent: Variable,
+        label: &[Variable],
+    ) -> Result<Variable, CircuitError> {
+        let domain_tag = F::from_le_bytes_mod_order(KDF_DOMAIN_SEPARATOR);
+        let mut sponge = RescueDuplexSpongeVar::<CRHF_RATE>::new(self, domain_tag)?;
+        sponge.absorb(self, &[parent])?;
+        sponge.separate(self, F::zero())?;
+        sponge.absorb(self, label)?;
+        sponge.separate(self, F::zero())?;
+        Ok(sponge.squeeze(self, 1)?[0])
+    }
+
+    fn derive_key_path(
+        &mut self,
+        master: Variable,
+        path: &[Variable],
+    ) -> Result<Variable, CircuitError> {
+        path.iter()
+            .try_fold(master, |key, &index| self.derive_key(key, &[index]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KDFGadget;
+    use crate::{kdf::RescueKDF, RescueParameter};
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::{vec::Vec, UniformRand};
+    use jf_relation::{Circuit, PlonkCircuit, Variable};
+
+    #[test]
+    fn test_kdf_circuit() {
+        test_kdf_circuit_helper::<FqEd254>();
+        test_kdf_circuit_helper::<FqEd377>();
+        test_kdf_circuit_helper::<FqEd381>();
+    }
+
+    fn test_kdf_circuit_helper<F: RescueParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let master = F::rand(&mut rng);
+        let label = [F::from(1u8), F::from(2u8)];
+        let path = [3u64, 5, 8];
+
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let master_var = circuit.create_variable(master).unwrap();
+        let label_vars: Vec<Variable> = label
+            .iter()
+            .map(|x| circuit.create_variable(*x).unwrap())
+            .collect();
+        let path_vars: Vec<Variable> = path
+            .iter()
+            .map(|&i| circuit.create_variable(F::from(i)).unwrap())
+            .collect();
+        let child_var = circuit.derive_key(master_var, &label_vars).unwrap();
+        let path_key_var = circuit.derive_key_path(master_var, &path_vars).unwrap();
+
+        // Check output consistency with the native KDF
+        assert_eq!(
+            circuit.witness(child_var).unwrap(),
+            RescueKDF::derive(&master, &label)
+        );
+        assert_eq!(
+            circuit.witness(path_key_var).unwrap(),
+            RescueKDF::derive_path(&master, &path)
+        );
+
+        // Check constraints
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        *circuit.witness_mut(path_key_var) = F::from(1_u32);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+    }
+}
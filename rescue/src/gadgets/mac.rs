@@ -0,0 +1,111 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Circuit implementation of the rescue MAC, mirroring
+//! [`RescueMAC`](crate::mac::RescueMAC).
+
+use super::sponge::RescueDuplexSpongeVar;
+use crate::{mac::MAC_DOMAIN_SEPARATOR, RescueParameter, CRHF_RATE};
+use jf_relation::{Circuit, CircuitError, PlonkCircuit, Variable};
+
+/// Circuit implementation of a MAC.
+pub trait MACGadget {
+    /// Compute the tag of a message
+    /// * `key` - key variable
+    /// * `msg` - message variables
+    /// * `returns` - variable that refers to the tag
+    fn eval_mac(&mut self, key: Variable, msg: &[Variable]) -> Result<Variable, CircuitError>;
+
+    /// Enforce that `tag` is the tag of `msg` under `key`
+    /// * `key` - key variable
+    /// * `msg` - message variables
+    /// * `tag` - tag variable
+    fn verify_mac(
+        &mut self,
+        key: Variable,
+        msg: &[Variable],
+        tag: Variable,
+    ) -> Result<(), CircuitError>;
+}
+
+impl<F> MACGadget for PlonkCircuit<F>
+where
+    F: RescueParameter,
+{
+    fn eval_mac(&mut self, key: Variable, msg: &[Variable]) -> Result<Variable, CircuitError> {
+        let domain_tag = F::from_le_bytes_mod_order(MAC_DOMAIN_SEPARATOR);
+        let mut sponge = RescueDuplexSpongeVar::<CRHF_RATE>::new(self, domain_tag)?;
+        sponge.absorb(self, &[key])?;
+        sponge.separate(self, F::zero())?;
+        sponge.absorb(self, msg)?;
+        sponge.separate(self, F::zero())?;
+        Ok(sponge.squeeze(self, 1)?[0])
+    }
+
+    fn verify_mac(
+        &mut self,
+        key: Variable,
+        msg: &[Variable],
+        tag: Variable,
+    ) -> Result<(), CircuitError> {
+        let expected = self.eval_mac(key, msg)?;
+        self.enforce_equal(expected, tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MACGadget;
+    use crate::{mac::RescueMAC, RescueParameter};
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::{vec::Vec, UniformRand};
+    use jf_relation::{Circuit, PlonkCircuit, Variable};
+
+    #[test]
+    fn test_mac_circuit() {
+        test_mac_circuit_helper::<FqEd254>();
+        test_mac_circuit_helper::<FqEd377>();
+        test_mac_circuit_helper::<FqEd381>();
+    }
+
+    fn test_mac_circuit_helper<F: RescueParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let key = F::rand(&mut rng);
+        for msg_len in [0, 1, 4] {
+            let msg: Vec<F> = (0..msg_len).map(|_| F::rand(&mut rng)).collect();
+            let tag = RescueMAC::mac(&key, &msg);
+
+            let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+            let key_var = circuit.create_variable(key).unwrap();
+            let msg_vars: Vec<Variable> = msg
+                .iter()
+                .map(|x| circuit.create_variable(*x).unwrap())
+                .collect();
+            let tag_var = circuit.eval_mac(key_var, &msg_vars).unwrap();
+
+            // Check output consistency with the native MAC
+            assert_eq!(circuit.witness(tag_var).unwrap(), tag);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+            *circuit.witness_mut(tag_var) = F::from(1_u32);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+            // Check verification
+            let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+            let key_var = circuit.create_variable(key).unwrap();
+            let msg_vars: Vec<Variable> = msg
+                .iter()
+                .map(|x| circuit.create_variable(*x).unwrap())
+                .collect();
+            let tag_var = circuit.create_variable(tag).unwrap();
+            circuit.verify_mac(key_var, &msg_vars, tag_var).unwrap();
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+            *circuit.witness_mut(tag_var) = tag + F::one();
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        }
+    }
+}
@@ -7,10 +7,17 @@
 //! Rescue hash related gates and gadgets. Including both native and non-native
 //! fields.
 
+pub mod aead;
 pub mod commitment;
+pub mod kdf;
+pub mod mac;
 mod native;
 mod non_native;
 pub mod prf;
+pub mod rescue_prime;
+pub mod sponge;
+pub mod tagged;
+pub mod variable_length;
 
 use crate::{RescueMatrix, RescueVector, PRP};
 use ark_ff::PrimeField;
@@ -534,6 +534,7 @@ mod tests {
     use ark_ed_on_bls12_381::Fq as FqEd381;
     use ark_ed_on_bn254::Fq as FqEd254;
     use ark_ff::{FftField, PrimeField};
+    use ark_pallas::{Fq as FqPallas, Fr as FqVesta};
     use ark_std::{vec, vec::Vec};
     use jf_relation::{Circuit, PlonkCircuit, Variable};
 
@@ -703,6 +704,8 @@ mod tests {
         test_rescue_perm_helper::<FqEd254>();
         test_rescue_perm_helper::<FqEd377>();
         test_rescue_perm_helper::<FqEd381>();
+        test_rescue_perm_helper::<FqPallas>();
+        test_rescue_perm_helper::<FqVesta>();
     }
     fn test_rescue_perm_helper<F: RescueParameter>() {
         let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
@@ -788,6 +791,8 @@ mod tests {
         test_prp_helper::<FqEd254>();
         test_prp_helper::<FqEd377>();
         test_prp_helper::<FqEd381>();
+        test_prp_helper::<FqPallas>();
+        test_prp_helper::<FqVesta>();
     }
 
     fn test_prp_helper<F: RescueParameter>() {
@@ -954,6 +959,8 @@ mod tests {
         test_rescue_sponge_with_padding_helper::<FqEd254>();
         test_rescue_sponge_with_padding_helper::<FqEd377>();
         test_rescue_sponge_with_padding_helper::<FqEd381>();
+        test_rescue_sponge_with_padding_helper::<FqPallas>();
+        test_rescue_sponge_with_padding_helper::<FqVesta>();
     }
     fn test_rescue_sponge_with_padding_helper<F: RescueParameter>() {
         for input_len in 1..10 {
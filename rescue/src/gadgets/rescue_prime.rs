@@ -0,0 +1,160 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Circuit implementation of the Rescue-Prime permutation and hash function.
+
+use super::{PermutationGadget, RescueStateVar};
+use crate::{
+    rescue_prime::{RescuePrimeParameter, RescuePrimePermutation},
+    CRHF_RATE,
+};
+use ark_std::{vec, vec::Vec};
+use jf_relation::{Circuit, CircuitError, PlonkCircuit, Variable};
+use jf_utils::compute_len_to_next_multiple;
+
+/// Circuit implementation of Rescue-Prime.
+pub trait RescuePrimeGadget {
+    /// Given an input state st_0, return the variables of the output state
+    /// st_1 = rescue_prime_permutation(st_0)
+    /// * `input_var` - variables corresponding to the input state
+    /// * `returns` - variables corresponding to the output state
+    fn rescue_prime_permutation(
+        &mut self,
+        input_var: RescueStateVar,
+    ) -> Result<RescueStateVar, CircuitError>;
+
+    /// Rescue-Prime hash, matching
+    /// [`RescuePrimeCRHF::hash`](crate::rescue_prime::RescuePrimeCRHF::hash)
+    /// * `data_vars` - input variables, of arbitrary length
+    /// * `returns` - the `CRHF_RATE` output variables
+    fn rescue_prime_hash(
+        &mut self,
+        data_vars: &[Variable],
+    ) -> Result<[Variable; CRHF_RATE], CircuitError>;
+}
+
+impl<F> RescuePrimeGadget for PlonkCircuit<F>
+where
+    F: RescuePrimeParameter,
+{
+    fn rescue_prime_permutation(
+        &mut self,
+        input_var: RescueStateVar,
+    ) -> Result<RescueStateVar, CircuitError> {
+        let permutation = RescuePrimePermutation::<F>::default();
+        let mds = permutation.mds_matrix_ref();
+
+        let mut state_var = input_var;
+        for constants in permutation.round_constants_ref().chunks_exact(2) {
+            state_var = self.non_linear_transform(&state_var, mds, &constants[0])?;
+            state_var = self.pow_alpha_inv_state(&state_var)?;
+            state_var = self.affine_transform(&state_var, mds, &constants[1])?;
+        }
+        Ok(state_var)
+    }
+
+    fn rescue_prime_hash(
+        &mut self,
+        data_vars: &[Variable],
+    ) -> Result<[Variable; CRHF_RATE], CircuitError> {
+        let zero_var = self.zero();
+        let data_len = compute_len_to_next_multiple(data_vars.len() + 1, CRHF_RATE);
+        let data_vars: Vec<Variable> = [
+            data_vars,
+            &[self.one()],
+            vec![zero_var; data_len - data_vars.len() - 1].as_ref(),
+        ]
+        .concat();
+
+        // the initial state is all zeros, so the first block is the state
+        let mut state_var =
+            RescueStateVar::from([data_vars[0], data_vars[1], data_vars[2], zero_var]);
+        state_var = self.rescue_prime_permutation(state_var)?;
+        for block in data_vars[CRHF_RATE..].chunks_exact(CRHF_RATE) {
+            state_var = self.add_state(
+                &state_var,
+                &RescueStateVar::from([block[0], block[1], block[2], zero_var]),
+            )?;
+            state_var = self.rescue_prime_permutation(state_var)?;
+        }
+        Ok([state_var.0[0], state_var.0[1], state_var.0[2]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RescuePrimeGadget;
+    use crate::{
+        gadgets::RescueNativeGadget,
+        rescue_prime::{RescuePrimeCRHF, RescuePrimeParameter, RescuePrimePermutation},
+        RescueVector,
+    };
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::{vec::Vec, UniformRand};
+    use jf_relation::{Circuit, PlonkCircuit, Variable};
+
+    #[test]
+    fn test_rescue_prime_perm() {
+        test_rescue_prime_perm_helper::<FqEd254>();
+        test_rescue_prime_perm_helper::<FqEd377>();
+        test_rescue_prime_perm_helper::<FqEd381>();
+    }
+    fn test_rescue_prime_perm_helper<F: RescuePrimeParameter>() {
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+
+        let state_in =
+            RescueVector::from(&[F::from(1u32), F::from(2u32), F::from(3u32), F::from(4u32)]);
+        let state_in_var =
+            RescueNativeGadget::<F>::create_rescue_state_variable(&mut circuit, &state_in).unwrap();
+
+        let state_out = RescuePrimePermutation::default().eval(&state_in);
+        let out_var = circuit.rescue_prime_permutation(state_in_var).unwrap();
+
+        for (var, val) in out_var.array().iter().zip(state_out.elems().iter()) {
+            assert_eq!(circuit.witness(*var).unwrap(), *val);
+        }
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        *circuit.witness_mut(out_var.array()[1]) = F::from(888_u32);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+    }
+
+    #[test]
+    fn test_rescue_prime_hash() {
+        test_rescue_prime_hash_helper::<FqEd254>();
+        test_rescue_prime_hash_helper::<FqEd377>();
+        test_rescue_prime_hash_helper::<FqEd381>();
+    }
+    fn test_rescue_prime_hash_helper<F: RescuePrimeParameter>() {
+        let mut rng = jf_utils::test_rng();
+        for input_len in 0..7 {
+            let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+            let input: Vec<F> = (0..input_len).map(|_| F::rand(&mut rng)).collect();
+            let input_vars: Vec<Variable> = input
+                .iter()
+                .map(|x| circuit.create_variable(*x).unwrap())
+                .collect();
+
+            let expected = RescuePrimeCRHF::<F>::hash(&input);
+            let out_vars = circuit.rescue_prime_hash(&input_vars).unwrap();
+
+            for (var, val) in out_vars.iter().zip(expected.iter()) {
+                assert_eq!(circuit.witness(*var).unwrap(), *val);
+            }
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+            *circuit.witness_mut(out_vars[0]) = F::from(1_u32);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        }
+
+        // error path
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let bad_var = circuit.num_vars();
+        assert!(circuit.rescue_prime_hash(&[bad_var]).is_err());
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Circuit implementation of the Rescue sponges, mirroring
+//! [`RescueSponge`](crate::sponge::RescueSponge) and
+//! [`RescueDuplexSponge`](crate::sponge::RescueDuplexSponge).
+
+use super::{RescueNativeGadget, RescueStateVar};
+use crate::{sponge::DuplexMode, RescueParameter, STATE_SIZE};
+use ark_std::{format, vec::Vec};
+use jf_relation::{Circuit, CircuitError, PlonkCircuit, Variable};
+
+/// Variables for the state of a Rescue sponge of rate `RATE`.
+#[derive(Debug, Clone)]
+pub struct RescueSpongeVar<const RATE: usize> {
+    state: RescueStateVar,
+}
+
+impl<const RATE: usize> RescueSpongeVar<RATE> {
+    /// A sponge with the all-zero initial state, as
+    /// [`CryptographicSponge::new`](ark_crypto_primitives::sponge::CryptographicSponge::new).
+    pub fn new<F: RescueParameter>(circuit: &mut PlonkCircuit<F>) -> Result<Self, CircuitError> {
+        Self::from_state(RescueStateVar::from([circuit.zero(); STATE_SIZE]))
+    }
+
+    /// A sponge with the given state.
+    pub fn from_state(state: RescueStateVar) -> Result<Self, CircuitError> {
+        if RATE == 0 || RATE > STATE_SIZE {
+            return Err(CircuitError::ParameterError(format!(
+                "Rescue sponge rate should be in [1, {}], got {}",
+                STATE_SIZE, RATE
+            )));
+        }
+        Ok(Self { state })
+    }
+
+    /// The current state of the sponge.
+    pub fn state(&self) -> &RescueStateVar {
+        &self.state
+    }
+
+    /// Absorb `data_vars` in chunks of `RATE`, permuting the state after each
+    /// chunk, even if the last one is not full.
+    pub fn absorb<F: RescueParameter>(
+        &mut self,
+        circuit: &mut PlonkCircuit<F>,
+        data_vars: &[Variable],
+    ) -> Result<(), CircuitError> {
+        for chunk in data_vars.chunks(RATE) {
+            for (state_var, data_var) in self.state.0.iter_mut().zip(chunk.iter()) {
+                *state_var = circuit.add(*state_var, *data_var)?;
+            }
+            self.state = RescueNativeGadget::<F>::rescue_permutation(circuit, self.state.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Squeeze `num_output` variables, reading the rate part of the state and
+    /// permuting it after each read, see
+    /// [`RescueSponge::squeeze`](crate::sponge::RescueSponge::squeeze).
+    pub fn squeeze<F: RescueParameter>(
+        &mut self,
+        circuit: &mut PlonkCircuit<F>,
+        num_output: usize,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        let mut result = Vec::with_capacity(num_output);
+        while result.len() < num_output {
+            let extract = (num_output - result.len()).min(RATE);
+            result.extend_from_slice(&self.state.0[0..extract]);
+            self.state = RescueNativeGadget::<F>::rescue_permutation(circuit, self.state.clone())?;
+        }
+        Ok(result)
+    }
+}
+
+/// Variables for the state of a Rescue duplex sponge of rate `RATE`, see
+/// [`RescueDuplexSponge`](crate::sponge::RescueDuplexSponge). Domain-separation
+/// tags are constants of the circuit.
+#[derive(Debug, Clone)]
+pub struct RescueDuplexSpongeVar<const RATE: usize> {
+    state: RescueStateVar,
+    mode: DuplexMode,
+}
+
+impl<const RATE: usize> RescueDuplexSpongeVar<RATE> {
+    /// Create a duplex sponge for the domain-separation tag `tag`. Return an
+    /// error unless `0 < RATE < STATE_SIZE`.
+    pub fn new<F: RescueParameter>(
+        circuit: &mut PlonkCircuit<F>,
+        tag: F,
+    ) -> Result<Self, CircuitError> {
+        if RATE == 0 || RATE >= STATE_SIZE {
+            return Err(CircuitError::ParameterError(format!(
+                "Duplex sponge rate should be in [1, {}), got {}",
+                STATE_SIZE, RATE
+            )));
+        }
+        let mut state = [circuit.zero(); STATE_SIZE];
+        state[STATE_SIZE - 1] = circuit.create_constant_variable(tag)?;
+        Ok(Self {
+            state: RescueStateVar::from(state),
+            mode: DuplexMode::Absorbing(0),
+        })
+    }
+
+    /// Absorb `data_vars` into the rate part of the state.
+    pub fn absorb<F: RescueParameter>(
+        &mut self,
+        circuit: &mut PlonkCircuit<F>,
+        data_vars: &[Variable],
+    ) -> Result<(), CircuitError> {
+        for data_var in data_vars {
+            let pos = self.next_absorb_position(circuit)?;
+            self.state.0[pos] = circuit.add(self.state.0[pos], *data_var)?;
+            self.mode = DuplexMode::Absorbing(pos + 1);
+        }
+        Ok(())
+    }
+
+    /// Squeeze `num_output` variables from the rate part of the state.
+    pub fn squeeze<F: RescueParameter>(
+        &mut self,
+        circuit: &mut PlonkCircuit<F>,
+        num_output: usize,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        let mut result = Vec::with_capacity(num_output);
+        for _ in 0..num_output {
+            let pos = match self.mode {
+                DuplexMode::Squeezing(pos) if pos < RATE => pos,
+                _ => {
+                    self.permute(circuit)?;
+                    0
+                },
+            };
+            self.mode = DuplexMode::Squeezing(pos + 1);
+            result.push(self.state.0[pos]);
+        }
+        Ok(result)
+    }
+
+    /// End the current phase of the protocol and start a new one labelled by
+    /// the domain-separation tag `tag`.
+    pub fn separate<F: RescueParameter>(
+        &mut self,
+        circuit: &mut PlonkCircuit<F>,
+        tag: F,
+    ) -> Result<(), CircuitError> {
+        let pos = self.next_absorb_position(circuit)?;
+        self.state.0[pos] = circuit.add_constant(self.state.0[pos], &F::one())?;
+        self.state.0[STATE_SIZE - 1] = circuit.add_constant(self.state.0[STATE_SIZE - 1], &tag)?;
+        self.mode = DuplexMode::Absorbing(RATE);
+        Ok(())
+    }
+
+    fn next_absorb_position<F: RescueParameter>(
+        &mut self,
+        circuit: &mut PlonkCircuit<F>,
+    ) -> Result<usize, CircuitError> {
+        Ok(match self.mode {
+            DuplexMode::Absorbing(pos) if pos < RATE => pos,
+            DuplexMode::Absorbing(_) => {
+                self.permute(circuit)?;
+                0
+            },
+            DuplexMode::Squeezing(_) => 0,
+        })
+    }
+
+    fn permute<F: RescueParameter>(
+        &mut self,
+        circuit: &mut PlonkCircuit<F>,
+    ) -> Result<(), CircuitError> {
+        self.state = RescueNativeGadget::<F>::rescue_permutation(circuit, self.state.clone())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RescueDuplexSpongeVar, RescueSpongeVar};
+    use crate::{
+        sponge::{RescueDuplexSponge, RescueSponge},
+        Permutation, RescueParameter,
+    };
+    use ark_crypto_primitives::sponge::CryptographicSponge;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::{vec::Vec, UniformRand};
+    use jf_relation::{Circuit, PlonkCircuit, Variable};
+
+    #[test]
+    fn test_sponge_absorb_squeeze() {
+        test_sponge_absorb_squeeze_helper::<FqEd254>();
+        test_sponge_absorb_squeeze_helper::<FqEd377>();
+        test_sponge_absorb_squeeze_helper::<FqEd381>();
+    }
+    fn test_sponge_absorb_squeeze_helper<F: RescueParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let permutation = Permutation::default();
+        for (input_len, num_output) in [(0, 1), (1, 3), (3, 4), (5, 7)] {
+            let input: Vec<F> = (0..input_len).map(|_| F::rand(&mut rng)).collect();
+            let mut sponge = RescueSponge::<F, 3>::new(&permutation);
+            sponge.absorb(&input);
+            let expected = [sponge.squeeze(num_output), sponge.squeeze(2)].concat();
+
+            let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+            let input_vars: Vec<Variable> = input
+                .iter()
+                .map(|x| circuit.create_variable(*x).unwrap())
+                .collect();
+            let mut sponge_var = RescueSpongeVar::<3>::new(&mut circuit).unwrap();
+            sponge_var.absorb(&mut circuit, &input_vars).unwrap();
+            let output_vars = [
+                sponge_var.squeeze(&mut circuit, num_output).unwrap(),
+                sponge_var.squeeze(&mut circuit, 2).unwrap(),
+            ]
+            .concat();
+
+            assert_eq!(output_vars.len(), expected.len());
+            for (var, val) in output_vars.iter().zip(expected.iter()) {
+                assert_eq!(circuit.witness(*var).unwrap(), *val);
+            }
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+            *circuit.witness_mut(output_vars[num_output]) = F::from(1_u32);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        }
+
+        // bad rate
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        assert!(RescueSpongeVar::<0>::new(&mut circuit).is_err());
+        assert!(RescueSpongeVar::<5>::new(&mut circuit).is_err());
+    }
+
+    #[test]
+    fn test_duplex_sponge() {
+        test_duplex_sponge_helper::<FqEd254>();
+        test_duplex_sponge_helper::<FqEd377>();
+        test_duplex_sponge_helper::<FqEd381>();
+    }
+    fn test_duplex_sponge_helper<F: RescueParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let tag = F::from(3u8);
+        let input: Vec<F> = (0..7).map(|_| F::rand(&mut rng)).collect();
+
+        let mut duplex = RescueDuplexSponge::<F, 3>::new(tag).unwrap();
+        duplex.absorb(&input[..4]);
+        let mut expected = duplex.squeeze(2);
+        duplex.absorb(&input[4..5]);
+        expected.extend(duplex.squeeze(4));
+        duplex.separate(F::from(5u8));
+        duplex.absorb(&input[5..]);
+        expected.extend(duplex.squeeze(1));
+
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let input_vars: Vec<Variable> = input
+            .iter()
+            .map(|x| circuit.create_variable(*x).unwrap())
+            .collect();
+        let mut duplex_var = RescueDuplexSpongeVar::<3>::new(&mut circuit, tag).unwrap();
+        duplex_var.absorb(&mut circuit, &input_vars[..4]).unwrap();
+        let mut output_vars = duplex_var.squeeze(&mut circuit, 2).unwrap();
+        duplex_var.absorb(&mut circuit, &input_vars[4..5]).unwrap();
+        output_vars.extend(duplex_var.squeeze(&mut circuit, 4).unwrap());
+        duplex_var.separate(&mut circuit, F::from(5u8)).unwrap();
+        duplex_var.absorb(&mut circuit, &input_vars[5..]).unwrap();
+        output_vars.extend(duplex_var.squeeze(&mut circuit, 1).unwrap());
+
+        assert_eq!(output_vars.len(), expected.len());
+        for (var, val) in output_vars.iter().zip(expected.iter()) {
+            assert_eq!(circuit.witness(*var).unwrap(), *val);
+        }
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+        *circuit.witness_mut(input_vars[6]) = F::from(1_u32);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+
+        // bad rate
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        assert!(RescueDuplexSpongeVar::<3>::new(&mut circuit, tag).is_ok());
+        assert!(RescueDuplexSpongeVar::<4>::new(&mut circuit, tag).is_err());
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Circuit implementation of the domain-separated rescue hash and commitment,
+//! mirroring [`RescueCRHF`](crate::crhf::RescueCRHF) and
+//! [`FixedLengthRescueCommitment`](crate::commitment::FixedLengthRescueCommitment)
+//! instantiated with a tag.
+
+use super::{commitment::pad_with, PermutationGadget, RescueNativeGadget, RescueStateVar};
+use crate::{RescueParameter, CRHF_RATE};
+use ark_std::{string::ToString, vec, vec::Vec};
+use jf_relation::{Circuit, CircuitError, PlonkCircuit, Variable};
+
+/// Domain-separated hash and commitment gadgets, whose tags are constants of
+/// the circuit.
+pub trait TaggedRescueGadget<F> {
+    /// Sponge-based hash whose domain-separation tag is absorbed first
+    /// * `tag` - domain-separation tag
+    /// * `data_vars` - sponge input variables, `data_vars.len()` should be a
+    ///   positive integer that is a multiple of the sponge rate (i.e. 3)
+    /// * `num_output` - number of output variables
+    /// * `returns` - a vector of variables that refers to the sponge hash
+    ///   output
+    fn rescue_tagged_sponge_no_padding(
+        &mut self,
+        tag: F,
+        data_vars: &[Variable],
+        num_output: usize,
+    ) -> Result<Vec<Variable>, CircuitError>;
+
+    /// Similar to [`Self::rescue_tagged_sponge_no_padding`] except
+    /// `data_vars` are padded with "bit padding", i.e. a "1" followed by as
+    /// many "0" as needed.
+    fn rescue_tagged_sponge_with_padding(
+        &mut self,
+        tag: F,
+        data_vars: &[Variable],
+        num_output: usize,
+    ) -> Result<Vec<Variable>, CircuitError>;
+
+    /// Commitment function whose domain-separation tag is absorbed first
+    /// * `tag` - domain-separation tag
+    /// * `input` - input variables,
+    /// * `blinding` - blinding variable
+    /// * `returns` a variable that refers to the commitment value
+    fn commit_with_tag(
+        &mut self,
+        tag: F,
+        input: &[Variable],
+        blinding: Variable,
+    ) -> Result<Variable, CircuitError>;
+}
+
+impl<F> TaggedRescueGadget<F> for PlonkCircuit<F>
+where
+    F: RescueParameter,
+{
+    fn rescue_tagged_sponge_no_padding(
+        &mut self,
+        tag: F,
+        data_vars: &[Variable],
+        num_output: usize,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        if (data_vars.is_empty()) || (data_vars.len() % CRHF_RATE != 0) {
+            return Err(CircuitError::ParameterError("empty data vars".to_string()));
+        }
+        let zero_var = self.zero();
+        let tag_var = self.create_constant_variable(tag)?;
+
+        // ABSORB PHASE
+        let mut state_var = RescueStateVar::from([zero_var, zero_var, zero_var, tag_var]);
+        for block in data_vars.chunks_exact(CRHF_RATE) {
+            state_var = self.add_state(
+                &state_var,
+                &RescueStateVar::from([block[0], block[1], block[2], zero_var]),
+            )?;
+            state_var = RescueNativeGadget::<F>::rescue_permutation(self, state_var)?;
+        }
+
+        // SQUEEZE PHASE
+        let mut result = vec![];
+        let mut remaining = num_output;
+        // extract current rate before calling PRP again
+        loop {
+            let extract = remaining.min(CRHF_RATE);
+            result.extend_from_slice(&state_var.0[0..extract]);
+            remaining -= extract;
+            if remaining == 0 {
+                break;
+            }
+            state_var = RescueNativeGadget::<F>::rescue_permutation(self, state_var)?;
+        }
+
+        Ok(result)
+    }
+
+    fn rescue_tagged_sponge_with_padding(
+        &mut self,
+        tag: F,
+        data_vars: &[Variable],
+        num_output: usize,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        let mut data_vars = data_vars.to_vec();
+        data_vars.push(self.one());
+        pad_with(&mut data_vars, CRHF_RATE, self.zero());
+        self.rescue_tagged_sponge_no_padding(tag, &data_vars, num_output)
+    }
+
+    fn commit_with_tag(
+        &mut self,
+        tag: F,
+        input: &[Variable],
+        blinding: Variable,
+    ) -> Result<Variable, CircuitError> {
+        let mut msg = vec![blinding];
+        msg.extend_from_slice(input);
+        pad_with(&mut msg, CRHF_RATE, self.zero());
+        Ok(self.rescue_tagged_sponge_no_padding(tag, &msg, 1)?[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TaggedRescueGadget;
+    use crate::{commitment::FixedLengthRescueCommitment, crhf::RescueCRHF, RescueParameter};
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::{vec::Vec, UniformRand};
+    use jf_relation::{Circuit, PlonkCircuit, Variable};
+
+    #[test]
+    fn test_tagged_sponge_circuit() {
+        test_tagged_sponge_circuit_helper::<FqEd254>();
+        test_tagged_sponge_circuit_helper::<FqEd377>();
+        test_tagged_sponge_circuit_helper::<FqEd381>();
+        test_tagged_sponge_circuit_helper::<Fq377>();
+    }
+
+    fn test_tagged_sponge_circuit_helper<F: RescueParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let tag = F::rand(&mut rng);
+        for input_len in [1, 3, 5] {
+            let input: Vec<F> = (0..input_len).map(|_| F::rand(&mut rng)).collect();
+            let expected = RescueCRHF::tagged_sponge_with_bit_padding(&tag, &input, 4);
+
+            let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+            let input_vars: Vec<Variable> = input
+                .iter()
+                .map(|x| circuit.create_variable(*x).unwrap())
+                .collect();
+            let output_vars = circuit
+                .rescue_tagged_sponge_with_padding(tag, &input_vars, 4)
+                .unwrap();
+
+            assert_eq!(output_vars.len(), expected.len());
+            for (var, val) in output_vars.iter().zip(expected.iter()) {
+                assert_eq!(circuit.witness(*var).unwrap(), *val);
+            }
+            assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+            *circuit.witness_mut(output_vars[0]) = F::from(1_u32);
+            assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        }
+
+        // bad input length
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let var = circuit.create_variable(F::one()).unwrap();
+        assert!(circuit
+            .rescue_tagged_sponge_no_padding(tag, &[], 1)
+            .is_err());
+        assert!(circuit
+            .rescue_tagged_sponge_no_padding(tag, &[var, var], 1)
+            .is_err());
+    }
+
+    #[test]
+    fn test_commit_with_tag_circuit() {
+        test_commit_with_tag_circuit_helper::<FqEd254>();
+        test_commit_with_tag_circuit_helper::<FqEd377>();
+        test_commit_with_tag_circuit_helper::<FqEd381>();
+        test_commit_with_tag_circuit_helper::<Fq377>();
+    }
+
+    fn test_commit_with_tag_circuit_helper<F: RescueParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let tag = F::rand(&mut rng);
+        let blinding = F::rand(&mut rng);
+        let data = [F::rand(&mut rng), F::rand(&mut rng), F::rand(&mut rng)];
+        let expected =
+            FixedLengthRescueCommitment::<F, 3, 4>::commit_with_tag(&tag, &data, Some(&blinding))
+                .unwrap();
+
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let blinding_var = circuit.create_variable(blinding).unwrap();
+        let data_vars: Vec<Variable> = data
+            .iter()
+            .map(|x| circuit.create_variable(*x).unwrap())
+            .collect();
+        let commitment_var = circuit
+            .commit_with_tag(tag, &data_vars, blinding_var)
+            .unwrap();
+
+        assert_eq!(circuit.witness(commitment_var).unwrap(), expected);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+        *circuit.witness_mut(commitment_var) = F::from(1_u32);
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+    }
+}
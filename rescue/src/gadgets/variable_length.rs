@@ -0,0 +1,194 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Circuit implementation of the Rescue sponge hashing of a message whose
+//! length is a witness, as in [`RescueCRHF::sponge_with_length_prefix`].
+//!
+//! The circuit absorbs the length followed by all the message variables, the
+//! ones beyond the length being zeroed, and selects the sponge state after
+//! the last block containing a message element. The selections are driven by
+//! the one-hot encoding of the length, which also constrains it to be at most
+//! the number of message variables.
+//!
+//! [`RescueCRHF::sponge_with_length_prefix`]: crate::crhf::RescueCRHF::sponge_with_length_prefix
+
+use super::{PermutationGadget, RescueNativeGadget, RescueStateVar};
+use crate::{RescueParameter, CRHF_RATE, STATE_SIZE};
+use ark_std::{vec, vec::Vec};
+use jf_relation::{BoolVar, Circuit, CircuitError, PlonkCircuit, Variable};
+use jf_utils::compute_len_to_next_multiple;
+
+/// Circuit implementation of the hashing of messages of variable length.
+pub trait VariableLengthRescueGadget {
+    /// Return the `num_output` elements of the sponge hash of the first `len`
+    /// variables of `data_vars`, with the length prepended to the message.
+    /// The circuit is unsatisfiable if `len` is larger than
+    /// `data_vars.len()`.
+    /// * `data_vars` - message variables, of the maximal length of the message
+    /// * `len` - variable for the length of the message
+    /// * `num_output` - number of output variables
+    /// * `returns` - a vector of variables that refers to the sponge hash
+    ///   output
+    fn rescue_sponge_with_length(
+        &mut self,
+        data_vars: &[Variable],
+        len: Variable,
+        num_output: usize,
+    ) -> Result<Vec<Variable>, CircuitError>;
+}
+
+impl<F> VariableLengthRescueGadget for PlonkCircuit<F>
+where
+    F: RescueParameter,
+{
+    fn rescue_sponge_with_length(
+        &mut self,
+        data_vars: &[Variable],
+        len: Variable,
+        num_output: usize,
+    ) -> Result<Vec<Variable>, CircuitError> {
+        self.check_var_bound(len)?;
+        self.check_vars_bound(data_vars)?;
+        let max_len = data_vars.len();
+
+        // is_len[j] iff len = j, exactly one of them being set
+        let is_len = (0..=max_len)
+            .map(|j| {
+                let j = self.create_constant_variable(F::from(j as u64))?;
+                self.is_equal(len, j)
+            })
+            .collect::<Result<Vec<BoolVar>, CircuitError>>()?;
+        let is_len_vars: Vec<Variable> = is_len.iter().map(|&b| b.into()).collect();
+        let num_len = self.sum(&is_len_vars)?;
+        self.enforce_constant(num_len, F::one())?;
+
+        // the message element i is kept iff len > i
+        let mut padded = vec![self.zero(); max_len];
+        let mut keep = is_len_vars[max_len];
+        for i in (0..max_len).rev() {
+            padded[i] = self.mul(keep, data_vars[i])?;
+            if i > 0 {
+                keep = self.add(keep, is_len_vars[i])?;
+            }
+        }
+        padded.insert(0, len);
+        padded.resize(
+            compute_len_to_next_multiple(max_len + 1, CRHF_RATE),
+            self.zero(),
+        );
+
+        // the state after the block t is selected iff the last element of the
+        // length-prefixed message, at position len, is in the block t
+        let zero = self.zero();
+        let mut selected = [zero; STATE_SIZE];
+        let mut state = RescueStateVar::from([zero; STATE_SIZE]);
+        for (t, block) in padded.chunks_exact(CRHF_RATE).enumerate() {
+            state = self.add_state(
+                &state,
+                &RescueStateVar::from([block[0], block[1], block[2], zero]),
+            )?;
+            state = RescueNativeGadget::<F>::rescue_permutation(self, state)?;
+            let end = ((t + 1) * CRHF_RATE).min(max_len + 1);
+            let is_last = self.sum(&is_len_vars[t * CRHF_RATE..end])?;
+            for (selected, &elem) in selected.iter_mut().zip(state.array().iter()) {
+                *selected = self.mul_add(
+                    &[is_last, elem, *selected, self.one()],
+                    &[F::one(), F::one()],
+                )?;
+            }
+        }
+
+        // SQUEEZE PHASE
+        let mut state = RescueStateVar::from(selected);
+        let mut result = vec![];
+        let mut remaining = num_output;
+        while remaining > 0 {
+            let extract = remaining.min(CRHF_RATE);
+            result.extend_from_slice(&state.array()[0..extract]);
+            remaining -= extract;
+            if remaining > 0 {
+                state = RescueNativeGadget::<F>::rescue_permutation(self, state)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VariableLengthRescueGadget;
+    use crate::{crhf::RescueCRHF, RescueParameter};
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::vec::Vec;
+    use jf_relation::{Circuit, PlonkCircuit, Variable};
+
+    #[test]
+    fn test_rescue_sponge_with_length() {
+        test_rescue_sponge_with_length_helper::<FqEd254>();
+        test_rescue_sponge_with_length_helper::<FqEd377>();
+        test_rescue_sponge_with_length_helper::<FqEd381>();
+        test_rescue_sponge_with_length_helper::<Fq377>();
+    }
+
+    fn test_rescue_sponge_with_length_helper<F: RescueParameter>() {
+        let max_len = 7;
+        let input: Vec<F> = (0..max_len).map(|i| F::from((i + 10) as u32)).collect();
+        for len in 0..=max_len {
+            for output_len in [1, 4] {
+                let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+                let input_vars: Vec<Variable> = input
+                    .iter()
+                    .map(|&x| circuit.create_variable(x).unwrap())
+                    .collect();
+                let len_var = circuit.create_variable(F::from(len as u32)).unwrap();
+                let out_vars = circuit
+                    .rescue_sponge_with_length(&input_vars, len_var, output_len)
+                    .unwrap();
+
+                let expected = RescueCRHF::sponge_with_length_prefix(&input[..len], output_len);
+                for (&e, &var) in expected.iter().zip(out_vars.iter()) {
+                    assert_eq!(e, circuit.witness(var).unwrap());
+                }
+                assert_eq!(out_vars.len(), output_len);
+                assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+
+                // the elements beyond the length are not hashed
+                if len < max_len {
+                    *circuit.witness_mut(input_vars[len]) = F::from(1u32);
+                    assert!(circuit.check_circuit_satisfiability(&[]).is_ok());
+                }
+                // bad path: incorrect output
+                *circuit.witness_mut(out_vars[0]) += F::from(1u32);
+                assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+            }
+        }
+        // messages differing by a trailing zero
+        assert_ne!(
+            RescueCRHF::sponge_with_length_prefix(&[F::from(1u32), F::from(0u32)], 1),
+            RescueCRHF::sponge_with_length_prefix(&[F::from(1u32)], 1)
+        );
+
+        // a length beyond the number of message variables
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let input_vars: Vec<Variable> = input
+            .iter()
+            .map(|&x| circuit.create_variable(x).unwrap())
+            .collect();
+        let len_var = circuit
+            .create_variable(F::from(max_len as u32 + 1))
+            .unwrap();
+        circuit
+            .rescue_sponge_with_length(&input_vars, len_var, 1)
+            .unwrap();
+        assert!(circuit.check_circuit_satisfiability(&[]).is_err());
+        assert!(circuit
+            .rescue_sponge_with_length(&input_vars, circuit.num_vars(), 1)
+            .is_err());
+    }
+}
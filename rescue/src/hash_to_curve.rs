@@ -0,0 +1,189 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Hashing of bytes to field elements and to points of twisted Edwards curves
+//! whose base field has Rescue parameters, e.g. the Jubjub-style curves
+//! embedded in the scalar fields of pairing-friendly curves.
+//!
+//! Unlike hashes built on SHA-2, the field elements are squeezed from a Rescue
+//! sponge, so that the derivation of points can be replayed in circuits
+//! over the base field.
+
+use crate::{sponge::RescueDuplexSponge, RescueParameter, CRHF_RATE};
+use ark_ec::{
+    twisted_edwards::{Affine, TECurveConfig},
+    AffineRepr, CurveGroup,
+};
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_std::vec::Vec;
+use jf_utils::bytes_to_field_elements;
+
+/// Domain-separation tag of the sponge underlying [`hash_to_field`].
+pub(crate) const HASH_TO_FIELD_DOMAIN_SEPARATOR: &[u8] = b"JF-RESCUE-H2F";
+
+/// Hash `msg` to `num_elements` field elements, under the domain-separation
+/// tag `dst` of the calling protocol.
+///
+/// A [`RescueDuplexSponge`] absorbs the byte strings `dst` then `msg`, both
+/// converted with [`bytes_to_field_elements`], each phase being closed by
+/// [`RescueDuplexSponge::separate`], and the elements are squeezed from it.
+///
+/// # Panics
+///
+/// Panics if the field is too small to hold a `u64`, see
+/// [`bytes_to_field_elements`].
+pub fn hash_to_field<F: RescueParameter>(dst: &[u8], msg: &[u8], num_elements: usize) -> Vec<F> {
+    let domain_tag = F::from_le_bytes_mod_order(HASH_TO_FIELD_DOMAIN_SEPARATOR);
+    // `RescueDuplexSponge::new` only fails on a bad rate
+    let mut sponge = RescueDuplexSponge::<F, CRHF_RATE>::new(domain_tag).unwrap();
+    sponge.absorb(&bytes_to_field_elements::<_, F>(dst));
+    sponge.separate(F::zero());
+    sponge.absorb(&bytes_to_field_elements::<_, F>(msg));
+    sponge.separate(F::zero());
+    sponge.squeeze(num_elements)
+}
+
+/// Hash `msg` to a point of the prime-order subgroup of the twisted Edwards
+/// curve `P`, under the domain-separation tag `dst`.
+///
+/// This is the random-oracle construction of
+/// [RFC 9380](https://datatracker.ietf.org/doc/html/rfc9380#section-3): two
+/// field elements from [`hash_to_field`] are mapped with [`map_to_curve`],
+/// and the cofactor is cleared from their sum.
+pub fn hash_to_curve<P>(dst: &[u8], msg: &[u8]) -> Affine<P>
+where
+    P: TECurveConfig,
+    P::BaseField: RescueParameter,
+{
+    let u = hash_to_field::<P::BaseField>(dst, msg, 2);
+    let point = map_to_curve::<P>(&u[0]) + map_to_curve::<P>(&u[1]);
+    point.into_affine().clear_cofactor()
+}
+
+/// Map a field element to a point of the twisted Edwards curve `P`, with the
+/// Elligator 2 method of
+/// [RFC 9380](https://datatracker.ietf.org/doc/html/rfc9380#section-6.8.2)
+/// applied to the birationally equivalent Montgomery curve. The point may lie
+/// outside the prime-order subgroup.
+pub fn map_to_curve<P>(u: &P::BaseField) -> Affine<P>
+where
+    P: TECurveConfig,
+    P::BaseField: RescueParameter,
+{
+    let one = P::BaseField::one();
+    let a = P::COEFF_A;
+    let d = P::COEFF_D;
+    // the Montgomery curve K * t^2 = s^3 + J * s^2 + s
+    let denom = (a - d)
+        .inverse()
+        .expect("a and d of a twisted Edwards curve differ");
+    let j = (a + d).double() * denom;
+    let k = denom.double().double();
+
+    // Elligator 2 on the curve t^2 = x^3 + c1 * x^2 + c2 * x
+    let k_inv = k.inverse().unwrap();
+    let c1 = j * k_inv;
+    let c2 = k_inv.square();
+    let z = non_square::<P::BaseField>();
+
+    let mut tv1 = z * u.square();
+    if tv1 == -one {
+        tv1 = P::BaseField::zero();
+    }
+    let x1 = -c1 * (tv1 + one).inverse().unwrap();
+    let gx1 = (x1 + c1) * x1.square() + c2 * x1;
+    let x2 = -x1 - c1;
+    let gx2 = tv1 * gx1;
+    let (x, mut y, gx1_is_square) = match gx1.sqrt() {
+        Some(y1) => (x1, y1, true),
+        None => (
+            x2,
+            gx2.sqrt().expect("one of gx1 and gx2 is a square"),
+            false,
+        ),
+    };
+    if gx1_is_square != sgn0(&y) {
+        y = -y;
+    }
+    let s = x * k;
+    let t = y * k;
+
+    // the rational map from the Montgomery curve to the twisted Edwards curve
+    match (t.inverse(), (s + one).inverse()) {
+        (Some(t_inv), Some(s_plus_one_inv)) => {
+            Affine::new_unchecked(s * t_inv, (s - one) * s_plus_one_inv)
+        },
+        _ => Affine::zero(),
+    }
+}
+
+// The smallest non-square element of the field.
+fn non_square<F: PrimeField>() -> F {
+    let mut z = F::from(2u64);
+    while z.legendre().is_qr() {
+        z += F::one();
+    }
+    z
+}
+
+// The "sign" of a field element, i.e. the parity of its canonical
+// representative.
+fn sgn0<F: PrimeField>(x: &F) -> bool {
+    x.into_bigint().is_odd()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hash_to_curve, hash_to_field, map_to_curve};
+    use crate::RescueParameter;
+    use ark_ec::twisted_edwards::TECurveConfig;
+    use ark_ed_on_bls12_377::EdwardsConfig as Ed377Config;
+    use ark_ed_on_bls12_381::JubjubConfig;
+    use ark_ed_on_bls12_381_bandersnatch::BandersnatchConfig;
+    use ark_ed_on_bn254::EdwardsConfig as BabyJubjubConfig;
+    use ark_std::{vec::Vec, UniformRand};
+
+    #[test]
+    fn test_hash_to_field() {
+        type F = ark_ed_on_bn254::Fq;
+        let output: Vec<F> = hash_to_field(b"dst", b"message", 5);
+        assert_eq!(output.len(), 5);
+        assert_eq!(output, hash_to_field::<F>(b"dst", b"message", 5));
+        assert_eq!(output[..2], hash_to_field::<F>(b"dst", b"message", 2)[..]);
+        assert_ne!(output, hash_to_field::<F>(b"dst", b"messagE", 5));
+        assert_ne!(output, hash_to_field::<F>(b"dsT", b"message", 5));
+        // the boundary between the tag and the message matters
+        assert_ne!(output, hash_to_field::<F>(b"dstm", b"essage", 5));
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        test_hash_to_curve_helper::<JubjubConfig>();
+        test_hash_to_curve_helper::<BandersnatchConfig>();
+        test_hash_to_curve_helper::<BabyJubjubConfig>();
+        test_hash_to_curve_helper::<Ed377Config>();
+    }
+
+    fn test_hash_to_curve_helper<P>()
+    where
+        P: TECurveConfig,
+        P::BaseField: RescueParameter,
+    {
+        let mut rng = jf_utils::test_rng();
+        for _ in 0..10 {
+            let point = map_to_curve::<P>(&P::BaseField::rand(&mut rng));
+            assert!(point.is_on_curve());
+        }
+        assert!(map_to_curve::<P>(&P::BaseField::zero()).is_on_curve());
+
+        let point = hash_to_curve::<P>(b"dst", b"message");
+        assert!(point.is_on_curve());
+        assert!(point.is_in_correct_subgroup_assuming_on_curve());
+        assert_eq!(point, hash_to_curve::<P>(b"dst", b"message"));
+        assert_ne!(point, hash_to_curve::<P>(b"dst", b"messagE"));
+        assert_ne!(point, hash_to_curve::<P>(b"dsT", b"message"));
+    }
+}
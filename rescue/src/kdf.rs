@@ -0,0 +1,101 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! A rescue hierarchical key derivation function (KDF), based on a keyed
+//! duplex sponge.
+
+use crate::{sponge::RescueDuplexSponge, RescueParameter, CRHF_RATE};
+use ark_std::marker::PhantomData;
+
+/// Domain-separation tag of the sponge underlying [`RescueKDF`].
+pub(crate) const KDF_DOMAIN_SEPARATOR: &[u8] = b"JF-RESCUE-KDF";
+
+/// Derivation of child keys from a parent key and a label.
+///
+/// A child key is squeezed from a [`RescueDuplexSponge`] of rate
+/// [`CRHF_RATE`] initialized with a KDF-specific domain-separation tag, which
+/// absorbs the parent key, then the label, each phase being closed by
+/// [`RescueDuplexSponge::separate`] so that labels of different lengths never
+/// collide. Keys derived for different purposes, e.g. note encryption and
+/// nullifiers, should use different labels.
+#[derive(Debug, Clone)]
+pub struct RescueKDF<F: RescueParameter>(PhantomData<F>);
+
+impl<F: RescueParameter> RescueKDF<F> {
+    /// Derive the child key of `parent` for `label`.
+    pub fn derive(parent: &F, label: &[F]) -> F {
+        let domain_tag = F::from_le_bytes_mod_order(KDF_DOMAIN_SEPARATOR);
+        // `RescueDuplexSponge::new` only fails on a bad rate
+        let mut sponge = RescueDuplexSponge::<F, CRHF_RATE>::new(domain_tag).unwrap();
+        sponge.absorb(&[*parent]);
+        sponge.separate(F::zero());
+        sponge.absorb(label);
+        sponge.separate(F::zero());
+        sponge.squeeze(1)[0]
+    }
+
+    /// Derive the `index`-th child key of `parent`, i.e. the child key for the
+    /// label `[index]`.
+    pub fn derive_child(parent: &F, index: u64) -> F {
+        Self::derive(parent, &[F::from(index)])
+    }
+
+    /// Derive the key at `path` below `master`, each index of the path
+    /// selecting a child of the previous key as in [`Self::derive_child`].
+    pub fn derive_path(master: &F, path: &[u64]) -> F {
+        path.iter()
+            .fold(*master, |key, &index| Self::derive_child(&key, index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RescueKDF;
+    use crate::RescueParameter;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::UniformRand;
+
+    #[test]
+    fn test_kdf() {
+        test_kdf_helper::<FqEd254>();
+        test_kdf_helper::<FqEd377>();
+        test_kdf_helper::<FqEd381>();
+        test_kdf_helper::<Fq377>();
+    }
+
+    fn test_kdf_helper<F: RescueParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let master = F::rand(&mut rng);
+        let label = [F::from(1u8), F::from(2u8)];
+
+        let child = RescueKDF::derive(&master, &label);
+        assert_eq!(child, RescueKDF::derive(&master, &label));
+        assert_ne!(child, master);
+        assert_ne!(child, RescueKDF::derive(&(master + F::one()), &label));
+        assert_ne!(child, RescueKDF::derive(&master, &label[..1]));
+        assert_ne!(
+            child,
+            RescueKDF::derive(&master, &[label[0], label[1], F::zero()])
+        );
+
+        assert_eq!(
+            RescueKDF::derive_child(&master, 7),
+            RescueKDF::derive(&master, &[F::from(7u64)])
+        );
+        assert_ne!(
+            RescueKDF::derive_child(&master, 7),
+            RescueKDF::derive_child(&master, 8)
+        );
+        assert_eq!(
+            RescueKDF::derive_path(&master, &[3, 5]),
+            RescueKDF::derive_child(&RescueKDF::derive_child(&master, 3), 5)
+        );
+        assert_eq!(RescueKDF::derive_path(&master, &[]), master);
+    }
+}
@@ -9,6 +9,8 @@
 //! - ed_on_bls12_377 base field
 //! - ed_on_bls12_381 base field
 //! - ed_on_bn254 base field
+//! - pallas base field
+//! - vesta base field
 //!
 //! It also has place holders for
 //! - bls12_381 base field
@@ -16,6 +18,22 @@
 //! - bw6_761 base field
 //!
 //! Those three place holders should never be used.
+//!
+//! The [Rescue-Prime](rescue_prime) variant is also available over some of
+//! these fields.
+//!
+//! The control flow of the native primitives (sponges, PRF, PRG, KDF, MAC,
+//! AEAD and commitments) only depends on public data, i.e. input lengths,
+//! rates and positions in the sponge states, and the exponents of the S-boxes
+//! are public constants. Tags, openings and commitments are compared in
+//! constant time rather than with `==`. However, the field arithmetic of
+//! `ark-ff` is not constant time, e.g. Montgomery multiplication ends with a
+//! conditional subtraction, so this crate makes no constant-time guarantee as
+//! a whole. The keyed types, i.e. the [duplex
+//! sponge](sponge::RescueDuplexSponge) underlying the MAC, KDF and AEAD, the
+//! PRF and the [PRG](prg::RescuePRG), zeroize their states when dropped; the
+//! [`RescueSponge`](sponge::RescueSponge) of the unkeyed hashes and the other
+//! types holding secrets only implement [`Zeroize`](zeroize::Zeroize).
 
 #![cfg_attr(not(feature = "std"), no_std)]
 // Temporarily allow warning for nightly compilation with [`displaydoc`].
@@ -28,28 +46,53 @@ extern crate std;
 #[doc(hidden)]
 extern crate alloc;
 
+pub mod aead;
 pub mod commitment;
 pub mod crhf;
 #[cfg(feature = "gadgets")]
 pub mod gadgets;
+pub mod hash_to_curve;
+pub mod kdf;
+pub mod mac;
+#[cfg(feature = "param-gen")]
+pub mod param_gen;
 pub mod prf;
+pub mod prg;
 mod rescue_constants;
+pub mod rescue_prime;
 pub mod sponge;
+pub mod tree_hash;
 
 use ark_crypto_primitives::sponge::Absorb;
-use ark_ff::{PrimeField, Zero};
+use ark_ff::{BigInteger, PrimeField, Zero};
 use ark_std::{string::String, vec, vec::Vec};
 use displaydoc::Display;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use zeroize::Zeroize;
 
 /// Rescue error type
 #[derive(Debug, Display, Eq, PartialEq)]
 pub enum RescueError {
     /// Bad parameter in function call, {0}
     ParameterError(String),
+    /// Failed verification, {0}
+    VerificationError(String),
 }
 
 impl ark_std::error::Error for RescueError {}
 
+/// Compare two field elements in constant time, i.e. without branching on the
+/// position of their first difference.
+pub(crate) fn ct_eq<F: PrimeField>(a: &F, b: &F) -> bool {
+    let a = a.into_bigint().to_bytes_le();
+    let b = b.into_bigint().to_bytes_le();
+    a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+        == 0
+}
+
 /// The state size of rescue hash.
 pub const STATE_SIZE: usize = 4;
 /// The rate of the sponge used in RescueCRHF.
@@ -114,7 +157,7 @@ pub trait RescueParameter: PrimeField + Absorb {
     const PERMUTATION_ROUND_KEYS: [[&'static [u8]; 4]; 25];
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Copy, Default)]
+#[derive(Clone, Debug, Eq, PartialEq, Copy, Default, Zeroize)]
 /// Data type for rescue prp inputs, keys and internal data
 pub struct RescueVector<F> {
     pub(crate) vec: [F; STATE_SIZE],
@@ -440,6 +483,23 @@ impl<F: RescueParameter> Permutation<F> {
         self.rescue_prp
             .prp_with_round_keys(self.round_keys.as_slice(), input)
     }
+
+    /// Compute the permutation on each of the `states`, in place. With the
+    /// `parallel` feature on, the states are permuted in parallel.
+    ///
+    /// Note that the permutation involves no field inversion, so there is
+    /// nothing to share between states beyond the round keys and the MDS
+    /// matrix.
+    pub fn permute_batch(&self, states: &mut [RescueVector<F>]) {
+        #[cfg(feature = "parallel")]
+        states
+            .par_iter_mut()
+            .for_each(|state| *state = self.eval(state));
+        #[cfg(not(feature = "parallel"))]
+        states
+            .iter_mut()
+            .for_each(|state| *state = self.eval(state));
+    }
 }
 
 #[cfg(test)]
@@ -450,6 +510,7 @@ mod test_prp {
     use ark_ed_on_bls12_377::Fq as Fr377;
     use ark_ed_on_bls12_381::Fq as Fr381;
     use ark_ed_on_bn254::Fq as Fr254;
+    use ark_pallas::{Fq as FqPallas, Fr as FqVesta};
 
     // hash output on vector [0, 0, 0, 0]
     // this value is cross checked with sage script
@@ -581,6 +642,56 @@ mod test_prp {
         ],
     ];
 
+    // this value is computed with an independent implementation of the
+    // procedure described in the constants file
+    const OUTPUTPALLAS: [[u8; 32]; 4] = [
+        [
+            0xCF, 0x70, 0x58, 0x7E, 0xE9, 0x9F, 0xFD, 0x18, 0x84, 0xBF, 0x75, 0xE9, 0x88, 0x4A,
+            0x78, 0x63, 0x10, 0x65, 0x40, 0x63, 0x15, 0x17, 0x5E, 0xD9, 0x88, 0xDC, 0x85, 0xF3,
+            0x44, 0xF9, 0x55, 0x2B,
+        ],
+        [
+            0x57, 0x75, 0x6E, 0xB0, 0xD5, 0x43, 0x0D, 0x04, 0x6A, 0x9F, 0x5B, 0x19, 0xA8, 0xDC,
+            0xB9, 0xE4, 0xA1, 0xC5, 0xF7, 0x27, 0x68, 0x9D, 0x9E, 0x20, 0x8B, 0x5C, 0xEE, 0x86,
+            0x65, 0x7F, 0x36, 0x35,
+        ],
+        [
+            0x48, 0xEE, 0xE3, 0xD3, 0xE6, 0xC1, 0xAC, 0x85, 0x52, 0xD5, 0x2A, 0x26, 0x38, 0x34,
+            0x95, 0x3F, 0x24, 0xA7, 0x93, 0xCF, 0xF9, 0xF9, 0x77, 0x7F, 0x72, 0xF0, 0xB5, 0x76,
+            0xA5, 0x6E, 0xD8, 0x15,
+        ],
+        [
+            0x2E, 0xFB, 0xAD, 0xF3, 0x3F, 0x2C, 0x4D, 0x8F, 0x1C, 0x22, 0x35, 0x1F, 0x48, 0x3C,
+            0x04, 0x74, 0x05, 0x91, 0x77, 0xA1, 0xE2, 0x25, 0x19, 0xE0, 0x06, 0xD8, 0x61, 0x39,
+            0x29, 0xF9, 0xB4, 0x1D,
+        ],
+    ];
+
+    // this value is computed with an independent implementation of the
+    // procedure described in the constants file
+    const OUTPUTVESTA: [[u8; 32]; 4] = [
+        [
+            0xC3, 0x4D, 0xAD, 0x4A, 0xC4, 0x41, 0x73, 0xBE, 0x68, 0x89, 0x74, 0xD7, 0xE2, 0x8C,
+            0xC8, 0x70, 0x86, 0x1A, 0xCC, 0x24, 0x2E, 0x78, 0xF7, 0xCB, 0x6D, 0x87, 0x47, 0x73,
+            0x9C, 0xAA, 0x12, 0x30,
+        ],
+        [
+            0xE6, 0xD4, 0xE8, 0x31, 0x7F, 0x26, 0xA5, 0x20, 0xA1, 0xF0, 0xD4, 0x1A, 0x62, 0x4A,
+            0xA6, 0x5C, 0x3A, 0x24, 0x0F, 0x9D, 0x06, 0xB4, 0xAE, 0xCC, 0x36, 0xE4, 0x01, 0xB6,
+            0x9A, 0x01, 0x5D, 0x02,
+        ],
+        [
+            0x94, 0xC9, 0x31, 0xF0, 0xDE, 0x51, 0xA5, 0xDE, 0x5D, 0xFF, 0xDA, 0xA2, 0x8A, 0x28,
+            0x7F, 0xCD, 0x7E, 0x7A, 0x7B, 0x1C, 0xEE, 0x8B, 0xDC, 0x64, 0x18, 0xB0, 0xC0, 0xC0,
+            0x5A, 0x69, 0x9E, 0x0A,
+        ],
+        [
+            0x05, 0x0C, 0xA0, 0xC7, 0xE0, 0xF2, 0xCB, 0x8E, 0x53, 0x30, 0x90, 0x63, 0x63, 0x92,
+            0xD7, 0x50, 0x49, 0x87, 0x03, 0xFC, 0x72, 0x2B, 0xFE, 0x45, 0xB7, 0xFD, 0x38, 0x0D,
+            0xF4, 0x9A, 0x45, 0x3A,
+        ],
+    ];
+
     #[test]
     fn test_rescue_perm_on_0_vec() {
         test_rescue_perm_on_0_vec_fq254();
@@ -588,6 +699,8 @@ mod test_prp {
         test_rescue_perm_on_0_vec_377();
         test_rescue_perm_on_0_vec_381();
         test_rescue_perm_on_0_vec_761();
+        test_rescue_perm_on_0_vec_pallas();
+        test_rescue_perm_on_0_vec_vesta();
     }
 
     fn test_rescue_perm_on_0_vec_fq254() {
@@ -677,6 +790,40 @@ mod test_prp {
         assert_eq!(real_output, expected);
     }
 
+    fn test_rescue_perm_on_0_vec_pallas() {
+        let rescue = PRP::<FqPallas>::default();
+        let key = RescueVector::zero();
+        let input = RescueVector::zero();
+        let expected = RescueVector::from_elems_le_bytes(
+            &OUTPUTPALLAS[0],
+            &OUTPUTPALLAS[1],
+            &OUTPUTPALLAS[2],
+            &OUTPUTPALLAS[3],
+        );
+        let real_output = rescue.prp(&key, &input);
+        let round_keys = rescue.key_schedule(&key);
+        let real_output_with_round_keys = rescue.prp_with_round_keys(&round_keys, &input);
+        assert_eq!(real_output, real_output_with_round_keys);
+        assert_eq!(real_output, expected);
+    }
+
+    fn test_rescue_perm_on_0_vec_vesta() {
+        let rescue = PRP::<FqVesta>::default();
+        let key = RescueVector::zero();
+        let input = RescueVector::zero();
+        let expected = RescueVector::from_elems_le_bytes(
+            &OUTPUTVESTA[0],
+            &OUTPUTVESTA[1],
+            &OUTPUTVESTA[2],
+            &OUTPUTVESTA[3],
+        );
+        let real_output = rescue.prp(&key, &input);
+        let round_keys = rescue.key_schedule(&key);
+        let real_output_with_round_keys = rescue.prp_with_round_keys(&round_keys, &input);
+        assert_eq!(real_output, real_output_with_round_keys);
+        assert_eq!(real_output, expected);
+    }
+
     // printing vectors as hex bytes little endian
     // use ark_ff::{BigInteger, PrimeField};
     // use ark_std::{format, println, string::String, vec::Vec};
@@ -710,7 +857,8 @@ mod test_permutation {
     use ark_ed_on_bls12_381::Fq as Fr381;
     use ark_ed_on_bn254::Fq as Fr254;
     use ark_ff::PrimeField;
-    use ark_std::{vec, Zero};
+    use ark_pallas::{Fq as FqPallas, Fr as FqVesta};
+    use ark_std::{vec, vec::Vec, Zero};
 
     #[test]
     fn test_round_keys() {
@@ -719,6 +867,8 @@ mod test_permutation {
         test_round_keys_helper::<Fr377>();
         test_round_keys_helper::<Fr381>();
         test_round_keys_helper::<Fq377>();
+        test_round_keys_helper::<FqPallas>();
+        test_round_keys_helper::<FqVesta>();
     }
 
     fn test_round_keys_helper<F: RescueParameter>() {
@@ -857,6 +1007,36 @@ mod test_permutation {
         test_sponge_helper::<Fr377>();
         test_sponge_helper::<Fr381>();
         test_sponge_helper::<Fq377>();
+        test_sponge_helper::<FqPallas>();
+        test_sponge_helper::<FqVesta>();
+    }
+
+    #[test]
+    fn test_permute_batch() {
+        test_permute_batch_helper::<Fr254>();
+        test_permute_batch_helper::<Fr377>();
+        test_permute_batch_helper::<Fr381>();
+        test_permute_batch_helper::<Fq377>();
+    }
+
+    fn test_permute_batch_helper<F: RescueParameter>() {
+        let permutation = Permutation::default();
+        let mut prng = jf_utils::test_rng();
+        let mut states: Vec<RescueVector<F>> = (0..17)
+            .map(|_| {
+                RescueVector::from(&[
+                    F::rand(&mut prng),
+                    F::rand(&mut prng),
+                    F::rand(&mut prng),
+                    F::rand(&mut prng),
+                ])
+            })
+            .collect();
+        let expected: Vec<RescueVector<F>> = states.iter().map(|s| permutation.eval(s)).collect();
+        permutation.permute_batch(&mut states);
+        assert_eq!(states, expected);
+
+        permutation.permute_batch(&mut []);
     }
 
     fn test_sponge_helper<F: RescueParameter>() {
@@ -883,6 +1063,48 @@ mod test_permutation {
         assert_eq!(output, state.vec[0]);
     }
 
+    #[test]
+    fn test_tagged_sponge() {
+        test_tagged_sponge_helper::<Fq254>();
+        test_tagged_sponge_helper::<Fr377>();
+        test_tagged_sponge_helper::<Fr381>();
+        test_tagged_sponge_helper::<Fq377>();
+    }
+
+    fn test_tagged_sponge_helper<F: RescueParameter>() {
+        let rescue_prp = PRP::default();
+        let mut prng = jf_utils::test_rng();
+        let tag = F::rand(&mut prng);
+        let input: Vec<F> = (0..6).map(|_| F::rand(&mut prng)).collect();
+
+        let output = RescueCRHF::<F>::tagged_sponge_no_padding(&tag, &input, 1).unwrap()[0];
+
+        let zero = RescueVector::zero();
+        let mut state = RescueVector {
+            vec: [input[0], input[1], input[2], tag],
+        };
+        state = rescue_prp.prp(&zero, &state);
+        state.add_assign_elems(&input[3..6]);
+        state = rescue_prp.prp(&zero, &state);
+        assert_eq!(output, state.vec[0]);
+
+        // a zero tag yields the untagged hash
+        assert_eq!(
+            RescueCRHF::<F>::tagged_sponge_with_bit_padding(&F::zero(), &input[..4], 2),
+            RescueCRHF::<F>::sponge_with_bit_padding(&input[..4], 2)
+        );
+        assert_eq!(
+            RescueCRHF::<F>::tagged_sponge_with_zero_padding(&F::zero(), &input[..4], 2),
+            RescueCRHF::<F>::sponge_with_zero_padding(&input[..4], 2)
+        );
+        // different tags yield different hashes
+        assert_ne!(
+            RescueCRHF::<F>::tagged_sponge_with_bit_padding(&tag, &input[..4], 2),
+            RescueCRHF::<F>::tagged_sponge_with_bit_padding(&(tag + F::one()), &input[..4], 2)
+        );
+        assert!(RescueCRHF::<F>::tagged_sponge_no_padding(&tag, &input[..4], 1).is_err());
+    }
+
     #[test]
     fn test_rescue_hash_on_0_vec() {
         test_rescue_hash_on_0_vec_fq254();
@@ -0,0 +1,90 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! A rescue MAC implementation, based on a keyed duplex sponge.
+
+use crate::{ct_eq, sponge::RescueDuplexSponge, RescueError, RescueParameter, CRHF_RATE};
+use ark_std::{marker::PhantomData, string::ToString};
+
+/// Domain-separation tag of the sponge underlying [`RescueMAC`].
+pub(crate) const MAC_DOMAIN_SEPARATOR: &[u8] = b"JF-RESCUE-MAC";
+
+/// Message authentication code over field elements.
+///
+/// The tag of a message is computed with a [`RescueDuplexSponge`] of rate
+/// [`CRHF_RATE`] initialized with a MAC-specific domain-separation tag, which
+/// absorbs the key, then the message, each phase being closed by
+/// [`RescueDuplexSponge::separate`] so that messages of different lengths
+/// never collide, and finally squeezes a single field element.
+#[derive(Debug, Clone)]
+pub struct RescueMAC<F: RescueParameter>(PhantomData<F>);
+
+impl<F: RescueParameter> RescueMAC<F> {
+    /// Compute the tag of `msg` under `key`.
+    pub fn mac(key: &F, msg: &[F]) -> F {
+        let domain_tag = F::from_le_bytes_mod_order(MAC_DOMAIN_SEPARATOR);
+        // `RescueDuplexSponge::new` only fails on a bad rate
+        let mut sponge = RescueDuplexSponge::<F, CRHF_RATE>::new(domain_tag).unwrap();
+        sponge.absorb(&[*key]);
+        sponge.separate(F::zero());
+        sponge.absorb(msg);
+        sponge.separate(F::zero());
+        sponge.squeeze(1)[0]
+    }
+
+    /// Verify that `tag` is the tag of `msg` under `key`. The comparison of
+    /// the expected and given tags runs in constant time.
+    pub fn verify(key: &F, msg: &[F], tag: &F) -> Result<(), RescueError> {
+        if ct_eq(&Self::mac(key, msg), tag) {
+            Ok(())
+        } else {
+            Err(RescueError::VerificationError(
+                "Rescue MAC Error: invalid tag.".to_string(),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RescueMAC;
+    use crate::RescueParameter;
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::{vec::Vec, UniformRand};
+
+    #[test]
+    fn test_mac() {
+        test_mac_helper::<FqEd254>();
+        test_mac_helper::<FqEd377>();
+        test_mac_helper::<FqEd381>();
+        test_mac_helper::<Fq377>();
+    }
+
+    fn test_mac_helper<F: RescueParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let key = F::rand(&mut rng);
+        for msg_len in [0, 1, 2, 3, 7] {
+            let msg: Vec<F> = (0..msg_len).map(|_| F::rand(&mut rng)).collect();
+            let tag = RescueMAC::mac(&key, &msg);
+            assert!(RescueMAC::verify(&key, &msg, &tag).is_ok());
+
+            // wrong tag, key or message
+            assert!(RescueMAC::verify(&key, &msg, &(tag + F::one())).is_err());
+            assert!(RescueMAC::verify(&(key + F::one()), &msg, &tag).is_err());
+            let mut bad_msg = msg.clone();
+            bad_msg.push(F::zero());
+            assert!(RescueMAC::verify(&key, &bad_msg, &tag).is_err());
+            if msg_len > 0 {
+                bad_msg = msg.clone();
+                bad_msg[0] += F::one();
+                assert!(RescueMAC::verify(&key, &bad_msg, &tag).is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,278 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Derivation of the constants of a new [`RescueParameter`] instance from a
+//! seed, so that supporting a new field doesn't require importing the output
+//! of the reference Sage script by hand.
+//!
+//! The constants follow the procedure of
+//! <https://github.com/EspressoSystems/Marvellous/blob/fcd4c41672f485ac2f62526bc87a16789d4d0459/rescue254.sage>,
+//! except that the key schedule constants, which the script samples at
+//! random, are read from the output of SHAKE256 on the seed:
+//! - `alpha` is the smallest integer `>= 3` coprime with `p - 1`;
+//! - the MDS matrix is the right half of the echelon form of the `4 x 8`
+//!   Vandermonde matrix `[g^(i * j)]`, where `g` is the multiplicative
+//!   generator of the field;
+//! - the initial vector, the key schedule matrix (row by row) and the key
+//!   schedule vector are the consecutive 64-byte little-endian integers,
+//!   reduced mod `p`, of `SHAKE256(seed)`;
+//! - the key injection constants and the permutation round keys are derived
+//!   from the above as in [`PRP::key_schedule`](crate::PRP::key_schedule).
+//!
+//! [`RescueParameter`]: crate::RescueParameter
+
+use crate::{RescueMatrix, RescueVector, ROUNDS, STATE_SIZE};
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::{format, string::String, vec, vec::Vec};
+use num_bigint::BigUint;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+/// Number of field elements read from the seed: the initial vector, the key
+/// schedule matrix and the key schedule vector.
+const NUM_SEEDED_ELEMS: usize = STATE_SIZE * (STATE_SIZE + 2);
+
+/// The constants of a Rescue instance over the field `F`.
+#[derive(Debug, Clone)]
+pub struct RescueConstants<F> {
+    /// The S-box exponent
+    pub alpha: u64,
+    /// The inverse of `alpha` modulo `p - 1`, in little-endian `u64` limbs
+    pub alpha_inv: Vec<u64>,
+    /// The MDS matrix
+    pub mds: RescueMatrix<F>,
+    /// The initial vector of the key schedule
+    pub init_vec: RescueVector<F>,
+    /// The matrix of the key schedule
+    pub key_sched_matrix: RescueMatrix<F>,
+    /// The vector of the key schedule
+    pub key_sched_vec: RescueVector<F>,
+    /// The `2 * ROUNDS` key injection constants
+    pub key_injection: Vec<RescueVector<F>>,
+    /// The `2 * ROUNDS + 1` round keys of the permutation, i.e. the key
+    /// schedule of the zero key
+    pub permutation_round_keys: Vec<RescueVector<F>>,
+}
+
+impl<F: PrimeField> RescueConstants<F> {
+    /// Derive the constants of the Rescue instance over `F` from `seed`.
+    pub fn from_seed(seed: &[u8]) -> Self {
+        let (alpha, alpha_inv) = sbox_exponents::<F>();
+
+        let mut reader = Shake256::default().chain(seed).finalize_xof();
+        let elems: Vec<F> = (0..NUM_SEEDED_ELEMS)
+            .map(|_| {
+                let mut buf = [0u8; 64];
+                reader.read(&mut buf);
+                F::from_le_bytes_mod_order(&buf)
+            })
+            .collect();
+        let vector = |i: usize| RescueVector::from(&elems[i * STATE_SIZE..(i + 1) * STATE_SIZE]);
+        let init_vec = vector(0);
+        let key_sched_matrix = RescueMatrix::from(&[vector(1), vector(2), vector(3), vector(4)]);
+        let key_sched_vec = vector(5);
+
+        let mds = echelon_mds::<F>();
+
+        let mut key_injection = Vec::with_capacity(2 * ROUNDS);
+        let mut aux = init_vec;
+        for _ in 0..2 * ROUNDS {
+            aux.linear(&key_sched_matrix, &key_sched_vec);
+            key_injection.push(aux);
+        }
+
+        let mut aux = init_vec;
+        let mut permutation_round_keys = vec![aux];
+        for (i, key) in key_injection.iter().enumerate() {
+            if i % 2 == 0 {
+                aux.pow(&alpha_inv);
+            } else {
+                aux.pow(&[alpha]);
+            }
+            aux.linear(&mds, key);
+            permutation_round_keys.push(aux);
+        }
+
+        Self {
+            alpha,
+            alpha_inv,
+            mds,
+            init_vec,
+            key_sched_matrix,
+            key_sched_vec,
+            key_injection,
+            permutation_round_keys,
+        }
+    }
+
+    /// Return the Rust source of the `RescueParameter` implementation for the
+    /// field type `field_type`, to be placed in a file of
+    /// `rescue_constants` importing `RescueParameter`, `ROUNDS`, `STATE_SIZE`
+    /// and the field type.
+    pub fn to_rust_source(&self, field_type: &str) -> String {
+        let mut src = format!("impl RescueParameter for {} {{\n", field_type);
+        src.push_str(&format!("    const A: u64 = {};\n", self.alpha));
+        src.push_str("    const A_INV: &'static [u64] = &[\n");
+        for limb in self.alpha_inv.iter() {
+            src.push_str(&format!("        0x{:016x},\n", limb));
+        }
+        src.push_str("    ];\n\n");
+
+        src.push_str("    const MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE] = [\n");
+        for i in 0..STATE_SIZE {
+            src.push_str(&vector_source(&self.mds.vec(i), 8));
+        }
+        src.push_str("    ];\n\n");
+
+        src.push_str("    const INIT_VEC_LE: [&'static [u8]; STATE_SIZE] = [\n");
+        for elem in self.init_vec.vec.iter() {
+            src.push_str(&elem_source(elem, 8));
+        }
+        src.push_str("    ];\n\n");
+
+        src.push_str("    const KEY_INJECTION_LE: [[&'static [u8]; 4]; 2 * ROUNDS] = [\n");
+        for key in self.key_injection.iter() {
+            src.push_str(&vector_source(key, 8));
+        }
+        src.push_str("    ];\n\n");
+
+        src.push_str("    const PERMUTATION_ROUND_KEYS: [[&'static [u8]; 4]; 25] = [\n");
+        for key in self.permutation_round_keys.iter() {
+            src.push_str(&vector_source(key, 8));
+        }
+        src.push_str("    ];\n}\n");
+        src
+    }
+}
+
+// Return `alpha`, the smallest integer `>= 3` coprime with `p - 1`, and its
+// inverse modulo `p - 1`.
+fn sbox_exponents<F: PrimeField>() -> (u64, Vec<u64>) {
+    let modulus: BigUint = F::MODULUS.into();
+    let modulus_minus_one = modulus - 1u32;
+    let rem = |x: &BigUint, a: u64| (x % a).iter_u64_digits().next().unwrap_or(0);
+    let alpha = (3u64..)
+        .find(|&a| gcd(a, rem(&modulus_minus_one, a)) == 1)
+        .expect("there are integers coprime with p - 1");
+    // alpha_inv = (k * (p - 1) + 1) / alpha, for the k < alpha making it exact
+    let alpha_inv = (1..alpha)
+        .map(|k| &modulus_minus_one * k + 1u32)
+        .find(|x| rem(x, alpha) == 0)
+        .map(|x| x / alpha)
+        .unwrap_or_else(|| BigUint::from(1u32));
+    (alpha, alpha_inv.to_u64_digits())
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// The right half of the echelon form of the Vandermonde matrix [g^(i * j)] of
+// size `STATE_SIZE x (2 * STATE_SIZE)`.
+fn echelon_mds<F: PrimeField>() -> RescueMatrix<F> {
+    let mut rows: Vec<Vec<F>> = (0..STATE_SIZE)
+        .map(|i| {
+            (0..2 * STATE_SIZE)
+                .map(|j| F::GENERATOR.pow([(i * j) as u64]))
+                .collect()
+        })
+        .collect();
+    for col in 0..STATE_SIZE {
+        let pivot = (col..STATE_SIZE)
+            .find(|&r| !rows[r][col].is_zero())
+            .expect("Vandermonde matrices of distinct points have full rank");
+        rows.swap(col, pivot);
+        let inv = rows[col][col].inverse().unwrap();
+        rows[col].iter_mut().for_each(|x| *x *= inv);
+        let pivot_row = rows[col].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            let factor = row[col];
+            if r != col && !factor.is_zero() {
+                row.iter_mut()
+                    .zip(pivot_row.iter())
+                    .for_each(|(x, y)| *x -= factor * y);
+            }
+        }
+    }
+    let right_half: Vec<RescueVector<F>> = rows
+        .iter()
+        .map(|row| RescueVector::from(&row[STATE_SIZE..]))
+        .collect();
+    RescueMatrix::from(&[right_half[0], right_half[1], right_half[2], right_half[3]])
+}
+
+fn elem_source<F: PrimeField>(elem: &F, indent: usize) -> String {
+    let num_bytes = (F::MODULUS_BIT_SIZE as usize + 7) / 8;
+    let bytes: Vec<String> = elem.into_bigint().to_bytes_le()[..num_bytes]
+        .iter()
+        .map(|b| format!("0x{:02X}", b))
+        .collect();
+    format!("{:indent$}&[{}],\n", "", bytes.join(", "), indent = indent)
+}
+
+fn vector_source<F: PrimeField>(vector: &RescueVector<F>, indent: usize) -> String {
+    let mut src = format!("{:indent$}[\n", "", indent = indent);
+    for elem in vector.vec.iter() {
+        src.push_str(&elem_source(elem, indent + 4));
+    }
+    src.push_str(&format!("{:indent$}],\n", "", indent = indent));
+    src
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RescueConstants;
+    use crate::RescueParameter;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_ff::fields::{Fp64, MontBackend, MontConfig};
+
+    // the 64-bit Goldilocks field, with a non-default S-box exponent
+    #[derive(MontConfig)]
+    #[modulus = "18446744069414584321"]
+    #[generator = "7"]
+    struct GoldilocksConfig;
+    type Goldilocks = Fp64<MontBackend<GoldilocksConfig, 1>>;
+
+    #[test]
+    fn test_goldilocks_constants() {
+        let constants = RescueConstants::<Goldilocks>::from_seed(b"jf-rescue goldilocks field");
+        assert_eq!(constants.alpha, 7);
+        assert_eq!(constants.alpha_inv, [0x92492491b6db6db7]);
+        // the first row of the MDS matrix
+        assert_eq!(
+            constants.mds.vec(0).elems(),
+            [
+                Goldilocks::from(18446744069414466672u64),
+                Goldilocks::from(18446744069367524721u64),
+                Goldilocks::from(18446744052937841871u64),
+                Goldilocks::from(18446738401415181521u64),
+            ]
+        );
+        assert_eq!(constants.key_injection.len(), 24);
+        assert_eq!(constants.permutation_round_keys.len(), 25);
+
+        let src = constants.to_rust_source("Goldilocks");
+        assert!(src.starts_with("impl RescueParameter for Goldilocks {\n    const A: u64 = 7;\n"));
+        assert!(src.contains("        0x92492491b6db6db7,\n"));
+        assert!(src.contains("            &[0x70, 0x34, 0xFE, 0xFF, 0xFE, 0xFF, 0xFF, 0xFF],\n"));
+    }
+
+    #[test]
+    fn test_sbox_exponents() {
+        // the exponents of the hand-imported constants
+        let constants = RescueConstants::<FqEd254>::from_seed(b"");
+        assert_eq!(constants.alpha, FqEd254::A);
+        assert_eq!(constants.alpha_inv, FqEd254::A_INV);
+        assert_eq!(constants.key_injection.len(), 24);
+        assert_eq!(constants.permutation_round_keys.len(), 25);
+    }
+}
@@ -15,6 +15,7 @@ use ark_crypto_primitives::sponge::{
 use ark_std::{borrow::Borrow, marker::PhantomData, string::ToString, vec::Vec};
 use jf_prf::PRF;
 use jf_utils::pad_with_zeros;
+use zeroize::Zeroize;
 
 /// Rescue PRF
 #[derive(Debug, Clone)]
@@ -22,6 +23,13 @@ pub(crate) struct RescuePRFCore<F: RescueParameter> {
     sponge: RescueSponge<F, STATE_SIZE>,
 }
 
+// The state of the sponge depends on the key.
+impl<F: RescueParameter> Drop for RescuePRFCore<F> {
+    fn drop(&mut self) {
+        self.sponge.zeroize();
+    }
+}
+
 impl<F: RescueParameter> RescuePRFCore<F> {
     /// Similar to [`Self::full_state_keyed_sponge_with_bit_padding`] except the
     /// padding scheme are all "0" until the length of padded input is a
@@ -105,6 +113,7 @@ mod tests {
     use ark_ed_on_bls12_377::Fq as FqEd377;
     use ark_ed_on_bls12_381::Fq as FqEd381;
     use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_pallas::{Fq as FqPallas, Fr as FqVesta};
     use ark_std::{vec, UniformRand};
     macro_rules! test_prf {
         ($tr:tt) => {
@@ -140,6 +149,8 @@ mod tests {
         test_fsks_no_padding_errors_helper::<Fr377>();
         test_fsks_no_padding_errors_helper::<Fr381>();
         test_fsks_no_padding_errors_helper::<Fq377>();
+        test_fsks_no_padding_errors_helper::<FqPallas>();
+        test_fsks_no_padding_errors_helper::<FqVesta>();
     }
     fn test_fsks_no_padding_errors_helper<F: RescueParameter>() {
         let key = F::rand(&mut jf_utils::test_rng());
@@ -175,6 +186,8 @@ mod tests {
         test_variable_output_sponge_and_fsks_helper::<Fr377>();
         test_variable_output_sponge_and_fsks_helper::<Fr381>();
         test_variable_output_sponge_and_fsks_helper::<Fq377>();
+        test_variable_output_sponge_and_fsks_helper::<FqPallas>();
+        test_variable_output_sponge_and_fsks_helper::<FqVesta>();
     }
     fn test_variable_output_sponge_and_fsks_helper<F: RescueParameter>() {
         let input = [F::zero(), F::one(), F::zero()];
@@ -0,0 +1,148 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! A rescue PRG implementation, producing a deterministic stream of field
+//! elements in counter mode.
+
+use crate::{crhf::RescueCRHF, RescueParameter, RescueVector, PRP, STATE_SIZE};
+use ark_std::vec::Vec;
+use jf_utils::bytes_to_field_elements;
+use zeroize::Zeroize;
+
+/// A pseudorandom generator producing an unbounded stream of field elements.
+///
+/// The `i`-th block of `STATE_SIZE` elements of the stream is the Rescue PRP,
+/// keyed by the seed, evaluated on the counter `[i, 0, 0, 0]`.
+#[derive(Debug, Clone)]
+pub struct RescuePRG<F: RescueParameter> {
+    prp: PRP<F>,
+    round_keys: Vec<RescueVector<F>>,
+    counter: u64,
+    block: RescueVector<F>,
+    // position of the next output in `block`
+    pos: usize,
+}
+
+impl<F: RescueParameter> RescuePRG<F> {
+    /// Create a PRG from a field element seed.
+    pub fn new(seed: F) -> Self {
+        let prp = PRP::default();
+        let mut key = RescueVector::zero();
+        key.vec[STATE_SIZE - 1] = seed;
+        let round_keys = prp.key_schedule(&key);
+        Self {
+            prp,
+            round_keys,
+            counter: 0,
+            block: RescueVector::zero(),
+            pos: STATE_SIZE,
+        }
+    }
+
+    /// Create a PRG from a byte seed, which is hashed into a field element with
+    /// [`RescueCRHF`]. The entropy of the seed is thus capped by the size of
+    /// the field.
+    pub fn from_seed_bytes(seed: &[u8]) -> Self {
+        let seed_elems: Vec<F> = bytes_to_field_elements(seed);
+        Self::new(RescueCRHF::sponge_with_bit_padding(&seed_elems, 1)[0])
+    }
+
+    /// Return the next field element of the stream.
+    pub fn next_elem(&mut self) -> F {
+        if self.pos == STATE_SIZE {
+            let mut input = RescueVector::zero();
+            input.vec[0] = F::from(self.counter);
+            self.block = self.prp.prp_with_round_keys(&self.round_keys, &input);
+            self.counter = self.counter.wrapping_add(1);
+            self.pos = 0;
+        }
+        self.pos += 1;
+        self.block.vec[self.pos - 1]
+    }
+
+    /// Return the next `num_elements` field elements of the stream.
+    pub fn next_elems(&mut self, num_elements: usize) -> Vec<F> {
+        (0..num_elements).map(|_| self.next_elem()).collect()
+    }
+}
+
+impl<F: RescueParameter> Zeroize for RescuePRG<F> {
+    fn zeroize(&mut self) {
+        self.round_keys.iter_mut().for_each(Zeroize::zeroize);
+        self.block.zeroize();
+        self.counter.zeroize();
+        self.pos = STATE_SIZE;
+    }
+}
+
+impl<F: RescueParameter> Drop for RescuePRG<F> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<F: RescueParameter> Iterator for RescuePRG<F> {
+    type Item = F;
+
+    fn next(&mut self) -> Option<F> {
+        Some(self.next_elem())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RescuePRG;
+    use crate::{RescueParameter, RescueVector, PRP, STATE_SIZE};
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::{vec::Vec, UniformRand};
+
+    #[test]
+    fn test_prg() {
+        test_prg_helper::<FqEd254>();
+        test_prg_helper::<FqEd377>();
+        test_prg_helper::<FqEd381>();
+        test_prg_helper::<Fq377>();
+    }
+
+    fn test_prg_helper<F: RescueParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let seed = F::rand(&mut rng);
+
+        // the stream is the PRP on the counters
+        let prp = PRP::default();
+        let mut key = RescueVector::zero();
+        key.vec[STATE_SIZE - 1] = seed;
+        let expected: Vec<F> = (0..3u64)
+            .flat_map(|i| {
+                prp.prp(
+                    &key,
+                    &RescueVector::from(&[F::from(i), F::zero(), F::zero(), F::zero()]),
+                )
+                .elems()
+            })
+            .collect();
+
+        let mut prg = RescuePRG::new(seed);
+        let mut output = prg.next_elems(5);
+        output.push(prg.next_elem());
+        output.extend(prg.by_ref().take(6));
+        assert_eq!(output, expected);
+
+        // the stream is deterministic and depends on the seed
+        assert_eq!(RescuePRG::new(seed).next_elems(12), expected);
+        assert_ne!(RescuePRG::new(seed + F::one()).next_elems(12), expected);
+
+        let prg1 = RescuePRG::<F>::from_seed_bytes(b"jellyfish");
+        let prg2 = RescuePRG::<F>::from_seed_bytes(b"jellyfish");
+        let prg3 = RescuePRG::<F>::from_seed_bytes(b"jellyfisH");
+        let output1: Vec<F> = prg1.take(5).collect();
+        assert_eq!(output1, prg2.take(5).collect::<Vec<F>>());
+        assert_ne!(output1, prg3.take(5).collect::<Vec<F>>());
+    }
+}
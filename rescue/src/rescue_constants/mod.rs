@@ -11,3 +11,6 @@ mod bw6_761_base;
 mod ed_on_bls12_377_base;
 mod ed_on_bls12_381_base;
 mod ed_on_bn254_base;
+mod pallas_base;
+mod rescue_prime;
+mod vesta_base;
@@ -0,0 +1,1283 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+use crate::{RescueParameter, ROUNDS, STATE_SIZE};
+use ark_pallas::Fq;
+
+// the constants in this file follow the procedure of
+// https://github.com/EspressoSystems/Marvellous/blob/fcd4c41672f485ac2f62526bc87a16789d4d0459/rescue254.sage
+// i.e. Rescue(128, q_pallas, 4, 5), with
+// q_pallas = 28948022309329048855892746252171976963363056481941560715954676764349967630337
+//
+// The script samples the key schedule constants `initial_constant`,
+// `constants_matrix` and `constants_constant` at random. To make them
+// reproducible, they are instead the 24 consecutive 64-byte little-endian
+// integers, reduced mod q_pallas, of the output of
+//   SHAKE256("jf-rescue pallas base field")
+// in this order, the matrix being read row by row.
+
+impl RescueParameter for Fq {
+    // smallest alpha such that gcd(alpha, q_pallas - 1) = 1
+    const A: u64 = 5;
+    // 1/5 % (q_pallas - 1)
+    // A_INV = 0x333333333333333333333333333333334e9ee0c9a10a60e2e0f0f3f0cccccccd
+    //       = 23158417847463239084714197001737581570690445185553248572763741411479974104269
+    const A_INV: &'static [u64] = &[
+        0xe0f0f3f0cccccccd,
+        0x4e9ee0c9a10a60e2,
+        0x3333333333333333,
+        0x3333333333333333,
+    ];
+
+    // The MDS matrix is the right half of the echelon form of the 4 x 8
+    // Vandermonde matrix [g^(i * j)], where g = 5 is the multiplicative
+    // generator of the field
+    // MDS[0,0]= "28948022309329048855892746252171976963363056481941560715954676764349967614712"
+    // MDS[0,1]= "28948022309329048855892746252171976963363056481941560715954676764349965192837"
+    // MDS[0,2]= "28948022309329048855892746252171976963363056481941560715954676764349650349087"
+    // MDS[0,3]= "28948022309329048855892746252171976963363056481941560715954676764309990192837"
+    // MDS[1,0]= "19500"
+    // MDS[1,1]= "3026375"
+    // MDS[1,2]= "393529500"
+    // MDS[1,3]= "49574560750"
+    // MDS[2,0]= "28948022309329048855892746252171976963363056481941560715954676764349967626307"
+    // MDS[2,1]= "28948022309329048855892746252171976963363056481941560715954676764349967021157"
+    // MDS[2,2]= "28948022309329048855892746252171976963363056481941560715954676764349888823532"
+    // MDS[2,3]= "28948022309329048855892746252171976963363056481941560715954676764340050179157"
+    // MDS[3,0]= "156"
+    // MDS[3,1]= "20306"
+    // MDS[3,2]= "2558556"
+    // MDS[3,3]= "320327931"
+    // MDS matrix in little-endian 32 bytes elements
+    const MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE] = [
+        [
+            &[
+                0xF8, 0xC2, 0xFF, 0xFF, 0xEC, 0x30, 0x2D, 0x99, 0x1B, 0xF9, 0x4C, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+            &[
+                0x85, 0xCE, 0xDA, 0xFF, 0xEC, 0x30, 0x2D, 0x99, 0x1B, 0xF9, 0x4C, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+            &[
+                0x1F, 0xAC, 0x16, 0xED, 0xEC, 0x30, 0x2D, 0x99, 0x1B, 0xF9, 0x4C, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+            &[
+                0xC5, 0xB6, 0x28, 0xB1, 0xE3, 0x30, 0x2D, 0x99, 0x1B, 0xF9, 0x4C, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+        ],
+        [
+            &[
+                0x2C, 0x4C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0xC7, 0x2D, 0x2E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x9C, 0xC8, 0x74, 0x17, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0xEE, 0xC3, 0xDF, 0x8A, 0x0B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+        [
+            &[
+                0x43, 0xF0, 0xFF, 0xFF, 0xEC, 0x30, 0x2D, 0x99, 0x1B, 0xF9, 0x4C, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+            &[
+                0x65, 0xB4, 0xF6, 0xFF, 0xEC, 0x30, 0x2D, 0x99, 0x1B, 0xF9, 0x4C, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+            &[
+                0xEC, 0x80, 0x4D, 0xFB, 0xEC, 0x30, 0x2D, 0x99, 0x1B, 0xF9, 0x4C, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+            &[
+                0x55, 0xB4, 0xDF, 0xB0, 0xEA, 0x30, 0x2D, 0x99, 0x1B, 0xF9, 0x4C, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+        ],
+        [
+            &[
+                0x9C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x52, 0x4F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x5C, 0x0A, 0x27, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0xFB, 0xD0, 0x17, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+    ];
+
+    // initial_constant
+    // a.k.a. INIT_VEC
+    // [28466445730371233327038979805518650275052068929629727936075703725243609481814]
+    // [13604656906941994143047656290097361462615336973318884577283173824514367164803]
+    // [7236495112043946932602747783725438033229418073869651219981836456474114305868]
+    // [3568482357891320707893786874715430595721816878212289128459257618277408286398]
+    const INIT_VEC_LE: [&'static [u8]; STATE_SIZE] = [
+        &[
+            0x56, 0x82, 0x1D, 0xEF, 0x6D, 0x81, 0xE1, 0xD6, 0x4E, 0xCA, 0xE2, 0xB1, 0xFC, 0xBD,
+            0x0D, 0x5B, 0xA3, 0x38, 0x89, 0x09, 0x69, 0xE5, 0x6F, 0xA1, 0x27, 0x1D, 0xBC, 0xE9,
+            0xF3, 0x6F, 0xEF, 0x3E,
+        ],
+        &[
+            0x83, 0x11, 0x48, 0xC6, 0x2B, 0x3F, 0x83, 0xAC, 0xDF, 0x9E, 0xAE, 0x56, 0xBD, 0xB7,
+            0xC4, 0xEE, 0x89, 0x88, 0x62, 0x8F, 0x08, 0x09, 0x1B, 0x1A, 0x70, 0xC9, 0xAF, 0xC5,
+            0x82, 0xF6, 0x13, 0x1E,
+        ],
+        &[
+            0x4C, 0xAF, 0xBC, 0x59, 0x69, 0x0C, 0xCC, 0xDA, 0x71, 0xB6, 0x36, 0x9D, 0xEF, 0x43,
+            0xCC, 0xAF, 0x1C, 0xC3, 0xA7, 0x35, 0x40, 0xEA, 0x9D, 0x03, 0x9F, 0xDF, 0x45, 0xCA,
+            0x09, 0xB6, 0xFF, 0x0F,
+        ],
+        &[
+            0xBE, 0xA6, 0xDD, 0x29, 0x0C, 0x49, 0x7A, 0xD4, 0x5B, 0x87, 0x25, 0xA9, 0x02, 0xED,
+            0x28, 0x0E, 0x2E, 0x13, 0x5B, 0xB0, 0x66, 0x3B, 0xBA, 0x11, 0xED, 0xDB, 0xE7, 0x1A,
+            0x7F, 0xB0, 0xE3, 0x07,
+        ],
+    ];
+
+    // constants_matrix
+    // a.k.a., KEY_SCHED_MATRIX
+    // Keep this for reference
+    // [11904704877291115236475278794687406118864042246372513973918694312289800948578 11633034459772808066830914140094078095096469592661551497665469993846229270566 22158098760345821542115539032161284410766611718121538044840613527373111251256 7747729936379193380920118973185385041816945300848425227683622141811586913552]
+    // [21830569974487819643148950144125852600251178916310125703170416955126742347156 15549121360907013656181563167617082096976656396205316453703100241624706590619 26307742790238818686628902528655039523524450540874927313185148807213267321425 26623741910999635832648807531283156977643393655993779244985758095367358477694]
+    // [13829279990615460611008617732479765872658112287208068827942467961039801436460 4634092602474963811039250723499914296021857365235310345041911196050285456027 5466212999478642903362924232485673404069039902360529858918883003092367484452 15812180866713897311360630095859115071661337548459915934531374919423703885213]
+    // [25157317170412603457274525143030418875098194523326078917329847452098700160026 7500497142341806596949473163468046456619273301429345693782998627469479958256 18645244118778036494310622217889491229826574218945485601162167641647669595469 7493789564708012485069666984559794831108896210772267595081470163206078017008]
+    //
+
+    // constants_constant
+    // a.k.a., KEY_SCHED_VEC
+    // [18235279634209384090416545700474728305402696632153552523484723775859186137055]
+    // [12383277893855559233175340162980790516936952463448845104766653878629547240859]
+    // [9743187817413313150492922566172251573885221610906232102284312627956843007792]
+    // [21292816594123834051311207333911417126302613919164276660780997110350050513638]
+    //
+
+    // The key injection constants are precomputed computed as
+    // 1. KEY_INJECTION[-1] = INIT_VECTOR
+    // 2. for i in [0 .. 2 * ROUNDS]
+    // 3. KEY_INJECTION[i] = KEY_SCHED_MATRIX * KEY_INJECTION[ i - 1] +
+    // KEY_SCHED_VEC
+    // where the INIT_VECTOR, KEY_SCHED_MATRIX and KEY_INJECTION are defined above
+    const KEY_INJECTION_LE: [[&'static [u8]; 4]; 2 * ROUNDS] = [
+        [
+            &[
+                0xF4, 0x6E, 0x90, 0xB0, 0xC7, 0x34, 0x2D, 0xEF, 0x1A, 0x8B, 0xDC, 0xEB, 0x34, 0xEE,
+                0xBC, 0xC1, 0x86, 0xB7, 0x05, 0xCD, 0x52, 0x4F, 0x9A, 0x7F, 0xC4, 0x6B, 0x2E, 0xC6,
+                0xA2, 0x2B, 0xD1, 0x2E,
+            ],
+            &[
+                0x86, 0x3B, 0xE3, 0xD9, 0xB1, 0xAC, 0x62, 0x88, 0x25, 0x2E, 0xB7, 0xF8, 0x46, 0xCE,
+                0x43, 0x84, 0xC8, 0x88, 0x0F, 0x82, 0x39, 0x67, 0x46, 0x17, 0x95, 0xE0, 0xB3, 0x90,
+                0x80, 0x0E, 0xEB, 0x10,
+            ],
+            &[
+                0xEB, 0x5A, 0x90, 0xC9, 0xD1, 0x1C, 0x98, 0xCF, 0x66, 0xAB, 0xB3, 0x0F, 0x2A, 0x67,
+                0x75, 0x80, 0x05, 0xFF, 0xB9, 0xD1, 0x84, 0x56, 0x78, 0x12, 0x71, 0x29, 0x4A, 0xB8,
+                0xFF, 0x2A, 0xF1, 0x35,
+            ],
+            &[
+                0x2C, 0xC9, 0x1C, 0x06, 0xA8, 0xFE, 0x59, 0x56, 0x21, 0xE2, 0xA7, 0x67, 0x2C, 0x74,
+                0x80, 0xF2, 0x8E, 0x54, 0xA7, 0xC8, 0x3E, 0xC4, 0x84, 0x86, 0xDF, 0x25, 0xAB, 0x41,
+                0xDF, 0x74, 0x5E, 0x16,
+            ],
+        ],
+        [
+            &[
+                0x9D, 0x7B, 0x14, 0x07, 0x3B, 0xEF, 0xEA, 0xFB, 0xF4, 0x30, 0x28, 0x55, 0x34, 0x8E,
+                0xD3, 0xA8, 0xC5, 0x74, 0x71, 0xF9, 0x44, 0x7F, 0xE8, 0x92, 0x74, 0x08, 0xEC, 0x96,
+                0xF5, 0x16, 0xF1, 0x13,
+            ],
+            &[
+                0x94, 0x5B, 0xE1, 0x58, 0x0A, 0xF0, 0x15, 0x50, 0x79, 0x2B, 0x1A, 0x61, 0x5A, 0xDA,
+                0x6A, 0x15, 0x58, 0x70, 0x98, 0xFC, 0xD3, 0xAD, 0xCA, 0xB7, 0xA9, 0x1F, 0xA0, 0x9A,
+                0x65, 0x5B, 0xC5, 0x37,
+            ],
+            &[
+                0x2F, 0x3F, 0x5E, 0x09, 0x80, 0x1E, 0x15, 0xCA, 0xDD, 0x14, 0xC7, 0xD4, 0xBD, 0x69,
+                0x6D, 0x7D, 0x43, 0x3B, 0xB9, 0x17, 0x5D, 0xE5, 0x67, 0xE8, 0x48, 0xEB, 0x58, 0x29,
+                0x3E, 0xC2, 0x65, 0x1B,
+            ],
+            &[
+                0xDF, 0x9E, 0x9B, 0x63, 0xE4, 0xFB, 0x93, 0xDE, 0x44, 0x88, 0x90, 0x35, 0x9D, 0x7D,
+                0x1D, 0xDA, 0xD8, 0xAC, 0xD0, 0x06, 0xD2, 0x7E, 0x36, 0x2F, 0x4D, 0xF0, 0xAE, 0x18,
+                0x68, 0xA9, 0x79, 0x1A,
+            ],
+        ],
+        [
+            &[
+                0xCD, 0xFA, 0xF6, 0x57, 0xB6, 0xFD, 0xF0, 0xDB, 0x5B, 0x35, 0xEF, 0x0D, 0x3D, 0xA3,
+                0x42, 0xFA, 0xB9, 0x6E, 0xFA, 0x04, 0xAA, 0x02, 0xED, 0x6B, 0xF3, 0xF8, 0x1B, 0xEA,
+                0x6A, 0x76, 0x2E, 0x07,
+            ],
+            &[
+                0xC8, 0x7F, 0x47, 0x50, 0x4B, 0x1E, 0x21, 0x40, 0x5D, 0xD9, 0xD3, 0x22, 0xD5, 0x57,
+                0x35, 0xC3, 0x79, 0xD7, 0x14, 0x86, 0xAF, 0x0B, 0x37, 0x10, 0x6A, 0xA9, 0x46, 0x22,
+                0x19, 0xF0, 0x34, 0x3D,
+            ],
+            &[
+                0xB3, 0xF0, 0x01, 0x89, 0x4C, 0xB0, 0xB9, 0xBD, 0x0E, 0x31, 0x25, 0x4C, 0xDC, 0xBD,
+                0x14, 0x80, 0xEA, 0x97, 0xD5, 0x91, 0x3F, 0xC0, 0xC7, 0x52, 0xA9, 0x21, 0x08, 0x24,
+                0x2C, 0xBF, 0xB4, 0x1D,
+            ],
+            &[
+                0x7A, 0x21, 0x4D, 0xBE, 0xA9, 0xD5, 0x2F, 0x79, 0xCD, 0x5E, 0x95, 0xDB, 0x29, 0x5E,
+                0x4F, 0x74, 0xFC, 0x99, 0x4A, 0xCB, 0xC1, 0x12, 0x1A, 0x69, 0x32, 0x1F, 0xF8, 0x6F,
+                0x14, 0x92, 0x59, 0x05,
+            ],
+        ],
+        [
+            &[
+                0xA0, 0xF9, 0x76, 0x43, 0xB6, 0x46, 0x6D, 0x25, 0x6E, 0x4B, 0x4D, 0x6E, 0x06, 0xA4,
+                0xB5, 0x2E, 0x08, 0x0E, 0x65, 0x0F, 0xB8, 0x90, 0x67, 0xAB, 0x41, 0xDD, 0xD8, 0x88,
+                0xCE, 0xB3, 0xF0, 0x16,
+            ],
+            &[
+                0x82, 0x2E, 0xCB, 0x32, 0x70, 0x64, 0xED, 0x30, 0x88, 0x1F, 0x48, 0xFE, 0xA3, 0xB5,
+                0x6E, 0xF3, 0x1B, 0xCD, 0x77, 0x25, 0x2D, 0x00, 0xF0, 0x0B, 0x4F, 0x80, 0xE2, 0x02,
+                0xD6, 0xBF, 0x77, 0x2F,
+            ],
+            &[
+                0x55, 0x3D, 0xB9, 0x30, 0x2C, 0x60, 0x9B, 0xE4, 0xB7, 0xE4, 0xA7, 0xA5, 0x9E, 0xAF,
+                0x04, 0x48, 0x0D, 0x85, 0xDA, 0x38, 0x02, 0x85, 0x75, 0xF8, 0xFD, 0x73, 0x4B, 0xFA,
+                0xDC, 0xAB, 0x2E, 0x39,
+            ],
+            &[
+                0x09, 0xC0, 0xE8, 0xEF, 0x67, 0x39, 0xCD, 0x4E, 0xB7, 0xF3, 0xD0, 0x7E, 0x78, 0xB9,
+                0x0F, 0xAC, 0xFA, 0xB7, 0x89, 0x8A, 0x8E, 0xC5, 0xED, 0x70, 0x50, 0xDE, 0xAA, 0x73,
+                0xD1, 0x8C, 0x86, 0x21,
+            ],
+        ],
+        [
+            &[
+                0x35, 0xEE, 0x8F, 0xE1, 0xFE, 0x7D, 0xB2, 0xBE, 0xFC, 0x98, 0x6F, 0xC0, 0x10, 0x41,
+                0x5A, 0x84, 0xFD, 0x08, 0x13, 0x33, 0x6D, 0x24, 0xB9, 0x33, 0x80, 0x34, 0xAE, 0xD2,
+                0x99, 0xCF, 0x48, 0x02,
+            ],
+            &[
+                0x53, 0x72, 0x62, 0x61, 0xBD, 0x15, 0x65, 0x3D, 0x09, 0x7B, 0x87, 0x48, 0xAE, 0xD8,
+                0x3D, 0x38, 0xE4, 0xD5, 0x25, 0x0A, 0xFA, 0x94, 0x4C, 0xA2, 0x3F, 0x09, 0xCF, 0xE3,
+                0xFF, 0x4F, 0xC8, 0x20,
+            ],
+            &[
+                0xE4, 0xBC, 0xF9, 0x21, 0xF0, 0xB4, 0x2F, 0xC3, 0x30, 0x35, 0x6B, 0xC2, 0x43, 0xB4,
+                0x3C, 0x07, 0xF6, 0xDD, 0x36, 0xB1, 0xCC, 0x4D, 0xB2, 0xBA, 0x72, 0xE6, 0x82, 0xB0,
+                0x47, 0x98, 0xF7, 0x06,
+            ],
+            &[
+                0x7B, 0x37, 0xE3, 0xB5, 0xEC, 0xD1, 0x33, 0x01, 0x62, 0x46, 0xC6, 0x8E, 0xF2, 0xBB,
+                0x56, 0x89, 0xD4, 0x97, 0x0D, 0x07, 0x7C, 0x94, 0x58, 0xF6, 0x54, 0x20, 0xF7, 0x34,
+                0xB7, 0xF4, 0x87, 0x39,
+            ],
+        ],
+        [
+            &[
+                0xBB, 0xC5, 0x1F, 0xB8, 0x65, 0xBD, 0x92, 0x9A, 0x16, 0xEF, 0x8A, 0xAD, 0xF9, 0x64,
+                0x66, 0x92, 0xBB, 0x3A, 0xA7, 0xE9, 0xEE, 0x1F, 0xB0, 0x9A, 0x07, 0xA4, 0xE3, 0x11,
+                0x8E, 0x61, 0x44, 0x28,
+            ],
+            &[
+                0x40, 0x59, 0xDF, 0x79, 0x27, 0xE2, 0x51, 0xD7, 0x36, 0x8D, 0x5A, 0xA6, 0xDC, 0xD8,
+                0x2A, 0x14, 0xF5, 0xEF, 0x0C, 0x00, 0x21, 0xE8, 0xC5, 0xB6, 0xF1, 0x2B, 0x4A, 0xEC,
+                0x1D, 0x84, 0x4C, 0x32,
+            ],
+            &[
+                0xC4, 0x78, 0x9E, 0x4D, 0x40, 0x95, 0xCD, 0x13, 0x69, 0x4A, 0x3A, 0x32, 0xD1, 0x55,
+                0x30, 0x83, 0xB5, 0x88, 0x11, 0x6E, 0xEA, 0xFB, 0x5A, 0xE6, 0x5C, 0xB5, 0xEF, 0x59,
+                0x7F, 0x37, 0xF7, 0x0B,
+            ],
+            &[
+                0xF6, 0x15, 0x5B, 0xD0, 0xBF, 0xF7, 0x4B, 0xC8, 0xB6, 0xE9, 0x76, 0xD2, 0xF9, 0x88,
+                0x01, 0x5A, 0x44, 0x94, 0x8D, 0x6C, 0xE8, 0x55, 0x33, 0x5A, 0x54, 0x00, 0x1D, 0xFA,
+                0x10, 0xD2, 0x92, 0x07,
+            ],
+        ],
+        [
+            &[
+                0x6B, 0xF4, 0x2E, 0x8C, 0x83, 0x6F, 0x33, 0xAC, 0xCD, 0x27, 0x4C, 0xC0, 0x6B, 0x7C,
+                0xC4, 0x57, 0x55, 0x7D, 0x6E, 0x42, 0x8D, 0x10, 0xCC, 0x29, 0xED, 0xA9, 0x81, 0x7A,
+                0xD8, 0x4F, 0xF8, 0x23,
+            ],
+            &[
+                0xA0, 0xC5, 0x64, 0x2C, 0x82, 0x81, 0x97, 0xC4, 0x53, 0x42, 0x14, 0xCA, 0x04, 0xFC,
+                0x21, 0x69, 0xC4, 0xCF, 0xD0, 0x2D, 0x9A, 0x94, 0xE3, 0xE7, 0xAD, 0x56, 0x17, 0x74,
+                0x4C, 0xFA, 0xE2, 0x29,
+            ],
+            &[
+                0x65, 0x6B, 0x8B, 0x38, 0x93, 0x99, 0x7C, 0x3F, 0x6A, 0xEB, 0x90, 0x11, 0x40, 0x4E,
+                0x5E, 0x06, 0x46, 0x9C, 0x9A, 0xCF, 0x43, 0xBA, 0x1D, 0xDE, 0xFC, 0x26, 0x46, 0x94,
+                0xBD, 0x72, 0xD3, 0x13,
+            ],
+            &[
+                0x8B, 0x70, 0x0D, 0x50, 0x36, 0x92, 0x96, 0xFC, 0x75, 0x08, 0xAC, 0x7B, 0x40, 0x00,
+                0x11, 0x3F, 0x21, 0x9C, 0xD3, 0x66, 0x55, 0x91, 0xF9, 0x3D, 0xEF, 0x23, 0x08, 0xDF,
+                0xEA, 0x74, 0x56, 0x21,
+            ],
+        ],
+        [
+            &[
+                0xDB, 0xBF, 0xBB, 0x3A, 0x20, 0x12, 0x9A, 0x1B, 0x2C, 0x0D, 0xDA, 0xD2, 0x13, 0x0D,
+                0x43, 0x52, 0x86, 0x87, 0xCE, 0x48, 0x0D, 0x71, 0x54, 0xE1, 0x5E, 0x4D, 0x64, 0x80,
+                0x39, 0x3C, 0x74, 0x1F,
+            ],
+            &[
+                0x24, 0xC0, 0xDB, 0xFE, 0x2A, 0xA4, 0xE1, 0xB9, 0x09, 0x04, 0x46, 0x24, 0xD5, 0xAA,
+                0x93, 0x43, 0x91, 0x46, 0xF0, 0xE4, 0xDA, 0x66, 0x8D, 0x22, 0x43, 0x52, 0x2A, 0xE0,
+                0x13, 0x3F, 0x38, 0x0C,
+            ],
+            &[
+                0x41, 0xFD, 0x7B, 0x6F, 0x85, 0xE6, 0xE8, 0xE5, 0xA2, 0x9B, 0x82, 0x83, 0xA7, 0x28,
+                0xC1, 0x86, 0xFE, 0xCC, 0xD5, 0x65, 0x5B, 0xB9, 0x52, 0x0D, 0x60, 0xD7, 0xE5, 0xAC,
+                0xC4, 0xCF, 0x65, 0x1D,
+            ],
+            &[
+                0x33, 0xFC, 0x2E, 0x6D, 0xEC, 0xDB, 0x0B, 0x13, 0x1D, 0xDF, 0xBD, 0x05, 0x7B, 0x31,
+                0x39, 0x33, 0x5C, 0xBC, 0x1E, 0x42, 0x79, 0x55, 0x2C, 0xF5, 0xDA, 0x09, 0xE4, 0xC3,
+                0xD2, 0x85, 0xE4, 0x21,
+            ],
+        ],
+        [
+            &[
+                0x5E, 0x12, 0x75, 0x33, 0x45, 0x38, 0x2E, 0xC6, 0x10, 0x8E, 0x7D, 0x6E, 0x90, 0x64,
+                0x21, 0x0F, 0x31, 0x0C, 0xFC, 0x35, 0xB6, 0x6B, 0x72, 0xBA, 0x97, 0x96, 0x56, 0xCE,
+                0x3D, 0x47, 0xD5, 0x16,
+            ],
+            &[
+                0xC3, 0xA6, 0x72, 0x7D, 0x27, 0xC1, 0xE2, 0x35, 0x3A, 0x3B, 0x4D, 0xEC, 0xF1, 0xB4,
+                0x87, 0x4A, 0xF8, 0xEA, 0x93, 0x86, 0xE9, 0xEF, 0xCE, 0x7C, 0x6F, 0x8C, 0x80, 0xF3,
+                0x6B, 0x69, 0x99, 0x0E,
+            ],
+            &[
+                0x63, 0xA8, 0xD9, 0xD3, 0x88, 0x7A, 0x18, 0x38, 0x45, 0x0C, 0xE3, 0x73, 0x35, 0xAF,
+                0x9E, 0x07, 0xE9, 0xBA, 0x53, 0x90, 0x31, 0xFB, 0x9D, 0x87, 0x87, 0x36, 0xC1, 0x01,
+                0x2A, 0xEB, 0x3B, 0x14,
+            ],
+            &[
+                0x59, 0x5C, 0x5E, 0x54, 0x93, 0xD5, 0xAC, 0x62, 0x90, 0xCE, 0x75, 0x90, 0x7B, 0x9C,
+                0xB6, 0xF0, 0xCE, 0xC1, 0xEA, 0xCE, 0x3F, 0x60, 0xE9, 0x0D, 0x60, 0xD9, 0x85, 0xB1,
+                0xCC, 0xAE, 0xCD, 0x1F,
+            ],
+        ],
+        [
+            &[
+                0xF3, 0xCA, 0xA9, 0xE7, 0xAC, 0x72, 0x2F, 0x06, 0x35, 0x3E, 0x09, 0xB8, 0xA5, 0x87,
+                0x2A, 0xC0, 0x8E, 0xD2, 0x20, 0x94, 0x8F, 0x0C, 0x25, 0xC2, 0x77, 0x91, 0xED, 0xA3,
+                0x5F, 0xA6, 0x22, 0x3B,
+            ],
+            &[
+                0x0D, 0x68, 0x28, 0xD0, 0x7A, 0x0A, 0x3E, 0x71, 0xE0, 0xB5, 0xE6, 0x3A, 0xF0, 0xDE,
+                0xC8, 0x30, 0xDE, 0x15, 0x2B, 0x48, 0xB6, 0x53, 0x37, 0x5F, 0x64, 0x7F, 0x46, 0x93,
+                0x22, 0x1C, 0xD8, 0x32,
+            ],
+            &[
+                0x72, 0x4F, 0x54, 0xEE, 0x23, 0xC1, 0x2D, 0x57, 0x90, 0x64, 0x76, 0x83, 0xB4, 0x4B,
+                0xD8, 0x94, 0xB7, 0xF0, 0x3C, 0xDD, 0xFF, 0x0E, 0xC9, 0x4C, 0x1D, 0x0C, 0x7B, 0xC9,
+                0x13, 0x86, 0x96, 0x12,
+            ],
+            &[
+                0xD1, 0x7E, 0x8B, 0x7E, 0x65, 0xBF, 0x0C, 0x48, 0x72, 0x27, 0x38, 0xDE, 0x55, 0xF6,
+                0x42, 0x5B, 0xC8, 0xEA, 0x62, 0xA3, 0xFB, 0xC9, 0xD9, 0x54, 0x09, 0x6C, 0xFA, 0x75,
+                0x06, 0x4D, 0x6E, 0x05,
+            ],
+        ],
+        [
+            &[
+                0x44, 0xF8, 0xB6, 0xB0, 0x96, 0x9D, 0x18, 0x23, 0x1E, 0x19, 0x2A, 0xE4, 0x0B, 0x69,
+                0x73, 0xBA, 0x33, 0xCD, 0x68, 0x0C, 0xB0, 0x17, 0x1E, 0xFC, 0x2D, 0x83, 0x7B, 0x6A,
+                0xD8, 0xFF, 0x11, 0x26,
+            ],
+            &[
+                0x30, 0xF6, 0x0B, 0xF7, 0x32, 0xCF, 0x95, 0x80, 0xC3, 0xC6, 0x58, 0xCD, 0xAF, 0x64,
+                0xBD, 0xC8, 0x76, 0x21, 0x73, 0x4C, 0xFC, 0xA6, 0x41, 0xD3, 0xBC, 0x22, 0x9A, 0xB6,
+                0x6F, 0xF9, 0xB9, 0x2C,
+            ],
+            &[
+                0x82, 0x62, 0x24, 0x56, 0x44, 0xAB, 0x92, 0x8B, 0xF6, 0xFA, 0xC2, 0x29, 0xE6, 0x1F,
+                0xEE, 0x35, 0x44, 0xAC, 0xC7, 0x42, 0x0D, 0xFA, 0xD1, 0x61, 0xF0, 0x13, 0x34, 0x91,
+                0x05, 0xC4, 0x13, 0x13,
+            ],
+            &[
+                0x5B, 0x38, 0x38, 0xC7, 0xE4, 0xC5, 0x5B, 0xA0, 0x9F, 0x19, 0x83, 0xCF, 0x38, 0x63,
+                0xF5, 0x55, 0x6D, 0xA8, 0x24, 0x0D, 0xEE, 0x6D, 0x17, 0x01, 0x04, 0xFD, 0xF8, 0xCC,
+                0x6B, 0x84, 0x7C, 0x01,
+            ],
+        ],
+        [
+            &[
+                0xFB, 0x6C, 0xE3, 0x63, 0xA5, 0x8D, 0x15, 0x06, 0x68, 0x7D, 0x03, 0x30, 0x51, 0x63,
+                0x51, 0x63, 0xEE, 0x7B, 0x43, 0x60, 0x09, 0x6D, 0xFE, 0x73, 0x4E, 0x51, 0x74, 0x18,
+                0x75, 0xF5, 0x55, 0x08,
+            ],
+            &[
+                0xD3, 0x73, 0x16, 0xF6, 0x18, 0x9D, 0xF0, 0x9B, 0xF2, 0xD9, 0xE5, 0xA1, 0x47, 0xB2,
+                0x33, 0xE5, 0xE6, 0xCC, 0x9B, 0x68, 0x0E, 0x6E, 0x8D, 0x24, 0x43, 0xC8, 0x9A, 0x63,
+                0x07, 0xE6, 0xF2, 0x0E,
+            ],
+            &[
+                0xC0, 0xF5, 0x0E, 0x9E, 0xB7, 0x94, 0x2F, 0x5B, 0x7A, 0x2D, 0xC8, 0x38, 0xD0, 0x74,
+                0x02, 0x8D, 0xBB, 0xB7, 0xCB, 0x9B, 0x30, 0x5A, 0xB5, 0x83, 0x77, 0xA5, 0xC6, 0x16,
+                0x1B, 0xFC, 0x07, 0x2E,
+            ],
+            &[
+                0x78, 0x5C, 0x80, 0x74, 0x67, 0x7D, 0x1B, 0x2E, 0x4D, 0x47, 0x5F, 0x1A, 0x9E, 0xA7,
+                0x3E, 0x71, 0xF0, 0x6C, 0xEF, 0x6C, 0x80, 0x54, 0x21, 0xFE, 0x50, 0xC5, 0x9A, 0x74,
+                0xE6, 0xF5, 0x9B, 0x18,
+            ],
+        ],
+        [
+            &[
+                0xD8, 0xD1, 0xE2, 0x40, 0x65, 0x57, 0xB6, 0x46, 0xCC, 0x57, 0x75, 0xF6, 0xB6, 0xF0,
+                0x97, 0x83, 0x7A, 0xB3, 0x58, 0x75, 0x6B, 0xCE, 0xEF, 0xD2, 0x70, 0x8F, 0x00, 0x04,
+                0xC4, 0xE9, 0x83, 0x23,
+            ],
+            &[
+                0xB7, 0xC1, 0x27, 0x39, 0xE2, 0xCC, 0xA0, 0x83, 0xF4, 0x66, 0x94, 0x74, 0x0F, 0x21,
+                0xA5, 0x14, 0xFE, 0xBD, 0xE5, 0x85, 0x9C, 0x83, 0xB0, 0xC6, 0xDC, 0x78, 0xC5, 0x1D,
+                0x02, 0x0B, 0x53, 0x1F,
+            ],
+            &[
+                0xCC, 0x60, 0x10, 0x0E, 0xDD, 0x8E, 0x9A, 0x59, 0xDA, 0x38, 0xB1, 0xF5, 0xE6, 0xDF,
+                0x25, 0x16, 0x6F, 0x93, 0x0A, 0x0B, 0x6C, 0x15, 0x5D, 0x6F, 0xF5, 0x7E, 0xC7, 0x44,
+                0xCD, 0x6A, 0xF9, 0x2A,
+            ],
+            &[
+                0x2F, 0x38, 0xF1, 0xFC, 0x5A, 0x63, 0xB2, 0x34, 0x95, 0xDB, 0xAF, 0x4F, 0xF5, 0xC1,
+                0xDD, 0x0A, 0x5E, 0xA4, 0xCA, 0x51, 0x27, 0x35, 0x3D, 0x3F, 0x09, 0x53, 0xBD, 0xBC,
+                0xD1, 0x53, 0x61, 0x1E,
+            ],
+        ],
+        [
+            &[
+                0xBE, 0xD8, 0xB9, 0x19, 0x4D, 0x37, 0x7B, 0x47, 0xB4, 0x35, 0x7A, 0x15, 0xAC, 0x9A,
+                0x7B, 0x75, 0xA7, 0xBE, 0x9B, 0x96, 0xBB, 0x35, 0x12, 0xFF, 0x7D, 0xC4, 0x43, 0x91,
+                0xB3, 0x60, 0x0D, 0x39,
+            ],
+            &[
+                0xD5, 0x28, 0x53, 0xF9, 0x77, 0x08, 0x85, 0xD9, 0x84, 0x1C, 0x9D, 0x72, 0x3B, 0x51,
+                0x92, 0xBE, 0x90, 0x17, 0x7A, 0x41, 0x22, 0x19, 0x89, 0x7B, 0x0B, 0x81, 0x10, 0xF6,
+                0x31, 0x38, 0xE4, 0x13,
+            ],
+            &[
+                0x15, 0x3B, 0x0A, 0x8D, 0x9A, 0xA0, 0x78, 0x8C, 0x2D, 0x56, 0xE1, 0x29, 0x96, 0x5E,
+                0xBB, 0x7D, 0xD6, 0x9F, 0x04, 0x14, 0x7A, 0x9B, 0x6F, 0xBC, 0x87, 0x4D, 0xA0, 0x84,
+                0xF8, 0xF3, 0x3B, 0x24,
+            ],
+            &[
+                0x7B, 0x70, 0x98, 0x4D, 0xD0, 0x4E, 0x42, 0xDE, 0x70, 0x58, 0x18, 0x27, 0xD3, 0xCF,
+                0xA8, 0xB3, 0x0A, 0x16, 0xC7, 0x0D, 0x91, 0xA4, 0x27, 0x75, 0xAC, 0x33, 0x0D, 0x3A,
+                0x70, 0xB3, 0xD3, 0x2F,
+            ],
+        ],
+        [
+            &[
+                0x40, 0x42, 0xF8, 0x60, 0xB6, 0x0A, 0xCF, 0x53, 0xE1, 0xF9, 0x30, 0xE9, 0x8A, 0x87,
+                0x3D, 0xD0, 0xC1, 0xF9, 0x12, 0xE0, 0xF6, 0xCD, 0x24, 0x9E, 0xF7, 0x13, 0x2D, 0x52,
+                0x07, 0xAF, 0xA0, 0x30,
+            ],
+            &[
+                0xBB, 0xC5, 0x50, 0x42, 0x86, 0xFE, 0x7B, 0x1F, 0xF3, 0xE8, 0x6B, 0x65, 0x64, 0x46,
+                0xDE, 0xE4, 0xE4, 0x39, 0x24, 0x19, 0x7A, 0x47, 0x76, 0x15, 0xFD, 0x56, 0xFE, 0x2D,
+                0xA7, 0x50, 0x06, 0x16,
+            ],
+            &[
+                0x91, 0x45, 0x5A, 0xFE, 0x07, 0x2D, 0x9E, 0xBE, 0xD3, 0xDD, 0x0C, 0xD1, 0xC7, 0xAD,
+                0x22, 0x91, 0x77, 0xEC, 0x2B, 0xC4, 0xFE, 0x4B, 0x17, 0x35, 0x6E, 0x04, 0xAF, 0xE2,
+                0x16, 0x0D, 0xF9, 0x02,
+            ],
+            &[
+                0xE3, 0xB8, 0x3B, 0xD1, 0xF9, 0xB7, 0x01, 0x54, 0xE4, 0x86, 0xC6, 0x45, 0xAB, 0x47,
+                0x6E, 0x52, 0x35, 0x11, 0x94, 0xE1, 0x57, 0x6F, 0x4C, 0xCC, 0x95, 0x0C, 0x60, 0x4D,
+                0x87, 0x9B, 0xBE, 0x03,
+            ],
+        ],
+        [
+            &[
+                0x09, 0xB2, 0xC9, 0xC5, 0xDC, 0xF3, 0x86, 0x71, 0xBD, 0xC3, 0xE2, 0xA1, 0x89, 0x38,
+                0x56, 0x90, 0xDA, 0x59, 0xE1, 0xB7, 0x3F, 0x90, 0xC0, 0x6C, 0xE8, 0x3F, 0x79, 0x8A,
+                0x97, 0x74, 0xA7, 0x03,
+            ],
+            &[
+                0x87, 0x0C, 0x3C, 0x8E, 0xB0, 0x59, 0x0A, 0xF4, 0xE2, 0x3A, 0x22, 0xB1, 0x91, 0xED,
+                0x4C, 0x32, 0x32, 0xB4, 0x95, 0x45, 0x92, 0x97, 0xE1, 0xF6, 0x6C, 0xC0, 0x55, 0x8E,
+                0xF8, 0x30, 0x66, 0x21,
+            ],
+            &[
+                0xD6, 0xD7, 0xB7, 0xC7, 0xBE, 0xE1, 0x75, 0xC5, 0x2E, 0xE4, 0xA7, 0x82, 0x36, 0xC3,
+                0x9C, 0x77, 0xDF, 0xB3, 0x56, 0xA3, 0x27, 0x75, 0x97, 0x15, 0x38, 0x52, 0xB0, 0x3C,
+                0x69, 0xC2, 0x7A, 0x38,
+            ],
+            &[
+                0xF3, 0xE8, 0xD9, 0x99, 0x62, 0xF9, 0x9D, 0x46, 0xEA, 0xA4, 0x24, 0xE9, 0x8A, 0x64,
+                0x02, 0x4A, 0xDF, 0x74, 0x04, 0x8B, 0xF7, 0x0B, 0x91, 0x57, 0xC0, 0x76, 0x31, 0xAB,
+                0x57, 0x84, 0x7A, 0x04,
+            ],
+        ],
+        [
+            &[
+                0x05, 0xED, 0x88, 0xAF, 0x4F, 0xB1, 0x29, 0x90, 0x94, 0x08, 0x5F, 0x10, 0x7E, 0xB9,
+                0xC2, 0xC3, 0xF5, 0xF5, 0xB2, 0xD6, 0x09, 0xE7, 0xCB, 0x5D, 0x85, 0x89, 0x2E, 0xAB,
+                0x55, 0xBC, 0xB2, 0x01,
+            ],
+            &[
+                0xF8, 0x91, 0xFF, 0x5B, 0xF1, 0xEF, 0xFF, 0xB8, 0x10, 0x7F, 0xB6, 0xD1, 0x50, 0xB0,
+                0x87, 0xE3, 0x98, 0x0B, 0x99, 0xBF, 0x4A, 0xE4, 0x02, 0x53, 0xAE, 0xF2, 0xB9, 0x8B,
+                0x7E, 0xA9, 0x8B, 0x1E,
+            ],
+            &[
+                0xF7, 0x0F, 0x05, 0xD7, 0x61, 0x6A, 0x41, 0x3F, 0xD7, 0x9A, 0x94, 0x21, 0x4E, 0xB8,
+                0x3C, 0xAB, 0x9E, 0x8A, 0x54, 0x46, 0xA2, 0x75, 0x9B, 0x78, 0xB4, 0x3A, 0xAC, 0xBA,
+                0x22, 0x7D, 0xE1, 0x31,
+            ],
+            &[
+                0xFD, 0x19, 0xF8, 0xA3, 0xFE, 0x05, 0x98, 0x57, 0xAF, 0xCE, 0xBC, 0x97, 0xE0, 0x3E,
+                0x52, 0xC9, 0x0B, 0x9C, 0x46, 0xD8, 0x1E, 0x3E, 0x09, 0x2A, 0x7E, 0x60, 0x04, 0x25,
+                0x3C, 0xC2, 0xD8, 0x3F,
+            ],
+        ],
+        [
+            &[
+                0xA7, 0x81, 0x0A, 0x4B, 0xB4, 0xD7, 0x66, 0x0C, 0xCA, 0x41, 0xF8, 0x74, 0x27, 0x7D,
+                0x30, 0xC7, 0xC6, 0x81, 0x1E, 0x19, 0x84, 0x61, 0x2F, 0x1A, 0x3C, 0x4B, 0x97, 0x6F,
+                0xF5, 0x59, 0x47, 0x1D,
+            ],
+            &[
+                0x3E, 0x83, 0x17, 0xD7, 0xF4, 0xEA, 0xF1, 0x6F, 0x68, 0x9E, 0xD3, 0x1D, 0x94, 0xC3,
+                0x53, 0x29, 0x2F, 0x2A, 0xDB, 0x83, 0x00, 0xD2, 0x7F, 0x76, 0xBE, 0x98, 0x27, 0x08,
+                0x21, 0xC0, 0xEF, 0x14,
+            ],
+            &[
+                0x0D, 0x8A, 0xB0, 0x2D, 0xE0, 0xAF, 0xB9, 0x62, 0xFA, 0x5C, 0xC4, 0x93, 0x38, 0x8A,
+                0xE2, 0x3B, 0x8F, 0xFA, 0xDF, 0x55, 0x6F, 0x48, 0xEF, 0xA8, 0x1E, 0xFB, 0x2D, 0x54,
+                0x99, 0xAD, 0x65, 0x0E,
+            ],
+            &[
+                0xC5, 0x6E, 0x25, 0x0A, 0xE9, 0x70, 0xC2, 0xFF, 0x52, 0x91, 0xC7, 0x3C, 0x11, 0xBC,
+                0xE4, 0xDA, 0xEB, 0x7B, 0xF2, 0x0F, 0x81, 0x3E, 0x91, 0xA9, 0xE5, 0x24, 0x78, 0xBA,
+                0xBD, 0x6F, 0xDD, 0x01,
+            ],
+        ],
+        [
+            &[
+                0xB9, 0x8F, 0xBC, 0x92, 0x76, 0xF7, 0x13, 0xFB, 0x04, 0xB2, 0xCF, 0x40, 0x07, 0x00,
+                0x1A, 0x2E, 0x55, 0xE3, 0x4A, 0x7D, 0xDB, 0x31, 0xF4, 0xEC, 0x2E, 0x70, 0x22, 0x68,
+                0xD1, 0x6F, 0xB9, 0x23,
+            ],
+            &[
+                0x80, 0x38, 0xD0, 0xC1, 0x73, 0x05, 0x13, 0x27, 0xA9, 0x1C, 0xA1, 0xF4, 0x4E, 0x7F,
+                0xA9, 0x1B, 0x57, 0x62, 0x08, 0x54, 0x7E, 0x32, 0x57, 0x30, 0xA8, 0x9A, 0xE8, 0xCC,
+                0xF3, 0x3D, 0x73, 0x3C,
+            ],
+            &[
+                0xFF, 0xFE, 0x71, 0x1D, 0x78, 0x4C, 0x8B, 0xBE, 0x7F, 0x0F, 0xE5, 0xCC, 0x68, 0x8D,
+                0x5F, 0xB0, 0x9F, 0x80, 0xC2, 0x9D, 0x12, 0xF8, 0x0B, 0xE6, 0xF5, 0xD7, 0x91, 0x99,
+                0x9B, 0x86, 0xC8, 0x22,
+            ],
+            &[
+                0xCD, 0x51, 0x1F, 0x6A, 0xB1, 0xE9, 0x82, 0x17, 0x98, 0x6C, 0x13, 0x05, 0x76, 0x87,
+                0x0F, 0x38, 0x02, 0xF2, 0x1D, 0x67, 0xF9, 0xE7, 0x88, 0xC7, 0x06, 0xD1, 0x13, 0x7F,
+                0x9F, 0xF7, 0x4D, 0x27,
+            ],
+        ],
+        [
+            &[
+                0xE8, 0xFD, 0x73, 0x2F, 0x6C, 0x8F, 0x61, 0xE6, 0x76, 0x92, 0x2E, 0xFE, 0x5F, 0x1D,
+                0x9C, 0x52, 0x69, 0x90, 0x4A, 0xB7, 0x70, 0xE4, 0x83, 0x38, 0x41, 0xAC, 0x0B, 0xB2,
+                0xF2, 0x94, 0x13, 0x0B,
+            ],
+            &[
+                0xE2, 0x82, 0xFC, 0x38, 0x56, 0x22, 0x36, 0x2F, 0x83, 0x8A, 0x7A, 0xA6, 0xB3, 0x87,
+                0x7D, 0x09, 0xAE, 0x6E, 0x91, 0xCF, 0x78, 0xE2, 0xC3, 0x33, 0xF1, 0xD9, 0x6E, 0x0E,
+                0xAA, 0x7F, 0xD3, 0x0D,
+            ],
+            &[
+                0x47, 0xEC, 0x36, 0x5C, 0xC7, 0xEE, 0xB8, 0x94, 0xC3, 0xCB, 0xBC, 0xC6, 0x64, 0xA1,
+                0x98, 0xE9, 0xD6, 0x50, 0x79, 0xA0, 0x02, 0x2D, 0xB4, 0x62, 0x1E, 0x72, 0xEF, 0x4E,
+                0x55, 0xCE, 0x89, 0x2A,
+            ],
+            &[
+                0x74, 0x4E, 0x39, 0xCE, 0x9A, 0x0A, 0xDF, 0xAD, 0x06, 0xE8, 0xE4, 0x3C, 0x85, 0x25,
+                0x29, 0x39, 0xC4, 0x5A, 0x1C, 0xC2, 0xC9, 0xDB, 0x22, 0x81, 0xE4, 0x72, 0xA8, 0x17,
+                0x61, 0xFA, 0xED, 0x1B,
+            ],
+        ],
+        [
+            &[
+                0x61, 0xE0, 0x15, 0x25, 0xC1, 0x58, 0x3B, 0x9F, 0x33, 0x82, 0x42, 0x9D, 0x90, 0x12,
+                0xDB, 0x76, 0xAF, 0x7F, 0x1B, 0x2F, 0x39, 0xA2, 0xE3, 0x44, 0x85, 0x7F, 0xA6, 0x5A,
+                0x8D, 0x60, 0x86, 0x0B,
+            ],
+            &[
+                0xF8, 0xB1, 0x37, 0x0D, 0x9C, 0x03, 0xFF, 0x25, 0xB2, 0x1D, 0x70, 0x28, 0x78, 0xA9,
+                0x6D, 0x79, 0x47, 0x73, 0xF8, 0x9B, 0x76, 0x6F, 0x0C, 0x9E, 0x8D, 0x6C, 0x17, 0x91,
+                0xCB, 0x4B, 0x3E, 0x13,
+            ],
+            &[
+                0x76, 0xCA, 0xA4, 0x51, 0xD9, 0xEE, 0x38, 0x0B, 0x24, 0xA2, 0x9D, 0x81, 0x5A, 0x5E,
+                0xBC, 0x01, 0x0C, 0x13, 0xD3, 0x27, 0xF5, 0x7D, 0x90, 0x1A, 0xF6, 0x9B, 0xB1, 0xF1,
+                0x03, 0xFA, 0x39, 0x3D,
+            ],
+            &[
+                0xA6, 0x35, 0x59, 0x0A, 0x83, 0x30, 0x35, 0xC5, 0x8E, 0x92, 0x3B, 0xE8, 0xC1, 0xF8,
+                0xAB, 0xFD, 0x7F, 0x88, 0xC6, 0x58, 0x1E, 0x2B, 0x5F, 0xA7, 0xB2, 0xD0, 0x03, 0xBD,
+                0x5D, 0xA1, 0x7E, 0x16,
+            ],
+        ],
+        [
+            &[
+                0x53, 0xAA, 0x44, 0x82, 0x3B, 0x7F, 0x66, 0xE1, 0x63, 0x76, 0x95, 0xE7, 0x95, 0x51,
+                0x73, 0xED, 0x8A, 0xAD, 0x19, 0xA3, 0x93, 0xB2, 0xB9, 0xE1, 0xF3, 0x2E, 0xB3, 0x0A,
+                0xB7, 0x86, 0x7A, 0x0C,
+            ],
+            &[
+                0xAD, 0xEE, 0x1C, 0xA8, 0xB8, 0xA0, 0x65, 0x5A, 0xBA, 0x4D, 0x26, 0x85, 0xE7, 0x0F,
+                0x4A, 0xF9, 0xF9, 0x63, 0xB4, 0x29, 0xBA, 0xA3, 0x24, 0x2F, 0x8C, 0xB3, 0x0E, 0x29,
+                0xB9, 0x85, 0x01, 0x39,
+            ],
+            &[
+                0x04, 0x45, 0xC2, 0x60, 0x9C, 0xD0, 0xF9, 0x83, 0x06, 0x78, 0x19, 0xF1, 0x9B, 0x57,
+                0x61, 0x51, 0x08, 0xFB, 0xF1, 0x37, 0xE8, 0xEC, 0x57, 0x6F, 0xF4, 0x29, 0x80, 0x73,
+                0x62, 0x78, 0xBF, 0x0E,
+            ],
+            &[
+                0xF1, 0xF7, 0x1E, 0xB0, 0x5A, 0x39, 0x7D, 0xED, 0xEC, 0xA3, 0x4C, 0xE7, 0x15, 0x94,
+                0x8C, 0x82, 0x2D, 0x20, 0x78, 0x2E, 0x94, 0xE9, 0x27, 0x34, 0x48, 0xC1, 0x16, 0x5C,
+                0x1D, 0xEF, 0x0D, 0x06,
+            ],
+        ],
+        [
+            &[
+                0x92, 0xBF, 0x16, 0x16, 0xBC, 0x53, 0x5E, 0x86, 0x1E, 0xDD, 0xE6, 0x4F, 0x63, 0x7B,
+                0xC7, 0xF3, 0x87, 0x79, 0x92, 0x0F, 0x39, 0xF4, 0x65, 0x42, 0xC3, 0x58, 0xF3, 0xBA,
+                0x2F, 0xCF, 0x58, 0x0B,
+            ],
+            &[
+                0xD5, 0x21, 0x9B, 0xA9, 0x8E, 0x8F, 0x23, 0x8B, 0x3C, 0x56, 0x7C, 0x9E, 0x4D, 0x6E,
+                0xB1, 0x32, 0x13, 0xF4, 0x6D, 0x45, 0x31, 0x8C, 0xA4, 0x3A, 0x88, 0x2A, 0x3E, 0x99,
+                0xAC, 0xF3, 0xF7, 0x2D,
+            ],
+            &[
+                0x72, 0xA2, 0x99, 0xA4, 0xE7, 0x19, 0xE3, 0xBA, 0x75, 0xB3, 0xCA, 0xA8, 0xAE, 0xEC,
+                0x06, 0xA2, 0x3D, 0xBC, 0xB2, 0x60, 0xF8, 0xD1, 0x0A, 0xCB, 0xCB, 0x02, 0xBC, 0xBA,
+                0x33, 0x05, 0xFF, 0x37,
+            ],
+            &[
+                0x1C, 0x39, 0x02, 0x7D, 0x0E, 0xF4, 0xD4, 0x0F, 0xE3, 0x11, 0x81, 0xAD, 0xA6, 0x1D,
+                0x39, 0x47, 0x4D, 0xB2, 0xF3, 0x69, 0xEC, 0xAC, 0x77, 0x54, 0x1D, 0xF8, 0x83, 0x2E,
+                0xC8, 0x0B, 0x22, 0x00,
+            ],
+        ],
+        [
+            &[
+                0x7C, 0xBB, 0xAF, 0x67, 0xB9, 0x75, 0x45, 0xA7, 0x6B, 0xCD, 0x97, 0x44, 0x4D, 0x7A,
+                0xE9, 0xF9, 0x40, 0x15, 0xF7, 0x4A, 0x98, 0x08, 0xBC, 0xF6, 0x6E, 0xE2, 0xFD, 0x9C,
+                0xB7, 0x4A, 0x4F, 0x1E,
+            ],
+            &[
+                0xB4, 0x4C, 0xC8, 0xCC, 0x23, 0x53, 0x0E, 0x65, 0xB2, 0xD4, 0x16, 0x74, 0xDD, 0x76,
+                0x69, 0xE4, 0x9E, 0x5B, 0xCA, 0xBB, 0xB6, 0xDA, 0x90, 0xDA, 0x73, 0x9C, 0xC6, 0xC1,
+                0x88, 0x9E, 0x69, 0x1C,
+            ],
+            &[
+                0x66, 0x9A, 0xC8, 0x3E, 0x1D, 0x96, 0xBB, 0xF0, 0x47, 0x8E, 0xD1, 0x75, 0x15, 0xA0,
+                0x33, 0x94, 0x42, 0xC1, 0x15, 0x6C, 0xA0, 0x38, 0x14, 0x40, 0x8C, 0x51, 0x14, 0x90,
+                0x10, 0x76, 0xD2, 0x21,
+            ],
+            &[
+                0x18, 0x7E, 0x97, 0xB5, 0x4F, 0x8D, 0x3D, 0x6F, 0xC9, 0xA5, 0x34, 0x64, 0x0E, 0xD7,
+                0xB9, 0xD6, 0xE1, 0x8E, 0x59, 0xE1, 0x1A, 0xC3, 0x5D, 0x23, 0x96, 0x7D, 0x56, 0xF3,
+                0xB5, 0x3C, 0x11, 0x2C,
+            ],
+        ],
+    ];
+
+    // The permutation round keys are the key schedule of the zero key, see
+    // `PRP::key_schedule()` and the unit test test_round_keys()
+    const PERMUTATION_ROUND_KEYS: [[&'static [u8]; 4]; 25] = [
+        [
+            &[
+                0x56, 0x82, 0x1D, 0xEF, 0x6D, 0x81, 0xE1, 0xD6, 0x4E, 0xCA, 0xE2, 0xB1, 0xFC, 0xBD,
+                0x0D, 0x5B, 0xA3, 0x38, 0x89, 0x09, 0x69, 0xE5, 0x6F, 0xA1, 0x27, 0x1D, 0xBC, 0xE9,
+                0xF3, 0x6F, 0xEF, 0x3E,
+            ],
+            &[
+                0x83, 0x11, 0x48, 0xC6, 0x2B, 0x3F, 0x83, 0xAC, 0xDF, 0x9E, 0xAE, 0x56, 0xBD, 0xB7,
+                0xC4, 0xEE, 0x89, 0x88, 0x62, 0x8F, 0x08, 0x09, 0x1B, 0x1A, 0x70, 0xC9, 0xAF, 0xC5,
+                0x82, 0xF6, 0x13, 0x1E,
+            ],
+            &[
+                0x4C, 0xAF, 0xBC, 0x59, 0x69, 0x0C, 0xCC, 0xDA, 0x71, 0xB6, 0x36, 0x9D, 0xEF, 0x43,
+                0xCC, 0xAF, 0x1C, 0xC3, 0xA7, 0x35, 0x40, 0xEA, 0x9D, 0x03, 0x9F, 0xDF, 0x45, 0xCA,
+                0x09, 0xB6, 0xFF, 0x0F,
+            ],
+            &[
+                0xBE, 0xA6, 0xDD, 0x29, 0x0C, 0x49, 0x7A, 0xD4, 0x5B, 0x87, 0x25, 0xA9, 0x02, 0xED,
+                0x28, 0x0E, 0x2E, 0x13, 0x5B, 0xB0, 0x66, 0x3B, 0xBA, 0x11, 0xED, 0xDB, 0xE7, 0x1A,
+                0x7F, 0xB0, 0xE3, 0x07,
+            ],
+        ],
+        [
+            &[
+                0xE8, 0x42, 0x90, 0xDA, 0xF6, 0x9C, 0xE8, 0x59, 0x75, 0x1A, 0x85, 0x98, 0xF3, 0x71,
+                0xEF, 0x95, 0xC6, 0xC5, 0x57, 0x28, 0x3A, 0xBD, 0x53, 0x7D, 0xDF, 0x83, 0x55, 0x40,
+                0xF8, 0x38, 0x49, 0x24,
+            ],
+            &[
+                0x5F, 0x43, 0xE9, 0x6A, 0x40, 0x4B, 0x83, 0xBA, 0x1C, 0x06, 0x01, 0xC8, 0x7F, 0x5E,
+                0x3E, 0x38, 0x0A, 0xB8, 0x5E, 0xA2, 0x6C, 0xA5, 0x73, 0x2A, 0xCE, 0x15, 0x1B, 0xA1,
+                0x25, 0xB7, 0x24, 0x3D,
+            ],
+            &[
+                0x22, 0xBD, 0xC6, 0x69, 0xF6, 0x75, 0xCE, 0x74, 0x4E, 0xC4, 0xA8, 0x37, 0xB1, 0x9A,
+                0xD7, 0x23, 0xB2, 0x2A, 0x68, 0xA5, 0xFA, 0xF0, 0x4C, 0x04, 0x25, 0x45, 0x89, 0xC4,
+                0xAA, 0x0A, 0x7D, 0x05,
+            ],
+            &[
+                0xC9, 0x68, 0xF6, 0xC0, 0x49, 0xB1, 0x18, 0xB2, 0x57, 0x0E, 0x85, 0xE0, 0x85, 0x23,
+                0x6F, 0x00, 0x11, 0x53, 0xF8, 0xF3, 0xAD, 0xE3, 0x8C, 0x97, 0x42, 0x4C, 0x94, 0x94,
+                0xF9, 0xF8, 0x83, 0x32,
+            ],
+        ],
+        [
+            &[
+                0x3E, 0x75, 0xD9, 0x95, 0x59, 0x12, 0xDC, 0x08, 0xAA, 0x0A, 0xCE, 0xC8, 0xCB, 0x9E,
+                0xED, 0xD2, 0xF7, 0x00, 0x42, 0xA8, 0x20, 0x39, 0xA3, 0x75, 0x1E, 0xDA, 0x7B, 0x4D,
+                0xB1, 0xB5, 0x92, 0x18,
+            ],
+            &[
+                0xDA, 0xFE, 0x71, 0xF8, 0x70, 0xF4, 0x35, 0xF0, 0x24, 0xDA, 0x2E, 0xD5, 0xDD, 0x13,
+                0xBB, 0x0A, 0xCF, 0xF1, 0x07, 0x96, 0x44, 0xDE, 0xBD, 0x42, 0x35, 0xF4, 0xE8, 0xA4,
+                0xC7, 0xC8, 0x09, 0x1E,
+            ],
+            &[
+                0x69, 0x3E, 0x2F, 0xAA, 0x2C, 0x82, 0x06, 0x8D, 0x7B, 0xC7, 0x80, 0x2D, 0xD6, 0xA8,
+                0xB9, 0x83, 0x4B, 0x1F, 0x63, 0x5F, 0x05, 0x20, 0x06, 0x1C, 0xB0, 0x0D, 0x39, 0xC1,
+                0x01, 0x36, 0xEE, 0x0D,
+            ],
+            &[
+                0x68, 0x97, 0xDA, 0x57, 0x86, 0x1A, 0x06, 0x51, 0xDE, 0xDD, 0xED, 0xDA, 0xC2, 0x3C,
+                0xB0, 0x9A, 0x5D, 0x23, 0x47, 0x07, 0xB4, 0x89, 0x2C, 0x06, 0x84, 0x7C, 0x21, 0x18,
+                0xBD, 0x51, 0x4D, 0x1E,
+            ],
+        ],
+        [
+            &[
+                0xF4, 0x9C, 0x5C, 0x2D, 0x3C, 0xA0, 0xFE, 0xB0, 0xD7, 0xFF, 0x5D, 0x31, 0x01, 0xFE,
+                0x5B, 0xF7, 0x0E, 0x87, 0x8F, 0xD1, 0x4A, 0xA3, 0x69, 0x5C, 0x04, 0xB7, 0x64, 0x87,
+                0x4B, 0x49, 0x4F, 0x26,
+            ],
+            &[
+                0x3A, 0xEC, 0x0D, 0x0B, 0x0C, 0xE2, 0x5E, 0x45, 0x34, 0x18, 0x24, 0x53, 0x0B, 0xB6,
+                0x04, 0x44, 0x23, 0xEA, 0x17, 0x7E, 0x4D, 0xD8, 0x41, 0xE9, 0xA4, 0xD2, 0xCD, 0x59,
+                0x5C, 0xBB, 0x5C, 0x01,
+            ],
+            &[
+                0x51, 0x82, 0x0B, 0xBC, 0xC6, 0xB6, 0x8F, 0xC3, 0x39, 0xFA, 0x42, 0x76, 0x8B, 0x4F,
+                0xB0, 0x24, 0x5A, 0xF4, 0x3C, 0x4C, 0xF8, 0x6E, 0x80, 0x2F, 0x92, 0xCC, 0xDC, 0xD0,
+                0x93, 0xE3, 0x4D, 0x26,
+            ],
+            &[
+                0x29, 0x85, 0xAC, 0x34, 0x6F, 0x15, 0x34, 0x0F, 0x33, 0x10, 0xD0, 0x57, 0x99, 0xDD,
+                0xA2, 0x10, 0xD4, 0xBB, 0xA9, 0x5C, 0x03, 0xED, 0xB5, 0xB9, 0xE4, 0x50, 0xBE, 0x71,
+                0xF4, 0x18, 0x9D, 0x20,
+            ],
+        ],
+        [
+            &[
+                0x74, 0xEC, 0x69, 0xAF, 0xB2, 0xBF, 0x7C, 0x10, 0x26, 0x91, 0xD3, 0x4B, 0xA5, 0xF1,
+                0xA5, 0x79, 0xC5, 0x19, 0xB0, 0x76, 0xDD, 0x82, 0x94, 0xCE, 0x06, 0x92, 0x36, 0xCA,
+                0x53, 0x39, 0x6A, 0x12,
+            ],
+            &[
+                0xB2, 0xBD, 0xCF, 0xDC, 0xE7, 0x5A, 0xE2, 0xCE, 0x6B, 0xAD, 0xD5, 0xD8, 0xFE, 0x17,
+                0x9D, 0xC0, 0x60, 0xE2, 0xA7, 0x38, 0x15, 0x84, 0x2F, 0x12, 0xB0, 0xCE, 0xAD, 0xBC,
+                0xEF, 0xA8, 0x2A, 0x0B,
+            ],
+            &[
+                0xA5, 0xCB, 0x87, 0x57, 0x2C, 0x09, 0xD9, 0x93, 0x75, 0xF1, 0xFC, 0x93, 0xD4, 0x13,
+                0xCD, 0x51, 0xC2, 0xFC, 0xE0, 0x89, 0x41, 0x1A, 0xC1, 0x55, 0x25, 0xBD, 0xB4, 0x62,
+                0xEC, 0xB0, 0x3B, 0x0C,
+            ],
+            &[
+                0xA5, 0x2A, 0x82, 0x8B, 0xE5, 0xD2, 0x54, 0x53, 0x4A, 0xAE, 0x2B, 0x1B, 0xAE, 0x5D,
+                0xFD, 0xD2, 0x4A, 0xCD, 0xCA, 0x7C, 0x55, 0x2F, 0x3C, 0xC6, 0xC1, 0xCD, 0xB5, 0xAE,
+                0xD2, 0xE2, 0x2A, 0x3F,
+            ],
+        ],
+        [
+            &[
+                0x60, 0x0A, 0xAE, 0xD1, 0x8D, 0x20, 0xD9, 0x1A, 0xD1, 0x1B, 0x38, 0x6A, 0x81, 0x35,
+                0x7E, 0x12, 0xC0, 0x56, 0xDC, 0x88, 0x1F, 0x8B, 0xB8, 0xDF, 0x6A, 0x9A, 0xB6, 0xBF,
+                0x4B, 0x6B, 0x11, 0x39,
+            ],
+            &[
+                0x3F, 0x41, 0x36, 0x60, 0x98, 0x20, 0xB3, 0xE1, 0xCE, 0xDA, 0x4B, 0xD0, 0x36, 0xA0,
+                0x06, 0x6B, 0x60, 0x17, 0xFA, 0xC8, 0xBE, 0xCC, 0xCA, 0xF1, 0x8D, 0x77, 0x86, 0x14,
+                0x65, 0xBA, 0x4F, 0x02,
+            ],
+            &[
+                0xDD, 0x6B, 0xC5, 0x82, 0xBA, 0x61, 0x6F, 0x53, 0xF7, 0x17, 0x1B, 0xE0, 0x38, 0xA9,
+                0x3F, 0xBE, 0x56, 0xD6, 0x6C, 0x24, 0x23, 0x2A, 0x84, 0x9E, 0x3F, 0x91, 0x11, 0xF3,
+                0x3F, 0x17, 0x78, 0x2B,
+            ],
+            &[
+                0xE2, 0x1E, 0xA9, 0xFE, 0xDF, 0xD9, 0x92, 0x86, 0x93, 0x75, 0x0F, 0x55, 0xFE, 0xEC,
+                0xD6, 0x3A, 0xD6, 0xC5, 0x16, 0x2F, 0xE9, 0x5F, 0x33, 0xFF, 0xBB, 0xA4, 0xB0, 0x31,
+                0xF4, 0x79, 0x41, 0x39,
+            ],
+        ],
+        [
+            &[
+                0xC4, 0xFB, 0x5B, 0x4E, 0xAB, 0xBC, 0xA9, 0x6C, 0x7A, 0x82, 0x5C, 0xBC, 0x0F, 0x0C,
+                0x91, 0x3D, 0x7C, 0xD8, 0x23, 0x7D, 0xC2, 0xBD, 0xC2, 0x3E, 0xB6, 0x7F, 0x78, 0x15,
+                0xD0, 0x51, 0xB9, 0x19,
+            ],
+            &[
+                0x00, 0xDD, 0xF1, 0xE8, 0x00, 0xF7, 0xD7, 0x00, 0x62, 0x5A, 0x06, 0x3E, 0xC4, 0xCE,
+                0x3B, 0x75, 0x61, 0x9E, 0xDE, 0x5D, 0x21, 0xFD, 0x50, 0x82, 0x6E, 0x23, 0x5B, 0x42,
+                0xAF, 0xE1, 0xA7, 0x05,
+            ],
+            &[
+                0x36, 0x3B, 0x5E, 0x38, 0xD1, 0x0D, 0x4A, 0x4B, 0xCB, 0x87, 0x03, 0xA0, 0x82, 0x7E,
+                0x76, 0xFD, 0x3A, 0x83, 0xD2, 0x16, 0xDC, 0x5D, 0xFC, 0x3C, 0x9E, 0x1C, 0x72, 0xC7,
+                0x20, 0xFE, 0x14, 0x21,
+            ],
+            &[
+                0x04, 0xAA, 0x6F, 0x81, 0xB6, 0x66, 0x4C, 0xCE, 0xC6, 0xC9, 0x3A, 0xEF, 0x62, 0x92,
+                0xBE, 0xBB, 0xF8, 0xB0, 0x91, 0xD0, 0x6C, 0xA5, 0xA3, 0xF5, 0x7A, 0x91, 0xDE, 0x1A,
+                0xA3, 0x90, 0x91, 0x26,
+            ],
+        ],
+        [
+            &[
+                0xB7, 0x87, 0x01, 0x5F, 0x3F, 0xE0, 0xF3, 0x8A, 0xA1, 0x4E, 0xFD, 0xC3, 0xFC, 0x99,
+                0x14, 0x28, 0x41, 0x96, 0x7C, 0xC2, 0xF2, 0xF4, 0xF8, 0x08, 0xD1, 0xCD, 0x15, 0x80,
+                0x5E, 0x7E, 0x50, 0x02,
+            ],
+            &[
+                0xF3, 0xDB, 0x68, 0x96, 0xBC, 0xB3, 0x4D, 0x0F, 0xFE, 0x91, 0x3D, 0xF2, 0x85, 0x24,
+                0x71, 0x13, 0x79, 0x08, 0x20, 0x7D, 0xCA, 0x9E, 0xA7, 0x25, 0x97, 0x01, 0xFE, 0xE4,
+                0x0E, 0x5D, 0xDA, 0x15,
+            ],
+            &[
+                0x13, 0x5A, 0x55, 0x21, 0xB3, 0x1F, 0xBB, 0xEC, 0x7B, 0x3B, 0x4D, 0xFC, 0xA7, 0x9C,
+                0x4F, 0x51, 0x65, 0x8C, 0x57, 0xFB, 0x97, 0x8B, 0x81, 0xD4, 0x95, 0x73, 0xEA, 0x13,
+                0xC7, 0x72, 0x21, 0x19,
+            ],
+            &[
+                0x2C, 0xB1, 0xFE, 0x76, 0x05, 0x0A, 0x9D, 0x72, 0x7C, 0x06, 0x54, 0x08, 0x3F, 0xE2,
+                0x48, 0xAD, 0x22, 0x3D, 0x6F, 0x2F, 0x51, 0x47, 0x1D, 0x83, 0xDC, 0x6B, 0x7A, 0xB0,
+                0xD4, 0x51, 0x5D, 0x25,
+            ],
+        ],
+        [
+            &[
+                0xCC, 0xC2, 0x13, 0x58, 0x14, 0x12, 0xE9, 0xA8, 0x53, 0xA6, 0x06, 0xB1, 0x26, 0x4F,
+                0xC0, 0x6B, 0x31, 0x45, 0x33, 0x3A, 0x54, 0xC8, 0x73, 0xAF, 0xD8, 0x2C, 0x9F, 0x48,
+                0x70, 0xCA, 0xC4, 0x2C,
+            ],
+            &[
+                0xF9, 0xD7, 0x76, 0x0F, 0xD3, 0xF8, 0x48, 0xCD, 0x86, 0xF3, 0xFD, 0xA7, 0x64, 0x4A,
+                0x77, 0x44, 0x3D, 0x00, 0x2D, 0x03, 0x6E, 0xAD, 0x86, 0xF8, 0x38, 0x47, 0x10, 0x3E,
+                0xA8, 0x02, 0x3F, 0x02,
+            ],
+            &[
+                0x4C, 0x02, 0x25, 0xD3, 0x8C, 0xA8, 0x3F, 0xB3, 0x9A, 0x05, 0x9C, 0xC9, 0xAA, 0x8B,
+                0xA4, 0xCB, 0x07, 0x95, 0xB0, 0xE0, 0x5F, 0x75, 0x96, 0x2C, 0xD6, 0xF1, 0x15, 0x73,
+                0xBF, 0xEE, 0x3A, 0x25,
+            ],
+            &[
+                0x06, 0x0F, 0x9D, 0x5D, 0x2F, 0x5E, 0xC4, 0x4F, 0x07, 0xA8, 0xB1, 0x12, 0x1A, 0x7B,
+                0x49, 0xF7, 0xA8, 0xD8, 0xAA, 0xF4, 0x6B, 0x35, 0x0B, 0xDB, 0xDF, 0x9C, 0x77, 0x68,
+                0xAB, 0xDE, 0x85, 0x2D,
+            ],
+        ],
+        [
+            &[
+                0xCA, 0xCC, 0x89, 0x26, 0x03, 0x74, 0x26, 0xB7, 0x38, 0xE4, 0x3E, 0xE4, 0x37, 0xA2,
+                0x18, 0x07, 0x11, 0xDD, 0x57, 0xB6, 0x3C, 0x8B, 0x8D, 0x36, 0x1D, 0x12, 0xA2, 0x32,
+                0x96, 0xAB, 0x0C, 0x36,
+            ],
+            &[
+                0xDF, 0xDA, 0x2D, 0x3E, 0xCD, 0x96, 0x6C, 0xC7, 0x46, 0xA7, 0x1C, 0x8F, 0x09, 0x1B,
+                0xD4, 0x4F, 0x07, 0x64, 0x27, 0x2B, 0x8E, 0xBB, 0x3D, 0xE1, 0xFD, 0xE0, 0x58, 0x44,
+                0xF6, 0x8F, 0x85, 0x1A,
+            ],
+            &[
+                0x38, 0x7F, 0xB2, 0xD1, 0x85, 0xC1, 0x42, 0x47, 0xC5, 0x1F, 0xFC, 0xF1, 0x7B, 0x5D,
+                0x39, 0xBE, 0x78, 0xB2, 0xAC, 0x98, 0x82, 0xE4, 0x9A, 0x8D, 0xD2, 0xF5, 0x9A, 0x6B,
+                0xC7, 0x4B, 0xDD, 0x19,
+            ],
+            &[
+                0x66, 0xF5, 0x3D, 0x4E, 0x40, 0x5F, 0x2B, 0x8D, 0x40, 0x0E, 0xFD, 0x63, 0xD5, 0xD5,
+                0x59, 0x59, 0xF8, 0x71, 0xB9, 0x12, 0x3F, 0x31, 0x16, 0x1F, 0x57, 0x06, 0x30, 0xC1,
+                0xA8, 0xA3, 0x0F, 0x2E,
+            ],
+        ],
+        [
+            &[
+                0x1F, 0x24, 0x4D, 0x4C, 0xCF, 0xF3, 0x2F, 0xE4, 0xAA, 0x1A, 0xC2, 0x4F, 0x0F, 0x56,
+                0xC3, 0x6D, 0x94, 0xEC, 0xC7, 0x00, 0x1B, 0x1C, 0x75, 0x59, 0x86, 0x96, 0x2E, 0xB6,
+                0xE0, 0x18, 0xDB, 0x04,
+            ],
+            &[
+                0xC7, 0x71, 0x7C, 0x6A, 0x3C, 0x33, 0xA1, 0x98, 0x83, 0x4A, 0x62, 0x43, 0x71, 0xFB,
+                0xC4, 0xBE, 0x05, 0x90, 0x27, 0x87, 0x7E, 0x4F, 0x18, 0xE2, 0x08, 0x2D, 0xA3, 0x0C,
+                0xDD, 0x0B, 0xEB, 0x12,
+            ],
+            &[
+                0x0F, 0x75, 0x36, 0x6A, 0xC8, 0xB2, 0x18, 0xB6, 0xEA, 0x18, 0x36, 0x50, 0x82, 0x63,
+                0xC9, 0x67, 0x80, 0xCD, 0x85, 0x78, 0xA5, 0x65, 0xB5, 0x48, 0xF5, 0x6A, 0x70, 0x2F,
+                0xFA, 0x2B, 0xFA, 0x14,
+            ],
+            &[
+                0xC4, 0xC0, 0x71, 0x4B, 0xFC, 0xAE, 0xE2, 0x67, 0xFB, 0xB0, 0x63, 0xFB, 0x07, 0xF8,
+                0xA2, 0x07, 0x5F, 0xD8, 0x08, 0xC8, 0x3F, 0x96, 0x8E, 0x6C, 0xD9, 0xD3, 0xD8, 0x70,
+                0x78, 0xF3, 0x34, 0x00,
+            ],
+        ],
+        [
+            &[
+                0x47, 0xC5, 0xEC, 0x65, 0xC1, 0xC8, 0xD9, 0xF3, 0x23, 0x16, 0xB6, 0x06, 0x33, 0xE8,
+                0xFE, 0x31, 0x44, 0x7B, 0x34, 0x51, 0xC2, 0x2B, 0xA3, 0x4B, 0xE1, 0x95, 0x1C, 0x01,
+                0xBB, 0xD4, 0x91, 0x32,
+            ],
+            &[
+                0x2F, 0x77, 0xB9, 0x6A, 0x27, 0x0F, 0x3D, 0x86, 0x0E, 0x76, 0xEA, 0x1B, 0x3D, 0xDB,
+                0x16, 0x53, 0x39, 0x9C, 0x21, 0x41, 0x0A, 0x7E, 0xD7, 0xAB, 0x92, 0xB5, 0x97, 0xD1,
+                0xCC, 0xEF, 0xAE, 0x3C,
+            ],
+            &[
+                0x02, 0xC6, 0x75, 0xC3, 0x54, 0xBE, 0xDB, 0x72, 0x03, 0x93, 0x98, 0xDF, 0x91, 0x2D,
+                0xA8, 0xE7, 0x7A, 0x9E, 0x04, 0x1A, 0x20, 0x98, 0x57, 0x8B, 0x95, 0x78, 0x80, 0x06,
+                0x53, 0x7F, 0xBD, 0x13,
+            ],
+            &[
+                0xFD, 0x81, 0xDC, 0xA0, 0x1A, 0xB8, 0xA2, 0x45, 0xB5, 0x6F, 0x70, 0x8C, 0xFE, 0x2B,
+                0x29, 0xEF, 0x3C, 0xD3, 0x09, 0x6F, 0x38, 0x0A, 0xBE, 0x26, 0xA7, 0x36, 0xD3, 0xE3,
+                0x8C, 0xFB, 0x38, 0x20,
+            ],
+        ],
+        [
+            &[
+                0x3E, 0x03, 0x93, 0xB0, 0x55, 0xF5, 0x64, 0xA0, 0x60, 0x35, 0x7E, 0x60, 0x29, 0x4A,
+                0x64, 0x0A, 0x3A, 0xB1, 0xF2, 0x5B, 0x26, 0x9A, 0xA0, 0xAE, 0x09, 0xD3, 0xB6, 0x4D,
+                0x9D, 0x7B, 0x88, 0x2C,
+            ],
+            &[
+                0xAA, 0xD2, 0xAF, 0x4F, 0x07, 0x4E, 0x36, 0xBE, 0x1A, 0xC6, 0x5A, 0x36, 0x72, 0x92,
+                0xB5, 0x75, 0xC1, 0xF0, 0xA7, 0xAB, 0x58, 0x5E, 0x9C, 0x37, 0x81, 0x84, 0x72, 0x08,
+                0x45, 0x1E, 0xB7, 0x37,
+            ],
+            &[
+                0x42, 0xBA, 0xF0, 0x84, 0x57, 0x21, 0x91, 0x79, 0xAE, 0xFB, 0xF2, 0x03, 0x5B, 0x89,
+                0x08, 0xF3, 0x94, 0x73, 0x50, 0x43, 0x8A, 0xB4, 0x10, 0x23, 0xF0, 0x86, 0x7F, 0x0D,
+                0x11, 0x45, 0x61, 0x06,
+            ],
+            &[
+                0x56, 0x7B, 0xC7, 0xED, 0x51, 0x2C, 0xD3, 0x79, 0x35, 0x18, 0x8E, 0x56, 0x9D, 0xF3,
+                0xB3, 0xC9, 0xED, 0x74, 0x52, 0xA5, 0xBF, 0xE9, 0x70, 0xD6, 0xA0, 0x8F, 0xB5, 0x3D,
+                0xAA, 0x90, 0xFE, 0x1C,
+            ],
+        ],
+        [
+            &[
+                0xCD, 0x11, 0xCB, 0xAC, 0xEE, 0x4E, 0x24, 0xFC, 0x63, 0xA6, 0x4D, 0x21, 0x96, 0x05,
+                0x42, 0x03, 0x20, 0xC4, 0x96, 0x7F, 0x55, 0xEA, 0xF2, 0x6A, 0x7B, 0x18, 0x41, 0x78,
+                0xAB, 0xF9, 0xC3, 0x06,
+            ],
+            &[
+                0xA3, 0x33, 0x14, 0xEA, 0xCE, 0x08, 0x59, 0x03, 0x4F, 0x19, 0xB1, 0xA6, 0x9B, 0x22,
+                0x7F, 0xED, 0xAE, 0x40, 0x4B, 0xB9, 0xE3, 0xD7, 0x45, 0x5F, 0x56, 0x42, 0x02, 0x10,
+                0x49, 0x59, 0x41, 0x09,
+            ],
+            &[
+                0x50, 0xEB, 0x68, 0xA6, 0x88, 0x3B, 0x41, 0xEF, 0x28, 0x93, 0xBC, 0x1C, 0xF9, 0x0E,
+                0x85, 0x9D, 0x54, 0xCE, 0x5E, 0x5B, 0xF5, 0xC7, 0xF1, 0x7D, 0xC3, 0x90, 0xCB, 0x99,
+                0xB7, 0xCB, 0x4F, 0x27,
+            ],
+            &[
+                0x8F, 0x38, 0x3A, 0x81, 0x1E, 0x25, 0xCA, 0x73, 0xD9, 0x29, 0x04, 0x7A, 0xA6, 0xCF,
+                0x5B, 0x89, 0xA7, 0xBA, 0xFA, 0x36, 0xF5, 0xE4, 0x2C, 0xA1, 0xD6, 0xFA, 0xF6, 0xFE,
+                0xB6, 0x0B, 0x7B, 0x0E,
+            ],
+        ],
+        [
+            &[
+                0x49, 0x88, 0xA8, 0x10, 0x60, 0x44, 0x5A, 0x36, 0x28, 0x4E, 0xC6, 0x7A, 0x52, 0xEA,
+                0x88, 0xC5, 0x2C, 0x01, 0x35, 0xC1, 0x4C, 0x6D, 0x5D, 0xA4, 0xF2, 0xFF, 0x91, 0x12,
+                0x87, 0xAE, 0x1E, 0x2F,
+            ],
+            &[
+                0xD8, 0xFF, 0x05, 0x20, 0x44, 0x0B, 0xAC, 0x7B, 0xFC, 0xE4, 0x46, 0xBD, 0x3A, 0x26,
+                0xC8, 0x43, 0x2E, 0x36, 0xDA, 0xDC, 0x91, 0xC5, 0xEA, 0x06, 0x68, 0xF5, 0xCB, 0xD9,
+                0xCD, 0x70, 0xC6, 0x04,
+            ],
+            &[
+                0x08, 0xDC, 0x6C, 0x60, 0xB3, 0x9D, 0x4A, 0xA0, 0xB6, 0x52, 0x60, 0x1C, 0xE0, 0x37,
+                0x49, 0x0D, 0x6B, 0x3A, 0xC4, 0xCF, 0x67, 0xFF, 0x47, 0x7F, 0x9C, 0x0D, 0x74, 0xB1,
+                0x46, 0x71, 0xB8, 0x25,
+            ],
+            &[
+                0xE7, 0x7B, 0xC9, 0xDE, 0x74, 0x54, 0xF7, 0xC4, 0x06, 0xFC, 0x3A, 0x94, 0xAE, 0xBE,
+                0x19, 0x68, 0x39, 0xAD, 0xD7, 0x54, 0xD4, 0xA4, 0xF0, 0x21, 0x5C, 0xD5, 0x9B, 0xF1,
+                0x07, 0x65, 0xE9, 0x1C,
+            ],
+        ],
+        [
+            &[
+                0xF2, 0x17, 0x2C, 0x48, 0xC3, 0x2E, 0xCC, 0x0A, 0x1B, 0x31, 0x12, 0x89, 0x0C, 0x4F,
+                0x40, 0xFC, 0xCE, 0x66, 0xF2, 0xE0, 0xD8, 0x51, 0x94, 0xAD, 0xBB, 0x1A, 0x91, 0x54,
+                0xC7, 0xCB, 0xE2, 0x00,
+            ],
+            &[
+                0xF5, 0x3C, 0x09, 0x0D, 0xA2, 0x5A, 0x26, 0x2E, 0xDD, 0xCA, 0xFE, 0xC2, 0x23, 0x6C,
+                0xD2, 0x2F, 0x43, 0x48, 0x5C, 0x54, 0x8E, 0xE5, 0x4C, 0x90, 0xE8, 0x73, 0x22, 0x78,
+                0xD6, 0x9C, 0x69, 0x03,
+            ],
+            &[
+                0xB7, 0xBC, 0xCF, 0x7B, 0x5B, 0xC2, 0x33, 0xE7, 0xC6, 0xB3, 0x52, 0x9F, 0x60, 0x8E,
+                0x75, 0x8A, 0xDB, 0x9D, 0x2D, 0xAC, 0x20, 0x82, 0x41, 0xE9, 0x5F, 0xBD, 0x67, 0xD5,
+                0x73, 0x35, 0x2B, 0x2F,
+            ],
+            &[
+                0x2C, 0x74, 0x30, 0xC7, 0x0B, 0x1A, 0xD8, 0x48, 0xAE, 0xF0, 0x03, 0xB3, 0xA1, 0xEE,
+                0x3F, 0x5E, 0x8E, 0xAE, 0xB1, 0xD8, 0x84, 0x97, 0x85, 0xCD, 0x19, 0x37, 0x1D, 0xEE,
+                0x74, 0x34, 0x23, 0x38,
+            ],
+        ],
+        [
+            &[
+                0xA8, 0xA3, 0xC3, 0x3B, 0x36, 0xB0, 0x54, 0x7F, 0x4B, 0x8F, 0x26, 0x4E, 0x5B, 0xEF,
+                0xF7, 0xEB, 0x55, 0x72, 0xED, 0xBA, 0xFA, 0x10, 0x80, 0xDF, 0x4F, 0xA2, 0x4C, 0x5D,
+                0xFE, 0x2B, 0x85, 0x0D,
+            ],
+            &[
+                0x36, 0x5D, 0x4B, 0xB6, 0xFE, 0x46, 0xB2, 0x97, 0x80, 0x1D, 0x39, 0x7E, 0x2B, 0x4D,
+                0xCC, 0x06, 0xD1, 0x49, 0xFC, 0xAB, 0x6B, 0x00, 0x67, 0x07, 0xF6, 0x14, 0xE7, 0x7D,
+                0x66, 0x5F, 0xF9, 0x18,
+            ],
+            &[
+                0x2D, 0x8D, 0x21, 0x74, 0xE4, 0x89, 0xF7, 0x3B, 0xE8, 0x8F, 0x4F, 0x76, 0x77, 0x62,
+                0x16, 0x0F, 0x10, 0x07, 0xFE, 0xC5, 0x77, 0xF5, 0xFA, 0xCE, 0xCA, 0xDB, 0xFE, 0x98,
+                0x9A, 0x10, 0x1B, 0x0C,
+            ],
+            &[
+                0xAA, 0x94, 0x22, 0x3B, 0x6F, 0x83, 0xFE, 0x2D, 0x1C, 0x09, 0x02, 0x04, 0x54, 0x00,
+                0x8B, 0x78, 0x9E, 0x2F, 0xF6, 0xB3, 0x5C, 0x2C, 0x2A, 0xE2, 0x2C, 0x1D, 0x96, 0x7B,
+                0x8D, 0x60, 0xBE, 0x08,
+            ],
+        ],
+        [
+            &[
+                0x94, 0xBE, 0x72, 0x7E, 0x33, 0x50, 0xFD, 0x7D, 0x7C, 0x59, 0x1B, 0xE7, 0x70, 0xB9,
+                0xE6, 0x98, 0x6E, 0x7E, 0x56, 0x50, 0x7B, 0x83, 0x40, 0x5C, 0xA6, 0x83, 0x08, 0x71,
+                0xAB, 0x31, 0x56, 0x08,
+            ],
+            &[
+                0xCE, 0x30, 0xEF, 0x68, 0xE5, 0x93, 0xEF, 0x94, 0xD9, 0x10, 0x24, 0x9F, 0x0B, 0x1F,
+                0xC8, 0xD3, 0x62, 0x65, 0x4F, 0xF0, 0x14, 0x48, 0x80, 0x16, 0x80, 0x29, 0x23, 0x71,
+                0x57, 0xE4, 0x61, 0x3F,
+            ],
+            &[
+                0xFC, 0xF5, 0x62, 0xAB, 0xC5, 0x5D, 0x12, 0x9B, 0xA1, 0x5A, 0x2C, 0x85, 0xC0, 0x91,
+                0xF8, 0x7C, 0xFA, 0x9B, 0x7A, 0x0D, 0x8F, 0x7E, 0xAB, 0xF7, 0xF5, 0x4D, 0x87, 0x1B,
+                0x84, 0x90, 0xA7, 0x08,
+            ],
+            &[
+                0xC7, 0x1A, 0x86, 0xDE, 0x6E, 0x53, 0x85, 0x1A, 0x5B, 0xBA, 0x72, 0x12, 0xF6, 0x2E,
+                0xCA, 0xB7, 0xC7, 0xC9, 0xD2, 0x94, 0xBC, 0x66, 0x5A, 0x0A, 0xFD, 0xCE, 0xF2, 0x68,
+                0x28, 0x24, 0xD6, 0x04,
+            ],
+        ],
+        [
+            &[
+                0x87, 0xDE, 0xA1, 0x81, 0x12, 0xAE, 0xE4, 0x30, 0x67, 0x8A, 0xEB, 0xFB, 0x4D, 0x47,
+                0x0A, 0x48, 0xBB, 0x4D, 0x69, 0xED, 0x14, 0xB5, 0x2C, 0x2D, 0x68, 0x96, 0x94, 0x04,
+                0x26, 0x54, 0xED, 0x22,
+            ],
+            &[
+                0xDB, 0x71, 0x46, 0x0C, 0x44, 0x33, 0x4D, 0x2B, 0x78, 0x79, 0x0A, 0xCB, 0xD7, 0x2B,
+                0xFF, 0x2D, 0xF6, 0x3E, 0xED, 0x39, 0x31, 0xBC, 0x44, 0xD3, 0x60, 0x88, 0x29, 0xA2,
+                0x3B, 0x50, 0xB8, 0x21,
+            ],
+            &[
+                0xBC, 0xDA, 0xB1, 0x81, 0x7B, 0x49, 0x90, 0x67, 0x18, 0xF0, 0xFB, 0xBF, 0x4E, 0xC2,
+                0xD2, 0xB1, 0x8B, 0x4C, 0xA7, 0x33, 0xC6, 0x2A, 0xF7, 0x0F, 0x2C, 0x0E, 0x12, 0x98,
+                0x10, 0xD7, 0x98, 0x09,
+            ],
+            &[
+                0xCE, 0xA8, 0xD3, 0x00, 0x5D, 0x5C, 0x3A, 0xEE, 0x6C, 0xEC, 0x63, 0x87, 0x02, 0xA5,
+                0x83, 0x74, 0xE2, 0xF2, 0x19, 0x8D, 0x14, 0x3E, 0xFA, 0x65, 0xD3, 0xF8, 0xD1, 0x16,
+                0x18, 0xE4, 0x64, 0x0A,
+            ],
+        ],
+        [
+            &[
+                0x3A, 0x9C, 0xB1, 0x5B, 0xB6, 0x73, 0xED, 0x46, 0xD2, 0xF3, 0xE5, 0xD9, 0x60, 0x88,
+                0x19, 0x6F, 0x00, 0x6C, 0x0D, 0x24, 0x22, 0x45, 0x24, 0xEF, 0xFC, 0x6C, 0x2E, 0x7B,
+                0x13, 0xB2, 0xB6, 0x36,
+            ],
+            &[
+                0x32, 0x40, 0xA6, 0x93, 0x77, 0xEE, 0xB8, 0x83, 0x0C, 0x45, 0xD4, 0x54, 0xDE, 0xC8,
+                0x31, 0x9F, 0xE7, 0x45, 0x6C, 0xAB, 0xC5, 0x9B, 0xBB, 0x77, 0x18, 0x95, 0x37, 0xD3,
+                0x54, 0x9C, 0xED, 0x3D,
+            ],
+            &[
+                0x65, 0xFF, 0x2E, 0xFF, 0xF9, 0x90, 0x5B, 0x37, 0x9F, 0x24, 0x72, 0xAE, 0xC1, 0x05,
+                0x3D, 0x9A, 0x0B, 0x97, 0x23, 0xF7, 0xC0, 0x76, 0xB4, 0x09, 0x9E, 0x75, 0xCE, 0x2F,
+                0x4F, 0x74, 0x6E, 0x33,
+            ],
+            &[
+                0x08, 0x4B, 0xDA, 0xC9, 0xA3, 0x81, 0xD7, 0x74, 0xEE, 0x11, 0x9E, 0xDF, 0x85, 0x04,
+                0x89, 0xB6, 0x86, 0x7E, 0x8C, 0x7E, 0x43, 0x76, 0x22, 0xE9, 0xF5, 0xCE, 0x1E, 0x14,
+                0xDE, 0xFD, 0xBC, 0x1B,
+            ],
+        ],
+        [
+            &[
+                0x3B, 0x79, 0x21, 0xEC, 0x82, 0x14, 0xC4, 0xEE, 0x54, 0xD5, 0x5B, 0x11, 0xF3, 0x2B,
+                0x1E, 0xF7, 0x8B, 0xFF, 0x24, 0xB7, 0x26, 0xF5, 0xE5, 0xDC, 0x0D, 0x76, 0x57, 0xA8,
+                0x33, 0x87, 0x7B, 0x1C,
+            ],
+            &[
+                0xCA, 0xB6, 0x6E, 0x45, 0x2C, 0x5C, 0x08, 0xC5, 0xA9, 0x05, 0x41, 0x8E, 0x9A, 0x9D,
+                0xC6, 0x83, 0x4A, 0x6B, 0x66, 0x6F, 0xD4, 0xFF, 0x6C, 0x21, 0x21, 0x8D, 0x73, 0x87,
+                0x7A, 0xAC, 0xA9, 0x21,
+            ],
+            &[
+                0x39, 0x44, 0x98, 0x2C, 0x22, 0xE7, 0xC8, 0x77, 0x4A, 0x98, 0x16, 0x11, 0x4F, 0x6A,
+                0x1D, 0xFA, 0x8E, 0x60, 0x19, 0xC3, 0x28, 0xCF, 0x4E, 0xC0, 0x62, 0xD8, 0x4B, 0x54,
+                0x46, 0x81, 0x38, 0x2F,
+            ],
+            &[
+                0x82, 0xA1, 0x69, 0xC4, 0x42, 0xD8, 0xAD, 0xB9, 0xDB, 0x34, 0xDA, 0x7B, 0xEB, 0xAF,
+                0x7B, 0xD2, 0x15, 0x89, 0x44, 0x47, 0xA8, 0xF5, 0xFC, 0x34, 0x13, 0x1F, 0xC9, 0x1E,
+                0x55, 0x1A, 0xE7, 0x3A,
+            ],
+        ],
+        [
+            &[
+                0x69, 0x13, 0x37, 0x80, 0x48, 0x34, 0x58, 0x1E, 0xBE, 0x49, 0xCB, 0x39, 0xBD, 0x39,
+                0x64, 0x6C, 0x30, 0xE0, 0xEF, 0xFE, 0x18, 0xDE, 0x21, 0x59, 0x13, 0xCC, 0xA9, 0xED,
+                0x32, 0x93, 0x3F, 0x06,
+            ],
+            &[
+                0x16, 0x39, 0x8D, 0x5C, 0xCA, 0x0F, 0xF6, 0x1C, 0x27, 0xEB, 0x94, 0x25, 0x7F, 0x47,
+                0xCA, 0xC4, 0x38, 0xB6, 0xCE, 0xFF, 0x54, 0xC2, 0x8E, 0x73, 0x12, 0x5B, 0xDE, 0x29,
+                0xC8, 0xE3, 0x91, 0x11,
+            ],
+            &[
+                0x29, 0x5A, 0x02, 0x84, 0xE9, 0xF6, 0xB2, 0xB8, 0x0E, 0x07, 0xFE, 0xFE, 0x47, 0x8A,
+                0xAC, 0xD5, 0x08, 0x53, 0x7C, 0x50, 0x29, 0xCE, 0x1B, 0x13, 0x23, 0xA9, 0x37, 0xC7,
+                0xFE, 0x68, 0x59, 0x17,
+            ],
+            &[
+                0x05, 0xBC, 0x2D, 0xBE, 0x5F, 0xA7, 0x8A, 0xE6, 0xC5, 0xC3, 0x44, 0x7E, 0xD2, 0xE8,
+                0xD8, 0xF1, 0xFA, 0x59, 0x27, 0xB3, 0xD1, 0xCA, 0x29, 0x5E, 0x46, 0x34, 0x3B, 0x80,
+                0x4E, 0x69, 0xCC, 0x3C,
+            ],
+        ],
+        [
+            &[
+                0x45, 0x2A, 0x85, 0xB5, 0x4B, 0x77, 0x50, 0x1F, 0xC8, 0xDB, 0x4D, 0xEC, 0x08, 0x1D,
+                0x89, 0x5E, 0x36, 0x6B, 0xB1, 0xE2, 0x14, 0x06, 0xF3, 0x22, 0x34, 0xF8, 0xB0, 0xE8,
+                0x4C, 0xFC, 0x5B, 0x0A,
+            ],
+            &[
+                0x7F, 0x1B, 0x65, 0x99, 0x2C, 0xC3, 0x51, 0x97, 0xC2, 0xF2, 0x03, 0xC4, 0xCE, 0x23,
+                0xCB, 0x00, 0xF9, 0x94, 0x46, 0x9D, 0x79, 0x09, 0x5E, 0x81, 0xB8, 0x5C, 0xB2, 0x7C,
+                0x73, 0xA1, 0xF7, 0x2D,
+            ],
+            &[
+                0x47, 0xFA, 0xDF, 0x02, 0xF0, 0xFB, 0x90, 0x1D, 0xBE, 0xAD, 0x98, 0xE9, 0xA0, 0x21,
+                0x91, 0x8F, 0x67, 0xA4, 0x2E, 0x5D, 0xB0, 0x8B, 0x44, 0xE2, 0x80, 0xB4, 0x24, 0xF0,
+                0xA5, 0x57, 0x43, 0x1B,
+            ],
+            &[
+                0xD2, 0x8C, 0x99, 0x8A, 0x59, 0xEB, 0x6B, 0xC0, 0x57, 0x35, 0x64, 0xBF, 0x5E, 0x42,
+                0xB0, 0x02, 0x3F, 0x62, 0x88, 0x60, 0x81, 0x00, 0x45, 0x15, 0xA2, 0x65, 0x7F, 0xB6,
+                0x06, 0x23, 0x93, 0x34,
+            ],
+        ],
+        [
+            &[
+                0x1E, 0xF9, 0x65, 0x6E, 0xC7, 0xEB, 0x93, 0x25, 0x88, 0xDD, 0x73, 0x46, 0x2A, 0x78,
+                0x12, 0x36, 0x7A, 0x7F, 0x4D, 0xC8, 0xE7, 0x85, 0xAD, 0xBE, 0x2A, 0x24, 0x7C, 0x20,
+                0x34, 0xE3, 0xC0, 0x36,
+            ],
+            &[
+                0x6B, 0x95, 0x5B, 0x17, 0x22, 0x60, 0xBA, 0xDF, 0x09, 0x32, 0x1C, 0x33, 0x17, 0xB5,
+                0x11, 0xCC, 0x16, 0x83, 0xA9, 0x29, 0x04, 0x47, 0x19, 0xA9, 0xE8, 0x72, 0xE0, 0x6B,
+                0xD0, 0x1F, 0x9F, 0x06,
+            ],
+            &[
+                0x48, 0x42, 0x76, 0x84, 0x82, 0x2D, 0xC1, 0xE4, 0x94, 0xB6, 0xD2, 0x3C, 0x02, 0x78,
+                0x27, 0x79, 0x6D, 0x55, 0x33, 0xA9, 0xBC, 0x15, 0x3F, 0x4D, 0x1C, 0xBD, 0x41, 0x1B,
+                0xFD, 0x64, 0x9F, 0x10,
+            ],
+            &[
+                0xD7, 0xCD, 0x5A, 0x26, 0x27, 0x88, 0x46, 0x87, 0x37, 0x8E, 0x7B, 0xB4, 0x4F, 0x45,
+                0x8C, 0xA5, 0x85, 0x69, 0x36, 0x42, 0x6A, 0x6B, 0xFB, 0x19, 0x1B, 0x55, 0x47, 0x50,
+                0x85, 0x4B, 0xDF, 0x25,
+            ],
+        ],
+        [
+            &[
+                0x53, 0xF0, 0x5F, 0xD1, 0xD3, 0x75, 0xD0, 0xE0, 0x49, 0x26, 0x41, 0x9C, 0x7E, 0xEA,
+                0x23, 0xD3, 0xED, 0x9C, 0xB2, 0xBC, 0xF0, 0x3B, 0x15, 0x75, 0xC6, 0x59, 0xA4, 0x5C,
+                0xA0, 0x9A, 0xD9, 0x0D,
+            ],
+            &[
+                0x04, 0xA4, 0x8F, 0xA6, 0xD8, 0x0A, 0x22, 0xBA, 0xD3, 0xA3, 0x89, 0x6C, 0x43, 0x68,
+                0xF5, 0xE5, 0xAF, 0xD3, 0x9D, 0x5F, 0x24, 0x7A, 0x48, 0xFF, 0x9C, 0xF2, 0x4A, 0x71,
+                0xB5, 0x1E, 0xA9, 0x07,
+            ],
+            &[
+                0xBA, 0x82, 0x36, 0xC1, 0xFB, 0xBE, 0x83, 0xCF, 0x25, 0x6E, 0xC9, 0x14, 0x5E, 0x83,
+                0x4F, 0x57, 0x42, 0xDB, 0x90, 0xB3, 0xB4, 0xC4, 0x56, 0x0F, 0x4C, 0x68, 0x74, 0xC6,
+                0x31, 0xA1, 0x88, 0x19,
+            ],
+            &[
+                0xF7, 0x23, 0x7B, 0xEF, 0x18, 0x4C, 0xB6, 0x85, 0xAB, 0xCD, 0xDF, 0xB3, 0xE5, 0xDA,
+                0x4C, 0xEB, 0x79, 0x79, 0x11, 0x72, 0x2D, 0xFF, 0xF4, 0xFA, 0x1B, 0x7C, 0x02, 0x56,
+                0xC7, 0xFC, 0xD2, 0x25,
+            ],
+        ],
+    ];
+}
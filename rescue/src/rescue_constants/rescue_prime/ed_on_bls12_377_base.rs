@@ -0,0 +1,490 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+use crate::{rescue_prime::RescuePrimeParameter, STATE_SIZE};
+use ark_ed_on_bls12_377::Fq;
+
+// the constants in this file follow the reference implementation
+// https://github.com/aszepieniec/rescue-prime/blob/master/rescue_prime.sage
+// i.e. get_number_of_rounds(), get_mds_matrix() and get_round_constants()
+// with security_level = 128, m = STATE_SIZE = 4, capacity = 1 and
+// q377 = 8444461749428370424248824938781546531375899335154063827935233455917409239041
+
+impl RescuePrimeParameter for Fq {
+    // ceil(1.5 * max(5, l1)) where l1 is the number of rounds thwarting
+    // Groebner basis attacks
+    const PRIME_ROUNDS: usize = 8;
+
+    // The MDS matrix is the transpose of the right half of the echelon form of
+    // the 4 x 8 Vandermonde matrix [g^(i * j)], where g = 22 is the smallest
+    // primitive element of the field
+    // MDS[0,0]= "8444461749428370424248824938781546531375899335154063827935233455917295859137"
+    // MDS[0,1]= "118778440"
+    // MDS[0,2]= "8444461749428370424248824938781546531375899335154063827935233455917403829351"
+    // MDS[0,3]= "11155"
+    // MDS[1,0]= "8444461749428370424248824938781546531375899335154063827935233454652656409921"
+    // MDS[1,1]= "1324860118296"
+    // MDS[1,2]= "8444461749428370424248824938781546531375899335154063827935233455857182925531"
+    // MDS[1,3]= "119024335"
+    // MDS[2,0]= "8444461749428370424248824938781546531375899335154063827935219960949733275201"
+    // MDS[2,1]= "14136260080508280"
+    // MDS[2,2]= "8444461749428370424248824938781546531375899335154063827935232813357514551187"
+    // MDS[2,3]= "1267490143415"
+    // MDS[3,0]= "8444461749428370424248824938781546531375899335154063827791525545136070306881"
+    // MDS[3,1]= "150537006982534008760"
+    // MDS[3,2]= "8444461749428370424248824938781546531375899335154063827928390863423559055971"
+    // MDS[3,3]= "13496292655106471"
+    // MDS matrix in little-endian 32 bytes elements
+    const PRIME_MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE] = [
+        [
+            &[
+                0xC1, 0xF5, 0x3D, 0xF9, 0xFF, 0x7F, 0x11, 0x0A, 0x01, 0x00, 0x00, 0xD0, 0xFE, 0x76,
+                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
+                0x5E, 0x65, 0xAB, 0x12,
+            ],
+            &[
+                0x48, 0x6A, 0x14, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x67, 0x74, 0xAD, 0xFF, 0xFF, 0x7F, 0x11, 0x0A, 0x01, 0x00, 0x00, 0xD0, 0xFE, 0x76,
+                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
+                0x5E, 0x65, 0xAB, 0x12,
+            ],
+            &[
+                0x93, 0x2B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+        [
+            &[
+                0x41, 0x5D, 0xDB, 0x86, 0xD9, 0x7E, 0x11, 0x0A, 0x01, 0x00, 0x00, 0xD0, 0xFE, 0x76,
+                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
+                0x5E, 0x65, 0xAB, 0x12,
+            ],
+            &[
+                0x18, 0x15, 0xD1, 0x77, 0x34, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0xDB, 0x62, 0x3B, 0xFA, 0xF1, 0x7F, 0x11, 0x0A, 0x01, 0x00, 0x00, 0xD0, 0xFE, 0x76,
+                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
+                0x5E, 0x65, 0xAB, 0x12,
+            ],
+            &[
+                0xCF, 0x2A, 0x18, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+        [
+            &[
+                0x41, 0x36, 0x6B, 0xFD, 0x65, 0x8E, 0xE1, 0x09, 0x01, 0x00, 0x00, 0xD0, 0xFE, 0x76,
+                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
+                0x5E, 0x65, 0xAB, 0x12,
+            ],
+            &[
+                0x78, 0x1D, 0x8D, 0x88, 0xDA, 0x38, 0x32, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x93, 0xEF, 0xBA, 0x5D, 0x98, 0x37, 0x0F, 0x0A, 0x01, 0x00, 0x00, 0xD0, 0xFE, 0x76,
+                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
+                0x5E, 0x65, 0xAB, 0x12,
+            ],
+            &[
+                0xB7, 0xBC, 0x4C, 0x1C, 0x27, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+        [
+            &[
+                0x41, 0xAC, 0xC3, 0xA0, 0x8A, 0x70, 0xB8, 0x3F, 0xF9, 0xFF, 0xFF, 0xCF, 0xFE, 0x76,
+                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
+                0x5E, 0x65, 0xAB, 0x12,
+            ],
+            &[
+                0xB8, 0x0F, 0x70, 0x78, 0x45, 0xE2, 0x1E, 0x29, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x63, 0x3A, 0x4E, 0x65, 0x61, 0xBA, 0x1B, 0xAB, 0x00, 0x00, 0x00, 0xD0, 0xFE, 0x76,
+                0xAA, 0x59, 0x01, 0xB0, 0x37, 0x5C, 0x1E, 0x4D, 0xB4, 0x60, 0x56, 0xA5, 0x2C, 0x9A,
+                0x5E, 0x65, 0xAB, 0x12,
+            ],
+            &[
+                0xA7, 0x09, 0x7E, 0x81, 0xCE, 0xF2, 0x2F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+    ];
+
+    // The 2 * m * PRIME_ROUNDS round constants are the consecutive
+    // little-endian integers of 33 bytes, reduced mod q377, of the output of
+    //   SHAKE256("Rescue-XLIX(q377,4,1,128)"), with q377 written in decimal,
+    // and are grouped by state-size vectors, two per round
+    const PRIME_ROUND_CONSTANTS_LE: &'static [[&'static [u8]; STATE_SIZE]] = &[
+        [
+            &[
+                0x97, 0xBB, 0x05, 0x4A, 0xA5, 0x76, 0xDD, 0xA2, 0x9A, 0xFA, 0xFC, 0x37, 0x15, 0xD4,
+                0x4C, 0x3B, 0x3A, 0xF2, 0x5E, 0x03, 0x51, 0xC5, 0x26, 0x69, 0xC7, 0xDD, 0xB5, 0xE6,
+                0x16, 0xD1, 0x40, 0x03,
+            ],
+            &[
+                0x60, 0xAC, 0xB9, 0xCE, 0xB6, 0xE8, 0x52, 0xA9, 0x02, 0xED, 0x79, 0x4E, 0xD2, 0x5A,
+                0xA9, 0x76, 0x21, 0x9F, 0xE0, 0x11, 0x9A, 0xBF, 0x7C, 0xCD, 0x49, 0xE9, 0xC1, 0x14,
+                0xF8, 0xD8, 0xFE, 0x00,
+            ],
+            &[
+                0x62, 0xB2, 0xFA, 0x06, 0x88, 0x17, 0x49, 0x6A, 0xFA, 0x41, 0xD1, 0x26, 0xED, 0x49,
+                0xDE, 0x17, 0x38, 0x7D, 0xD4, 0xC4, 0xA1, 0xF4, 0xAB, 0xEC, 0x50, 0x9C, 0x05, 0x88,
+                0xCD, 0x23, 0xF9, 0x09,
+            ],
+            &[
+                0x60, 0x04, 0x27, 0x37, 0xDD, 0xF7, 0xB9, 0xA6, 0xD0, 0xE7, 0x9E, 0xD9, 0x56, 0x1D,
+                0x39, 0x8F, 0xB4, 0x86, 0xAB, 0x5B, 0x91, 0x3A, 0xB8, 0x2A, 0x15, 0xE6, 0x4B, 0x99,
+                0x13, 0xD8, 0x26, 0x11,
+            ],
+        ],
+        [
+            &[
+                0x81, 0xCE, 0x3A, 0xEA, 0x24, 0x20, 0x55, 0x37, 0xFE, 0x85, 0x03, 0xA4, 0xE0, 0x4F,
+                0xC8, 0x03, 0xA6, 0xAF, 0x07, 0x6D, 0xC8, 0xAC, 0x3D, 0xC1, 0x15, 0x64, 0x17, 0x8A,
+                0x4E, 0x04, 0x24, 0x0D,
+            ],
+            &[
+                0x50, 0x63, 0x1E, 0x72, 0xC2, 0xC3, 0xB6, 0x66, 0xF7, 0xDC, 0xD0, 0x84, 0x5C, 0xFC,
+                0x22, 0x9A, 0x1D, 0x05, 0x49, 0xD0, 0xE6, 0x34, 0x4A, 0x28, 0xDE, 0x59, 0xAF, 0x39,
+                0x7D, 0x83, 0x80, 0x09,
+            ],
+            &[
+                0xE9, 0xBA, 0xE3, 0xEC, 0x98, 0x13, 0xCD, 0x35, 0xD8, 0x26, 0xCA, 0xBC, 0x00, 0xD5,
+                0x8F, 0x4C, 0x61, 0xFC, 0x01, 0xEE, 0x24, 0x73, 0xD2, 0x29, 0xC9, 0xFC, 0x5C, 0x05,
+                0x81, 0xDB, 0x4C, 0x00,
+            ],
+            &[
+                0x0F, 0x59, 0x49, 0x7D, 0xAB, 0x72, 0x8E, 0x7A, 0xB2, 0xC1, 0x8C, 0xC8, 0x61, 0x3F,
+                0x30, 0x0B, 0xEB, 0xA3, 0x06, 0x28, 0xA2, 0x31, 0x0F, 0x14, 0x5F, 0xE4, 0x73, 0x31,
+                0x28, 0x00, 0xAB, 0x0E,
+            ],
+        ],
+        [
+            &[
+                0x4B, 0x96, 0xDA, 0xD6, 0x34, 0x70, 0x45, 0xF2, 0xA6, 0x75, 0x0B, 0xDA, 0xF2, 0x35,
+                0xB0, 0x72, 0x4F, 0x58, 0x1F, 0xC4, 0x59, 0x15, 0x42, 0x5D, 0x71, 0xFE, 0x04, 0x3A,
+                0xA8, 0xF4, 0x42, 0x0C,
+            ],
+            &[
+                0xA7, 0xF3, 0xC4, 0x2F, 0xB9, 0xF2, 0xB8, 0xA0, 0x44, 0xB9, 0xD3, 0x44, 0xBC, 0x2B,
+                0xC9, 0x87, 0x9B, 0x8B, 0x51, 0x24, 0x8A, 0x17, 0x66, 0x6A, 0x09, 0x21, 0x58, 0x19,
+                0x68, 0xC2, 0x42, 0x05,
+            ],
+            &[
+                0xBA, 0x70, 0x51, 0x0F, 0x04, 0x55, 0xD7, 0x37, 0x25, 0xFD, 0x67, 0xBD, 0x72, 0xF5,
+                0xD4, 0x37, 0xA9, 0x5A, 0x77, 0x01, 0xD7, 0xCC, 0x32, 0x42, 0xF1, 0x2A, 0x25, 0x90,
+                0xCB, 0xFD, 0xB2, 0x00,
+            ],
+            &[
+                0x2F, 0x88, 0x89, 0x76, 0xC8, 0xF0, 0xCE, 0xF8, 0x6A, 0xF9, 0xA7, 0x12, 0x68, 0xE1,
+                0x5E, 0x6C, 0xF4, 0x85, 0xB0, 0x29, 0x1C, 0x73, 0xDD, 0x79, 0xAA, 0xA5, 0x2A, 0x8B,
+                0xAC, 0x32, 0x76, 0x06,
+            ],
+        ],
+        [
+            &[
+                0x33, 0x67, 0xC3, 0x74, 0xA9, 0x37, 0x11, 0xCD, 0xD7, 0x14, 0xE4, 0x08, 0xC4, 0xF9,
+                0x18, 0x22, 0x65, 0xBB, 0xB4, 0xE2, 0x5B, 0x5D, 0x96, 0x43, 0x0E, 0xD6, 0x98, 0xE4,
+                0x08, 0x1A, 0x0E, 0x04,
+            ],
+            &[
+                0xAE, 0x7A, 0x87, 0x6A, 0x31, 0x9A, 0x60, 0x97, 0x1D, 0xF4, 0x5F, 0xDE, 0x20, 0xAA,
+                0x93, 0xFD, 0x8F, 0xA9, 0xB3, 0xD4, 0x22, 0xBB, 0x64, 0xFB, 0x8E, 0xF6, 0x74, 0x2C,
+                0xE5, 0xCA, 0xF7, 0x0D,
+            ],
+            &[
+                0x03, 0x4F, 0x26, 0x90, 0x0B, 0xBB, 0xF2, 0x19, 0xCF, 0x04, 0xA1, 0x6E, 0x55, 0x45,
+                0x4A, 0x3B, 0x8B, 0xC0, 0x35, 0xA4, 0xEE, 0x36, 0x6F, 0x81, 0x1B, 0xEE, 0xF2, 0x1B,
+                0xC4, 0xF3, 0xDA, 0x07,
+            ],
+            &[
+                0x24, 0xCB, 0x62, 0x8A, 0xDC, 0xFC, 0x1F, 0x63, 0x80, 0x1D, 0x72, 0x3C, 0xB5, 0x47,
+                0xAF, 0xFA, 0x53, 0x52, 0x13, 0xE8, 0x29, 0x6E, 0x1B, 0x10, 0xFF, 0x7D, 0x0A, 0x72,
+                0xC3, 0x71, 0xE7, 0x04,
+            ],
+        ],
+        [
+            &[
+                0x63, 0x15, 0xCD, 0x48, 0xF0, 0x2E, 0x62, 0xC6, 0x8D, 0x83, 0x64, 0x54, 0xBE, 0x32,
+                0xB8, 0x00, 0xFB, 0xDC, 0x55, 0x42, 0xE6, 0x3E, 0x4D, 0x83, 0xA9, 0x07, 0xBB, 0x04,
+                0xFE, 0x6C, 0x7A, 0x06,
+            ],
+            &[
+                0x68, 0x59, 0x1B, 0xAE, 0x49, 0x35, 0x7F, 0x67, 0x02, 0xF4, 0x86, 0x01, 0x52, 0x9A,
+                0xF4, 0x85, 0x58, 0xC7, 0xAD, 0x3E, 0x6A, 0xDA, 0x93, 0xA8, 0xD1, 0x99, 0x3D, 0x0B,
+                0xC1, 0x1A, 0xFF, 0x05,
+            ],
+            &[
+                0x25, 0xA7, 0x8A, 0x24, 0x4E, 0xFA, 0x92, 0xF9, 0x4C, 0x1A, 0x69, 0x82, 0x7C, 0x4D,
+                0x78, 0xDF, 0x13, 0x50, 0x15, 0x5C, 0x3F, 0xCD, 0xDD, 0xD1, 0xAB, 0xF3, 0x4B, 0x85,
+                0xD1, 0x0C, 0x30, 0x06,
+            ],
+            &[
+                0x62, 0x19, 0xA7, 0x6F, 0x47, 0xC1, 0x0D, 0x16, 0x4E, 0x4D, 0xA2, 0xEA, 0x3D, 0x2C,
+                0x26, 0x14, 0xEB, 0x6B, 0x5B, 0x0C, 0x23, 0xD5, 0x46, 0x83, 0x58, 0xA5, 0xD9, 0xE9,
+                0x0A, 0x51, 0x0B, 0x07,
+            ],
+        ],
+        [
+            &[
+                0xB5, 0x35, 0xD9, 0x6D, 0x3A, 0xF5, 0x9B, 0xD5, 0xB8, 0x36, 0x50, 0x90, 0x37, 0xF4,
+                0x66, 0x90, 0x52, 0x9F, 0x44, 0x64, 0x90, 0xD0, 0xB8, 0x13, 0x25, 0x67, 0xCD, 0x47,
+                0xDD, 0x18, 0xDD, 0x06,
+            ],
+            &[
+                0x52, 0x4A, 0x36, 0xD3, 0xA8, 0xB8, 0x3F, 0x51, 0x0E, 0xD7, 0xD5, 0xBA, 0x53, 0x60,
+                0xF8, 0x4F, 0x65, 0x65, 0xB0, 0x38, 0xEF, 0x2B, 0x9B, 0xE6, 0x48, 0x99, 0x27, 0x35,
+                0x13, 0x92, 0x27, 0x05,
+            ],
+            &[
+                0xD0, 0x3F, 0x84, 0x45, 0x6D, 0x7C, 0x8D, 0x38, 0x1E, 0x29, 0xA1, 0xCD, 0x4B, 0xF6,
+                0xB5, 0x59, 0xDB, 0x7A, 0xA3, 0x79, 0xC6, 0x63, 0xE4, 0x98, 0xBB, 0x6D, 0x49, 0x27,
+                0x47, 0x23, 0x94, 0x12,
+            ],
+            &[
+                0xD1, 0xBC, 0x73, 0x53, 0x07, 0xE7, 0x10, 0x2F, 0x3F, 0x8C, 0xDC, 0xC2, 0xEC, 0x44,
+                0x6C, 0x47, 0x4F, 0x4D, 0x6D, 0x0A, 0xD9, 0x24, 0x98, 0x06, 0x9D, 0x97, 0xDF, 0x20,
+                0x95, 0xFE, 0x68, 0x07,
+            ],
+        ],
+        [
+            &[
+                0xB2, 0xA3, 0x0E, 0x6F, 0xAB, 0xD1, 0x8D, 0xDD, 0x4C, 0xC0, 0x7A, 0xE8, 0xA4, 0x2B,
+                0xB0, 0xD8, 0xDF, 0xA6, 0xB1, 0xC6, 0x6A, 0x62, 0x9B, 0xC5, 0x88, 0xEE, 0x80, 0xFA,
+                0xF4, 0x03, 0x60, 0x12,
+            ],
+            &[
+                0xEE, 0x51, 0xA3, 0x45, 0xD8, 0x01, 0x98, 0x03, 0xC1, 0xB1, 0x18, 0xD8, 0x32, 0x8A,
+                0x0D, 0x2D, 0x73, 0xD4, 0xC2, 0xA2, 0x0D, 0x2C, 0x33, 0x77, 0x14, 0x11, 0xBA, 0xFF,
+                0x8A, 0xB2, 0x54, 0x10,
+            ],
+            &[
+                0xEF, 0xB8, 0x0E, 0x19, 0xAD, 0x7C, 0xB6, 0x74, 0xC4, 0x83, 0xFB, 0x96, 0x82, 0x32,
+                0x2B, 0xE8, 0xB2, 0x75, 0x86, 0x1B, 0xFD, 0x3F, 0x15, 0xAC, 0x88, 0x87, 0x28, 0x07,
+                0x54, 0xCF, 0x97, 0x03,
+            ],
+            &[
+                0xBD, 0x9D, 0x17, 0x69, 0x01, 0x24, 0x8E, 0xBF, 0x90, 0xF3, 0x4C, 0xCE, 0x38, 0x68,
+                0x33, 0xDA, 0x4D, 0xD3, 0x4F, 0x28, 0x08, 0xB9, 0x4C, 0xB7, 0xC6, 0x2A, 0x80, 0xB1,
+                0xFD, 0x5D, 0x2B, 0x11,
+            ],
+        ],
+        [
+            &[
+                0x02, 0x2E, 0x29, 0xE6, 0x7B, 0x50, 0x44, 0xB3, 0x09, 0x72, 0x1A, 0x7E, 0x01, 0xE0,
+                0x61, 0xFC, 0xAC, 0x22, 0x66, 0x33, 0xAB, 0xB1, 0xF0, 0xB5, 0xB8, 0xA6, 0x6E, 0xC9,
+                0x91, 0xC7, 0xF3, 0x08,
+            ],
+            &[
+                0xE0, 0xE9, 0xE4, 0xA5, 0x20, 0x2B, 0xE7, 0x0D, 0x4C, 0x8E, 0xE3, 0x88, 0x7D, 0x3A,
+                0x55, 0xEB, 0x43, 0x65, 0x95, 0x41, 0x57, 0x6F, 0xCB, 0x09, 0x85, 0x9A, 0xD0, 0xB2,
+                0x61, 0xFD, 0x2B, 0x03,
+            ],
+            &[
+                0xF6, 0xFE, 0xEC, 0x68, 0x55, 0x9B, 0x5C, 0xD1, 0xD7, 0xED, 0xBC, 0x90, 0x79, 0x0E,
+                0x53, 0x85, 0xA1, 0xFE, 0xF2, 0x2F, 0x51, 0x38, 0x2F, 0x0F, 0x2D, 0x55, 0x56, 0x1F,
+                0x2D, 0xFC, 0xBE, 0x10,
+            ],
+            &[
+                0xB0, 0xF7, 0x59, 0x1D, 0xD2, 0x75, 0x94, 0x33, 0x39, 0xD4, 0xBD, 0x93, 0xAE, 0x1E,
+                0x74, 0x55, 0xE6, 0x2D, 0x38, 0x83, 0x06, 0x77, 0x11, 0x13, 0xBB, 0x42, 0xB2, 0xBA,
+                0x98, 0x6F, 0x47, 0x0D,
+            ],
+        ],
+        [
+            &[
+                0x36, 0x12, 0xD4, 0xB2, 0xD2, 0xD9, 0xA1, 0xE2, 0xFF, 0xFB, 0xD0, 0x07, 0x26, 0xDF,
+                0xB7, 0xF3, 0xB3, 0xE1, 0x92, 0x28, 0x42, 0x02, 0x2B, 0x93, 0xE2, 0x49, 0xDB, 0xC6,
+                0xDE, 0xBA, 0x6B, 0x09,
+            ],
+            &[
+                0x2E, 0xE2, 0x11, 0xD4, 0x71, 0xD5, 0x58, 0xA2, 0x35, 0x81, 0xDC, 0x13, 0x7A, 0x3B,
+                0x16, 0xB0, 0xC5, 0xDE, 0x27, 0x9C, 0xCF, 0x8A, 0x81, 0xD6, 0x29, 0xCD, 0x66, 0xC7,
+                0xAE, 0x8B, 0xD3, 0x08,
+            ],
+            &[
+                0x38, 0x7A, 0x62, 0x61, 0x30, 0x02, 0x70, 0x05, 0xCD, 0x9E, 0xCA, 0x86, 0x93, 0x68,
+                0xD3, 0x74, 0x2F, 0xC1, 0xC6, 0xDB, 0x3E, 0x36, 0x77, 0x17, 0x37, 0x27, 0x00, 0x1E,
+                0x5B, 0xD0, 0xEB, 0x01,
+            ],
+            &[
+                0xB1, 0x7F, 0x69, 0x18, 0x62, 0xB6, 0xC4, 0x7B, 0x45, 0x07, 0x60, 0xA0, 0x96, 0xC6,
+                0x28, 0x04, 0xA3, 0x47, 0x4F, 0x14, 0x74, 0x59, 0x17, 0x7B, 0xE7, 0x52, 0x0B, 0x0A,
+                0xC1, 0x60, 0x8B, 0x02,
+            ],
+        ],
+        [
+            &[
+                0x4A, 0x76, 0x85, 0x14, 0x9C, 0x93, 0xE1, 0x50, 0x52, 0xE6, 0x69, 0x33, 0xDB, 0x7D,
+                0xD2, 0xA3, 0xBC, 0x64, 0x99, 0x38, 0xDC, 0xA6, 0xD0, 0xA4, 0xC1, 0x3F, 0x9C, 0xE0,
+                0xD0, 0x37, 0xD1, 0x0B,
+            ],
+            &[
+                0xE2, 0xEC, 0x40, 0x28, 0x3F, 0x07, 0xCD, 0x94, 0x6C, 0x66, 0x1E, 0x1B, 0x71, 0x1B,
+                0xD0, 0x0F, 0xE7, 0x49, 0x42, 0x1F, 0x72, 0x05, 0x5B, 0x8F, 0xE3, 0xE6, 0x3B, 0xD0,
+                0x4B, 0x80, 0x72, 0x05,
+            ],
+            &[
+                0x7F, 0x10, 0x06, 0x19, 0xBE, 0x97, 0x30, 0xE1, 0xA7, 0xC2, 0x16, 0x88, 0x95, 0xCB,
+                0xCC, 0x3A, 0x10, 0x59, 0x3C, 0xEC, 0xDF, 0x7B, 0xDC, 0xE9, 0x09, 0xAC, 0xDA, 0x76,
+                0xCE, 0x4C, 0xD6, 0x0A,
+            ],
+            &[
+                0xDC, 0xDF, 0x82, 0x9A, 0x90, 0xA6, 0x4D, 0x08, 0x6F, 0x80, 0x9B, 0xEE, 0xBA, 0xB6,
+                0x4A, 0xB3, 0x02, 0x6A, 0xF4, 0xCD, 0x19, 0x65, 0x83, 0xF1, 0x2B, 0xE9, 0xBA, 0x6A,
+                0xFD, 0xF7, 0x53, 0x03,
+            ],
+        ],
+        [
+            &[
+                0xF0, 0xEE, 0xEB, 0x44, 0x49, 0x0F, 0x57, 0x6B, 0x66, 0xAD, 0xEA, 0x94, 0xF2, 0x70,
+                0x53, 0xC9, 0x51, 0xE3, 0x08, 0xC2, 0x70, 0xC1, 0x63, 0xAD, 0x1C, 0xD9, 0xAA, 0x3F,
+                0xB5, 0x51, 0xFB, 0x0B,
+            ],
+            &[
+                0x5D, 0x1C, 0x50, 0xB2, 0x8F, 0x6D, 0x81, 0x17, 0xEF, 0x24, 0x95, 0xEF, 0x7F, 0x92,
+                0xAB, 0xAC, 0x62, 0xF4, 0x34, 0x1C, 0xDB, 0xA3, 0x44, 0xE1, 0xE6, 0x48, 0x12, 0xD8,
+                0x0D, 0xDE, 0xF6, 0x02,
+            ],
+            &[
+                0xBA, 0xC4, 0xDA, 0x36, 0xF8, 0x3A, 0xFD, 0x70, 0x44, 0x17, 0x93, 0x2E, 0x64, 0x65,
+                0x5C, 0xA3, 0x61, 0xA0, 0x92, 0x6A, 0x02, 0x3D, 0x99, 0x72, 0x80, 0xF0, 0xFD, 0x36,
+                0xF1, 0x1E, 0x62, 0x10,
+            ],
+            &[
+                0x87, 0x01, 0xE9, 0x38, 0x10, 0xC0, 0x86, 0xC8, 0x81, 0x5C, 0xDF, 0x35, 0x85, 0x96,
+                0xC0, 0x56, 0xC6, 0xBC, 0xD4, 0x44, 0x15, 0x3D, 0xCA, 0x2A, 0x7F, 0xDE, 0xBD, 0x35,
+                0xC9, 0x7A, 0x6B, 0x04,
+            ],
+        ],
+        [
+            &[
+                0x4E, 0x8C, 0x05, 0x05, 0x6F, 0x9E, 0x50, 0x85, 0x94, 0x2A, 0xD6, 0x0F, 0x0E, 0x74,
+                0xF1, 0x25, 0xED, 0xB3, 0x9B, 0x84, 0x6E, 0x86, 0x64, 0xC6, 0xB1, 0x11, 0x78, 0x39,
+                0xC6, 0xF6, 0xB1, 0x02,
+            ],
+            &[
+                0xE9, 0x14, 0xA8, 0x81, 0x30, 0x29, 0x95, 0x5D, 0x9F, 0x31, 0x16, 0x74, 0x27, 0xF3,
+                0xAC, 0x13, 0x88, 0xD2, 0x1D, 0xB3, 0x1A, 0x71, 0xD4, 0x9E, 0x71, 0x23, 0x21, 0x46,
+                0x1E, 0x64, 0x8B, 0x03,
+            ],
+            &[
+                0x70, 0xF7, 0x82, 0x09, 0x1D, 0x92, 0x56, 0x28, 0x88, 0x53, 0xB6, 0x0F, 0x64, 0x9F,
+                0x43, 0x30, 0xF0, 0xD0, 0xE3, 0xA8, 0xF1, 0x90, 0xD6, 0xE3, 0x17, 0xB6, 0x3D, 0x0B,
+                0x96, 0x61, 0x10, 0x0E,
+            ],
+            &[
+                0xBF, 0x35, 0x79, 0x95, 0xAC, 0x46, 0x5D, 0x91, 0x63, 0x59, 0x7D, 0xE9, 0x3C, 0xB1,
+                0x67, 0x7E, 0x11, 0xDB, 0x81, 0x1C, 0x9C, 0xF5, 0xC8, 0x79, 0x13, 0xD5, 0x29, 0x5A,
+                0x86, 0x32, 0x75, 0x02,
+            ],
+        ],
+        [
+            &[
+                0xCF, 0x4D, 0x51, 0xD4, 0x01, 0x81, 0xC5, 0xCA, 0xD9, 0x2C, 0x4E, 0xBD, 0x10, 0x32,
+                0xDC, 0x90, 0xA2, 0x5A, 0x96, 0x95, 0xE0, 0xBC, 0xDC, 0x5A, 0x7B, 0x35, 0x26, 0x35,
+                0x63, 0xB7, 0xEB, 0x0F,
+            ],
+            &[
+                0x42, 0xD6, 0xA8, 0x5D, 0x52, 0x93, 0x0B, 0x25, 0x24, 0x82, 0x67, 0x6D, 0xE7, 0x08,
+                0x37, 0x05, 0x47, 0xD1, 0xC8, 0x4F, 0x37, 0xEF, 0xFD, 0x49, 0xEE, 0xF4, 0x27, 0x08,
+                0x66, 0x2F, 0x26, 0x12,
+            ],
+            &[
+                0x5A, 0x31, 0x79, 0x93, 0x85, 0xD5, 0x46, 0xC5, 0xFB, 0x47, 0x56, 0x24, 0xFD, 0x78,
+                0x5F, 0x4F, 0x38, 0xC8, 0x9C, 0x08, 0x60, 0x40, 0x8F, 0x5B, 0xA0, 0x85, 0x5C, 0x49,
+                0x39, 0xC3, 0xA6, 0x01,
+            ],
+            &[
+                0x5F, 0x11, 0x56, 0x3F, 0x89, 0xB9, 0xB1, 0xDB, 0xC8, 0xC3, 0xB3, 0x03, 0xEA, 0xBF,
+                0x3C, 0xFB, 0xA3, 0x3B, 0x0C, 0x70, 0xB9, 0xAB, 0x4C, 0x87, 0x35, 0x64, 0x5C, 0xD0,
+                0x51, 0x25, 0x15, 0x07,
+            ],
+        ],
+        [
+            &[
+                0x32, 0x4C, 0x93, 0xB7, 0xFA, 0xC7, 0x95, 0x2A, 0xCF, 0xF0, 0xE2, 0x39, 0xDD, 0x25,
+                0x28, 0xC4, 0x25, 0x13, 0xF2, 0xC7, 0xD2, 0xFE, 0xB6, 0x17, 0xA7, 0x68, 0xFF, 0x17,
+                0x5D, 0xE4, 0xB0, 0x00,
+            ],
+            &[
+                0x9D, 0x5C, 0x64, 0x92, 0x90, 0x1A, 0xA7, 0x2C, 0x87, 0xFC, 0x21, 0x67, 0xC7, 0x02,
+                0x70, 0xA9, 0x13, 0x57, 0xDA, 0xD4, 0x9E, 0x57, 0x1E, 0xA3, 0x3C, 0x0D, 0xD6, 0x5C,
+                0xC6, 0x4E, 0xA0, 0x10,
+            ],
+            &[
+                0xE2, 0x55, 0x38, 0x28, 0xEA, 0x27, 0x02, 0x3E, 0xF0, 0xE5, 0xC1, 0x90, 0x8B, 0x27,
+                0x58, 0x33, 0xBE, 0x50, 0x9D, 0xFC, 0x93, 0xBC, 0x7C, 0x0D, 0x1F, 0x54, 0x5D, 0xF1,
+                0x5B, 0xA0, 0x91, 0x10,
+            ],
+            &[
+                0xAD, 0x0F, 0xEF, 0xA1, 0xAE, 0x91, 0x14, 0x80, 0xF6, 0x81, 0x44, 0x50, 0xCC, 0x3D,
+                0xBC, 0x63, 0xFD, 0x86, 0x73, 0x21, 0x0C, 0x3A, 0x48, 0x02, 0x51, 0xD7, 0x6A, 0x77,
+                0xC0, 0xB8, 0x0C, 0x12,
+            ],
+        ],
+        [
+            &[
+                0x08, 0x8F, 0x49, 0xBB, 0x93, 0x6C, 0x82, 0xC7, 0x34, 0x88, 0x45, 0x2F, 0x9C, 0x6B,
+                0x63, 0x17, 0x48, 0x8B, 0x70, 0x11, 0xDB, 0xC0, 0x98, 0x3B, 0x10, 0x0F, 0x23, 0x0E,
+                0x29, 0xCF, 0xEB, 0x0A,
+            ],
+            &[
+                0xEF, 0xDD, 0xCC, 0x3F, 0x8E, 0xA7, 0xA7, 0x80, 0xC4, 0xA0, 0x0F, 0xDE, 0xDD, 0x6E,
+                0x23, 0xAC, 0x4F, 0x1D, 0x1B, 0x2A, 0xEC, 0x56, 0x41, 0x8F, 0x15, 0x05, 0x4B, 0xAF,
+                0xC9, 0xA4, 0x8C, 0x0C,
+            ],
+            &[
+                0x3F, 0x36, 0x89, 0x9D, 0xAF, 0x8E, 0x49, 0xC8, 0xCB, 0xB1, 0xE3, 0x6C, 0x96, 0x5F,
+                0x9B, 0xDD, 0xD5, 0x79, 0x8E, 0x45, 0x46, 0xF6, 0xDF, 0x22, 0xFF, 0x6B, 0xC7, 0x25,
+                0x30, 0x77, 0x2A, 0x06,
+            ],
+            &[
+                0x80, 0xE1, 0x2C, 0xB6, 0xA3, 0xE2, 0x17, 0xB0, 0xF2, 0x56, 0x1F, 0x7D, 0x4D, 0xF6,
+                0x34, 0xB1, 0x4C, 0x9A, 0x1F, 0x20, 0x03, 0x5E, 0x16, 0x84, 0xF3, 0x4A, 0x10, 0x30,
+                0x2D, 0xF4, 0x64, 0x01,
+            ],
+        ],
+        [
+            &[
+                0xEC, 0x5F, 0xA5, 0xF8, 0x54, 0x8B, 0x7F, 0xAD, 0x47, 0xA6, 0x54, 0x64, 0x41, 0x41,
+                0xD8, 0x0C, 0xE3, 0xF1, 0x99, 0x0D, 0x63, 0x3B, 0xD7, 0xFC, 0xA8, 0xE8, 0xC1, 0x29,
+                0x5B, 0x83, 0x46, 0x0E,
+            ],
+            &[
+                0x63, 0xAD, 0x7D, 0x06, 0x90, 0x67, 0xBD, 0x2B, 0x9C, 0x0F, 0x5A, 0xA6, 0x87, 0xA8,
+                0xDD, 0x90, 0x80, 0xFD, 0xD4, 0xF1, 0x6E, 0x04, 0x0B, 0x3B, 0x48, 0xBF, 0x06, 0x17,
+                0xA4, 0x93, 0xBA, 0x0E,
+            ],
+            &[
+                0xF0, 0x3E, 0x16, 0x16, 0x76, 0xB8, 0x89, 0x3F, 0x8B, 0x67, 0x67, 0x9F, 0x94, 0xD0,
+                0x0D, 0x65, 0x83, 0xE7, 0x42, 0xEF, 0x16, 0x76, 0x0C, 0x4A, 0x2F, 0x94, 0xC0, 0xCB,
+                0xA2, 0xCC, 0x61, 0x04,
+            ],
+            &[
+                0x96, 0x75, 0x75, 0x79, 0xAA, 0xD6, 0x26, 0x77, 0x17, 0x3A, 0x74, 0x50, 0x27, 0xC7,
+                0xDC, 0xC2, 0x4B, 0x3B, 0xA0, 0x35, 0x5B, 0xD0, 0x84, 0x3E, 0x72, 0x44, 0x11, 0x2A,
+                0x3D, 0x07, 0xA2, 0x11,
+            ],
+        ],
+    ];
+}
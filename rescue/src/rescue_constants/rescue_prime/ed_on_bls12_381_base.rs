@@ -0,0 +1,622 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+use crate::{rescue_prime::RescuePrimeParameter, STATE_SIZE};
+use ark_ed_on_bls12_381::Fq;
+
+// the constants in this file follow the reference implementation
+// https://github.com/aszepieniec/rescue-prime/blob/master/rescue_prime.sage
+// i.e. get_number_of_rounds(), get_mds_matrix() and get_round_constants()
+// with security_level = 128, m = STATE_SIZE = 4, capacity = 1 and
+// q381 = 52435875175126190479447740508185965837690552500527637822603658699938581184513
+
+impl RescuePrimeParameter for Fq {
+    // ceil(1.5 * max(5, l1)) where l1 is the number of rounds thwarting
+    // Groebner basis attacks
+    const PRIME_ROUNDS: usize = 11;
+
+    // The MDS matrix is the transpose of the right half of the echelon form of
+    // the 4 x 8 Vandermonde matrix [g^(i * j)], where g = 7 is the smallest
+    // primitive element of the field
+    // MDS[0,0]= "52435875175126190479447740508185965837690552500527637822603658699938581066864"
+    // MDS[0,1]= "137200"
+    // MDS[0,2]= "52435875175126190479447740508185965837690552500527637822603658699938581164563"
+    // MDS[0,3]= "400"
+    // MDS[1,0]= "52435875175126190479447740508185965837690552500527637822603658699938534124913"
+    // MDS[1,1]= "54762351"
+    // MDS[1,2]= "52435875175126190479447740508185965837690552500527637822603658699938573341713"
+    // MDS[1,3]= "140050"
+    // MDS[2,0]= "52435875175126190479447740508185965837690552500527637822603658699922104442063"
+    // MDS[2,1]= "19167800400"
+    // MDS[2,2]= "52435875175126190479447740508185965837690552500527637822603658699935841949364"
+    // MDS[2,3]= "48177200"
+    // MDS[3,0]= "52435875175126190479447740508185965837690552500527637822603658694270581781713"
+    // MDS[3,1]= "6593435097550"
+    // MDS[3,2]= "52435875175126190479447740508185965837690552500527637822603658698996613844913"
+    // MDS[3,3]= "16531644851"
+    // MDS matrix in little-endian 32 bytes elements
+    const PRIME_MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE] = [
+        [
+            &[
+                0x70, 0x34, 0xFE, 0xFF, 0xFE, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
+                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
+                0x53, 0xA7, 0xED, 0x73,
+            ],
+            &[
+                0xF0, 0x17, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x13, 0xB2, 0xFF, 0xFF, 0xFE, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
+                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
+                0x53, 0xA7, 0xED, 0x73,
+            ],
+            &[
+                0x90, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+        [
+            &[
+                0x71, 0xED, 0x31, 0xFD, 0xFE, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
+                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
+                0x53, 0xA7, 0xED, 0x73,
+            ],
+            &[
+                0x6F, 0x9B, 0x43, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x11, 0x54, 0x88, 0xFF, 0xFE, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
+                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
+                0x53, 0xA7, 0xED, 0x73,
+            ],
+            &[
+                0x12, 0x23, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+        [
+            &[
+                0xCF, 0xDC, 0xE8, 0x29, 0xFB, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
+                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
+                0x53, 0xA7, 0xED, 0x73,
+            ],
+            &[
+                0x50, 0x6C, 0x7D, 0x76, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0xB4, 0x96, 0xBA, 0x5C, 0xFE, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
+                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
+                0x53, 0xA7, 0xED, 0x73,
+            ],
+            &[
+                0x30, 0x20, 0xDF, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+        [
+            &[
+                0xD1, 0xB4, 0xE8, 0x50, 0xD7, 0xFA, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
+                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
+                0x53, 0xA7, 0xED, 0x73,
+            ],
+            &[
+                0xCE, 0x59, 0x5B, 0x27, 0xFF, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0xB1, 0x0F, 0x5F, 0xAE, 0x23, 0xFF, 0xFF, 0xFF, 0xFE, 0x5B, 0xFE, 0xFF, 0x02, 0xA4,
+                0xBD, 0x53, 0x05, 0xD8, 0xA1, 0x09, 0x08, 0xD8, 0x39, 0x33, 0x48, 0x7D, 0x9D, 0x29,
+                0x53, 0xA7, 0xED, 0x73,
+            ],
+            &[
+                0xB3, 0xE1, 0x5C, 0xD9, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+    ];
+
+    // The 2 * m * PRIME_ROUNDS round constants are the consecutive
+    // little-endian integers of 33 bytes, reduced mod q381, of the output of
+    //   SHAKE256("Rescue-XLIX(q381,4,1,128)"), with q381 written in decimal,
+    // and are grouped by state-size vectors, two per round
+    const PRIME_ROUND_CONSTANTS_LE: &'static [[&'static [u8]; STATE_SIZE]] = &[
+        [
+            &[
+                0x9A, 0x33, 0x82, 0xAB, 0xD3, 0x1A, 0x32, 0x55, 0x5E, 0x0E, 0x89, 0xF6, 0xC7, 0xED,
+                0x5C, 0x63, 0x4D, 0x5B, 0x4F, 0x23, 0x91, 0xF9, 0x1B, 0x9F, 0xF8, 0x22, 0x33, 0x11,
+                0x8C, 0xE0, 0x66, 0x5D,
+            ],
+            &[
+                0xAD, 0xD4, 0x87, 0xB1, 0x29, 0xEC, 0x88, 0xD0, 0x7D, 0x9F, 0x0A, 0x94, 0xF2, 0xA5,
+                0xC4, 0x32, 0xC7, 0x3A, 0x99, 0xD8, 0x4E, 0xF6, 0xF8, 0xB3, 0xB5, 0x67, 0x93, 0x96,
+                0x25, 0xFE, 0x90, 0x61,
+            ],
+            &[
+                0x11, 0x1C, 0x12, 0x87, 0x34, 0x6A, 0xC5, 0x15, 0x44, 0xE6, 0xFF, 0x0C, 0xC9, 0x1C,
+                0x61, 0x04, 0x94, 0x0B, 0x19, 0x77, 0xF8, 0xE4, 0x8C, 0xF7, 0xEB, 0xCD, 0x47, 0x0F,
+                0x3E, 0x2F, 0xC4, 0x30,
+            ],
+            &[
+                0x13, 0x70, 0xF9, 0xF1, 0x43, 0x83, 0xB4, 0xF2, 0x8F, 0xC3, 0xA0, 0xD7, 0xDA, 0x7B,
+                0x57, 0x53, 0x4C, 0x51, 0x13, 0xBB, 0x4C, 0xEE, 0x10, 0xDC, 0x4C, 0xAD, 0x56, 0xC7,
+                0x54, 0x28, 0xCB, 0x2A,
+            ],
+        ],
+        [
+            &[
+                0x34, 0xB8, 0x7E, 0x1D, 0x6F, 0x9C, 0xD2, 0x78, 0x77, 0x92, 0x67, 0x3B, 0xB0, 0xA8,
+                0xE9, 0xEC, 0x90, 0xAD, 0x2C, 0x6F, 0xDA, 0x4D, 0xF0, 0x13, 0x2D, 0x82, 0x55, 0x75,
+                0x6C, 0x58, 0xC8, 0x54,
+            ],
+            &[
+                0xBD, 0xAD, 0x0C, 0x58, 0x0A, 0xF0, 0xDB, 0x02, 0x02, 0x8A, 0x16, 0x7A, 0x94, 0xE2,
+                0x4F, 0x71, 0xF7, 0x0A, 0x75, 0x09, 0xA9, 0x8C, 0x92, 0xF2, 0x8A, 0x84, 0x6A, 0xBA,
+                0x5C, 0x42, 0xB8, 0x6A,
+            ],
+            &[
+                0xE0, 0xFF, 0xBC, 0x80, 0xA8, 0x2F, 0x87, 0x6E, 0x88, 0xEA, 0x72, 0xB1, 0x64, 0x9E,
+                0x0D, 0xD9, 0x73, 0xE1, 0xAC, 0x35, 0x11, 0xC9, 0x32, 0x30, 0x34, 0x87, 0x70, 0x25,
+                0x33, 0xD3, 0xAD, 0x0D,
+            ],
+            &[
+                0xAA, 0x16, 0x30, 0x2B, 0x18, 0x24, 0x60, 0xA5, 0x92, 0x49, 0x81, 0x88, 0xFD, 0x54,
+                0xDA, 0x45, 0x11, 0x48, 0x26, 0x66, 0x72, 0xBC, 0x46, 0x7A, 0x4F, 0x86, 0x0A, 0xE9,
+                0xC8, 0xC3, 0xD7, 0x3C,
+            ],
+        ],
+        [
+            &[
+                0x26, 0x4C, 0x23, 0x83, 0x16, 0x01, 0xA7, 0x42, 0xB7, 0xE3, 0x5A, 0x33, 0xEC, 0xEF,
+                0x33, 0x50, 0xB6, 0x66, 0x5D, 0x3E, 0x86, 0xEB, 0x12, 0x26, 0x47, 0x6D, 0x1E, 0xC7,
+                0x2D, 0xA8, 0x2C, 0x65,
+            ],
+            &[
+                0xA8, 0x21, 0xF5, 0x1E, 0x5B, 0xE9, 0x78, 0x49, 0x2A, 0x60, 0x39, 0xD0, 0x4F, 0x02,
+                0x87, 0x56, 0x63, 0x93, 0x64, 0xA8, 0x61, 0x6F, 0x58, 0xEC, 0xFD, 0x0A, 0x7E, 0x67,
+                0x07, 0x86, 0xB0, 0x34,
+            ],
+            &[
+                0x68, 0x8A, 0x55, 0x53, 0xFE, 0x38, 0x02, 0x46, 0x05, 0x41, 0x5D, 0xBB, 0xDD, 0xA4,
+                0xAC, 0x1A, 0xF1, 0x80, 0xB3, 0xD1, 0x21, 0x7F, 0x7A, 0xD4, 0x5C, 0x8C, 0x1A, 0xB4,
+                0x48, 0x39, 0x2D, 0x34,
+            ],
+            &[
+                0xAE, 0xB7, 0x59, 0x08, 0x62, 0xB6, 0xFD, 0x0D, 0x98, 0xED, 0xA8, 0x82, 0x94, 0x4A,
+                0x11, 0xD7, 0xF8, 0x5A, 0x67, 0x64, 0xDD, 0x13, 0xFB, 0xB2, 0xED, 0x92, 0x98, 0xF3,
+                0xF8, 0xE6, 0xBC, 0x12,
+            ],
+        ],
+        [
+            &[
+                0x01, 0xDF, 0x2D, 0xCC, 0x33, 0x5D, 0xEA, 0x16, 0xFB, 0x55, 0x71, 0x4F, 0xFC, 0x4C,
+                0xD2, 0x69, 0x61, 0x02, 0x58, 0x4B, 0x9E, 0x0C, 0xD0, 0x65, 0xAB, 0x0D, 0x40, 0xC8,
+                0x2A, 0xFC, 0xFB, 0x40,
+            ],
+            &[
+                0x79, 0xCA, 0xFC, 0x09, 0x58, 0x1C, 0x5D, 0x53, 0xB7, 0x2B, 0x8A, 0xF4, 0x6C, 0x3D,
+                0xAA, 0x59, 0x4E, 0xF1, 0xB4, 0xB3, 0x1C, 0x97, 0x34, 0xC6, 0x5C, 0x3D, 0xB5, 0x8D,
+                0xD5, 0x9C, 0xF8, 0x27,
+            ],
+            &[
+                0xD7, 0x9E, 0x61, 0x29, 0xFE, 0xC8, 0xD4, 0xD2, 0xF1, 0xF7, 0xEC, 0xF3, 0xE8, 0x2F,
+                0xC9, 0x11, 0xB2, 0x47, 0xE6, 0x81, 0xFF, 0x60, 0x92, 0x1B, 0x47, 0xC3, 0xF6, 0x98,
+                0x03, 0x58, 0xB9, 0x56,
+            ],
+            &[
+                0x56, 0x1C, 0xCC, 0xC6, 0x0C, 0xC3, 0x36, 0x40, 0xF7, 0x58, 0xE5, 0xC9, 0xDC, 0x8E,
+                0x94, 0x6D, 0xDE, 0xAA, 0xFA, 0x4B, 0x75, 0xE8, 0x8C, 0xE2, 0x73, 0x23, 0x6B, 0x46,
+                0x4D, 0x0F, 0x62, 0x3C,
+            ],
+        ],
+        [
+            &[
+                0x38, 0x98, 0x27, 0x7E, 0x76, 0x26, 0xC0, 0x59, 0xFD, 0x56, 0x85, 0x42, 0xDF, 0x07,
+                0x7C, 0x6F, 0xD6, 0x06, 0xE5, 0x34, 0x9F, 0x92, 0x85, 0x71, 0x63, 0x4C, 0x84, 0x7E,
+                0xB3, 0xB7, 0xEE, 0x47,
+            ],
+            &[
+                0xAD, 0xAD, 0xED, 0xEC, 0xEA, 0xFC, 0x9C, 0x2A, 0x88, 0xCE, 0x69, 0x73, 0x92, 0xC6,
+                0x96, 0xAD, 0x2E, 0x7F, 0xCE, 0x52, 0x4E, 0x0F, 0xFF, 0x20, 0xF3, 0xE0, 0x85, 0x5D,
+                0x16, 0xC5, 0xC7, 0x19,
+            ],
+            &[
+                0x5D, 0x82, 0x1E, 0xBA, 0x56, 0x66, 0xA2, 0xB1, 0x36, 0xD1, 0x5D, 0x15, 0x7B, 0x09,
+                0x7F, 0xAE, 0xD1, 0x0F, 0x27, 0x70, 0x9B, 0x87, 0x44, 0xBF, 0x50, 0xF1, 0xBE, 0x5D,
+                0x6B, 0x0A, 0x3F, 0x28,
+            ],
+            &[
+                0x23, 0x27, 0xCC, 0x53, 0xD2, 0xEE, 0x87, 0xBD, 0x49, 0xB9, 0x62, 0xC5, 0xB7, 0x28,
+                0xD9, 0xAC, 0xFC, 0x18, 0xA0, 0x58, 0xFB, 0x32, 0xF8, 0x34, 0x69, 0x1B, 0x54, 0xD2,
+                0xD7, 0x2F, 0x88, 0x0B,
+            ],
+        ],
+        [
+            &[
+                0x5A, 0x9D, 0x9D, 0x71, 0x05, 0xE0, 0xEE, 0x74, 0xCC, 0x0A, 0x02, 0xA6, 0x22, 0xEC,
+                0xB1, 0xD6, 0x49, 0x34, 0x61, 0x18, 0xEE, 0x37, 0x5C, 0x88, 0x64, 0xF4, 0x03, 0x4D,
+                0xCF, 0x23, 0x82, 0x0D,
+            ],
+            &[
+                0x68, 0xA3, 0xD7, 0x9B, 0xD7, 0x08, 0xBB, 0xB3, 0xFA, 0x02, 0x83, 0x01, 0x81, 0xED,
+                0xF5, 0x77, 0xFB, 0x40, 0x32, 0xD0, 0x85, 0x9A, 0xD8, 0xA4, 0x6C, 0xB2, 0x9F, 0x2A,
+                0x7D, 0x20, 0xC8, 0x2B,
+            ],
+            &[
+                0x46, 0x97, 0xB0, 0x27, 0x5C, 0x2F, 0x35, 0x0C, 0x00, 0x25, 0xC3, 0xEE, 0xE6, 0x13,
+                0x40, 0xFE, 0xC1, 0xE8, 0x76, 0xF9, 0x62, 0x10, 0x9F, 0xE3, 0xA7, 0x6E, 0x37, 0x31,
+                0x31, 0xC1, 0xED, 0x72,
+            ],
+            &[
+                0x09, 0x69, 0x0D, 0x49, 0xC7, 0x7D, 0xD5, 0x4B, 0x12, 0xA8, 0xE2, 0x33, 0x7A, 0x88,
+                0x6B, 0x59, 0x29, 0x07, 0x46, 0x07, 0x4F, 0x7E, 0x5C, 0xAB, 0xAA, 0x7D, 0xF9, 0x6B,
+                0x47, 0x9C, 0xDD, 0x4F,
+            ],
+        ],
+        [
+            &[
+                0x43, 0x2D, 0xA7, 0xE6, 0xC4, 0x14, 0x6E, 0xC9, 0x23, 0xFF, 0x1F, 0x91, 0x3F, 0xA3,
+                0xE4, 0xD6, 0xD6, 0x86, 0x1E, 0xDA, 0x41, 0x32, 0x0D, 0xC1, 0x11, 0xDF, 0x66, 0xF1,
+                0x90, 0x5C, 0x67, 0x00,
+            ],
+            &[
+                0x89, 0x64, 0x52, 0xD3, 0xA3, 0xE9, 0xDB, 0x4B, 0x52, 0x3D, 0xC3, 0x85, 0x41, 0xD7,
+                0x5C, 0x21, 0xB8, 0x56, 0xB5, 0xA9, 0x3B, 0x50, 0xC7, 0x11, 0xA3, 0x5A, 0x01, 0x79,
+                0x9B, 0x58, 0x35, 0x10,
+            ],
+            &[
+                0xFB, 0x52, 0x88, 0x76, 0x9C, 0x98, 0x36, 0x11, 0x44, 0xF0, 0xCA, 0x0C, 0xCA, 0xBD,
+                0xE6, 0xFF, 0xBD, 0x30, 0xD0, 0x3A, 0x77, 0x56, 0xC3, 0x1B, 0xD4, 0xE5, 0xE6, 0xB1,
+                0x84, 0xAA, 0x3A, 0x31,
+            ],
+            &[
+                0x60, 0x75, 0x83, 0x9B, 0x2D, 0xD6, 0xDB, 0x54, 0x5C, 0x38, 0x72, 0xA0, 0x21, 0xD9,
+                0xA7, 0x54, 0x2A, 0x21, 0x2D, 0x02, 0x8D, 0xCE, 0x1D, 0xF3, 0x2A, 0xFB, 0x31, 0x4E,
+                0x3E, 0xF3, 0xE5, 0x72,
+            ],
+        ],
+        [
+            &[
+                0x71, 0x63, 0x4F, 0x2F, 0x7D, 0xA0, 0xDF, 0xAC, 0x91, 0x48, 0x0E, 0xBD, 0x29, 0xD5,
+                0xAD, 0xFB, 0x87, 0xCD, 0x25, 0x54, 0xBB, 0x3C, 0xAB, 0xE5, 0xF3, 0x2F, 0xAB, 0x11,
+                0x26, 0x7D, 0x54, 0x72,
+            ],
+            &[
+                0xBD, 0x26, 0x95, 0x0E, 0x13, 0xB6, 0xD0, 0x79, 0x33, 0x73, 0x8C, 0xEA, 0x0E, 0x00,
+                0xDB, 0xD1, 0x3C, 0x22, 0xCC, 0x43, 0xD8, 0x50, 0xE5, 0x55, 0xED, 0xAB, 0xE6, 0x4B,
+                0xF7, 0x09, 0x5C, 0x44,
+            ],
+            &[
+                0xE3, 0xF0, 0x5C, 0xCC, 0x37, 0x8F, 0x19, 0xA6, 0xE3, 0x5F, 0x4D, 0x42, 0xF3, 0xBC,
+                0x5C, 0x21, 0x0F, 0xC6, 0x5F, 0xA6, 0x47, 0x68, 0xCB, 0x42, 0x91, 0xC0, 0x2D, 0x46,
+                0x4A, 0xAE, 0xB3, 0x69,
+            ],
+            &[
+                0xCC, 0x76, 0xB3, 0x01, 0x58, 0x77, 0x13, 0x1E, 0x5C, 0xF7, 0x2E, 0xAC, 0x10, 0xA2,
+                0x9A, 0xD7, 0x05, 0xC8, 0x39, 0x06, 0x70, 0xDE, 0x2D, 0x6F, 0x48, 0x19, 0xF4, 0xDD,
+                0x38, 0x5C, 0x0A, 0x48,
+            ],
+        ],
+        [
+            &[
+                0x30, 0xAE, 0xFB, 0xED, 0x66, 0xD3, 0x7C, 0x2F, 0xF3, 0x70, 0x14, 0xCD, 0x12, 0x52,
+                0xAC, 0x1E, 0x8A, 0x1C, 0x13, 0x71, 0x0B, 0x3A, 0x3B, 0xD2, 0x98, 0x02, 0x7E, 0x7B,
+                0x78, 0x3A, 0x69, 0x23,
+            ],
+            &[
+                0xE8, 0x3A, 0x90, 0x80, 0x5D, 0x03, 0x42, 0x92, 0xE2, 0xC6, 0x42, 0x0D, 0x32, 0x3A,
+                0xD0, 0x24, 0xE6, 0x16, 0x75, 0x1D, 0x92, 0xF6, 0x5E, 0x41, 0x73, 0x47, 0xB5, 0x2D,
+                0x85, 0xE3, 0xAE, 0x48,
+            ],
+            &[
+                0x2F, 0x5A, 0xC8, 0x16, 0x50, 0xE2, 0xDE, 0xBD, 0x43, 0x29, 0xD9, 0x02, 0x6B, 0x58,
+                0x8B, 0xEF, 0x8E, 0xEB, 0x7B, 0x48, 0x7B, 0xEF, 0x23, 0x56, 0xEB, 0x79, 0xBC, 0x72,
+                0x87, 0xA5, 0xDA, 0x0E,
+            ],
+            &[
+                0x33, 0x0A, 0xCA, 0xBB, 0xEF, 0x25, 0xC8, 0xB4, 0x7A, 0x40, 0x9C, 0x40, 0x86, 0x30,
+                0xC1, 0x1D, 0x1E, 0x76, 0x9C, 0x6A, 0x13, 0x25, 0x5D, 0xA1, 0xFC, 0x58, 0x8A, 0x0C,
+                0xC0, 0x01, 0x38, 0x31,
+            ],
+        ],
+        [
+            &[
+                0x6F, 0x18, 0x34, 0xDC, 0x21, 0x1E, 0x14, 0x72, 0x42, 0xA3, 0xCB, 0xF8, 0xF5, 0x6D,
+                0x5B, 0x9B, 0xF8, 0x81, 0x9A, 0x5D, 0xE2, 0x53, 0xC4, 0x46, 0xAC, 0xE9, 0x02, 0x69,
+                0xCF, 0xDA, 0x08, 0x02,
+            ],
+            &[
+                0x9A, 0xE5, 0x83, 0x9A, 0x77, 0xE8, 0x5B, 0x90, 0x69, 0xB8, 0x93, 0xAA, 0xCE, 0xC5,
+                0x0E, 0x70, 0x1A, 0xDB, 0xB8, 0xE8, 0xC3, 0x7C, 0xD2, 0x71, 0xE8, 0x3B, 0xFD, 0xD0,
+                0xBA, 0x1D, 0xEE, 0x3D,
+            ],
+            &[
+                0x2B, 0xE7, 0xBC, 0xBA, 0xC3, 0xD7, 0x2F, 0x4B, 0xB6, 0x2B, 0xBB, 0x24, 0x50, 0xDD,
+                0x51, 0x17, 0x78, 0x5A, 0xC5, 0x1F, 0x67, 0xE2, 0xE8, 0x3A, 0x80, 0xA5, 0xEC, 0xB8,
+                0x75, 0x33, 0x02, 0x6D,
+            ],
+            &[
+                0xE5, 0x47, 0xC9, 0x1B, 0xEB, 0x62, 0xF3, 0xE4, 0x3C, 0x64, 0x26, 0xB4, 0x78, 0xDD,
+                0x75, 0x62, 0x92, 0xB7, 0x1F, 0x2A, 0x69, 0x3C, 0x5A, 0xE1, 0xB2, 0xCB, 0x3C, 0xF2,
+                0xE5, 0x0D, 0xC3, 0x14,
+            ],
+        ],
+        [
+            &[
+                0x9A, 0x64, 0xEE, 0x0D, 0x56, 0xE0, 0x7A, 0x96, 0x02, 0x57, 0x02, 0x60, 0x77, 0x30,
+                0xEF, 0x95, 0xFF, 0xF9, 0xA3, 0x65, 0x25, 0x61, 0x70, 0x9E, 0x99, 0x8C, 0xEE, 0xE6,
+                0xEC, 0x0C, 0x24, 0x0B,
+            ],
+            &[
+                0xDF, 0x91, 0x71, 0xE9, 0xB3, 0x36, 0xD3, 0xA2, 0x13, 0xAB, 0xA5, 0xAB, 0x70, 0xF7,
+                0xB8, 0x2F, 0x7B, 0x9D, 0xA6, 0xCB, 0xF6, 0xE3, 0xA6, 0x36, 0x8C, 0xB0, 0x25, 0x47,
+                0x1B, 0xD1, 0xE3, 0x4E,
+            ],
+            &[
+                0xAA, 0x57, 0x28, 0xEE, 0xFA, 0x49, 0xF9, 0xB1, 0x5C, 0xF7, 0xBA, 0x69, 0xDA, 0x01,
+                0x39, 0x46, 0x8E, 0x21, 0xE0, 0x14, 0xAB, 0x2F, 0x53, 0x24, 0x73, 0x4C, 0xC4, 0x63,
+                0xB6, 0x82, 0xA9, 0x52,
+            ],
+            &[
+                0x31, 0xDB, 0x3B, 0x44, 0x50, 0xE0, 0xC9, 0x93, 0xA8, 0x72, 0xF3, 0x90, 0xF4, 0x48,
+                0x4B, 0x4E, 0x0E, 0x40, 0x19, 0x71, 0x5E, 0xC7, 0xB6, 0xC3, 0xD1, 0xFA, 0x41, 0xAA,
+                0x9E, 0x22, 0x4F, 0x24,
+            ],
+        ],
+        [
+            &[
+                0xD8, 0xAB, 0xF9, 0xBD, 0xD3, 0xC1, 0x2A, 0x25, 0x8B, 0x11, 0x26, 0x4E, 0x01, 0x09,
+                0xED, 0x09, 0x7E, 0x9B, 0x2A, 0xEE, 0xD7, 0x31, 0x63, 0xDB, 0x26, 0xF5, 0xD8, 0xCA,
+                0xAF, 0x70, 0x08, 0x63,
+            ],
+            &[
+                0xCF, 0x62, 0xE8, 0x7C, 0x55, 0x80, 0x9D, 0x4D, 0x2F, 0xAC, 0x6B, 0x77, 0xA1, 0x54,
+                0x52, 0x40, 0xAF, 0x46, 0x75, 0x37, 0x71, 0xE2, 0x6E, 0x4B, 0x9B, 0x08, 0xE5, 0x5B,
+                0x4B, 0x68, 0x57, 0x22,
+            ],
+            &[
+                0xE3, 0x2E, 0xD0, 0xDB, 0x4B, 0x1D, 0xC3, 0x2D, 0x0E, 0x18, 0x94, 0x50, 0xF2, 0x6E,
+                0x2F, 0x45, 0x6B, 0x14, 0x90, 0x90, 0xDF, 0x28, 0x40, 0x28, 0xE0, 0x18, 0xE5, 0xFB,
+                0x32, 0xE2, 0x20, 0x4C,
+            ],
+            &[
+                0xF6, 0xE2, 0x36, 0xB9, 0x5E, 0x37, 0x3B, 0xCA, 0x03, 0x45, 0xDE, 0x99, 0x86, 0x91,
+                0x30, 0x8C, 0x3F, 0x11, 0x59, 0x33, 0x6A, 0x2D, 0xFA, 0x6B, 0x1C, 0xB6, 0x2E, 0x91,
+                0x8A, 0x5E, 0xCC, 0x0F,
+            ],
+        ],
+        [
+            &[
+                0x2A, 0x7A, 0x5A, 0xAC, 0x79, 0xF8, 0x10, 0x9A, 0xCA, 0xB8, 0x5D, 0xB8, 0x8C, 0xA1,
+                0x75, 0x37, 0xE3, 0xBE, 0xE7, 0x5C, 0x57, 0x78, 0x2B, 0xC0, 0x09, 0x32, 0xFC, 0xBD,
+                0x8C, 0xA9, 0x20, 0x4D,
+            ],
+            &[
+                0xDC, 0xEC, 0xB0, 0xD8, 0x21, 0x76, 0x7C, 0x5E, 0xDD, 0x12, 0xA5, 0xC0, 0x37, 0x25,
+                0x6F, 0x82, 0x17, 0xE7, 0x07, 0x79, 0x45, 0xAB, 0x9A, 0xC1, 0x16, 0xB9, 0xE3, 0x33,
+                0x59, 0xBF, 0x32, 0x2C,
+            ],
+            &[
+                0x0F, 0x15, 0x0F, 0x97, 0x93, 0xE5, 0x5F, 0xE6, 0xBC, 0x05, 0xF2, 0xB1, 0xB9, 0x56,
+                0xF8, 0x1B, 0x48, 0xC3, 0x89, 0x0E, 0x5A, 0xC6, 0x9B, 0x6A, 0x3B, 0x06, 0x9A, 0x9E,
+                0xB8, 0xD6, 0xAB, 0x50,
+            ],
+            &[
+                0x22, 0x78, 0x88, 0x7A, 0x57, 0xAF, 0x2F, 0xC9, 0x6B, 0x49, 0x49, 0xEA, 0x73, 0x6F,
+                0x2F, 0xA2, 0xCF, 0x6B, 0x3D, 0xED, 0x9E, 0x59, 0x7F, 0xA1, 0xEB, 0xDC, 0xCD, 0x36,
+                0x8C, 0x48, 0xDA, 0x0C,
+            ],
+        ],
+        [
+            &[
+                0x25, 0xF9, 0xCC, 0x7D, 0xA2, 0xB9, 0x48, 0xB2, 0xD7, 0x15, 0x07, 0x81, 0x85, 0x03,
+                0xEC, 0x23, 0xEB, 0x69, 0xD0, 0x1E, 0x0C, 0xA8, 0x66, 0x62, 0x37, 0x76, 0xDD, 0xFD,
+                0xE8, 0xD3, 0x8A, 0x5A,
+            ],
+            &[
+                0x80, 0xD1, 0x8C, 0xB0, 0x99, 0xA8, 0x1C, 0xAD, 0xD6, 0x54, 0x4B, 0xE3, 0xCB, 0x58,
+                0x5C, 0x4F, 0x87, 0xDA, 0xA8, 0x6D, 0xE4, 0x92, 0xC0, 0x33, 0x0E, 0x13, 0xF8, 0x6F,
+                0xDC, 0x1F, 0x7E, 0x17,
+            ],
+            &[
+                0x82, 0xEA, 0xD6, 0x1D, 0x50, 0xCF, 0x8D, 0x5E, 0x41, 0x50, 0xFD, 0x03, 0x9C, 0x0B,
+                0xE9, 0xD7, 0xF6, 0x15, 0x5B, 0xB4, 0x95, 0xF0, 0x4B, 0x9C, 0x85, 0x72, 0x87, 0x52,
+                0x68, 0x08, 0x9A, 0x17,
+            ],
+            &[
+                0xF5, 0xB5, 0x71, 0x46, 0x34, 0xF6, 0xF7, 0x46, 0x0D, 0xB9, 0xAD, 0x6B, 0x14, 0x09,
+                0xCE, 0x0D, 0x02, 0xCC, 0xE5, 0xE1, 0x81, 0x3B, 0x21, 0x6E, 0x2D, 0xF2, 0x63, 0x40,
+                0xFD, 0xF9, 0xC0, 0x22,
+            ],
+        ],
+        [
+            &[
+                0x4B, 0xC8, 0x01, 0x89, 0xAD, 0xF7, 0xD1, 0x9C, 0x75, 0x7F, 0x18, 0x71, 0xA9, 0xE9,
+                0xC4, 0x0B, 0xBB, 0xB5, 0x5B, 0x95, 0xD2, 0x8D, 0x08, 0x02, 0xA5, 0x8A, 0x3C, 0x6E,
+                0x58, 0xBB, 0x7C, 0x1C,
+            ],
+            &[
+                0xDB, 0x1E, 0xAD, 0xEC, 0x50, 0x1E, 0x54, 0x6F, 0xC4, 0x7C, 0xD5, 0x8E, 0x0C, 0x32,
+                0x07, 0x75, 0x10, 0x66, 0x3F, 0x8E, 0x4A, 0xEE, 0x7E, 0x79, 0xF6, 0x3C, 0x1F, 0x9E,
+                0x56, 0x2A, 0x4A, 0x46,
+            ],
+            &[
+                0x48, 0xE9, 0x86, 0x2E, 0xE6, 0xDD, 0xBB, 0xD3, 0x32, 0x86, 0x87, 0xB2, 0x0E, 0x74,
+                0x3F, 0x64, 0xAC, 0xCF, 0x49, 0xEE, 0x51, 0x08, 0xB6, 0x52, 0x78, 0xDE, 0x8C, 0x9D,
+                0x54, 0x6E, 0xFD, 0x53,
+            ],
+            &[
+                0xBD, 0x9A, 0x72, 0x8A, 0xBF, 0x46, 0x9D, 0x87, 0xAD, 0x8C, 0x4A, 0x2B, 0x50, 0xE6,
+                0x03, 0x3D, 0x87, 0x14, 0x0F, 0xF2, 0x8A, 0x07, 0xA0, 0x9C, 0x9A, 0x0E, 0xC1, 0x63,
+                0xC5, 0x4A, 0x45, 0x19,
+            ],
+        ],
+        [
+            &[
+                0x83, 0x98, 0xB2, 0x98, 0xED, 0x6B, 0x20, 0x74, 0x0C, 0xCA, 0xF1, 0x28, 0xB8, 0xA4,
+                0xDE, 0xCB, 0xBA, 0x68, 0x55, 0x3E, 0x6F, 0xA3, 0x85, 0xB9, 0x6D, 0xDA, 0xF1, 0x30,
+                0x1A, 0xB1, 0x18, 0x46,
+            ],
+            &[
+                0x9B, 0x01, 0x8D, 0x4E, 0xDC, 0x4F, 0xB2, 0x70, 0x0E, 0x53, 0x64, 0x56, 0x18, 0xF3,
+                0x55, 0x66, 0xD5, 0x77, 0xC1, 0xF6, 0xDC, 0xAA, 0x42, 0xBD, 0xF2, 0x65, 0x51, 0x15,
+                0x52, 0x11, 0xB1, 0x2A,
+            ],
+            &[
+                0x11, 0x5F, 0xB9, 0x3F, 0xA6, 0xEE, 0x0F, 0x2F, 0xA1, 0x4F, 0x9C, 0x00, 0xFF, 0xBC,
+                0x13, 0x5B, 0xE7, 0x0A, 0x5B, 0x89, 0x23, 0x93, 0x6B, 0x5D, 0x9F, 0x3A, 0xC5, 0x71,
+                0xBE, 0xFD, 0x22, 0x37,
+            ],
+            &[
+                0x19, 0xEE, 0x20, 0x0A, 0xFD, 0x46, 0x01, 0x15, 0x06, 0x9E, 0x5E, 0x77, 0x7E, 0x0F,
+                0xB3, 0x35, 0x98, 0x9D, 0x03, 0xB2, 0x2D, 0x8F, 0xB7, 0x83, 0x29, 0xEB, 0x15, 0x96,
+                0xC7, 0x2D, 0x66, 0x4E,
+            ],
+        ],
+        [
+            &[
+                0x22, 0xB8, 0x13, 0x4E, 0x9C, 0xBC, 0x3B, 0xD0, 0x61, 0x45, 0x55, 0x08, 0x35, 0x6C,
+                0xDB, 0xC8, 0xA4, 0xA9, 0xAD, 0x09, 0xB9, 0x45, 0xE6, 0x50, 0xEC, 0xFA, 0x58, 0x0D,
+                0x16, 0xE0, 0xCD, 0x5B,
+            ],
+            &[
+                0x7D, 0x0A, 0x24, 0xDC, 0x25, 0x20, 0x70, 0x36, 0x2E, 0xF0, 0xFA, 0xD6, 0x03, 0x9A,
+                0x88, 0x7A, 0x76, 0xE3, 0xD8, 0x76, 0xAC, 0x4C, 0x26, 0x35, 0x4E, 0x9B, 0x9E, 0x61,
+                0x48, 0xC8, 0xC9, 0x73,
+            ],
+            &[
+                0x78, 0xDC, 0x18, 0x6D, 0x55, 0x34, 0xA6, 0x33, 0x38, 0xBE, 0x79, 0x1B, 0xC2, 0x4B,
+                0xF3, 0xB0, 0xE5, 0x62, 0xDD, 0xCB, 0xC1, 0x29, 0x5F, 0xA8, 0x3C, 0xA8, 0xFD, 0x19,
+                0x31, 0x7D, 0xF1, 0x69,
+            ],
+            &[
+                0xC6, 0x2E, 0x1E, 0xEB, 0xE7, 0xCF, 0xF3, 0x9E, 0x78, 0x91, 0x8D, 0xE8, 0xDC, 0x38,
+                0x6A, 0x51, 0x30, 0x11, 0x63, 0x13, 0x8E, 0x4D, 0x3E, 0xDE, 0x67, 0xB9, 0x44, 0xB8,
+                0xF4, 0x67, 0xC7, 0x04,
+            ],
+        ],
+        [
+            &[
+                0x6D, 0xBF, 0xFF, 0x31, 0x7C, 0xD0, 0xB1, 0xE6, 0x6D, 0x9C, 0xF0, 0xC7, 0xC8, 0x24,
+                0xCB, 0xAC, 0xE3, 0xFC, 0x33, 0x21, 0x38, 0xE4, 0x3F, 0xA7, 0xF8, 0x24, 0xAA, 0x48,
+                0x04, 0xCF, 0x9C, 0x66,
+            ],
+            &[
+                0x5E, 0xB0, 0x21, 0x7E, 0x3C, 0xB0, 0xA5, 0xE5, 0xEF, 0x12, 0xA5, 0xFB, 0x7E, 0xCB,
+                0x44, 0xE4, 0x12, 0x70, 0xF3, 0x80, 0x5C, 0xCD, 0x77, 0xED, 0x4F, 0x32, 0x76, 0xEE,
+                0xCB, 0x1F, 0x05, 0x6F,
+            ],
+            &[
+                0x82, 0x2B, 0xCF, 0x65, 0xB0, 0x01, 0x66, 0x8C, 0x08, 0x92, 0x19, 0x8B, 0x7E, 0x96,
+                0x55, 0x09, 0xE8, 0x9E, 0xF0, 0x82, 0x4E, 0xA9, 0xFF, 0x63, 0xDE, 0x1E, 0x3F, 0x1F,
+                0x67, 0x2C, 0xB3, 0x60,
+            ],
+            &[
+                0x33, 0x49, 0x46, 0x19, 0x7E, 0x19, 0x55, 0x9C, 0x29, 0x66, 0x25, 0xEA, 0x1C, 0xA5,
+                0x3C, 0x27, 0x09, 0x80, 0x79, 0x0E, 0x4B, 0x26, 0x5B, 0xD9, 0xBD, 0xB6, 0xF3, 0xC8,
+                0x42, 0xC5, 0x43, 0x5F,
+            ],
+        ],
+        [
+            &[
+                0x96, 0x3C, 0x62, 0xCB, 0x1F, 0xE1, 0x86, 0x9D, 0xD3, 0xC9, 0x7D, 0xEB, 0x0C, 0x87,
+                0xE1, 0xAB, 0x75, 0xDA, 0x5D, 0xD1, 0x20, 0x48, 0xE1, 0x14, 0x29, 0x32, 0xF4, 0x54,
+                0x07, 0xC4, 0x09, 0x4A,
+            ],
+            &[
+                0xE9, 0x16, 0x83, 0x4F, 0xAA, 0xB9, 0x24, 0x72, 0xFA, 0x2F, 0xBB, 0x29, 0xE5, 0xBF,
+                0xB6, 0x55, 0x5C, 0x5B, 0xDD, 0x67, 0x69, 0xA2, 0x90, 0xB7, 0x82, 0xE3, 0xC2, 0x42,
+                0x94, 0x21, 0xA0, 0x15,
+            ],
+            &[
+                0x99, 0xC6, 0xC7, 0x46, 0x46, 0xC9, 0xD4, 0xEA, 0xC8, 0xE1, 0xFF, 0xCE, 0xEF, 0xF8,
+                0x72, 0x4D, 0xB5, 0xF3, 0x45, 0xB4, 0xFA, 0x13, 0x82, 0x63, 0xAA, 0xB5, 0x5E, 0xB9,
+                0x8D, 0xA0, 0x69, 0x31,
+            ],
+            &[
+                0x55, 0x1A, 0x02, 0x64, 0x1D, 0x97, 0x49, 0xDA, 0x23, 0xB3, 0x0F, 0x2F, 0xD3, 0x35,
+                0xFC, 0xAB, 0x10, 0xE5, 0xC2, 0x34, 0x32, 0xFE, 0x90, 0x4D, 0xE2, 0x3F, 0x58, 0x2A,
+                0xA3, 0x28, 0x48, 0x0D,
+            ],
+        ],
+        [
+            &[
+                0x57, 0x2B, 0x81, 0xD0, 0xBE, 0xBE, 0xBF, 0xF6, 0xF4, 0x88, 0x42, 0xA7, 0xB9, 0x46,
+                0xC2, 0xF6, 0x23, 0x94, 0x37, 0xE5, 0xB3, 0xFA, 0xD8, 0x94, 0x22, 0xAA, 0xFE, 0xD5,
+                0x32, 0xD5, 0x0E, 0x61,
+            ],
+            &[
+                0x68, 0xC3, 0x94, 0x03, 0x2A, 0xCC, 0xC9, 0xBF, 0x7D, 0xC7, 0x53, 0xEF, 0xFA, 0xCF,
+                0x70, 0x2D, 0x4B, 0x14, 0x86, 0xCB, 0x4E, 0xE3, 0xA2, 0xA2, 0xBD, 0x67, 0x5C, 0x98,
+                0x88, 0x04, 0x21, 0x31,
+            ],
+            &[
+                0x8A, 0xE5, 0xCD, 0x1B, 0xAC, 0x83, 0x7E, 0x44, 0x58, 0x84, 0xAF, 0x20, 0xC0, 0x7D,
+                0x28, 0xF8, 0x16, 0xEA, 0xFC, 0x8F, 0x5E, 0x98, 0x6B, 0xAC, 0x93, 0x04, 0x86, 0x4A,
+                0xDE, 0xF0, 0x66, 0x2E,
+            ],
+            &[
+                0x2E, 0x17, 0xD5, 0xEC, 0x99, 0xDA, 0x9C, 0xA2, 0xD7, 0x11, 0x0A, 0x04, 0xE2, 0x06,
+                0x68, 0xE8, 0x3D, 0x66, 0x38, 0x5E, 0xD3, 0x8C, 0x19, 0xA1, 0xA7, 0x41, 0xE6, 0x71,
+                0x60, 0xBC, 0x18, 0x39,
+            ],
+        ],
+        [
+            &[
+                0xF5, 0xD0, 0x4A, 0x26, 0xDE, 0xA3, 0xDE, 0xA5, 0x38, 0xC0, 0x91, 0x95, 0x55, 0xB0,
+                0x31, 0xAE, 0x76, 0x80, 0x12, 0x21, 0x38, 0x26, 0x48, 0x8D, 0x8D, 0x04, 0x89, 0x63,
+                0xC3, 0x4C, 0x9E, 0x31,
+            ],
+            &[
+                0x9D, 0xA8, 0x59, 0x48, 0x1A, 0xE5, 0x72, 0x5B, 0xF3, 0xFD, 0xC6, 0x86, 0xCD, 0x8D,
+                0xF4, 0x70, 0xFB, 0x72, 0xC0, 0x7C, 0x55, 0x04, 0x3C, 0xF4, 0x0C, 0x84, 0x0D, 0xD7,
+                0x1B, 0x14, 0xB8, 0x62,
+            ],
+            &[
+                0x3A, 0xEF, 0x08, 0x59, 0xD0, 0x81, 0x88, 0x6A, 0x21, 0x31, 0x9C, 0xED, 0xF3, 0x43,
+                0x74, 0x80, 0xA5, 0x7C, 0x04, 0x7C, 0x35, 0x50, 0x99, 0xE1, 0xEF, 0xAC, 0xA4, 0xEA,
+                0x51, 0x66, 0x06, 0x1E,
+            ],
+            &[
+                0x01, 0x6D, 0x41, 0x24, 0x47, 0x78, 0xDE, 0x2A, 0xA3, 0x5F, 0x03, 0xE3, 0x7E, 0x20,
+                0x78, 0xB8, 0x94, 0x70, 0x13, 0xFD, 0xA0, 0x87, 0x4C, 0x23, 0x3F, 0xB5, 0x54, 0xA2,
+                0xC5, 0x87, 0x70, 0x37,
+            ],
+        ],
+        [
+            &[
+                0xDB, 0x17, 0x47, 0x39, 0x68, 0x4E, 0xBA, 0xFA, 0x45, 0xFF, 0x8D, 0xF5, 0x51, 0xD2,
+                0x0B, 0xE6, 0xF4, 0xE9, 0xF8, 0x19, 0xE9, 0x57, 0x94, 0x45, 0x0C, 0xF6, 0x0E, 0xA6,
+                0x26, 0xAB, 0x10, 0x5B,
+            ],
+            &[
+                0x21, 0xC8, 0x00, 0xBF, 0x60, 0x3A, 0xF2, 0x90, 0x99, 0xC7, 0x85, 0xFD, 0x86, 0x28,
+                0xC0, 0x2F, 0xD7, 0x16, 0x09, 0xAE, 0xDB, 0x45, 0xD9, 0x19, 0x3F, 0xDF, 0x2D, 0x3B,
+                0x41, 0x34, 0x28, 0x58,
+            ],
+            &[
+                0x83, 0x63, 0xE2, 0x9B, 0x0E, 0x81, 0x51, 0x36, 0x57, 0xA0, 0x2E, 0xCE, 0x31, 0x13,
+                0x96, 0xF8, 0x6E, 0x6B, 0x87, 0xF1, 0x3F, 0x72, 0x25, 0x8F, 0xB5, 0x73, 0x34, 0x99,
+                0xC1, 0xD5, 0x80, 0x39,
+            ],
+            &[
+                0x94, 0xB9, 0x7D, 0x28, 0x25, 0xBE, 0x3A, 0x05, 0xE4, 0xA1, 0xAB, 0x6A, 0x73, 0xF0,
+                0x99, 0xBD, 0x1D, 0x1E, 0x66, 0x2A, 0x64, 0xEF, 0xAC, 0xD9, 0x1E, 0x50, 0xE4, 0xB3,
+                0x3F, 0xB3, 0xF0, 0x5F,
+            ],
+        ],
+    ];
+}
@@ -0,0 +1,622 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+use crate::{rescue_prime::RescuePrimeParameter, STATE_SIZE};
+use ark_ed_on_bn254::Fq;
+
+// the constants in this file follow the reference implementation
+// https://github.com/aszepieniec/rescue-prime/blob/master/rescue_prime.sage
+// i.e. get_number_of_rounds(), get_mds_matrix() and get_round_constants()
+// with security_level = 128, m = STATE_SIZE = 4, capacity = 1 and
+// q254 = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+
+impl RescuePrimeParameter for Fq {
+    // ceil(1.5 * max(5, l1)) where l1 is the number of rounds thwarting
+    // Groebner basis attacks
+    const PRIME_ROUNDS: usize = 11;
+
+    // The MDS matrix is the transpose of the right half of the echelon form of
+    // the 4 x 8 Vandermonde matrix [g^(i * j)], where g = 5 is the smallest
+    // primitive element of the field
+    // MDS[0,0]= "21888242871839275222246405745257275088548364400416034343698204186575808479992"
+    // MDS[0,1]= "19500"
+    // MDS[0,2]= "21888242871839275222246405745257275088548364400416034343698204186575808491587"
+    // MDS[0,3]= "156"
+    // MDS[1,0]= "21888242871839275222246405745257275088548364400416034343698204186575806058117"
+    // MDS[1,1]= "3026375"
+    // MDS[1,2]= "21888242871839275222246405745257275088548364400416034343698204186575807886437"
+    // MDS[1,3]= "20306"
+    // MDS[2,0]= "21888242871839275222246405745257275088548364400416034343698204186575491214367"
+    // MDS[2,1]= "393529500"
+    // MDS[2,2]= "21888242871839275222246405745257275088548364400416034343698204186575729688812"
+    // MDS[2,3]= "2558556"
+    // MDS[3,0]= "21888242871839275222246405745257275088548364400416034343698204186535831058117"
+    // MDS[3,1]= "49574560750"
+    // MDS[3,2]= "21888242871839275222246405745257275088548364400416034343698204186565891044437"
+    // MDS[3,3]= "320327931"
+    // MDS matrix in little-endian 32 bytes elements
+    const PRIME_MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE] = [
+        [
+            &[
+                0xF8, 0xC2, 0xFF, 0xEF, 0x93, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
+                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
+                0x72, 0x4E, 0x64, 0x30,
+            ],
+            &[
+                0x2C, 0x4C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x43, 0xF0, 0xFF, 0xEF, 0x93, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
+                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
+                0x72, 0x4E, 0x64, 0x30,
+            ],
+            &[
+                0x9C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+        [
+            &[
+                0x85, 0xCE, 0xDA, 0xEF, 0x93, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
+                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
+                0x72, 0x4E, 0x64, 0x30,
+            ],
+            &[
+                0xC7, 0x2D, 0x2E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x65, 0xB4, 0xF6, 0xEF, 0x93, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
+                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
+                0x72, 0x4E, 0x64, 0x30,
+            ],
+            &[
+                0x52, 0x4F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+        [
+            &[
+                0x1F, 0xAC, 0x16, 0xDD, 0x93, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
+                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
+                0x72, 0x4E, 0x64, 0x30,
+            ],
+            &[
+                0x9C, 0xC8, 0x74, 0x17, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0xEC, 0x80, 0x4D, 0xEB, 0x93, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
+                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
+                0x72, 0x4E, 0x64, 0x30,
+            ],
+            &[
+                0x5C, 0x0A, 0x27, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+        [
+            &[
+                0xC5, 0xB6, 0x28, 0xA1, 0x8A, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
+                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
+                0x72, 0x4E, 0x64, 0x30,
+            ],
+            &[
+                0xEE, 0xC3, 0xDF, 0x8A, 0x0B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x55, 0xB4, 0xDF, 0xA0, 0x91, 0xF5, 0xE1, 0x43, 0x91, 0x70, 0xB9, 0x79, 0x48, 0xE8,
+                0x33, 0x28, 0x5D, 0x58, 0x81, 0x81, 0xB6, 0x45, 0x50, 0xB8, 0x29, 0xA0, 0x31, 0xE1,
+                0x72, 0x4E, 0x64, 0x30,
+            ],
+            &[
+                0xFB, 0xD0, 0x17, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+    ];
+
+    // The 2 * m * PRIME_ROUNDS round constants are the consecutive
+    // little-endian integers of 33 bytes, reduced mod q254, of the output of
+    //   SHAKE256("Rescue-XLIX(q254,4,1,128)"), with q254 written in decimal,
+    // and are grouped by state-size vectors, two per round
+    const PRIME_ROUND_CONSTANTS_LE: &'static [[&'static [u8]; STATE_SIZE]] = &[
+        [
+            &[
+                0x52, 0xAD, 0xA4, 0x86, 0x22, 0x5A, 0xB0, 0x11, 0xB4, 0xA0, 0x28, 0xF0, 0x57, 0x65,
+                0x27, 0x0C, 0x8F, 0x19, 0x2D, 0x63, 0x31, 0x3E, 0x6C, 0xFF, 0x1E, 0xCD, 0xC9, 0xF8,
+                0x71, 0x6A, 0xD4, 0x2A,
+            ],
+            &[
+                0x0B, 0x57, 0xB7, 0x5F, 0x06, 0xC9, 0x45, 0x87, 0xD9, 0xB9, 0xEF, 0xD1, 0x5C, 0x65,
+                0x23, 0x74, 0x3D, 0x3A, 0xB8, 0xF2, 0x3F, 0x93, 0xF8, 0xEB, 0x44, 0xDD, 0x3E, 0x38,
+                0x34, 0x66, 0x6B, 0x10,
+            ],
+            &[
+                0x75, 0xEC, 0xD1, 0xD8, 0xDE, 0xC7, 0x1B, 0x8F, 0xE8, 0x0A, 0x7F, 0x36, 0x9C, 0x39,
+                0x38, 0x5D, 0x16, 0x87, 0x47, 0x30, 0x40, 0x8E, 0xB7, 0xB2, 0x68, 0xA0, 0x0E, 0x81,
+                0x13, 0x36, 0xE0, 0x23,
+            ],
+            &[
+                0xED, 0xFF, 0xD3, 0x0D, 0x4B, 0x89, 0x2D, 0x75, 0x7B, 0x4C, 0x2F, 0xC5, 0x4B, 0x20,
+                0x55, 0xFE, 0x64, 0x54, 0x8D, 0x00, 0x0D, 0x0C, 0x56, 0xD2, 0x12, 0x5F, 0x8E, 0x41,
+                0x77, 0x50, 0xEE, 0x0B,
+            ],
+        ],
+        [
+            &[
+                0x54, 0x75, 0xE6, 0x7D, 0x9E, 0x67, 0x93, 0x9D, 0x62, 0x2E, 0xC6, 0xDF, 0xCD, 0xCC,
+                0x2A, 0xBC, 0x98, 0x1F, 0x1E, 0xD1, 0x5C, 0xD8, 0x8D, 0x1E, 0xBA, 0x20, 0x4A, 0x2F,
+                0xD0, 0x69, 0xB5, 0x1A,
+            ],
+            &[
+                0x2F, 0x70, 0x77, 0x4A, 0x62, 0xAD, 0x9D, 0xB3, 0xA2, 0xE7, 0xC7, 0x65, 0x9C, 0xDD,
+                0xA7, 0xDF, 0x3E, 0x22, 0x97, 0xC4, 0x03, 0x1E, 0x31, 0x77, 0xD9, 0xE6, 0x1D, 0x33,
+                0x38, 0x0D, 0xFC, 0x0D,
+            ],
+            &[
+                0xE8, 0x7C, 0xC5, 0x85, 0x47, 0xA7, 0xAF, 0xC4, 0xFE, 0x34, 0x4D, 0x11, 0x11, 0xC9,
+                0x9A, 0x7D, 0xDD, 0xE5, 0xD0, 0x31, 0x65, 0x79, 0x61, 0x40, 0xA4, 0x1A, 0x4F, 0x64,
+                0x06, 0x68, 0x9C, 0x06,
+            ],
+            &[
+                0x50, 0x71, 0x04, 0x4C, 0x17, 0x08, 0x4C, 0x1D, 0x93, 0x36, 0x4D, 0xF8, 0x7B, 0x71,
+                0x79, 0xFE, 0xC1, 0xC8, 0x7F, 0xD1, 0x0B, 0x3C, 0x79, 0x1E, 0xDC, 0x85, 0x00, 0x8D,
+                0x30, 0x0E, 0x67, 0x10,
+            ],
+        ],
+        [
+            &[
+                0xB6, 0x68, 0xD7, 0x2E, 0xB4, 0x4C, 0x36, 0x2D, 0x89, 0x24, 0xED, 0xE8, 0x18, 0x4E,
+                0x02, 0x26, 0xCB, 0x40, 0x99, 0xE8, 0x12, 0x2D, 0xE4, 0x36, 0x4E, 0x39, 0xBF, 0xA1,
+                0x27, 0xAF, 0xC2, 0x01,
+            ],
+            &[
+                0x64, 0xE7, 0xB1, 0x3A, 0x2F, 0x07, 0xB5, 0xC8, 0x70, 0xC1, 0xB5, 0x3D, 0x1E, 0x7E,
+                0x14, 0xC0, 0xFC, 0x97, 0x24, 0x10, 0x3F, 0xC1, 0x46, 0x7A, 0x7E, 0xDC, 0xCF, 0xF9,
+                0xE3, 0xF4, 0x3C, 0x00,
+            ],
+            &[
+                0xD9, 0x8B, 0xAF, 0x56, 0x1B, 0x85, 0x76, 0x4A, 0x49, 0x27, 0x46, 0xA0, 0x38, 0xF6,
+                0xE2, 0x4E, 0x28, 0x96, 0x21, 0xCD, 0xD2, 0x6A, 0x39, 0xBD, 0x42, 0x51, 0xE9, 0xD9,
+                0xAF, 0xFF, 0xCB, 0x2B,
+            ],
+            &[
+                0xC3, 0x3E, 0xD8, 0x92, 0xD5, 0xE1, 0x11, 0x9C, 0xEB, 0x9A, 0x40, 0x6E, 0x9A, 0x3B,
+                0x63, 0xB3, 0xD0, 0xD7, 0xBF, 0xDE, 0x9C, 0x60, 0xF3, 0x03, 0x1F, 0x7F, 0xD0, 0x35,
+                0x6B, 0x45, 0xA6, 0x2F,
+            ],
+        ],
+        [
+            &[
+                0x96, 0x4B, 0x62, 0x17, 0xD1, 0x63, 0xA5, 0x95, 0xB7, 0xCA, 0x9B, 0xC8, 0x03, 0x6B,
+                0xA2, 0xE3, 0x7B, 0x4E, 0xF1, 0x86, 0x19, 0x42, 0x35, 0x61, 0x75, 0x5A, 0x8C, 0x23,
+                0xF2, 0x86, 0xC3, 0x0F,
+            ],
+            &[
+                0x18, 0xB2, 0x09, 0xC9, 0xDC, 0xEC, 0x03, 0x78, 0x9E, 0xDC, 0x3B, 0x61, 0xD8, 0xAF,
+                0xEB, 0xA4, 0xB8, 0xE3, 0x82, 0xB8, 0x6B, 0x4F, 0x5F, 0xC0, 0xC2, 0xCF, 0xAD, 0x5E,
+                0x73, 0x0D, 0xE1, 0x29,
+            ],
+            &[
+                0xE7, 0x30, 0x3B, 0x93, 0x59, 0xC3, 0x3E, 0x20, 0xC6, 0xB4, 0xBC, 0xB6, 0xD7, 0x1F,
+                0x34, 0xCC, 0x7C, 0x7C, 0x0C, 0x8A, 0xCA, 0xB2, 0x1A, 0x74, 0xE5, 0xA6, 0xB1, 0x5F,
+                0xED, 0x42, 0x72, 0x10,
+            ],
+            &[
+                0xB5, 0x25, 0x11, 0x42, 0x46, 0xF0, 0xA3, 0x3A, 0x58, 0x8C, 0xED, 0x45, 0x88, 0x70,
+                0x03, 0x18, 0x04, 0xD5, 0x48, 0x83, 0xAF, 0x88, 0xEB, 0xD8, 0xA8, 0xD3, 0xC9, 0x1F,
+                0xD8, 0x08, 0x01, 0x10,
+            ],
+        ],
+        [
+            &[
+                0xC1, 0xC5, 0x18, 0xB2, 0x3E, 0xEF, 0x68, 0x95, 0xBE, 0x1B, 0x84, 0x2C, 0x5A, 0x38,
+                0x05, 0xBB, 0x57, 0x94, 0x3C, 0xBC, 0xF0, 0xA9, 0x12, 0xA9, 0xFD, 0x09, 0x82, 0x04,
+                0xEE, 0x29, 0xF7, 0x04,
+            ],
+            &[
+                0x00, 0xC8, 0xDD, 0x4F, 0x2B, 0x89, 0xF0, 0x85, 0xD2, 0xEB, 0x67, 0x80, 0xB2, 0x93,
+                0x59, 0x27, 0x0C, 0xEE, 0x19, 0x57, 0xBB, 0xFE, 0xB7, 0xA4, 0x42, 0x79, 0x84, 0x5D,
+                0x1D, 0xF9, 0x1A, 0x19,
+            ],
+            &[
+                0x32, 0x1E, 0x09, 0x9F, 0xF6, 0xAB, 0xFD, 0xC5, 0xFC, 0xC4, 0x06, 0x7E, 0x88, 0x97,
+                0xF0, 0x3D, 0xEE, 0xC4, 0x3A, 0xED, 0x94, 0x93, 0x33, 0x40, 0x95, 0xA5, 0x47, 0xB5,
+                0x79, 0x06, 0x75, 0x2A,
+            ],
+            &[
+                0x99, 0xF9, 0xD5, 0xA4, 0x13, 0x45, 0x8F, 0xC5, 0x14, 0x36, 0xE2, 0xCD, 0x31, 0xF2,
+                0xF9, 0xA0, 0x49, 0x11, 0x51, 0x57, 0xB8, 0x23, 0xA7, 0x73, 0x76, 0xE7, 0x39, 0x78,
+                0x13, 0xCA, 0xAE, 0x09,
+            ],
+        ],
+        [
+            &[
+                0xA6, 0xE2, 0x0F, 0x4C, 0xA7, 0x1B, 0xCC, 0xF8, 0x44, 0xA6, 0xC0, 0xA7, 0x91, 0x1D,
+                0x2D, 0xC5, 0x7E, 0xE3, 0xE1, 0xD4, 0x3D, 0xE3, 0x56, 0x35, 0x07, 0x2D, 0x20, 0xE0,
+                0x3B, 0x2E, 0x34, 0x17,
+            ],
+            &[
+                0xE9, 0x19, 0xAF, 0x02, 0x69, 0xA5, 0xD1, 0xA5, 0xC8, 0xAF, 0x79, 0xBA, 0xF7, 0xD2,
+                0x71, 0x1C, 0x37, 0x9A, 0x6A, 0x2E, 0xD7, 0x4C, 0x63, 0x2A, 0x32, 0x94, 0x2B, 0x88,
+                0x68, 0xB7, 0xAB, 0x1E,
+            ],
+            &[
+                0x51, 0x6C, 0x5F, 0x6E, 0xBE, 0xB5, 0x4D, 0x0F, 0xA5, 0x04, 0xFE, 0x37, 0x61, 0xA7,
+                0x15, 0xF4, 0x46, 0xB0, 0x64, 0x25, 0x2F, 0xA9, 0x0B, 0x03, 0x51, 0xC0, 0x73, 0x38,
+                0x0C, 0x9A, 0xD7, 0x2C,
+            ],
+            &[
+                0x6E, 0x32, 0x0D, 0x1E, 0x08, 0xEA, 0xD2, 0xA1, 0x99, 0xFB, 0x65, 0xFE, 0x58, 0x96,
+                0x7F, 0xEB, 0xAA, 0xE4, 0x40, 0x7E, 0xBE, 0x8D, 0xE9, 0x6D, 0x26, 0x73, 0xB9, 0x3F,
+                0x96, 0x96, 0x55, 0x1B,
+            ],
+        ],
+        [
+            &[
+                0x60, 0x6D, 0x95, 0x39, 0x22, 0x4C, 0x73, 0x2D, 0xE7, 0x66, 0xA1, 0x29, 0xCD, 0xED,
+                0xBF, 0xF8, 0x46, 0xF3, 0xD0, 0x89, 0x66, 0x46, 0xD7, 0x2A, 0x8C, 0x2E, 0x8D, 0xDF,
+                0xD4, 0x0F, 0x71, 0x1E,
+            ],
+            &[
+                0x2C, 0x18, 0x88, 0x27, 0x1C, 0x20, 0x8B, 0x6C, 0x42, 0x28, 0xC0, 0xC1, 0x51, 0x09,
+                0x78, 0x88, 0x95, 0xF3, 0x92, 0x48, 0xB6, 0xE6, 0x46, 0x77, 0xA0, 0x88, 0xF7, 0x30,
+                0x2F, 0x3F, 0xDD, 0x15,
+            ],
+            &[
+                0xFC, 0x27, 0xFE, 0x9E, 0xB4, 0xB3, 0x49, 0xB1, 0x3B, 0xC7, 0x11, 0x78, 0xDF, 0x47,
+                0xDD, 0xA6, 0x4E, 0x35, 0xA8, 0x06, 0x32, 0x39, 0x67, 0x29, 0xBC, 0x0A, 0x10, 0x3E,
+                0x23, 0xF0, 0xDC, 0x21,
+            ],
+            &[
+                0xBF, 0x2D, 0x3D, 0x2A, 0x52, 0xDD, 0x3C, 0x40, 0x3D, 0xEA, 0x86, 0xB9, 0x29, 0xD4,
+                0x71, 0x00, 0x70, 0xFC, 0x64, 0x3D, 0x46, 0xCB, 0xBB, 0xEB, 0x23, 0xDE, 0xF6, 0xCC,
+                0x31, 0x7F, 0x1C, 0x21,
+            ],
+        ],
+        [
+            &[
+                0x08, 0x32, 0x7A, 0x8E, 0x6F, 0x63, 0x0B, 0x5A, 0x61, 0x53, 0xBA, 0xA0, 0x2A, 0xB3,
+                0xC1, 0x58, 0x5B, 0xB2, 0x84, 0x3F, 0x30, 0xF7, 0x41, 0x90, 0x77, 0xE5, 0xA4, 0x9D,
+                0xE3, 0x56, 0x5A, 0x09,
+            ],
+            &[
+                0x0B, 0x5D, 0xE1, 0x49, 0x2F, 0xFD, 0x8A, 0xE3, 0xC3, 0x52, 0x41, 0x2E, 0x7C, 0xAC,
+                0x1E, 0x03, 0x23, 0xDF, 0x0D, 0x72, 0x8A, 0x85, 0x01, 0xEB, 0xFB, 0x35, 0x2F, 0x06,
+                0xFF, 0xD2, 0x0E, 0x13,
+            ],
+            &[
+                0xCB, 0x88, 0x4D, 0x83, 0x9C, 0xE5, 0x9A, 0x0C, 0x1C, 0xBB, 0xD5, 0x76, 0x39, 0x58,
+                0x6A, 0x1C, 0x56, 0x4F, 0x7E, 0xE2, 0xA8, 0x90, 0x7B, 0x6A, 0x17, 0xD1, 0x14, 0x72,
+                0xEC, 0xB9, 0x8C, 0x0A,
+            ],
+            &[
+                0x95, 0x99, 0x83, 0x63, 0x78, 0x0C, 0x27, 0xEF, 0x47, 0x85, 0x41, 0x42, 0xDA, 0xB0,
+                0x12, 0xD6, 0x09, 0x28, 0x52, 0x10, 0x14, 0x56, 0x90, 0x26, 0x80, 0xB9, 0xBE, 0xCA,
+                0xF7, 0xF2, 0x19, 0x2E,
+            ],
+        ],
+        [
+            &[
+                0xED, 0xC6, 0x86, 0x51, 0x81, 0xD0, 0xD1, 0xF9, 0xFE, 0x5F, 0xAD, 0xD4, 0x79, 0x92,
+                0xCE, 0x9B, 0xAC, 0x9B, 0x0E, 0x85, 0xBA, 0x03, 0xF5, 0xA3, 0x05, 0xFF, 0xE1, 0xA7,
+                0x78, 0x34, 0x11, 0x01,
+            ],
+            &[
+                0xE3, 0x47, 0x3B, 0xFB, 0xD6, 0xF6, 0x45, 0x10, 0xCB, 0xB3, 0x17, 0xB1, 0x18, 0x07,
+                0x0F, 0x42, 0xE5, 0xDE, 0x29, 0xB1, 0x5D, 0xF3, 0xA6, 0xAF, 0x07, 0x8F, 0x4C, 0xEB,
+                0x7A, 0xC4, 0x97, 0x0A,
+            ],
+            &[
+                0xC3, 0xA0, 0xA7, 0x4D, 0x7D, 0x98, 0x67, 0x9A, 0x45, 0x7E, 0x27, 0x89, 0xE6, 0x50,
+                0xAE, 0x89, 0x25, 0xE0, 0xF3, 0x0C, 0x47, 0xA8, 0xB9, 0x05, 0xA3, 0x6A, 0x48, 0x46,
+                0xFA, 0x47, 0x23, 0x10,
+            ],
+            &[
+                0x09, 0x54, 0x5C, 0xCC, 0x37, 0x3D, 0x5B, 0x4B, 0x71, 0x51, 0x73, 0x76, 0x5D, 0xA6,
+                0x47, 0x7D, 0xA9, 0xD2, 0xE5, 0xFD, 0x97, 0xD4, 0xBF, 0x4F, 0x12, 0xD4, 0x25, 0x0D,
+                0x4B, 0xD1, 0x08, 0x0E,
+            ],
+        ],
+        [
+            &[
+                0x25, 0x0F, 0xF5, 0xAD, 0xBA, 0x02, 0x2A, 0x0C, 0x03, 0x96, 0xC6, 0x51, 0xA0, 0x04,
+                0x18, 0xEC, 0x91, 0xA2, 0x2F, 0x7C, 0xD7, 0xF4, 0x54, 0x89, 0xC3, 0x78, 0xA4, 0x0D,
+                0x14, 0xB0, 0xF6, 0x22,
+            ],
+            &[
+                0x33, 0x71, 0xDB, 0xC8, 0x26, 0x48, 0x53, 0x1F, 0x4D, 0x8C, 0x7D, 0xB0, 0xEA, 0x77,
+                0x35, 0x96, 0xAF, 0x06, 0x18, 0x24, 0xDA, 0xA5, 0xA6, 0xC6, 0x83, 0x0B, 0x2B, 0xFC,
+                0xBE, 0x4D, 0x64, 0x26,
+            ],
+            &[
+                0x1C, 0xB8, 0x68, 0x6B, 0x67, 0x95, 0x1E, 0x7F, 0x53, 0x93, 0x47, 0xD3, 0x0D, 0x0D,
+                0x79, 0x3D, 0x37, 0x96, 0xC0, 0x70, 0x02, 0xF2, 0xD1, 0x2B, 0x6E, 0x30, 0xCC, 0x4C,
+                0x14, 0xF1, 0x1B, 0x11,
+            ],
+            &[
+                0xB9, 0x94, 0xC2, 0x46, 0x06, 0x26, 0x84, 0x96, 0xCA, 0x1F, 0xDA, 0xF5, 0x0E, 0xE8,
+                0x81, 0x59, 0x8A, 0xFE, 0xD9, 0x58, 0x76, 0x6F, 0xC8, 0x34, 0xD5, 0xF9, 0x94, 0x71,
+                0x03, 0xBC, 0x91, 0x2D,
+            ],
+        ],
+        [
+            &[
+                0x97, 0x7C, 0x40, 0x06, 0x1B, 0xBC, 0xEE, 0xA8, 0xCF, 0xA2, 0xDE, 0xED, 0x0D, 0x86,
+                0x2F, 0xD4, 0xB6, 0xF0, 0x34, 0x14, 0xAB, 0xDD, 0x8B, 0xC8, 0x02, 0x80, 0x1D, 0x3C,
+                0x32, 0xAA, 0x6D, 0x1F,
+            ],
+            &[
+                0x0F, 0xE0, 0xDB, 0xCA, 0x6C, 0xDE, 0x1F, 0xDE, 0x1E, 0x1E, 0xAE, 0x1D, 0x35, 0x46,
+                0x4C, 0x08, 0xEF, 0x55, 0x91, 0xF8, 0xA6, 0x3C, 0x16, 0xAB, 0x55, 0x0D, 0x40, 0x52,
+                0x51, 0x8F, 0xE1, 0x28,
+            ],
+            &[
+                0x03, 0x87, 0x03, 0x1A, 0x0A, 0xF1, 0xC2, 0x7C, 0x2E, 0xE0, 0x23, 0xEF, 0xFD, 0xAF,
+                0x4C, 0xF7, 0xF2, 0xC3, 0xF2, 0x3E, 0x2F, 0xC7, 0xE9, 0x12, 0x75, 0xD9, 0x18, 0x8E,
+                0xFC, 0x74, 0x75, 0x13,
+            ],
+            &[
+                0x3E, 0x66, 0x7F, 0x06, 0x13, 0xE1, 0xE2, 0x1F, 0x27, 0x9C, 0x5D, 0x98, 0x03, 0xBB,
+                0x95, 0x95, 0xD8, 0xD4, 0x50, 0xC5, 0xF8, 0x5B, 0x6B, 0xC8, 0xB1, 0x82, 0x07, 0x3C,
+                0x5A, 0xBF, 0x00, 0x2A,
+            ],
+        ],
+        [
+            &[
+                0x3A, 0xDB, 0x85, 0x6E, 0xC4, 0x56, 0xF8, 0x1F, 0xED, 0x97, 0xAD, 0x01, 0xE8, 0x90,
+                0xEB, 0xA8, 0xA5, 0x0E, 0xE6, 0x1B, 0x90, 0x5C, 0x4B, 0xD6, 0xD0, 0x70, 0xE7, 0x7A,
+                0x01, 0x71, 0x82, 0x02,
+            ],
+            &[
+                0x94, 0x7A, 0x89, 0x04, 0x94, 0x07, 0xE5, 0x67, 0xB0, 0x48, 0x06, 0x1F, 0x72, 0x6E,
+                0x60, 0xAA, 0x20, 0x34, 0xA4, 0x33, 0x75, 0x06, 0xFD, 0x25, 0x07, 0x6C, 0xA8, 0x4E,
+                0x90, 0x5B, 0x9F, 0x28,
+            ],
+            &[
+                0x4E, 0x2C, 0x77, 0xCE, 0xFB, 0x80, 0x02, 0xA5, 0x11, 0xAF, 0xCD, 0x86, 0x9A, 0x25,
+                0x66, 0x7E, 0xFC, 0xA2, 0x6D, 0x3E, 0x7C, 0xD6, 0xBF, 0xC5, 0x1A, 0x5C, 0xE1, 0xB0,
+                0xCD, 0xC2, 0xD1, 0x00,
+            ],
+            &[
+                0x56, 0xA8, 0x43, 0xC3, 0x7C, 0x1E, 0xAA, 0xCB, 0xC7, 0x66, 0x56, 0xA5, 0x22, 0xAA,
+                0x16, 0x03, 0xB4, 0xB9, 0xCD, 0x9F, 0x63, 0x92, 0x82, 0x0C, 0x60, 0x82, 0xA0, 0x60,
+                0x2C, 0x7E, 0x7F, 0x1C,
+            ],
+        ],
+        [
+            &[
+                0x6E, 0xEB, 0x5B, 0x3D, 0x9E, 0x89, 0x51, 0x51, 0x2D, 0x7A, 0x4C, 0xC5, 0xAA, 0xEF,
+                0xED, 0xBA, 0xE6, 0x82, 0x3B, 0x42, 0x1A, 0xFD, 0x13, 0x36, 0xA1, 0xCE, 0xEF, 0x0F,
+                0x0A, 0xDA, 0xF6, 0x02,
+            ],
+            &[
+                0xC4, 0x35, 0xD5, 0x0D, 0x58, 0xA4, 0xEC, 0x71, 0x52, 0xD7, 0xCA, 0x0D, 0x9B, 0x78,
+                0x31, 0x85, 0xA1, 0x2A, 0xEF, 0xAD, 0x0C, 0xA9, 0x90, 0x16, 0xF3, 0x9A, 0xE4, 0xBE,
+                0x71, 0x0E, 0x2E, 0x0C,
+            ],
+            &[
+                0xD7, 0xD0, 0x8D, 0xCE, 0xD4, 0x69, 0xAA, 0x5D, 0x0A, 0x0F, 0x4E, 0x18, 0xAA, 0x82,
+                0x09, 0xFB, 0x49, 0xD5, 0xD2, 0x52, 0x25, 0xE3, 0xC7, 0x03, 0x28, 0xB8, 0xB7, 0x50,
+                0x70, 0x56, 0x52, 0x20,
+            ],
+            &[
+                0x21, 0x9D, 0xEC, 0xB2, 0x71, 0x39, 0x3E, 0xE1, 0x6F, 0x32, 0xA6, 0x62, 0xF9, 0xAF,
+                0x2D, 0x56, 0x77, 0x59, 0xF8, 0xD0, 0x30, 0xF5, 0xB0, 0x86, 0xCB, 0x83, 0x75, 0xD2,
+                0x1F, 0x3B, 0xBA, 0x28,
+            ],
+        ],
+        [
+            &[
+                0xD1, 0x65, 0x02, 0x9F, 0x0D, 0x82, 0x46, 0x81, 0x8A, 0x06, 0xA4, 0x98, 0x8A, 0x1A,
+                0xFC, 0xBF, 0x17, 0x96, 0x18, 0x55, 0x76, 0xA9, 0x50, 0x48, 0x59, 0x06, 0xBB, 0x8E,
+                0x81, 0x4E, 0x51, 0x2E,
+            ],
+            &[
+                0xE9, 0x6E, 0x36, 0x5C, 0x95, 0x65, 0x8D, 0x58, 0x72, 0x93, 0xFF, 0xA3, 0x6A, 0xEE,
+                0xDC, 0xBB, 0x48, 0x6D, 0xE2, 0xD2, 0xE9, 0x43, 0xB3, 0xD9, 0xE8, 0x8D, 0x3D, 0x2D,
+                0xEB, 0x91, 0xD2, 0x28,
+            ],
+            &[
+                0xC5, 0x22, 0xCE, 0xA1, 0x5B, 0xF7, 0x34, 0xD5, 0xD1, 0x0B, 0x0A, 0x24, 0x51, 0x8A,
+                0x2E, 0xD9, 0x51, 0x62, 0xD7, 0x82, 0x82, 0x91, 0x6C, 0xA6, 0xB3, 0xBB, 0x93, 0x00,
+                0x09, 0xB8, 0xBC, 0x22,
+            ],
+            &[
+                0xDA, 0x6C, 0x57, 0x4C, 0x64, 0x45, 0xBD, 0x68, 0xED, 0xFB, 0xFC, 0xA5, 0x1C, 0x1B,
+                0x58, 0xE4, 0x26, 0x3A, 0xE5, 0x3F, 0x49, 0xE8, 0x40, 0x23, 0x33, 0xFE, 0xBB, 0x5B,
+                0x10, 0x38, 0x5F, 0x15,
+            ],
+        ],
+        [
+            &[
+                0x95, 0xE5, 0x4D, 0x9B, 0x4E, 0x6A, 0x8A, 0x5D, 0x9A, 0xD3, 0x52, 0xF3, 0x12, 0x2A,
+                0x0E, 0xFC, 0x48, 0x7F, 0x74, 0xE6, 0x21, 0x50, 0x6F, 0xDE, 0x2E, 0xD6, 0x3E, 0xEC,
+                0xFE, 0xDD, 0x48, 0x22,
+            ],
+            &[
+                0x14, 0xC9, 0xFA, 0x00, 0x68, 0xE6, 0x90, 0xD6, 0x33, 0x4D, 0xF4, 0x48, 0xF0, 0xB6,
+                0x47, 0x48, 0x86, 0x41, 0x55, 0x4C, 0x12, 0xE5, 0xC3, 0x56, 0x24, 0x52, 0xD4, 0x9B,
+                0xC8, 0x40, 0x67, 0x1B,
+            ],
+            &[
+                0xFB, 0xFA, 0x9D, 0xAD, 0xAC, 0x7F, 0x6C, 0x51, 0x69, 0x41, 0x34, 0x74, 0xF9, 0x6A,
+                0x10, 0x91, 0x37, 0x6C, 0x29, 0xFA, 0x2B, 0xB6, 0x0F, 0x62, 0xCE, 0x94, 0xE6, 0x7C,
+                0xD9, 0x49, 0xE7, 0x10,
+            ],
+            &[
+                0x5D, 0x32, 0x56, 0x01, 0xB7, 0xC6, 0xA8, 0xB7, 0xF3, 0xD7, 0x1E, 0xE6, 0x60, 0x02,
+                0x8C, 0x4A, 0x12, 0xD3, 0x7B, 0xFF, 0x9E, 0x1F, 0x69, 0x6D, 0x35, 0x1C, 0xA4, 0xDA,
+                0x46, 0xA2, 0x63, 0x10,
+            ],
+        ],
+        [
+            &[
+                0x93, 0x59, 0x31, 0xDF, 0xCD, 0x41, 0x23, 0xA4, 0x7B, 0x24, 0x94, 0x81, 0x79, 0x1B,
+                0x2D, 0xEB, 0x63, 0x8E, 0x41, 0xFE, 0x3A, 0x92, 0xA1, 0xF9, 0x28, 0x69, 0x42, 0x9F,
+                0x85, 0xD4, 0x37, 0x18,
+            ],
+            &[
+                0x44, 0xE8, 0x9C, 0xF7, 0x8A, 0xCD, 0x9B, 0x5C, 0x68, 0xF9, 0x22, 0x2A, 0xBE, 0xD1,
+                0xA0, 0x8E, 0x74, 0xC4, 0x03, 0x87, 0x3E, 0xB1, 0x62, 0x9F, 0xFC, 0x19, 0xA1, 0xAC,
+                0x54, 0x40, 0xBB, 0x13,
+            ],
+            &[
+                0xAF, 0x10, 0xC7, 0x74, 0x34, 0x41, 0x96, 0xB0, 0x55, 0x2B, 0xA8, 0x7D, 0x13, 0x9B,
+                0xB2, 0x65, 0x5D, 0xDD, 0xA5, 0x4D, 0x27, 0xC3, 0x48, 0xF4, 0xAB, 0x34, 0x00, 0x06,
+                0x1F, 0x0F, 0x9D, 0x18,
+            ],
+            &[
+                0x59, 0xE7, 0xF2, 0x0A, 0x82, 0x4F, 0x92, 0xFD, 0x66, 0x66, 0x27, 0x5B, 0xA3, 0x87,
+                0x2B, 0xFC, 0x60, 0xA0, 0xB9, 0x48, 0x78, 0x8A, 0x84, 0x69, 0xD3, 0x5B, 0xD4, 0xD4,
+                0xD1, 0x5A, 0x8A, 0x19,
+            ],
+        ],
+        [
+            &[
+                0x05, 0xF0, 0x79, 0x1B, 0x02, 0x03, 0x4F, 0x3D, 0xB1, 0xFA, 0xE1, 0xB8, 0x65, 0x15,
+                0x03, 0x00, 0xD4, 0x4B, 0xB6, 0xC8, 0x69, 0x9E, 0xB7, 0x8F, 0x43, 0xF5, 0x55, 0x99,
+                0x2E, 0xD9, 0xB4, 0x07,
+            ],
+            &[
+                0x25, 0x13, 0x2C, 0xD7, 0x62, 0xD3, 0x90, 0x8B, 0xAF, 0x9F, 0x48, 0x56, 0xF9, 0xAA,
+                0xDB, 0x36, 0xCE, 0xBC, 0xEC, 0x10, 0xEC, 0x38, 0xE7, 0x88, 0xC6, 0xA1, 0x6C, 0x9A,
+                0xBD, 0x21, 0x0B, 0x25,
+            ],
+            &[
+                0x2F, 0xFB, 0xA3, 0x73, 0x03, 0x9E, 0xA6, 0x6B, 0x5D, 0x8B, 0x84, 0x10, 0x93, 0x0A,
+                0x36, 0x7B, 0xDB, 0xC8, 0xF9, 0x0A, 0xCC, 0x49, 0x25, 0xAB, 0xA8, 0x5A, 0x44, 0x45,
+                0xC6, 0x45, 0x31, 0x02,
+            ],
+            &[
+                0x0A, 0x1E, 0x70, 0x9C, 0xF8, 0xD6, 0x8B, 0xA6, 0xD5, 0xAD, 0x7D, 0x2D, 0xD0, 0xC1,
+                0x17, 0x91, 0x72, 0xAB, 0x80, 0x8D, 0x55, 0xA7, 0x4F, 0xFD, 0x07, 0x48, 0xDD, 0x5C,
+                0x46, 0x9C, 0x4F, 0x17,
+            ],
+        ],
+        [
+            &[
+                0x68, 0xE1, 0x07, 0xFA, 0x39, 0xAE, 0x8C, 0xF2, 0x0E, 0x9A, 0x48, 0x5B, 0x0D, 0x8D,
+                0x91, 0x5F, 0x60, 0x4E, 0x35, 0x28, 0x55, 0x1C, 0x55, 0x29, 0x62, 0xAB, 0x2F, 0x35,
+                0x35, 0xB5, 0x02, 0x13,
+            ],
+            &[
+                0xBD, 0x7C, 0x91, 0x4B, 0x02, 0xA3, 0x20, 0xD3, 0xAF, 0x6F, 0x62, 0x33, 0x93, 0xF0,
+                0xBE, 0x58, 0x87, 0xA1, 0xF1, 0xC5, 0x50, 0xA8, 0x58, 0x10, 0x08, 0x18, 0x7B, 0x6F,
+                0xC9, 0xC3, 0xDB, 0x1C,
+            ],
+            &[
+                0x53, 0x11, 0xAE, 0x77, 0x41, 0xA2, 0xCE, 0x79, 0x09, 0x4C, 0x1C, 0x30, 0xE3, 0xC6,
+                0xE1, 0xA3, 0x61, 0xDE, 0xF7, 0x71, 0x58, 0x15, 0x95, 0x4E, 0x9A, 0xAE, 0x9B, 0xE1,
+                0x0A, 0xEE, 0x5F, 0x0D,
+            ],
+            &[
+                0xAB, 0x64, 0x6D, 0x08, 0x20, 0xD5, 0xB6, 0x96, 0xAC, 0x98, 0x32, 0x47, 0x19, 0x6A,
+                0x08, 0x54, 0x36, 0xE0, 0x82, 0xD4, 0xB0, 0x8F, 0x14, 0xAC, 0x46, 0xE7, 0xCB, 0xFC,
+                0xBE, 0xBE, 0x22, 0x23,
+            ],
+        ],
+        [
+            &[
+                0xA0, 0x93, 0x2E, 0xD4, 0xFA, 0xA7, 0xF8, 0x8F, 0xF9, 0xD2, 0x5B, 0xCB, 0x69, 0xA9,
+                0x50, 0x80, 0x19, 0x10, 0x89, 0xDF, 0x9C, 0x36, 0x7A, 0x78, 0x56, 0x6E, 0x59, 0xF8,
+                0xE6, 0x7D, 0x0E, 0x09,
+            ],
+            &[
+                0x46, 0xF4, 0xA5, 0xB3, 0x70, 0x01, 0xB1, 0xE1, 0x50, 0xB6, 0x48, 0x39, 0xE8, 0x20,
+                0x4D, 0x77, 0x14, 0x93, 0xF7, 0x2D, 0x55, 0xC9, 0xB1, 0xBC, 0x97, 0xAE, 0xE9, 0x02,
+                0x0B, 0x4B, 0x79, 0x1B,
+            ],
+            &[
+                0xE8, 0x56, 0x7C, 0x19, 0x35, 0x15, 0x60, 0x28, 0xA4, 0xEE, 0x0D, 0x92, 0x4A, 0xB8,
+                0xD1, 0x61, 0xB3, 0x70, 0x9E, 0xFC, 0xBD, 0x78, 0xFE, 0xAB, 0x1C, 0x33, 0x27, 0x39,
+                0x0D, 0x59, 0x51, 0x05,
+            ],
+            &[
+                0xAD, 0x68, 0x37, 0x7E, 0x86, 0x54, 0x1D, 0x0B, 0x82, 0x81, 0xF9, 0xDA, 0x17, 0x31,
+                0xC8, 0xC1, 0x83, 0xB4, 0xCD, 0xB2, 0x02, 0xA9, 0xD8, 0xAF, 0x3F, 0xE1, 0xB3, 0x3E,
+                0x9A, 0xC5, 0x32, 0x18,
+            ],
+        ],
+        [
+            &[
+                0x71, 0x9D, 0x8A, 0xE5, 0x9B, 0xCA, 0x6B, 0x0B, 0xF5, 0xAB, 0x31, 0x44, 0x0D, 0x13,
+                0xE7, 0xA3, 0xB2, 0x93, 0x2B, 0xD9, 0x52, 0x84, 0x04, 0x9E, 0x86, 0x62, 0x28, 0x72,
+                0x34, 0x60, 0x20, 0x19,
+            ],
+            &[
+                0xF5, 0xDC, 0xE2, 0xBE, 0x30, 0x30, 0xF3, 0x66, 0x02, 0x05, 0x24, 0x24, 0x33, 0x49,
+                0x82, 0x84, 0x77, 0xFC, 0x6F, 0xFE, 0x2B, 0x92, 0xC2, 0xE3, 0x19, 0x3D, 0x50, 0x53,
+                0x27, 0xF0, 0xDD, 0x23,
+            ],
+            &[
+                0xD5, 0xC1, 0x48, 0xE8, 0xA9, 0x1F, 0x8A, 0xFC, 0xFE, 0x85, 0xE6, 0x1A, 0xC4, 0x03,
+                0x01, 0xD5, 0xC4, 0x09, 0xA8, 0x77, 0x4B, 0x43, 0x43, 0x48, 0x6C, 0xD4, 0x2F, 0xD7,
+                0x09, 0x61, 0x5E, 0x22,
+            ],
+            &[
+                0xC4, 0x9D, 0x3D, 0xD9, 0x3D, 0x03, 0x1B, 0xA5, 0xAB, 0x5D, 0x84, 0x0C, 0x5E, 0x2E,
+                0x2C, 0x61, 0x01, 0x2B, 0xD8, 0x5A, 0xAA, 0x56, 0xE5, 0x49, 0x59, 0xE6, 0x42, 0xE7,
+                0x89, 0xDF, 0x3D, 0x1B,
+            ],
+        ],
+        [
+            &[
+                0xD2, 0x8D, 0x03, 0x7D, 0x5B, 0x18, 0xD4, 0x3B, 0x20, 0x82, 0xFB, 0x01, 0xFE, 0x5B,
+                0x75, 0x19, 0x87, 0x4C, 0x4F, 0xAE, 0x4B, 0xF3, 0xED, 0xFD, 0xA4, 0x7C, 0xD8, 0x88,
+                0x1E, 0x5B, 0x5C, 0x24,
+            ],
+            &[
+                0x83, 0xE8, 0x31, 0x5B, 0x22, 0x36, 0xC6, 0xA5, 0x05, 0xD8, 0x8B, 0x6D, 0xAF, 0x09,
+                0xF7, 0xB6, 0x45, 0xEE, 0x64, 0xDD, 0xA1, 0xF3, 0xD4, 0x7B, 0xC5, 0xEE, 0x94, 0x30,
+                0xF3, 0x49, 0x24, 0x0B,
+            ],
+            &[
+                0xED, 0x2B, 0xB6, 0x9E, 0x72, 0x49, 0x3D, 0x0C, 0xA4, 0xF6, 0x87, 0x5D, 0xBB, 0xBC,
+                0x1D, 0xBC, 0xA4, 0x38, 0x7E, 0x5A, 0xE3, 0xE8, 0x72, 0x57, 0x26, 0x30, 0x51, 0x5B,
+                0xC6, 0xC8, 0x28, 0x0E,
+            ],
+            &[
+                0x26, 0x83, 0xCB, 0x92, 0x4C, 0xDB, 0x6F, 0xEF, 0xCA, 0x36, 0x74, 0x32, 0x74, 0xB3,
+                0x54, 0x4A, 0xB5, 0x8B, 0xDB, 0xF8, 0x69, 0x69, 0x43, 0x62, 0xEE, 0xAE, 0xD5, 0x3B,
+                0xD7, 0x0D, 0xD4, 0x18,
+            ],
+        ],
+        [
+            &[
+                0x9F, 0x68, 0xA8, 0x5F, 0x03, 0x1F, 0xD9, 0xBD, 0x9F, 0x0A, 0x7B, 0xF3, 0xCF, 0x6D,
+                0x67, 0x68, 0x5D, 0x8A, 0x7A, 0xC7, 0x88, 0x61, 0x69, 0xA2, 0x43, 0x43, 0x8C, 0x08,
+                0x6B, 0x31, 0xEF, 0x00,
+            ],
+            &[
+                0x52, 0xF7, 0x6A, 0x8B, 0xFB, 0x53, 0x3B, 0xA1, 0xA4, 0x7C, 0xB5, 0x30, 0x1D, 0xB9,
+                0x2B, 0x8E, 0x4E, 0x5D, 0x30, 0xBF, 0x32, 0xA0, 0xBD, 0xB1, 0xD6, 0xFF, 0x02, 0x73,
+                0x7D, 0x9A, 0xE7, 0x27,
+            ],
+            &[
+                0xEA, 0x41, 0x46, 0x3C, 0x40, 0x7A, 0xAD, 0xE3, 0x13, 0xD3, 0x92, 0x9E, 0x71, 0x52,
+                0x0F, 0x9A, 0x04, 0x19, 0x09, 0x0A, 0xD9, 0xA3, 0x56, 0xB8, 0x0D, 0xC4, 0xF0, 0x6C,
+                0x26, 0x1B, 0xE1, 0x1E,
+            ],
+            &[
+                0x38, 0x93, 0xE7, 0xCA, 0x77, 0xA1, 0xC7, 0x31, 0x63, 0x41, 0xFE, 0x78, 0x5E, 0xA4,
+                0x8F, 0xDF, 0xCB, 0x95, 0x12, 0x0C, 0xC4, 0x83, 0x4E, 0xEE, 0x54, 0xF4, 0xAD, 0x52,
+                0x9F, 0xC8, 0x4B, 0x1F,
+            ],
+        ],
+    ];
+}
@@ -0,0 +1,9 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+mod ed_on_bls12_377_base;
+mod ed_on_bls12_381_base;
+mod ed_on_bn254_base;
@@ -0,0 +1,1284 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+use crate::{RescueParameter, ROUNDS, STATE_SIZE};
+use ark_pallas::Fr;
+
+// the constants in this file follow the procedure of
+// https://github.com/EspressoSystems/Marvellous/blob/fcd4c41672f485ac2f62526bc87a16789d4d0459/rescue254.sage
+// i.e. Rescue(128, q_vesta, 4, 5), with
+// q_vesta = 28948022309329048855892746252171976963363056481941647379679742748393362948097
+//
+// The script samples the key schedule constants `initial_constant`,
+// `constants_matrix` and `constants_constant` at random. To make them
+// reproducible, they are instead the 24 consecutive 64-byte little-endian
+// integers, reduced mod q_vesta, of the output of
+//   SHAKE256("jf-rescue vesta base field")
+// in this order, the matrix being read row by row.
+
+// The base field of Vesta is the scalar field of Pallas.
+impl RescueParameter for Fr {
+    // smallest alpha such that gcd(alpha, q_vesta - 1) = 1
+    const A: u64 = 5;
+    // 1/5 % (q_vesta - 1)
+    // A_INV = 0x333333333333333333333333333333334e9ee0c9a143ba4ad69f2280cccccccd
+    //       = 23158417847463239084714197001737581570690445185553317903743794198714690358477
+    const A_INV: &'static [u64] = &[
+        0xd69f2280cccccccd,
+        0x4e9ee0c9a143ba4a,
+        0x3333333333333333,
+        0x3333333333333333,
+    ];
+
+    // The MDS matrix is the right half of the echelon form of the 4 x 8
+    // Vandermonde matrix [g^(i * j)], where g = 5 is the multiplicative
+    // generator of the field
+    // MDS[0,0]= "28948022309329048855892746252171976963363056481941647379679742748393362932472"
+    // MDS[0,1]= "28948022309329048855892746252171976963363056481941647379679742748393360510597"
+    // MDS[0,2]= "28948022309329048855892746252171976963363056481941647379679742748393045666847"
+    // MDS[0,3]= "28948022309329048855892746252171976963363056481941647379679742748353385510597"
+    // MDS[1,0]= "19500"
+    // MDS[1,1]= "3026375"
+    // MDS[1,2]= "393529500"
+    // MDS[1,3]= "49574560750"
+    // MDS[2,0]= "28948022309329048855892746252171976963363056481941647379679742748393362944067"
+    // MDS[2,1]= "28948022309329048855892746252171976963363056481941647379679742748393362338917"
+    // MDS[2,2]= "28948022309329048855892746252171976963363056481941647379679742748393284141292"
+    // MDS[2,3]= "28948022309329048855892746252171976963363056481941647379679742748383445496917"
+    // MDS[3,0]= "156"
+    // MDS[3,1]= "20306"
+    // MDS[3,2]= "2558556"
+    // MDS[3,3]= "320327931"
+    // MDS matrix in little-endian 32 bytes elements
+    const MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE] = [
+        [
+            &[
+                0xF8, 0xC2, 0xFF, 0xFF, 0x20, 0xEB, 0x46, 0x8C, 0xDD, 0xA8, 0x94, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+            &[
+                0x85, 0xCE, 0xDA, 0xFF, 0x20, 0xEB, 0x46, 0x8C, 0xDD, 0xA8, 0x94, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+            &[
+                0x1F, 0xAC, 0x16, 0xED, 0x20, 0xEB, 0x46, 0x8C, 0xDD, 0xA8, 0x94, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+            &[
+                0xC5, 0xB6, 0x28, 0xB1, 0x17, 0xEB, 0x46, 0x8C, 0xDD, 0xA8, 0x94, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+        ],
+        [
+            &[
+                0x2C, 0x4C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0xC7, 0x2D, 0x2E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x9C, 0xC8, 0x74, 0x17, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0xEE, 0xC3, 0xDF, 0x8A, 0x0B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+        [
+            &[
+                0x43, 0xF0, 0xFF, 0xFF, 0x20, 0xEB, 0x46, 0x8C, 0xDD, 0xA8, 0x94, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+            &[
+                0x65, 0xB4, 0xF6, 0xFF, 0x20, 0xEB, 0x46, 0x8C, 0xDD, 0xA8, 0x94, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+            &[
+                0xEC, 0x80, 0x4D, 0xFB, 0x20, 0xEB, 0x46, 0x8C, 0xDD, 0xA8, 0x94, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+            &[
+                0x55, 0xB4, 0xDF, 0xB0, 0x1E, 0xEB, 0x46, 0x8C, 0xDD, 0xA8, 0x94, 0x09, 0xFC, 0x98,
+                0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x40,
+            ],
+        ],
+        [
+            &[
+                0x9C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x52, 0x4F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0x5C, 0x0A, 0x27, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+            &[
+                0xFB, 0xD0, 0x17, 0x13, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+            ],
+        ],
+    ];
+
+    // initial_constant
+    // a.k.a. INIT_VEC
+    // [25420395681025619667053984108828218200953115338292297175331674805032494478138]
+    // [11103897110096042545706222664517686538311607994861254585702927919199692707576]
+    // [21578565237928994900405576561563442533344786927038718475678564269543384095041]
+    // [4664784757768373980462126661693392055815127061284273762951024985860887749199]
+    const INIT_VEC_LE: [&'static [u8]; STATE_SIZE] = [
+        &[
+            0x3A, 0xB3, 0x26, 0x95, 0xA5, 0xD0, 0x67, 0x81, 0x9A, 0x90, 0x00, 0xA5, 0x80, 0xBB,
+            0x55, 0x5B, 0x66, 0x46, 0x5A, 0xFB, 0xF0, 0xE1, 0x97, 0xA0, 0x50, 0xD5, 0x32, 0x21,
+            0x20, 0x6F, 0x33, 0x38,
+        ],
+        &[
+            0xF8, 0xD6, 0xCA, 0xCB, 0xB7, 0x1D, 0xC9, 0xA9, 0x41, 0xF9, 0x16, 0x30, 0xCF, 0xC5,
+            0xEB, 0x1B, 0xE3, 0x21, 0xAC, 0x7D, 0x33, 0xB6, 0x5B, 0xE1, 0x42, 0x09, 0xDD, 0x92,
+            0x42, 0x95, 0x8C, 0x18,
+        ],
+        &[
+            0x41, 0x19, 0xB3, 0x1E, 0x90, 0x2F, 0xDC, 0x6D, 0x06, 0x43, 0x2A, 0x8B, 0x88, 0xFB,
+            0x7A, 0xEC, 0x89, 0x5C, 0xCB, 0xC2, 0x6E, 0x2C, 0xCB, 0xE5, 0xC2, 0x5B, 0x0D, 0x09,
+            0xFD, 0x08, 0xB5, 0x2F,
+        ],
+        &[
+            0x4F, 0x22, 0x5B, 0xE0, 0x0E, 0x9A, 0x78, 0xC6, 0x4F, 0xA6, 0x8B, 0x33, 0xF1, 0xAB,
+            0x1B, 0x13, 0x05, 0x05, 0x1C, 0xA3, 0xC2, 0x6E, 0x93, 0x4A, 0xA4, 0xFD, 0xC6, 0x26,
+            0xB3, 0x2C, 0x50, 0x0A,
+        ],
+    ];
+
+    // constants_matrix
+    // a.k.a., KEY_SCHED_MATRIX
+    // Keep this for reference
+    // [2878886769048805431119725458140055132057099245755203722533907499362641290720 28357403235924894775774823690243542737521787116552225776153490006376699112236 7002691810865409374635785980020163475101896546581550893628045806338927931151 28492043905397236033228265437826586680796095795039602437605166035279003247513]
+    // [21053504679438556068553927101300640298101265778124363765348834264496543223533 22865918942860019244487359641446364152947055379882093130512033946770495310225 6924417581462104526884316092650164834970872548393015625556459400517370529827 6081270373790462201631729667513474382203909627892767304262846697006273829895]
+    // [21941300544323462576260002000124304510414910584771304503103916929105920691776 24181866358207000686472880449648683179228349651863357168842961464400829776734 5071561807458843897210594346298551592693917860334545016983137252039517885737 6127153970259503160060253301902086144636728567130134125546638559577521004138]
+    // [8525381572380644940894573890489152205555041822251778127199231096992819431564 3090754022567033494794474030065154486741904628706695939232473198443628365771 10290416363086217218318531497254096150491300986814315698736434508107356196858 947927644477594704533987712276088909954073100835992769200868568373667902249]
+    //
+
+    // constants_constant
+    // a.k.a., KEY_SCHED_VEC
+    // [12455293067222645570511592428267570817320309560722395909096668814984952244402]
+    // [22517246116560182021384452246499429231717425237763362320463992340668174362654]
+    // [15926015257281627152114065542648197271330222823442737248623605572748814486964]
+    // [28808646078238474479908388645308769400428792433910361537581126749499318602258]
+    //
+
+    // The key injection constants are precomputed computed as
+    // 1. KEY_INJECTION[-1] = INIT_VECTOR
+    // 2. for i in [0 .. 2 * ROUNDS]
+    // 3. KEY_INJECTION[i] = KEY_SCHED_MATRIX * KEY_INJECTION[ i - 1] +
+    // KEY_SCHED_VEC
+    // where the INIT_VECTOR, KEY_SCHED_MATRIX and KEY_INJECTION are defined above
+    const KEY_INJECTION_LE: [[&'static [u8]; 4]; 2 * ROUNDS] = [
+        [
+            &[
+                0x46, 0x37, 0x61, 0xE3, 0xBA, 0xC7, 0xE3, 0x0A, 0xFC, 0xD8, 0x44, 0x46, 0xF3, 0xB0,
+                0x78, 0x68, 0xBC, 0xF3, 0x3D, 0x7A, 0x66, 0x23, 0x59, 0x0D, 0xB5, 0x34, 0x64, 0x75,
+                0xA3, 0xAE, 0x11, 0x35,
+            ],
+            &[
+                0xB4, 0xD4, 0x12, 0x84, 0x81, 0xCD, 0x80, 0xD8, 0xCD, 0x47, 0x08, 0x72, 0x78, 0x4D,
+                0xDE, 0xAC, 0xBC, 0x9A, 0xA0, 0x12, 0x6E, 0x9F, 0x3C, 0xCC, 0x6E, 0x98, 0x94, 0x6F,
+                0x7C, 0x51, 0xD9, 0x29,
+            ],
+            &[
+                0xBC, 0x70, 0x54, 0x08, 0x4F, 0xAF, 0xC7, 0xF3, 0x9E, 0x65, 0x59, 0xE4, 0xC5, 0x36,
+                0x15, 0x44, 0xCE, 0xF2, 0x80, 0xF6, 0xDF, 0xB3, 0x24, 0xED, 0x22, 0x45, 0x72, 0x83,
+                0x85, 0x4D, 0xD4, 0x17,
+            ],
+            &[
+                0x38, 0x77, 0x47, 0x35, 0x49, 0xE2, 0x2D, 0xAC, 0xF4, 0xD3, 0x16, 0x1B, 0x1E, 0xA2,
+                0x8D, 0x9A, 0x72, 0x1F, 0xEA, 0x34, 0x0A, 0x82, 0xB3, 0x23, 0x2F, 0x5E, 0x7C, 0x24,
+                0xAF, 0x81, 0x62, 0x03,
+            ],
+        ],
+        [
+            &[
+                0xD1, 0x3A, 0xA6, 0xAF, 0x14, 0x4C, 0x4E, 0x3C, 0x08, 0xCF, 0x9C, 0x7D, 0xBD, 0xA3,
+                0xA2, 0xB5, 0x27, 0x8D, 0xFA, 0xFB, 0x2C, 0xD7, 0xF3, 0xA1, 0xE1, 0x5F, 0x60, 0x7F,
+                0x42, 0x3C, 0x9E, 0x1B,
+            ],
+            &[
+                0x12, 0x9D, 0x19, 0xB9, 0xFB, 0x18, 0x3F, 0x85, 0xC3, 0x4F, 0xF5, 0x6D, 0xAA, 0x2C,
+                0xFC, 0x09, 0x46, 0x92, 0x07, 0x10, 0x3E, 0x10, 0x1B, 0xF8, 0xEB, 0x95, 0xC3, 0xA6,
+                0x79, 0x93, 0x66, 0x18,
+            ],
+            &[
+                0xC2, 0x55, 0x4C, 0x96, 0x53, 0x58, 0x88, 0x22, 0xF5, 0x35, 0x71, 0x6A, 0x95, 0x46,
+                0x3A, 0x9E, 0x6F, 0xEB, 0xA9, 0x38, 0x0A, 0x74, 0x5F, 0xF2, 0xBF, 0xAC, 0x07, 0x85,
+                0x79, 0xF0, 0xEB, 0x12,
+            ],
+            &[
+                0x7E, 0x06, 0xEA, 0xF1, 0xEB, 0x1D, 0xC0, 0x6F, 0xA2, 0xF9, 0x5F, 0xAC, 0xF2, 0x2F,
+                0x85, 0xA6, 0x2C, 0x43, 0x0D, 0x09, 0x6C, 0x47, 0x6B, 0x3E, 0xCC, 0x53, 0x8E, 0xD7,
+                0xA6, 0xA8, 0x56, 0x06,
+            ],
+        ],
+        [
+            &[
+                0xE4, 0x61, 0x40, 0x72, 0xE3, 0x66, 0xC5, 0xE3, 0x26, 0xF1, 0x23, 0x31, 0x03, 0x6C,
+                0x08, 0x93, 0xD5, 0x06, 0x49, 0xA8, 0x80, 0xF6, 0x67, 0x30, 0x20, 0x34, 0xDF, 0x74,
+                0x70, 0xD2, 0x9F, 0x0C,
+            ],
+            &[
+                0x1F, 0x94, 0x57, 0xB8, 0xB9, 0x9C, 0x20, 0xF6, 0xFC, 0x2D, 0x67, 0x0A, 0xCD, 0x74,
+                0x47, 0x6B, 0xDB, 0x38, 0x60, 0x88, 0xCA, 0xA6, 0xC3, 0x71, 0x2B, 0xB4, 0x43, 0x43,
+                0xEF, 0xBB, 0x83, 0x28,
+            ],
+            &[
+                0xFF, 0xB8, 0x00, 0x83, 0x6A, 0xC5, 0xAF, 0x1D, 0x32, 0xAC, 0x83, 0x5D, 0x23, 0xE0,
+                0xCE, 0x9C, 0xCB, 0xDF, 0x28, 0x7C, 0x95, 0xB5, 0x47, 0x38, 0x06, 0xB4, 0xD9, 0x46,
+                0xFD, 0xD1, 0x46, 0x10,
+            ],
+            &[
+                0x3E, 0x98, 0xDC, 0xB2, 0x8C, 0x5F, 0x3A, 0xAD, 0xFE, 0x08, 0x1D, 0x63, 0x45, 0x23,
+                0xDC, 0xCB, 0x5F, 0x1B, 0x10, 0x99, 0xB6, 0xCA, 0xE2, 0xB3, 0x9A, 0x93, 0x8C, 0xC3,
+                0x10, 0x41, 0xA0, 0x22,
+            ],
+        ],
+        [
+            &[
+                0x5C, 0x99, 0xA6, 0xD5, 0xF2, 0x70, 0x56, 0x58, 0x5B, 0x34, 0xFF, 0xBE, 0xA1, 0x0B,
+                0x94, 0x4E, 0x8E, 0x94, 0x33, 0x6A, 0x4B, 0x99, 0x8F, 0x9E, 0xA3, 0x1F, 0x7F, 0xB0,
+                0x5E, 0x62, 0xD0, 0x16,
+            ],
+            &[
+                0x12, 0x9F, 0xE2, 0xE4, 0x14, 0x4C, 0x84, 0x1B, 0x50, 0xA6, 0x67, 0xC4, 0x69, 0x1F,
+                0xD6, 0xA7, 0xAB, 0x04, 0x65, 0x63, 0xC3, 0x3C, 0xCB, 0xB0, 0x8A, 0xA3, 0xE7, 0xD6,
+                0x77, 0x87, 0x2F, 0x07,
+            ],
+            &[
+                0x74, 0x69, 0xEA, 0x79, 0x78, 0xAE, 0xB1, 0xA2, 0xA0, 0xE7, 0xA9, 0xC0, 0x54, 0x42,
+                0x66, 0x63, 0xB4, 0xFB, 0x29, 0x2C, 0xE7, 0x43, 0x7E, 0x1A, 0x4E, 0x82, 0x1C, 0xED,
+                0x66, 0x53, 0x6F, 0x01,
+            ],
+            &[
+                0x98, 0x35, 0x0D, 0x46, 0x51, 0xC8, 0xFD, 0x7B, 0xAB, 0x18, 0x83, 0x49, 0xB8, 0x9E,
+                0x2F, 0x26, 0x14, 0x65, 0x27, 0xB5, 0x3D, 0x6E, 0xB5, 0x95, 0x45, 0x6C, 0xFE, 0x13,
+                0x53, 0xFB, 0x5C, 0x0A,
+            ],
+        ],
+        [
+            &[
+                0x26, 0xE4, 0x02, 0x6F, 0xC3, 0x4D, 0x86, 0x15, 0xA5, 0x3F, 0x22, 0x75, 0x7A, 0x8D,
+                0xFB, 0xED, 0x1D, 0xF7, 0xE6, 0xEA, 0x89, 0xB7, 0x2C, 0x2D, 0xAF, 0x01, 0xE2, 0x8C,
+                0x31, 0x13, 0x06, 0x2C,
+            ],
+            &[
+                0x30, 0xC7, 0xB0, 0x76, 0x2D, 0x87, 0x9E, 0xB9, 0x8F, 0x7D, 0x5C, 0x39, 0xE1, 0xC8,
+                0xFD, 0x02, 0x37, 0x8C, 0xCC, 0x95, 0x3B, 0xC1, 0xD5, 0xAA, 0x6E, 0xB6, 0x2F, 0xA6,
+                0x52, 0xC9, 0x31, 0x1F,
+            ],
+            &[
+                0x84, 0x35, 0x3E, 0x7F, 0x3F, 0x36, 0x6E, 0x93, 0x40, 0xC7, 0x41, 0xEF, 0x2A, 0xE1,
+                0x49, 0x25, 0xB3, 0xAA, 0x44, 0xDE, 0x63, 0x03, 0xA1, 0x45, 0x44, 0x3F, 0x40, 0x21,
+                0x3B, 0x18, 0x7F, 0x26,
+            ],
+            &[
+                0xA5, 0xD5, 0x81, 0x05, 0x38, 0xD0, 0x7F, 0x1C, 0x7B, 0x95, 0x48, 0x65, 0xF6, 0xCD,
+                0xF6, 0x66, 0x35, 0xED, 0x39, 0x35, 0x22, 0x7B, 0x8C, 0x42, 0x60, 0x31, 0x32, 0xED,
+                0x00, 0x59, 0xF2, 0x22,
+            ],
+        ],
+        [
+            &[
+                0xA2, 0x67, 0x7F, 0x52, 0xA4, 0xA1, 0x57, 0x4C, 0x86, 0x00, 0xBB, 0x8D, 0xC4, 0xD3,
+                0xAA, 0x86, 0xF8, 0x84, 0x03, 0xCC, 0x34, 0xF5, 0xEE, 0xF3, 0x70, 0x36, 0x3B, 0x96,
+                0x53, 0x21, 0xC0, 0x15,
+            ],
+            &[
+                0x0B, 0x59, 0x1C, 0x93, 0xA2, 0x4E, 0xF8, 0xCA, 0x95, 0x0E, 0x3A, 0xFC, 0x6C, 0x00,
+                0xB2, 0x70, 0x58, 0x83, 0xE8, 0x73, 0x1D, 0x31, 0x3C, 0x54, 0xBB, 0xD2, 0x2E, 0xDF,
+                0x57, 0xCE, 0xA0, 0x2C,
+            ],
+            &[
+                0x51, 0x51, 0x0D, 0x83, 0xC0, 0xC9, 0xCB, 0x51, 0xAC, 0x4F, 0x8F, 0x4A, 0x99, 0x4F,
+                0x50, 0x40, 0xCD, 0x8C, 0x1F, 0x20, 0xC4, 0x70, 0xAB, 0x7F, 0x49, 0xF3, 0x30, 0xFF,
+                0xBC, 0x72, 0xB6, 0x0C,
+            ],
+            &[
+                0x4B, 0x5B, 0x80, 0x68, 0x08, 0x81, 0x4E, 0x4F, 0x35, 0xF0, 0x7F, 0x34, 0x1F, 0xCF,
+                0x2A, 0xDF, 0x1A, 0x79, 0x97, 0x9E, 0x8A, 0x75, 0xF5, 0x5E, 0x24, 0x62, 0xF8, 0x89,
+                0x5F, 0x75, 0x09, 0x11,
+            ],
+        ],
+        [
+            &[
+                0xD0, 0x4C, 0x79, 0x12, 0x5F, 0xAD, 0xB3, 0x9F, 0x1C, 0xB0, 0xF7, 0x94, 0xCA, 0xFB,
+                0xB6, 0x8C, 0xC6, 0xF9, 0x47, 0x82, 0xC7, 0x6F, 0x1B, 0x8D, 0xC6, 0x02, 0x02, 0xB8,
+                0xB0, 0xE4, 0x39, 0x3E,
+            ],
+            &[
+                0xD8, 0x1A, 0xFC, 0xCC, 0x12, 0xD7, 0x4E, 0xE9, 0x27, 0xD5, 0x5E, 0x26, 0x97, 0x2D,
+                0xD8, 0x6D, 0xA5, 0x3F, 0x3B, 0xCF, 0x9C, 0xA5, 0x65, 0x00, 0x36, 0xA7, 0xFD, 0x38,
+                0x02, 0xDE, 0x18, 0x36,
+            ],
+            &[
+                0x4C, 0xFC, 0x83, 0x37, 0xF8, 0x69, 0x1A, 0x5B, 0x93, 0x63, 0x4B, 0xF5, 0x1F, 0x37,
+                0x93, 0x70, 0x83, 0xA5, 0x45, 0xE8, 0xC7, 0xC0, 0x7D, 0x44, 0x49, 0xD8, 0x5B, 0x49,
+                0x8E, 0x1A, 0x7B, 0x04,
+            ],
+            &[
+                0xEA, 0xA8, 0x2F, 0x3B, 0x92, 0x47, 0x30, 0x9E, 0x3E, 0x8F, 0x68, 0x5D, 0xA0, 0x0E,
+                0x38, 0xA3, 0x3A, 0x25, 0x3E, 0x38, 0xE2, 0x00, 0x16, 0x8F, 0x68, 0x9F, 0xB1, 0x1C,
+                0x06, 0x13, 0xCB, 0x24,
+            ],
+        ],
+        [
+            &[
+                0xD1, 0x9E, 0x36, 0x5F, 0x65, 0x4C, 0xA3, 0x7E, 0x7A, 0x23, 0xBC, 0xDF, 0xF9, 0x35,
+                0x37, 0x63, 0x7D, 0xB5, 0x2D, 0x29, 0xB3, 0xF0, 0x1E, 0x0E, 0xD5, 0xE6, 0x31, 0x3B,
+                0xD2, 0x69, 0xCE, 0x2E,
+            ],
+            &[
+                0xE3, 0xD8, 0x58, 0x6A, 0xF6, 0x6F, 0xC0, 0xDE, 0x6F, 0x77, 0x8B, 0xE0, 0x32, 0x25,
+                0xD6, 0x18, 0x5D, 0x06, 0xE4, 0xC4, 0x56, 0x44, 0xEE, 0xF8, 0x77, 0xDF, 0xE0, 0xC5,
+                0xD8, 0xAB, 0x68, 0x37,
+            ],
+            &[
+                0xAA, 0xE7, 0x55, 0xDB, 0x2D, 0x7F, 0x35, 0x0A, 0x7C, 0xFC, 0x38, 0xF8, 0x63, 0xD8,
+                0x10, 0xC5, 0x8D, 0xE1, 0xF5, 0x94, 0xA4, 0x6D, 0x37, 0x36, 0xC0, 0x7B, 0x47, 0xDE,
+                0x90, 0x09, 0xE6, 0x01,
+            ],
+            &[
+                0x4E, 0xBC, 0xD4, 0xD8, 0x0E, 0x85, 0x43, 0xFE, 0x4E, 0xFE, 0x74, 0x76, 0xA0, 0x9B,
+                0x4B, 0xC5, 0x42, 0xCC, 0xF8, 0x91, 0x0C, 0xA4, 0x8A, 0xAA, 0xCF, 0x0B, 0x2F, 0xA1,
+                0x6C, 0x9E, 0xF1, 0x02,
+            ],
+        ],
+        [
+            &[
+                0x83, 0xAE, 0x18, 0x96, 0x49, 0x44, 0xD7, 0x37, 0xDA, 0x1B, 0x07, 0xB5, 0xF4, 0x5D,
+                0x0E, 0xE9, 0x5A, 0xFD, 0x85, 0x52, 0x40, 0xD4, 0x16, 0x2B, 0x71, 0x0F, 0x17, 0xFD,
+                0xC0, 0x8C, 0xE6, 0x07,
+            ],
+            &[
+                0x3B, 0x9D, 0x60, 0xD4, 0x6F, 0x66, 0x7F, 0xF4, 0x7E, 0xD7, 0x2C, 0xC6, 0x7A, 0xC2,
+                0x81, 0xA8, 0x8D, 0xBA, 0x64, 0x9B, 0x43, 0x15, 0xA2, 0xA2, 0x10, 0x58, 0x6E, 0xC6,
+                0xA6, 0x81, 0x5E, 0x14,
+            ],
+            &[
+                0x81, 0xCA, 0x6E, 0x44, 0x70, 0xA4, 0x83, 0x76, 0xD6, 0x4E, 0x28, 0x87, 0x7B, 0x34,
+                0x34, 0xB6, 0x94, 0x9F, 0x9E, 0xAD, 0x18, 0x2E, 0x1E, 0xC1, 0x32, 0x1F, 0x4A, 0xF6,
+                0xEE, 0xC8, 0xA9, 0x32,
+            ],
+            &[
+                0xBC, 0x84, 0xA3, 0xB4, 0x12, 0x0D, 0xC7, 0xEB, 0xDB, 0xAD, 0xFF, 0xC5, 0x16, 0x11,
+                0xB1, 0xD7, 0x5B, 0xE3, 0xF6, 0xEF, 0x97, 0xEA, 0xA5, 0x68, 0xD9, 0x2B, 0x5B, 0x24,
+                0x91, 0x79, 0xD8, 0x20,
+            ],
+        ],
+        [
+            &[
+                0xB7, 0x43, 0xED, 0x1F, 0x91, 0xDC, 0x5A, 0x4C, 0xF5, 0x0A, 0xAB, 0xDD, 0x9E, 0x6B,
+                0xC9, 0x77, 0xBD, 0x89, 0xCF, 0x77, 0x60, 0x59, 0xF5, 0x65, 0x43, 0xFC, 0x2B, 0x50,
+                0x82, 0x4A, 0x18, 0x14,
+            ],
+            &[
+                0x72, 0x8B, 0x94, 0x40, 0xB6, 0x54, 0xB3, 0x4B, 0x59, 0x8F, 0xDB, 0x03, 0xDC, 0x86,
+                0x7A, 0x7B, 0x7A, 0xF5, 0xA6, 0xF7, 0x86, 0x59, 0x01, 0xC4, 0x6D, 0x43, 0x9E, 0xCA,
+                0xE2, 0x65, 0x57, 0x15,
+            ],
+            &[
+                0x21, 0x9B, 0xC4, 0x5D, 0x59, 0x77, 0xDB, 0x8C, 0x17, 0xC2, 0x28, 0x50, 0x3B, 0x00,
+                0xC9, 0x1E, 0x23, 0xD8, 0x8B, 0x42, 0x6B, 0xAD, 0x5D, 0x54, 0xC0, 0x8F, 0x3E, 0xF8,
+                0xEC, 0x24, 0x52, 0x3A,
+            ],
+            &[
+                0xDD, 0x59, 0x30, 0xEB, 0x47, 0xC1, 0x7D, 0x43, 0xF5, 0x31, 0xFC, 0x15, 0x9C, 0xD4,
+                0x38, 0xD4, 0x51, 0x6F, 0x03, 0x45, 0xF9, 0xD1, 0x01, 0xD2, 0xE9, 0x98, 0x17, 0xD7,
+                0x29, 0x04, 0x3E, 0x0B,
+            ],
+        ],
+        [
+            &[
+                0xD1, 0xB4, 0x37, 0xE1, 0x47, 0x42, 0xE1, 0xB5, 0x42, 0x24, 0xA7, 0x43, 0x14, 0x57,
+                0x88, 0x7C, 0xC4, 0x30, 0x54, 0x73, 0x7A, 0xB9, 0x1D, 0xC9, 0x02, 0xB8, 0x4D, 0x7D,
+                0x11, 0x92, 0xD2, 0x25,
+            ],
+            &[
+                0x45, 0x3B, 0x26, 0xC8, 0x1D, 0x16, 0x62, 0xD8, 0xCE, 0xFA, 0x98, 0x49, 0xC2, 0xA3,
+                0x4A, 0x92, 0x99, 0x36, 0x71, 0xB4, 0xA0, 0xED, 0xDC, 0x8E, 0x36, 0x7F, 0x83, 0x2C,
+                0x27, 0x47, 0x8B, 0x05,
+            ],
+            &[
+                0x09, 0x54, 0xCC, 0x0B, 0xBD, 0xD5, 0x32, 0x5C, 0x8D, 0xE3, 0x9C, 0x7F, 0xCD, 0x56,
+                0x0B, 0x5B, 0x0E, 0x06, 0xD0, 0xFC, 0x47, 0xBA, 0x40, 0x70, 0x24, 0x06, 0x28, 0x48,
+                0x6E, 0x33, 0xC2, 0x10,
+            ],
+            &[
+                0x62, 0x90, 0x44, 0x0E, 0x51, 0x85, 0x6D, 0x86, 0xD4, 0x40, 0xB4, 0xE0, 0xCC, 0x47,
+                0x9D, 0x82, 0x82, 0xBF, 0x9B, 0x7D, 0x70, 0xBF, 0x77, 0x9B, 0xED, 0x8D, 0x7A, 0xEB,
+                0xD1, 0x9F, 0xA8, 0x3D,
+            ],
+        ],
+        [
+            &[
+                0x84, 0x1F, 0x93, 0xA9, 0x57, 0x0E, 0xD0, 0xC5, 0xBE, 0x9B, 0xEF, 0xF7, 0xD0, 0xCF,
+                0xB7, 0x12, 0x71, 0x7D, 0xFD, 0x14, 0xE9, 0xFD, 0x3A, 0xC4, 0x27, 0xE7, 0xC5, 0x3F,
+                0xC0, 0x78, 0x46, 0x3A,
+            ],
+            &[
+                0xE3, 0xC7, 0xDD, 0x7A, 0x48, 0x66, 0x1B, 0xA1, 0x45, 0xF0, 0x27, 0x35, 0x8B, 0xE5,
+                0x5E, 0x5E, 0x1F, 0x14, 0x42, 0xA0, 0x1F, 0xA8, 0x96, 0x37, 0xBE, 0x34, 0x7F, 0xA7,
+                0xCE, 0x9C, 0xBE, 0x1F,
+            ],
+            &[
+                0x89, 0xC7, 0x71, 0x49, 0x24, 0xFD, 0xB9, 0x8A, 0xF3, 0x00, 0xFF, 0xD6, 0xE9, 0x5A,
+                0xDD, 0x9B, 0x80, 0x5B, 0xC4, 0x1D, 0xBD, 0x75, 0x1D, 0x3D, 0x06, 0x7A, 0xA2, 0xAF,
+                0x2D, 0x1A, 0xAC, 0x08,
+            ],
+            &[
+                0xEB, 0xA5, 0xBD, 0x39, 0x74, 0x00, 0xC9, 0x3F, 0xB8, 0x6D, 0xDE, 0xC5, 0x94, 0xB6,
+                0x5C, 0xF1, 0xB5, 0xCE, 0xDB, 0xB2, 0xF5, 0xE7, 0xDE, 0x83, 0xBA, 0x30, 0x61, 0x2F,
+                0x99, 0x51, 0x9E, 0x24,
+            ],
+        ],
+        [
+            &[
+                0x8A, 0xE5, 0x70, 0xB0, 0x37, 0x4D, 0x44, 0x0F, 0x0F, 0x69, 0x46, 0x64, 0xCA, 0x90,
+                0x7C, 0xB7, 0x4D, 0x3C, 0x27, 0x5D, 0x4C, 0x70, 0x5F, 0x18, 0x2E, 0xD5, 0x0B, 0x41,
+                0xF9, 0xFD, 0xFA, 0x27,
+            ],
+            &[
+                0xB3, 0x0D, 0xBE, 0x3E, 0x3C, 0x63, 0x92, 0x7D, 0x1D, 0x63, 0xD7, 0xDA, 0x4D, 0x1A,
+                0x84, 0xEE, 0xB2, 0x51, 0x23, 0x59, 0x5C, 0xA2, 0x8B, 0xC1, 0x99, 0x66, 0xCC, 0xDC,
+                0xED, 0xE2, 0xC8, 0x2E,
+            ],
+            &[
+                0xAD, 0x8F, 0xBF, 0x94, 0x6A, 0xAD, 0xE1, 0xDB, 0xAB, 0x17, 0xE2, 0xD2, 0x3E, 0x1A,
+                0xFD, 0x58, 0x6D, 0x8D, 0xD0, 0x0A, 0x74, 0x1C, 0x52, 0xC1, 0x5F, 0x20, 0x66, 0x89,
+                0x34, 0x79, 0x38, 0x12,
+            ],
+            &[
+                0xBA, 0x77, 0x08, 0x85, 0x21, 0xF6, 0x91, 0x54, 0xA5, 0x1D, 0x1D, 0x84, 0xFC, 0x07,
+                0x60, 0x42, 0xF5, 0xEF, 0xE5, 0xB2, 0x53, 0x3D, 0x8A, 0x01, 0x4F, 0x7B, 0x21, 0x3D,
+                0x43, 0xA8, 0x56, 0x24,
+            ],
+        ],
+        [
+            &[
+                0x50, 0xC1, 0x8A, 0x56, 0x50, 0xBA, 0xF0, 0x1E, 0x71, 0x39, 0xB3, 0xEE, 0xF0, 0xFC,
+                0xEC, 0x87, 0x31, 0x53, 0xBF, 0x94, 0x84, 0xC6, 0x7C, 0x00, 0xDD, 0xEC, 0x78, 0x17,
+                0xD1, 0xDE, 0xDF, 0x20,
+            ],
+            &[
+                0x8D, 0x89, 0x2E, 0x9F, 0xA1, 0xDD, 0xB3, 0x34, 0x2A, 0x33, 0x44, 0xF2, 0x4D, 0x34,
+                0x4E, 0x89, 0xDF, 0x66, 0x00, 0x6A, 0x7B, 0x73, 0x9C, 0x5E, 0xE5, 0x69, 0x4E, 0x86,
+                0x39, 0x0D, 0x0E, 0x15,
+            ],
+            &[
+                0xD1, 0x44, 0xD8, 0xEA, 0x98, 0xF6, 0x9E, 0xF1, 0xD2, 0x3D, 0x6F, 0x22, 0xAA, 0x7E,
+                0xDA, 0x29, 0xB5, 0x54, 0x0C, 0x81, 0x4C, 0x9F, 0x5A, 0x2B, 0x00, 0x20, 0xFE, 0x1F,
+                0xED, 0x97, 0x45, 0x37,
+            ],
+            &[
+                0x1B, 0x72, 0x49, 0x58, 0x45, 0xE0, 0x6B, 0x6E, 0xB7, 0x0C, 0x4F, 0x90, 0x31, 0xEB,
+                0x66, 0x2F, 0x86, 0x71, 0x30, 0x84, 0xBB, 0xDD, 0xC5, 0xE9, 0xFE, 0x5A, 0x02, 0x4D,
+                0xA9, 0xDA, 0xAE, 0x24,
+            ],
+        ],
+        [
+            &[
+                0xC7, 0xA6, 0xC4, 0x78, 0x71, 0x51, 0x53, 0x9F, 0xCB, 0x3A, 0x5B, 0x62, 0x17, 0xB1,
+                0xA3, 0x4C, 0x9B, 0x2F, 0xFD, 0x0F, 0x06, 0x62, 0x26, 0x85, 0x0F, 0x80, 0x8C, 0x3D,
+                0x96, 0xF1, 0x21, 0x20,
+            ],
+            &[
+                0xAA, 0xF5, 0xD2, 0x29, 0xF4, 0xB3, 0xFE, 0xE4, 0x89, 0x3B, 0xCC, 0xF0, 0x98, 0x82,
+                0x50, 0x7D, 0x50, 0x77, 0xF2, 0x09, 0x4C, 0xBE, 0x7B, 0xD3, 0xF0, 0xAF, 0xC1, 0xBF,
+                0x14, 0xF2, 0x5C, 0x2C,
+            ],
+            &[
+                0x14, 0x3D, 0xE8, 0xCF, 0x2E, 0x61, 0x4A, 0x8F, 0xE8, 0xBA, 0x95, 0x11, 0xDA, 0xCB,
+                0x17, 0xA6, 0xFF, 0xF4, 0x22, 0xC9, 0x08, 0x64, 0x0F, 0xB2, 0xB9, 0x22, 0x1B, 0x2E,
+                0x89, 0x7E, 0x9B, 0x23,
+            ],
+            &[
+                0x62, 0xF1, 0x7C, 0x7B, 0x76, 0xB8, 0x5F, 0x2B, 0x5B, 0x2C, 0x5E, 0x7A, 0x1B, 0xAC,
+                0x4B, 0xC0, 0xA7, 0x8A, 0x75, 0x59, 0x54, 0x5B, 0xF5, 0x41, 0xD1, 0x3B, 0xA7, 0x9D,
+                0x1D, 0x3A, 0x99, 0x05,
+            ],
+        ],
+        [
+            &[
+                0x41, 0xC0, 0x60, 0x66, 0xA9, 0x88, 0x30, 0x4A, 0xE4, 0x10, 0x6B, 0xFD, 0x7F, 0xB6,
+                0xE5, 0x9F, 0x9E, 0x79, 0x02, 0xF4, 0xD5, 0x16, 0x8C, 0x23, 0xAA, 0x25, 0x5F, 0x63,
+                0x5F, 0x33, 0xD3, 0x2D,
+            ],
+            &[
+                0xAC, 0xFB, 0x2F, 0xF6, 0xC8, 0x2D, 0xA6, 0xDD, 0xDF, 0xB7, 0xBD, 0xDB, 0x65, 0xE8,
+                0xA2, 0xAC, 0x11, 0x5B, 0x9A, 0xFC, 0x42, 0x0A, 0xC1, 0x40, 0x08, 0xF1, 0xBC, 0x8B,
+                0x6A, 0xA7, 0x2F, 0x1A,
+            ],
+            &[
+                0xB8, 0x6A, 0x24, 0xDC, 0x8A, 0x27, 0xEF, 0x83, 0xD1, 0xA5, 0x37, 0x3F, 0x2D, 0x88,
+                0x63, 0x52, 0xF1, 0xA5, 0xE3, 0x96, 0xBE, 0x9A, 0x08, 0xCE, 0xB6, 0x9B, 0xC1, 0xF8,
+                0x3B, 0xB7, 0x30, 0x3E,
+            ],
+            &[
+                0xBA, 0x60, 0x40, 0x31, 0x65, 0xAD, 0x54, 0x73, 0xA6, 0xCC, 0x86, 0xCA, 0x74, 0x9E,
+                0x0A, 0x96, 0xFD, 0xAD, 0x2C, 0xD5, 0x60, 0xC2, 0xEF, 0xEB, 0x25, 0xFD, 0x8A, 0x7E,
+                0x30, 0xA9, 0xAC, 0x28,
+            ],
+        ],
+        [
+            &[
+                0xF7, 0xE5, 0x2E, 0x22, 0x76, 0x80, 0xA4, 0x82, 0x24, 0xC2, 0x7F, 0xD9, 0xA6, 0x64,
+                0x00, 0x69, 0x7F, 0xD5, 0x8A, 0x3A, 0x76, 0xE2, 0xDA, 0xB4, 0xFD, 0x73, 0xD9, 0x1E,
+                0xA4, 0x26, 0xE8, 0x31,
+            ],
+            &[
+                0xAE, 0x2C, 0x61, 0x0A, 0xAD, 0x56, 0x13, 0x9A, 0xD8, 0x33, 0x3C, 0x83, 0xC0, 0x7E,
+                0xDA, 0x48, 0x21, 0x6A, 0x9B, 0xB6, 0x62, 0x55, 0x3C, 0xD2, 0x96, 0x76, 0x63, 0xA6,
+                0xA4, 0xF0, 0xBA, 0x39,
+            ],
+            &[
+                0x06, 0x99, 0xE2, 0x45, 0x51, 0xDD, 0x11, 0x31, 0x96, 0xE9, 0x7F, 0x21, 0x5C, 0x88,
+                0xAC, 0xF2, 0xCF, 0xDE, 0x4C, 0xC8, 0x91, 0x62, 0x5D, 0x2E, 0x9E, 0xA2, 0x97, 0xD1,
+                0x16, 0x65, 0x5B, 0x06,
+            ],
+            &[
+                0x62, 0xCD, 0x02, 0xE2, 0x34, 0x07, 0x37, 0xE1, 0xE0, 0x75, 0xA8, 0x0A, 0x3F, 0xBF,
+                0xE7, 0xB2, 0x57, 0xBA, 0xBD, 0x13, 0x08, 0xCC, 0x7E, 0x6D, 0xCC, 0x47, 0x9E, 0x6C,
+                0x4C, 0xC2, 0x26, 0x28,
+            ],
+        ],
+        [
+            &[
+                0x2B, 0x3F, 0x2C, 0x25, 0x03, 0xF3, 0x7E, 0xED, 0xB9, 0xF6, 0x41, 0xAE, 0xB0, 0xF8,
+                0x8E, 0x9C, 0x97, 0xF8, 0x47, 0xAC, 0xEF, 0x84, 0xFF, 0x8F, 0x82, 0x5F, 0xFE, 0xD1,
+                0xF0, 0x54, 0xD7, 0x39,
+            ],
+            &[
+                0xE4, 0x00, 0x8D, 0x09, 0xE4, 0x3B, 0xEE, 0xFB, 0x66, 0xCE, 0x5A, 0xC3, 0x8C, 0x72,
+                0x3C, 0xCD, 0x68, 0xFC, 0x79, 0x26, 0xF7, 0xEF, 0x71, 0x96, 0x39, 0xFD, 0x3D, 0x08,
+                0x94, 0x87, 0x95, 0x3B,
+            ],
+            &[
+                0x49, 0xBF, 0xE9, 0x85, 0x32, 0xE9, 0x2D, 0x72, 0xAB, 0xEF, 0x90, 0x96, 0x41, 0xC8,
+                0xE7, 0xB2, 0xC2, 0xA3, 0x7F, 0x93, 0x2F, 0xC8, 0xCF, 0x02, 0xCE, 0x7E, 0x6F, 0x22,
+                0xCD, 0x39, 0xDB, 0x0D,
+            ],
+            &[
+                0x83, 0x47, 0x42, 0x0F, 0x86, 0xAF, 0x30, 0xDC, 0x1B, 0x3D, 0x4F, 0x68, 0x75, 0x67,
+                0x9F, 0x39, 0x7B, 0x17, 0xAB, 0x69, 0x54, 0xE0, 0xC8, 0xD2, 0x6C, 0xE8, 0x2D, 0x56,
+                0xE5, 0x5C, 0x6C, 0x3E,
+            ],
+        ],
+        [
+            &[
+                0xC5, 0x8A, 0xAA, 0xA7, 0x5A, 0x7C, 0x06, 0xB9, 0x28, 0x10, 0xC0, 0xF5, 0xA1, 0x7B,
+                0xFB, 0x7B, 0x7B, 0x35, 0x3F, 0xF6, 0x52, 0x39, 0x2D, 0x9A, 0xA2, 0x2D, 0x62, 0xE0,
+                0xD4, 0xAF, 0x95, 0x38,
+            ],
+            &[
+                0xDC, 0x32, 0x19, 0xA3, 0xA5, 0x55, 0xF2, 0x67, 0xC2, 0xC1, 0xF6, 0x15, 0x56, 0x57,
+                0x9B, 0x53, 0x85, 0xAE, 0x75, 0x5F, 0x06, 0xC2, 0xFA, 0x1A, 0x95, 0x77, 0xDE, 0x47,
+                0xA4, 0xDC, 0xE0, 0x2D,
+            ],
+            &[
+                0xA9, 0x78, 0x7E, 0xAB, 0xDB, 0x04, 0x67, 0xDE, 0x84, 0xBC, 0x12, 0xAE, 0x22, 0x4A,
+                0x74, 0xB6, 0xC3, 0x7B, 0x80, 0x92, 0xFF, 0x9C, 0x8F, 0xF9, 0x89, 0x79, 0x0F, 0x52,
+                0x75, 0x5F, 0x39, 0x2D,
+            ],
+            &[
+                0xD1, 0xF0, 0x10, 0xAC, 0xDD, 0x64, 0x8C, 0xCB, 0xE1, 0xFB, 0x88, 0xD3, 0x0E, 0x4C,
+                0x72, 0x2C, 0xB1, 0x86, 0x44, 0x55, 0x6C, 0xF6, 0x7A, 0x05, 0xDA, 0x13, 0x7B, 0xEE,
+                0xE2, 0xA7, 0x7A, 0x1F,
+            ],
+        ],
+        [
+            &[
+                0xA4, 0xBF, 0xF2, 0xF4, 0xBD, 0x64, 0xA3, 0x1F, 0x90, 0x31, 0xA0, 0x60, 0x0D, 0xED,
+                0x5B, 0xBF, 0x8E, 0xE7, 0x35, 0x2B, 0x94, 0x37, 0xA1, 0x8F, 0x0F, 0xFD, 0xD2, 0x65,
+                0x1C, 0xD8, 0x1B, 0x36,
+            ],
+            &[
+                0xF4, 0x1C, 0x1A, 0x62, 0x50, 0xB4, 0x73, 0x26, 0x26, 0x78, 0xC4, 0xE5, 0x8F, 0x1D,
+                0xEC, 0x7E, 0xA1, 0xC2, 0x0C, 0xEB, 0x99, 0xE9, 0xA7, 0x05, 0x95, 0x12, 0x4C, 0x63,
+                0x13, 0x91, 0xBE, 0x0A,
+            ],
+            &[
+                0x44, 0xC8, 0xA3, 0xAD, 0x62, 0x9B, 0x2B, 0xB5, 0x4B, 0x7E, 0xF4, 0x63, 0x36, 0x23,
+                0x57, 0xB5, 0x71, 0xFC, 0x12, 0xE7, 0xDC, 0xAD, 0xE2, 0x87, 0xCE, 0xE8, 0x4B, 0x27,
+                0xC9, 0xDF, 0xC6, 0x23,
+            ],
+            &[
+                0x5F, 0x40, 0x7F, 0x8D, 0x2C, 0xA3, 0xC2, 0x3F, 0x02, 0x95, 0x97, 0xB5, 0x0D, 0x93,
+                0xFE, 0x3B, 0xFB, 0xC9, 0x39, 0xC6, 0xDB, 0x87, 0x36, 0xAB, 0x91, 0xAD, 0x75, 0x3A,
+                0x7A, 0xFE, 0x60, 0x2E,
+            ],
+        ],
+        [
+            &[
+                0x1E, 0x03, 0xCB, 0xE0, 0x61, 0xC0, 0x5A, 0xD6, 0x1D, 0xB2, 0x70, 0x55, 0x14, 0x3A,
+                0x38, 0x00, 0xEC, 0xD7, 0x73, 0x05, 0x2F, 0xBA, 0x32, 0xF3, 0x1C, 0xA5, 0x35, 0xCA,
+                0xE8, 0xDF, 0xBC, 0x2B,
+            ],
+            &[
+                0xA1, 0x62, 0x6F, 0x64, 0x6A, 0xED, 0xBC, 0xC1, 0xFA, 0x6A, 0x93, 0xC2, 0xEE, 0x15,
+                0xD7, 0x21, 0x12, 0xE1, 0xA1, 0x1A, 0x58, 0xAB, 0xEE, 0xB7, 0x65, 0x0A, 0x5D, 0xA7,
+                0xF7, 0x09, 0x4F, 0x1B,
+            ],
+            &[
+                0x96, 0x2D, 0x6E, 0x2A, 0x2D, 0xDA, 0xD2, 0x77, 0x54, 0x28, 0x53, 0xB2, 0x2B, 0x03,
+                0x21, 0x21, 0xEF, 0x31, 0x74, 0xB2, 0x8F, 0xB3, 0xCA, 0x93, 0xA8, 0x3B, 0x0E, 0x4D,
+                0x5B, 0xE1, 0x5E, 0x0E,
+            ],
+            &[
+                0x1B, 0x62, 0x64, 0xBB, 0xCE, 0x18, 0xB7, 0xE7, 0x66, 0x77, 0x75, 0xA8, 0xCE, 0x62,
+                0x47, 0xA3, 0x46, 0xC6, 0x93, 0x22, 0x6C, 0x95, 0xEF, 0x12, 0xF8, 0xC4, 0x6A, 0x89,
+                0xB7, 0xFD, 0xCC, 0x00,
+            ],
+        ],
+        [
+            &[
+                0x19, 0x40, 0x99, 0x3F, 0x00, 0x7D, 0x0C, 0x68, 0xD3, 0xA6, 0x3D, 0x5A, 0x21, 0xA0,
+                0xAC, 0x5D, 0x87, 0x94, 0x61, 0x69, 0x53, 0x08, 0x2D, 0xDD, 0x64, 0xD1, 0x35, 0x82,
+                0x3E, 0x51, 0xBD, 0x24,
+            ],
+            &[
+                0x56, 0x23, 0x9A, 0xE6, 0x4E, 0x28, 0xA9, 0x56, 0xE9, 0x9A, 0x51, 0xCA, 0x49, 0xA0,
+                0x6C, 0x20, 0x8C, 0x97, 0x5E, 0xCC, 0x7F, 0xEB, 0xB4, 0x09, 0xAD, 0xE5, 0x1E, 0xAF,
+                0x10, 0x99, 0xC4, 0x0D,
+            ],
+            &[
+                0xEB, 0x15, 0xF3, 0xF5, 0x86, 0xAE, 0x10, 0x9C, 0xA2, 0x41, 0x9A, 0x33, 0x50, 0xBD,
+                0x6C, 0xE2, 0xBA, 0x27, 0xCB, 0x77, 0xF5, 0xB8, 0x25, 0xA5, 0x2E, 0xDD, 0xE0, 0x97,
+                0xB6, 0xDE, 0x85, 0x2D,
+            ],
+            &[
+                0x12, 0x22, 0xB2, 0x4C, 0x20, 0xF0, 0x84, 0x83, 0x38, 0x34, 0xA1, 0xDB, 0x6A, 0x32,
+                0xE5, 0xBB, 0xB8, 0x89, 0x0C, 0x7B, 0xA6, 0xC7, 0x68, 0x17, 0x0F, 0x15, 0x8B, 0xB2,
+                0xCD, 0x31, 0xA5, 0x11,
+            ],
+        ],
+        [
+            &[
+                0x6A, 0x06, 0x66, 0x12, 0x44, 0xE7, 0x72, 0xD4, 0xC1, 0x8B, 0x87, 0xA9, 0xC9, 0x7A,
+                0x24, 0xB3, 0xD4, 0x59, 0x87, 0x02, 0x24, 0xCB, 0x7C, 0x21, 0xF1, 0xE5, 0xCB, 0x3D,
+                0x93, 0xF1, 0x02, 0x0D,
+            ],
+            &[
+                0x69, 0xFB, 0xEF, 0xAB, 0xE7, 0xD6, 0xBF, 0x16, 0xFC, 0x8A, 0x38, 0x07, 0xFA, 0xC6,
+                0x44, 0x00, 0x77, 0xEB, 0x0B, 0x5C, 0xF6, 0xD2, 0x6F, 0xF5, 0xA1, 0x69, 0x78, 0x11,
+                0xF2, 0xD1, 0x71, 0x0F,
+            ],
+            &[
+                0xA6, 0x18, 0x60, 0x4E, 0x69, 0x24, 0xE6, 0x34, 0x56, 0x20, 0xB0, 0x60, 0xCC, 0x83,
+                0xBE, 0x76, 0xD5, 0xE6, 0xAC, 0xD3, 0x84, 0x7A, 0x9C, 0x2F, 0xD8, 0xE5, 0x90, 0x16,
+                0x32, 0xFA, 0x93, 0x35,
+            ],
+            &[
+                0x2B, 0x80, 0xC1, 0x33, 0xE5, 0xA4, 0xCA, 0x86, 0x75, 0x29, 0xF2, 0xAE, 0xBC, 0x06,
+                0xC0, 0xD6, 0xBD, 0x6D, 0xF2, 0xB2, 0xD3, 0x19, 0x3F, 0x04, 0x3C, 0xE3, 0xED, 0xAB,
+                0x84, 0x1A, 0x6F, 0x19,
+            ],
+        ],
+        [
+            &[
+                0x8A, 0x81, 0xAC, 0xD9, 0x72, 0x14, 0x6B, 0x9D, 0x0B, 0xAC, 0x34, 0xFE, 0xCE, 0xB7,
+                0xB5, 0xC4, 0x6F, 0xE8, 0x29, 0x50, 0xEC, 0xE2, 0x06, 0x65, 0x0C, 0xCE, 0xE7, 0xE1,
+                0xF3, 0x23, 0x95, 0x2B,
+            ],
+            &[
+                0xFF, 0xFD, 0xFC, 0xEB, 0xDC, 0x43, 0xF8, 0xFF, 0x15, 0xC1, 0xDB, 0xAC, 0xD4, 0xB3,
+                0xFE, 0x6D, 0xBE, 0xF8, 0x66, 0x46, 0x08, 0x62, 0x00, 0xC9, 0xBA, 0x44, 0x26, 0xCD,
+                0x7F, 0x7B, 0xF4, 0x1D,
+            ],
+            &[
+                0x72, 0xA6, 0xD7, 0x20, 0x8C, 0x0E, 0x63, 0xF6, 0x92, 0xA5, 0xE7, 0x2E, 0xB2, 0xC3,
+                0x6C, 0x7F, 0x26, 0x47, 0x96, 0x07, 0xA9, 0x35, 0xDB, 0x25, 0x31, 0x1F, 0x9E, 0xC1,
+                0x3F, 0x0E, 0x58, 0x00,
+            ],
+            &[
+                0x5B, 0xF0, 0x90, 0x60, 0x66, 0xFF, 0x4F, 0x17, 0x5F, 0x26, 0x0F, 0x0A, 0x69, 0xFD,
+                0xC4, 0xC6, 0x8B, 0x46, 0x64, 0x6E, 0x0C, 0x42, 0x34, 0x37, 0xDD, 0xA4, 0x08, 0xFD,
+                0xEC, 0x15, 0x0E, 0x34,
+            ],
+        ],
+    ];
+
+    // The permutation round keys are the key schedule of the zero key, see
+    // `PRP::key_schedule()` and the unit test test_round_keys()
+    const PERMUTATION_ROUND_KEYS: [[&'static [u8]; 4]; 25] = [
+        [
+            &[
+                0x3A, 0xB3, 0x26, 0x95, 0xA5, 0xD0, 0x67, 0x81, 0x9A, 0x90, 0x00, 0xA5, 0x80, 0xBB,
+                0x55, 0x5B, 0x66, 0x46, 0x5A, 0xFB, 0xF0, 0xE1, 0x97, 0xA0, 0x50, 0xD5, 0x32, 0x21,
+                0x20, 0x6F, 0x33, 0x38,
+            ],
+            &[
+                0xF8, 0xD6, 0xCA, 0xCB, 0xB7, 0x1D, 0xC9, 0xA9, 0x41, 0xF9, 0x16, 0x30, 0xCF, 0xC5,
+                0xEB, 0x1B, 0xE3, 0x21, 0xAC, 0x7D, 0x33, 0xB6, 0x5B, 0xE1, 0x42, 0x09, 0xDD, 0x92,
+                0x42, 0x95, 0x8C, 0x18,
+            ],
+            &[
+                0x41, 0x19, 0xB3, 0x1E, 0x90, 0x2F, 0xDC, 0x6D, 0x06, 0x43, 0x2A, 0x8B, 0x88, 0xFB,
+                0x7A, 0xEC, 0x89, 0x5C, 0xCB, 0xC2, 0x6E, 0x2C, 0xCB, 0xE5, 0xC2, 0x5B, 0x0D, 0x09,
+                0xFD, 0x08, 0xB5, 0x2F,
+            ],
+            &[
+                0x4F, 0x22, 0x5B, 0xE0, 0x0E, 0x9A, 0x78, 0xC6, 0x4F, 0xA6, 0x8B, 0x33, 0xF1, 0xAB,
+                0x1B, 0x13, 0x05, 0x05, 0x1C, 0xA3, 0xC2, 0x6E, 0x93, 0x4A, 0xA4, 0xFD, 0xC6, 0x26,
+                0xB3, 0x2C, 0x50, 0x0A,
+            ],
+        ],
+        [
+            &[
+                0x8B, 0x7B, 0x69, 0xE1, 0x4A, 0xAB, 0x91, 0xA1, 0x93, 0xE6, 0xA4, 0x61, 0x5D, 0x8A,
+                0x80, 0x1B, 0xB0, 0xC5, 0x71, 0x9A, 0x65, 0x6C, 0x98, 0xA8, 0x07, 0x44, 0x06, 0xA5,
+                0xF2, 0x84, 0xC5, 0x11,
+            ],
+            &[
+                0xC2, 0x3D, 0x0A, 0x8D, 0x9E, 0x64, 0x6B, 0x08, 0x49, 0x56, 0xEF, 0x0F, 0xD9, 0xC7,
+                0x3C, 0x54, 0x35, 0x89, 0xF0, 0x6D, 0xF7, 0x83, 0x77, 0x7A, 0x02, 0x18, 0x9C, 0x4C,
+                0xE8, 0x43, 0xB7, 0x02,
+            ],
+            &[
+                0x9A, 0xB6, 0xB3, 0x2B, 0xC7, 0xD6, 0xC0, 0x0F, 0xA4, 0xEB, 0xD5, 0x47, 0xE2, 0x0A,
+                0x50, 0x8B, 0xC3, 0xBB, 0x91, 0x67, 0xF6, 0xA2, 0x87, 0x0C, 0xFA, 0xDE, 0x98, 0x1A,
+                0xC0, 0x03, 0x40, 0x2B,
+            ],
+            &[
+                0xEF, 0x25, 0x5E, 0x8A, 0x11, 0x03, 0x34, 0x6B, 0xB3, 0xB1, 0x58, 0x33, 0x1C, 0xDB,
+                0xAE, 0x42, 0x43, 0x95, 0x61, 0xDB, 0xAD, 0x4F, 0xB4, 0xCB, 0xEE, 0xD6, 0x14, 0x3F,
+                0x9A, 0xDF, 0xCD, 0x09,
+            ],
+        ],
+        [
+            &[
+                0xAD, 0xCB, 0xDC, 0xCD, 0xBC, 0xE5, 0x06, 0x24, 0x9A, 0x88, 0x31, 0x3E, 0x8A, 0xB7,
+                0x9B, 0x80, 0xCA, 0xEB, 0x2C, 0xF8, 0xB0, 0xFC, 0xB3, 0x9F, 0x4E, 0x54, 0xC1, 0xA6,
+                0x77, 0x7A, 0xE6, 0x37,
+            ],
+            &[
+                0x24, 0x63, 0x35, 0x90, 0x77, 0x0C, 0x84, 0x1A, 0x62, 0x96, 0x0C, 0x5C, 0xD6, 0x41,
+                0xA5, 0xDB, 0x22, 0x6D, 0x3C, 0x77, 0x87, 0xC8, 0x20, 0x38, 0xF9, 0x0B, 0x4E, 0x64,
+                0xE5, 0xE9, 0xD5, 0x27,
+            ],
+            &[
+                0xA3, 0x91, 0x33, 0xAB, 0x94, 0x72, 0x00, 0x2D, 0x6F, 0xCD, 0x9B, 0x4A, 0xCA, 0x03,
+                0x69, 0x9F, 0x05, 0x0E, 0x63, 0xB2, 0xD4, 0xCB, 0x68, 0x04, 0xFB, 0xD1, 0xF0, 0xC4,
+                0x17, 0x14, 0x2A, 0x0D,
+            ],
+            &[
+                0xD5, 0xC8, 0xA9, 0x02, 0xF2, 0xC5, 0x9B, 0x0B, 0x1E, 0x5C, 0x0F, 0x86, 0x12, 0xD7,
+                0xCD, 0xA6, 0x32, 0x74, 0x74, 0x8B, 0x32, 0x81, 0x10, 0xA8, 0x7D, 0x66, 0xC1, 0xC8,
+                0xF0, 0xB8, 0x97, 0x3F,
+            ],
+        ],
+        [
+            &[
+                0x94, 0xA4, 0x34, 0x29, 0x8C, 0x25, 0x40, 0x8F, 0xFB, 0xE4, 0x03, 0x78, 0x78, 0x92,
+                0xC6, 0x1E, 0xFB, 0x64, 0xBC, 0x74, 0xF8, 0xA2, 0x3A, 0x96, 0x8D, 0xC1, 0x87, 0x14,
+                0x3D, 0xB1, 0xA7, 0x2A,
+            ],
+            &[
+                0x97, 0xC1, 0xE0, 0xCD, 0x27, 0xDD, 0xA6, 0x67, 0x55, 0x98, 0x3A, 0x3E, 0x41, 0x9E,
+                0x99, 0x94, 0x01, 0x54, 0x3B, 0xE0, 0xC1, 0x43, 0x92, 0xEF, 0xC3, 0x18, 0xEF, 0x9F,
+                0xA6, 0x3E, 0xE6, 0x0A,
+            ],
+            &[
+                0x3A, 0xEC, 0x89, 0xCA, 0x59, 0x60, 0xB6, 0x2D, 0x66, 0x75, 0x29, 0x24, 0x6C, 0x32,
+                0x25, 0x6F, 0x6F, 0xD3, 0x84, 0x26, 0xBE, 0x06, 0xDC, 0x86, 0xB6, 0xC5, 0x51, 0xB7,
+                0x44, 0x3D, 0x55, 0x19,
+            ],
+            &[
+                0xDF, 0x96, 0x24, 0x04, 0x0C, 0x96, 0x5F, 0xEA, 0x40, 0xDB, 0x7A, 0x3D, 0xEA, 0xAC,
+                0x37, 0x09, 0x00, 0xA7, 0xC7, 0x1F, 0x12, 0x35, 0xD2, 0x39, 0xEC, 0xE1, 0xC2, 0x66,
+                0x09, 0x82, 0xBE, 0x0F,
+            ],
+        ],
+        [
+            &[
+                0xD8, 0x7A, 0x1B, 0x41, 0x75, 0xF5, 0xD3, 0xD2, 0xF8, 0x0F, 0xB1, 0x89, 0xE3, 0xFB,
+                0xAB, 0xD2, 0xD8, 0x0D, 0x78, 0x88, 0xAA, 0xB1, 0xF7, 0x44, 0x89, 0x2E, 0xCC, 0x0F,
+                0xA7, 0x20, 0xF4, 0x28,
+            ],
+            &[
+                0x2F, 0x91, 0x00, 0x83, 0x37, 0xE2, 0x4A, 0x23, 0x91, 0xA0, 0x4F, 0xDB, 0x8C, 0x9C,
+                0xD8, 0x0D, 0x44, 0x4E, 0x52, 0xD4, 0x46, 0x95, 0xD7, 0xE7, 0xC8, 0x51, 0x5E, 0x84,
+                0x93, 0x10, 0x8B, 0x17,
+            ],
+            &[
+                0xC2, 0xE0, 0xDE, 0x0B, 0xC4, 0x98, 0xFE, 0xDC, 0x9B, 0xC0, 0xA8, 0x00, 0x2D, 0x6A,
+                0xEF, 0x5E, 0x75, 0xB0, 0xE4, 0xCF, 0xBC, 0x37, 0x0B, 0x76, 0xF0, 0x9D, 0x97, 0xF8,
+                0x3A, 0x6B, 0x1B, 0x11,
+            ],
+            &[
+                0x32, 0x19, 0x6F, 0x5B, 0xFF, 0x24, 0x4F, 0x5B, 0x32, 0xCB, 0x2A, 0xE1, 0x1C, 0x69,
+                0xF7, 0xF8, 0x26, 0x94, 0x73, 0x62, 0x9B, 0xE9, 0x95, 0x18, 0x68, 0xA5, 0x0A, 0xB4,
+                0xD8, 0xC0, 0xAB, 0x34,
+            ],
+        ],
+        [
+            &[
+                0xB2, 0x92, 0xFB, 0x2A, 0xE8, 0x4D, 0x0E, 0x9D, 0xAA, 0xFF, 0x8B, 0xF4, 0xB8, 0x71,
+                0x4E, 0x82, 0x4D, 0xDE, 0x1E, 0x5A, 0x23, 0x19, 0x0E, 0x3D, 0x1B, 0x74, 0x4B, 0xB3,
+                0x61, 0xA0, 0x99, 0x16,
+            ],
+            &[
+                0x4B, 0xAB, 0x8C, 0x5D, 0xD9, 0x67, 0x23, 0x4F, 0x9B, 0xF5, 0x2D, 0x28, 0x5F, 0x44,
+                0x67, 0x0D, 0x55, 0xAD, 0x8F, 0x48, 0xE9, 0xCA, 0x09, 0x01, 0xD5, 0xC6, 0x91, 0x40,
+                0x3A, 0x72, 0x8B, 0x0D,
+            ],
+            &[
+                0xB8, 0xD3, 0x1D, 0x6D, 0x2E, 0xFD, 0xC1, 0x11, 0x3C, 0x80, 0x62, 0x17, 0x18, 0xB0,
+                0x4E, 0x03, 0x28, 0x93, 0x23, 0xE8, 0x9E, 0xB9, 0xD6, 0xF3, 0x35, 0xF2, 0x58, 0x34,
+                0x5D, 0x64, 0xB1, 0x12,
+            ],
+            &[
+                0x76, 0xB7, 0x08, 0x1A, 0xBF, 0xA5, 0xE4, 0xB3, 0x82, 0x21, 0xA6, 0xB4, 0xB9, 0x11,
+                0xAF, 0x15, 0x3A, 0x81, 0xAE, 0xCC, 0x55, 0xD2, 0xCC, 0xA2, 0xDD, 0x16, 0xA3, 0xA6,
+                0x1E, 0x35, 0x84, 0x30,
+            ],
+        ],
+        [
+            &[
+                0xCB, 0xC7, 0x55, 0x79, 0x7E, 0x6C, 0x3B, 0x71, 0x2E, 0x68, 0x3D, 0xBA, 0xEB, 0xC2,
+                0xF4, 0x12, 0xB9, 0xBA, 0xE0, 0xF0, 0x61, 0x08, 0x06, 0xB6, 0x38, 0x1A, 0xF1, 0x5B,
+                0xAF, 0xD8, 0x22, 0x08,
+            ],
+            &[
+                0x0A, 0x75, 0xEF, 0x02, 0xE0, 0xF4, 0xF5, 0x92, 0xD6, 0x7A, 0xD6, 0x5D, 0x86, 0x3F,
+                0xA8, 0xE0, 0xDA, 0x44, 0xB7, 0x17, 0x16, 0xDA, 0x36, 0x66, 0xD0, 0xB8, 0x25, 0x33,
+                0x77, 0x6D, 0x72, 0x29,
+            ],
+            &[
+                0xA5, 0x6F, 0x5B, 0xA7, 0x55, 0x17, 0xCD, 0x3F, 0xEB, 0x82, 0xA5, 0xA8, 0xB3, 0xA3,
+                0x48, 0xED, 0x23, 0xA0, 0x0B, 0x6B, 0x50, 0xFC, 0x16, 0x37, 0xF5, 0x2F, 0x3C, 0x93,
+                0x57, 0x90, 0x6F, 0x02,
+            ],
+            &[
+                0xF8, 0x28, 0x5F, 0x17, 0x4F, 0xF0, 0xF6, 0xFF, 0x59, 0xD0, 0xDD, 0xA3, 0xE1, 0x51,
+                0x41, 0x85, 0xA1, 0xC6, 0x45, 0xA6, 0xA9, 0x76, 0x8E, 0x6E, 0xEF, 0xF2, 0x93, 0xFD,
+                0xA9, 0xD8, 0xE0, 0x1D,
+            ],
+        ],
+        [
+            &[
+                0x8D, 0x58, 0x3A, 0x92, 0xE1, 0x56, 0x5E, 0x8E, 0x64, 0xCE, 0x76, 0xEA, 0x49, 0x59,
+                0xB6, 0x24, 0x4E, 0xA4, 0x78, 0x01, 0x1C, 0xF1, 0x25, 0x89, 0xD0, 0xAD, 0xD0, 0x1E,
+                0x2A, 0xD8, 0x02, 0x24,
+            ],
+            &[
+                0x8D, 0xF2, 0x0C, 0xDC, 0x50, 0xB9, 0x14, 0x75, 0x6A, 0x77, 0x82, 0xBA, 0x27, 0x94,
+                0x14, 0x04, 0x2A, 0xC0, 0x3C, 0x57, 0x46, 0x35, 0x4C, 0xA8, 0xF3, 0x36, 0x97, 0x89,
+                0x4E, 0xF8, 0x5A, 0x09,
+            ],
+            &[
+                0x05, 0x14, 0x85, 0x4A, 0x63, 0x65, 0x50, 0xE7, 0x0B, 0x02, 0x56, 0x57, 0x4C, 0xC0,
+                0xF3, 0xF7, 0x84, 0x9F, 0x17, 0xE6, 0x6F, 0xFB, 0x5E, 0x57, 0x79, 0x18, 0x89, 0x12,
+                0x8C, 0x59, 0x51, 0x37,
+            ],
+            &[
+                0xE8, 0xFE, 0xDB, 0xAD, 0x1C, 0xFA, 0xEC, 0xBB, 0x8D, 0x61, 0x88, 0xE6, 0xB5, 0x9C,
+                0x64, 0x97, 0xB5, 0xCF, 0xED, 0x43, 0xAE, 0xA5, 0x71, 0xCB, 0x82, 0xD5, 0x80, 0xF3,
+                0xF9, 0x3F, 0x38, 0x04,
+            ],
+        ],
+        [
+            &[
+                0xDD, 0x60, 0x9F, 0x89, 0x74, 0xB4, 0xC3, 0x80, 0x32, 0x8F, 0x10, 0x35, 0xB5, 0x0E,
+                0xD0, 0xA6, 0xF4, 0x25, 0x50, 0x87, 0xFC, 0x4F, 0x7F, 0x93, 0x17, 0x57, 0x5F, 0xE9,
+                0xE0, 0x1E, 0xD6, 0x1D,
+            ],
+            &[
+                0xD1, 0x7B, 0x11, 0x64, 0x40, 0x71, 0x85, 0x62, 0x02, 0xFF, 0xE6, 0x95, 0xC0, 0x41,
+                0x53, 0xC4, 0xF7, 0x98, 0x53, 0x60, 0x87, 0x8D, 0x2F, 0xC3, 0xB6, 0x07, 0x3A, 0x36,
+                0x0C, 0xF9, 0xC4, 0x12,
+            ],
+            &[
+                0xE6, 0xF9, 0xE4, 0xEC, 0xA0, 0x63, 0x91, 0xD9, 0x97, 0x70, 0x6C, 0xDE, 0x4B, 0x13,
+                0x7D, 0x71, 0x83, 0x2D, 0x80, 0xB3, 0x0B, 0x66, 0x78, 0x53, 0xCF, 0x0E, 0x13, 0x87,
+                0x35, 0x97, 0x8C, 0x1F,
+            ],
+            &[
+                0x45, 0xCC, 0xB0, 0x68, 0x2F, 0x77, 0xA5, 0x04, 0xB3, 0x78, 0xD2, 0x6A, 0x9D, 0x6A,
+                0x7D, 0xB6, 0x24, 0x24, 0x8E, 0xBA, 0xEE, 0xD8, 0xBC, 0x94, 0x66, 0x22, 0xAD, 0xCC,
+                0xB7, 0x7D, 0x12, 0x1D,
+            ],
+        ],
+        [
+            &[
+                0x83, 0xC3, 0x36, 0x66, 0xD2, 0x02, 0x61, 0x7B, 0x13, 0xA1, 0x30, 0x1C, 0x6F, 0xED,
+                0x7C, 0xEA, 0xF9, 0x48, 0x08, 0x53, 0xD4, 0xE8, 0x14, 0xDB, 0x3D, 0xC1, 0x9B, 0x7A,
+                0x5D, 0x66, 0x49, 0x05,
+            ],
+            &[
+                0x00, 0x33, 0xF4, 0x70, 0xC1, 0x87, 0x56, 0x22, 0x9D, 0xEB, 0x40, 0xE0, 0x39, 0x96,
+                0x37, 0x38, 0x50, 0x86, 0x0F, 0x0B, 0xE1, 0x8E, 0x27, 0x22, 0xE5, 0x4B, 0x6C, 0x6D,
+                0x1A, 0x37, 0x14, 0x1D,
+            ],
+            &[
+                0x29, 0x0F, 0x45, 0xF9, 0xB6, 0x46, 0x78, 0x97, 0xA8, 0xF3, 0x11, 0x28, 0x14, 0xDF,
+                0x4E, 0xBF, 0x27, 0x5E, 0x8A, 0x20, 0xC1, 0x27, 0x91, 0xF9, 0xF3, 0x30, 0xE7, 0x4D,
+                0xF1, 0xF7, 0x2A, 0x0F,
+            ],
+            &[
+                0x90, 0xD2, 0xCE, 0x0A, 0x9E, 0x05, 0x60, 0x95, 0xCB, 0x2B, 0x29, 0xDA, 0x49, 0x79,
+                0xD9, 0x20, 0xF9, 0x3B, 0x27, 0x4C, 0x73, 0x7F, 0xD7, 0x10, 0xC9, 0xE3, 0x58, 0x14,
+                0x39, 0x31, 0x86, 0x2C,
+            ],
+        ],
+        [
+            &[
+                0x69, 0x3F, 0x1C, 0xFC, 0x54, 0x8C, 0x53, 0x1A, 0xEB, 0xCE, 0xDC, 0xBB, 0x57, 0x42,
+                0x73, 0xF7, 0x53, 0xE0, 0x78, 0xBE, 0x1F, 0x41, 0xFD, 0x2F, 0x7F, 0x78, 0x21, 0x50,
+                0x44, 0x33, 0x93, 0x24,
+            ],
+            &[
+                0xB2, 0x43, 0xBA, 0x27, 0x22, 0xC8, 0xF3, 0x8E, 0x1F, 0x3D, 0xA0, 0xDC, 0x07, 0x5E,
+                0x2F, 0x48, 0x92, 0xE8, 0xD2, 0x87, 0x51, 0xF3, 0x87, 0xFC, 0xE7, 0x94, 0xCC, 0xD9,
+                0xE8, 0x92, 0x4D, 0x2F,
+            ],
+            &[
+                0x46, 0x3F, 0x71, 0x38, 0x7E, 0xE2, 0xAB, 0x6A, 0xCB, 0x67, 0xD3, 0x30, 0xE4, 0xAE,
+                0x44, 0x5B, 0x55, 0x23, 0xF3, 0x00, 0x58, 0x4E, 0xDB, 0x33, 0x99, 0xB5, 0x25, 0xCA,
+                0x6E, 0xF9, 0x32, 0x20,
+            ],
+            &[
+                0x3B, 0xAD, 0x2A, 0x0A, 0xB1, 0x56, 0x40, 0xA1, 0x58, 0x84, 0xCA, 0xA5, 0x7A, 0x91,
+                0xCD, 0x5D, 0x03, 0xAF, 0x0A, 0x1A, 0x09, 0xC4, 0xC0, 0xD2, 0xB6, 0x9A, 0xCA, 0xA4,
+                0x27, 0x32, 0x09, 0x15,
+            ],
+        ],
+        [
+            &[
+                0x03, 0x54, 0x2D, 0x09, 0xA1, 0xBA, 0x3C, 0x91, 0xB5, 0x8E, 0x4A, 0x2B, 0x85, 0xE2,
+                0x6D, 0x17, 0xD9, 0xAA, 0x65, 0x22, 0x52, 0x11, 0x07, 0x49, 0xCE, 0x3A, 0x7B, 0x9F,
+                0x98, 0xEF, 0xD8, 0x27,
+            ],
+            &[
+                0xEE, 0x8E, 0xF7, 0xE1, 0xB3, 0x10, 0x37, 0x6C, 0x74, 0x3C, 0x05, 0x1F, 0x37, 0xA9,
+                0xE5, 0x1D, 0xDC, 0x4D, 0xE4, 0xBD, 0x0C, 0xBC, 0x4C, 0x80, 0xC7, 0x60, 0xB7, 0x08,
+                0x2E, 0xC3, 0x6B, 0x2D,
+            ],
+            &[
+                0xE8, 0x05, 0xCF, 0xD6, 0x2D, 0xAE, 0x24, 0x2E, 0xFF, 0xB4, 0x18, 0xC3, 0xB7, 0xC1,
+                0x5A, 0x58, 0xCA, 0x51, 0xE0, 0x88, 0xF1, 0x04, 0x25, 0xD1, 0x27, 0xAA, 0x55, 0xA1,
+                0x49, 0x1A, 0x37, 0x28,
+            ],
+            &[
+                0xE4, 0x6A, 0x0A, 0xBE, 0x36, 0xD4, 0xF9, 0x09, 0x80, 0x78, 0x81, 0x01, 0x6A, 0x13,
+                0x9C, 0x1A, 0x67, 0x09, 0xF9, 0x5E, 0xA1, 0xAD, 0x5E, 0x57, 0x30, 0x50, 0x6D, 0x5E,
+                0xD8, 0x4C, 0x7A, 0x36,
+            ],
+        ],
+        [
+            &[
+                0x96, 0x8A, 0x7B, 0x13, 0xF6, 0xB0, 0x5A, 0x87, 0x4A, 0x9B, 0xCC, 0x03, 0x14, 0x09,
+                0x3C, 0xF7, 0xFD, 0x64, 0xDA, 0xEC, 0xCC, 0xB2, 0x22, 0xC5, 0xDC, 0xF2, 0x37, 0xA7,
+                0x46, 0x38, 0x07, 0x1C,
+            ],
+            &[
+                0xEE, 0xE7, 0xF5, 0xF2, 0x01, 0xA3, 0x54, 0x73, 0x0B, 0x05, 0x2A, 0xAE, 0x88, 0x69,
+                0xB6, 0xBC, 0x68, 0x9E, 0x8A, 0xC1, 0x11, 0x6D, 0xBB, 0xB1, 0x24, 0xB8, 0x81, 0x66,
+                0x94, 0x81, 0x3D, 0x3A,
+            ],
+            &[
+                0x67, 0x38, 0x9D, 0x42, 0x25, 0xC9, 0xE4, 0xF5, 0xEB, 0x4B, 0x9A, 0xA4, 0x2D, 0x90,
+                0x9C, 0xE4, 0x41, 0x05, 0xE6, 0x1C, 0x38, 0xAA, 0x48, 0x14, 0x5F, 0xFA, 0x1B, 0xDA,
+                0x27, 0x18, 0x53, 0x0D,
+            ],
+            &[
+                0x11, 0x1D, 0xDB, 0x02, 0x28, 0xC4, 0x5C, 0xD0, 0x64, 0xC7, 0x8F, 0xD3, 0xBE, 0xCD,
+                0x29, 0x9A, 0x90, 0x05, 0xC9, 0x59, 0xCC, 0x66, 0xCF, 0x02, 0xA6, 0xA1, 0xA2, 0x49,
+                0x0F, 0x84, 0xA1, 0x02,
+            ],
+        ],
+        [
+            &[
+                0xDA, 0xE5, 0x2B, 0xFA, 0xEF, 0x2C, 0xBF, 0xD6, 0x88, 0x5B, 0x36, 0x50, 0xB4, 0x87,
+                0x06, 0x33, 0x80, 0xF9, 0xEF, 0x6C, 0x53, 0xB4, 0x6E, 0x75, 0x86, 0x81, 0x53, 0x56,
+                0x49, 0x75, 0x85, 0x12,
+            ],
+            &[
+                0x04, 0x0D, 0x53, 0x1A, 0x63, 0xEF, 0x58, 0xFF, 0xF4, 0x58, 0x64, 0x47, 0xF0, 0x6A,
+                0xD4, 0x41, 0xEF, 0xB4, 0x8A, 0x39, 0x0C, 0x3D, 0x75, 0x53, 0xEC, 0xFC, 0x3B, 0x87,
+                0xF7, 0x6E, 0x50, 0x38,
+            ],
+            &[
+                0xC3, 0xF4, 0xAC, 0xA7, 0x9B, 0x97, 0xF8, 0xAF, 0x58, 0xC6, 0x98, 0x83, 0x02, 0x90,
+                0x73, 0x95, 0x96, 0xD0, 0x8D, 0x38, 0xBB, 0xCE, 0xDB, 0xA5, 0x6D, 0x2F, 0x52, 0xB0,
+                0xD3, 0x66, 0xAF, 0x02,
+            ],
+            &[
+                0x5C, 0xB3, 0x31, 0xB3, 0x6D, 0x50, 0xF9, 0x3C, 0xEF, 0x2E, 0x50, 0xCC, 0x99, 0x36,
+                0x79, 0x48, 0x81, 0xD4, 0x4A, 0xB9, 0x59, 0x5B, 0x9A, 0xDF, 0x04, 0x32, 0xF9, 0x46,
+                0xE6, 0x01, 0x04, 0x2F,
+            ],
+        ],
+        [
+            &[
+                0x5B, 0x89, 0xAE, 0x30, 0x56, 0xAB, 0x40, 0xFE, 0x6A, 0x63, 0x04, 0x77, 0x86, 0xE7,
+                0x03, 0x45, 0xA5, 0xC8, 0x77, 0xCD, 0x54, 0x0A, 0x57, 0x99, 0xB0, 0xA8, 0x9B, 0xE2,
+                0x55, 0xF6, 0x47, 0x0F,
+            ],
+            &[
+                0xEE, 0xE4, 0x99, 0xEE, 0x57, 0x2C, 0x98, 0x32, 0x25, 0xEE, 0x9D, 0x9C, 0x16, 0x33,
+                0x2A, 0xDF, 0x48, 0x1D, 0x0A, 0x42, 0x7F, 0xBB, 0x99, 0xCC, 0x00, 0x0E, 0x17, 0xC1,
+                0xB1, 0x0D, 0x51, 0x15,
+            ],
+            &[
+                0xB1, 0x22, 0xC5, 0xEB, 0xC8, 0x32, 0x5D, 0xC8, 0xD2, 0x80, 0x4B, 0x0E, 0x61, 0x91,
+                0xD3, 0xDF, 0x89, 0x42, 0xA5, 0xAE, 0x73, 0xAB, 0x85, 0x6B, 0x81, 0xF7, 0x7B, 0xF9,
+                0xA6, 0x1A, 0x57, 0x13,
+            ],
+            &[
+                0x71, 0x31, 0xE2, 0x33, 0x07, 0x18, 0x66, 0xC6, 0xA5, 0xF9, 0x8D, 0x34, 0xC2, 0xBF,
+                0xE9, 0x1A, 0xC8, 0x26, 0x17, 0x14, 0x02, 0x73, 0x01, 0x16, 0xAC, 0x8D, 0xAE, 0xD8,
+                0xA2, 0xE9, 0xB8, 0x17,
+            ],
+        ],
+        [
+            &[
+                0xB7, 0x23, 0xF7, 0x28, 0x4C, 0xFD, 0x5C, 0xFE, 0x6C, 0x60, 0xEE, 0x44, 0xEC, 0x2D,
+                0xD8, 0xCC, 0x8A, 0x3A, 0x27, 0x33, 0xA3, 0x28, 0x58, 0x1C, 0xCE, 0xD9, 0x63, 0xB9,
+                0xA7, 0x0F, 0x05, 0x3E,
+            ],
+            &[
+                0x28, 0x92, 0x3C, 0xCD, 0x1B, 0x6E, 0xFE, 0xA4, 0x93, 0x33, 0x92, 0x20, 0x38, 0x3C,
+                0x92, 0x3D, 0x4C, 0x50, 0xD5, 0x68, 0x7C, 0x60, 0x3E, 0x52, 0x1A, 0xBA, 0x22, 0x89,
+                0x25, 0xB9, 0x7B, 0x3C,
+            ],
+            &[
+                0xE6, 0xDD, 0xD7, 0x4A, 0xAE, 0xA8, 0xFF, 0x8A, 0x69, 0x18, 0x98, 0xB9, 0xAB, 0x55,
+                0x04, 0xBF, 0x3A, 0x0C, 0x87, 0x3A, 0xCA, 0x69, 0x86, 0x56, 0x82, 0x29, 0x4D, 0xEA,
+                0x9B, 0x41, 0x86, 0x3C,
+            ],
+            &[
+                0x38, 0x93, 0xA3, 0x2E, 0xD2, 0xF7, 0x1E, 0x42, 0x8E, 0x3E, 0x55, 0x17, 0xA7, 0x89,
+                0x52, 0xFD, 0x96, 0x03, 0x60, 0xC2, 0xA5, 0x66, 0xFD, 0x28, 0x21, 0xAF, 0xBF, 0x94,
+                0x5C, 0x5A, 0xB0, 0x07,
+            ],
+        ],
+        [
+            &[
+                0x84, 0xF4, 0x38, 0xA3, 0xD4, 0xCD, 0x92, 0x78, 0x55, 0x4F, 0xD3, 0xDF, 0x5E, 0xC2,
+                0x3F, 0x17, 0x75, 0xC6, 0xD8, 0x3D, 0x96, 0x55, 0xBB, 0x80, 0xBA, 0x33, 0x35, 0xB6,
+                0xBA, 0x03, 0x79, 0x02,
+            ],
+            &[
+                0x80, 0x6C, 0xC5, 0x8F, 0x9A, 0xFF, 0x54, 0xDF, 0x8B, 0xAB, 0x38, 0xCF, 0x0E, 0x73,
+                0x45, 0xED, 0x8F, 0x51, 0xC6, 0x9D, 0xB1, 0x11, 0xA2, 0x9B, 0xBA, 0xBD, 0xFB, 0x25,
+                0x2A, 0x29, 0xF7, 0x01,
+            ],
+            &[
+                0xF8, 0x8F, 0xAA, 0x29, 0x92, 0x74, 0x43, 0x1A, 0xFA, 0x0D, 0xE3, 0xCE, 0xCF, 0x92,
+                0x7A, 0xEF, 0xC1, 0x33, 0x14, 0xC6, 0x38, 0xB8, 0x01, 0x92, 0x75, 0x45, 0xBE, 0x4D,
+                0x6E, 0x56, 0xB6, 0x27,
+            ],
+            &[
+                0xDA, 0x3F, 0xDF, 0xAE, 0xE4, 0x28, 0x41, 0x4E, 0x53, 0x2D, 0x11, 0xFD, 0x4A, 0xE7,
+                0x3F, 0xBB, 0xEB, 0xF2, 0x5A, 0x28, 0x77, 0x61, 0x47, 0x4E, 0xCC, 0xEE, 0xED, 0xFE,
+                0x5F, 0x67, 0x8B, 0x29,
+            ],
+        ],
+        [
+            &[
+                0x0F, 0xFC, 0xC0, 0xA2, 0xB4, 0x1E, 0xFD, 0xBE, 0xB8, 0x04, 0x32, 0xAD, 0xFD, 0xA4,
+                0xAE, 0xAE, 0xFF, 0xBC, 0x3D, 0x04, 0x78, 0x33, 0xD0, 0xEE, 0x8C, 0x46, 0x9C, 0xE7,
+                0x87, 0xB4, 0x81, 0x24,
+            ],
+            &[
+                0x0B, 0xAF, 0xCB, 0x45, 0xB0, 0x46, 0xD1, 0x2E, 0x73, 0x25, 0xB3, 0x33, 0x5A, 0x17,
+                0x58, 0xD5, 0x89, 0x36, 0x53, 0x52, 0x8C, 0x45, 0x7E, 0x45, 0xDD, 0x48, 0xB1, 0x76,
+                0x06, 0xAF, 0xBE, 0x3D,
+            ],
+            &[
+                0x4D, 0x19, 0x4A, 0xE9, 0xCE, 0xBF, 0x46, 0x96, 0x97, 0xB4, 0x81, 0x60, 0xAD, 0x81,
+                0x0A, 0x28, 0xE1, 0x70, 0x1B, 0xF1, 0xF0, 0x9E, 0xBA, 0x0E, 0xC3, 0x0F, 0xB5, 0x87,
+                0x04, 0xF3, 0x0B, 0x23,
+            ],
+            &[
+                0xBA, 0xBB, 0xE5, 0x6F, 0x0D, 0x46, 0xC9, 0x93, 0xC4, 0xEC, 0xDF, 0xC0, 0xEB, 0x9A,
+                0x8D, 0xA1, 0xAA, 0xB1, 0xA8, 0xE7, 0x0A, 0xE6, 0xBC, 0x85, 0xF2, 0x81, 0x68, 0x68,
+                0x84, 0x8B, 0x11, 0x31,
+            ],
+        ],
+        [
+            &[
+                0x46, 0xA4, 0xA0, 0x3B, 0x07, 0xDF, 0x2C, 0x71, 0x31, 0xB0, 0xD1, 0xE9, 0x3D, 0xFD,
+                0x0C, 0xCE, 0x49, 0x73, 0xD5, 0xDD, 0x7D, 0x26, 0xC6, 0xA1, 0x26, 0xD2, 0xBA, 0xBE,
+                0xFA, 0x76, 0xF9, 0x03,
+            ],
+            &[
+                0xC3, 0x40, 0xF7, 0x33, 0xE2, 0xEB, 0xA4, 0x82, 0xA0, 0xEB, 0xEA, 0xAF, 0xDE, 0x34,
+                0x57, 0x60, 0x1E, 0x99, 0x25, 0xD2, 0x94, 0xB0, 0x08, 0xBF, 0xBD, 0xD2, 0x99, 0x32,
+                0xB2, 0xBD, 0x76, 0x07,
+            ],
+            &[
+                0x30, 0x49, 0x69, 0x80, 0x56, 0x4E, 0x3E, 0x86, 0x99, 0x8A, 0x2D, 0x13, 0xDF, 0xA6,
+                0x66, 0x71, 0xE0, 0x53, 0x22, 0x46, 0xAD, 0xE3, 0x4A, 0xBE, 0xB7, 0x07, 0x2E, 0xAB,
+                0x6A, 0x00, 0xD8, 0x13,
+            ],
+            &[
+                0x7A, 0x13, 0x23, 0x7F, 0xA7, 0x3B, 0xF7, 0xCC, 0x1C, 0x3A, 0x2F, 0xFB, 0x96, 0x8D,
+                0x28, 0x3D, 0x9A, 0xD8, 0x3A, 0x7E, 0xBC, 0xD7, 0xE7, 0x14, 0x26, 0x33, 0xCC, 0xE0,
+                0xC5, 0x75, 0x4F, 0x09,
+            ],
+        ],
+        [
+            &[
+                0x02, 0x7F, 0x23, 0xDF, 0x20, 0xA9, 0xDD, 0xFA, 0xB3, 0xF7, 0x2E, 0x40, 0xBA, 0x91,
+                0x25, 0xF7, 0x06, 0xEE, 0x2B, 0xAA, 0x23, 0x5C, 0xE4, 0x31, 0x92, 0xC9, 0x38, 0xB7,
+                0x36, 0x78, 0x2C, 0x19,
+            ],
+            &[
+                0x75, 0xC8, 0x4C, 0x7D, 0xCB, 0x9B, 0xA3, 0xCF, 0xCA, 0x37, 0x23, 0x85, 0xEE, 0x41,
+                0xC4, 0xB0, 0xAA, 0xA8, 0x30, 0x76, 0x25, 0x22, 0x4F, 0x44, 0xCF, 0xB0, 0xFF, 0xDA,
+                0x9E, 0x67, 0x1A, 0x3A,
+            ],
+            &[
+                0xD1, 0xC5, 0xDD, 0x7C, 0x98, 0xD9, 0xD6, 0xC2, 0x61, 0x16, 0xE9, 0x8F, 0x2D, 0x3D,
+                0x01, 0x0C, 0x65, 0x30, 0x1A, 0x29, 0x9B, 0xFB, 0x0F, 0x9E, 0x47, 0x0E, 0x04, 0x3B,
+                0xA5, 0x3E, 0x95, 0x00,
+            ],
+            &[
+                0x34, 0xF9, 0xD0, 0xA4, 0xBF, 0x38, 0x56, 0xBC, 0xAF, 0xFA, 0x08, 0x85, 0x36, 0xFE,
+                0xF5, 0x3F, 0x6F, 0x2E, 0xF5, 0xD5, 0x04, 0x8C, 0xC9, 0x9C, 0xD9, 0x42, 0x24, 0xB9,
+                0xA6, 0x86, 0x41, 0x1E,
+            ],
+        ],
+        [
+            &[
+                0xAD, 0x42, 0x9F, 0x61, 0xBE, 0xBA, 0x72, 0x90, 0x8C, 0xB7, 0xF3, 0x14, 0x92, 0x19,
+                0x77, 0x9A, 0xC9, 0x94, 0x8A, 0xB6, 0xCF, 0x2C, 0xB5, 0x0E, 0xD9, 0xA0, 0xFD, 0x60,
+                0xA0, 0xA0, 0x11, 0x33,
+            ],
+            &[
+                0xD3, 0xB6, 0x77, 0xB8, 0x6F, 0x48, 0x38, 0x6B, 0x0B, 0x30, 0x95, 0x4A, 0xF0, 0x6D,
+                0x55, 0xAB, 0x25, 0xCF, 0x6C, 0x85, 0xFC, 0x80, 0xE9, 0x30, 0xE1, 0x60, 0x6E, 0x0C,
+                0xCC, 0xEB, 0xD7, 0x0D,
+            ],
+            &[
+                0x9C, 0x03, 0xB9, 0x0A, 0xA1, 0xC4, 0x5B, 0xF1, 0x47, 0xF8, 0xD4, 0x5A, 0x60, 0x1B,
+                0x2C, 0x02, 0xE8, 0x7B, 0xE8, 0xDC, 0xB4, 0x84, 0x6F, 0xCD, 0x6E, 0xB1, 0x41, 0xE6,
+                0x4D, 0x44, 0xE6, 0x3D,
+            ],
+            &[
+                0x85, 0x59, 0xA0, 0xA8, 0xB2, 0x67, 0x8F, 0x2C, 0x3A, 0x21, 0x01, 0xBA, 0xE0, 0x05,
+                0xEB, 0x62, 0x48, 0xD0, 0xB1, 0xF1, 0xEA, 0xA5, 0xBE, 0xC0, 0xE9, 0xFD, 0x9D, 0xF0,
+                0xB1, 0xEE, 0x76, 0x17,
+            ],
+        ],
+        [
+            &[
+                0xB8, 0x8D, 0x45, 0xB3, 0x11, 0x98, 0xF3, 0x94, 0x5B, 0xE6, 0x10, 0xE0, 0x80, 0x5C,
+                0x86, 0xFC, 0x0B, 0x39, 0x7E, 0xF9, 0x91, 0xCB, 0x3D, 0xEB, 0xD2, 0x6C, 0x3A, 0x2A,
+                0x72, 0x8E, 0xCB, 0x2C,
+            ],
+            &[
+                0xBC, 0xC2, 0x03, 0xA4, 0x42, 0x85, 0xE2, 0x65, 0x6F, 0x2E, 0x18, 0xB8, 0x8B, 0xA3,
+                0xD2, 0x80, 0x90, 0x72, 0x4F, 0x16, 0x0F, 0xB6, 0x35, 0xB9, 0x25, 0x43, 0x7D, 0xFF,
+                0x99, 0x30, 0xD4, 0x33,
+            ],
+            &[
+                0x37, 0xEF, 0x8B, 0xE3, 0x2C, 0x25, 0x0D, 0xB8, 0x47, 0x60, 0x45, 0x13, 0x1D, 0xAE,
+                0x3F, 0xBD, 0x7E, 0x5C, 0xA0, 0x2A, 0x98, 0xD6, 0x95, 0x2F, 0x34, 0x04, 0xB7, 0x51,
+                0x5C, 0xFE, 0x17, 0x17,
+            ],
+            &[
+                0x09, 0xE9, 0x5B, 0x07, 0xE8, 0x9F, 0x6F, 0x96, 0x10, 0xFD, 0x82, 0x5B, 0x16, 0x22,
+                0xC4, 0xF2, 0x86, 0x8A, 0x0B, 0x37, 0xDB, 0x42, 0x15, 0xFC, 0x4C, 0x5B, 0x31, 0x92,
+                0x38, 0xF7, 0x4A, 0x3E,
+            ],
+        ],
+        [
+            &[
+                0x92, 0x58, 0xE1, 0xAE, 0xE6, 0xFD, 0xB1, 0x08, 0x0A, 0xDA, 0x30, 0xC1, 0xCC, 0x6E,
+                0x58, 0x7E, 0x45, 0xFE, 0xAC, 0xDF, 0x3F, 0x2A, 0xCC, 0x8B, 0xA1, 0x6C, 0x84, 0x72,
+                0xBC, 0xF1, 0x80, 0x22,
+            ],
+            &[
+                0xD8, 0x80, 0x97, 0x53, 0x70, 0x62, 0xEF, 0x8F, 0x3B, 0x35, 0xDD, 0x91, 0x5D, 0xAF,
+                0x33, 0x06, 0xAD, 0x47, 0x7D, 0x80, 0x3B, 0x12, 0x16, 0x1A, 0xBE, 0xFC, 0x30, 0xDE,
+                0x5B, 0xC0, 0xA5, 0x26,
+            ],
+            &[
+                0x78, 0x7A, 0xDD, 0x92, 0xC6, 0x76, 0x1B, 0xE6, 0x85, 0x1C, 0xBB, 0x33, 0x77, 0x56,
+                0x00, 0xB6, 0xB5, 0x10, 0x62, 0x1B, 0x88, 0x49, 0x15, 0xAE, 0x8C, 0x86, 0x05, 0xC0,
+                0x54, 0x8A, 0x1B, 0x01,
+            ],
+            &[
+                0xA9, 0x39, 0xD7, 0xCF, 0x4F, 0x2C, 0xF4, 0xBE, 0x21, 0xB8, 0x17, 0x6B, 0xEA, 0x78,
+                0x58, 0xCF, 0x1A, 0x01, 0x49, 0x9D, 0x52, 0x28, 0x38, 0xEF, 0x70, 0xD8, 0xAE, 0x7A,
+                0x79, 0x15, 0x2E, 0x37,
+            ],
+        ],
+        [
+            &[
+                0xE0, 0x62, 0x1C, 0x97, 0x03, 0xB6, 0x28, 0x18, 0xD0, 0xF0, 0x5C, 0x70, 0xE7, 0x6C,
+                0xFE, 0x8C, 0x28, 0x23, 0xFE, 0x66, 0xEA, 0x1A, 0x1F, 0xA9, 0x85, 0xBA, 0xA4, 0x74,
+                0xCE, 0x95, 0x1C, 0x0F,
+            ],
+            &[
+                0x76, 0x62, 0x01, 0xBB, 0xE8, 0x37, 0x8A, 0x99, 0x68, 0x40, 0x55, 0x04, 0x86, 0xFE,
+                0x23, 0xF2, 0xFE, 0x78, 0x1D, 0x89, 0xAE, 0xC0, 0xF1, 0xBB, 0x6C, 0x95, 0x01, 0x70,
+                0xD0, 0xD5, 0xB2, 0x13,
+            ],
+            &[
+                0x62, 0x85, 0x15, 0x94, 0xC5, 0xB7, 0x50, 0x3D, 0x8F, 0xB6, 0x4A, 0xD5, 0x24, 0x8D,
+                0x2F, 0x39, 0x54, 0x9E, 0xE2, 0xBC, 0x74, 0x37, 0x56, 0x23, 0x84, 0x1D, 0xED, 0xFB,
+                0xE6, 0x3F, 0xA9, 0x35,
+            ],
+            &[
+                0xC3, 0x09, 0x3B, 0x59, 0xD5, 0xC0, 0x2C, 0x01, 0xA8, 0x48, 0x2E, 0x60, 0xD1, 0x45,
+                0xA9, 0x7A, 0x8F, 0x67, 0xE1, 0x1F, 0x13, 0x51, 0x0D, 0xDA, 0x4C, 0xA2, 0x1D, 0xCA,
+                0xB9, 0xEA, 0xE7, 0x0D,
+            ],
+        ],
+        [
+            &[
+                0x06, 0xEE, 0x2A, 0xC4, 0x70, 0x4B, 0x81, 0x2A, 0xA9, 0x05, 0xBF, 0x6D, 0x8A, 0x82,
+                0x8B, 0xEC, 0x8E, 0x84, 0x33, 0x2B, 0x83, 0x5B, 0xE2, 0xA7, 0xA5, 0xC4, 0xCC, 0x5E,
+                0x7B, 0x3C, 0x52, 0x0C,
+            ],
+            &[
+                0x0A, 0xD7, 0xC3, 0xAB, 0x77, 0xD9, 0x0B, 0x47, 0xE5, 0x04, 0x2B, 0x0A, 0xB4, 0x09,
+                0x1D, 0xCB, 0x3B, 0x16, 0x5B, 0x20, 0x78, 0xBD, 0xDC, 0x8C, 0x91, 0xCE, 0xA6, 0x2B,
+                0x0F, 0xBB, 0xCE, 0x32,
+            ],
+            &[
+                0x9B, 0x07, 0xF3, 0x2E, 0xE3, 0xEC, 0xBF, 0x22, 0x15, 0xE2, 0x95, 0x7A, 0xA2, 0xE0,
+                0x53, 0xFF, 0x37, 0x4B, 0x14, 0x59, 0x88, 0x2F, 0x7B, 0x04, 0xE4, 0x95, 0x9F, 0x4C,
+                0x33, 0x02, 0x50, 0x3A,
+            ],
+            &[
+                0xCB, 0xF7, 0x44, 0x02, 0x83, 0xA9, 0x12, 0x9B, 0xAA, 0x79, 0x0C, 0x3D, 0x7B, 0x4D,
+                0x8F, 0x1F, 0x4C, 0x29, 0x64, 0x73, 0x75, 0x6B, 0xC8, 0x79, 0x88, 0xE7, 0xB6, 0xA6,
+                0xB7, 0xE3, 0xEA, 0x30,
+            ],
+        ],
+    ];
+}
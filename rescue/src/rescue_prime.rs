@@ -0,0 +1,243 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements the [Rescue-Prime](https://eprint.iacr.org/2020/1143)
+//! permutation and hash function, for the fields implementing
+//! [`RescuePrimeParameter`].
+//!
+//! Rescue-Prime shares the S-boxes and the state size of the Rescue instances
+//! of this crate, but it has no key schedule: each round applies `x^alpha`,
+//! the MDS matrix and a round constant, followed by `x^(1/alpha)`, the MDS
+//! matrix and another round constant. The hash function always pads its input
+//! with a one followed by zeros, and outputs the rate part of the state.
+
+use crate::{RescueMatrix, RescueParameter, RescueVector, CRHF_RATE, STATE_SIZE};
+use ark_std::{marker::PhantomData, vec::Vec};
+use jf_utils::pad_with_zeros;
+
+/// This trait defines the constants of the Rescue-Prime permutation, on top of
+/// the S-box parameters of [`RescueParameter`].
+pub trait RescuePrimeParameter: RescueParameter {
+    /// Number of rounds, each made of two half-rounds.
+    const PRIME_ROUNDS: usize;
+    /// MDS matrix
+    const PRIME_MDS_LE: [[&'static [u8]; STATE_SIZE]; STATE_SIZE];
+    /// Round constants, one vector per half-round.
+    const PRIME_ROUND_CONSTANTS_LE: &'static [[&'static [u8]; STATE_SIZE]];
+}
+
+/// Instance of the Rescue-Prime permutation.
+#[derive(Debug, Clone)]
+pub struct RescuePrimePermutation<F> {
+    mds: RescueMatrix<F>,
+    round_constants: Vec<RescueVector<F>>,
+}
+
+impl<F: RescuePrimeParameter> Default for RescuePrimePermutation<F> {
+    fn default() -> Self {
+        let mds = F::PRIME_MDS_LE;
+        let round_constants = F::PRIME_ROUND_CONSTANTS_LE
+            .iter()
+            .map(|c| RescueVector::from_elems_le_bytes(c[0], c[1], c[2], c[3]))
+            .collect();
+        RescuePrimePermutation {
+            mds: RescueMatrix::from(&[
+                RescueVector::from_elems_le_bytes(mds[0][0], mds[0][1], mds[0][2], mds[0][3]),
+                RescueVector::from_elems_le_bytes(mds[1][0], mds[1][1], mds[1][2], mds[1][3]),
+                RescueVector::from_elems_le_bytes(mds[2][0], mds[2][1], mds[2][2], mds[2][3]),
+                RescueVector::from_elems_le_bytes(mds[3][0], mds[3][1], mds[3][2], mds[3][3]),
+            ]),
+            round_constants,
+        }
+    }
+}
+
+impl<F: RescuePrimeParameter> RescuePrimePermutation<F> {
+    /// Return a pointer to the mds matrix.
+    #[inline]
+    pub fn mds_matrix_ref(&self) -> &RescueMatrix<F> {
+        &self.mds
+    }
+
+    /// Return a pointer to the round constants, two per round.
+    #[inline]
+    pub fn round_constants_ref(&self) -> &[RescueVector<F>] {
+        &self.round_constants
+    }
+
+    /// Compute the permutation on RescueVector `input`
+    pub fn eval(&self, input: &RescueVector<F>) -> RescueVector<F> {
+        let mut state = *input;
+        self.round_constants.chunks_exact(2).for_each(|constants| {
+            state.non_linear(&self.mds, &constants[0]);
+            state.pow(F::A_INV);
+            state.linear(&self.mds, &constants[1]);
+        });
+        state
+    }
+}
+
+/// The Rescue-Prime hash function, with rate `CRHF_RATE` and capacity 1.
+#[derive(Debug, Clone)]
+pub struct RescuePrimeCRHF<F>(PhantomData<F>);
+
+impl<F: RescuePrimeParameter> RescuePrimeCRHF<F> {
+    /// Rescue-Prime hash of an input of arbitrary length: a "1" is always
+    /// appended to `input`, then as many "0" as required for the overall
+    /// length to be a multiple of `CRHF_RATE`.
+    pub fn hash(input: &[F]) -> [F; CRHF_RATE] {
+        let mut padded = input.to_vec();
+        padded.push(F::one());
+        pad_with_zeros(&mut padded, CRHF_RATE);
+
+        let permutation = RescuePrimePermutation::default();
+        let mut state = RescueVector::zero();
+        padded.chunks_exact(CRHF_RATE).for_each(|chunk| {
+            state.add_assign_elems(chunk);
+            state = permutation.eval(&state);
+        });
+        [state.vec[0], state.vec[1], state.vec[2]]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RescuePrimeCRHF, RescuePrimeParameter, RescuePrimePermutation};
+    use crate::{RescueVector, PRP};
+    use ark_ed_on_bls12_377::Fq as Fr377;
+    use ark_ed_on_bls12_381::Fq as Fr381;
+    use ark_ed_on_bn254::Fq as Fr254;
+    use ark_std::UniformRand;
+
+    // permutation output on vector [0, 0, 0, 0]
+    // this value is computed with an independent implementation of the
+    // reference procedure described in the constants files
+    const OUTPUT254: [[u8; 32]; 4] = [
+        [
+            0x3E, 0xFD, 0xE2, 0x09, 0x14, 0x4D, 0xAC, 0x66, 0xEC, 0xA4, 0x7F, 0x87, 0xAB, 0x4E,
+            0xC7, 0x7A, 0x94, 0x46, 0x1C, 0x66, 0xC2, 0x43, 0xC7, 0xFD, 0x5F, 0xB4, 0x71, 0xB7,
+            0x9F, 0x6C, 0xAC, 0x1A,
+        ],
+        [
+            0xD5, 0xB2, 0x84, 0x05, 0xE1, 0x1F, 0x7E, 0xD9, 0x8A, 0xA8, 0xB4, 0xDD, 0x9E, 0xCC,
+            0x74, 0x19, 0xBE, 0x9E, 0xD2, 0xB5, 0xF6, 0x57, 0x42, 0x0A, 0x0C, 0x0B, 0x0B, 0x15,
+            0xFD, 0x9D, 0xD3, 0x1A,
+        ],
+        [
+            0xDB, 0x82, 0x08, 0x44, 0xA3, 0xCC, 0x8D, 0x81, 0x05, 0x44, 0x32, 0x81, 0xC0, 0x2D,
+            0xF5, 0xC5, 0x12, 0x51, 0xEB, 0x10, 0xF4, 0xF1, 0xA1, 0x94, 0x19, 0xB9, 0x88, 0x28,
+            0xF7, 0x5A, 0xE9, 0x21,
+        ],
+        [
+            0xDD, 0x4C, 0x1A, 0xE0, 0x05, 0x18, 0x01, 0x39, 0xFD, 0xAD, 0x5E, 0xC8, 0xAD, 0x2E,
+            0x3D, 0x98, 0xDB, 0xA8, 0x6B, 0xF6, 0x70, 0x75, 0xBC, 0xB9, 0xBE, 0xF9, 0xD4, 0xD9,
+            0xAE, 0xC3, 0x98, 0x27,
+        ],
+    ];
+
+    const OUTPUT381: [[u8; 32]; 4] = [
+        [
+            0x2D, 0xD4, 0xF6, 0xE6, 0x74, 0xAA, 0x2F, 0xFF, 0xBE, 0xCC, 0x96, 0xBE, 0x56, 0x3C,
+            0x78, 0x33, 0xA7, 0x2E, 0x4A, 0xCF, 0x3D, 0xD9, 0xDA, 0x74, 0x5E, 0x6B, 0xFA, 0xEE,
+            0xC3, 0x27, 0xE4, 0x20,
+        ],
+        [
+            0x09, 0x06, 0xE3, 0x42, 0x05, 0x8E, 0x8F, 0x00, 0x7E, 0xFF, 0x07, 0x59, 0xE4, 0xD0,
+            0x10, 0x4C, 0x0D, 0xDC, 0x67, 0xC0, 0x5B, 0xF1, 0x14, 0x13, 0xFF, 0x7E, 0xAE, 0x73,
+            0x24, 0xDD, 0x4E, 0x18,
+        ],
+        [
+            0x4E, 0xFE, 0xBE, 0xCD, 0x31, 0x85, 0x31, 0x84, 0x0D, 0x90, 0xFB, 0xF2, 0xDF, 0x66,
+            0x29, 0x43, 0x87, 0xE9, 0x07, 0x30, 0x59, 0x17, 0x7B, 0x51, 0x71, 0x1D, 0x25, 0x86,
+            0x80, 0x33, 0xC2, 0x42,
+        ],
+        [
+            0x67, 0xD3, 0x1E, 0x20, 0xCC, 0x0A, 0x88, 0x14, 0x5E, 0x61, 0x54, 0x64, 0xC8, 0x59,
+            0x7D, 0x8C, 0xF2, 0xC2, 0xE9, 0x68, 0xBE, 0x87, 0x35, 0x09, 0x7F, 0x86, 0x96, 0x27,
+            0xA9, 0xDB, 0x5D, 0x37,
+        ],
+    ];
+
+    const OUTPUT377: [[u8; 32]; 4] = [
+        [
+            0x53, 0x0B, 0x70, 0xEC, 0x26, 0xC0, 0x20, 0x4C, 0x28, 0x0E, 0xF4, 0x6C, 0xE9, 0x28,
+            0x44, 0x75, 0xC6, 0x13, 0x31, 0xE3, 0xEB, 0x02, 0xBB, 0x17, 0x29, 0x1B, 0x93, 0x28,
+            0xD3, 0x5F, 0xF4, 0x06,
+        ],
+        [
+            0x11, 0xD5, 0xDF, 0x2A, 0x4D, 0xF0, 0xE1, 0xB1, 0x97, 0x8C, 0x34, 0xAE, 0xE3, 0x0D,
+            0xBC, 0xAA, 0x49, 0x54, 0xBD, 0x47, 0xE1, 0x01, 0x58, 0xDD, 0x86, 0x4F, 0xB8, 0x03,
+            0xFD, 0xAA, 0x3B, 0x00,
+        ],
+        [
+            0xCE, 0x23, 0xF9, 0x93, 0x3F, 0x48, 0xC9, 0xF6, 0x73, 0x60, 0x0A, 0xA7, 0x12, 0x6A,
+            0xC4, 0x80, 0xD4, 0xB4, 0xAF, 0x2A, 0xFC, 0x1C, 0x8F, 0xDF, 0xD0, 0xDC, 0x4C, 0x49,
+            0xE5, 0xD3, 0x93, 0x04,
+        ],
+        [
+            0xFA, 0xAB, 0x32, 0x27, 0x66, 0x28, 0x8D, 0xFB, 0xDE, 0x61, 0x82, 0x1F, 0xAF, 0x22,
+            0x5F, 0xCA, 0x2E, 0xD3, 0xD8, 0xD3, 0x12, 0x41, 0x65, 0x35, 0x37, 0xE1, 0x88, 0x8D,
+            0xCC, 0x58, 0x08, 0x11,
+        ],
+    ];
+
+    #[test]
+    fn test_rescue_prime_perm_on_0_vec() {
+        test_rescue_prime_perm_on_0_vec_helper::<Fr254>(&OUTPUT254);
+        test_rescue_prime_perm_on_0_vec_helper::<Fr381>(&OUTPUT381);
+        test_rescue_prime_perm_on_0_vec_helper::<Fr377>(&OUTPUT377);
+    }
+
+    fn test_rescue_prime_perm_on_0_vec_helper<F: RescuePrimeParameter>(output: &[[u8; 32]; 4]) {
+        let permutation = RescuePrimePermutation::<F>::default();
+        let expected =
+            RescueVector::from_elems_le_bytes(&output[0], &output[1], &output[2], &output[3]);
+        assert_eq!(permutation.round_constants_ref().len(), 2 * F::PRIME_ROUNDS);
+        assert_eq!(permutation.eval(&RescueVector::zero()), expected);
+    }
+
+    #[test]
+    fn test_rescue_prime_hash() {
+        test_rescue_prime_hash_helper::<Fr254>();
+        test_rescue_prime_hash_helper::<Fr381>();
+        test_rescue_prime_hash_helper::<Fr377>();
+    }
+
+    fn test_rescue_prime_hash_helper<F: RescuePrimeParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let permutation = RescuePrimePermutation::<F>::default();
+        let input = [F::rand(&mut rng), F::rand(&mut rng), F::rand(&mut rng)];
+
+        // a full block is followed by a padding block
+        let mut state = permutation.eval(&RescueVector::from(&[
+            input[0],
+            input[1],
+            input[2],
+            F::zero(),
+        ]));
+        state.add_assign_elems(&[F::one(), F::zero(), F::zero()]);
+        state = permutation.eval(&state);
+        assert_eq!(
+            RescuePrimeCRHF::<F>::hash(&input).to_vec(),
+            state.elems()[..3].to_vec()
+        );
+
+        // padding is unambiguous
+        assert_ne!(
+            RescuePrimeCRHF::<F>::hash(&input[..1]),
+            RescuePrimeCRHF::<F>::hash(&[input[0], F::zero()])
+        );
+        assert_ne!(
+            RescuePrimeCRHF::<F>::hash(&input[..2]),
+            RescuePrimeCRHF::<F>::hash(&[input[0], input[1], F::one()])
+        );
+
+        // Rescue-Prime differs from Rescue
+        let prp = PRP::<F>::default();
+        let zero = RescueVector::zero();
+        assert_ne!(permutation.eval(&zero), prp.prp(&zero, &zero));
+    }
+}
@@ -6,12 +6,13 @@
 
 //! This file contains the APIs wrappers for ark-sponge
 
-use super::{Permutation, RescueParameter, RescueVector};
+use super::{Permutation, RescueError, RescueParameter, RescueVector, STATE_SIZE};
 use ark_crypto_primitives::sponge::{
     Absorb, CryptographicSponge, FieldBasedCryptographicSponge, FieldElementSize, SpongeExt,
 };
 use ark_ff::PrimeField;
-use ark_std::{vec, vec::Vec};
+use ark_std::{format, vec, vec::Vec};
+use zeroize::Zeroize;
 
 #[derive(Clone, Default, Debug)]
 /// A rescue hash function consists of a permutation function and
@@ -21,6 +22,31 @@ pub struct RescueSponge<F: RescueParameter, const RATE: usize> {
     pub(crate) permutation: Permutation<F>,
 }
 
+impl<F: RescueParameter, const RATE: usize> RescueSponge<F, RATE> {
+    /// Squeeze `num_elements` field elements from the sponge, reading the rate
+    /// part of the state and permuting it after each read.
+    ///
+    /// Unlike [`FieldBasedCryptographicSponge::squeeze_native_field_elements`],
+    /// the state is also permuted after the last read, so that consecutive
+    /// calls, possibly interleaved with absorptions, never output the same
+    /// elements twice.
+    pub fn squeeze(&mut self, num_elements: usize) -> Vec<F> {
+        let mut result = Vec::with_capacity(num_elements);
+        while result.len() < num_elements {
+            let extract = (num_elements - result.len()).min(RATE);
+            result.extend_from_slice(&self.state.vec[0..extract]);
+            self.state = self.permutation.eval(&self.state);
+        }
+        result
+    }
+}
+
+impl<F: RescueParameter, const RATE: usize> Zeroize for RescueSponge<F, RATE> {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+    }
+}
+
 impl<F: RescueParameter, const RATE: usize> SpongeExt for RescueSponge<F, RATE> {
     type State = RescueVector<F>;
 
@@ -132,6 +158,117 @@ impl<T: RescueParameter, const RATE: usize> FieldBasedCryptographicSponge<T>
     }
 }
 
+/// Whether a duplex sponge is absorbing or squeezing, with the position in the
+/// rate part of the state of the next element to absorb or squeeze.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DuplexMode {
+    Absorbing(usize),
+    Squeezing(usize),
+}
+
+/// A duplex sponge over the Rescue permutation, whose `absorb`, `squeeze` and
+/// `separate` calls can be interleaved arbitrarily, e.g. to back a
+/// Fiat-Shamir transcript.
+///
+/// The permutation is only applied when needed: before absorbing into a full
+/// rate, and before squeezing after an absorption or from an exhausted rate.
+/// Hence consecutive calls compose, e.g. absorbing `[a]` then `[b]` is the
+/// same as absorbing `[a, b]`. Distinct phases of a protocol should be framed
+/// by [`RescueDuplexSponge::separate`].
+#[derive(Clone, Debug)]
+pub struct RescueDuplexSponge<F: RescueParameter, const RATE: usize> {
+    state: RescueVector<F>,
+    permutation: Permutation<F>,
+    mode: DuplexMode,
+}
+
+impl<F: RescueParameter, const RATE: usize> RescueDuplexSponge<F, RATE> {
+    /// Create a duplex sponge for the domain-separation tag `tag`, which is
+    /// written in the capacity part of the initial state. Return an error
+    /// unless `0 < RATE < STATE_SIZE`.
+    pub fn new(tag: F) -> Result<Self, RescueError> {
+        if RATE == 0 || RATE >= STATE_SIZE {
+            return Err(RescueError::ParameterError(format!(
+                "Duplex sponge rate should be in [1, {}), got {}",
+                STATE_SIZE, RATE
+            )));
+        }
+        let mut state = RescueVector::zero();
+        state.vec[STATE_SIZE - 1] = tag;
+        Ok(Self {
+            state,
+            permutation: Permutation::default(),
+            mode: DuplexMode::Absorbing(0),
+        })
+    }
+
+    /// Absorb `input` into the rate part of the state.
+    pub fn absorb(&mut self, input: &[F]) {
+        for elem in input {
+            let pos = self.next_absorb_position();
+            self.state.vec[pos] += elem;
+            self.mode = DuplexMode::Absorbing(pos + 1);
+        }
+    }
+
+    /// Squeeze `num_elements` field elements from the rate part of the state.
+    pub fn squeeze(&mut self, num_elements: usize) -> Vec<F> {
+        (0..num_elements)
+            .map(|_| {
+                let pos = match self.mode {
+                    DuplexMode::Squeezing(pos) if pos < RATE => pos,
+                    _ => {
+                        self.state = self.permutation.eval(&self.state);
+                        0
+                    },
+                };
+                self.mode = DuplexMode::Squeezing(pos + 1);
+                self.state.vec[pos]
+            })
+            .collect()
+    }
+
+    /// End the current phase of the protocol and start a new one labelled by
+    /// the domain-separation tag `tag`.
+    ///
+    /// The absorbed input is padded with a "1", which makes it unambiguous
+    /// (e.g. absorbing `[a]` and `[a, 0]` yield different states), and `tag`
+    /// is added to the capacity part of the state. The next `absorb` or
+    /// `squeeze` call starts from a freshly permuted state.
+    pub fn separate(&mut self, tag: F) {
+        let pos = self.next_absorb_position();
+        self.state.vec[pos] += F::one();
+        self.state.vec[STATE_SIZE - 1] += tag;
+        self.mode = DuplexMode::Absorbing(RATE);
+    }
+
+    // Position of the next absorbed element, permuting the state if the rate is
+    // full. After a squeeze, the input overwrites the already read elements: a
+    // permutation happens before anything is squeezed again.
+    fn next_absorb_position(&mut self) -> usize {
+        match self.mode {
+            DuplexMode::Absorbing(pos) if pos < RATE => pos,
+            DuplexMode::Absorbing(_) => {
+                self.state = self.permutation.eval(&self.state);
+                0
+            },
+            DuplexMode::Squeezing(_) => 0,
+        }
+    }
+}
+
+impl<F: RescueParameter, const RATE: usize> Zeroize for RescueDuplexSponge<F, RATE> {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+    }
+}
+
+impl<F: RescueParameter, const RATE: usize> Drop for RescueDuplexSponge<F, RATE> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -139,7 +276,7 @@ mod test {
     use ark_crypto_primitives::{
         absorb, collect_sponge_bytes, collect_sponge_field_elements, sponge::AbsorbWithLength,
     };
-    use ark_ff::{One, UniformRand};
+    use ark_ff::{One, UniformRand, Zero};
     use jf_utils::test_rng;
 
     fn assert_different_encodings<F: RescueParameter, A: Absorb>(a: &A, b: &A) {
@@ -237,4 +374,111 @@ mod test {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_squeeze() {
+        let sponge_param = Permutation::default();
+        let mut rng = test_rng();
+        let input: Vec<Fr> = (0..5).map(|_| Fr::rand(&mut rng)).collect();
+
+        for num_elements in 0..10 {
+            let mut sponge1 = RescueSponge::<Fr, 3>::new(&sponge_param);
+            sponge1.absorb(&input);
+            let mut sponge2 = sponge1.clone();
+
+            // the output agrees with the one-shot squeeze
+            let output = sponge1.squeeze(num_elements);
+            assert_eq!(output.len(), num_elements);
+            assert_eq!(output, sponge2.squeeze_native_field_elements(num_elements));
+        }
+
+        // consecutive squeezes produce fresh elements
+        let mut sponge = RescueSponge::<Fr, 3>::new(&sponge_param);
+        sponge.absorb(&input);
+        let mut reference = sponge.clone();
+        let first = sponge.squeeze(2);
+        let second = sponge.squeeze(2);
+        assert_ne!(first, second);
+        assert_eq!(first, reference.squeeze_native_field_elements(2));
+        reference.state = sponge_param.eval(&reference.state);
+        assert_eq!(second, reference.squeeze_native_field_elements(2));
+    }
+
+    #[test]
+    fn test_duplex_sponge() {
+        let mut rng = test_rng();
+        let tag = Fr::from(7u8);
+        let input: Vec<Fr> = (0..5).map(|_| Fr::rand(&mut rng)).collect();
+        let permutation = Permutation::<Fr>::default();
+
+        // bad rates
+        assert!(RescueDuplexSponge::<Fr, 0>::new(tag).is_err());
+        assert!(RescueDuplexSponge::<Fr, 4>::new(tag).is_err());
+
+        // absorbing 5 elements then squeezing 4 of them at rate 3
+        let mut duplex = RescueDuplexSponge::<Fr, 3>::new(tag).unwrap();
+        duplex.absorb(&input[..2]);
+        duplex.absorb(&input[2..]);
+        let output = duplex.squeeze(4);
+
+        let mut state = RescueVector::from(&[input[0], input[1], input[2], tag]);
+        state = permutation.eval(&state);
+        state.add_assign_elems(&input[3..]);
+        state = permutation.eval(&state);
+        let mut expected = state.vec[..3].to_vec();
+        state = permutation.eval(&state);
+        expected.push(state.vec[0]);
+        assert_eq!(output, expected);
+
+        // squeezing in several calls is the same
+        let mut duplex2 = RescueDuplexSponge::<Fr, 3>::new(tag).unwrap();
+        duplex2.absorb(&input);
+        let mut output2 = duplex2.squeeze(1);
+        output2.extend(duplex2.squeeze(3));
+        assert_eq!(output, output2);
+
+        // absorbing after squeezing affects further outputs
+        duplex.absorb(&input[..1]);
+        duplex2.absorb(&input[1..2]);
+        assert_ne!(duplex.squeeze(2), duplex2.squeeze(2));
+
+        // tags separate domains
+        let mut duplex1 = RescueDuplexSponge::<Fr, 3>::new(tag).unwrap();
+        let mut duplex2 = RescueDuplexSponge::<Fr, 3>::new(tag + Fr::one()).unwrap();
+        duplex1.absorb(&input);
+        duplex2.absorb(&input);
+        assert_ne!(duplex1.squeeze(3), duplex2.squeeze(3));
+
+        let mut duplex1 = RescueDuplexSponge::<Fr, 3>::new(tag).unwrap();
+        let mut duplex2 = duplex1.clone();
+        duplex1.separate(Fr::from(1u8));
+        duplex2.separate(Fr::from(2u8));
+        assert_ne!(duplex1.squeeze(1), duplex2.squeeze(1));
+
+        // phases are unambiguous
+        let mut duplex1 = RescueDuplexSponge::<Fr, 3>::new(tag).unwrap();
+        let mut duplex2 = duplex1.clone();
+        duplex1.absorb(&input[..1]);
+        duplex1.separate(tag);
+        duplex2.absorb(&[input[0], Fr::zero()]);
+        duplex2.separate(tag);
+        assert_ne!(duplex1.squeeze(3), duplex2.squeeze(3));
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut rng = test_rng();
+        let input: Vec<Fr> = (0..5).map(|_| Fr::rand(&mut rng)).collect();
+
+        let mut sponge = RescueSponge::<Fr, 3>::new(&Permutation::default());
+        sponge.absorb(&input);
+        assert_ne!(sponge.state, RescueVector::zero());
+        sponge.zeroize();
+        assert_eq!(sponge.state, RescueVector::zero());
+
+        let mut duplex = RescueDuplexSponge::<Fr, 3>::new(Fr::from(7u8)).unwrap();
+        duplex.absorb(&input);
+        duplex.zeroize();
+        assert_eq!(duplex.state, RescueVector::zero());
+    }
 }
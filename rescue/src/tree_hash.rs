@@ -0,0 +1,147 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! A tree hashing mode over the Rescue sponge, for long inputs.
+
+use crate::{crhf::RescueCRHF, RescueError, RescueParameter};
+use ark_std::{format, marker::PhantomData, vec::Vec};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Domain-separation tags of the leaves, the inner nodes and the root.
+const LEAF_DOMAIN_SEPARATOR: &[u8] = b"JF-RESCUE-TREE-LEAF";
+const NODE_DOMAIN_SEPARATOR: &[u8] = b"JF-RESCUE-TREE-NODE";
+const ROOT_DOMAIN_SEPARATOR: &[u8] = b"JF-RESCUE-TREE-ROOT";
+
+/// Tree hashing of inputs of arbitrary length.
+///
+/// The input is split into chunks of `CHUNK_LEN` elements, the last one being
+/// possibly shorter, which are hashed independently, in parallel with the
+/// `parallel` feature. The digests are then compressed pairwise, level by
+/// level, the last digest of a level of odd size being carried over to the
+/// next level. The root is finally hashed together with the length of the
+/// input and `CHUNK_LEN`, which determine the shape of the tree.
+///
+/// Leaves, inner nodes and the root are hashed with
+/// [`RescueCRHF::tagged_sponge_no_padding`] and its variants, under distinct
+/// domain-separation tags.
+#[derive(Debug, Clone)]
+pub struct RescueTreeHash<F: RescueParameter, const CHUNK_LEN: usize>(PhantomData<F>);
+
+impl<F: RescueParameter, const CHUNK_LEN: usize> RescueTreeHash<F, CHUNK_LEN> {
+    /// Return the tree hash of `input`. Return an error if `CHUNK_LEN` is 0.
+    pub fn hash(input: &[F]) -> Result<F, RescueError> {
+        if CHUNK_LEN == 0 {
+            return Err(RescueError::ParameterError(format!(
+                "Tree hash chunk length should be positive, got {}",
+                CHUNK_LEN
+            )));
+        }
+        let leaf_tag = F::from_le_bytes_mod_order(LEAF_DOMAIN_SEPARATOR);
+        let node_tag = F::from_le_bytes_mod_order(NODE_DOMAIN_SEPARATOR);
+        let root_tag = F::from_le_bytes_mod_order(ROOT_DOMAIN_SEPARATOR);
+
+        let hash_leaf =
+            |chunk: &[F]| RescueCRHF::tagged_sponge_with_bit_padding(&leaf_tag, chunk, 1)[0];
+        #[cfg(feature = "parallel")]
+        let mut level: Vec<F> = input.par_chunks(CHUNK_LEN).map(&hash_leaf).collect();
+        #[cfg(not(feature = "parallel"))]
+        let mut level: Vec<F> = input.chunks(CHUNK_LEN).map(&hash_leaf).collect();
+        if level.is_empty() {
+            level.push(hash_leaf(&[]));
+        }
+
+        let hash_node = |pair: &[F]| match pair {
+            [left, right] => {
+                RescueCRHF::tagged_sponge_no_padding(&node_tag, &[*left, *right, F::zero()], 1)
+                    .expect("the input length is the rate")[0]
+            },
+            _ => pair[0],
+        };
+        while level.len() > 1 {
+            #[cfg(feature = "parallel")]
+            {
+                level = level.par_chunks(2).map(&hash_node).collect();
+            }
+            #[cfg(not(feature = "parallel"))]
+            {
+                level = level.chunks(2).map(&hash_node).collect();
+            }
+        }
+
+        let finalization = [
+            level[0],
+            F::from(input.len() as u64),
+            F::from(CHUNK_LEN as u64),
+        ];
+        RescueCRHF::tagged_sponge_no_padding(&root_tag, &finalization, 1).map(|output| output[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RescueTreeHash, LEAF_DOMAIN_SEPARATOR, NODE_DOMAIN_SEPARATOR, ROOT_DOMAIN_SEPARATOR,
+    };
+    use crate::{crhf::RescueCRHF, RescueParameter};
+    use ark_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_377::Fq as FqEd377;
+    use ark_ed_on_bls12_381::Fq as FqEd381;
+    use ark_ed_on_bn254::Fq as FqEd254;
+    use ark_std::{vec::Vec, UniformRand};
+
+    #[test]
+    fn test_tree_hash() {
+        test_tree_hash_helper::<FqEd254>();
+        test_tree_hash_helper::<FqEd377>();
+        test_tree_hash_helper::<FqEd381>();
+        test_tree_hash_helper::<Fq377>();
+    }
+
+    fn test_tree_hash_helper<F: RescueParameter>() {
+        let mut rng = jf_utils::test_rng();
+        let input: Vec<F> = (0..11).map(|_| F::rand(&mut rng)).collect();
+
+        // 3 leaves of 4, 4 and 3 elements
+        let leaf_tag = F::from_le_bytes_mod_order(LEAF_DOMAIN_SEPARATOR);
+        let node_tag = F::from_le_bytes_mod_order(NODE_DOMAIN_SEPARATOR);
+        let root_tag = F::from_le_bytes_mod_order(ROOT_DOMAIN_SEPARATOR);
+        let leaves: Vec<F> = input
+            .chunks(4)
+            .map(|chunk| RescueCRHF::tagged_sponge_with_bit_padding(&leaf_tag, chunk, 1)[0])
+            .collect();
+        let node =
+            RescueCRHF::tagged_sponge_no_padding(&node_tag, &[leaves[0], leaves[1], F::zero()], 1)
+                .unwrap()[0];
+        let root =
+            RescueCRHF::tagged_sponge_no_padding(&node_tag, &[node, leaves[2], F::zero()], 1)
+                .unwrap()[0];
+        let expected = RescueCRHF::tagged_sponge_no_padding(
+            &root_tag,
+            &[root, F::from(11u64), F::from(4u64)],
+            1,
+        )
+        .unwrap()[0];
+        let output = RescueTreeHash::<F, 4>::hash(&input).unwrap();
+        assert_eq!(output, expected);
+
+        // the length and the chunk size are bound to the hash
+        let mut longer = input.clone();
+        longer.push(F::zero());
+        assert_ne!(RescueTreeHash::<F, 4>::hash(&longer).unwrap(), output);
+        assert_ne!(RescueTreeHash::<F, 5>::hash(&input).unwrap(), output);
+        let mut tampered = input.clone();
+        tampered[9] += F::one();
+        assert_ne!(RescueTreeHash::<F, 4>::hash(&tampered).unwrap(), output);
+
+        // a single chunk and the empty input
+        assert_ne!(
+            RescueTreeHash::<F, 16>::hash(&input).unwrap(),
+            RescueTreeHash::<F, 16>::hash(&[]).unwrap()
+        );
+        assert!(RescueTreeHash::<F, 0>::hash(&input).is_err());
+    }
+}
@@ -5,16 +5,17 @@ set -e
 export RUSTFLAGS="-C overflow-checks=on"
 
 cargo test --release -p jf-utils #-- -Zunstable-options --report-time
-cargo test --release -p jf-plonk --lib --bins #-- -Zunstable-options --report-time
+cargo test --release -p jf-plonk --lib --bins --features json #-- -Zunstable-options --report-time
 cargo test --release -p jf-merkle-tree --features gadgets #-- -Zunstable-options --report-time
 cargo test --release -p jf-pcs --features test-srs #-- -Zunstable-options --report-time
 cargo test --release -p jf-rescue --features gadgets #-- -Zunstable-options --report-time
+cargo test --release -p jf-rescue --features "gadgets param-gen" #-- -Zunstable-options --report-time
 cargo test --release -p jf-signature --features "bls, schnorr, gadgets" #-- -Zunstable-options --report-time
 cargo test --release -p jf-vdf #-- -Zunstable-options --report-time
 cargo test --release -p jf-vid --features test-srs #-- -Zunstable-options --report-time
 cargo test --release -p jf-aead #-- -Zunstable-options --report-time
 cargo test --release -p jf-elgamal --features gadgets #-- -Zunstable-options --report-time
-cargo test --release -p jf-vrf #-- -Zunstable-options --report-time
+cargo test --release -p jf-vrf --features ring #-- -Zunstable-options --report-time
 cargo test --release -p jf-prf #-- -Zunstable-options --report-time
 cargo test --release -p jf-crhf #-- -Zunstable-options --report-time
 cargo test --release -p jf-commitment #-- -Zunstable-options --report-time
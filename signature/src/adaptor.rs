@@ -0,0 +1,228 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements adaptor signatures for the Schnorr signature scheme
+//! of [`crate::schnorr`], e.g. for atomic swaps.
+//!
+//! A signer holding a key pair and given an *adaptor point* `T = t * G`
+//! computes a [`PreSignature`] with [`SchnorrAdaptor::pre_sign`], which anyone
+//! can check with [`SchnorrAdaptor::pre_verify`] but which is not a valid
+//! signature by itself. Whoever knows the adaptor secret `t` completes it into
+//! a plain Schnorr [`Signature`] with [`SchnorrAdaptor::adapt`], and
+//! publishing that signature reveals `t` to the holder of the pre-signature
+//! through [`SchnorrAdaptor::extract`].
+//!
+//! A pre-signature with nonce `R = r * G + T` on a message `m` is
+//! `s' = r + c * sk` for the challenge `c = H(vk, R, m)` of the plain scheme,
+//! and the adapted signature is `(R, s' + t)`.
+
+use crate::{
+    constants::CS_ID_SCHNORR,
+    schnorr::{curve_cofactor, KeyPair, Signature, VerKey},
+    SignatureError::{self, ParameterError, VerificationError},
+};
+use ark_ec::{
+    twisted_edwards::{Projective, TECurveConfig as Config},
+    CurveGroup, Group,
+};
+use ark_ff::PrimeField;
+use ark_serialize::*;
+use ark_std::{
+    marker::PhantomData,
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec, UniformRand,
+};
+use jf_crhf::CRHF;
+use jf_rescue::{crhf::VariableLengthRescueCRHF, RescueParameter};
+use jf_utils::{fq_to_fr, fr_to_fq};
+
+/// Domain separator of the nonce derivation of pre-signatures.
+const ADAPTOR_NONCE_TAG: &[u8] = b"SCHNORR_ADAPTOR_NONCE";
+
+/// Adaptor signatures over the curve `P`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SchnorrAdaptor<P> {
+    curve_param: PhantomData<P>,
+}
+
+/// A pre-signature, which becomes a Schnorr signature once adapted with the
+/// secret of its adaptor point.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+#[allow(non_snake_case)]
+pub struct PreSignature<P: Config> {
+    s: P::ScalarField,
+    R: Projective<P>,
+}
+
+impl<F, P> SchnorrAdaptor<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Sample an adaptor secret `t` and its adaptor point `T = t * G`.
+    pub fn adaptor_gen<R: CryptoRng + RngCore>(prng: &mut R) -> (P::ScalarField, Projective<P>) {
+        let t = P::ScalarField::rand(prng);
+        (t, Projective::<P>::generator() * t)
+    }
+
+    /// Pre-sign `msg` with the key pair `keypair` for the adaptor point
+    /// `adaptor`. As for [`KeyPair::sign_randomized`], the nonce is derived
+    /// from the signing key, the message, the adaptor point and fresh
+    /// randomness from `prng`.
+    #[allow(non_snake_case)]
+    pub fn pre_sign<R: CryptoRng + RngCore>(
+        keypair: &KeyPair<P>,
+        msg: &[F],
+        adaptor: &Projective<P>,
+        prng: &mut R,
+    ) -> PreSignature<P> {
+        let T_affine = adaptor.into_affine();
+        let mut nonce_input = vec![
+            F::from_be_bytes_mod_order(ADAPTOR_NONCE_TAG),
+            fr_to_fq::<F, P>(keypair.sign_key_internal()),
+            F::rand(prng),
+            T_affine.x,
+            T_affine.y,
+        ];
+        nonce_input.extend(msg);
+        let r = fq_to_fr::<F, P>(
+            &VariableLengthRescueCRHF::<F, 1>::evaluate(nonce_input).unwrap()[0], // safe unwrap
+        );
+
+        let R = Projective::<P>::generator() * r + adaptor;
+        let c = keypair.ver_key_ref().challenge(&R, msg, CS_ID_SCHNORR);
+        PreSignature {
+            s: r + c * keypair.sign_key_internal(),
+            R,
+        }
+    }
+
+    /// Check that `pre_sig` is a pre-signature on `msg` under `vk` for the
+    /// adaptor point `adaptor`, i.e. that adapting it with the secret of
+    /// `adaptor` yields a valid signature.
+    pub fn pre_verify(
+        vk: &VerKey<P>,
+        msg: &[F],
+        adaptor: &Projective<P>,
+        pre_sig: &PreSignature<P>,
+    ) -> Result<(), SignatureError> {
+        // Reject if public key is of small order
+        if (*vk.internal() * P::ScalarField::from(curve_cofactor::<P>()))
+            == Projective::<P>::default()
+        {
+            return Err(VerificationError(
+                "public key is not valid: not in the correct subgroup".to_string(),
+            ));
+        }
+
+        let c = vk.challenge(&pre_sig.R, msg, CS_ID_SCHNORR);
+        if Projective::<P>::generator() * pre_sig.s + adaptor == pre_sig.R + *vk.internal() * c {
+            Ok(())
+        } else {
+            Err(VerificationError(
+                "Pre-signature verification error".to_string(),
+            ))
+        }
+    }
+
+    /// Complete `pre_sig` into a Schnorr signature with the adaptor secret.
+    pub fn adapt(pre_sig: &PreSignature<P>, secret: &P::ScalarField) -> Signature<P> {
+        Signature {
+            s: pre_sig.s + secret,
+            R: pre_sig.R,
+        }
+    }
+
+    /// Extract the secret of the adaptor point `adaptor` from the signature
+    /// `sig` adapted from `pre_sig`.
+    pub fn extract(
+        pre_sig: &PreSignature<P>,
+        sig: &Signature<P>,
+        adaptor: &Projective<P>,
+    ) -> Result<P::ScalarField, SignatureError> {
+        if sig.R != pre_sig.R {
+            return Err(ParameterError(
+                "signature is not adapted from the pre-signature".to_string(),
+            ));
+        }
+        let secret = sig.s - pre_sig.s;
+        if Projective::<P>::generator() * secret != *adaptor {
+            return Err(ParameterError(
+                "extracted secret does not match the adaptor point".to_string(),
+            ));
+        }
+        Ok(secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{schnorr::SchnorrSignatureScheme, SignatureScheme};
+    use ark_ed_on_bls12_377::EdwardsConfig as Param377;
+    use ark_ed_on_bls12_381::EdwardsConfig as Param381;
+    use ark_ed_on_bls12_381_bandersnatch::EdwardsConfig as Param381b;
+    use ark_ed_on_bn254::EdwardsConfig as Param254;
+
+    fn test_adaptor_signature_helper<F, P>()
+    where
+        F: RescueParameter,
+        P: Config<BaseField = F> + Clone,
+    {
+        let mut rng = jf_utils::test_rng();
+        let keypair = KeyPair::<P>::generate(&mut rng);
+        let vk = keypair.ver_key();
+        let msg = vec![F::from(1u8), F::from(2u8), F::from(3u8)];
+        let (secret, adaptor) = SchnorrAdaptor::<P>::adaptor_gen(&mut rng);
+
+        let pre_sig = SchnorrAdaptor::<P>::pre_sign(&keypair, &msg, &adaptor, &mut rng);
+        assert!(SchnorrAdaptor::<P>::pre_verify(&vk, &msg, &adaptor, &pre_sig).is_ok());
+        // wrong message, key or adaptor point
+        let bad_msg = vec![F::from(1u8)];
+        assert!(SchnorrAdaptor::<P>::pre_verify(&vk, &bad_msg, &adaptor, &pre_sig).is_err());
+        let bad_vk = KeyPair::<P>::generate(&mut rng).ver_key();
+        assert!(SchnorrAdaptor::<P>::pre_verify(&bad_vk, &msg, &adaptor, &pre_sig).is_err());
+        let (_, bad_adaptor) = SchnorrAdaptor::<P>::adaptor_gen(&mut rng);
+        assert!(SchnorrAdaptor::<P>::pre_verify(&vk, &msg, &bad_adaptor, &pre_sig).is_err());
+
+        // a pre-signature is not a signature
+        let not_adapted = Signature {
+            s: pre_sig.s,
+            R: pre_sig.R,
+        };
+        assert!(SchnorrSignatureScheme::<P>::verify(&(), &vk, &msg, &not_adapted).is_err());
+
+        // adapt, then extract the secret
+        let sig = SchnorrAdaptor::<P>::adapt(&pre_sig, &secret);
+        assert!(SchnorrSignatureScheme::<P>::verify(&(), &vk, &msg, &sig).is_ok());
+        assert_eq!(
+            SchnorrAdaptor::<P>::extract(&pre_sig, &sig, &adaptor).unwrap(),
+            secret
+        );
+        assert!(SchnorrAdaptor::<P>::extract(&pre_sig, &sig, &bad_adaptor).is_err());
+        let other_sig =
+            SchnorrSignatureScheme::<P>::sign(&(), keypair.sign_key_ref(), &msg, &mut rng).unwrap();
+        assert!(SchnorrAdaptor::<P>::extract(&pre_sig, &other_sig, &adaptor).is_err());
+
+        // a wrong secret does not adapt into a valid signature
+        let sig = SchnorrAdaptor::<P>::adapt(&pre_sig, &(secret + P::ScalarField::from(1u8)));
+        assert!(SchnorrSignatureScheme::<P>::verify(&(), &vk, &msg, &sig).is_err());
+    }
+
+    #[test]
+    fn test_adaptor_signature() {
+        test_adaptor_signature_helper::<_, Param254>();
+        test_adaptor_signature_helper::<_, Param377>();
+        test_adaptor_signature_helper::<_, Param381>();
+        test_adaptor_signature_helper::<_, Param381b>();
+    }
+}
@@ -0,0 +1,291 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements a compact wire format for aggregate BLS signatures
+//! over BN254 by a subset of a committee, as exchanged by consensus
+//! protocols.
+//!
+//! An [`AggregateCertificate`] holds the bitfield of the committee members
+//! who signed, their aggregate signature on a common message and, optionally,
+//! their aggregate verification key, so that a receiver can skip summing the
+//! keys of the signers. It is encoded by [`AggregateCertificate::to_bytes`]
+//! as:
+//!
+//! * the committee size `n`, as 4 little-endian bytes,
+//! * the bitfield, in `ceil(n / 8)` bytes, where committee member `i` is the
+//!   bit `i % 8` (least significant first) of byte `i / 8`, and the unused bits
+//!   of the last byte are zero,
+//! * a flag byte, 1 if the aggregate verification key is present and 0
+//!   otherwise,
+//! * the aggregate signature, as a compressed G1 point of 32 bytes,
+//! * the aggregate verification key if present, as a compressed G2 point of 64
+//!   bytes.
+//!
+//! The encoding is canonical: [`AggregateCertificate::from_bytes`] rejects
+//! any other byte string than the encoding of a certificate.
+//!
+//! As for [`BLSOverBN254CurveSignatureScheme::fast_aggregate_verify`], the
+//! verification keys of the committee MUST have been checked with
+//! [`VerKey::verify_possession`] beforehand.
+
+use crate::{
+    bls_over_bn254::{BLSOverBN254CurveSignatureScheme, Signature, VerKey},
+    constants::CS_ID_BLS_BN254,
+    AggregateableSignatureSchemes,
+    SignatureError::{self, ParameterError, VerificationError},
+};
+use ark_bn254::{G1Affine, G2Affine, G2Projective};
+use ark_ec::CurveGroup;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{format, string::ToString, vec, vec::Vec, Zero};
+
+/// Size in bytes of a compressed aggregate signature.
+const COMPRESSED_SIG_SIZE: usize = 32;
+/// Size in bytes of a compressed aggregate verification key.
+const COMPRESSED_VK_SIZE: usize = 64;
+
+/// Aggregate signature of a subset of a committee on a common message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregateCertificate {
+    signers: Vec<bool>,
+    sig: Signature,
+    agg_vk: Option<VerKey>,
+}
+
+impl AggregateCertificate {
+    /// Aggregate the signatures `sigs` of the committee members at `indices`
+    /// on a common message, in a committee of verification keys `committee`.
+    /// The aggregate verification key is included if `with_agg_vk` is set.
+    pub fn aggregate(
+        committee: &[VerKey],
+        indices: &[usize],
+        sigs: &[Signature],
+        with_agg_vk: bool,
+    ) -> Result<Self, SignatureError> {
+        if indices.len() != sigs.len() {
+            return Err(ParameterError(format!(
+                "{} signer indices for {} signatures",
+                indices.len(),
+                sigs.len()
+            )));
+        }
+        let mut signers = vec![false; committee.len()];
+        for &i in indices {
+            match signers.get_mut(i) {
+                Some(signed) if !*signed => *signed = true,
+                Some(_) => {
+                    return Err(ParameterError(format!("duplicated signer index {i}")));
+                },
+                None => {
+                    return Err(ParameterError(format!(
+                        "signer index {i} out of a committee of {}",
+                        committee.len()
+                    )));
+                },
+            }
+        }
+        let sig = BLSOverBN254CurveSignatureScheme::aggregate(&(), &[], sigs)?;
+        let agg_vk = with_agg_vk.then(|| Self::sum_ver_keys(committee, &signers));
+        Ok(Self {
+            signers,
+            sig,
+            agg_vk,
+        })
+    }
+
+    /// Verify the certificate on `msg` against the committee of verification
+    /// keys `committee`. If the certificate carries an aggregate verification
+    /// key, it must be the sum of the keys of the signers.
+    pub fn verify(&self, committee: &[VerKey], msg: &[u8]) -> Result<(), SignatureError> {
+        if committee.len() != self.signers.len() {
+            return Err(ParameterError(format!(
+                "certificate for a committee of {}, got {} verification keys",
+                self.signers.len(),
+                committee.len()
+            )));
+        }
+        let agg_vk = Self::sum_ver_keys(committee, &self.signers);
+        if self.agg_vk.map_or(false, |vk| vk != agg_vk) {
+            return Err(VerificationError(
+                "aggregate verification key does not match the signers".to_string(),
+            ));
+        }
+        agg_vk.verify(msg, &self.sig, CS_ID_BLS_BN254)
+    }
+
+    /// The bitfield of the committee members who signed.
+    pub fn signers(&self) -> &[bool] {
+        &self.signers
+    }
+
+    /// The number of committee members who signed.
+    pub fn num_signers(&self) -> usize {
+        self.signers.iter().filter(|signed| **signed).count()
+    }
+
+    /// The aggregate signature.
+    pub fn signature(&self) -> &Signature {
+        &self.sig
+    }
+
+    /// The aggregate verification key, if included.
+    pub fn agg_ver_key(&self) -> Option<&VerKey> {
+        self.agg_vk.as_ref()
+    }
+
+    /// Encode the certificate, see the [module-level documentation](self).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let n = self.signers.len();
+        let mut bytes =
+            Vec::with_capacity(4 + (n + 7) / 8 + 1 + COMPRESSED_SIG_SIZE + COMPRESSED_VK_SIZE);
+        bytes.extend_from_slice(&(n as u32).to_le_bytes());
+        for chunk in self.signers.chunks(8) {
+            bytes.push(
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0u8, |byte, (j, signed)| byte | ((*signed as u8) << j)),
+            );
+        }
+        bytes.push(self.agg_vk.is_some() as u8);
+        // serializing into a vector can't fail
+        self.sig
+            .sigma
+            .into_affine()
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        if let Some(vk) = &self.agg_vk {
+            vk.to_affine().serialize_compressed(&mut bytes).unwrap();
+        }
+        bytes
+    }
+
+    /// Decode a certificate encoded with [`Self::to_bytes`], checking that the
+    /// points are in the prime-order subgroups.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SignatureError> {
+        let invalid = |what: &str| ParameterError(format!("invalid certificate encoding: {what}"));
+
+        let n_bytes: [u8; 4] = bytes
+            .get(..4)
+            .and_then(|n| n.try_into().ok())
+            .ok_or_else(|| invalid("missing committee size"))?;
+        let n = u32::from_le_bytes(n_bytes) as usize;
+        let bitfield_len = (n + 7) / 8;
+        let rest = &bytes[4..];
+        if rest.len() < bitfield_len + 1 {
+            return Err(invalid("truncated bitfield"));
+        }
+        let (bitfield, rest) = rest.split_at(bitfield_len);
+        let signers: Vec<bool> = (0..n)
+            .map(|i| (bitfield[i / 8] >> (i % 8)) & 1 == 1)
+            .collect();
+        if n % 8 != 0 && bitfield[bitfield_len - 1] >> (n % 8) != 0 {
+            return Err(invalid("non-zero padding bits"));
+        }
+
+        let (flag, rest) = (rest[0], &rest[1..]);
+        let expected_len = match flag {
+            0 => COMPRESSED_SIG_SIZE,
+            1 => COMPRESSED_SIG_SIZE + COMPRESSED_VK_SIZE,
+            _ => return Err(invalid("unknown flag")),
+        };
+        if rest.len() != expected_len {
+            return Err(invalid("wrong length"));
+        }
+        let sigma = G1Affine::deserialize_compressed(&rest[..COMPRESSED_SIG_SIZE])
+            .map_err(|_| invalid("signature not in G1"))?;
+        let agg_vk = if flag == 1 {
+            let vk = G2Affine::deserialize_compressed(&rest[COMPRESSED_SIG_SIZE..])
+                .map_err(|_| invalid("verification key not in G2"))?;
+            Some(VerKey(vk.into()))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            signers,
+            sig: Signature {
+                sigma: sigma.into(),
+            },
+            agg_vk,
+        })
+    }
+
+    fn sum_ver_keys(committee: &[VerKey], signers: &[bool]) -> VerKey {
+        VerKey(
+            committee
+                .iter()
+                .zip(signers)
+                .filter(|(_, signed)| **signed)
+                .fold(G2Projective::zero(), |sum, (vk, _)| sum + vk.0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls_over_bn254::KeyPair;
+
+    #[test]
+    fn test_aggregate_certificate() {
+        let mut rng = jf_utils::test_rng();
+        let msg = b"block 42";
+        let key_pairs: Vec<_> = (0..11).map(|_| KeyPair::generate(&mut rng)).collect();
+        let committee: Vec<_> = key_pairs.iter().map(|kp| kp.ver_key()).collect();
+        let indices = [0, 3, 4, 8, 10];
+        let sigs: Vec<_> = indices
+            .iter()
+            .map(|i| key_pairs[*i].sign(msg, CS_ID_BLS_BN254))
+            .collect();
+
+        for with_agg_vk in [false, true] {
+            let cert =
+                AggregateCertificate::aggregate(&committee, &indices, &sigs, with_agg_vk).unwrap();
+            assert_eq!(cert.num_signers(), indices.len());
+            assert!(cert.signers()[3] && !cert.signers()[5]);
+            assert!(cert.verify(&committee, msg).is_ok());
+            assert!(cert.verify(&committee, b"block 43").is_err());
+            assert!(cert.verify(&committee[1..], msg).is_err());
+
+            let bytes = cert.to_bytes();
+            assert_eq!(
+                bytes.len(),
+                4 + 2 + 1 + COMPRESSED_SIG_SIZE + with_agg_vk as usize * COMPRESSED_VK_SIZE
+            );
+            assert_eq!(AggregateCertificate::from_bytes(&bytes).unwrap(), cert);
+
+            // non-canonical encodings
+            let mut bad = bytes.clone();
+            bad[5] |= 0x80;
+            assert!(AggregateCertificate::from_bytes(&bad).is_err());
+            let mut bad = bytes.clone();
+            bad[6] = 2;
+            assert!(AggregateCertificate::from_bytes(&bad).is_err());
+            let mut bad = bytes.clone();
+            bad.push(0);
+            assert!(AggregateCertificate::from_bytes(&bad).is_err());
+            assert!(AggregateCertificate::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+            // a different set of signers
+            let mut bad = bytes.clone();
+            bad[4] ^= 0x02;
+            let bad_cert = AggregateCertificate::from_bytes(&bad).unwrap();
+            assert!(bad_cert.verify(&committee, msg).is_err());
+        }
+
+        // a wrong aggregate verification key
+        let mut cert = AggregateCertificate::aggregate(&committee, &indices, &sigs, true).unwrap();
+        cert.agg_vk = Some(committee[0]);
+        assert!(cert.verify(&committee, msg).is_err());
+
+        // invalid signer indices
+        assert!(AggregateCertificate::aggregate(&committee, &[0, 0], &sigs[..2], false).is_err());
+        assert!(AggregateCertificate::aggregate(&committee, &[11], &sigs[..1], false).is_err());
+        assert!(AggregateCertificate::aggregate(&committee, &[0], &sigs[..2], false).is_err());
+        assert!(AggregateCertificate::aggregate(&committee, &[], &[], false).is_err());
+    }
+}
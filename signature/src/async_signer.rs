@@ -0,0 +1,182 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Asynchronous signers, for signing keys held outside of the process, e.g.
+//! in an HSM, a cloud KMS or a hardware wallet.
+//!
+//! An [`AsyncSigner`] only exposes the verification key and an asynchronous
+//! signing function; the signatures it produces are the ones of the
+//! underlying [`SignatureScheme`] and are verified with
+//! [`SignatureScheme::verify`] as usual. [`LocalSigner`] implements the trait
+//! with an in-memory signing key, e.g. for tests.
+
+use crate::{SignatureError, SignatureScheme};
+use ark_std::{boxed::Box, future::Future, pin::Pin};
+
+/// Future returned by [`AsyncSigner::sign`].
+pub type SignFuture<'a, S> = Pin<
+    Box<dyn Future<Output = Result<<S as SignatureScheme>::Signature, SignatureError>> + Send + 'a>,
+>;
+
+/// A signer holding the signing key of the signature scheme `S`, which may
+/// live in a remote or hardware device.
+pub trait AsyncSigner<S: SignatureScheme>: Send + Sync {
+    /// The verification key of the signer.
+    fn ver_key(&self) -> S::VerificationKey;
+
+    /// Sign a message.
+    fn sign<'a>(&'a self, msg: &'a [S::MessageUnit]) -> SignFuture<'a, S>;
+
+    /// Sign a message and check the signature against [`Self::ver_key`]
+    /// before returning it, to detect faulty or misconfigured devices.
+    fn sign_checked<'a>(
+        &'a self,
+        pp: &'a S::PublicParameter,
+        msg: &'a [S::MessageUnit],
+    ) -> SignFuture<'a, S>
+    where
+        S::PublicParameter: Sync,
+    {
+        Box::pin(async move {
+            let sig = self.sign(msg).await?;
+            S::verify(pp, &self.ver_key(), msg, &sig)?;
+            Ok(sig)
+        })
+    }
+}
+
+#[cfg(any(test, feature = "std"))]
+pub use local::LocalSigner;
+
+#[cfg(any(test, feature = "std"))]
+mod local {
+    use super::{AsyncSigner, SignFuture};
+    use crate::{SignatureError, SignatureScheme};
+    use ark_std::{
+        boxed::Box,
+        future,
+        rand::{CryptoRng, RngCore},
+        string::ToString,
+    };
+    use std::sync::Mutex;
+
+    /// An [`AsyncSigner`] holding its signing key in memory, signing with
+    /// randomness from its own RNG.
+    pub struct LocalSigner<S: SignatureScheme, R> {
+        pp: S::PublicParameter,
+        sk: S::SigningKey,
+        vk: S::VerificationKey,
+        prng: Mutex<R>,
+    }
+
+    impl<S: SignatureScheme, R: CryptoRng + RngCore> LocalSigner<S, R> {
+        /// Create a signer with a fresh key pair, sampled with `prng` which is
+        /// then used for signing.
+        pub fn new(pp: S::PublicParameter, mut prng: R) -> Result<Self, SignatureError> {
+            let (sk, vk) = S::key_gen(&pp, &mut prng)?;
+            Ok(Self::from_keys(pp, sk, vk, prng))
+        }
+
+        /// Create a signer from an existing key pair.
+        pub fn from_keys(
+            pp: S::PublicParameter,
+            sk: S::SigningKey,
+            vk: S::VerificationKey,
+            prng: R,
+        ) -> Self {
+            Self {
+                pp,
+                sk,
+                vk,
+                prng: Mutex::new(prng),
+            }
+        }
+    }
+
+    impl<S, R> AsyncSigner<S> for LocalSigner<S, R>
+    where
+        S: SignatureScheme,
+        S::PublicParameter: Send + Sync,
+        R: CryptoRng + RngCore + Send,
+    {
+        fn ver_key(&self) -> S::VerificationKey {
+            self.vk.clone()
+        }
+
+        fn sign<'a>(&'a self, msg: &'a [S::MessageUnit]) -> SignFuture<'a, S> {
+            let sig = match self.prng.lock() {
+                Ok(mut prng) => S::sign(&self.pp, &self.sk, msg, &mut *prng),
+                Err(_) => Err(SignatureError::ParameterError(
+                    "signer RNG lock poisoned".to_string(),
+                )),
+            };
+            Box::pin(future::ready(sig))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bls_over_bls12381::BLSSignatureScheme, bls_over_bn254::BLSOverBN254CurveSignatureScheme,
+        schnorr::SchnorrSignatureScheme,
+    };
+    use ark_ed_on_bn254::{EdwardsConfig as Param254, Fq as FqEd254};
+    use ark_std::vec;
+    use futures::executor::block_on;
+
+    fn test_async_signer_helper<S>(msg: &[S::MessageUnit], bad_msg: &[S::MessageUnit])
+    where
+        S: SignatureScheme<PublicParameter = ()>,
+    {
+        let signer = LocalSigner::<S, _>::new((), jf_utils::test_rng()).unwrap();
+        let sig = block_on(signer.sign(msg)).unwrap();
+        assert!(S::verify(&(), &signer.ver_key(), msg, &sig).is_ok());
+        assert!(S::verify(&(), &signer.ver_key(), bad_msg, &sig).is_err());
+        let sig = block_on(signer.sign_checked(&(), msg)).unwrap();
+        assert!(S::verify(&(), &signer.ver_key(), msg, &sig).is_ok());
+
+        // usable as a trait object
+        let signer: Box<dyn AsyncSigner<S>> = Box::new(signer);
+        assert!(block_on(signer.sign_checked(&(), bad_msg)).is_ok());
+    }
+
+    #[test]
+    fn test_async_signer() {
+        test_async_signer_helper::<BLSSignatureScheme>(b"message", b"wrong message");
+        test_async_signer_helper::<BLSOverBN254CurveSignatureScheme>(b"message", b"wrong");
+        test_async_signer_helper::<SchnorrSignatureScheme<Param254>>(
+            &[FqEd254::from(1u8)],
+            &[FqEd254::from(2u8)],
+        );
+    }
+
+    // A faulty device returning signatures under another key.
+    struct FaultySigner(LocalSigner<BLSSignatureScheme, rand_chacha::ChaCha20Rng>);
+
+    impl AsyncSigner<BLSSignatureScheme> for FaultySigner {
+        fn ver_key(&self) -> <BLSSignatureScheme as SignatureScheme>::VerificationKey {
+            let (_, vk) = BLSSignatureScheme::key_gen(&(), &mut jf_utils::test_rng()).unwrap();
+            vk
+        }
+
+        fn sign<'a>(&'a self, msg: &'a [u8]) -> SignFuture<'a, BLSSignatureScheme> {
+            self.0.sign(msg)
+        }
+    }
+
+    #[test]
+    fn test_sign_checked() {
+        use rand_chacha::rand_core::SeedableRng;
+        let signer = FaultySigner(
+            LocalSigner::new((), rand_chacha::ChaCha20Rng::from_seed([7u8; 32])).unwrap(),
+        );
+        let msg = vec![1u8, 2, 3];
+        assert!(block_on(signer.sign(&msg)).is_ok());
+        assert!(block_on(signer.sign_checked(&(), &msg)).is_err());
+    }
+}
@@ -70,8 +70,36 @@
 //! ```
 //!
 //! [zeroize]: https://github.com/RustCrypto/utils/tree/master/zeroize
+//!
+//! ## Aggregating signatures on distinct messages
+//!
+//! Signatures of different signers on pairwise distinct messages can be
+//! aggregated into a single signature, which is verified against all the
+//! (public key, message) pairs at once. As required by the basic scheme of
+//! the [IRTF draft][irtf] to rule out rogue-key attacks, verification fails
+//! if any two messages are equal.
+//!
+//! ```
+//! use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
+//! use jf_signature::{SignatureScheme, bls_over_bls12381::BLSSignatureScheme};
+//!
+//! let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+//! let (sk1, pk1) = BLSSignatureScheme::key_gen(&(), &mut rng)?;
+//! let (sk2, pk2) = BLSSignatureScheme::key_gen(&(), &mut rng)?;
+//!
+//! let sig1 = BLSSignatureScheme::sign(&(), &sk1, "message 1", &mut rng)?;
+//! let sig2 = BLSSignatureScheme::sign(&(), &sk2, "message 2", &mut rng)?;
+//! let agg_sig = BLSSignatureScheme::aggregate(&[sig1, sig2])?;
+//! assert!(BLSSignatureScheme::aggregate_verify(
+//!     &[(pk1, "message 1"), (pk2, "message 2")],
+//!     &agg_sig
+//! )
+//! .is_ok());
+//!
+//! # Ok::<(), Box<dyn ark_std::error::Error>>(())
+//! ```
 
-use super::SignatureScheme;
+use super::{ContextSignatureScheme, SignatureScheme};
 use crate::{
     constants::{
         tag, BLS_SIG_COMPRESSED_PK_SIZE, BLS_SIG_COMPRESSED_SIGNATURE_SIZE, BLS_SIG_PK_SIZE,
@@ -80,21 +108,23 @@ use crate::{
     SignatureError,
 };
 
-use crate::constants::CS_ID_BLS_MIN_SIG;
+use crate::constants::{CS_ID_BLS_MIN_SIG, CS_ID_BLS_MIN_SIG_CTX};
 use ark_serialize::*;
 use ark_std::{
     format,
     ops::{Deref, DerefMut},
     rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec::Vec,
 };
-use blst::{min_sig::*, BLST_ERROR};
+use blst::{blst_scalar, min_sig::*, BLST_ERROR};
 use derivative::Derivative;
+use hashbrown::HashSet;
 use tagged_base64::tagged;
-use zeroize::{Zeroize, Zeroizing};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 #[tagged(tag::BLS_SIGNING_KEY)]
-#[derive(Clone, Derivative, Zeroize)]
-#[zeroize(drop)]
+#[derive(Clone, Derivative, Zeroize, ZeroizeOnDrop)]
 #[derivative(Debug)]
 /// A BLS Secret Key (Signing Key).
 pub struct BLSSignKey(#[derivative(Debug = "ignore")] SecretKey);
@@ -172,6 +202,12 @@ impl Deref for BLSVerKey {
     }
 }
 
+impl From<&BLSSignKey> for BLSVerKey {
+    fn from(sk: &BLSSignKey) -> Self {
+        BLSVerKey(sk.sk_to_pk())
+    }
+}
+
 impl CanonicalSerialize for BLSVerKey {
     fn serialize_with_mode<W: Write>(
         &self,
@@ -395,6 +431,49 @@ impl SignatureScheme for BLSSignatureScheme {
     }
 }
 
+impl ContextSignatureScheme for BLSSignatureScheme {
+    /// Sign a message under the context `ctx`, hashing the message to the
+    /// curve with the DST [`CS_ID_BLS_MIN_SIG_CTX`] || `ctx`. Return an error
+    /// if the DST is longer than 255 bytes.
+    fn sign_with_context<R: CryptoRng + RngCore, M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        sk: &Self::SigningKey,
+        ctx: &[u8],
+        msg: M,
+        _prng: &mut R,
+    ) -> Result<Self::Signature, SignatureError> {
+        let dst = context_dst(ctx)?;
+        Ok(BLSSignature(sk.sign(msg.as_ref(), &dst, &[])))
+    }
+
+    /// Verify a signature under the context `ctx`.
+    fn verify_with_context<M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        vk: &Self::VerificationKey,
+        ctx: &[u8],
+        msg: M,
+        sig: &Self::Signature,
+    ) -> Result<(), SignatureError> {
+        let dst = context_dst(ctx)?;
+        match sig.verify(false, msg.as_ref(), &dst, &[], vk, true) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            e => Err(SignatureError::VerificationError(format!("{e:?}"))),
+        }
+    }
+}
+
+// The hash-to-curve DST of signatures under the context `ctx`.
+fn context_dst(ctx: &[u8]) -> Result<Vec<u8>, SignatureError> {
+    let dst = [CS_ID_BLS_MIN_SIG_CTX.as_bytes(), ctx].concat();
+    if dst.len() > 255 {
+        return Err(SignatureError::ParameterError(format!(
+            "context of {} bytes is too long for the hash-to-curve DST",
+            ctx.len()
+        )));
+    }
+    Ok(dst)
+}
+
 impl BLSSignatureScheme {
     /// Alternative deterministic key_gen compatible with [IRTF draft v5][v5].
     ///
@@ -425,12 +504,158 @@ impl BLSSignatureScheme {
 
         Ok((BLSSignKey(sk), BLSVerKey(vk)))
     }
+
+    /// Hierarchical deterministic key derivation following [EIP-2333][eip2333]:
+    /// derive the master key from `seed`, which MUST be at least 32 bytes
+    /// long, then the child keys along `path`, e.g. `[12381, 3600, 0, 0]` for
+    /// the `m/12381/3600/0/0` path of [EIP-2334][eip2334].
+    ///
+    /// Every derivation step is hardened: a child verification key cannot be
+    /// computed from its parent verification key. Public derivation would
+    /// make each child signing key and the parent verification key enough to
+    /// recover the parent signing key, see [`crate::hd`] for Schnorr keys.
+    ///
+    /// [eip2333]: https://eips.ethereum.org/EIPS/eip-2333
+    /// [eip2334]: https://eips.ethereum.org/EIPS/eip-2334
+    pub fn derive_key(
+        seed: &[u8],
+        path: &[u32],
+    ) -> Result<
+        (
+            <Self as SignatureScheme>::SigningKey,
+            <Self as SignatureScheme>::VerificationKey,
+        ),
+        SignatureError,
+    > {
+        let master = SecretKey::derive_master_eip2333(seed)?;
+        let sk = path
+            .iter()
+            .fold(master, |sk, index| sk.derive_child_eip2333(*index));
+        let vk = sk.sk_to_pk();
+
+        Ok((BLSSignKey(sk), BLSVerKey(vk)))
+    }
+
+    /// Derive the [EIP-2333][eip2333] child key at `index` of the signing key
+    /// `sk`, see [`Self::derive_key`].
+    ///
+    /// [eip2333]: https://eips.ethereum.org/EIPS/eip-2333
+    pub fn derive_child_key(
+        sk: &<Self as SignatureScheme>::SigningKey,
+        index: u32,
+    ) -> (
+        <Self as SignatureScheme>::SigningKey,
+        <Self as SignatureScheme>::VerificationKey,
+    ) {
+        let sk = sk.derive_child_eip2333(index);
+        let vk = sk.sk_to_pk();
+        (BLSSignKey(sk), BLSVerKey(vk))
+    }
+
+    /// Aggregate signatures, possibly on different messages, into a single
+    /// signature, to be verified with [`Self::aggregate_verify`]. Every
+    /// signature is checked to be in the prime-order subgroup.
+    pub fn aggregate(
+        sigs: &[<Self as SignatureScheme>::Signature],
+    ) -> Result<<Self as SignatureScheme>::Signature, SignatureError> {
+        if sigs.is_empty() {
+            return Err(SignatureError::ParameterError(
+                "no signatures to aggregate".to_string(),
+            ));
+        }
+        let sigs: Vec<&Signature> = sigs.iter().map(|sig| &sig.0).collect();
+        let agg_sig = AggregateSignature::aggregate(&sigs, true)?;
+        Ok(BLSSignature(agg_sig.to_signature()))
+    }
+
+    /// Verify an aggregate signature w.r.t. a list of (verification key,
+    /// message) pairs, following `AggregateVerify` of the basic scheme of the
+    /// [IRTF draft][v5].
+    ///
+    /// The messages MUST be pairwise distinct, otherwise a rogue verification
+    /// key could forge an aggregate signature on a message shared with honest
+    /// signers: the verification fails in that case. The verification keys
+    /// are validated and the signature is checked to be in the prime-order
+    /// subgroup.
+    ///
+    /// [v5]: https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-aggregateverify
+    pub fn aggregate_verify<M: AsRef<[u8]>>(
+        pairs: &[(<Self as SignatureScheme>::VerificationKey, M)],
+        sig: &<Self as SignatureScheme>::Signature,
+    ) -> Result<(), SignatureError> {
+        if pairs.is_empty() {
+            return Err(SignatureError::ParameterError(
+                "no verification key for signature verification".to_string(),
+            ));
+        }
+        let msgs: Vec<&[u8]> = pairs.iter().map(|(_, msg)| msg.as_ref()).collect();
+        let mut distinct_msgs = HashSet::with_capacity(msgs.len());
+        if !msgs.iter().all(|msg| distinct_msgs.insert(*msg)) {
+            return Err(SignatureError::VerificationError(
+                "aggregated messages are not distinct".to_string(),
+            ));
+        }
+        let vks: Vec<&PublicKey> = pairs.iter().map(|(vk, _)| &vk.0).collect();
+
+        match sig.aggregate_verify(true, &msgs, Self::CS_ID.as_bytes(), &vks, true) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            e => Err(SignatureError::VerificationError(format!("{e:?}"))),
+        }
+    }
+
+    /// Verify a batch of signatures, each on its own message under its own
+    /// verification key, with a single multi-pairing. Each signature is
+    /// weighted by a random 128-bit scalar sampled from `prng`, so the batch
+    /// fails, without telling which, as soon as one signature is invalid.
+    /// The verification keys are validated and the signatures are checked to
+    /// be in the prime-order subgroup.
+    pub fn batch_verify<M: AsRef<[u8]>, R: CryptoRng + RngCore>(
+        triples: &[(
+            <Self as SignatureScheme>::VerificationKey,
+            M,
+            <Self as SignatureScheme>::Signature,
+        )],
+        prng: &mut R,
+    ) -> Result<(), SignatureError> {
+        if triples.is_empty() {
+            return Err(SignatureError::ParameterError(
+                "no signature to batch verify".to_string(),
+            ));
+        }
+        let msgs: Vec<&[u8]> = triples.iter().map(|(_, msg, _)| msg.as_ref()).collect();
+        let vks: Vec<&PublicKey> = triples.iter().map(|(vk, ..)| &vk.0).collect();
+        let sigs: Vec<&Signature> = triples.iter().map(|(.., sig)| &sig.0).collect();
+        let rands: Vec<blst_scalar> = triples
+            .iter()
+            .map(|_| {
+                let mut rand = blst_scalar { b: [0u8; 32] };
+                while rand.b[..16].iter().all(|b| *b == 0) {
+                    prng.fill_bytes(&mut rand.b[..16]);
+                }
+                rand
+            })
+            .collect();
+
+        match Signature::verify_multiple_aggregate_signatures(
+            &msgs,
+            Self::CS_ID.as_bytes(),
+            &vks,
+            true,
+            &sigs,
+            true,
+            &rands,
+            128,
+        ) {
+            BLST_ERROR::BLST_SUCCESS => Ok(()),
+            e => Err(SignatureError::VerificationError(format!("{e:?}"))),
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::tests::{failed_verification, sign_and_verify};
+    use crate::tests::{context_sign_and_verify, failed_verification, sign_and_verify};
     use ark_std::{fmt::Debug, vec};
 
     #[test]
@@ -441,6 +666,142 @@ mod test {
         failed_verification::<BLSSignatureScheme>(message.as_ref(), message_bad.as_ref());
     }
 
+    #[test]
+    fn test_bls_sig_with_context() {
+        context_sign_and_verify::<BLSSignatureScheme>(b"this is a test message");
+
+        let mut rng = jf_utils::test_rng();
+        let (sk, _) = BLSSignatureScheme::key_gen(&(), &mut rng).unwrap();
+        let max_ctx = [0u8; 255 - CS_ID_BLS_MIN_SIG_CTX.len()];
+        assert!(BLSSignatureScheme::sign_with_context(&(), &sk, &max_ctx, "msg", &mut rng).is_ok());
+        assert!(
+            BLSSignatureScheme::sign_with_context(&(), &sk, &[0u8; 256], "msg", &mut rng).is_err()
+        );
+    }
+
+    #[test]
+    fn test_bls_aggregate_sig() {
+        let mut rng = jf_utils::test_rng();
+        let msgs = ["message 0", "message 1", "message 2", "message 3"];
+        let mut pairs = vec![];
+        let mut sigs = vec![];
+        for msg in msgs.iter() {
+            let (sk, pk) = BLSSignatureScheme::key_gen(&(), &mut rng).unwrap();
+            sigs.push(BLSSignatureScheme::sign(&(), &sk, msg, &mut rng).unwrap());
+            pairs.push((pk, *msg));
+        }
+        let agg_sig = BLSSignatureScheme::aggregate(&sigs).unwrap();
+        assert!(BLSSignatureScheme::aggregate_verify(&pairs, &agg_sig).is_ok());
+        // a single signature is its own aggregate
+        let single = BLSSignatureScheme::aggregate(&sigs[..1]).unwrap();
+        assert!(BLSSignatureScheme::aggregate_verify(&pairs[..1], &single).is_ok());
+
+        // empty inputs
+        assert!(BLSSignatureScheme::aggregate(&[]).is_err());
+        assert!(BLSSignatureScheme::aggregate_verify::<&str>(&[], &agg_sig).is_err());
+        // missing pair
+        assert!(BLSSignatureScheme::aggregate_verify(&pairs[1..], &agg_sig).is_err());
+        // wrong message
+        let mut bad_pairs = pairs.clone();
+        bad_pairs[0].1 = "wrong message";
+        assert!(BLSSignatureScheme::aggregate_verify(&bad_pairs, &agg_sig).is_err());
+        // swapped verification keys
+        let mut bad_pairs = pairs.clone();
+        bad_pairs.swap(0, 1);
+        bad_pairs[0].1 = pairs[0].1;
+        bad_pairs[1].1 = pairs[1].1;
+        assert!(BLSSignatureScheme::aggregate_verify(&bad_pairs, &agg_sig).is_err());
+
+        // duplicated messages are rejected, even with valid signatures
+        let (sk, pk) = BLSSignatureScheme::key_gen(&(), &mut rng).unwrap();
+        sigs.push(BLSSignatureScheme::sign(&(), &sk, msgs[0], &mut rng).unwrap());
+        pairs.push((pk, msgs[0]));
+        let agg_sig = BLSSignatureScheme::aggregate(&sigs).unwrap();
+        assert_eq!(
+            BLSSignatureScheme::aggregate_verify(&pairs, &agg_sig),
+            Err(SignatureError::VerificationError(
+                "aggregated messages are not distinct".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_bls_batch_verify() {
+        let mut rng = jf_utils::test_rng();
+        let mut triples = vec![];
+        for i in 0..8 {
+            let (sk, pk) = BLSSignatureScheme::key_gen(&(), &mut rng).unwrap();
+            // messages need not be distinct
+            let msg = if i < 2 { "shared message" } else { "message" };
+            let sig = BLSSignatureScheme::sign(&(), &sk, msg, &mut rng).unwrap();
+            triples.push((pk, msg, sig));
+        }
+        assert!(BLSSignatureScheme::batch_verify(&triples, &mut rng).is_ok());
+        assert!(BLSSignatureScheme::batch_verify(&triples[..1], &mut rng).is_ok());
+        assert!(BLSSignatureScheme::batch_verify::<&str, _>(&[], &mut rng).is_err());
+
+        // a wrong message, key or signature
+        let mut bad_triples = triples.clone();
+        bad_triples[3].1 = "wrong message";
+        assert!(BLSSignatureScheme::batch_verify(&bad_triples, &mut rng).is_err());
+        let mut bad_triples = triples.clone();
+        bad_triples[3].0 = triples[4].0;
+        assert!(BLSSignatureScheme::batch_verify(&bad_triples, &mut rng).is_err());
+        // two swapped signatures, which an unweighted sum would not detect
+        let mut bad_triples = triples.clone();
+        bad_triples[2].2 = triples[3].2;
+        bad_triples[3].2 = triples[2].2;
+        assert!(BLSSignatureScheme::batch_verify(&bad_triples, &mut rng).is_err());
+    }
+
+    // Test case 0 of EIP-2333.
+    #[test]
+    fn test_eip2333_derivation() {
+        use num_bigint::BigUint;
+
+        let seed_hex = "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e5349553\
+                        1f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04";
+        let seed: Vec<u8> = (0..seed_hex.len() / 2)
+            .map(|i| u8::from_str_radix(&seed_hex[2 * i..2 * i + 2], 16).unwrap())
+            .collect();
+        let to_int = |sk: &BLSSignKey| BigUint::from_bytes_be(&sk.to_bytes());
+
+        let (master, _) = BLSSignatureScheme::derive_key(&seed, &[]).unwrap();
+        assert_eq!(
+            to_int(&master).to_string(),
+            "6083874454709270928345386274498605044986640685124978867557563392430687146096"
+        );
+        let (child, child_pk) = BLSSignatureScheme::derive_key(&seed, &[0]).unwrap();
+        assert_eq!(
+            to_int(&child).to_string(),
+            "20397789859736650942317412262472558107875392172444076792671091975210932703118"
+        );
+        assert_eq!(
+            BLSSignatureScheme::derive_child_key(&master, 0),
+            (child.clone(), child_pk)
+        );
+
+        let mut rng = jf_utils::test_rng();
+        let (sk, pk) = BLSSignatureScheme::derive_key(&seed, &[12381, 3600, 0, 0]).unwrap();
+        let sig = BLSSignatureScheme::sign(&(), &sk, "message", &mut rng).unwrap();
+        assert!(BLSSignatureScheme::verify(&(), &pk, "message", &sig).is_ok());
+        assert_ne!(pk, child_pk);
+
+        // too short a seed
+        assert!(BLSSignatureScheme::derive_key(&seed[..31], &[]).is_err());
+    }
+
+    #[test]
+    fn test_sign_key_hardening() {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<BLSSignKey>();
+
+        let mut rng = jf_utils::test_rng();
+        let (sk, _) = BLSSignatureScheme::key_gen(&(), &mut rng).unwrap();
+        let secret = ark_std::format!("{:?}", sk.to_bytes());
+        assert!(!ark_std::format!("{:?}", sk).contains(&secret));
+    }
+
     #[test]
     fn test_canonical_serde() {
         let mut rng = jf_utils::test_rng();
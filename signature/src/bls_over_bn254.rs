@@ -29,18 +29,40 @@
 //! * `H` is implemented using the "hash-and-pray" approach. See function
 //!   [`hash_to_curve`]
 //!
+//! ## Proofs of possession
+//!
+//! Aggregating signatures on a common message, see
+//! [`BLSOverBN254CurveSignatureScheme::fast_aggregate_verify`], is only secure
+//! against rogue-key attacks if each signer proved the possession of its
+//! signing key, with [`SignKey::prove_possession`], and the proof was checked
+//! with [`VerKey::verify_possession`] before the verification key is used.
+//! This follows the proof-of-possession scheme of the [IRTF draft][irtf].
+//!
+//! ## EVM encoding
+//!
+//! Verification keys and signatures can be encoded as the EVM precompiles
+//! expect their G2 and G1 inputs, see [`VerKey::to_evm_bytes`] and
+//! [`Signature::to_evm_bytes`], and
+//! [`BLSOverBN254CurveSignatureScheme::evm_pairing_input`] returns the exact
+//! input of the pairing precompile checking an aggregate signature, so that
+//! it can be verified on-chain.
+//!
 //! [bls]: https://hovav.net/ucsd/dist/sigs.pdf
+//! [irtf]: https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-proof-of-possession
 //! [bn254]: https://eprint.iacr.org/2005/133.pdf
 //! [eip196]: https://eips.ethereum.org/EIPS/eip-196
 //! [eip197]: https://eips.ethereum.org/EIPS/eip-197
 
-use super::{AggregateableSignatureSchemes, SignatureScheme};
+use super::{AggregateableSignatureSchemes, ContextSignatureScheme, SignatureScheme};
 use crate::{
-    constants::{tag, CS_ID_BLS_BN254},
+    constants::{
+        tag, BLS_BN254_EVM_G1_SIZE, BLS_BN254_EVM_G2_SIZE, CS_ID_BLS_BN254, CS_ID_BLS_BN254_CTX,
+        CS_ID_BLS_BN254_POP,
+    },
     SignatureError,
 };
 use ark_bn254::{
-    Bn254, Fq as BaseField, Fr as ScalarField, G1Affine, G1Projective, G2Affine, G2Projective,
+    Bn254, Fq as BaseField, Fq2, Fr as ScalarField, G1Affine, G1Projective, G2Affine, G2Projective,
 };
 use ark_ec::{
     bn::{Bn, G1Prepared, G2Prepared},
@@ -58,17 +80,22 @@ use ark_std::{
     rand::{CryptoRng, RngCore},
     string::ToString,
     vec::Vec,
-    One, UniformRand,
+    One, UniformRand, Zero,
 };
 use derivative::Derivative;
 use digest::DynDigest;
+use jf_prf::PRF;
+use jf_rescue::prf::RescuePRF;
 use serde::{Deserialize, Serialize};
 use sha3::Keccak256;
 
 use crate::SignatureError::{ParameterError, VerificationError};
 
 use tagged_base64::tagged;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Domain separator of the PRF deriving key pairs from a seed.
+const KEY_BATCH_TAG: &[u8] = b"BLS_BN254_KEY_BATCH";
 
 /// BLS signature scheme.
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -130,6 +157,105 @@ impl SignatureScheme for BLSOverBN254CurveSignatureScheme {
     }
 }
 
+impl ContextSignatureScheme for BLSOverBN254CurveSignatureScheme {
+    /// Sign a message under the context `ctx`, which is hashed to the curve
+    /// along with the message, see [`Self::context_csid`].
+    fn sign_with_context<R: CryptoRng + RngCore, M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        sk: &Self::SigningKey,
+        ctx: &[u8],
+        msg: M,
+        _prng: &mut R,
+    ) -> Result<Self::Signature, SignatureError> {
+        let kp = KeyPair::generate_with_sign_key(sk.0);
+        Ok(kp.sign(msg.as_ref(), Self::context_csid(ctx)))
+    }
+
+    /// Verify a signature under the context `ctx`.
+    fn verify_with_context<M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        vk: &Self::VerificationKey,
+        ctx: &[u8],
+        msg: M,
+        sig: &Self::Signature,
+    ) -> Result<(), SignatureError> {
+        vk.verify(msg.as_ref(), sig, Self::context_csid(ctx))
+    }
+}
+
+impl BLSOverBN254CurveSignatureScheme {
+    /// The suffix appended to messages signed under the context `ctx` before
+    /// hashing them to the curve: `ctx`, its length as 8 big-endian bytes and
+    /// [`CS_ID_BLS_BN254_CTX`]. The length makes the suffix unambiguous, and
+    /// the ciphersuite identifier tells it apart from plain signatures.
+    pub fn context_csid(ctx: &[u8]) -> Vec<u8> {
+        [
+            ctx,
+            &(ctx.len() as u64).to_be_bytes(),
+            CS_ID_BLS_BN254_CTX.as_bytes(),
+        ]
+        .concat()
+    }
+
+    /// Verify a signature aggregated from signatures on the same message,
+    /// following `FastAggregateVerify` of the [IRTF draft][pop].
+    ///
+    /// Every verification key MUST have been checked with
+    /// [`VerKey::verify_possession`] beforehand, otherwise a rogue
+    /// verification key could forge the aggregate signature.
+    ///
+    /// [pop]: https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-fastaggregateverify
+    pub fn fast_aggregate_verify(
+        vks: &[VerKey],
+        msg: &[u8],
+        sig: &Signature,
+    ) -> Result<(), SignatureError> {
+        <Self as AggregateableSignatureSchemes>::multi_sig_verify(&(), vks, msg, sig)
+    }
+
+    /// Return the EVM encoding of the hash of `msg`, i.e. the G1 point signed
+    /// by [`SignatureScheme::sign`].
+    pub fn evm_message_point(msg: &[u8]) -> [u8; BLS_BN254_EVM_G1_SIZE] {
+        let msg_input = [msg, Self::CS_ID.as_bytes()].concat();
+        g1_to_evm_bytes(&hash_to_curve::<Keccak256>(&msg_input))
+    }
+
+    /// Return the input of the EVM pairing precompile ([EIP-197]) that
+    /// verifies the aggregate signature `sig` w.r.t. a list of messages and
+    /// verification keys, i.e. the encoding of the pairs `(H(msgs[i]),
+    /// vks[i])` followed by `(-sig, g2)`. The precompile outputs `1` if and
+    /// only if [`AggregateableSignatureSchemes::aggregate_verify`] succeeds.
+    ///
+    /// [EIP-197]: https://eips.ethereum.org/EIPS/eip-197
+    pub fn evm_pairing_input<M: AsRef<[u8]>>(
+        vks: &[VerKey],
+        msgs: &[M],
+        sig: &Signature,
+    ) -> Result<Vec<u8>, SignatureError> {
+        if vks.is_empty() {
+            return Err(ParameterError(
+                "no verification key for signature verification".to_string(),
+            ));
+        }
+        if vks.len() != msgs.len() {
+            return Err(ParameterError(format!(
+                "vks.len = {}; msgs.len = {}",
+                vks.len(),
+                msgs.len(),
+            )));
+        }
+        let mut input =
+            Vec::with_capacity((vks.len() + 1) * (BLS_BN254_EVM_G1_SIZE + BLS_BN254_EVM_G2_SIZE));
+        for (vk, msg) in vks.iter().zip(msgs.iter()) {
+            input.extend_from_slice(&Self::evm_message_point(msg.as_ref()));
+            input.extend_from_slice(&vk.to_evm_bytes());
+        }
+        input.extend_from_slice(&g1_to_evm_bytes(&-sig.sigma));
+        input.extend_from_slice(&g2_to_evm_bytes(&G2Projective::generator()));
+        Ok(input)
+    }
+}
+
 impl AggregateableSignatureSchemes for BLSOverBN254CurveSignatureScheme {
     /// Aggregate multiple signatures into a single signature
     /// Follow the instantiation from <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-aggregate>
@@ -230,6 +356,7 @@ impl AggregateableSignatureSchemes for BLSOverBN254CurveSignatureScheme {
     Hash,
     Default,
     Zeroize,
+    ZeroizeOnDrop,
     Eq,
     PartialEq,
     CanonicalSerialize,
@@ -238,7 +365,6 @@ impl AggregateableSignatureSchemes for BLSOverBN254CurveSignatureScheme {
     Ord,
     PartialOrd,
 )]
-#[zeroize(drop)]
 #[derivative(Debug)]
 /// Signing key for BLS signature.
 pub struct SignKey(#[derivative(Debug = "ignore")] pub(crate) ScalarField);
@@ -311,6 +437,120 @@ impl PartialEq for Signature {
     }
 }
 
+impl Signature {
+    /// Encode the signature as a G1 point of the EVM precompiles: the big
+    /// endian coordinates `x || y`, or zeros for the identity.
+    pub fn to_evm_bytes(&self) -> [u8; BLS_BN254_EVM_G1_SIZE] {
+        g1_to_evm_bytes(&self.sigma)
+    }
+
+    /// Decode a signature encoded with [`Self::to_evm_bytes`], checking that
+    /// the point is on the curve.
+    pub fn from_evm_bytes(bytes: &[u8]) -> Result<Self, SignatureError> {
+        if bytes.len() != BLS_BN254_EVM_G1_SIZE {
+            return Err(ParameterError(format!(
+                "EVM encoding of a signature of length {}, expecting {}",
+                bytes.len(),
+                BLS_BN254_EVM_G1_SIZE
+            )));
+        }
+        let x = fq_from_evm_bytes(&bytes[..32])?;
+        let y = fq_from_evm_bytes(&bytes[32..])?;
+        let point = if x.is_zero() && y.is_zero() {
+            G1Affine::identity()
+        } else {
+            G1Affine::new_unchecked(x, y)
+        };
+        if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(ParameterError(
+                "EVM encoding of a signature not on the curve".to_string(),
+            ));
+        }
+        Ok(Self {
+            sigma: point.into(),
+        })
+    }
+}
+
+impl VerKey {
+    /// Encode the verification key as a G2 point of the EVM pairing
+    /// precompile: the big endian coordinates `x.c1 || x.c0 || y.c1 || y.c0`,
+    /// i.e. imaginary parts first, or zeros for the identity.
+    pub fn to_evm_bytes(&self) -> [u8; BLS_BN254_EVM_G2_SIZE] {
+        g2_to_evm_bytes(&self.0)
+    }
+
+    /// Decode a verification key encoded with [`Self::to_evm_bytes`], checking
+    /// that the point is in the prime-order subgroup.
+    pub fn from_evm_bytes(bytes: &[u8]) -> Result<Self, SignatureError> {
+        if bytes.len() != BLS_BN254_EVM_G2_SIZE {
+            return Err(ParameterError(format!(
+                "EVM encoding of a verification key of length {}, expecting {}",
+                bytes.len(),
+                BLS_BN254_EVM_G2_SIZE
+            )));
+        }
+        let x = Fq2::new(
+            fq_from_evm_bytes(&bytes[32..64])?,
+            fq_from_evm_bytes(&bytes[..32])?,
+        );
+        let y = Fq2::new(
+            fq_from_evm_bytes(&bytes[96..])?,
+            fq_from_evm_bytes(&bytes[64..96])?,
+        );
+        let point = if x.is_zero() && y.is_zero() {
+            G2Affine::identity()
+        } else {
+            G2Affine::new_unchecked(x, y)
+        };
+        if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(ParameterError(
+                "EVM encoding of a verification key not in the G2 subgroup".to_string(),
+            ));
+        }
+        Ok(Self(point.into()))
+    }
+}
+
+/// Big endian encoding of a base field element, as in the EVM precompiles.
+fn fq_to_evm_bytes(x: &BaseField) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&x.into_bigint().to_bytes_be());
+    bytes
+}
+
+/// Decode a base field element encoded with [`fq_to_evm_bytes`], rejecting
+/// non-canonical encodings.
+fn fq_from_evm_bytes(bytes: &[u8]) -> Result<BaseField, SignatureError> {
+    let x = BaseField::from_be_bytes_mod_order(bytes);
+    if fq_to_evm_bytes(&x) != bytes {
+        return Err(ParameterError(
+            "non-canonical encoding of a field element".to_string(),
+        ));
+    }
+    Ok(x)
+}
+
+fn g1_to_evm_bytes(point: &G1Projective) -> [u8; BLS_BN254_EVM_G1_SIZE] {
+    let mut bytes = [0u8; BLS_BN254_EVM_G1_SIZE];
+    if let Some((x, y)) = point.into_affine().xy() {
+        bytes[..32].copy_from_slice(&fq_to_evm_bytes(x));
+        bytes[32..].copy_from_slice(&fq_to_evm_bytes(y));
+    }
+    bytes
+}
+
+fn g2_to_evm_bytes(point: &G2Projective) -> [u8; BLS_BN254_EVM_G2_SIZE] {
+    let mut bytes = [0u8; BLS_BN254_EVM_G2_SIZE];
+    if let Some((x, y)) = point.into_affine().xy() {
+        bytes[..32].copy_from_slice(&fq_to_evm_bytes(&x.c1));
+        bytes[32..64].copy_from_slice(&fq_to_evm_bytes(&x.c0));
+        bytes[64..96].copy_from_slice(&fq_to_evm_bytes(&y.c1));
+        bytes[96..].copy_from_slice(&fq_to_evm_bytes(&y.c0));
+    }
+    bytes
+}
+
 // =====================================================
 // end of definitions
 // =====================================================
@@ -375,6 +615,27 @@ impl KeyPair {
         KeyPair { sk, vk }
     }
 
+    /// Deterministic generation of the key pair at `index` from `seed`: the
+    /// signing key is the output of the Rescue PRF keyed by `seed` on the
+    /// domain-separated `index`. The seed must be kept as secret as the keys.
+    pub fn generate_from_seed(seed: &ScalarField, index: u64) -> Self {
+        let input = [
+            ScalarField::from_be_bytes_mod_order(KEY_BATCH_TAG),
+            ScalarField::from(index),
+        ];
+        // safe unwrap: the input has a fixed length
+        let [sk] = RescuePRF::<ScalarField, 2, 1>::evaluate(seed, input).unwrap();
+        Self::generate_with_sign_key(sk)
+    }
+
+    /// Deterministic generation of the `n` key pairs at indices `0..n` from
+    /// `seed`, see [`Self::generate_from_seed`].
+    pub fn generate_batch(seed: &ScalarField, n: u64) -> Vec<Self> {
+        (0..n)
+            .map(|index| Self::generate_from_seed(seed, index))
+            .collect()
+    }
+
     /// Get reference to verification key
     pub fn ver_key_ref(&self) -> &VerKey {
         &self.vk
@@ -416,6 +677,14 @@ impl SignKey {
     pub fn generate<R: CryptoRng + RngCore>(prng: &mut R) -> SignKey {
         SignKey(ScalarField::rand(prng))
     }
+
+    /// Prove the possession of the signing key, i.e. sign the serialized
+    /// verification key under the ciphersuite [`CS_ID_BLS_BN254_POP`]. The
+    /// proof is checked with [`VerKey::verify_possession`].
+    pub fn prove_possession(&self) -> Signature {
+        let kp = KeyPair::from(self.clone());
+        kp.sign(&kp.vk.pop_message(), CS_ID_BLS_BN254_POP)
+    }
 }
 
 impl From<&SignKey> for VerKey {
@@ -430,6 +699,30 @@ impl VerKey {
         self.0
     }
 
+    /// Check a proof of possession of the signing key of `self`, computed with
+    /// [`SignKey::prove_possession`]. The verification key is also checked
+    /// not to be the identity.
+    pub fn verify_possession(&self, proof: &Signature) -> Result<(), SignatureError> {
+        if self.0.is_zero() {
+            return Err(VerificationError(
+                "verification key is the identity".to_string(),
+            ));
+        }
+        self.verify(&self.pop_message(), proof, CS_ID_BLS_BN254_POP)
+    }
+
+    /// The message signed by a proof of possession: the compressed
+    /// serialization of the verification key.
+    fn pop_message(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        // serializing into a vector can't fail
+        self.0
+            .into_affine()
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        bytes
+    }
+
     /// Signature verification function
     pub fn verify<B: AsRef<[u8]>>(
         &self,
@@ -456,9 +749,14 @@ mod tests {
     use crate::{
         bls_over_bn254::{BLSOverBN254CurveSignatureScheme, KeyPair, SignKey, Signature, VerKey},
         constants::CS_ID_BLS_BN254,
-        tests::{agg_sign_and_verify, failed_verification, sign_and_verify},
+        tests::{
+            agg_sign_and_verify, context_sign_and_verify, failed_verification, sign_and_verify,
+        },
+        AggregateableSignatureSchemes,
     };
-    use ark_ff::vec;
+    use ark_bn254::{Bn254, Fr as ScalarField, G1Projective, G2Projective};
+    use ark_ec::{pairing::Pairing, CurveGroup};
+    use ark_ff::{vec, One, Zero};
     use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
     use ark_std::{vec::Vec, UniformRand};
 
@@ -509,6 +807,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_batch() {
+        let mut rng = jf_utils::test_rng();
+        let seed = ScalarField::rand(&mut rng);
+        let key_pairs = KeyPair::generate_batch(&seed, 4);
+        let vks: Vec<_> = key_pairs.iter().map(|kp| kp.ver_key()).collect();
+        assert_eq!(
+            vks,
+            KeyPair::generate_batch(&seed, 4)
+                .iter()
+                .map(|kp| kp.ver_key())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(vks[2], KeyPair::generate_from_seed(&seed, 2).ver_key());
+        assert!((1..4).all(|i| !vks[..i].contains(&vks[i])));
+        assert_ne!(
+            vks[0],
+            KeyPair::generate_from_seed(&ScalarField::rand(&mut rng), 0).ver_key()
+        );
+
+        let msg = [1u8, 2, 3];
+        let sig = key_pairs[1].sign(&msg, CS_ID_BLS_BN254);
+        assert!(vks[1].verify(&msg, &sig, CS_ID_BLS_BN254).is_ok());
+    }
+
+    #[test]
+    fn test_sig_with_context() {
+        context_sign_and_verify::<BLSOverBN254CurveSignatureScheme>(&[87u8, 32u8]);
+    }
+
     #[test]
     fn test_agg_sig_trait() {
         let m1 = [87u8, 32u8];
@@ -523,6 +851,142 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_proof_of_possession() {
+        let mut rng = jf_utils::test_rng();
+        let msg = [87u8, 32u8];
+        let mut vks = vec![];
+        let mut sigs = vec![];
+        for _ in 0..4 {
+            let key_pair = KeyPair::generate(&mut rng);
+            let proof = key_pair.sign_key_ref().prove_possession();
+            assert!(key_pair.ver_key_ref().verify_possession(&proof).is_ok());
+            vks.push(key_pair.ver_key());
+            sigs.push(key_pair.sign(&msg, CS_ID_BLS_BN254));
+        }
+
+        // a proof doesn't verify under another key
+        let proof = KeyPair::generate(&mut rng)
+            .sign_key_ref()
+            .prove_possession();
+        assert!(vks[0].verify_possession(&proof).is_err());
+        // a signature on the serialized key is not a proof of possession
+        let key_pair = KeyPair::generate(&mut rng);
+        let mut vk_bytes = Vec::new();
+        key_pair
+            .ver_key()
+            .internal()
+            .into_affine()
+            .serialize_compressed(&mut vk_bytes)
+            .unwrap();
+        let sig = key_pair.sign(&vk_bytes, CS_ID_BLS_BN254);
+        assert!(key_pair.ver_key_ref().verify_possession(&sig).is_err());
+        // the identity is rejected
+        let zero_key_pair = KeyPair::generate_with_sign_key(ScalarField::zero());
+        let proof = zero_key_pair.sign_key_ref().prove_possession();
+        assert!(zero_key_pair
+            .ver_key_ref()
+            .verify_possession(&proof)
+            .is_err());
+
+        // fast aggregate verification
+        let agg_sig = BLSOverBN254CurveSignatureScheme::aggregate(&(), &vks, &sigs).unwrap();
+        assert!(
+            BLSOverBN254CurveSignatureScheme::fast_aggregate_verify(&vks, &msg, &agg_sig).is_ok()
+        );
+        assert!(
+            BLSOverBN254CurveSignatureScheme::fast_aggregate_verify(&vks[1..], &msg, &agg_sig)
+                .is_err()
+        );
+        assert!(
+            BLSOverBN254CurveSignatureScheme::fast_aggregate_verify(&vks, &[255u8], &agg_sig)
+                .is_err()
+        );
+        assert!(
+            BLSOverBN254CurveSignatureScheme::fast_aggregate_verify(&[], &msg, &agg_sig).is_err()
+        );
+    }
+
+    #[test]
+    fn test_evm_encoding() {
+        let mut rng = jf_utils::test_rng();
+        let messages: Vec<Vec<u8>> = (0..4u8).map(|i| vec![i; i as usize + 1]).collect();
+        let mut vks = vec![];
+        let mut sigs = vec![];
+        for msg in messages.iter() {
+            let key_pair = KeyPair::generate(&mut rng);
+            let sig = key_pair.sign(msg, CS_ID_BLS_BN254);
+            // round trips
+            assert_eq!(
+                VerKey::from_evm_bytes(&key_pair.ver_key().to_evm_bytes()).unwrap(),
+                key_pair.ver_key()
+            );
+            assert_eq!(Signature::from_evm_bytes(&sig.to_evm_bytes()).unwrap(), sig);
+            vks.push(key_pair.ver_key());
+            sigs.push(sig);
+        }
+        let identity = Signature {
+            sigma: G1Projective::zero(),
+        };
+        assert_eq!(identity.to_evm_bytes(), [0u8; 64]);
+        assert_eq!(Signature::from_evm_bytes(&[0u8; 64]).unwrap(), identity);
+
+        // invalid encodings
+        assert!(Signature::from_evm_bytes(&[1u8; 64]).is_err());
+        assert!(Signature::from_evm_bytes(&[0u8; 63]).is_err());
+        assert!(Signature::from_evm_bytes(&[0xffu8; 64]).is_err());
+        assert!(VerKey::from_evm_bytes(&[1u8; 128]).is_err());
+        // swapping the real and imaginary parts breaks the encoding
+        let bytes = vks[0].to_evm_bytes();
+        let swapped = [&bytes[32..64], &bytes[..32], &bytes[96..], &bytes[64..96]].concat();
+        assert!(VerKey::from_evm_bytes(&swapped).is_err());
+
+        // pairing precompile input
+        let agg_sig = BLSOverBN254CurveSignatureScheme::aggregate(&(), &vks, &sigs).unwrap();
+        let input =
+            BLSOverBN254CurveSignatureScheme::evm_pairing_input(&vks, &messages, &agg_sig).unwrap();
+        assert_eq!(input.len(), 192 * (vks.len() + 1));
+        assert!(evm_pairing_precompile(&input));
+        let mut bad_messages = messages.clone();
+        bad_messages[0] = vec![255u8];
+        let input =
+            BLSOverBN254CurveSignatureScheme::evm_pairing_input(&vks, &bad_messages, &agg_sig)
+                .unwrap();
+        assert!(!evm_pairing_precompile(&input));
+        assert!(BLSOverBN254CurveSignatureScheme::evm_pairing_input(
+            &vks,
+            &messages[1..],
+            &agg_sig
+        )
+        .is_err());
+    }
+
+    // Mimic the EVM pairing precompile: decode the input and check that the
+    // product of the pairings is one.
+    fn evm_pairing_precompile(input: &[u8]) -> bool {
+        let (g1_points, g2_points): (Vec<G1Projective>, Vec<G2Projective>) = input
+            .chunks(192)
+            .map(|chunk| {
+                (
+                    Signature::from_evm_bytes(&chunk[..64]).unwrap().sigma,
+                    VerKey::from_evm_bytes(&chunk[64..]).unwrap().0,
+                )
+            })
+            .unzip();
+        Bn254::multi_pairing(g1_points, g2_points).0.is_one()
+    }
+
+    #[test]
+    fn test_sign_key_hardening() {
+        fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<SignKey>();
+
+        let mut rng = jf_utils::test_rng();
+        let sk = SignKey::generate(&mut rng);
+        let secret = ark_std::format!("{:?}", sk.0);
+        assert!(!ark_std::format!("{:?}", sk).contains(&secret));
+    }
+
     #[test]
     fn test_serde() {
         let mut rng = jf_utils::test_rng();
@@ -22,15 +22,32 @@ pub mod tag {
     pub const SCHNORR_SIGNING_KEY: &str = "SCHNORR_SIGNING_KEY";
     /// Tag for Schnorr signature
     pub const SCHNORR_SIG: &str = "SCHNORR_SIG";
+
+    /// Tag for Ed25519 verification key
+    pub const ED25519_VER_KEY: &str = "ED25519_VER_KEY";
+    /// Tag for Ed25519 signing key
+    pub const ED25519_SIGNING_KEY: &str = "ED25519_SIGNING_KEY";
+    /// Tag for Ed25519 signature
+    pub const ED25519_SIG: &str = "ED25519_SIG";
 }
 
 /// ciphersuite identifier for schnorr signature
 pub const CS_ID_SCHNORR: &str = "SCHNORR_WITH_RESCUE_HASH_v01";
 
+/// ciphersuite identifier for schnorr signature under a context, see
+/// [`ContextSignatureScheme`](crate::ContextSignatureScheme). It is hashed
+/// together with the context into the challenge domain separator.
+pub const CS_ID_SCHNORR_CTX: &str = "SCHNORR_WITH_RESCUE_HASH_CTX_v01";
+
 /// ciphersuite identifier for BLS signature over BLS12_381, see:
 /// <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-ciphersuite-format>
 pub const CS_ID_BLS_MIN_SIG: &str = "BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
 
+/// ciphersuite identifier for BLS signature over BLS12_381 under a context,
+/// see [`ContextSignatureScheme`](crate::ContextSignatureScheme). The context
+/// is appended to it to form the hash-to-curve DST.
+pub const CS_ID_BLS_MIN_SIG_CTX: &str = "BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_CTX_";
+
 /// Size in bytes of a secret key in our BLS signature scheme.
 pub const BLS_SIG_SK_SIZE: usize = 32;
 /// Size in bytes of a signature in our BLS signature scheme.
@@ -46,3 +63,26 @@ pub const BLS_SIG_COMPRESSED_PK_SIZE: usize = 96;
 /// Note this is **adapted** from <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-ciphersuite-format>.
 /// In particular the "hash-and-pray" method is not part of <https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-16>, so the tag "NCTH" (non constant time hash) is not standard.
 pub const CS_ID_BLS_BN254: &str = "BLS_SIG_BN254G1_XMD:KECCAK_NCTH_NUL_";
+
+/// ciphersuite identifier for BLS signature over BN254 under a context, see
+/// [`ContextSignatureScheme`](crate::ContextSignatureScheme). It has the same
+/// length as [`CS_ID_BLS_BN254`] and [`CS_ID_BLS_BN254_POP`], so that hash
+/// inputs under the three ciphersuites never collide.
+pub const CS_ID_BLS_BN254_CTX: &str = "BLS_SIG_BN254G1_XMD:KECCAK_NCTH_CTX_";
+
+/// Size in bytes of a BN254 G1 point, e.g. a signature, in the encoding of the
+/// EVM precompiles, see <https://eips.ethereum.org/EIPS/eip-196#encoding>.
+pub const BLS_BN254_EVM_G1_SIZE: usize = 64;
+/// Size in bytes of a BN254 G2 point, e.g. a verification key, in the encoding
+/// of the EVM pairing precompile, see <https://eips.ethereum.org/EIPS/eip-197#encoding>.
+pub const BLS_BN254_EVM_G2_SIZE: usize = 128;
+
+/// ciphersuite identifier for proofs of possession of BLS keys over BN254,
+/// adapted from <https://www.ietf.org/archive/id/draft-irtf-cfrg-bls-signature-05.html#name-proof-of-possession>.
+/// It differs from [`CS_ID_BLS_BN254`] so that a proof of possession can never
+/// be mistaken for a signature.
+pub const CS_ID_BLS_BN254_POP: &str = "BLS_POP_BN254G1_XMD:KECCAK_NCTH_POP_";
+
+/// ciphersuite identifier for Ed25519 signature, see:
+/// <https://datatracker.ietf.org/doc/html/rfc8032#section-5.1>
+pub const CS_ID_ED25519: &str = "ED25519_SHA-512_RFC8032";
@@ -0,0 +1,424 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Ed25519 Signature Scheme
+//!
+//! Conforming to [RFC 8032][rfc], wrapping the [`ed25519-dalek` crate][dalek]
+//! under the hood, so that Ed25519 can be used wherever a [`SignatureScheme`]
+//! is expected. Verification follows the strict rules of
+//! [`VerifyingKey::verify_strict`], rejecting small-order verification keys
+//! and non-canonical signatures.
+//!
+//! [rfc]: https://datatracker.ietf.org/doc/html/rfc8032
+//! [dalek]: https://github.com/dalek-cryptography/curve25519-dalek
+//!
+//! # Examples
+//!
+//! ```
+//! use rand_chacha::{ChaCha20Rng, rand_core::SeedableRng};
+//! use jf_signature::{SignatureScheme, ed25519::Ed25519SignatureScheme};
+//!
+//! let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+//! let (sk, pk) = Ed25519SignatureScheme::key_gen(&(), &mut rng)?;
+//!
+//! let msg = "The quick brown fox jumps over the lazy dog";
+//! let sig = Ed25519SignatureScheme::sign(&(), &sk, &msg, &mut rng)?;
+//! assert!(Ed25519SignatureScheme::verify(&(), &pk, &msg, &sig).is_ok());
+//!
+//! # Ok::<(), Box<dyn ark_std::error::Error>>(())
+//! ```
+
+use super::SignatureScheme;
+use crate::{
+    constants::{tag, CS_ID_ED25519},
+    SignatureError,
+};
+use ark_serialize::*;
+use ark_std::{
+    fmt, format,
+    ops::{Deref, DerefMut},
+    rand::{CryptoRng, RngCore},
+};
+use ed25519_dalek::{
+    Signature, Signer, SigningKey, VerifyingKey, PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH,
+    SIGNATURE_LENGTH,
+};
+use tagged_base64::tagged;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+#[tagged(tag::ED25519_SIGNING_KEY)]
+#[derive(Clone, PartialEq, Eq)]
+/// An Ed25519 Secret Key (Signing Key).
+pub struct Ed25519SignKey(SigningKey);
+
+impl Deref for Ed25519SignKey {
+    type Target = SigningKey;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Ed25519SignKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ed25519SignKey").finish_non_exhaustive()
+    }
+}
+
+impl Zeroize for Ed25519SignKey {
+    fn zeroize(&mut self) {
+        // the replaced key wipes its secret when dropped
+        self.0 = SigningKey::from_bytes(&[0u8; SECRET_KEY_LENGTH]);
+    }
+}
+
+// `SigningKey` wipes its secret on drop.
+impl ZeroizeOnDrop for Ed25519SignKey {}
+
+impl CanonicalSerialize for Ed25519SignKey {
+    /// Secret key is serialized in the same way regardless of `compress`.
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        _compress: Compress,
+    ) -> Result<(), SerializationError> {
+        let bytes = Zeroizing::new(self.0.to_bytes());
+        CanonicalSerialize::serialize_compressed(&bytes[..], writer)
+    }
+
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        // length prefix followed by the bytes
+        8 + SECRET_KEY_LENGTH
+    }
+}
+
+impl CanonicalDeserialize for Ed25519SignKey {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let len = <usize as ark_serialize::CanonicalDeserialize>::deserialize_with_mode(
+            &mut reader,
+            compress,
+            validate,
+        )?;
+        if len != SECRET_KEY_LENGTH {
+            return Err(SerializationError::InvalidData);
+        }
+
+        let mut sk_bytes = Zeroizing::new([0u8; SECRET_KEY_LENGTH]);
+        reader.read_exact(sk_bytes.deref_mut())?;
+        Ok(Self(SigningKey::from_bytes(sk_bytes.deref())))
+    }
+}
+
+impl Valid for Ed25519SignKey {
+    fn check(&self) -> Result<(), SerializationError> {
+        // every 32-byte string is a valid secret key
+        Ok(())
+    }
+}
+
+#[tagged(tag::ED25519_VER_KEY)]
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+/// An Ed25519 Public Key (Verification Key).
+pub struct Ed25519VerKey(VerifyingKey);
+
+impl Deref for Ed25519VerKey {
+    type Target = VerifyingKey;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl CanonicalSerialize for Ed25519VerKey {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        _compress: Compress,
+    ) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize_compressed(&self.0.as_bytes()[..], writer)
+    }
+
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        8 + PUBLIC_KEY_LENGTH
+    }
+}
+
+impl CanonicalDeserialize for Ed25519VerKey {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let len = <usize as ark_serialize::CanonicalDeserialize>::deserialize_with_mode(
+            &mut reader,
+            compress,
+            validate,
+        )?;
+        if len != PUBLIC_KEY_LENGTH {
+            return Err(SerializationError::InvalidData);
+        }
+
+        let mut pk_bytes = [0u8; PUBLIC_KEY_LENGTH];
+        reader.read_exact(&mut pk_bytes)?;
+        // decompression always checks that the bytes encode a curve point
+        let ver_key = VerifyingKey::from_bytes(&pk_bytes)
+            .map(Self)
+            .map_err(|_| SerializationError::InvalidData)?;
+        if validate == Validate::Yes && ver_key.check().is_err() {
+            return Err(SerializationError::InvalidData);
+        }
+
+        Ok(ver_key)
+    }
+}
+
+impl Valid for Ed25519VerKey {
+    /// Rejects small-order keys, which strict verification never accepts.
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.0.is_weak() {
+            Err(SerializationError::InvalidData)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// An Ed25519 Signature.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+#[tagged(tag::ED25519_SIG)]
+pub struct Ed25519Signature(Signature);
+
+impl Deref for Ed25519Signature {
+    type Target = Signature;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl CanonicalSerialize for Ed25519Signature {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        _compress: Compress,
+    ) -> Result<(), SerializationError> {
+        CanonicalSerialize::serialize_compressed(&self.0.to_bytes()[..], writer)
+    }
+
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        8 + SIGNATURE_LENGTH
+    }
+}
+
+impl CanonicalDeserialize for Ed25519Signature {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let len = <usize as ark_serialize::CanonicalDeserialize>::deserialize_with_mode(
+            &mut reader,
+            compress,
+            validate,
+        )?;
+        if len != SIGNATURE_LENGTH {
+            return Err(SerializationError::InvalidData);
+        }
+
+        let mut sig_bytes = [0u8; SIGNATURE_LENGTH];
+        reader.read_exact(&mut sig_bytes)?;
+        // canonicity of the signature is checked during verification
+        Ok(Self(Signature::from_bytes(&sig_bytes)))
+    }
+}
+
+impl Valid for Ed25519Signature {
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+/// Ed25519 signature scheme. Wrapping around structs from the
+/// `ed25519-dalek` crate.
+/// See [module-level documentation](self) for example usage.
+#[derive(Clone, Debug)]
+pub struct Ed25519SignatureScheme;
+
+impl SignatureScheme for Ed25519SignatureScheme {
+    const CS_ID: &'static str = CS_ID_ED25519;
+
+    /// Signing key
+    type SigningKey = Ed25519SignKey;
+
+    /// Verification key
+    type VerificationKey = Ed25519VerKey;
+
+    /// Public parameter
+    type PublicParameter = ();
+
+    /// Signature
+    type Signature = Ed25519Signature;
+
+    /// A message is &\[MessageUnit\]
+    type MessageUnit = u8;
+
+    /// Ed25519 has no public parameters.
+    fn param_gen<R: CryptoRng + RngCore>(
+        _prng: Option<&mut R>,
+    ) -> Result<Self::PublicParameter, SignatureError> {
+        Ok(())
+    }
+
+    /// Generate an Ed25519 key pair.
+    /// Make sure the `prng` passed in are properly seeded with trusted entropy.
+    fn key_gen<R: CryptoRng + RngCore>(
+        _pp: &Self::PublicParameter,
+        prng: &mut R,
+    ) -> Result<(Self::SigningKey, Self::VerificationKey), SignatureError> {
+        let mut sk_bytes = Zeroizing::new([0u8; SECRET_KEY_LENGTH]);
+        prng.fill_bytes(sk_bytes.deref_mut());
+
+        let sk = SigningKey::from_bytes(sk_bytes.deref());
+        let vk = sk.verifying_key();
+        Ok((Ed25519SignKey(sk), Ed25519VerKey(vk)))
+    }
+
+    /// Sign a message. Ed25519 signing is deterministic, `prng` is unused.
+    fn sign<R: CryptoRng + RngCore, M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        sk: &Self::SigningKey,
+        msg: M,
+        _prng: &mut R,
+    ) -> Result<Self::Signature, SignatureError> {
+        Ok(Ed25519Signature(sk.0.sign(msg.as_ref())))
+    }
+
+    /// Verify a signature.
+    fn verify<M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        vk: &Self::VerificationKey,
+        msg: M,
+        sig: &Self::Signature,
+    ) -> Result<(), SignatureError> {
+        vk.0.verify_strict(msg.as_ref(), &sig.0)
+            .map_err(|e| SignatureError::VerificationError(format!("{e:?}")))
+    }
+}
+
+impl Ed25519SignatureScheme {
+    /// Recover a key pair from the 32-byte secret key of [RFC 8032][rfc].
+    ///
+    /// [rfc]: https://datatracker.ietf.org/doc/html/rfc8032#section-5.1.5
+    pub fn key_from_bytes(
+        sk_bytes: &[u8; SECRET_KEY_LENGTH],
+    ) -> (
+        <Self as SignatureScheme>::SigningKey,
+        <Self as SignatureScheme>::VerificationKey,
+    ) {
+        let sk = SigningKey::from_bytes(sk_bytes);
+        let vk = sk.verifying_key();
+        (Ed25519SignKey(sk), Ed25519VerKey(vk))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::{failed_verification, sign_and_verify};
+    use ark_std::{fmt::Debug, vec};
+
+    #[test]
+    fn test_ed25519_sig() {
+        let message = "this is a test message";
+        let message_bad = "this is a wrong message";
+        sign_and_verify::<Ed25519SignatureScheme>(message.as_ref());
+        failed_verification::<Ed25519SignatureScheme>(message.as_ref(), message_bad.as_ref());
+    }
+
+    // Test 2 of RFC 8032, Section 7.1.
+    #[test]
+    fn test_rfc8032_vector() {
+        fn from_hex<const N: usize>(s: &str) -> [u8; N] {
+            let mut out = [0u8; N];
+            for (i, byte) in out.iter_mut().enumerate() {
+                *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+            }
+            out
+        }
+        let (sk, pk) = Ed25519SignatureScheme::key_from_bytes(&from_hex(
+            "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+        ));
+        assert_eq!(
+            pk.to_bytes(),
+            from_hex::<32>("3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c")
+        );
+        let msg = [0x72u8];
+        let sig = Ed25519SignatureScheme::sign(&(), &sk, msg, &mut jf_utils::test_rng()).unwrap();
+        assert_eq!(
+            sig.to_bytes(),
+            from_hex::<64>(
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da\
+                 085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00"
+            )
+        );
+        assert!(Ed25519SignatureScheme::verify(&(), &pk, msg, &sig).is_ok());
+    }
+
+    #[test]
+    fn test_sign_key_hardening() {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<Ed25519SignKey>();
+
+        let mut rng = jf_utils::test_rng();
+        let (mut sk, _) = Ed25519SignatureScheme::key_gen(&(), &mut rng).unwrap();
+        let secret = ark_std::format!("{:?}", sk.to_bytes());
+        assert!(!ark_std::format!("{:?}", sk).contains(&secret));
+
+        sk.zeroize();
+        assert_eq!(sk.to_bytes(), [0u8; SECRET_KEY_LENGTH]);
+    }
+
+    #[test]
+    fn test_canonical_serde() {
+        let mut rng = jf_utils::test_rng();
+        let (sk, pk) = Ed25519SignatureScheme::key_gen(&(), &mut rng).unwrap();
+        let msg = "The quick brown fox jumps over the lazy dog";
+        let sig = Ed25519SignatureScheme::sign(&(), &sk, msg, &mut rng).unwrap();
+
+        test_canonical_serde_helper(sk);
+        test_canonical_serde_helper(pk);
+        test_canonical_serde_helper(sig);
+
+        // small-order verification keys are rejected under validation
+        let mut identity = [0u8; PUBLIC_KEY_LENGTH];
+        identity[0] = 1;
+        let mut bytes = vec![];
+        CanonicalSerialize::serialize_compressed(&identity[..], &mut bytes).unwrap();
+        assert!(Ed25519VerKey::deserialize_compressed(&bytes[..]).is_err());
+        assert!(Ed25519VerKey::deserialize_compressed_unchecked(&bytes[..]).is_ok());
+    }
+
+    fn test_canonical_serde_helper<T>(data: T)
+    where
+        T: CanonicalSerialize + CanonicalDeserialize + Debug + PartialEq,
+    {
+        let mut bytes = vec![];
+        CanonicalSerialize::serialize_compressed(&data, &mut bytes).unwrap();
+        let de: T = CanonicalDeserialize::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(data, de);
+
+        bytes = vec![];
+        CanonicalSerialize::serialize_uncompressed(&data, &mut bytes).unwrap();
+        let de: T = CanonicalDeserialize::deserialize_uncompressed(&bytes[..]).unwrap();
+        assert_eq!(data, de);
+
+        bytes = vec![];
+        CanonicalSerialize::serialize_compressed(&data, &mut bytes).unwrap();
+        let de: T = CanonicalDeserialize::deserialize_compressed_unchecked(&bytes[..]).unwrap();
+        assert_eq!(data, de);
+    }
+}
@@ -5,6 +5,15 @@
 // along with the Jellyfish library. If not, see <https://mit-license.org/>.
 
 //! Circuit implementation of a Schnorr signature scheme.
+//!
+//! Several signatures can be verified at once with a random linear
+//! combination of their verification equations `s_i * G = R_i + c_i * VK_i`,
+//! with weights `z_i` of [`BATCH_WEIGHT_BIT_LEN`] bits hashed from the
+//! challenges and the `s_i`:
+//! `(sum_i z_i * s_i) * G = sum_i z_i * R_i + sum_i (z_i * c_i mod r) * VK_i`.
+//! The scalars are reduced modulo the order `r` of the subgroup with big
+//! integer arithmetic, and all the scalar multiplications are done by
+//! multi-scalar multiplications.
 
 use crate::{
     constants::CS_ID_SCHNORR,
@@ -15,13 +24,20 @@ use ark_ec::{
     AffineRepr,
 };
 use ark_ff::PrimeField;
-use ark_std::{vec, vec::Vec};
+use ark_std::{format, vec, vec::Vec};
 use jf_relation::{
-    gadgets::ecc::{PointVariable, TEPoint},
+    gadgets::{
+        ecc::{MultiScalarMultiplicationCircuit, PointVariable, TEPoint},
+        BigUintVar, BIGUINT_LIMB_BITS,
+    },
     BoolVar, Circuit, CircuitError, PlonkCircuit, Variable,
 };
 use jf_rescue::{gadgets::RescueNativeGadget, RescueParameter};
 use jf_utils::{challenge_bit_len, field_bit_len, fr_to_fq};
+use num_bigint::BigUint;
+
+/// The number of bits of the random weights of a batch verification.
+pub const BATCH_WEIGHT_BIT_LEN: usize = 128;
 
 #[derive(Debug, Clone)]
 /// Signature verification key variable
@@ -56,6 +72,24 @@ where
         sig: &SignatureVar,
     ) -> Result<(), CircuitError>;
 
+    /// Verify the signatures `sigs` of the messages `msgs` under the keys
+    /// `vks` at once, with a random linear combination of the verification
+    /// equations. The keys and the points `R` of the signatures are expected
+    /// to be in the prime-order subgroup, as for any batch verification.
+    /// The batch verification relies on lookups for the range checks and
+    /// the multi-scalar multiplications: on circuits supporting lookup, it
+    /// takes e.g. 6797 gates instead of 12654 for 4 signatures over Jubjub,
+    /// the hashing of the challenges being the same. Other circuits verify
+    /// the signatures one by one.
+    /// Return error if the numbers of keys, messages and signatures differ or
+    /// the variables are invalid.
+    fn verify_signatures_batch(
+        &mut self,
+        vks: &[VerKeyVar],
+        msgs: &[Vec<Variable>],
+        sigs: &[SignatureVar],
+    ) -> Result<(), CircuitError>;
+
     /// Obtain the result bit of a signature verification.
     /// * `vk` - signature verification key variable.
     /// * `msg` - message variables that have been signed.
@@ -104,6 +138,93 @@ where
         Ok(())
     }
 
+    fn verify_signatures_batch(
+        &mut self,
+        vks: &[VerKeyVar],
+        msgs: &[Vec<Variable>],
+        sigs: &[SignatureVar],
+    ) -> Result<(), CircuitError> {
+        if vks.len() != msgs.len() || vks.len() != sigs.len() {
+            return Err(CircuitError::ParameterError(format!(
+                "{} keys, {} messages and {} signatures",
+                vks.len(),
+                msgs.len(),
+                sigs.len()
+            )));
+        }
+        if !self.support_lookup() {
+            for ((vk, msg), sig) in vks.iter().zip(msgs.iter()).zip(sigs.iter()) {
+                <Self as SignatureGadget<F, P>>::verify_signature(self, vk, msg, sig)?;
+            }
+            return Ok(());
+        }
+        if vks.is_empty() {
+            return Ok(());
+        }
+
+        let mut challenges = Vec::with_capacity(vks.len());
+        for ((vk, msg), sig) in vks.iter().zip(msgs.iter()).zip(sigs.iter()) {
+            let challenge =
+                <Self as SignatureHelperGadget<F, P>>::challenge(self, vk, &sig.R, msg)?;
+            challenges.push(self.truncate(challenge, challenge_bit_len::<F>())?);
+        }
+        // the weights are bound to all the signatures through the challenges
+        let mut transcript = challenges.clone();
+        transcript.extend(sigs.iter().map(|sig| sig.s));
+        let weights =
+            RescueNativeGadget::<F>::rescue_sponge_with_padding(self, &transcript, vks.len())?
+                .into_iter()
+                .map(|weight| self.truncate(weight, BATCH_WEIGHT_BIT_LEN))
+                .collect::<Result<Vec<_>, CircuitError>>()?;
+
+        let order: BigUint = P::ScalarField::MODULUS.into();
+        let order_bits = P::ScalarField::MODULUS_BIT_SIZE as usize;
+        let num_limbs = (order_bits - 1) / BIGUINT_LIMB_BITS + 1;
+        let order = self.create_constant_biguint_variable(&order, num_limbs)?;
+        let mut s_sum: Option<BigUintVar> = None;
+        let mut vk_scalars = Vec::with_capacity(vks.len());
+        for ((&weight, &challenge), sig) in weights.iter().zip(challenges.iter()).zip(sigs.iter()) {
+            let weight = self.biguint_from_variable(weight, BATCH_WEIGHT_BIT_LEN, num_limbs)?;
+            let challenge =
+                self.biguint_from_variable(challenge, challenge_bit_len::<F>(), num_limbs)?;
+            let s = self.biguint_from_variable(sig.s, order_bits, num_limbs)?;
+            let vk_scalar = self.biguint_mul_mod(&weight, &challenge, &order)?;
+            vk_scalars.push(self.biguint_to_variable(&vk_scalar)?);
+            let weighted_s = self.biguint_mul_mod(&weight, &s, &order)?;
+            s_sum = Some(match s_sum {
+                Some(s_sum) => self.biguint_add_mod(&s_sum, &weighted_s, &order)?,
+                None => weighted_s,
+            });
+        }
+        // `vks` is not empty
+        let s_sum = self.biguint_to_variable(&s_sum.unwrap())?;
+
+        // (sum_i z_i * s_i) * G = sum_i z_i * R_i + sum_i (z_i * c_i mod r) * VK_i
+        let sig_points: Vec<PointVariable> = sigs.iter().map(|sig| sig.R).collect();
+        let vk_points: Vec<PointVariable> = vks.iter().map(|vk| vk.0).collect();
+        let base = self.create_constant_point_variable(TEPoint::from(Affine::<P>::generator()))?;
+        let lhs = MultiScalarMultiplicationCircuit::<F, P>::msm_with_var_scalar_length(
+            self,
+            &[base],
+            &[s_sum],
+            order_bits,
+        )?;
+        let sig_sum = MultiScalarMultiplicationCircuit::<F, P>::msm_with_var_scalar_length(
+            self,
+            &sig_points,
+            &weights,
+            BATCH_WEIGHT_BIT_LEN,
+        )?;
+        let vk_sum = MultiScalarMultiplicationCircuit::<F, P>::msm_with_var_scalar_length(
+            self,
+            &vk_points,
+            &vk_scalars,
+            order_bits,
+        )?;
+        let rhs = self.ecc_add::<P>(&sig_sum, &vk_sum)?;
+        self.enforce_point_equal(&lhs, &rhs)
+    }
+
     fn check_signature_validity(
         &mut self,
         vk: &VerKeyVar,
@@ -151,6 +272,14 @@ where
     F: PrimeField,
     P: Config<BaseField = F>,
 {
+    // Return signature hash challenge, before its truncation.
+    fn challenge(
+        &mut self,
+        vk: &VerKeyVar,
+        sig_point: &PointVariable,
+        msg: &[Variable],
+    ) -> Result<Variable, CircuitError>;
+
     // Return signature hash challenge in little-endian binary form.
     fn challenge_bits(
         &mut self,
@@ -165,12 +294,12 @@ where
     F: RescueParameter,
     P: Config<BaseField = F>,
 {
-    fn challenge_bits(
+    fn challenge(
         &mut self,
         vk: &VerKeyVar,
         sig_point: &PointVariable,
         msg: &[Variable],
-    ) -> Result<Vec<BoolVar>, CircuitError> {
+    ) -> Result<Variable, CircuitError> {
         let instance_description = F::from_be_bytes_mod_order(CS_ID_SCHNORR.as_ref());
         // TODO: create `inst_desc_var` and the constant gate *only once* during the
         // entire circuit construction.
@@ -185,8 +314,16 @@ where
         ];
         chal_input.extend(msg);
 
-        let challenge =
-            RescueNativeGadget::<F>::rescue_sponge_with_padding(self, &chal_input, 1)?[0];
+        Ok(RescueNativeGadget::<F>::rescue_sponge_with_padding(self, &chal_input, 1)?[0])
+    }
+
+    fn challenge_bits(
+        &mut self,
+        vk: &VerKeyVar,
+        sig_point: &PointVariable,
+        msg: &[Variable],
+    ) -> Result<Vec<BoolVar>, CircuitError> {
+        let challenge = <Self as SignatureHelperGadget<F, P>>::challenge(self, vk, sig_point, msg)?;
         let c_bits = self.unpack(challenge, field_bit_len::<F>())?;
         Ok(c_bits[..challenge_bit_len::<F>()].to_vec())
     }
@@ -269,6 +406,118 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_batch_verification() -> Result<(), CircuitError> {
+        test_batch_verification_helper::<_, Param377>()?;
+        test_batch_verification_helper::<_, Param381>()?;
+        test_batch_verification_helper::<_, Param381b>()?;
+        test_batch_verification_helper::<_, Param254>()
+    }
+
+    fn test_batch_verification_helper<F, P>() -> Result<(), CircuitError>
+    where
+        F: RescueParameter,
+        P: Config<BaseField = F>,
+    {
+        let mut rng = jf_utils::test_rng();
+        let num_sigs = 4;
+        let keypairs: Vec<_> = (0..num_sigs)
+            .map(|_| KeyPair::<P>::generate(&mut rng))
+            .collect();
+        let vks: Vec<VerKey<P>> = keypairs
+            .iter()
+            .map(|keypair| keypair.ver_key_ref().clone())
+            .collect();
+        let msgs: Vec<Vec<F>> = (0..num_sigs)
+            .map(|i| (0..i + 1).map(|j| F::from((i * j) as u64)).collect())
+            .collect();
+        let sigs: Vec<Signature<P>> = keypairs
+            .iter()
+            .zip(msgs.iter())
+            .map(|(keypair, msg)| keypair.sign(msg, CS_ID_SCHNORR))
+            .collect();
+
+        for circuit in [
+            PlonkCircuit::<F>::new_turbo_plonk(),
+            PlonkCircuit::<F>::new_ultra_plonk(8),
+        ] {
+            // Good path
+            let batch_circuit =
+                build_verify_sigs_batch_circuit(circuit.clone(), &vks, &msgs, &sigs)?;
+            assert!(batch_circuit.check_circuit_satisfiability(&[]).is_ok());
+            let mut single_circuit = circuit.clone();
+            for ((vk, msg), sig) in vks.iter().zip(msgs.iter()).zip(sigs.iter()) {
+                single_circuit = build_verify_sig_circuit_from(single_circuit, vk, msg, sig)?;
+            }
+            if circuit.support_lookup() {
+                assert!(batch_circuit.num_gates() < single_circuit.num_gates());
+            } else {
+                assert_eq!(batch_circuit.num_gates(), single_circuit.num_gates());
+            }
+            // No signature
+            let empty_circuit =
+                build_verify_sigs_batch_circuit::<F, P>(circuit.clone(), &[], &[], &[])?;
+            assert!(empty_circuit.check_circuit_satisfiability(&[]).is_ok());
+
+            // Bad verification key
+            let mut vks_bad = vks.clone();
+            vks_bad[1] = vks[2].clone();
+            let bad_circuit =
+                build_verify_sigs_batch_circuit(circuit.clone(), &vks_bad, &msgs, &sigs)?;
+            assert!(bad_circuit.check_circuit_satisfiability(&[]).is_err());
+            // Bad signature
+            let mut sigs_bad = sigs.clone();
+            sigs_bad[3] = keypairs[3].sign(&msgs[2], CS_ID_SCHNORR);
+            let bad_circuit =
+                build_verify_sigs_batch_circuit(circuit.clone(), &vks, &msgs, &sigs_bad)?;
+            assert!(bad_circuit.check_circuit_satisfiability(&[]).is_err());
+            // Bad message
+            let mut msgs_bad = msgs.clone();
+            msgs_bad[0][0] += F::one();
+            let bad_circuit =
+                build_verify_sigs_batch_circuit(circuit.clone(), &vks, &msgs_bad, &sigs)?;
+            assert!(bad_circuit.check_circuit_satisfiability(&[]).is_err());
+            // Mismatched lengths
+            assert!(build_verify_sigs_batch_circuit(circuit, &vks, &msgs[1..], &sigs).is_err());
+        }
+        Ok(())
+    }
+
+    fn build_verify_sigs_batch_circuit<F, P>(
+        mut circuit: PlonkCircuit<F>,
+        vks: &[VerKey<P>],
+        msgs: &[Vec<F>],
+        sigs: &[Signature<P>],
+    ) -> Result<PlonkCircuit<F>, CircuitError>
+    where
+        F: RescueParameter,
+        P: Config<BaseField = F>,
+    {
+        let vk_vars = vks
+            .iter()
+            .map(|vk| circuit.create_signature_vk_variable(vk))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        let sig_vars = sigs
+            .iter()
+            .map(|sig| circuit.create_signature_variable(sig))
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        let msg_vars = msgs
+            .iter()
+            .map(|msg| {
+                msg.iter()
+                    .map(|m| circuit.create_variable(*m))
+                    .collect::<Result<Vec<_>, CircuitError>>()
+            })
+            .collect::<Result<Vec<_>, CircuitError>>()?;
+        SignatureGadget::<F, P>::verify_signatures_batch(
+            &mut circuit,
+            &vk_vars,
+            &msg_vars,
+            &sig_vars,
+        )?;
+        Ok(circuit)
+    }
+
     fn build_verify_sig_circuit<F, P>(
         vk: &VerKey<P>,
         msg: &[F],
@@ -278,7 +527,19 @@ mod tests {
         F: RescueParameter,
         P: Config<BaseField = F>,
     {
-        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        build_verify_sig_circuit_from(PlonkCircuit::<F>::new_turbo_plonk(), vk, msg, sig)
+    }
+
+    fn build_verify_sig_circuit_from<F, P>(
+        mut circuit: PlonkCircuit<F>,
+        vk: &VerKey<P>,
+        msg: &[F],
+        sig: &Signature<P>,
+    ) -> Result<PlonkCircuit<F>, CircuitError>
+    where
+        F: RescueParameter,
+        P: Config<BaseField = F>,
+    {
         let vk_var = circuit.create_signature_vk_variable(vk)?;
         let sig_var = circuit.create_signature_variable(sig)?;
         let msg_var: Vec<Variable> = msg
@@ -0,0 +1,384 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Hierarchical deterministic (HD) derivation of Schnorr keys, in the style of
+//! [BIP32][bip32].
+//!
+//! An [`ExtendedSignKey`] is a Schnorr key pair together with a 32-byte chain
+//! code. It derives child keys by index, either *hardened* (index at least
+//! [`HARDENED_OFFSET`]), where the tweak depends on the parent signing key,
+//! or *non-hardened*, where it only depends on the parent verification key.
+//! The latter can thus also be computed from the [`ExtendedVerKey`] alone,
+//! e.g. by a watch-only wallet deriving fresh addresses.
+//!
+//! As in BIP32, leaking a non-hardened child signing key together with the
+//! parent extended verification key reveals the parent signing key: use
+//! hardened derivation for any key whose children may be exposed.
+//!
+//! The derivation hashes with SHAKE256 instead of HMAC-SHA512, so keys are
+//! not compatible with BIP32 wallets, whose keys live on secp256k1 anyway.
+//! For BLS keys, see the [EIP-2333][eip2333] derivation of
+//! [`BLSSignatureScheme::derive_key`](crate::bls_over_bls12381::BLSSignatureScheme::derive_key).
+//!
+//! [bip32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+//! [eip2333]: https://eips.ethereum.org/EIPS/eip-2333
+//!
+//! # Examples
+//!
+//! ```
+//! use ark_ed_on_bn254::EdwardsConfig;
+//! use jf_signature::hd::{parse_path, ExtendedSignKey};
+//!
+//! let master = ExtendedSignKey::<EdwardsConfig>::from_seed(&[7u8; 32])?;
+//! let account = master.derive_path(&parse_path("m/44'/0'")?)?;
+//!
+//! // the extended verification key derives the same non-hardened children
+//! let xpub = account.ext_ver_key();
+//! assert_eq!(
+//!     account.derive_child(3)?.ver_key(),
+//!     xpub.derive_child(3)?.ver_key()
+//! );
+//!
+//! # Ok::<(), jf_signature::SignatureError>(())
+//! ```
+
+use crate::{
+    schnorr::{KeyPair, SignKey, VerKey},
+    SignatureError,
+};
+use ark_ec::{
+    twisted_edwards::{Projective, TECurveConfig as Config},
+    Group,
+};
+use ark_ff::{PrimeField, Zero};
+use ark_serialize::CanonicalSerialize;
+use ark_std::{format, string::ToString, vec::Vec};
+use jf_rescue::RescueParameter;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Indices from `HARDENED_OFFSET` on derive hardened children.
+pub const HARDENED_OFFSET: u32 = 1 << 31;
+
+/// Domain separator of the master key derivation.
+const MASTER_TAG: &[u8] = b"JF_SCHNORR_HD_MASTER";
+/// Domain separator of the child key derivation.
+const CHILD_TAG: &[u8] = b"JF_SCHNORR_HD_CHILD";
+
+/// Size in bytes of a chain code.
+pub const CHAIN_CODE_SIZE: usize = 32;
+
+/// Parse a derivation path such as `m/44'/0'/1`, where `'` (or `h`) marks a
+/// hardened index.
+pub fn parse_path(path: &str) -> Result<Vec<u32>, SignatureError> {
+    let mut parts = path.split('/');
+    if parts.next() != Some("m") {
+        return Err(SignatureError::ParameterError(
+            "derivation path must start with `m`".to_string(),
+        ));
+    }
+    parts
+        .map(|part| {
+            let (index, hardened) = match part.strip_suffix(['\'', 'h']) {
+                Some(index) => (index, true),
+                None => (part, false),
+            };
+            match index.parse::<u32>() {
+                Ok(index) if index < HARDENED_OFFSET => Ok(if hardened {
+                    index + HARDENED_OFFSET
+                } else {
+                    index
+                }),
+                _ => Err(SignatureError::ParameterError(format!(
+                    "invalid derivation path index: {part}"
+                ))),
+            }
+        })
+        .collect()
+}
+
+/// Extended Schnorr signing key: a key pair and its chain code.
+#[derive(Derivative)]
+#[derivative(Debug(bound = "P: Config"), Clone(bound = "P: Config"))]
+pub struct ExtendedSignKey<P: Config> {
+    key_pair: KeyPair<P>,
+    #[derivative(Debug = "ignore")]
+    chain_code: [u8; CHAIN_CODE_SIZE],
+}
+
+impl<P: Config> Drop for ExtendedSignKey<P> {
+    fn drop(&mut self) {
+        self.chain_code.zeroize();
+    }
+}
+
+/// Extended Schnorr verification key: a verification key and its chain code.
+#[derive(Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config"),
+    Eq(bound = "P: Config")
+)]
+pub struct ExtendedVerKey<P: Config> {
+    ver_key: VerKey<P>,
+    chain_code: [u8; CHAIN_CODE_SIZE],
+}
+
+impl<F, P> ExtendedSignKey<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Derive the master key from a seed of 16 to 64 bytes, which should come
+    /// from a trusted source of entropy.
+    pub fn from_seed(seed: &[u8]) -> Result<Self, SignatureError> {
+        if !(16..=64).contains(&seed.len()) {
+            return Err(SignatureError::ParameterError(format!(
+                "HD seed must have 16 to 64 bytes, got {}",
+                seed.len()
+            )));
+        }
+        let mut reader = Shake256::default()
+            .chain(MASTER_TAG)
+            .chain(seed)
+            .finalize_xof();
+        let (sk, chain_code) = read_tweak_and_chain_code::<P::ScalarField>(&mut reader);
+        if sk.is_zero() {
+            return Err(SignatureError::ParameterError(
+                "invalid HD seed, try another one".to_string(),
+            ));
+        }
+        Ok(Self {
+            key_pair: KeyPair::generate_with_sign_key(sk),
+            chain_code,
+        })
+    }
+
+    /// Derive the child key at `index`, hardened if `index` is at least
+    /// [`HARDENED_OFFSET`].
+    pub fn derive_child(&self, index: u32) -> Result<Self, SignatureError> {
+        let sk = self.key_pair.sign_key_internal();
+        let mut reader = if index >= HARDENED_OFFSET {
+            let mut sk_bytes = Zeroizing::new(Vec::new());
+            sk.serialize_compressed(&mut *sk_bytes)
+                .map_err(|e| SignatureError::ParameterError(format!("{e:?}")))?;
+            child_hasher(&self.chain_code, &[0u8], &sk_bytes, index).finalize_xof()
+        } else {
+            let vk_bytes = ver_key_bytes(self.key_pair.ver_key_ref())?;
+            child_hasher(&self.chain_code, &[1u8], &vk_bytes, index).finalize_xof()
+        };
+        let (tweak, chain_code) = read_tweak_and_chain_code::<P::ScalarField>(&mut reader);
+        let child_sk = *sk + tweak;
+        if child_sk.is_zero() {
+            return Err(SignatureError::ParameterError(format!(
+                "invalid child key at index {index}, try the next one"
+            )));
+        }
+        Ok(Self {
+            key_pair: KeyPair::generate_with_sign_key(child_sk),
+            chain_code,
+        })
+    }
+
+    /// Derive the key at `path`, relative to this key.
+    pub fn derive_path(&self, path: &[u32]) -> Result<Self, SignatureError> {
+        path.iter()
+            .try_fold(self.clone(), |key, index| key.derive_child(*index))
+    }
+
+    /// Get the extended verification key, which derives the same
+    /// non-hardened children.
+    pub fn ext_ver_key(&self) -> ExtendedVerKey<P> {
+        ExtendedVerKey {
+            ver_key: self.key_pair.ver_key(),
+            chain_code: self.chain_code,
+        }
+    }
+
+    /// Get reference to the key pair.
+    pub fn key_pair(&self) -> &KeyPair<P> {
+        &self.key_pair
+    }
+
+    /// Get the signing key.
+    pub fn sign_key(&self) -> SignKey<P::ScalarField> {
+        self.key_pair.sign_key()
+    }
+
+    /// Get the verification key.
+    pub fn ver_key(&self) -> VerKey<P> {
+        self.key_pair.ver_key()
+    }
+}
+
+impl<F, P> ExtendedVerKey<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Derive the non-hardened child key at `index`, fails if `index` is at
+    /// least [`HARDENED_OFFSET`].
+    pub fn derive_child(&self, index: u32) -> Result<Self, SignatureError> {
+        if index >= HARDENED_OFFSET {
+            return Err(SignatureError::ParameterError(
+                "cannot derive a hardened child from a verification key".to_string(),
+            ));
+        }
+        let vk_bytes = ver_key_bytes(&self.ver_key)?;
+        let mut reader = child_hasher(&self.chain_code, &[1u8], &vk_bytes, index).finalize_xof();
+        let (tweak, chain_code) = read_tweak_and_chain_code::<P::ScalarField>(&mut reader);
+        let child_vk = *self.ver_key.internal() + Projective::<P>::generator() * tweak;
+        if child_vk.is_zero() {
+            return Err(SignatureError::ParameterError(format!(
+                "invalid child key at index {index}, try the next one"
+            )));
+        }
+        Ok(Self {
+            ver_key: VerKey(child_vk),
+            chain_code,
+        })
+    }
+
+    /// Derive the key at the non-hardened `path`, relative to this key.
+    pub fn derive_path(&self, path: &[u32]) -> Result<Self, SignatureError> {
+        path.iter()
+            .try_fold(self.clone(), |key, index| key.derive_child(*index))
+    }
+
+    /// Get the verification key.
+    pub fn ver_key(&self) -> VerKey<P> {
+        self.ver_key.clone()
+    }
+
+    /// Get the chain code.
+    pub fn chain_code(&self) -> &[u8; CHAIN_CODE_SIZE] {
+        &self.chain_code
+    }
+}
+
+fn ver_key_bytes<P: Config>(vk: &VerKey<P>) -> Result<Vec<u8>, SignatureError> {
+    let mut bytes = Vec::new();
+    vk.to_affine()
+        .serialize_compressed(&mut bytes)
+        .map_err(|e| SignatureError::ParameterError(format!("{e:?}")))?;
+    Ok(bytes)
+}
+
+fn child_hasher(
+    chain_code: &[u8; CHAIN_CODE_SIZE],
+    prefix: &[u8],
+    key_bytes: &[u8],
+    index: u32,
+) -> Shake256 {
+    Shake256::default()
+        .chain(CHILD_TAG)
+        .chain(chain_code)
+        .chain(prefix)
+        .chain(key_bytes)
+        .chain(index.to_be_bytes())
+}
+
+// Read a scalar, with negligible bias from 64 bytes, followed by a chain code.
+fn read_tweak_and_chain_code<F: PrimeField>(
+    reader: &mut impl XofReader,
+) -> (F, [u8; CHAIN_CODE_SIZE]) {
+    let mut buf = Zeroizing::new([0u8; 64]);
+    reader.read(&mut *buf);
+    let mut chain_code = [0u8; CHAIN_CODE_SIZE];
+    reader.read(&mut chain_code);
+    (F::from_le_bytes_mod_order(&*buf), chain_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{schnorr::SchnorrSignatureScheme, SignatureScheme};
+    use ark_ed_on_bls12_377::EdwardsConfig as Param377;
+    use ark_ed_on_bls12_381::EdwardsConfig as Param381;
+    use ark_ed_on_bls12_381_bandersnatch::EdwardsConfig as Param381b;
+    use ark_ed_on_bn254::EdwardsConfig as Param254;
+    use ark_std::vec;
+
+    fn test_hd_derivation_helper<F, P>()
+    where
+        F: RescueParameter,
+        P: Config<BaseField = F> + Clone,
+    {
+        let seed = [42u8; 32];
+        let master = ExtendedSignKey::<P>::from_seed(&seed).unwrap();
+        // deterministic
+        assert_eq!(
+            master.ver_key(),
+            ExtendedSignKey::<P>::from_seed(&seed).unwrap().ver_key()
+        );
+        assert_ne!(
+            master.ver_key(),
+            ExtendedSignKey::<P>::from_seed(&[43u8; 32])
+                .unwrap()
+                .ver_key()
+        );
+        assert!(ExtendedSignKey::<P>::from_seed(&[0u8; 15]).is_err());
+        assert!(ExtendedSignKey::<P>::from_seed(&[0u8; 65]).is_err());
+
+        // public and private derivation agree on non-hardened paths
+        let xpub = master.ext_ver_key();
+        let path = [0, 7, 1 << 20];
+        let child = master.derive_path(&path).unwrap();
+        let child_pub = xpub.derive_path(&path).unwrap();
+        assert_eq!(child.ext_ver_key(), child_pub);
+        assert_eq!(child.ver_key(), VerKey::from(&child.sign_key()));
+
+        // hardened children only derive from the signing key
+        let hardened = master.derive_child(HARDENED_OFFSET).unwrap();
+        assert!(xpub.derive_child(HARDENED_OFFSET).is_err());
+        assert_ne!(
+            hardened.ver_key(),
+            master.derive_child(0).unwrap().ver_key()
+        );
+        assert_ne!(
+            master.derive_child(0).unwrap().ver_key(),
+            master.derive_child(1).unwrap().ver_key()
+        );
+
+        // derived keys sign as usual
+        let msg = vec![F::from(1u8), F::from(2u8)];
+        for key in [&child, &hardened] {
+            let sig = SchnorrSignatureScheme::<P>::sign(
+                &(),
+                &key.sign_key(),
+                &msg,
+                &mut jf_utils::test_rng(),
+            )
+            .unwrap();
+            assert!(SchnorrSignatureScheme::<P>::verify(&(), &key.ver_key(), &msg, &sig).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_hd_derivation() {
+        test_hd_derivation_helper::<_, Param254>();
+        test_hd_derivation_helper::<_, Param377>();
+        test_hd_derivation_helper::<_, Param381>();
+        test_hd_derivation_helper::<_, Param381b>();
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(parse_path("m").unwrap(), vec![]);
+        assert_eq!(
+            parse_path("m/44'/0h/5").unwrap(),
+            vec![44 + HARDENED_OFFSET, HARDENED_OFFSET, 5]
+        );
+        assert!(parse_path("44'/0").is_err());
+        assert!(parse_path("m/").is_err());
+        assert!(parse_path("m/a").is_err());
+        assert!(parse_path("m/2147483648").is_err());
+    }
+}
@@ -22,15 +22,28 @@ extern crate alloc;
 
 use ark_std::rand::{CryptoRng, RngCore};
 
+#[cfg(any(test, feature = "schnorr"))]
+pub mod adaptor;
+#[cfg(any(test, feature = "bls"))]
+pub mod aggregate_certificate;
+pub mod async_signer;
 #[cfg(any(test, feature = "bls"))]
 pub mod bls_over_bls12381;
 #[cfg(any(test, feature = "bls"))]
 pub mod bls_over_bn254;
 pub mod constants;
+#[cfg(any(test, feature = "ed25519"))]
+pub mod ed25519;
 #[cfg(feature = "gadgets")]
 pub mod gadgets;
 #[cfg(any(test, feature = "schnorr"))]
+pub mod hd;
+#[cfg(any(test, feature = "schnorr"))]
+pub mod musig2;
+#[cfg(any(test, feature = "schnorr"))]
 pub mod schnorr;
+#[cfg(any(test, feature = "bls"))]
+pub mod threshold_bls;
 
 use ark_std::{
     format,
@@ -143,6 +156,30 @@ pub trait SignatureScheme: Clone + Send + Sync + 'static {
     ) -> Result<(), SignatureError>;
 }
 
+/// Trait for signature schemes with domain-separated signing: a signature
+/// under a context, e.g. a protocol-specific tag, only verifies under the
+/// same context, and never as a plain signature, preventing the replay of
+/// signatures across protocols sharing keys.
+pub trait ContextSignatureScheme: SignatureScheme {
+    /// Sign a message with the signing key under the context `ctx`.
+    fn sign_with_context<R: CryptoRng + RngCore, M: AsRef<[Self::MessageUnit]>>(
+        pp: &Self::PublicParameter,
+        sk: &Self::SigningKey,
+        ctx: &[u8],
+        msg: M,
+        prng: &mut R,
+    ) -> Result<Self::Signature, SignatureError>;
+
+    /// Verify a signature under the context `ctx`.
+    fn verify_with_context<M: AsRef<[Self::MessageUnit]>>(
+        pp: &Self::PublicParameter,
+        vk: &Self::VerificationKey,
+        ctx: &[u8],
+        msg: M,
+        sig: &Self::Signature,
+    ) -> Result<(), SignatureError>;
+}
+
 /// Trait for aggregatable signatures.
 /// TODO: generic over hash functions
 // NOTE: we +Debug here instead of on `SignatureSchemes` because `schnorr <P:
@@ -234,6 +271,24 @@ mod tests {
         assert!(S::multi_sig_verify(&parameters, &pks, bad_message, &multi_sig).is_err());
     }
 
+    pub(crate) fn context_sign_and_verify<S: ContextSignatureScheme>(message: &[S::MessageUnit]) {
+        let rng = &mut test_rng();
+        let parameters = S::param_gen(Some(rng)).unwrap();
+        let (sk, pk) = S::key_gen(&parameters, rng).unwrap();
+        let sig = S::sign_with_context(&parameters, &sk, b"protocol A", message, rng).unwrap();
+        assert!(S::verify_with_context(&parameters, &pk, b"protocol A", message, &sig).is_ok());
+        // no replay under another context, or as a plain signature
+        assert!(S::verify_with_context(&parameters, &pk, b"protocol B", message, &sig).is_err());
+        assert!(S::verify(&parameters, &pk, message, &sig).is_err());
+
+        // the empty context differs from no context
+        let sig = S::sign_with_context(&parameters, &sk, b"", message, rng).unwrap();
+        assert!(S::verify_with_context(&parameters, &pk, b"", message, &sig).is_ok());
+        assert!(S::verify(&parameters, &pk, message, &sig).is_err());
+        let sig = S::sign(&parameters, &sk, message, rng).unwrap();
+        assert!(S::verify_with_context(&parameters, &pk, b"", message, &sig).is_err());
+    }
+
     pub(crate) fn failed_verification<S: SignatureScheme>(
         message: &[S::MessageUnit],
         bad_message: &[S::MessageUnit],
@@ -0,0 +1,403 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements [MuSig2][musig2] two-round multisignatures for the
+//! Schnorr signature scheme of [`crate::schnorr`].
+//!
+//! The signers first aggregate their verification keys with
+//! [`KeyAggContext::new`]. Then, to sign a message:
+//!
+//! **Round 1**
+//!    * each signer samples a pair of nonces with [`MuSig2::nonce_gen`], keeps
+//!      the [`SecretNonce`] and broadcasts the [`PublicNonce`], which are
+//!      aggregated into an [`AggregateNonce`].
+//!
+//! **Round 2**
+//!    * each signer computes a [`PartialSignature`] with
+//!      [`MuSig2::partial_sign`], and the partial signatures are combined with
+//!      [`MuSig2::aggregate`] into a plain Schnorr [`Signature`] verifiable
+//!      against the aggregated verification key by
+//!      [`SchnorrSignatureScheme`](crate::schnorr::SchnorrSignatureScheme).
+//!
+//! The first round can be run before the message is known. A secret nonce
+//! must never be used twice, which the API enforces by consuming it.
+//!
+//! [musig2]: https://eprint.iacr.org/2020/1261
+
+use crate::{
+    constants::CS_ID_SCHNORR,
+    schnorr::{KeyPair, Signature, VerKey},
+    SignatureError::{self, ParameterError, VerificationError},
+};
+use ark_ec::{
+    twisted_edwards::{Projective, TECurveConfig as Config},
+    CurveGroup, Group,
+};
+use ark_ff::Zero;
+use ark_serialize::*;
+use ark_std::{
+    format,
+    marker::PhantomData,
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec,
+    vec::Vec,
+    UniformRand,
+};
+use jf_crhf::CRHF;
+use jf_rescue::{crhf::VariableLengthRescueCRHF, RescueParameter};
+use jf_utils::fq_to_fr_with_mask;
+use zeroize::Zeroize;
+
+/// Domain separator of the hash of the list of verification keys.
+const MUSIG2_KEY_LIST_TAG: &[u8] = b"MUSIG2_KEY_LIST";
+/// Domain separator of the hash computing key aggregation coefficients.
+const MUSIG2_KEY_COEFF_TAG: &[u8] = b"MUSIG2_KEY_COEFF";
+/// Domain separator of the hash computing the nonce coefficient.
+const MUSIG2_NONCE_COEFF_TAG: &[u8] = b"MUSIG2_NONCE_COEFF";
+
+/// MuSig2 multisignature scheme over the curve `P`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MuSig2<P> {
+    curve_param: PhantomData<P>,
+}
+
+/// Aggregation of the verification keys of a set of signers, with their
+/// aggregation coefficients.
+#[derive(Derivative)]
+#[derivative(Debug(bound = "P: Config"), Clone(bound = "P: Config"))]
+pub struct KeyAggContext<P: Config> {
+    agg_vk: VerKey<P>,
+    vks: Vec<VerKey<P>>,
+    coeffs: Vec<P::ScalarField>,
+}
+
+/// Secret nonces of a signer for a single signing session.
+// not `Clone` so that the nonces can't be reused
+#[derive(Derivative)]
+#[derivative(Debug(bound = "P: Config"))]
+pub struct SecretNonce<P: Config> {
+    #[derivative(Debug = "ignore")]
+    r1: P::ScalarField,
+    #[derivative(Debug = "ignore")]
+    r2: P::ScalarField,
+}
+
+impl<P: Config> Drop for SecretNonce<P> {
+    fn drop(&mut self) {
+        self.r1.zeroize();
+        self.r2.zeroize();
+    }
+}
+
+/// Public nonces of a signer, broadcast in the first round.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+#[allow(non_snake_case)]
+pub struct PublicNonce<P: Config> {
+    R1: Projective<P>,
+    R2: Projective<P>,
+}
+
+/// Sum of the public nonces of all the signers.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+#[allow(non_snake_case)]
+pub struct AggregateNonce<P: Config> {
+    R1: Projective<P>,
+    R2: Projective<P>,
+}
+
+/// Signature share of a signer, computed in the second round.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+pub struct PartialSignature<P: Config> {
+    s: P::ScalarField,
+}
+
+impl<F, P> KeyAggContext<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Aggregate the verification keys `vks` of the signers. The order of
+    /// `vks` matters and must be the same for all the signers.
+    pub fn new(vks: &[VerKey<P>]) -> Result<Self, SignatureError> {
+        if vks.is_empty() {
+            return Err(ParameterError(
+                "no verification key to aggregate".to_string(),
+            ));
+        }
+        let mut list_input = vec![F::from_be_bytes_mod_order(MUSIG2_KEY_LIST_TAG)];
+        for vk in vks.iter() {
+            let vk_affine = vk.0.into_affine();
+            list_input.extend([vk_affine.x, vk_affine.y]);
+        }
+        let list_hash = VariableLengthRescueCRHF::<F, 1>::evaluate(list_input).unwrap()[0]; // safe unwrap
+
+        let coeffs: Vec<P::ScalarField> = vks
+            .iter()
+            .map(|vk| {
+                let vk_affine = vk.0.into_affine();
+                hash_to_scalar::<F, P>(vec![
+                    F::from_be_bytes_mod_order(MUSIG2_KEY_COEFF_TAG),
+                    list_hash,
+                    vk_affine.x,
+                    vk_affine.y,
+                ])
+            })
+            .collect();
+        let agg_vk = vks
+            .iter()
+            .zip(coeffs.iter())
+            .fold(Projective::<P>::zero(), |acc, (vk, a)| acc + vk.0 * a);
+        if agg_vk.is_zero() {
+            return Err(ParameterError(
+                "aggregated verification key is the identity".to_string(),
+            ));
+        }
+        Ok(Self {
+            agg_vk: VerKey(agg_vk),
+            vks: vks.to_vec(),
+            coeffs,
+        })
+    }
+
+    /// The aggregated verification key, against which the aggregated
+    /// signatures verify.
+    pub fn agg_ver_key(&self) -> VerKey<P> {
+        self.agg_vk.clone()
+    }
+
+    // Return the aggregation coefficient of `vk`, or an error if it is not
+    // one of the aggregated keys.
+    fn coeff(&self, vk: &VerKey<P>) -> Result<P::ScalarField, SignatureError> {
+        self.vks
+            .iter()
+            .position(|other| other == vk)
+            .map(|i| self.coeffs[i])
+            .ok_or_else(|| ParameterError("signer is not part of the key aggregation".to_string()))
+    }
+}
+
+impl<P: Config> AggregateNonce<P> {
+    /// Aggregate the public nonces of all the signers.
+    pub fn aggregate(nonces: &[PublicNonce<P>]) -> Result<Self, SignatureError> {
+        if nonces.is_empty() {
+            return Err(ParameterError("no public nonce to aggregate".to_string()));
+        }
+        Ok(Self {
+            R1: nonces.iter().map(|nonce| nonce.R1).sum(),
+            R2: nonces.iter().map(|nonce| nonce.R2).sum(),
+        })
+    }
+}
+
+impl<F, P> MuSig2<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    /// Sample the nonces of a signer for a signing session. The secret nonce
+    /// is kept for [`Self::partial_sign`] and the public nonce is broadcast.
+    pub fn nonce_gen<R: CryptoRng + RngCore>(prng: &mut R) -> (SecretNonce<P>, PublicNonce<P>) {
+        let r1 = P::ScalarField::rand(prng);
+        let r2 = P::ScalarField::rand(prng);
+        let generator = Projective::<P>::generator();
+        let public = PublicNonce {
+            R1: generator * r1,
+            R2: generator * r2,
+        };
+        (SecretNonce { r1, r2 }, public)
+    }
+
+    /// Compute the partial signature of the signer with key pair `keypair` on
+    /// `msg`, consuming its secret nonce.
+    pub fn partial_sign(
+        ctx: &KeyAggContext<P>,
+        keypair: &KeyPair<P>,
+        sec_nonce: SecretNonce<P>,
+        agg_nonce: &AggregateNonce<P>,
+        msg: &[F],
+    ) -> Result<PartialSignature<P>, SignatureError> {
+        let a = ctx.coeff(keypair.ver_key_ref())?;
+        let (b, _, c) = Self::session_coeffs(ctx, agg_nonce, msg);
+        Ok(PartialSignature {
+            s: c * a * keypair.sign_key_internal() + sec_nonce.r1 + b * sec_nonce.r2,
+        })
+    }
+
+    /// Check the partial signature of the signer with verification key `vk`
+    /// and public nonce `pub_nonce`.
+    pub fn partial_verify(
+        ctx: &KeyAggContext<P>,
+        vk: &VerKey<P>,
+        pub_nonce: &PublicNonce<P>,
+        agg_nonce: &AggregateNonce<P>,
+        msg: &[F],
+        partial_sig: &PartialSignature<P>,
+    ) -> Result<(), SignatureError> {
+        let a = ctx.coeff(vk)?;
+        let (b, _, c) = Self::session_coeffs(ctx, agg_nonce, msg);
+        if Projective::<P>::generator() * partial_sig.s
+            == pub_nonce.R1 + pub_nonce.R2 * b + vk.0 * (c * a)
+        {
+            Ok(())
+        } else {
+            Err(VerificationError(
+                "Partial signature verification error".to_string(),
+            ))
+        }
+    }
+
+    /// Combine the partial signatures of all the signers into a Schnorr
+    /// signature on `msg` under [`KeyAggContext::agg_ver_key`].
+    pub fn aggregate(
+        ctx: &KeyAggContext<P>,
+        agg_nonce: &AggregateNonce<P>,
+        msg: &[F],
+        partial_sigs: &[PartialSignature<P>],
+    ) -> Result<Signature<P>, SignatureError> {
+        if partial_sigs.len() != ctx.vks.len() {
+            return Err(ParameterError(format!(
+                "{} partial signatures for {} signers",
+                partial_sigs.len(),
+                ctx.vks.len()
+            )));
+        }
+        #[allow(non_snake_case)]
+        let (_, R, _) = Self::session_coeffs(ctx, agg_nonce, msg);
+        let s = partial_sigs.iter().map(|partial_sig| partial_sig.s).sum();
+        Ok(Signature { s, R })
+    }
+
+    // Return the nonce coefficient `b`, the session nonce `R = R1 + b * R2`
+    // and the Schnorr challenge `c` of the session.
+    #[allow(non_snake_case)]
+    fn session_coeffs(
+        ctx: &KeyAggContext<P>,
+        agg_nonce: &AggregateNonce<P>,
+        msg: &[F],
+    ) -> (P::ScalarField, Projective<P>, P::ScalarField) {
+        let vk_affine = ctx.agg_vk.0.into_affine();
+        let R1_affine = agg_nonce.R1.into_affine();
+        let R2_affine = agg_nonce.R2.into_affine();
+        let mut input = vec![
+            F::from_be_bytes_mod_order(MUSIG2_NONCE_COEFF_TAG),
+            vk_affine.x,
+            vk_affine.y,
+            R1_affine.x,
+            R1_affine.y,
+            R2_affine.x,
+            R2_affine.y,
+        ];
+        input.extend(msg);
+        let b = hash_to_scalar::<F, P>(input);
+        let R = agg_nonce.R1 + agg_nonce.R2 * b;
+        let c = ctx.agg_vk.challenge(&R, msg, CS_ID_SCHNORR);
+        (b, R, c)
+    }
+}
+
+fn hash_to_scalar<F, P>(input: Vec<F>) -> P::ScalarField
+where
+    F: RescueParameter,
+    P: Config<BaseField = F>,
+{
+    let hash = VariableLengthRescueCRHF::<F, 1>::evaluate(input).unwrap()[0]; // safe unwrap
+    fq_to_fr_with_mask(&hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{schnorr::SchnorrSignatureScheme, SignatureScheme};
+    use ark_ed_on_bls12_377::EdwardsConfig as Param377;
+    use ark_ed_on_bls12_381::EdwardsConfig as Param381;
+    use ark_ed_on_bls12_381_bandersnatch::EdwardsConfig as Param381b;
+    use ark_ed_on_bn254::EdwardsConfig as Param254;
+
+    #[test]
+    fn test_musig2() {
+        test_musig2_helper::<Param254>(1);
+        test_musig2_helper::<Param254>(4);
+        test_musig2_helper::<Param377>(3);
+        test_musig2_helper::<Param381>(3);
+        test_musig2_helper::<Param381b>(3);
+    }
+
+    fn test_musig2_helper<P>(num_signers: usize)
+    where
+        P: Config + Clone,
+        P::BaseField: RescueParameter,
+    {
+        let mut rng = jf_utils::test_rng();
+        let keypairs: Vec<KeyPair<P>> = (0..num_signers)
+            .map(|_| KeyPair::generate(&mut rng))
+            .collect();
+        let vks: Vec<VerKey<P>> = keypairs.iter().map(|kp| kp.ver_key()).collect();
+        let ctx = KeyAggContext::new(&vks).unwrap();
+        let msg = vec![P::BaseField::from(3u8), P::BaseField::from(7u8)];
+
+        // round 1
+        let (sec_nonces, pub_nonces): (Vec<_>, Vec<_>) = (0..num_signers)
+            .map(|_| MuSig2::<P>::nonce_gen(&mut rng))
+            .unzip();
+        let agg_nonce = AggregateNonce::aggregate(&pub_nonces).unwrap();
+
+        // round 2
+        let partial_sigs: Vec<PartialSignature<P>> = keypairs
+            .iter()
+            .zip(sec_nonces)
+            .map(|(kp, sec_nonce)| {
+                MuSig2::partial_sign(&ctx, kp, sec_nonce, &agg_nonce, &msg).unwrap()
+            })
+            .collect();
+        for ((vk, pub_nonce), partial_sig) in vks.iter().zip(&pub_nonces).zip(&partial_sigs) {
+            assert!(
+                MuSig2::partial_verify(&ctx, vk, pub_nonce, &agg_nonce, &msg, partial_sig).is_ok()
+            );
+            assert!(MuSig2::partial_verify(
+                &ctx,
+                vk,
+                pub_nonce,
+                &agg_nonce,
+                &msg[..1],
+                partial_sig
+            )
+            .is_err());
+        }
+        let sig = MuSig2::aggregate(&ctx, &agg_nonce, &msg, &partial_sigs).unwrap();
+
+        // verifiable by the single-key Schnorr verifier
+        let agg_vk = ctx.agg_ver_key();
+        assert!(SchnorrSignatureScheme::<P>::verify(&(), &agg_vk, &msg, &sig).is_ok());
+        assert!(SchnorrSignatureScheme::<P>::verify(&(), &agg_vk, &msg[..1], &sig).is_err());
+        assert!(SchnorrSignatureScheme::<P>::verify(&(), &vks[0], &msg, &sig).is_err());
+
+        // missing partial signature
+        assert!(MuSig2::aggregate(&ctx, &agg_nonce, &msg, &partial_sigs[1..]).is_err());
+        // signer outside of the key aggregation
+        let outsider = KeyPair::<P>::generate(&mut rng);
+        let (sec_nonce, _) = MuSig2::<P>::nonce_gen(&mut rng);
+        assert!(MuSig2::partial_sign(&ctx, &outsider, sec_nonce, &agg_nonce, &msg).is_err());
+        // empty inputs
+        assert!(KeyAggContext::<P>::new(&[]).is_err());
+        assert!(AggregateNonce::<P>::aggregate(&[]).is_err());
+    }
+}
@@ -7,30 +7,40 @@
 //! This module implements the Schnorr signature over the various Edwards
 //! curves.
 
-use super::SignatureScheme;
+use super::{ContextSignatureScheme, SignatureScheme};
 use crate::{
-    constants::{tag, CS_ID_SCHNORR},
+    constants::{tag, CS_ID_SCHNORR, CS_ID_SCHNORR_CTX},
     SignatureError,
 };
 use ark_ec::{
+    scalar_mul::variable_base::VariableBaseMSM,
     twisted_edwards::{Affine, Projective, TECurveConfig as Config},
     AffineRepr, CurveConfig, CurveGroup, Group,
 };
-use ark_ff::PrimeField;
+use ark_ff::{BigInteger, One, PrimeField, Zero};
 use ark_serialize::*;
 use ark_std::{
+    format,
     hash::{Hash, Hasher},
     marker::PhantomData,
     rand::{CryptoRng, Rng, RngCore},
     string::ToString,
     vec,
     vec::Vec,
+    UniformRand,
 };
 use jf_crhf::CRHF;
-use jf_rescue::{crhf::VariableLengthRescueCRHF, RescueParameter};
+use jf_prf::PRF;
+use jf_rescue::{crhf::VariableLengthRescueCRHF, prf::RescuePRF, RescueParameter};
 use jf_utils::{fq_to_fr, fq_to_fr_with_mask, fr_to_fq};
+use sha3::{Digest, Sha3_256};
 use tagged_base64::tagged;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Domain separator of the hash computing the half-aggregation coefficients.
+const HALF_AGG_TAG: &[u8] = b"SCHNORR_HALF_AGG";
+/// Domain separator of the PRF deriving key pairs from a seed.
+const KEY_BATCH_TAG: &[u8] = b"SCHNORR_KEY_BATCH";
 
 /// Schnorr signature scheme.
 #[derive(Debug, PartialEq, Clone)]
@@ -76,15 +86,18 @@ where
         Ok((kp.sk, kp.vk))
     }
 
-    /// Sign a message with the signing key
+    /// Sign a message with the signing key. The nonce is derived from the
+    /// signing key, the message and fresh randomness from `prng`, see
+    /// [`KeyPair::sign_randomized`]; use [`Self::sign_deterministic`] for
+    /// signers without a good source of randomness.
     fn sign<R: CryptoRng + RngCore, M: AsRef<[Self::MessageUnit]>>(
         _pp: &Self::PublicParameter,
         sk: &Self::SigningKey,
         msg: M,
-        _prng: &mut R,
+        prng: &mut R,
     ) -> Result<Self::Signature, SignatureError> {
         let kp = KeyPair::<P>::generate_with_sign_key(sk.0);
-        Ok(kp.sign(msg.as_ref(), Self::CS_ID))
+        Ok(kp.sign_randomized(msg.as_ref(), Self::CS_ID, prng))
     }
 
     /// Verify a signature.
@@ -98,6 +111,223 @@ where
     }
 }
 
+impl<F, P> ContextSignatureScheme for SchnorrSignatureScheme<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F> + Clone,
+{
+    /// Sign a message under the context `ctx`, which is hashed into the
+    /// domain separator of both the challenge and the nonce derivation.
+    fn sign_with_context<R: CryptoRng + RngCore, M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        sk: &Self::SigningKey,
+        ctx: &[u8],
+        msg: M,
+        prng: &mut R,
+    ) -> Result<Self::Signature, SignatureError> {
+        let kp = KeyPair::<P>::generate_with_sign_key(sk.0);
+        Ok(kp.sign_randomized(msg.as_ref(), context_csid(ctx), prng))
+    }
+
+    /// Verify a signature under the context `ctx`.
+    fn verify_with_context<M: AsRef<[Self::MessageUnit]>>(
+        _pp: &Self::PublicParameter,
+        vk: &Self::VerificationKey,
+        ctx: &[u8],
+        msg: M,
+        sig: &Self::Signature,
+    ) -> Result<(), SignatureError> {
+        vk.verify(msg.as_ref(), sig, context_csid(ctx))
+    }
+}
+
+impl<F, P> SchnorrSignatureScheme<P>
+where
+    F: RescueParameter,
+    P: Config<BaseField = F> + Clone,
+{
+    /// Sign a message with the signing key and a nonce derived
+    /// deterministically from the signing key and the message, see
+    /// [`KeyPair::sign`]. Signing the same message twice yields the same
+    /// signature.
+    pub fn sign_deterministic<M: AsRef<[F]>>(sk: &SignKey<P::ScalarField>, msg: M) -> Signature<P> {
+        let kp = KeyPair::<P>::generate_with_sign_key(sk.0);
+        kp.sign(msg.as_ref(), Self::CS_ID)
+    }
+
+    /// Verify a batch of signatures at once, each with its own verification
+    /// key and message.
+    ///
+    /// The verification equations `g^s = R * vk^c` are combined with random
+    /// 128-bit coefficients sampled from `prng` into a single multi-scalar
+    /// multiplication, which is much faster than verifying the signatures one
+    /// by one. The batch is accepted if every signature is valid, and rejected
+    /// with overwhelming probability otherwise, except that signatures which
+    /// only fail [`SignatureScheme::verify`] because of small-order components
+    /// in `R` or in the verification key may be accepted. An empty batch is
+    /// accepted.
+    #[allow(non_snake_case)]
+    pub fn batch_verify<R: CryptoRng + RngCore, M: AsRef<[F]>>(
+        items: &[(VerKey<P>, M, Signature<P>)],
+        prng: &mut R,
+    ) -> Result<(), SignatureError> {
+        let mut bases = Vec::with_capacity(2 * items.len() + 1);
+        let mut scalars = Vec::with_capacity(2 * items.len() + 1);
+        let mut s_combined = P::ScalarField::zero();
+        for (vk, msg, sig) in items.iter() {
+            // Reject if public key is of small order
+            if (vk.0 * P::ScalarField::from(curve_cofactor::<P>())) == Projective::<P>::default() {
+                return Err(SignatureError::VerificationError(
+                    "public key is not valid: not in the correct subgroup".to_string(),
+                ));
+            }
+            let c = vk.challenge(&sig.R, msg.as_ref(), Self::CS_ID);
+            let z = P::ScalarField::from(prng.gen::<u128>());
+            s_combined += z * sig.s;
+            bases.push(sig.R);
+            scalars.push(-z);
+            bases.push(vk.0);
+            scalars.push(-z * c);
+        }
+        bases.push(Projective::<P>::generator());
+        scalars.push(s_combined);
+
+        let bases = Projective::<P>::normalize_batch(&bases);
+        // safe unwrap: `bases` and `scalars` have the same length
+        if Projective::<P>::msm(&bases, &scalars).unwrap().is_zero() {
+            Ok(())
+        } else {
+            Err(SignatureError::VerificationError(
+                "Batch signature verification error".to_string(),
+            ))
+        }
+    }
+
+    /// Half-aggregate the signatures of `items` on their messages under their
+    /// verification keys, without interaction with the signers, e.g. to
+    /// carry many signatures in a block.
+    ///
+    /// The `s` components are combined as `s = sum_i z_i * s_i` with
+    /// coefficients `z_i` hashed from all the nonces, verification keys and
+    /// messages, following <https://eprint.iacr.org/2022/222>. The signatures
+    /// should be verified beforehand: an invalid one makes the aggregate
+    /// invalid. Return an error if `items` is empty.
+    #[allow(non_snake_case)]
+    pub fn half_aggregate<M: AsRef<[F]>>(
+        items: &[(VerKey<P>, M, Signature<P>)],
+    ) -> Result<HalfAggSignature<P>, SignatureError> {
+        if items.is_empty() {
+            return Err(SignatureError::ParameterError(
+                "no signatures to aggregate".to_string(),
+            ));
+        }
+        let Rs: Vec<_> = items.iter().map(|(_, _, sig)| sig.R).collect();
+        let coeffs =
+            Self::half_agg_coeffs(items.iter().map(|(vk, msg, _)| (vk, msg.as_ref())), &Rs);
+        let s = items
+            .iter()
+            .zip(coeffs)
+            .map(|((_, _, sig), z)| z * sig.s)
+            .sum();
+        Ok(HalfAggSignature { s, Rs })
+    }
+
+    /// Verify a half-aggregate signature against the verification keys and
+    /// messages of the aggregated signatures, in the order of aggregation.
+    ///
+    /// The verification equations `g^s_i = R_i * vk_i^c_i` combined with the
+    /// aggregation coefficients are checked with a single multi-scalar
+    /// multiplication. As for [`Self::batch_verify`], signatures which only
+    /// fail [`SignatureScheme::verify`] because of small-order components in
+    /// `R` may be accepted.
+    #[allow(non_snake_case)]
+    pub fn half_aggregate_verify<M: AsRef<[F]>>(
+        items: &[(VerKey<P>, M)],
+        agg_sig: &HalfAggSignature<P>,
+    ) -> Result<(), SignatureError> {
+        if items.is_empty() || items.len() != agg_sig.Rs.len() {
+            return Err(SignatureError::ParameterError(format!(
+                "{} verification keys for {} aggregated signatures",
+                items.len(),
+                agg_sig.Rs.len()
+            )));
+        }
+        let coeffs = Self::half_agg_coeffs(
+            items.iter().map(|(vk, msg)| (vk, msg.as_ref())),
+            &agg_sig.Rs,
+        );
+        let mut bases = Vec::with_capacity(2 * items.len() + 1);
+        let mut scalars = Vec::with_capacity(2 * items.len() + 1);
+        for (((vk, msg), R), z) in items.iter().zip(agg_sig.Rs.iter()).zip(coeffs) {
+            // Reject if public key is of small order
+            if (vk.0 * P::ScalarField::from(curve_cofactor::<P>())) == Projective::<P>::default() {
+                return Err(SignatureError::VerificationError(
+                    "public key is not valid: not in the correct subgroup".to_string(),
+                ));
+            }
+            let c = vk.challenge(R, msg.as_ref(), Self::CS_ID);
+            bases.push(*R);
+            scalars.push(-z);
+            bases.push(vk.0);
+            scalars.push(-z * c);
+        }
+        bases.push(Projective::<P>::generator());
+        scalars.push(agg_sig.s);
+
+        let bases = Projective::<P>::normalize_batch(&bases);
+        // safe unwrap: `bases` and `scalars` have the same length
+        if Projective::<P>::msm(&bases, &scalars).unwrap().is_zero() {
+            Ok(())
+        } else {
+            Err(SignatureError::VerificationError(
+                "Half-aggregate signature verification error".to_string(),
+            ))
+        }
+    }
+
+    // The half-aggregation coefficients of the signatures with nonces `Rs` on
+    // the messages of `keys_msgs`: `z_0 = 1` and `z_i = H(L, i)` for the hash
+    // `L` of all the nonces, verification keys and messages.
+    #[allow(non_snake_case)]
+    fn half_agg_coeffs<'a>(
+        keys_msgs: impl Iterator<Item = (&'a VerKey<P>, &'a [F])>,
+        Rs: &[Projective<P>],
+    ) -> Vec<P::ScalarField>
+    where
+        P: 'a,
+    {
+        let mut input = vec![F::from_be_bytes_mod_order(HALF_AGG_TAG)];
+        for ((vk, msg), R) in keys_msgs.zip(Rs.iter()) {
+            let R_affine = R.into_affine();
+            let vk_affine = vk.0.into_affine();
+            input.extend([
+                R_affine.x,
+                R_affine.y,
+                vk_affine.x,
+                vk_affine.y,
+                F::from(msg.len() as u64),
+            ]);
+            input.extend(msg);
+        }
+        let list_hash = VariableLengthRescueCRHF::<F, 1>::evaluate(input).unwrap()[0]; // safe unwrap
+
+        (0..Rs.len())
+            .map(|i| {
+                if i == 0 {
+                    P::ScalarField::from(1u8)
+                } else {
+                    let hash = VariableLengthRescueCRHF::<F, 1>::evaluate(vec![
+                        list_hash,
+                        F::from(i as u64),
+                    ])
+                    .unwrap()[0]; // safe unwrap
+                    fq_to_fr_with_mask(&hash)
+                }
+            })
+            .collect()
+    }
+}
+
 // =====================================================
 // Signing key
 // =====================================================
@@ -123,6 +353,8 @@ impl<F: PrimeField> Drop for SignKey<F> {
     }
 }
 
+impl<F: PrimeField> ZeroizeOnDrop for SignKey<F> {}
+
 impl<F: PrimeField> SignKey<F> {
     // returns the randomized key
     fn randomize_with(&self, randomizer: &F) -> Self {
@@ -256,6 +488,36 @@ where
         self.s == other.s && self.R.into_affine() == other.R.into_affine()
     }
 }
+/// Half-aggregation of Schnorr signatures on many messages under many
+/// verification keys: the nonces `R` of all the signatures with a single
+/// combined `s`, about half the size of the signatures.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Debug(bound = "P: Config"),
+    Clone(bound = "P: Config"),
+    PartialEq(bound = "P: Config")
+)]
+#[allow(non_snake_case)]
+pub struct HalfAggSignature<P>
+where
+    P: Config,
+{
+    pub(crate) s: P::ScalarField,
+    pub(crate) Rs: Vec<Projective<P>>,
+}
+
+impl<P: Config> HalfAggSignature<P> {
+    /// Number of aggregated signatures.
+    pub fn len(&self) -> usize {
+        self.Rs.len()
+    }
+
+    /// Whether no signature is aggregated.
+    pub fn is_empty(&self) -> bool {
+        self.Rs.is_empty()
+    }
+}
+
 // =====================================================
 // end of definitions
 // =====================================================
@@ -279,6 +541,30 @@ where
         KeyPair { sk, vk }
     }
 
+    /// Deterministic generation of the key pair at `index` from `seed`: the
+    /// signing key is derived with the Rescue PRF keyed by `seed` on the
+    /// domain-separated `index`. The seed must be kept as secret as the keys.
+    pub fn generate_from_seed(seed: &F, index: u64) -> Self {
+        let input = [F::from_be_bytes_mod_order(KEY_BATCH_TAG), F::from(index)];
+        // safe unwrap: the input has a fixed length
+        let output = RescuePRF::<F, 2, 2>::evaluate(seed, input).unwrap();
+        // reduce 2 base field elements, so that the signing key is
+        // statistically close to uniform
+        let mut bytes = output[0].into_bigint().to_bytes_le();
+        bytes.extend(output[1].into_bigint().to_bytes_le());
+        let sk = P::ScalarField::from_le_bytes_mod_order(&bytes);
+        bytes.zeroize();
+        Self::generate_with_sign_key(sk)
+    }
+
+    /// Deterministic generation of the `n` key pairs at indices `0..n` from
+    /// `seed`, see [`Self::generate_from_seed`].
+    pub fn generate_batch(seed: &F, n: u64) -> Vec<Self> {
+        (0..n)
+            .map(|index| Self::generate_from_seed(seed, index))
+            .collect()
+    }
+
     /// Get reference to verification key
     pub fn ver_key_ref(&self) -> &VerKey<P> {
         &self.vk
@@ -306,14 +592,34 @@ where
         &self.sk.0
     }
 
-    /// Signature function
-    #[allow(non_snake_case)]
+    /// Signature function, with a deterministic nonce: in the spirit of RFC
+    /// 6979, the nonce is a keyed hash of the signing key and the message, so
+    /// that no randomness is needed and a nonce is never reused for different
+    /// messages.
     pub fn sign<B: AsRef<[u8]>>(&self, msg: &[F], csid: B) -> Signature<P> {
-        // Do we want to remove the instance description?
-        let instance_description = F::from_be_bytes_mod_order(csid.as_ref());
-        let mut msg_input = vec![instance_description, fr_to_fq::<F, P>(&self.sk.0)];
-        msg_input.extend(msg.iter());
+        self.sign_internal(msg, csid, None)
+    }
+
+    /// Signature function, with a randomized nonce: fresh randomness from
+    /// `prng` is hashed along with the signing key and the message, so that
+    /// the nonce stays unpredictable even if `prng` is weak.
+    pub fn sign_randomized<B: AsRef<[u8]>, R: CryptoRng + RngCore>(
+        &self,
+        msg: &[F],
+        csid: B,
+        prng: &mut R,
+    ) -> Signature<P> {
+        self.sign_internal(msg, csid, Some(F::rand(prng)))
+    }
 
+    #[allow(non_snake_case)]
+    fn sign_internal<B: AsRef<[u8]>>(
+        &self,
+        msg: &[F],
+        csid: B,
+        randomness: Option<F>,
+    ) -> Signature<P> {
+        let msg_input = self.nonce_input(msg, csid.as_ref(), randomness);
         let r =
             fq_to_fr::<F, P>(&VariableLengthRescueCRHF::<F, 1>::evaluate(&msg_input).unwrap()[0]); // safe unwrap
         let R = Projective::<P>::generator() * r;
@@ -323,6 +629,25 @@ where
         Signature { s, R }
     }
 
+    // The input of the hash deriving the nonce: the instance description, the
+    // signing key, a flag separating the deterministic and randomized nonces,
+    // then the randomness if any and the message. Without the flag, the
+    // deterministic nonce of `[randomness] ++ msg` would be the randomized
+    // nonce of `msg`, and the two signatures would leak the signing key.
+    fn nonce_input(&self, msg: &[F], csid: &[u8], randomness: Option<F>) -> Vec<F> {
+        // Do we want to remove the instance description?
+        let instance_description = F::from_be_bytes_mod_order(csid);
+        let flag = if randomness.is_some() {
+            F::one()
+        } else {
+            F::zero()
+        };
+        let mut msg_input = vec![instance_description, fr_to_fq::<F, P>(&self.sk.0), flag];
+        msg_input.extend(randomness);
+        msg_input.extend(msg.iter());
+        msg_input
+    }
+
     /// Randomize the key pair with the `randomizer`, return the randomized key
     /// pair.
     pub fn randomize_with(&self, randomizer: &<P as CurveConfig>::ScalarField) -> Self {
@@ -412,7 +737,12 @@ where
     // TODO: this function should be generic w.r.t. hash functions
     // Fixme after the hash-api PR is merged.
     #[allow(non_snake_case)]
-    fn challenge<B: AsRef<[u8]>>(&self, R: &Projective<P>, msg: &[F], csid: B) -> P::ScalarField {
+    pub(crate) fn challenge<B: AsRef<[u8]>>(
+        &self,
+        R: &Projective<P>,
+        msg: &[F],
+        csid: B,
+    ) -> P::ScalarField {
         // is the domain separator always an Fr? If so how about using Fr as domain
         // separator rather than bytes?
         let instance_description = F::from_be_bytes_mod_order(csid.as_ref());
@@ -441,10 +771,19 @@ pub(crate) fn curve_cofactor<P: Config>() -> u64 {
     P::COFACTOR[0]
 }
 
+// The domain separator of signatures under the context `ctx`. The context is
+// hashed, as domain separators are reduced into a single field element.
+fn context_csid(ctx: &[u8]) -> impl AsRef<[u8]> {
+    Sha3_256::new()
+        .chain_update(CS_ID_SCHNORR_CTX)
+        .chain_update(ctx)
+        .finalize()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::{failed_verification, sign_and_verify};
+    use crate::tests::{context_sign_and_verify, failed_verification, sign_and_verify};
     use ark_ed_on_bls12_377::EdwardsConfig as Param377;
     use ark_ed_on_bls12_381::EdwardsConfig as Param381;
     use ark_ed_on_bls12_381_bandersnatch::EdwardsConfig as Param381b;
@@ -483,6 +822,7 @@ mod tests {
 
             let message = <$curve_param as CurveConfig>::BaseField::rand(&mut rng);
             sign_and_verify::<SchnorrSignatureScheme<$curve_param>>(&[message]);
+            context_sign_and_verify::<SchnorrSignatureScheme<$curve_param>>(&[message]);
             failed_verification::<SchnorrSignatureScheme<$curve_param>>(
                 &[message],
                 &[<$curve_param as CurveConfig>::BaseField::rand(&mut rng)],
@@ -498,6 +838,231 @@ mod tests {
         test_signature!(Param381b);
     }
 
+    #[test]
+    fn test_sign_key_hardening() {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<SignKey<<Param254 as CurveConfig>::ScalarField>>();
+
+        let mut rng = jf_utils::test_rng();
+        let keypair = KeyPair::<Param254>::generate(&mut rng);
+        let secret = ark_std::format!("{:?}", keypair.sign_key_internal());
+        assert!(!ark_std::format!("{:?}", keypair).contains(&secret));
+    }
+
+    #[test]
+    fn test_generate_batch() {
+        test_generate_batch_helper::<Param254>();
+        test_generate_batch_helper::<Param377>();
+        test_generate_batch_helper::<Param381>();
+        test_generate_batch_helper::<Param381b>();
+    }
+
+    fn test_generate_batch_helper<P>()
+    where
+        P: Config,
+        P::BaseField: RescueParameter,
+    {
+        let mut rng = jf_utils::test_rng();
+        let seed = P::BaseField::rand(&mut rng);
+        let key_pairs = KeyPair::<P>::generate_batch(&seed, 5);
+        assert_eq!(key_pairs.len(), 5);
+        // deterministic, and the batch agrees with single generation
+        assert_eq!(key_pairs, KeyPair::<P>::generate_batch(&seed, 5));
+        assert_eq!(key_pairs[3], KeyPair::<P>::generate_from_seed(&seed, 3));
+        assert_eq!(key_pairs[..2], KeyPair::<P>::generate_batch(&seed, 2));
+        // distinct keys, across indices and seeds
+        for i in 0..5 {
+            for j in 0..i {
+                assert_ne!(key_pairs[i].vk, key_pairs[j].vk);
+            }
+        }
+        let other_seed = P::BaseField::rand(&mut rng);
+        assert_ne!(
+            key_pairs[0].vk,
+            KeyPair::<P>::generate_from_seed(&other_seed, 0).vk
+        );
+        assert!(KeyPair::<P>::generate_batch(&seed, 0).is_empty());
+    }
+
+    #[test]
+    fn test_deterministic_nonce() {
+        test_deterministic_nonce_helper::<Param254>();
+        test_deterministic_nonce_helper::<Param377>();
+        test_deterministic_nonce_helper::<Param381>();
+        test_deterministic_nonce_helper::<Param381b>();
+    }
+
+    fn test_deterministic_nonce_helper<P>()
+    where
+        P: Config + Clone,
+        P::BaseField: RescueParameter,
+    {
+        let mut rng = jf_utils::test_rng();
+        let (sk, vk) = SchnorrSignatureScheme::<P>::key_gen(&(), &mut rng).unwrap();
+        let msg = vec![P::BaseField::from(5u8)];
+
+        // deterministic signatures only depend on the key and the message
+        let sig = SchnorrSignatureScheme::<P>::sign_deterministic(&sk, &msg);
+        assert_eq!(
+            sig,
+            SchnorrSignatureScheme::<P>::sign_deterministic(&sk, &msg)
+        );
+        assert!(SchnorrSignatureScheme::<P>::verify(&(), &vk, &msg, &sig).is_ok());
+        let other_sig =
+            SchnorrSignatureScheme::<P>::sign_deterministic(&sk, [P::BaseField::from(6u8)]);
+        assert_ne!(sig.R.into_affine(), other_sig.R.into_affine());
+
+        // randomized signatures are the default
+        let sig1 = SchnorrSignatureScheme::<P>::sign(&(), &sk, &msg, &mut rng).unwrap();
+        let sig2 = SchnorrSignatureScheme::<P>::sign(&(), &sk, &msg, &mut rng).unwrap();
+        assert_ne!(sig1, sig2);
+        assert_ne!(sig1, sig);
+        assert!(SchnorrSignatureScheme::<P>::verify(&(), &vk, &msg, &sig1).is_ok());
+        assert!(SchnorrSignatureScheme::<P>::verify(&(), &vk, &msg, &sig2).is_ok());
+
+        // the deterministic nonce of `[randomness] ++ msg` differs from the
+        // randomized nonce of `msg`, whatever the message prefix
+        let keypair = KeyPair::<P>::from(sk);
+        let randomness = P::BaseField::rand(&mut rng);
+        let randomized_input =
+            keypair.nonce_input(&msg, CS_ID_SCHNORR.as_bytes(), Some(randomness));
+        for prefix in [
+            vec![randomness],
+            vec![P::BaseField::one(), randomness],
+            vec![P::BaseField::zero(), P::BaseField::one(), randomness],
+        ] {
+            let det_msg = [prefix, msg.clone()].concat();
+            let det_input = keypair.nonce_input(&det_msg, CS_ID_SCHNORR.as_bytes(), None);
+            assert_ne!(det_input, randomized_input);
+            assert_ne!(det_input[..3], randomized_input[..3]);
+        }
+    }
+
+    #[test]
+    fn test_batch_verify() {
+        test_batch_verify_helper::<Param254>();
+        test_batch_verify_helper::<Param377>();
+        test_batch_verify_helper::<Param381>();
+        test_batch_verify_helper::<Param381b>();
+    }
+
+    fn test_batch_verify_helper<P>()
+    where
+        P: Config + Clone,
+        P::BaseField: RescueParameter,
+    {
+        let mut rng = jf_utils::test_rng();
+        let mut items = vec![];
+        for i in 0..10u64 {
+            let keypair = KeyPair::<P>::generate(&mut rng);
+            let msg = vec![P::BaseField::from(i); i as usize + 1];
+            let sig = keypair.sign(&msg, CS_ID_SCHNORR);
+            items.push((keypair.ver_key(), msg, sig));
+        }
+        assert!(SchnorrSignatureScheme::<P>::batch_verify(&items, &mut rng).is_ok());
+        assert!(
+            SchnorrSignatureScheme::<P>::batch_verify::<_, Vec<P::BaseField>>(&[], &mut rng)
+                .is_ok()
+        );
+
+        // wrong message
+        let mut bad_items = items.clone();
+        bad_items[3].1[0] += P::BaseField::from(1u8);
+        assert!(SchnorrSignatureScheme::<P>::batch_verify(&bad_items, &mut rng).is_err());
+        // wrong verification key
+        let mut bad_items = items.clone();
+        bad_items[5].0 = KeyPair::<P>::generate(&mut rng).ver_key();
+        assert!(SchnorrSignatureScheme::<P>::batch_verify(&bad_items, &mut rng).is_err());
+        // signatures swapped between two items
+        let mut bad_items = items.clone();
+        let sig = bad_items[0].2.clone();
+        bad_items[0].2 = bad_items[1].2.clone();
+        bad_items[1].2 = sig;
+        assert!(SchnorrSignatureScheme::<P>::batch_verify(&bad_items, &mut rng).is_err());
+        // small-order verification key
+        let mut bad_items = items.clone();
+        bad_items[0].0 = VerKey(Projective::<P>::zero());
+        assert!(SchnorrSignatureScheme::<P>::batch_verify(&bad_items, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_half_aggregation() {
+        test_half_aggregation_helper::<Param254>();
+        test_half_aggregation_helper::<Param377>();
+        test_half_aggregation_helper::<Param381>();
+        test_half_aggregation_helper::<Param381b>();
+    }
+
+    fn test_half_aggregation_helper<P>()
+    where
+        P: Config + Clone,
+        P::BaseField: RescueParameter,
+    {
+        let mut rng = jf_utils::test_rng();
+        let mut items = vec![];
+        for i in 0..10u64 {
+            let keypair = KeyPair::<P>::generate(&mut rng);
+            let msg = vec![P::BaseField::from(i); i as usize + 1];
+            let sig = keypair.sign(&msg, CS_ID_SCHNORR);
+            items.push((keypair.ver_key(), msg, sig));
+        }
+        let keys_msgs: Vec<_> = items
+            .iter()
+            .map(|(vk, msg, _)| (vk.clone(), msg.clone()))
+            .collect();
+        let agg_sig = SchnorrSignatureScheme::<P>::half_aggregate(&items).unwrap();
+        assert_eq!(agg_sig.len(), items.len());
+        assert!(SchnorrSignatureScheme::<P>::half_aggregate_verify(&keys_msgs, &agg_sig).is_ok());
+
+        // smaller than the signatures
+        let sigs: Vec<_> = items.iter().map(|(_, _, sig)| sig.clone()).collect();
+        assert!(agg_sig.compressed_size() < sigs.compressed_size());
+
+        // a single signature
+        let single = SchnorrSignatureScheme::<P>::half_aggregate(&items[..1]).unwrap();
+        assert!(
+            SchnorrSignatureScheme::<P>::half_aggregate_verify(&keys_msgs[..1], &single).is_ok()
+        );
+
+        // wrong message
+        let mut bad_keys_msgs = keys_msgs.clone();
+        bad_keys_msgs[3].1[0] += P::BaseField::from(1u8);
+        assert!(
+            SchnorrSignatureScheme::<P>::half_aggregate_verify(&bad_keys_msgs, &agg_sig).is_err()
+        );
+        // wrong verification key
+        let mut bad_keys_msgs = keys_msgs.clone();
+        bad_keys_msgs[5].0 = KeyPair::<P>::generate(&mut rng).ver_key();
+        assert!(
+            SchnorrSignatureScheme::<P>::half_aggregate_verify(&bad_keys_msgs, &agg_sig).is_err()
+        );
+        // wrong order
+        let mut bad_keys_msgs = keys_msgs.clone();
+        bad_keys_msgs.swap(0, 1);
+        assert!(
+            SchnorrSignatureScheme::<P>::half_aggregate_verify(&bad_keys_msgs, &agg_sig).is_err()
+        );
+        // missing signature
+        assert!(
+            SchnorrSignatureScheme::<P>::half_aggregate_verify(&keys_msgs[1..], &agg_sig).is_err()
+        );
+        // invalid signature in the aggregate
+        let mut bad_items = items.clone();
+        bad_items[2].2.s += P::ScalarField::from(1u8);
+        let bad_agg_sig = SchnorrSignatureScheme::<P>::half_aggregate(&bad_items).unwrap();
+        assert!(
+            SchnorrSignatureScheme::<P>::half_aggregate_verify(&keys_msgs, &bad_agg_sig).is_err()
+        );
+        // small-order verification key
+        let mut bad_keys_msgs = keys_msgs.clone();
+        bad_keys_msgs[0].0 = VerKey(Projective::<P>::zero());
+        assert!(
+            SchnorrSignatureScheme::<P>::half_aggregate_verify(&bad_keys_msgs, &agg_sig).is_err()
+        );
+        // empty inputs
+        assert!(SchnorrSignatureScheme::<P>::half_aggregate::<Vec<P::BaseField>>(&[]).is_err());
+    }
+
     mod serde {
         use super::super::{KeyPair, SignKey, Signature, VerKey};
         use crate::constants::CS_ID_SCHNORR;
@@ -0,0 +1,390 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! This module implements threshold BLS signatures over the BN254 curve,
+//! compatible with [`BLSOverBN254CurveSignatureScheme`].
+//!
+//! A committee of `n` parties jointly generates a key pair with Pedersen's
+//! distributed key generation (DKG), i.e. a joint run of Feldman's verifiable
+//! secret sharing, so that no party ever learns the signing key:
+//!
+//! **Deal**
+//!    * each party `i` samples a random polynomial `f_i` of degree `t-1`,
+//!      broadcasts the commitment `(g2^{a_{i,0}}, ..., g2^{a_{i,t-1}})` to its
+//!      coefficients and privately sends the share `f_i(j)` to party `j`.
+//!
+//! **Combine shares**
+//!    * party `j` checks every share it received against the dealer's
+//!      commitment, then its key share is the sum of the shares, i.e. `f(j)`
+//!      where `f = sum_i f_i`. The group verification key is `g2^{f(0)}`.
+//!
+//! Any `t` of the parties can then sign a message: each one computes a
+//! partial signature with its key share, which can be checked against its
+//! verification key share `g2^{f(j)}`, and `t` partial signatures are combined
+//! by Lagrange interpolation in the exponent into a plain BLS signature of the
+//! group verification key.
+
+use crate::{
+    bls_over_bn254::{BLSOverBN254CurveSignatureScheme, KeyPair, SignKey, Signature, VerKey},
+    constants::CS_ID_BLS_BN254,
+    SignatureError::{self, ParameterError, VerificationError},
+    SignatureScheme,
+};
+use ark_bn254::{Fr as ScalarField, G1Projective, G2Projective};
+use ark_ec::Group;
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{
+    format,
+    rand::{CryptoRng, RngCore},
+    string::ToString,
+    vec::Vec,
+    UniformRand, Zero,
+};
+use derivative::Derivative;
+use zeroize::Zeroize;
+
+/// Broadcast message of a dealer in the DKG: Feldman commitments to the
+/// coefficients of its secret polynomial.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DealerCommitment(pub(crate) Vec<G2Projective>);
+
+/// Private message of a dealer to a party in the DKG: the evaluation of the
+/// dealer's secret polynomial at the index of the party.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Clone, Zeroize, Derivative)]
+#[zeroize(drop)]
+#[derivative(Debug)]
+pub struct SecretShare {
+    /// Index of the receiving party, in `[1, n]`
+    pub index: u32,
+    #[derivative(Debug = "ignore")]
+    value: ScalarField,
+}
+
+/// Signature of a single party on a message, computed with its key share.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PartialSignature {
+    /// Index of the signing party, in `[1, n]`
+    pub index: u32,
+    /// Signature under the key share of the party
+    pub sig: Signature,
+}
+
+/// Threshold BLS signature scheme where any `threshold` out of `num_parties`
+/// parties can sign.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThresholdBLSOverBN254 {
+    threshold: usize,
+    num_parties: usize,
+}
+
+impl ThresholdBLSOverBN254 {
+    /// Create a `threshold`-out-of-`num_parties` scheme. Return an error
+    /// unless `1 <= threshold <= num_parties`.
+    pub fn new(threshold: usize, num_parties: usize) -> Result<Self, SignatureError> {
+        if threshold == 0 || threshold > num_parties || num_parties > u32::MAX as usize {
+            return Err(ParameterError(format!(
+                "invalid threshold {threshold} for {num_parties} parties"
+            )));
+        }
+        Ok(Self {
+            threshold,
+            num_parties,
+        })
+    }
+
+    /// Sample a secret polynomial and return the commitment to broadcast
+    /// along with the shares to send to parties `1..=n`, in order.
+    pub fn deal<R: CryptoRng + RngCore>(
+        &self,
+        prng: &mut R,
+    ) -> (DealerCommitment, Vec<SecretShare>) {
+        let coeffs: Vec<ScalarField> = (0..self.threshold)
+            .map(|_| ScalarField::rand(prng))
+            .collect();
+        let commitment = DealerCommitment(
+            coeffs
+                .iter()
+                .map(|c| G2Projective::generator() * c)
+                .collect(),
+        );
+        let shares = (1..=self.num_parties as u32)
+            .map(|index| {
+                // Horner evaluation of the polynomial at `index`
+                let x = ScalarField::from(index);
+                let value = coeffs
+                    .iter()
+                    .rev()
+                    .fold(ScalarField::zero(), |acc, c| acc * x + c);
+                SecretShare { index, value }
+            })
+            .collect();
+        (commitment, shares)
+    }
+
+    /// Check that `share` is consistent with the dealer's `commitment`.
+    pub fn verify_share(
+        &self,
+        commitment: &DealerCommitment,
+        share: &SecretShare,
+    ) -> Result<(), SignatureError> {
+        self.check_commitment(commitment)?;
+        self.check_index(share.index)?;
+        if G2Projective::generator() * share.value != commitment.eval(share.index) {
+            return Err(VerificationError(format!(
+                "secret share of party {} doesn't match the dealer's commitment",
+                share.index
+            )));
+        }
+        Ok(())
+    }
+
+    /// Combine the shares received by party `index`, one per dealer, into its
+    /// key share after checking each of them with [`Self::verify_share`].
+    pub fn combine_shares(
+        &self,
+        index: u32,
+        dealings: &[(DealerCommitment, SecretShare)],
+    ) -> Result<SignKey, SignatureError> {
+        if dealings.is_empty() {
+            return Err(ParameterError("no secret shares to combine".to_string()));
+        }
+        let mut value = ScalarField::zero();
+        for (commitment, share) in dealings.iter() {
+            if share.index != index {
+                return Err(ParameterError(format!(
+                    "secret share for party {} given to party {}",
+                    share.index, index
+                )));
+            }
+            self.verify_share(commitment, share)?;
+            value += share.value;
+        }
+        Ok(SignKey(value))
+    }
+
+    /// Compute the group verification key from the commitments of all the
+    /// dealers.
+    pub fn group_ver_key(
+        &self,
+        commitments: &[DealerCommitment],
+    ) -> Result<VerKey, SignatureError> {
+        self.ver_key_share(commitments, 0)
+    }
+
+    /// Compute the verification key share of party `index` from the
+    /// commitments of all the dealers, against which its partial signatures
+    /// are checked. Index `0` yields the group verification key.
+    pub fn ver_key_share(
+        &self,
+        commitments: &[DealerCommitment],
+        index: u32,
+    ) -> Result<VerKey, SignatureError> {
+        if commitments.is_empty() {
+            return Err(ParameterError("no dealer commitments".to_string()));
+        }
+        let mut point = G2Projective::zero();
+        for commitment in commitments.iter() {
+            self.check_commitment(commitment)?;
+            point += commitment.eval(index);
+        }
+        Ok(VerKey(point))
+    }
+
+    /// Sign `msg` as party `index` with its key share `sk`.
+    pub fn partial_sign(&self, index: u32, sk: &SignKey, msg: &[u8]) -> PartialSignature {
+        let kp = KeyPair::from(sk.clone());
+        PartialSignature {
+            index,
+            sig: kp.sign(msg, CS_ID_BLS_BN254),
+        }
+    }
+
+    /// Check a partial signature on `msg` against the verification key share
+    /// of its signer, see [`Self::ver_key_share`].
+    pub fn partial_verify(
+        &self,
+        vk: &VerKey,
+        msg: &[u8],
+        partial_sig: &PartialSignature,
+    ) -> Result<(), SignatureError> {
+        self.check_index(partial_sig.index)?;
+        BLSOverBN254CurveSignatureScheme::verify(&(), vk, msg, &partial_sig.sig)
+    }
+
+    /// Combine partial signatures of at least `threshold` distinct parties
+    /// into a signature under the group verification key, verifiable with
+    /// [`BLSOverBN254CurveSignatureScheme::verify`]. Only the first
+    /// `threshold` partial signatures are used; they should have been checked
+    /// with [`Self::partial_verify`].
+    pub fn combine(&self, partial_sigs: &[PartialSignature]) -> Result<Signature, SignatureError> {
+        if partial_sigs.len() < self.threshold {
+            return Err(ParameterError(format!(
+                "{} partial signatures, expecting at least {}",
+                partial_sigs.len(),
+                self.threshold
+            )));
+        }
+        let partial_sigs = &partial_sigs[..self.threshold];
+        for (i, partial_sig) in partial_sigs.iter().enumerate() {
+            self.check_index(partial_sig.index)?;
+            if partial_sigs[..i]
+                .iter()
+                .any(|other| other.index == partial_sig.index)
+            {
+                return Err(ParameterError(format!(
+                    "duplicated partial signature of party {}",
+                    partial_sig.index
+                )));
+            }
+        }
+
+        // Lagrange interpolation at 0 in the exponent
+        let mut sigma = G1Projective::zero();
+        for partial_sig in partial_sigs.iter() {
+            let x_i = ScalarField::from(partial_sig.index);
+            let mut lambda = ScalarField::from(1u32);
+            for other in partial_sigs.iter() {
+                if other.index != partial_sig.index {
+                    let x_j = ScalarField::from(other.index);
+                    // safe unwrap: indices are distinct
+                    lambda *= x_j * (x_j - x_i).inverse().unwrap();
+                }
+            }
+            sigma += partial_sig.sig.sigma * lambda;
+        }
+        Ok(Signature { sigma })
+    }
+
+    fn check_index(&self, index: u32) -> Result<(), SignatureError> {
+        if index == 0 || index as usize > self.num_parties {
+            return Err(ParameterError(format!(
+                "party index {index} out of range [1, {}]",
+                self.num_parties
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_commitment(&self, commitment: &DealerCommitment) -> Result<(), SignatureError> {
+        if commitment.0.len() != self.threshold {
+            return Err(ParameterError(format!(
+                "dealer commitment of length {}, expecting {}",
+                commitment.0.len(),
+                self.threshold
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl DealerCommitment {
+    /// Evaluate the committed polynomial at `index` in the exponent.
+    fn eval(&self, index: u32) -> G2Projective {
+        let x = ScalarField::from(index);
+        self.0
+            .iter()
+            .rev()
+            .fold(G2Projective::zero(), |acc, c| acc * x + c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_std::vec;
+
+    #[test]
+    fn test_threshold_bls() {
+        test_threshold_bls_helper(1, 1);
+        test_threshold_bls_helper(1, 3);
+        test_threshold_bls_helper(2, 3);
+        test_threshold_bls_helper(3, 5);
+        test_threshold_bls_helper(5, 5);
+    }
+
+    fn test_threshold_bls_helper(threshold: usize, num_parties: usize) {
+        let mut rng = jf_utils::test_rng();
+        let scheme = ThresholdBLSOverBN254::new(threshold, num_parties).unwrap();
+
+        // DKG
+        let dealings: Vec<_> = (0..num_parties).map(|_| scheme.deal(&mut rng)).collect();
+        let commitments: Vec<DealerCommitment> = dealings.iter().map(|(c, _)| c.clone()).collect();
+        let group_vk = scheme.group_ver_key(&commitments).unwrap();
+        let mut sks = vec![];
+        let mut vks = vec![];
+        for j in 1..=num_parties as u32 {
+            let received: Vec<_> = dealings
+                .iter()
+                .map(|(c, shares)| (c.clone(), shares[j as usize - 1].clone()))
+                .collect();
+            let sk = scheme.combine_shares(j, &received).unwrap();
+            let vk = scheme.ver_key_share(&commitments, j).unwrap();
+            assert_eq!(VerKey::from(&sk), vk);
+            sks.push(sk);
+            vks.push(vk);
+        }
+
+        // threshold signing
+        let msg = b"threshold message";
+        let partial_sigs: Vec<_> = sks
+            .iter()
+            .enumerate()
+            .map(|(i, sk)| scheme.partial_sign(i as u32 + 1, sk, msg))
+            .collect();
+        for (vk, partial_sig) in vks.iter().zip(partial_sigs.iter()) {
+            assert!(scheme.partial_verify(vk, msg, partial_sig).is_ok());
+            assert!(scheme.partial_verify(vk, b"wrong", partial_sig).is_err());
+        }
+        // any subset of `threshold` parties yields the same signature
+        let sig = scheme.combine(&partial_sigs).unwrap();
+        assert!(BLSOverBN254CurveSignatureScheme::verify(&(), &group_vk, msg, &sig).is_ok());
+        let sig_rev = scheme
+            .combine(&partial_sigs.iter().rev().cloned().collect::<Vec<_>>())
+            .unwrap();
+        assert_eq!(sig, sig_rev);
+
+        // not enough partial signatures
+        assert!(scheme.combine(&partial_sigs[..threshold - 1]).is_err());
+        if threshold > 1 {
+            // duplicated partial signatures
+            let mut dup = partial_sigs[..threshold].to_vec();
+            dup[1] = dup[0].clone();
+            assert!(scheme.combine(&dup).is_err());
+        }
+    }
+
+    #[test]
+    fn test_dkg_errors() {
+        let mut rng = jf_utils::test_rng();
+        assert!(ThresholdBLSOverBN254::new(0, 3).is_err());
+        assert!(ThresholdBLSOverBN254::new(4, 3).is_err());
+
+        let scheme = ThresholdBLSOverBN254::new(2, 3).unwrap();
+        let (commitment, shares) = scheme.deal(&mut rng);
+        let (other_commitment, _) = scheme.deal(&mut rng);
+        assert!(scheme.verify_share(&commitment, &shares[0]).is_ok());
+        // share checked against another dealer's commitment
+        assert!(scheme.verify_share(&other_commitment, &shares[0]).is_err());
+        // share tampered with
+        let mut bad_share = shares[0].clone();
+        bad_share.value += ScalarField::from(1u32);
+        assert!(scheme.verify_share(&commitment, &bad_share).is_err());
+        // share for another party
+        assert!(scheme
+            .combine_shares(2, &[(commitment.clone(), shares[0].clone())])
+            .is_err());
+        // commitment of the wrong length
+        let (short_commitment, short_shares) =
+            ThresholdBLSOverBN254::new(1, 3).unwrap().deal(&mut rng);
+        assert!(scheme
+            .verify_share(&short_commitment, &short_shares[0])
+            .is_err());
+        // out of range index
+        let partial_sig = scheme.partial_sign(4, &SignKey(ScalarField::from(1u32)), b"msg");
+        assert!(scheme.combine(&[partial_sig.clone(), partial_sig]).is_err());
+    }
+}
@@ -8,7 +8,8 @@
 use super::Vrf;
 use ark_std::{
     boxed::Box,
-    rand::{CryptoRng, RngCore},
+    format,
+    rand::{rngs::StdRng, CryptoRng, RngCore, SeedableRng},
     string::{String, ToString},
     vec::Vec,
 };
@@ -16,10 +17,17 @@ use digest::{Digest, DynDigest};
 use displaydoc::Display;
 use jf_signature::{
     bls_over_bls12381::{BLSSignKey, BLSSignature, BLSSignatureScheme, BLSVerKey},
-    SignatureScheme,
+    ContextSignatureScheme, SignatureScheme,
 };
+use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Sha512};
 
+/// Salt of the deterministic key generation from a seed.
+const KEYGEN_SALT: &[u8] = b"JF_BLS_VRF_KEYGEN";
+/// Signature context of the key rotation statements, separating them from the
+/// VRF proofs.
+const ROTATION_CTX: &[u8] = b"JF_BLS_VRF_KEY_ROTATION";
+
 /// Supported Cipher Suites for BLS VRF.
 #[allow(non_camel_case_types)]
 #[derive(Debug)]
@@ -55,6 +63,145 @@ impl BLSVRFScheme {
 pub struct BLSVRFError(String);
 impl ark_std::error::Error for BLSVRFError {}
 
+impl BLSVRFScheme {
+    /// Deterministically derive the key pair of generation `generation` from
+    /// `seed`, with the key generation of the [IRTF draft v5][v5]. The seed
+    /// MUST be infeasible to guess and at least 32 bytes long.
+    ///
+    /// The keys of successive generations are independent, so that a beacon
+    /// holding a single seed rotates its keys with [`Self::rotate_key`].
+    ///
+    /// [v5]: https://datatracker.ietf.org/doc/pdf/draft-irtf-cfrg-bls-signature-05
+    pub fn key_gen_from_seed(
+        seed: &[u8],
+        generation: u32,
+    ) -> Result<(BLSSignKey, BLSVerKey), BLSVRFError> {
+        BLSSignatureScheme::key_gen_v5(seed, KEYGEN_SALT, &generation.to_be_bytes())
+            .map_err(|e| BLSVRFError(e.to_string()))
+    }
+
+    /// Create the statement rotating the VRF key from `old_sk` to `new_sk` as
+    /// of `epoch`, signed by both keys: the old key authorizes the rotation
+    /// and the new key proves its possession.
+    pub fn rotate_key<R: CryptoRng + RngCore>(
+        epoch: u64,
+        old_sk: &BLSSignKey,
+        new_sk: &BLSSignKey,
+        prng: &mut R,
+    ) -> Result<BLSVRFKeyRotation, BLSVRFError> {
+        let old_key = BLSVerKey::from(old_sk);
+        let new_key = BLSVerKey::from(new_sk);
+        let msg = rotation_message(epoch, &old_key, &new_key);
+        let sign = |sk, prng: &mut R| {
+            BLSSignatureScheme::sign_with_context(&(), sk, ROTATION_CTX, &msg, prng)
+                .map_err(|e| BLSVRFError(e.to_string()))
+        };
+        Ok(BLSVRFKeyRotation {
+            epoch,
+            old_key,
+            new_key,
+            old_key_sig: sign(old_sk, prng)?,
+            new_key_sig: sign(new_sk, prng)?,
+        })
+    }
+
+    /// Verify the continuity of a chain of key rotations starting from
+    /// `initial_key`: each rotation is valid, starts from the key of the
+    /// previous one and has a larger epoch. Return the current key.
+    pub fn verify_key_rotations(
+        initial_key: &BLSVerKey,
+        rotations: &[BLSVRFKeyRotation],
+    ) -> Result<BLSVerKey, BLSVRFError> {
+        let mut key = *initial_key;
+        let mut last_epoch = None;
+        for (i, rotation) in rotations.iter().enumerate() {
+            if rotation.old_key != key {
+                return Err(BLSVRFError(format!(
+                    "rotation {i} does not start from the current key"
+                )));
+            }
+            if matches!(last_epoch, Some(epoch) if rotation.epoch <= epoch) {
+                return Err(BLSVRFError(format!(
+                    "rotation {i} at epoch {} does not follow the previous one",
+                    rotation.epoch
+                )));
+            }
+            rotation.verify()?;
+            key = rotation.new_key;
+            last_epoch = Some(rotation.epoch);
+        }
+        Ok(key)
+    }
+
+    /// Aggregate the proofs of a single key on pairwise distinct inputs into
+    /// one proof of the size of a single proof, checked with
+    /// [`Self::verify_aggregate`].
+    ///
+    /// The aggregate shows that the key evaluated the VRF on every input, but
+    /// the VRF outputs, which are hashes of the individual proofs, can't be
+    /// recovered from it: the individual proofs of the outputs that matter,
+    /// e.g. of the slots a node leads, still have to be published.
+    pub fn aggregate_proofs(proofs: &[BLSSignature]) -> Result<BLSSignature, BLSVRFError> {
+        BLSSignatureScheme::aggregate(proofs).map_err(|e| BLSVRFError(e.to_string()))
+    }
+
+    /// Verify an aggregate of the proofs of `public_key` on `inputs`, which
+    /// must be pairwise distinct.
+    pub fn verify_aggregate(
+        public_key: &BLSVerKey,
+        inputs: &[Vec<u8>],
+        aggregate_proof: &BLSSignature,
+    ) -> Result<(), BLSVRFError> {
+        let pairs: Vec<_> = inputs.iter().map(|input| (*public_key, input)).collect();
+        BLSSignatureScheme::aggregate_verify(&pairs, aggregate_proof)
+            .map_err(|e| BLSVRFError(e.to_string()))
+    }
+}
+
+/// A statement that the VRF key `old_key` is replaced by `new_key` as of
+/// `epoch`, signed by both keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BLSVRFKeyRotation {
+    /// The epoch from which the new key is used.
+    pub epoch: u64,
+    /// The key being replaced.
+    pub old_key: BLSVerKey,
+    /// The replacing key.
+    pub new_key: BLSVerKey,
+    /// Signature of the statement under the old key.
+    pub old_key_sig: BLSSignature,
+    /// Signature of the statement under the new key.
+    pub new_key_sig: BLSSignature,
+}
+
+impl BLSVRFKeyRotation {
+    /// Verify the signatures of both keys on the statement.
+    pub fn verify(&self) -> Result<(), BLSVRFError> {
+        if self.old_key == self.new_key {
+            return Err(BLSVRFError("rotation to the same key".to_string()));
+        }
+        let msg = rotation_message(self.epoch, &self.old_key, &self.new_key);
+        for (key, sig) in [
+            (&self.old_key, &self.old_key_sig),
+            (&self.new_key, &self.new_key_sig),
+        ] {
+            BLSSignatureScheme::verify_with_context(&(), key, ROTATION_CTX, &msg, sig)
+                .map_err(|e| BLSVRFError(e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+// The message signed by both keys of a rotation.
+fn rotation_message(epoch: u64, old_key: &BLSVerKey, new_key: &BLSVerKey) -> Vec<u8> {
+    [
+        &epoch.to_be_bytes()[..],
+        &old_key.compress(),
+        &new_key.compress(),
+    ]
+    .concat()
+}
+
 impl Vrf for BLSVRFScheme {
     /// Public Parameter.
     /// For BLS signatures, we want to use default
@@ -137,6 +284,39 @@ impl Vrf for BLSVRFScheme {
             Ok((false, None))
         }
     }
+
+    /// Verifies a batch of VRF proofs with a single multi-pairing.
+    fn batch_verify(
+        &mut self,
+        pp: &Self::PublicParameter,
+        instances: &[(Self::PublicKey, Self::Input, Self::Proof)],
+    ) -> Result<(bool, Option<Vec<Self::Output>>), BLSVRFError> {
+        if instances.is_empty() {
+            return Ok((true, Some(Vec::new())));
+        }
+        // The weights of the proofs in the batch are derived from the whole
+        // batch, so that they are unpredictable to whoever crafts the proofs.
+        let mut hasher = Sha256::new();
+        for (public_key, input, proof) in instances {
+            Digest::update(&mut hasher, public_key.compress());
+            Digest::update(&mut hasher, (input.len() as u64).to_le_bytes());
+            Digest::update(&mut hasher, input);
+            Digest::update(&mut hasher, proof.compress());
+        }
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&Digest::finalize(hasher));
+        let mut prng = StdRng::from_seed(seed);
+
+        if BLSSignatureScheme::batch_verify(instances, &mut prng).is_ok() {
+            let outputs = instances
+                .iter()
+                .map(|(_, _, proof)| self.proof_to_hash(pp, proof))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok((true, Some(outputs)))
+        } else {
+            Ok((false, None))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -176,6 +356,150 @@ mod test {
         assert!(!is_correct);
     }
 
+    #[test]
+    fn test_bls_vrf_batch_verify() {
+        let rng = &mut test_rng();
+        let mut vrf = BLSVRFScheme::new(BLSVRFCipherSuite::VRF_BLS_12_381_SHA256);
+        let mut instances = Vec::new();
+        for _ in 0..8 {
+            let message = rng.gen::<[u8; 32]>().to_vec();
+            let (sk, pk) = vrf.key_gen(&(), rng).unwrap();
+            let proof = vrf.prove(&(), &sk, &message, rng).unwrap();
+            instances.push((pk, message, proof));
+        }
+
+        let (is_correct, outputs) = vrf.batch_verify(&(), &instances).unwrap();
+        assert!(is_correct);
+        for ((_, _, proof), output) in instances.iter().zip(outputs.unwrap()) {
+            assert_eq!(vrf.proof_to_hash(&(), proof).unwrap(), output);
+        }
+        assert_eq!(
+            vrf.batch_verify(&(), &[]).unwrap(),
+            (true, Some(Vec::new()))
+        );
+
+        // one bad input fails the whole batch
+        let mut bad_instances = instances.clone();
+        bad_instances[5].1.truncate(31);
+        assert_eq!(
+            vrf.batch_verify(&(), &bad_instances).unwrap(),
+            (false, None)
+        );
+        // as do two swapped proofs
+        let mut bad_instances = instances.clone();
+        bad_instances[2].2 = instances[3].2;
+        bad_instances[3].2 = instances[2].2;
+        assert_eq!(
+            vrf.batch_verify(&(), &bad_instances).unwrap(),
+            (false, None)
+        );
+    }
+
+    #[test]
+    fn test_key_gen_from_seed() {
+        let seed = [7u8; 32];
+        let (sk, pk) = BLSVRFScheme::key_gen_from_seed(&seed, 0).unwrap();
+        assert_eq!(BLSVerKey::from(&sk), pk);
+        assert_eq!(BLSVRFScheme::key_gen_from_seed(&seed, 0).unwrap(), (sk, pk));
+        assert_ne!(BLSVRFScheme::key_gen_from_seed(&seed, 1).unwrap().1, pk);
+        assert_ne!(
+            BLSVRFScheme::key_gen_from_seed(&[8u8; 32], 0).unwrap().1,
+            pk
+        );
+        // too short a seed
+        assert!(BLSVRFScheme::key_gen_from_seed(&seed[..31], 0).is_err());
+    }
+
+    #[test]
+    fn test_key_rotation() {
+        let rng = &mut test_rng();
+        let seed = [42u8; 32];
+        let keys: Vec<_> = (0..4)
+            .map(|generation| BLSVRFScheme::key_gen_from_seed(&seed, generation).unwrap())
+            .collect();
+        let rotations: Vec<_> = keys
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                BLSVRFScheme::rotate_key(10 * (i as u64 + 1), &pair[0].0, &pair[1].0, rng).unwrap()
+            })
+            .collect();
+        assert_eq!(
+            BLSVRFScheme::verify_key_rotations(&keys[0].1, &rotations).unwrap(),
+            keys[3].1
+        );
+        assert_eq!(
+            BLSVRFScheme::verify_key_rotations(&keys[0].1, &[]).unwrap(),
+            keys[0].1
+        );
+        // the rotated key evaluates the VRF
+        let mut vrf = BLSVRFScheme::new(BLSVRFCipherSuite::VRF_BLS_12_381_SHA256);
+        let input = b"round 31".to_vec();
+        let proof = vrf.prove(&(), &keys[3].0, &input, rng).unwrap();
+        assert!(vrf.verify(&(), &proof, &keys[3].1, &input).unwrap().0);
+
+        // broken continuity
+        assert!(BLSVRFScheme::verify_key_rotations(&keys[1].1, &rotations).is_err());
+        assert!(BLSVRFScheme::verify_key_rotations(&keys[0].1, &rotations[1..]).is_err());
+        let mut reordered = rotations.clone();
+        reordered[1].epoch = 5;
+        assert!(BLSVRFScheme::verify_key_rotations(&keys[0].1, &reordered).is_err());
+
+        // forged statements
+        let mut forged = rotations[0];
+        forged.new_key = keys[3].1;
+        assert!(forged.verify().is_err());
+        let mut forged = rotations[0];
+        forged.epoch += 1;
+        assert!(forged.verify().is_err());
+        let mut forged = rotations[0];
+        forged.new_key_sig = forged.old_key_sig;
+        assert!(forged.verify().is_err());
+        // the signatures are not VRF proofs
+        let msg = rotation_message(10, &keys[0].1, &keys[1].1);
+        assert!(
+            !vrf.verify(&(), &rotations[0].old_key_sig, &keys[0].1, &msg)
+                .unwrap()
+                .0
+        );
+        let same_key = BLSVRFScheme::rotate_key(50, &keys[0].0, &keys[0].0, rng).unwrap();
+        assert!(same_key.verify().is_err());
+    }
+
+    #[test]
+    fn test_bls_vrf_aggregate() {
+        let rng = &mut test_rng();
+        let vrf = BLSVRFScheme::new(BLSVRFCipherSuite::VRF_BLS_12_381_SHA256);
+        let (sk, pk) = vrf.key_gen(&(), rng).unwrap();
+        let inputs: Vec<_> = (0..8u64).map(|slot| slot.to_le_bytes().to_vec()).collect();
+        let proofs: Vec<_> = inputs
+            .iter()
+            .map(|input| vrf.prove(&(), &sk, input, rng).unwrap())
+            .collect();
+
+        let aggregate_proof = BLSVRFScheme::aggregate_proofs(&proofs).unwrap();
+        assert!(BLSVRFScheme::verify_aggregate(&pk, &inputs, &aggregate_proof).is_ok());
+        assert!(BLSVRFScheme::aggregate_proofs(&[]).is_err());
+        assert!(BLSVRFScheme::verify_aggregate(&pk, &[], &aggregate_proof).is_err());
+
+        // missing, wrong or repeated inputs
+        assert!(BLSVRFScheme::verify_aggregate(&pk, &inputs[1..], &aggregate_proof).is_err());
+        let mut bad_inputs = inputs.clone();
+        bad_inputs[3] = 100u64.to_le_bytes().to_vec();
+        assert!(BLSVRFScheme::verify_aggregate(&pk, &bad_inputs, &aggregate_proof).is_err());
+        let repeated_proof = BLSVRFScheme::aggregate_proofs(&[proofs[0], proofs[0]]).unwrap();
+        assert!(BLSVRFScheme::verify_aggregate(
+            &pk,
+            &[inputs[0].clone(), inputs[0].clone()],
+            &repeated_proof
+        )
+        .is_err());
+
+        // another key
+        let (_, other_pk) = vrf.key_gen(&(), rng).unwrap();
+        assert!(BLSVRFScheme::verify_aggregate(&other_pk, &inputs, &aggregate_proof).is_err());
+    }
+
     #[test]
     fn test_bls_vrf() {
         let rng = &mut test_rng();
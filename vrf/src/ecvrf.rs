@@ -0,0 +1,467 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Elliptic curve VRF of [RFC 9381][rfc], with the ciphersuite
+//! `ECVRF-EDWARDS25519-SHA512-TAI`, so that proofs and outputs are
+//! interoperable with other implementations of the standard.
+//!
+//! Secret and public keys are those of Ed25519 ([RFC 8032][ed25519]):
+//! the secret key is a 32-byte string and the secret scalar is derived from
+//! its SHA-512 hash. Public keys are validated during verification, rejecting
+//! small-order points.
+//!
+//! RFC 9381 proofs carry the challenge rather than the commitments it is
+//! computed from, so they can't be checked with a single multi-scalar
+//! multiplication: [`Vrf::batch_verify`] verifies them one by one.
+//!
+//! [rfc]: https://datatracker.ietf.org/doc/html/rfc9381
+//! [ed25519]: https://datatracker.ietf.org/doc/html/rfc8032
+
+use super::Vrf;
+use ark_std::{
+    format,
+    rand::{CryptoRng, RngCore},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use curve25519_dalek::{
+    constants::ED25519_BASEPOINT_POINT,
+    edwards::{CompressedEdwardsY, EdwardsPoint},
+    scalar::{clamp_integer, Scalar},
+};
+use displaydoc::Display;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Identifier of the `ECVRF-EDWARDS25519-SHA512-TAI` ciphersuite.
+const SUITE_STRING: u8 = 0x03;
+/// Size in bytes of an encoded point.
+const PT_LEN: usize = 32;
+/// Size in bytes of an encoded challenge.
+const C_LEN: usize = 16;
+/// Size in bytes of an encoded scalar.
+const Q_LEN: usize = 32;
+/// Size in bytes of an encoded proof.
+pub const ECVRF_PROOF_SIZE: usize = PT_LEN + C_LEN + Q_LEN;
+
+/// ECVRF secret key: an Ed25519 secret key.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EcVrfSecretKey([u8; 32]);
+
+impl fmt::Debug for EcVrfSecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EcVrfSecretKey").finish_non_exhaustive()
+    }
+}
+
+impl Zeroize for EcVrfSecretKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for EcVrfSecretKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for EcVrfSecretKey {}
+
+impl EcVrfSecretKey {
+    /// Create a secret key from its 32-byte encoding.
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// The 32-byte encoding of the secret key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// The public key of this secret key.
+    pub fn public_key(&self) -> EcVrfPublicKey {
+        let (x, _) = self.expand();
+        EcVrfPublicKey((ED25519_BASEPOINT_POINT * x).compress().to_bytes())
+    }
+
+    // The secret scalar and the nonce generation prefix, as in RFC 8032.
+    fn expand(&self) -> (Scalar, [u8; 32]) {
+        let hash = Sha512::digest(self.0);
+        let mut scalar_bytes = [0u8; 32];
+        scalar_bytes.copy_from_slice(&hash[..32]);
+        let mut prefix = [0u8; 32];
+        prefix.copy_from_slice(&hash[32..]);
+        let x = Scalar::from_bytes_mod_order(clamp_integer(scalar_bytes));
+        scalar_bytes.zeroize();
+        (x, prefix)
+    }
+}
+
+/// ECVRF public key: an Ed25519 public key, i.e. a compressed point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EcVrfPublicKey([u8; 32]);
+
+impl EcVrfPublicKey {
+    /// Create a public key from its 32-byte encoding. The key is validated
+    /// when verifying proofs.
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// The 32-byte encoding of the public key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+}
+
+/// ECVRF proof.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EcVrfProof {
+    gamma: [u8; PT_LEN],
+    c: [u8; C_LEN],
+    s: [u8; Q_LEN],
+}
+
+impl EcVrfProof {
+    /// The encoding `Gamma || c || s` of the proof.
+    pub fn to_bytes(&self) -> [u8; ECVRF_PROOF_SIZE] {
+        let mut bytes = [0u8; ECVRF_PROOF_SIZE];
+        bytes[..PT_LEN].copy_from_slice(&self.gamma);
+        bytes[PT_LEN..PT_LEN + C_LEN].copy_from_slice(&self.c);
+        bytes[PT_LEN + C_LEN..].copy_from_slice(&self.s);
+        bytes
+    }
+
+    /// Decode a proof encoded with [`Self::to_bytes`]. The proof is
+    /// validated when verifying it.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EcVrfError> {
+        if bytes.len() != ECVRF_PROOF_SIZE {
+            return Err(EcVrfError(format!(
+                "proof of length {}, expecting {}",
+                bytes.len(),
+                ECVRF_PROOF_SIZE
+            )));
+        }
+        let mut proof = Self {
+            gamma: [0u8; PT_LEN],
+            c: [0u8; C_LEN],
+            s: [0u8; Q_LEN],
+        };
+        proof.gamma.copy_from_slice(&bytes[..PT_LEN]);
+        proof.c.copy_from_slice(&bytes[PT_LEN..PT_LEN + C_LEN]);
+        proof.s.copy_from_slice(&bytes[PT_LEN + C_LEN..]);
+        Ok(proof)
+    }
+}
+
+/// ECVRF Error: {0}
+#[derive(Debug, Display)]
+pub struct EcVrfError(String);
+impl ark_std::error::Error for EcVrfError {}
+
+/// ECVRF scheme with the `ECVRF-EDWARDS25519-SHA512-TAI` ciphersuite.
+#[derive(Clone, Debug, Default)]
+pub struct EcVrfEdwards25519;
+
+impl Vrf for EcVrfEdwards25519 {
+    /// The ciphersuite has no public parameters.
+    type PublicParameter = ();
+
+    /// VRF public key.
+    type PublicKey = EcVrfPublicKey;
+
+    /// VRF secret key.
+    type SecretKey = EcVrfSecretKey;
+
+    /// VRF proof.
+    type Proof = EcVrfProof;
+
+    /// The input of VRF proof.
+    type Input = Vec<u8>;
+
+    /// The output of VRF evaluation, of 64 bytes.
+    type Output = Vec<u8>;
+
+    /// VRF Error
+    type Error = EcVrfError;
+
+    /// generate public parameters from RNG.
+    fn param_gen<R: CryptoRng + RngCore>(
+        &self,
+        _prng: Option<&mut R>,
+    ) -> Result<Self::PublicParameter, EcVrfError> {
+        Ok(())
+    }
+
+    /// Creates a pair of VRF public and private keys.
+    fn key_gen<R: CryptoRng + RngCore>(
+        &self,
+        _pp: &Self::PublicParameter,
+        prng: &mut R,
+    ) -> Result<(Self::SecretKey, Self::PublicKey), EcVrfError> {
+        let mut sk = EcVrfSecretKey([0u8; 32]);
+        prng.fill_bytes(&mut sk.0);
+        let pk = sk.public_key();
+        Ok((sk, pk))
+    }
+
+    /// Creates the VRF proof associated with a VRF secret key, following
+    /// Section 5.1 of RFC 9381. Proving is deterministic, `prng` is unused.
+    #[allow(non_snake_case)]
+    fn prove<R: CryptoRng + RngCore>(
+        &self,
+        _pp: &Self::PublicParameter,
+        secret_key: &Self::SecretKey,
+        input: &Self::Input,
+        _prng: &mut R,
+    ) -> Result<Self::Proof, EcVrfError> {
+        let (mut x, mut prefix) = secret_key.expand();
+        let pk = secret_key.public_key();
+        let Y = ED25519_BASEPOINT_POINT * x;
+        let H = encode_to_curve(&pk.0, input)?;
+        let h_string = H.compress().to_bytes();
+        let Gamma = H * x;
+
+        // nonce generation of Section 5.4.2.2
+        let mut k = Scalar::from_hash(Sha512::new().chain_update(prefix).chain_update(h_string));
+        let c = challenge(&[&Y, &H, &Gamma, &(ED25519_BASEPOINT_POINT * k), &(H * k)]);
+        let s = k + c * x;
+        x.zeroize();
+        k.zeroize();
+        prefix.zeroize();
+
+        let mut c_bytes = [0u8; C_LEN];
+        c_bytes.copy_from_slice(&c.to_bytes()[..C_LEN]);
+        Ok(EcVrfProof {
+            gamma: Gamma.compress().to_bytes(),
+            c: c_bytes,
+            s: s.to_bytes(),
+        })
+    }
+
+    /// Computes the VRF output associated with a VRF proof, following Section
+    /// 5.2 of RFC 9381.
+    fn proof_to_hash(
+        &mut self,
+        _pp: &Self::PublicParameter,
+        proof: &Self::Proof,
+    ) -> Result<Self::Output, EcVrfError> {
+        let gamma = string_to_point(&proof.gamma)
+            .ok_or_else(|| EcVrfError("invalid point in the proof".to_string()))?;
+        Ok(gamma_to_hash(&gamma))
+    }
+
+    /// Verifies a VRF proof, following Section 5.3 of RFC 9381 with public
+    /// key validation.
+    #[allow(non_snake_case)]
+    fn verify(
+        &mut self,
+        _pp: &Self::PublicParameter,
+        proof: &Self::Proof,
+        public_key: &Self::PublicKey,
+        input: &Self::Input,
+    ) -> Result<(bool, Option<Self::Output>), EcVrfError> {
+        let Y = match string_to_point(&public_key.0) {
+            Some(Y) if !Y.is_small_order() => Y,
+            _ => return Ok((false, None)),
+        };
+        let Gamma = match string_to_point(&proof.gamma) {
+            Some(Gamma) => Gamma,
+            None => return Ok((false, None)),
+        };
+        let mut c_bytes = [0u8; 32];
+        c_bytes[..C_LEN].copy_from_slice(&proof.c);
+        let c = Scalar::from_bytes_mod_order(c_bytes);
+        let s = match Option::<Scalar>::from(Scalar::from_canonical_bytes(proof.s)) {
+            Some(s) => s,
+            None => return Ok((false, None)),
+        };
+
+        let H = encode_to_curve(&public_key.0, input)?;
+        let U = ED25519_BASEPOINT_POINT * s - Y * c;
+        let V = H * s - Gamma * c;
+        if challenge(&[&Y, &H, &Gamma, &U, &V]) == c {
+            Ok((true, Some(gamma_to_hash(&Gamma))))
+        } else {
+            Ok((false, None))
+        }
+    }
+}
+
+// Decode a point as in Section 5.1.3 of RFC 8032, rejecting non-canonical
+// encodings.
+fn string_to_point(bytes: &[u8; PT_LEN]) -> Option<EdwardsPoint> {
+    let point = CompressedEdwardsY(*bytes).decompress()?;
+    if point.compress().as_bytes() == bytes {
+        Some(point)
+    } else {
+        None
+    }
+}
+
+// `ECVRF_encode_to_curve_try_and_increment` of Section 5.4.1.1 of RFC 9381.
+fn encode_to_curve(pk_string: &[u8; PT_LEN], alpha: &[u8]) -> Result<EdwardsPoint, EcVrfError> {
+    for ctr in 0..=u8::MAX {
+        let hash = Sha512::new()
+            .chain_update([SUITE_STRING, 0x01])
+            .chain_update(pk_string)
+            .chain_update(alpha)
+            .chain_update([ctr, 0x00])
+            .finalize();
+        let mut candidate = [0u8; PT_LEN];
+        candidate.copy_from_slice(&hash[..PT_LEN]);
+        if let Some(point) = string_to_point(&candidate) {
+            return Ok(point.mul_by_cofactor());
+        }
+    }
+    Err(EcVrfError(
+        "failed to encode the input to the curve".to_string(),
+    ))
+}
+
+// `ECVRF_challenge_generation` of Section 5.4.3 of RFC 9381.
+fn challenge(points: &[&EdwardsPoint; 5]) -> Scalar {
+    let mut hasher = Sha512::new().chain_update([SUITE_STRING, 0x02]);
+    for point in points {
+        hasher.update(point.compress().as_bytes());
+    }
+    let hash = hasher.chain_update([0x00]).finalize();
+    let mut c_bytes = [0u8; 32];
+    c_bytes[..C_LEN].copy_from_slice(&hash[..C_LEN]);
+    Scalar::from_bytes_mod_order(c_bytes)
+}
+
+// `ECVRF_proof_to_hash` of Section 5.2 of RFC 9381, from the decoded `Gamma`.
+fn gamma_to_hash(gamma: &EdwardsPoint) -> Vec<u8> {
+    Sha512::new()
+        .chain_update([SUITE_STRING, 0x03])
+        .chain_update(gamma.mul_by_cofactor().compress().as_bytes())
+        .chain_update([0x00])
+        .finalize()
+        .to_vec()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_std::{rand::Rng, vec};
+    use jf_utils::test_rng;
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len() / 2)
+            .map(|i| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // Example 16 of Appendix B.3 of RFC 9381.
+    #[test]
+    fn test_rfc9381_vector() {
+        let mut vrf = EcVrfEdwards25519;
+        let mut sk_bytes = [0u8; 32];
+        sk_bytes.copy_from_slice(&from_hex(
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+        ));
+        let sk = EcVrfSecretKey::from_bytes(sk_bytes);
+        let pk = sk.public_key();
+        assert_eq!(
+            pk.to_bytes().to_vec(),
+            from_hex("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+        );
+
+        let alpha = vec![];
+        let proof = vrf.prove(&(), &sk, &alpha, &mut test_rng()).unwrap();
+        assert_eq!(
+            proof.to_bytes().to_vec(),
+            from_hex(
+                "8657106690b5526245a92b003bb079ccd1a92130477671f6fc01ad16f26f723f\
+                 26f8a57ccaed74ee1b190bed1f479d9727d2d0f9b005a6e456a35d4fb0daab12\
+                 68a1b0db10836d9826a528ca76567805"
+            )
+        );
+        let beta = from_hex(
+            "90cf1df3b703cce59e2a35b925d411164068269d7b2d29f3301c03dd757876ff\
+             66b71dda49d2de59d03450451af026798e8f81cd2e333de5cdf4f3e140fdd8ae",
+        );
+        assert_eq!(vrf.proof_to_hash(&(), &proof).unwrap(), beta);
+        assert_eq!(
+            vrf.verify(&(), &proof, &pk, &alpha).unwrap(),
+            (true, Some(beta))
+        );
+    }
+
+    #[test]
+    fn test_ecvrf() {
+        let rng = &mut test_rng();
+        let mut vrf = EcVrfEdwards25519;
+        for _ in 0..10 {
+            let message = rng.gen::<[u8; 32]>().to_vec();
+            let message_bad = message[..31].to_vec();
+
+            let (sk, pk) = vrf.key_gen(&(), rng).unwrap();
+            let proof = vrf.prove(&(), &sk, &message, rng).unwrap();
+            let output = vrf.proof_to_hash(&(), &proof).unwrap();
+            assert_eq!(output.len(), 64);
+            assert_eq!(vrf.evaluate(&(), &sk, &message, rng).unwrap(), output);
+            assert_eq!(
+                vrf.verify(&(), &proof, &pk, &message).unwrap(),
+                (true, Some(output))
+            );
+            assert_eq!(EcVrfProof::from_bytes(&proof.to_bytes()).unwrap(), proof);
+
+            // wrong input, key or proof
+            assert!(!vrf.verify(&(), &proof, &pk, &message_bad).unwrap().0);
+            let (_, pk_bad) = vrf.key_gen(&(), rng).unwrap();
+            assert!(!vrf.verify(&(), &proof, &pk_bad, &message).unwrap().0);
+            let mut bad_proof = proof.clone();
+            bad_proof.s[0] ^= 1;
+            assert!(!vrf.verify(&(), &bad_proof, &pk, &message).unwrap().0);
+            // non-canonical s
+            let mut bad_proof = proof.clone();
+            bad_proof.s[31] |= 0xf0;
+            assert!(!vrf.verify(&(), &bad_proof, &pk, &message).unwrap().0);
+        }
+
+        // batch verification
+        let instances: Vec<_> = (0..4u8)
+            .map(|i| {
+                let (sk, pk) = vrf.key_gen(&(), rng).unwrap();
+                let proof = vrf.prove(&(), &sk, &vec![i], rng).unwrap();
+                (pk, vec![i], proof)
+            })
+            .collect();
+        let (is_correct, outputs) = vrf.batch_verify(&(), &instances).unwrap();
+        assert!(is_correct);
+        assert_eq!(
+            outputs.unwrap()[2],
+            vrf.proof_to_hash(&(), &instances[2].2).unwrap()
+        );
+        let mut bad_instances = instances.clone();
+        bad_instances[1].1 = vec![0];
+        assert_eq!(
+            vrf.batch_verify(&(), &bad_instances).unwrap(),
+            (false, None)
+        );
+
+        // small-order public keys are rejected
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        let (sk, _) = vrf.key_gen(&(), rng).unwrap();
+        let proof = vrf.prove(&(), &sk, &vec![1u8], rng).unwrap();
+        assert!(
+            !vrf.verify(
+                &(),
+                &proof,
+                &EcVrfPublicKey::from_bytes(identity),
+                &vec![1u8]
+            )
+            .unwrap()
+            .0
+        );
+    }
+}
@@ -17,12 +17,21 @@ extern crate std;
 #[doc(hidden)]
 extern crate alloc;
 
-use ark_std::rand::{CryptoRng, RngCore};
+use ark_std::{
+    rand::{CryptoRng, RngCore},
+    vec::Vec,
+};
 use core::fmt::Debug;
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroize;
 
 pub mod blsvrf;
+#[cfg(any(test, feature = "ecvrf"))]
+pub mod ecvrf;
+pub mod randomness;
+#[cfg(feature = "ring")]
+pub mod ring;
+pub mod threshold;
 
 /// A trait for VRF proof, evaluation and verification.
 pub trait Vrf {
@@ -117,4 +126,26 @@ pub trait Vrf {
         public_key: &Self::PublicKey,
         input: &Self::Input,
     ) -> Result<(bool, Option<Self::Output>), Self::Error>;
+
+    /// Verifies a batch of VRF proofs, given as (public key, input, proof)
+    /// triples. Returns the outputs, in the order of the batch, if all the
+    /// proofs are valid.
+    ///
+    /// The default implementation verifies the proofs one by one; schemes
+    /// override it to batch the underlying group operations.
+    #[must_use = "Output must be used"]
+    fn batch_verify(
+        &mut self,
+        pp: &Self::PublicParameter,
+        instances: &[(Self::PublicKey, Self::Input, Self::Proof)],
+    ) -> Result<(bool, Option<Vec<Self::Output>>), Self::Error> {
+        let mut outputs = Vec::with_capacity(instances.len());
+        for (public_key, input, proof) in instances {
+            match self.verify(pp, proof, public_key, input)? {
+                (true, Some(output)) => outputs.push(output),
+                _ => return Ok((false, None)),
+            }
+        }
+        Ok((true, Some(outputs)))
+    }
 }
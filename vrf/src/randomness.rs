@@ -0,0 +1,280 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Mappings from VRF outputs to randomness.
+//!
+//! The helpers of this module apply to byte-string VRF outputs, such as those
+//! of [`crate::blsvrf`] and of the ECVRF of the `ecvrf` feature. Each helper
+//! expands the output with SHA-512 under its own domain separator, so that the
+//! values extracted from a single output by different helpers are independent,
+//! and is deterministic across platforms.
+
+use ark_std::{
+    format,
+    string::{String, ToString},
+};
+use displaydoc::Display;
+use sha2::{Digest, Sha512};
+
+/// Domain separator of [`output_to_u64`] and [`output_to_u128`].
+const UINT_TAG: &[u8] = b"JF_VRF_OUTPUT_UINT";
+/// Domain separator of [`output_to_range`].
+const RANGE_TAG: &[u8] = b"JF_VRF_OUTPUT_RANGE";
+/// Domain separator of [`sortition`].
+const SORTITION_TAG: &[u8] = b"JF_VRF_OUTPUT_SORTITION";
+
+/// Randomness Error: {0}
+#[derive(Debug, Display)]
+pub struct RandomnessError(String);
+impl ark_std::error::Error for RandomnessError {}
+
+/// A uniform `u64` derived from a VRF output.
+pub fn output_to_u64(output: &[u8]) -> u64 {
+    let block = expand(UINT_TAG, output, 0);
+    u64::from_le_bytes(block[..8].try_into().unwrap())
+}
+
+/// A uniform `u128` derived from a VRF output.
+pub fn output_to_u128(output: &[u8]) -> u128 {
+    let block = expand(UINT_TAG, output, 0);
+    u128::from_le_bytes(block[..16].try_into().unwrap())
+}
+
+/// A uniform integer in `[0, n)` derived from a VRF output, without modulo
+/// bias: 64-bit samples are drawn until one falls below the largest multiple
+/// of `n`, and reduced modulo `n`.
+pub fn output_to_range(output: &[u8], n: u64) -> Result<u64, RandomnessError> {
+    if n == 0 {
+        return Err(RandomnessError("empty range".to_string()));
+    }
+    let zone = u64::MAX - (u64::MAX - n + 1) % n;
+    for counter in 0.. {
+        let block = expand(RANGE_TAG, output, counter);
+        for sample in block.chunks_exact(8) {
+            let sample = u64::from_le_bytes(sample.try_into().unwrap());
+            if sample <= zone {
+                return Ok(sample % n);
+            }
+        }
+    }
+    unreachable!()
+}
+
+/// Cryptographic sortition of [Algorand][algorand]: the number of times a
+/// participant holding `stake` out of `total_stake` is selected, when
+/// `expected` selections are expected in total.
+///
+/// Each unit of stake is selected with probability `expected / total_stake`,
+/// so the participant is selected `j` times, for `j` distributed according to
+/// the binomial distribution `B(stake, expected / total_stake)`: the VRF
+/// output, mapped to a uniform `x` in `[0, 1)`, selects the `j` such that
+/// `P(B < j) <= x < P(B <= j)`. The participant is not selected if `j` is 0.
+///
+/// The computation takes time linear in the number of selections, so
+/// `expected` should be the size of a committee rather than an amount of
+/// stake.
+///
+/// [algorand]: https://eprint.iacr.org/2017/454
+pub fn sortition(
+    output: &[u8],
+    stake: u64,
+    total_stake: u64,
+    expected: u64,
+) -> Result<u64, RandomnessError> {
+    if stake > total_stake || expected > total_stake {
+        return Err(RandomnessError(format!(
+            "stake {stake} and expected selections {expected} out of a total stake of \
+             {total_stake}"
+        )));
+    }
+    if stake == 0 || expected == 0 {
+        return Ok(0);
+    }
+    if expected == total_stake {
+        return Ok(stake);
+    }
+
+    // uniform in [0, 1), with the 53 bits of precision of an f64
+    let block = expand(SORTITION_TAG, output, 0);
+    let sample = u64::from_le_bytes(block[..8].try_into().unwrap());
+    let x = (sample >> 11) as f64 / (1u64 << 53) as f64;
+
+    let p = expected as f64 / total_stake as f64;
+    let q = (total_stake - expected) as f64 / total_stake as f64;
+    let ratio = expected as f64 / (total_stake - expected) as f64;
+    let mean = stake as f64 * p;
+
+    // walk the binomial distribution from P(B = 0) = q^stake
+    let mut pmf = pow_one_minus(p, q, stake);
+    let mut cdf = pmf.to_f64();
+    let mut j = 0;
+    while x >= cdf && j < stake {
+        pmf = pmf.mul(Scaled::new((stake - j) as f64 / (j + 1) as f64 * ratio));
+        j += 1;
+        let next_cdf = cdf + pmf.to_f64();
+        // past the mean, the remaining mass is below the precision of `cdf`
+        if next_cdf == cdf && j as f64 > mean {
+            break;
+        }
+        cdf = next_cdf;
+    }
+    Ok(j)
+}
+
+// The 64-byte block `counter` of the expansion of `output` under `tag`.
+fn expand(tag: &[u8], output: &[u8], counter: u64) -> [u8; 64] {
+    let hash = Sha512::new()
+        .chain_update((tag.len() as u64).to_be_bytes())
+        .chain_update(tag)
+        .chain_update(output)
+        .chain_update(counter.to_be_bytes())
+        .finalize();
+    let mut block = [0u8; 64];
+    block.copy_from_slice(&hash);
+    block
+}
+
+// `(1 - p)^n`, given both `p` and `q = 1 - p`. While `1 - p^(2^i)` is close
+// to 1, its square is computed as `1 - p'` for `p' = p * (2 - p)` so that the
+// precision of a small `p` is not lost.
+fn pow_one_minus(mut p: f64, q: f64, mut n: u64) -> Scaled {
+    let mut result = Scaled::new(1.0);
+    let mut square = (p > 0.5).then(|| Scaled::new(q));
+    while n > 0 {
+        let factor = square.unwrap_or_else(|| Scaled::new(1.0 - p));
+        if n & 1 == 1 {
+            result = result.mul(factor);
+        }
+        square = match square {
+            Some(square) => Some(square.mul(square)),
+            None => {
+                p *= 2.0 - p;
+                (p > 0.5).then(|| Scaled::new(1.0 - p))
+            },
+        };
+        n >>= 1;
+    }
+    result
+}
+
+// A non-negative `m * 2^e`, with `m` in `[0.5, 1)` or 0, which does not
+// underflow as an f64 would.
+#[derive(Clone, Copy, Debug)]
+struct Scaled {
+    m: f64,
+    e: i64,
+}
+
+impl Scaled {
+    fn new(x: f64) -> Self {
+        Self { m: x, e: 0 }.normalize()
+    }
+
+    fn normalize(self) -> Self {
+        if self.m == 0.0 {
+            return self;
+        }
+        let bits = self.m.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i64 - 1022;
+        Self {
+            m: f64::from_bits((bits & !(0x7ff << 52)) | (1022 << 52)),
+            e: self.e + exponent,
+        }
+    }
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            m: self.m * other.m,
+            e: self.e + other.e,
+        }
+        .normalize()
+    }
+
+    fn to_f64(self) -> f64 {
+        if self.m == 0.0 || self.e < -1021 {
+            0.0
+        } else {
+            self.m * f64::from_bits(((self.e + 1023) as u64) << 52)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_std::{vec, vec::Vec};
+
+    fn outputs(n: u64) -> Vec<Vec<u8>> {
+        (0..n).map(|i| expand(b"test", &[], i).to_vec()).collect()
+    }
+
+    #[test]
+    fn test_output_to_uint() {
+        let output = expand(b"test", &[], 0);
+        assert_eq!(output_to_u64(&output), output_to_u128(&output) as u64);
+        assert_ne!(output_to_u64(&output), output_to_u64(&output[1..]));
+    }
+
+    #[test]
+    fn test_output_to_range() {
+        assert!(output_to_range(&[], 0).is_err());
+        assert_eq!(output_to_range(&[], 1).unwrap(), 0);
+        assert!(output_to_range(&[], u64::MAX).unwrap() < u64::MAX);
+        // the rejection zone of a range just above 2^63 is almost half of the
+        // samples
+        let n = (1u64 << 63) + 1;
+        for output in outputs(100) {
+            assert!(output_to_range(&output, n).unwrap() < n);
+        }
+
+        let mut counts = vec![0; 6];
+        for output in outputs(6000) {
+            counts[output_to_range(&output, 6).unwrap() as usize] += 1;
+        }
+        assert!(counts.iter().all(|count| (850..1150).contains(count)));
+    }
+
+    #[test]
+    fn test_sortition() {
+        let output = expand(b"test", &[], 0);
+        assert!(sortition(&output, 11, 10, 5).is_err());
+        assert!(sortition(&output, 5, 10, 11).is_err());
+        assert_eq!(sortition(&output, 0, 10, 5).unwrap(), 0);
+        assert_eq!(sortition(&output, 5, 10, 0).unwrap(), 0);
+        assert_eq!(sortition(&output, 5, 10, 10).unwrap(), 5);
+        assert!(sortition(&output, 5, 10, 9).unwrap() <= 5);
+
+        // the selections average to the stake share of the expected ones, even
+        // when P(B = 0) underflows an f64
+        for (stake, total_stake, expected) in [
+            (1_000, 1_000_000, 5_000),
+            (u64::MAX / 2, u64::MAX, 2_000),
+            (1 << 40, 1 << 41, 4_000),
+            (8, 10, 9),
+        ] {
+            let mean = stake as f64 / total_stake as f64 * expected as f64;
+            let sum: u64 = outputs(1000)
+                .iter()
+                .map(|output| sortition(output, stake, total_stake, expected).unwrap())
+                .sum();
+            let average = sum as f64 / 1000.0;
+            assert!((average - mean).abs() < 0.1 * mean);
+        }
+    }
+
+    #[test]
+    fn test_pow_one_minus() {
+        assert_eq!(pow_one_minus(0.25, 0.75, 3).to_f64(), 0.421875);
+        // a probability below the precision of 1 - p
+        let p = 1e-18;
+        let pow = pow_one_minus(p, 1.0 - p, 1 << 50).to_f64();
+        assert!((pow - 0.998874733680649).abs() < 1e-12);
+        // an underflowing power
+        let pow = pow_one_minus(0.5, 0.5, 2000);
+        assert_eq!(pow.to_f64(), 0.0);
+        assert_eq!(pow.e, -1999);
+    }
+}
@@ -0,0 +1,428 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Ring VRF over a twisted Edwards curve `P` embedded in the scalar field of
+//! a pairing-friendly curve `E`, e.g. Jubjub and BLS12-381, for anonymous
+//! leader election.
+//!
+//! A [`Ring`] commits to a list of registered public keys with a Rescue
+//! Merkle tree. The holder of one of the keys evaluates the VRF on an input
+//! as follows:
+//!
+//! * the input is hashed to a point `H` of the curve, see
+//!   [`jf_rescue::hash_to_curve`],
+//! * the pre-output is `Gamma = sk * H`, and the VRF output is the hash of
+//!   `Gamma`,
+//! * a PLONK proof shows that `Gamma = sk * H` for the secret key `sk` of some
+//!   public key `sk * G` registered in the ring, without revealing which.
+//!
+//! As `Gamma` only depends on the secret key and the input, each member of
+//! the ring has a single output per input, while the output can't be linked
+//! to the member who computed it.
+
+use ark_ec::{
+    pairing::Pairing,
+    short_weierstrass::{Affine as SWAffine, SWCurveConfig},
+    twisted_edwards::{Affine, TECurveConfig},
+    AffineRepr, CurveGroup,
+};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{
+    fmt, format,
+    marker::PhantomData,
+    rand::{CryptoRng, RngCore},
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+    One, UniformRand,
+};
+use derivative::Derivative;
+use displaydoc::Display;
+use jf_merkle_tree::{
+    gadgets::MerkleTreeGadget, prelude::RescueMerkleTree, MerkleCommitment, MerkleTreeScheme,
+};
+use jf_plonk::{
+    proof_system::{
+        structs::{Proof, ProvingKey, VerifyingKey},
+        PlonkKzgSnark, UniversalSNARK,
+    },
+    transcript::StandardTranscript,
+};
+use jf_relation::{
+    gadgets::ecc::{SWToTEConParam, TEPoint},
+    Arithmetization, Circuit, CircuitError, PlonkCircuit,
+};
+use jf_rescue::{
+    crhf::RescueCRHF, gadgets::RescueNativeGadget, hash_to_curve::hash_to_curve, RescueParameter,
+};
+use jf_utils::fr_to_fq;
+use sha2::{Digest, Sha512};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Domain separator of the hash of the VRF inputs to the curve.
+const INPUT_DST: &[u8] = b"JF_RING_VRF_INPUT";
+/// Domain separator of the leaves of the ring, hashing the public keys.
+const KEY_TAG: &[u8] = b"JF_RING_VRF_KEY";
+/// Domain separator of the VRF output.
+const OUTPUT_TAG: &[u8] = b"JF_RING_VRF_OUTPUT";
+
+/// Ring VRF Error: {0}
+#[derive(Debug, Display)]
+pub struct RingVRFError(String);
+impl ark_std::error::Error for RingVRFError {}
+
+/// Secret key of a member of a ring.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "P: TECurveConfig"),
+    PartialEq(bound = "P: TECurveConfig"),
+    Eq(bound = "P: TECurveConfig")
+)]
+pub struct RingSecretKey<P: TECurveConfig>(P::ScalarField);
+
+impl<P: TECurveConfig> fmt::Debug for RingSecretKey<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RingSecretKey").finish_non_exhaustive()
+    }
+}
+
+impl<P: TECurveConfig> Zeroize for RingSecretKey<P> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<P: TECurveConfig> Drop for RingSecretKey<P> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<P: TECurveConfig> ZeroizeOnDrop for RingSecretKey<P> {}
+
+impl<P: TECurveConfig> RingSecretKey<P> {
+    /// The public key `sk * G` to register in a ring.
+    pub fn public_key(&self) -> Affine<P> {
+        (Affine::<P>::generator() * self.0).into_affine()
+    }
+}
+
+/// A list of public keys, committed to with a Rescue Merkle tree of fixed
+/// height.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "P: TECurveConfig"), Debug(bound = "P: TECurveConfig"))]
+pub struct Ring<P: TECurveConfig>
+where
+    P::BaseField: RescueParameter,
+{
+    keys: Vec<Affine<P>>,
+    tree: RescueMerkleTree<P::BaseField>,
+}
+
+impl<P, F> Ring<P>
+where
+    P: TECurveConfig<BaseField = F>,
+    F: RescueParameter,
+{
+    /// Commit to `keys` with a tree of height `height`, which holds up to
+    /// `3^height` keys.
+    pub fn new(keys: Vec<Affine<P>>, height: usize) -> Result<Self, RingVRFError> {
+        let tree = RescueMerkleTree::<F>::from_elems(Some(height), keys.iter().map(key_leaf::<P>))
+            .map_err(|e| RingVRFError(e.to_string()))?;
+        Ok(Self { keys, tree })
+    }
+
+    /// The commitment to the ring, against which proofs are verified.
+    pub fn commitment(&self) -> F {
+        self.tree.commitment().digest()
+    }
+
+    /// The height of the tree.
+    pub fn height(&self) -> usize {
+        self.tree.height()
+    }
+
+    /// The public keys of the ring.
+    pub fn keys(&self) -> &[Affine<P>] {
+        &self.keys
+    }
+}
+
+/// Preprocessed proving and verifying keys for rings of a given height.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "E: Pairing"), Debug(bound = "E: Pairing"))]
+pub struct RingVRFParams<E: Pairing> {
+    height: usize,
+    proving_key: ProvingKey<E>,
+    verifying_key: VerifyingKey<E>,
+}
+
+impl<E: Pairing> RingVRFParams<E> {
+    /// The height of the rings that the parameters support.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+}
+
+/// Ring VRF proof: the pre-output and the proof that it was computed by a
+/// member of the ring.
+#[derive(CanonicalSerialize, CanonicalDeserialize, Derivative)]
+#[derivative(
+    Clone(bound = "E: Pairing, P: TECurveConfig"),
+    Debug(bound = "E: Pairing, P: TECurveConfig"),
+    PartialEq(bound = "E: Pairing, P: TECurveConfig")
+)]
+pub struct RingVRFProof<E: Pairing, P: TECurveConfig> {
+    gamma: Affine<P>,
+    proof: Proof<E>,
+}
+
+/// Ring VRF over the curve `P`, with PLONK proofs over `E`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RingVRF<E, P> {
+    _phantom: PhantomData<(E, P)>,
+}
+
+impl<E, P, F, Fq, Q> RingVRF<E, P>
+where
+    E: Pairing<ScalarField = F, BaseField = Fq, G1Affine = SWAffine<Q>>,
+    P: TECurveConfig<BaseField = F>,
+    F: RescueParameter,
+    Fq: RescueParameter + SWToTEConParam,
+    Q: SWCurveConfig<BaseField = Fq>,
+{
+    /// Sample a secret key and its public key.
+    pub fn key_gen<R: CryptoRng + RngCore>(prng: &mut R) -> (RingSecretKey<P>, Affine<P>) {
+        let sk = RingSecretKey(P::ScalarField::rand(prng));
+        let pk = sk.public_key();
+        (sk, pk)
+    }
+
+    /// The size of the universal SRS needed for rings of height `height`.
+    pub fn srs_size(height: usize) -> Result<usize, RingVRFError> {
+        Self::dummy_circuit(height)?
+            .srs_size()
+            .map_err(|e| RingVRFError(e.to_string()))
+    }
+
+    /// Preprocess the proving and verifying keys for rings of height `height`
+    /// from the universal SRS `srs`.
+    pub fn setup(
+        srs: &<PlonkKzgSnark<E> as UniversalSNARK<E>>::UniversalSRS,
+        height: usize,
+    ) -> Result<RingVRFParams<E>, RingVRFError> {
+        let circuit = Self::dummy_circuit(height)?;
+        let (proving_key, verifying_key) = PlonkKzgSnark::<E>::preprocess(srs, &circuit)
+            .map_err(|e| RingVRFError(e.to_string()))?;
+        Ok(RingVRFParams {
+            height,
+            proving_key,
+            verifying_key,
+        })
+    }
+
+    /// Evaluate the VRF on `input` with the secret key `sk`, whose public key
+    /// must be in `ring`, and prove it anonymously.
+    pub fn prove<R: CryptoRng + RngCore>(
+        params: &RingVRFParams<E>,
+        ring: &Ring<P>,
+        sk: &RingSecretKey<P>,
+        input: &[u8],
+        prng: &mut R,
+    ) -> Result<RingVRFProof<E, P>, RingVRFError> {
+        let (circuit, gamma) = Self::circuit(params.height, ring, sk, input)?;
+        let proof = PlonkKzgSnark::<E>::prove::<_, _, StandardTranscript>(
+            prng,
+            &circuit,
+            &params.proving_key,
+            None,
+        )
+        .map_err(|e| RingVRFError(e.to_string()))?;
+        Ok(RingVRFProof { gamma, proof })
+    }
+
+    /// Verify that `proof` was computed on `input` by a member of the ring of
+    /// commitment `ring_commitment`, and return the VRF output.
+    pub fn verify(
+        params: &RingVRFParams<E>,
+        ring_commitment: &F,
+        input: &[u8],
+        proof: &RingVRFProof<E, P>,
+    ) -> Result<Vec<u8>, RingVRFError> {
+        let h = hash_to_curve::<P>(INPUT_DST, input);
+        let public_input = [*ring_commitment, h.x, h.y, proof.gamma.x, proof.gamma.y];
+        PlonkKzgSnark::<E>::verify::<StandardTranscript>(
+            &params.verifying_key,
+            &public_input,
+            &proof.proof,
+            None,
+        )
+        .map_err(|e| RingVRFError(e.to_string()))?;
+        Ok(Self::proof_to_hash(proof))
+    }
+
+    /// Computes the VRF output associated with a proof, which should have been
+    /// checked with [`Self::verify`].
+    pub fn proof_to_hash(proof: &RingVRFProof<E, P>) -> Vec<u8> {
+        let mut gamma_bytes = Vec::new();
+        // serializing into a vector can't fail
+        proof.gamma.serialize_compressed(&mut gamma_bytes).unwrap();
+        Sha512::new()
+            .chain_update(OUTPUT_TAG)
+            .chain_update(gamma_bytes)
+            .finalize()
+            .to_vec()
+    }
+
+    // A circuit for the unit key in a ring of height `height`, whose shape
+    // only depends on the height.
+    fn dummy_circuit(height: usize) -> Result<PlonkCircuit<F>, RingVRFError> {
+        let sk = RingSecretKey(P::ScalarField::one());
+        let ring = Ring::new(vec![sk.public_key()], height)?;
+        Ok(Self::circuit(height, &ring, &sk, &[])?.0)
+    }
+
+    // The circuit proving the evaluation of the VRF on `input` with `sk`,
+    // along with the pre-output.
+    //
+    // The public inputs are the commitment to the ring, the point `H` and the
+    // pre-output `Gamma`.
+    fn circuit(
+        height: usize,
+        ring: &Ring<P>,
+        sk: &RingSecretKey<P>,
+        input: &[u8],
+    ) -> Result<(PlonkCircuit<F>, Affine<P>), RingVRFError> {
+        if ring.height() != height {
+            return Err(RingVRFError(format!(
+                "ring of height {}, expecting {}",
+                ring.height(),
+                height
+            )));
+        }
+        let pk = sk.public_key();
+        let index = ring
+            .keys
+            .iter()
+            .position(|key| *key == pk)
+            .ok_or_else(|| RingVRFError("public key not in the ring".to_string()))?;
+        let (_, merkle_proof) = ring
+            .tree
+            .lookup(index as u64)
+            .expect_ok()
+            .map_err(|e| RingVRFError(e.to_string()))?;
+        let h = hash_to_curve::<P>(INPUT_DST, input);
+        let gamma = (h * sk.0).into_affine();
+
+        let mut circuit = PlonkCircuit::<F>::new_turbo_plonk();
+        let build = |circuit: &mut PlonkCircuit<F>| -> Result<(), CircuitError> {
+            let root_var = circuit.create_public_variable(ring.commitment())?;
+            let h_var = circuit.create_public_point_variable(TEPoint::from(h))?;
+            let gamma_var = circuit.create_public_point_variable(TEPoint::from(gamma))?;
+
+            let sk_var = circuit.create_variable(fr_to_fq::<F, P>(&sk.0))?;
+            let index_var = circuit.create_variable(F::from(index as u64))?;
+            let proof_var =
+                MerkleTreeGadget::<RescueMerkleTree<F>>::create_membership_proof_variable(
+                    circuit,
+                    &merkle_proof,
+                )?;
+
+            // the public key `sk * G` is a leaf of the ring
+            let pk_var = circuit.fixed_base_scalar_mul::<P>(sk_var, &Affine::<P>::generator())?;
+            let tag_var = circuit.create_constant_variable(F::from_le_bytes_mod_order(KEY_TAG))?;
+            let leaf_var = RescueNativeGadget::<F>::rescue_sponge_no_padding(
+                circuit,
+                &[pk_var.get_x(), pk_var.get_y(), tag_var],
+                1,
+            )?[0];
+            circuit.enforce_equal(leaf_var, proof_var.elem_var())?;
+            MerkleTreeGadget::<RescueMerkleTree<F>>::enforce_membership_proof(
+                circuit, index_var, proof_var, root_var,
+            )?;
+
+            // Gamma = sk * H
+            let computed_gamma_var = circuit.variable_base_scalar_mul::<P>(sk_var, &h_var)?;
+            circuit.enforce_point_equal(&computed_gamma_var, &gamma_var)?;
+            circuit.finalize_for_arithmetization()
+        };
+        build(&mut circuit).map_err(|e| RingVRFError(e.to_string()))?;
+        Ok((circuit, gamma))
+    }
+}
+
+// The leaf of the ring for the public key `pk`.
+fn key_leaf<P>(pk: &Affine<P>) -> P::BaseField
+where
+    P: TECurveConfig,
+    P::BaseField: RescueParameter,
+{
+    let tag = P::BaseField::from_le_bytes_mod_order(KEY_TAG);
+    // the input length is a multiple of the rate
+    RescueCRHF::<P::BaseField>::sponge_no_padding(&[pk.x, pk.y, tag], 1).unwrap()[0]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_381::Bls12_381;
+    use ark_ed_on_bls12_381::EdwardsConfig as JubjubConfig;
+    use jf_utils::test_rng;
+
+    type Scheme = RingVRF<Bls12_381, JubjubConfig>;
+
+    #[test]
+    fn test_ring_vrf() {
+        let rng = &mut test_rng();
+        let height = 2;
+        let srs =
+            <PlonkKzgSnark<Bls12_381> as UniversalSNARK<Bls12_381>>::universal_setup_for_testing(
+                Scheme::srs_size(height).unwrap(),
+                rng,
+            )
+            .unwrap();
+        let params = Scheme::setup(&srs, height).unwrap();
+
+        let members: Vec<_> = (0..5).map(|_| Scheme::key_gen(rng)).collect();
+        let ring = Ring::new(members.iter().map(|(_, pk)| *pk).collect(), height).unwrap();
+        let commitment = ring.commitment();
+
+        let input = b"slot 42";
+        let proof = Scheme::prove(&params, &ring, &members[3].0, input, rng).unwrap();
+        let output = Scheme::verify(&params, &commitment, input, &proof).unwrap();
+        assert_eq!(output, Scheme::proof_to_hash(&proof));
+
+        // the output is unique: a fresh proof has the same output, and other
+        // members have different ones
+        let other_proof = Scheme::prove(&params, &ring, &members[3].0, input, rng).unwrap();
+        assert_eq!(
+            Scheme::verify(&params, &commitment, input, &other_proof).unwrap(),
+            output
+        );
+        let other_member_proof = Scheme::prove(&params, &ring, &members[0].0, input, rng).unwrap();
+        assert_ne!(
+            Scheme::verify(&params, &commitment, input, &other_member_proof).unwrap(),
+            output
+        );
+
+        // wrong input, ring or pre-output
+        assert!(Scheme::verify(&params, &commitment, b"slot 43", &proof).is_err());
+        let other_ring =
+            Ring::new(members[..4].iter().map(|(_, pk)| *pk).collect(), height).unwrap();
+        assert!(Scheme::verify(&params, &other_ring.commitment(), input, &proof).is_err());
+        let mut bad_proof = proof.clone();
+        bad_proof.gamma = other_member_proof.gamma;
+        assert!(Scheme::verify(&params, &commitment, input, &bad_proof).is_err());
+
+        // non-members and rings of another height can't prove
+        let (outsider, _) = Scheme::key_gen(rng);
+        assert!(Scheme::prove(&params, &ring, &outsider, input, rng).is_err());
+        let tall_ring = Ring::new(ring.keys().to_vec(), height + 1).unwrap();
+        assert!(Scheme::prove(&params, &tall_ring, &members[0].0, input, rng).is_err());
+        assert!(Ring::new(vec![members[0].1; 10], height).is_err());
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright (c) 2022 Espresso Systems (espressosys.com)
+// This file is part of the Jellyfish library.
+
+// You should have received a copy of the MIT License
+// along with the Jellyfish library. If not, see <https://mit-license.org/>.
+
+//! Threshold VRF over BN254, built on the threshold BLS signatures of
+//! [`jf_signature::threshold_bls`], e.g. for unbiased randomness beacons.
+//!
+//! A committee of `n` parties runs the distributed key generation of
+//! [`ThresholdBLSOverBN254`] to share a group key pair. Any `t` of them then
+//! evaluate the VRF on an input: each one computes a partial proof with its key
+//! share, checked against its verification key share, and `t` partial proofs
+//! are combined into a proof under the group public key. As BLS signatures are
+//! unique, the combined proof, and thus the VRF output, doesn't depend on
+//! which parties took part, and no coalition of less than `t` parties can
+//! predict or bias it.
+//!
+//! The combined proofs are verified with [`Vrf::verify`], as proofs computed
+//! with a single group secret key.
+
+use super::Vrf;
+use ark_std::{
+    format,
+    rand::{CryptoRng, RngCore},
+    string::{String, ToString},
+    vec::Vec,
+    Zero,
+};
+use displaydoc::Display;
+use jf_signature::{
+    bls_over_bn254::{KeyPair, SignKey, Signature, VerKey},
+    constants::CS_ID_BLS_BN254,
+    threshold_bls::{PartialSignature, ThresholdBLSOverBN254},
+};
+use sha2::{Digest, Sha256};
+
+/// Domain separator of the VRF output.
+const OUTPUT_TAG: &[u8] = b"JF_THRESHOLD_VRF_BN254_OUTPUT";
+
+/// Threshold VRF Error: {0}
+#[derive(Debug, Display)]
+pub struct ThresholdVRFError(String);
+impl ark_std::error::Error for ThresholdVRFError {}
+
+/// Threshold VRF where any `threshold` out of `num_parties` parties can
+/// evaluate the VRF.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ThresholdBLSVRF {
+    scheme: ThresholdBLSOverBN254,
+}
+
+impl ThresholdBLSVRF {
+    /// Create a `threshold`-out-of-`num_parties` VRF. Return an error unless
+    /// `1 <= threshold <= num_parties`.
+    pub fn new(threshold: usize, num_parties: usize) -> Result<Self, ThresholdVRFError> {
+        let scheme = ThresholdBLSOverBN254::new(threshold, num_parties)
+            .map_err(|e| ThresholdVRFError(e.to_string()))?;
+        Ok(Self { scheme })
+    }
+
+    /// The underlying threshold signature scheme, which runs the distributed
+    /// key generation.
+    pub fn threshold_scheme(&self) -> &ThresholdBLSOverBN254 {
+        &self.scheme
+    }
+
+    /// Compute the partial proof of party `index` on `input` with its key
+    /// share `sk`.
+    pub fn partial_prove(&self, index: u32, sk: &SignKey, input: &[u8]) -> PartialSignature {
+        self.scheme.partial_sign(index, sk, input)
+    }
+
+    /// Check a partial proof on `input` against the verification key share of
+    /// its party.
+    pub fn partial_verify(
+        &self,
+        vk: &VerKey,
+        input: &[u8],
+        partial_proof: &PartialSignature,
+    ) -> Result<(), ThresholdVRFError> {
+        self.scheme
+            .partial_verify(vk, input, partial_proof)
+            .map_err(|e| ThresholdVRFError(e.to_string()))
+    }
+
+    /// Combine the partial proofs of at least `threshold` distinct parties,
+    /// checked with [`Self::partial_verify`], into a proof under the group
+    /// public key.
+    pub fn combine(
+        &self,
+        partial_proofs: &[PartialSignature],
+    ) -> Result<Signature, ThresholdVRFError> {
+        self.scheme
+            .combine(partial_proofs)
+            .map_err(|e| ThresholdVRFError(e.to_string()))
+    }
+}
+
+impl Vrf for ThresholdBLSVRF {
+    /// The default generators are used.
+    type PublicParameter = ();
+
+    /// VRF public key, the group verification key.
+    type PublicKey = VerKey;
+
+    /// VRF secret key.
+    type SecretKey = SignKey;
+
+    /// VRF proof, a BLS signature on the input.
+    type Proof = Signature;
+
+    /// The input of VRF proof.
+    type Input = Vec<u8>;
+
+    /// The output of VRF evaluation.
+    type Output = Vec<u8>;
+
+    /// VRF Error
+    type Error = ThresholdVRFError;
+
+    /// generate public parameters from RNG.
+    fn param_gen<R: CryptoRng + RngCore>(
+        &self,
+        _prng: Option<&mut R>,
+    ) -> Result<Self::PublicParameter, ThresholdVRFError> {
+        Ok(())
+    }
+
+    /// Creates a pair of VRF public and private keys held by a single party.
+    /// Shared keys are generated with the distributed key generation of
+    /// [`ThresholdBLSOverBN254`].
+    fn key_gen<R: CryptoRng + RngCore>(
+        &self,
+        _pp: &Self::PublicParameter,
+        prng: &mut R,
+    ) -> Result<(Self::SecretKey, Self::PublicKey), ThresholdVRFError> {
+        let kp = KeyPair::generate(prng);
+        Ok((kp.sign_key_ref().clone(), kp.ver_key()))
+    }
+
+    /// Creates the VRF proof associated with a VRF secret key held by a
+    /// single party.
+    fn prove<R: CryptoRng + RngCore>(
+        &self,
+        _pp: &Self::PublicParameter,
+        secret_key: &Self::SecretKey,
+        input: &Self::Input,
+        _prng: &mut R,
+    ) -> Result<Self::Proof, ThresholdVRFError> {
+        Ok(KeyPair::from(secret_key.clone()).sign(input, CS_ID_BLS_BN254))
+    }
+
+    /// Computes the VRF output associated with a VRF proof.
+    fn proof_to_hash(
+        &mut self,
+        _pp: &Self::PublicParameter,
+        proof: &Self::Proof,
+    ) -> Result<Self::Output, ThresholdVRFError> {
+        Ok(Sha256::new()
+            .chain_update(OUTPUT_TAG)
+            .chain_update(proof.to_evm_bytes())
+            .finalize()
+            .to_vec())
+    }
+
+    /// Verifies a VRF proof, either computed with a single secret key or
+    /// combined from partial proofs. The identity public key is rejected.
+    fn verify(
+        &mut self,
+        pp: &Self::PublicParameter,
+        proof: &Self::Proof,
+        public_key: &Self::PublicKey,
+        input: &Self::Input,
+    ) -> Result<(bool, Option<Self::Output>), ThresholdVRFError> {
+        if public_key.internal().is_zero()
+            || public_key.verify(input, proof, CS_ID_BLS_BN254).is_err()
+        {
+            return Ok((false, None));
+        }
+        Ok((true, Some(self.proof_to_hash(pp, proof)?)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_std::vec;
+    use jf_signature::threshold_bls::DealerCommitment;
+    use jf_utils::test_rng;
+
+    #[test]
+    fn test_threshold_vrf() {
+        let rng = &mut test_rng();
+        let (threshold, num_parties) = (3, 5);
+        let mut vrf = ThresholdBLSVRF::new(threshold, num_parties).unwrap();
+        let scheme = *vrf.threshold_scheme();
+
+        // distributed key generation
+        let dealings: Vec<_> = (0..num_parties).map(|_| scheme.deal(rng)).collect();
+        let commitments: Vec<DealerCommitment> = dealings.iter().map(|(c, _)| c.clone()).collect();
+        let group_pk = scheme.group_ver_key(&commitments).unwrap();
+        let mut sks = vec![];
+        let mut vks = vec![];
+        for j in 1..=num_parties as u32 {
+            let received: Vec<_> = dealings
+                .iter()
+                .map(|(c, shares)| (c.clone(), shares[j as usize - 1].clone()))
+                .collect();
+            sks.push(scheme.combine_shares(j, &received).unwrap());
+            vks.push(scheme.ver_key_share(&commitments, j).unwrap());
+        }
+
+        let input = b"beacon round 7".to_vec();
+        let partial_proofs: Vec<_> = sks
+            .iter()
+            .enumerate()
+            .map(|(i, sk)| vrf.partial_prove(i as u32 + 1, sk, &input))
+            .collect();
+        for (vk, partial_proof) in vks.iter().zip(partial_proofs.iter()) {
+            assert!(vrf.partial_verify(vk, &input, partial_proof).is_ok());
+            assert!(vrf.partial_verify(vk, b"round 8", partial_proof).is_err());
+        }
+
+        // any `threshold` parties yield the same output
+        let proof = vrf.combine(&partial_proofs).unwrap();
+        let (is_correct, output) = vrf.verify(&(), &proof, &group_pk, &input).unwrap();
+        assert!(is_correct);
+        assert_eq!(output.unwrap(), vrf.proof_to_hash(&(), &proof).unwrap());
+        let other_proof = vrf.combine(&partial_proofs[2..]).unwrap();
+        assert_eq!(other_proof, proof);
+        assert!(vrf.combine(&partial_proofs[..threshold - 1]).is_err());
+
+        // wrong input or key
+        let bad_input = b"beacon round 8".to_vec();
+        assert_eq!(
+            vrf.verify(&(), &proof, &group_pk, &bad_input).unwrap(),
+            (false, None)
+        );
+        assert!(!vrf.verify(&(), &proof, &vks[0], &input).unwrap().0);
+    }
+
+    #[test]
+    fn test_single_party_vrf() {
+        let rng = &mut test_rng();
+        let mut vrf = ThresholdBLSVRF::new(1, 1).unwrap();
+        let (sk, pk) = vrf.key_gen(&(), rng).unwrap();
+        let input = b"input".to_vec();
+        let proof = vrf.prove(&(), &sk, &input, rng).unwrap();
+        // a single party's partial proof is the proof
+        assert_eq!(vrf.partial_prove(1, &sk, &input).sig, proof);
+        let output = vrf.evaluate(&(), &sk, &input, rng).unwrap();
+        assert_eq!(
+            vrf.verify(&(), &proof, &pk, &input).unwrap(),
+            (true, Some(output))
+        );
+
+        // the identity public key is rejected
+        let (zero_sk, zero_pk) = (SignKey::default(), VerKey::from(&SignKey::default()));
+        let proof = vrf.prove(&(), &zero_sk, &input, rng).unwrap();
+        assert!(!vrf.verify(&(), &proof, &zero_pk, &input).unwrap().0);
+    }
+}